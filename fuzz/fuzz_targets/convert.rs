@@ -0,0 +1,71 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use palette::convert::FromColorUnclamped;
+use palette::encoding::Srgb;
+use palette::rgb::Rgb;
+use palette::white_point::D65;
+use palette::{ComponentArray, Hsl, Hsluv, Hsv, Hwb, Lab, Lch, Lchuv, LinSrgb, Luv, Xyz, Yxy};
+
+/// Convert `color` to `C` and back, and check that neither conversion
+/// produces NaN.
+fn round_trip<C>(color: Rgb<Srgb, f64>)
+where
+    C: FromColorUnclamped<Rgb<Srgb, f64>> + ComponentArray<f64, 3> + Copy + std::fmt::Debug,
+    Rgb<Srgb, f64>: FromColorUnclamped<C>,
+{
+    let converted = C::from_color_unclamped(color);
+    let components = converted.into_array();
+    assert!(
+        components.iter().all(|c| !c.is_nan()),
+        "{:?} -> {:?}",
+        color,
+        converted
+    );
+
+    let back = Rgb::<Srgb, f64>::from_color_unclamped(converted);
+    assert!(
+        !back.red.is_nan() && !back.green.is_nan() && !back.blue.is_nan(),
+        "{:?} -> {:?} -> {:?}",
+        color,
+        converted,
+        back
+    );
+}
+
+fuzz_target!(|components: (f64, f64, f64)| {
+    let (red, green, blue) = components;
+    if !(red.is_finite() && green.is_finite() && blue.is_finite()) {
+        return;
+    }
+
+    // The CIE spaces are fine with colors outside the sRGB gamut, within a
+    // margin that keeps the intermediate values finite.
+    let limit = 1.0e6;
+    let color = Rgb::new(
+        red.max(-limit).min(limit),
+        green.max(-limit).min(limit),
+        blue.max(-limit).min(limit),
+    );
+
+    round_trip::<LinSrgb<f64>>(color);
+    round_trip::<Xyz<D65, f64>>(color);
+    round_trip::<Yxy<D65, f64>>(color);
+    round_trip::<Lab<D65, f64>>(color);
+    round_trip::<Lch<D65, f64>>(color);
+    round_trip::<Luv<D65, f64>>(color);
+    round_trip::<Lchuv<D65, f64>>(color);
+
+    // The RGB based cylindrical spaces, and HSLuv, are only defined within
+    // the gamut.
+    let color = Rgb::new(
+        red.max(0.0).min(1.0),
+        green.max(0.0).min(1.0),
+        blue.max(0.0).min(1.0),
+    );
+
+    round_trip::<Hsl<Srgb, f64>>(color);
+    round_trip::<Hsv<Srgb, f64>>(color);
+    round_trip::<Hwb<Srgb, f64>>(color);
+    round_trip::<Hsluv<D65, f64>>(color);
+});
@@ -0,0 +1,41 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use palette::css::relative_color;
+use palette::named::{self, KeywordResolver, SystemColor};
+use palette::{Srgb, Srgba};
+
+struct Resolver;
+
+impl KeywordResolver for Resolver {
+    fn current_color(&self) -> Srgba<u8> {
+        Srgba::new(12, 34, 56, 78)
+    }
+
+    fn system_color(&self, color: SystemColor) -> Srgb<u8> {
+        color.default_color()
+    }
+}
+
+fuzz_target!(|data: &str| {
+    let _ = named::from_str(data);
+    let _ = named::resolve(data, &Resolver);
+
+    let origin = Srgba::new(0.2f64, 0.4, 0.6, 0.8);
+    if let Ok(color) = relative_color(data, origin) {
+        // The channels of `rgb()` are used directly, so NaN here would come
+        // from the parser. Other functions may convert extreme but valid
+        // values into NaN.
+        let is_rgb =
+            matches!(data.trim_start().get(..3), Some(name) if name.eq_ignore_ascii_case("rgb"));
+        if is_rgb {
+            let components = [color.red, color.green, color.blue, color.alpha];
+            assert!(
+                components.iter().all(|c| !c.is_nan()),
+                "{:?} -> {:?}",
+                data,
+                color
+            );
+        }
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use palette::rgb::{Rgb, Rgba};
+
+fuzz_target!(|data: &str| {
+    // Colors that parse should be written back as the same digits.
+    if let Ok(color) = Rgb::<palette::encoding::Srgb, u8>::from_str(data) {
+        let hex = format!("{:02x}{:02x}{:02x}", color.red, color.green, color.blue);
+        assert_eq!(Rgb::from_str(&hex), Ok(color));
+    }
+
+    if let Ok(color) = Rgba::<palette::encoding::Srgb, u8>::from_str(data) {
+        let hex = format!(
+            "{:02x}{:02x}{:02x}{:02x}",
+            color.red, color.green, color.blue, color.alpha
+        );
+        assert_eq!(Rgba::from_str(&hex), Ok(color));
+    }
+});
@@ -0,0 +1,40 @@
+#[cfg(feature = "blue_noise")]
+pub fn build() {
+    use std::fs::File;
+    use std::io::{BufRead, BufReader, Write};
+    use std::path::Path;
+
+    let reader = BufReader::new(
+        File::open("build/blue_noise_64.txt").expect("could not open blue_noise_64.txt"),
+    );
+
+    let mut ranks = Vec::with_capacity(64 * 64);
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.unwrap();
+        let row: Vec<u16> = line
+            .split_whitespace()
+            .map(|rank| {
+                rank.parse().unwrap_or_else(|_| {
+                    panic!("couldn't parse rank {} on line {}", rank, index + 1)
+                })
+            })
+            .collect();
+        assert_eq!(row.len(), 64, "line {} should have 64 ranks", index + 1);
+        ranks.extend(row);
+    }
+    assert_eq!(ranks.len(), 64 * 64, "there should be 64 lines of ranks");
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("blue_noise.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create blue_noise.rs");
+
+    writeln!(writer, "const RANKS: [u16; {}] = [", ranks.len()).unwrap();
+    for row in ranks.chunks(16) {
+        let row: Vec<String> = row.iter().map(|rank| rank.to_string()).collect();
+        writeln!(writer, "    {},", row.join(", ")).unwrap();
+    }
+    writeln!(writer, "];").unwrap();
+}
+
+#[cfg(not(feature = "blue_noise"))]
+pub fn build() {}
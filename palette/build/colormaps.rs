@@ -0,0 +1,118 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// The number of colors in each colormap.
+const COLORMAP_SIZE: usize = 256;
+
+pub fn build() {
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("colormaps.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create colormaps.rs");
+
+    let reader = BufReader::new(
+        File::open("build/svg_gradients_mpl.txt").expect("could not open svg_gradients_mpl.txt"),
+    );
+
+    let mut lines = reader.lines();
+    while let Some(Ok(line)) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let name = parts.next().expect("couldn't get the colormap name");
+        let number_of_colors: usize = parts
+            .nth(1)
+            .and_then(|number| number.parse().ok())
+            .unwrap_or_else(|| panic!("couldn't get the number of colors for {}", name));
+        assert_eq!(
+            number_of_colors, COLORMAP_SIZE,
+            "{} should have {} colors",
+            name, COLORMAP_SIZE
+        );
+
+        let colors: Vec<[f64; 3]> = (0..number_of_colors)
+            .map(|i| {
+                let line = lines
+                    .next()
+                    .unwrap_or_else(|| panic!("less lines than stated colors in {}", name))
+                    .unwrap_or_else(|_| panic!("couldn't read the {}th line of {}", i, name));
+                let mut components = line
+                    .split(',')
+                    .map(|component| component.trim())
+                    .filter(|component| !component.is_empty())
+                    .map(|component| {
+                        component.parse().unwrap_or_else(|_| {
+                            panic!("couldn't parse the {}th color of {}", i, name)
+                        })
+                    });
+                let mut next = || {
+                    components
+                        .next()
+                        .unwrap_or_else(|| panic!("the {}th color of {} is incomplete", i, name))
+                };
+                [next(), next(), next()]
+            })
+            .collect();
+
+        write_colors(&mut writer, name, &colors);
+    }
+
+    let turbo: Vec<[f64; 3]> = (0..COLORMAP_SIZE)
+        .map(|i| turbo(i as f64 / (COLORMAP_SIZE - 1) as f64))
+        .collect();
+    write_colors(&mut writer, "turbo", &turbo);
+}
+
+fn write_colors(writer: &mut File, name: &str, colors: &[[f64; 3]]) {
+    writeln!(
+        writer,
+        "const {}_COLORS: [[f32; 3]; {}] = [",
+        name.to_uppercase(),
+        colors.len()
+    )
+    .unwrap();
+    for [red, green, blue] in colors {
+        writeln!(writer, "    [{:.6}, {:.6}, {:.6}],", red, green, blue).unwrap();
+    }
+    writeln!(writer, "];").unwrap();
+}
+
+/// The polynomial approximation of Turbo, by its author Anton Mikhailov.
+fn turbo(x: f64) -> [f64; 3] {
+    const RED: [f64; 6] = [
+        0.13572138,
+        4.61539260,
+        -42.66032258,
+        132.13108234,
+        -152.94239396,
+        59.28637943,
+    ];
+    const GREEN: [f64; 6] = [
+        0.09140261,
+        2.19418839,
+        4.84296658,
+        -14.18503333,
+        4.27729857,
+        2.82956604,
+    ];
+    const BLUE: [f64; 6] = [
+        0.10667330,
+        12.64194608,
+        -60.58204836,
+        110.36276771,
+        -89.90310912,
+        27.34824973,
+    ];
+
+    let evaluate = |coefficients: [f64; 6]| {
+        let value = coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |sum, &coefficient| sum * x + coefficient);
+        value.clamp(0.0, 1.0)
+    };
+
+    [evaluate(RED), evaluate(GREEN), evaluate(BLUE)]
+}
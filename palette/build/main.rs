@@ -1,8 +1,12 @@
 #[cfg(feature = "phf_codegen")]
 extern crate phf_codegen;
 
+mod blue_noise;
+mod colormaps;
 mod named;
 
 fn main() {
     named::build();
+    blue_noise::build();
+    colormaps::build();
 }
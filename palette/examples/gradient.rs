@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+mod util;
+
 #[cfg(not(feature = "std"))]
 fn main() {
     println!("You can't use gradients without the standard library");
@@ -5,9 +8,7 @@ fn main() {
 
 #[cfg(feature = "std")]
 fn main() {
-    use palette::{FromColor, Gradient, IntoColor, Lch, LinSrgb, Pixel, Srgb};
-
-    use image::{GenericImage, GenericImageView, RgbImage};
+    use palette::{FromColor, Gradient, IntoColor, Lch, LinSrgb, Srgb};
 
     //A gradient of evenly spaced colors
     let grad1 = Gradient::new(vec![
@@ -39,63 +40,14 @@ fn main() {
         (1.0, Lch::from_color(LinSrgb::new(0.1, 1.0, 0.1))),
     ]);
 
-    let mut image = RgbImage::new(256, 128);
-
-    for (i, ((c1, c2), (c3, c4))) in grad1
-        .take(256)
-        .zip(grad2.take(256))
-        .zip(grad3.take(256).zip(grad4.take(256)))
-        .enumerate()
-    {
-        let c1 = Srgb::from_linear(c1).into_format().into_raw();
-        let c2 = Srgb::from_linear(c2).into_format().into_raw();
-        let c3 = Srgb::from_linear(c3.into_color()).into_format().into_raw();
-        let c4 = Srgb::from_linear(c4.into_color()).into_format().into_raw();
+    let to_srgb = |color: LinSrgb| Srgb::from_linear(color).into_format();
 
-        {
-            let mut sub_image = image.sub_image(i as u32, 0, 1, 31);
-            let (width, height) = sub_image.dimensions();
-            for x in 0..width {
-                for y in 0..height {
-                    sub_image.put_pixel(x, y, image::Rgb(c1));
-                }
-            }
-        }
-
-        {
-            let mut sub_image = image.sub_image(i as u32, 32, 1, 31);
-            let (width, height) = sub_image.dimensions();
-            for x in 0..width {
-                for y in 0..height {
-                    sub_image.put_pixel(x, y, image::Rgb(c2));
-                }
-            }
-        }
-
-        {
-            let mut sub_image = image.sub_image(i as u32, 65, 1, 31);
-            let (width, height) = sub_image.dimensions();
-            for x in 0..width {
-                for y in 0..height {
-                    sub_image.put_pixel(x, y, image::Rgb(c3));
-                }
-            }
-        }
-
-        {
-            let mut sub_image = image.sub_image(i as u32, 97, 1, 31);
-            let (width, height) = sub_image.dimensions();
-            for x in 0..width {
-                for y in 0..height {
-                    sub_image.put_pixel(x, y, image::Rgb(c4));
-                }
-            }
-        }
-    }
+    let image = util::stack(&[
+        util::continuous_strip(256, 32, |x| to_srgb(grad1.get(x))),
+        util::continuous_strip(256, 32, |x| to_srgb(grad2.get(x))),
+        util::continuous_strip(256, 32, |x| to_srgb(grad3.get(x).into_color())),
+        util::continuous_strip(256, 32, |x| to_srgb(grad4.get(x).into_color())),
+    ]);
 
-    let _ = std::fs::create_dir("example-data/output");
-    match image.save("example-data/output/gradient.png") {
-        Ok(()) => println!("see 'example-data/output/gradient.png' for the result"),
-        Err(e) => println!("failed to write 'example-data/output/gradient.png': {}", e),
-    }
+    util::save(&image, "example-data/output/gradient.png");
 }
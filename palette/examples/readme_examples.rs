@@ -1,11 +1,10 @@
-use image::{GenericImage, GenericImageView, RgbImage};
-
 #[cfg(feature = "std")]
-use palette::{FromColor, Gradient, IntoColor, LinSrgb, Mix};
-use palette::{Pixel, Srgb};
+use palette::{FromColor, Gradient, IntoColor, LinSrgb, Mix, Srgb};
+
+mod util;
 
 mod color_spaces {
-    use crate::display_colors;
+    use crate::util::display_colors;
     use palette::{FromColor, Hue, IntoColor, Lch, Srgb};
 
     pub fn run() {
@@ -23,7 +22,7 @@ mod color_spaces {
 }
 
 mod manipulation {
-    use crate::display_colors;
+    use crate::util::display_colors;
     use palette::{FromColor, IntoColor, Lch, Saturate, Shade, Srgb};
 
     pub fn run() {
@@ -62,25 +61,6 @@ mod gradients {
     }
 }
 
-fn display_colors(filename: &str, colors: &[Srgb<u8>]) {
-    let mut image = RgbImage::new(colors.len() as u32 * 64, 64);
-    for (i, &color) in colors.iter().enumerate() {
-        let mut sub_image = image.sub_image(i as u32 * 64, 0, 64, 64);
-        let (width, height) = sub_image.dimensions();
-        for x in 0..width {
-            for y in 0..height {
-                sub_image.put_pixel(x, y, image::Rgb(*color.as_raw()));
-            }
-        }
-    }
-
-    let _ = std::fs::create_dir("example-data/output");
-    match image.save(filename) {
-        Ok(()) => println!("see '{}' for the result", filename),
-        Err(e) => println!("failed to write '{}': {}", filename, e),
-    }
-}
-
 #[cfg(feature = "std")]
 fn display_gradients<A: Mix<Scalar = f32> + Clone, B: Mix<Scalar = f32> + Clone>(
     filename: &str,
@@ -90,67 +70,22 @@ fn display_gradients<A: Mix<Scalar = f32> + Clone, B: Mix<Scalar = f32> + Clone>
     LinSrgb: FromColor<A>,
     LinSrgb: FromColor<B>,
 {
-    let mut image = RgbImage::new(256, 96);
-    {
-        let mut sub_image = image.sub_image(0, 0, 256, 32);
-        let (width, height) = sub_image.dimensions();
-        for x in 0..width {
-            for y in 0..height {
-                sub_image.put_pixel(
-                    x,
-                    y,
-                    image::Rgb(
-                        Srgb::from_linear(grad1.get(x as f32 / 255.0).into_color())
-                            .into_format()
-                            .into_raw(),
-                    ),
-                );
-            }
-        }
-    }
-
-    {
-        let mut sub_image = image.sub_image(0, 32, 256, 32);
-        let (width, height) = sub_image.dimensions();
-        for x in 0..width {
-            for y in 0..height {
-                sub_image.put_pixel(
-                    x,
-                    y,
-                    image::Rgb(
-                        Srgb::from_linear(grad2.get(x as f32 / 255.0).into_color())
-                            .into_format()
-                            .into_raw(),
-                    ),
-                );
-            }
-        }
-    }
-
-    {
-        let mut sub_image = image.sub_image(0, 64, 256, 32);
-        let swatch_size = 32;
-        let mut v1 = Vec::new();
-        for color in grad2.take(8) {
-            let pix: [u8; 3] = Srgb::from_linear(LinSrgb::from_color(color))
-                .into_format()
-                .into_raw();
-            v1.push(pix);
-        }
-        for (s, color) in v1.into_iter().enumerate() {
-            for x in (s * swatch_size)..((s + 1) * swatch_size) {
-                for y in 0..swatch_size {
-                    let pixel = sub_image.get_pixel_mut(x as u32, y as u32);
-                    *pixel = image::Rgb(color);
-                }
-            }
-        }
-    }
-
-    match image.save(filename) {
-        Ok(()) => println!("see '{}' for the result", filename),
-        Err(e) => println!("failed to write '{}': {}", filename, e),
-    }
+    let swatches: Vec<Srgb<u8>> = grad2
+        .take(8)
+        .map(|color| Srgb::from_linear(LinSrgb::from_color(color)).into_format())
+        .collect();
+
+    let image = util::stack(&[
+        util::continuous_strip(256, 32, |x| {
+            Srgb::from_linear(grad1.get(x).into_color()).into_format()
+        }),
+        util::continuous_strip(256, 32, |x| {
+            Srgb::from_linear(grad2.get(x).into_color()).into_format()
+        }),
+        util::discrete_strip(&swatches, 256, 32),
+    ]);
+
+    util::save(&image, filename);
 }
 
 fn main() {
@@ -0,0 +1,135 @@
+//! Helpers for rendering colors into images, shared by the examples and the
+//! golden image tests.
+//!
+//! Include it with `mod util;` from an example, or with
+//! `#[path = "../examples/util/mod.rs"] mod util;` from a test.
+
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use image::{GenericImage, RgbImage};
+use palette::{Pixel, Srgb};
+
+/// Render `colors` as a row of square swatches, `size` pixels wide.
+pub fn swatches(colors: &[Srgb<u8>], size: u32) -> RgbImage {
+    discrete_strip(colors, colors.len() as u32 * size, size)
+}
+
+/// Render `colors` as a strip of equally wide blocks.
+pub fn discrete_strip(colors: &[Srgb<u8>], width: u32, height: u32) -> RgbImage {
+    let mut image = RgbImage::new(width, height);
+    if colors.is_empty() {
+        return image;
+    }
+
+    for (x, _, pixel) in image.enumerate_pixels_mut() {
+        let index = (x as usize * colors.len()) / width as usize;
+        *pixel = image::Rgb(*colors[index].as_raw());
+    }
+
+    image
+}
+
+/// Render a continuous strip, where `color` gets the position of each column,
+/// from `0.0` at the first column to `1.0` at the last.
+pub fn continuous_strip<F>(width: u32, height: u32, color: F) -> RgbImage
+where
+    F: Fn(f32) -> Srgb<u8>,
+{
+    let mut image = RgbImage::new(width, height);
+    let last = width.saturating_sub(1).max(1) as f32;
+
+    for x in 0..width {
+        let pixel = image::Rgb(*color(x as f32 / last).as_raw());
+        for y in 0..height {
+            image.put_pixel(x, y, pixel);
+        }
+    }
+
+    image
+}
+
+/// Stack `images` on top of each other, with the first image at the top.
+pub fn stack(images: &[RgbImage]) -> RgbImage {
+    let width = images.iter().map(RgbImage::width).max().unwrap_or(0);
+    let height = images.iter().map(RgbImage::height).sum();
+
+    let mut stacked = RgbImage::new(width, height);
+    let mut y = 0;
+    for image in images {
+        stacked
+            .copy_from(image, 0, y)
+            .expect("the stacked image should fit every image");
+        y += image.height();
+    }
+
+    stacked
+}
+
+/// Render `colors` as a row of swatches and save it to `filename`.
+pub fn display_colors(filename: &str, colors: &[Srgb<u8>]) {
+    save(&swatches(colors, 64), filename);
+}
+
+/// Save `image` to `filename`, creating its directory if necessary.
+pub fn save(image: &RgbImage, filename: &str) {
+    if let Some(directory) = Path::new(filename).parent() {
+        let _ = std::fs::create_dir_all(directory);
+    }
+
+    match image.save(filename) {
+        Ok(()) => println!("see '{}' for the result", filename),
+        Err(e) => println!("failed to write '{}': {}", filename, e),
+    }
+}
+
+/// Compare `image` with the golden image at `filename`. Each component may
+/// differ by at most `tolerance`.
+///
+/// The golden image is written instead of compared if it doesn't exist, or
+/// if the `PALETTE_UPDATE_GOLDEN` environment variable is set. Review the new
+/// image before committing it.
+pub fn compare_golden(image: &RgbImage, filename: &str, tolerance: u8) -> Result<(), String> {
+    if std::env::var_os("PALETTE_UPDATE_GOLDEN").is_some() || !Path::new(filename).exists() {
+        if let Some(directory) = Path::new(filename).parent() {
+            let _ = std::fs::create_dir_all(directory);
+        }
+
+        return image
+            .save(filename)
+            .map_err(|e| format!("failed to write '{}': {}", filename, e));
+    }
+
+    let golden = image::open(filename)
+        .map_err(|e| format!("failed to read '{}': {}", filename, e))?
+        .to_rgb8();
+
+    if golden.dimensions() != image.dimensions() {
+        return Err(format!(
+            "'{}' is {:?} pixels, but the rendered image is {:?}",
+            filename,
+            golden.dimensions(),
+            image.dimensions()
+        ));
+    }
+
+    for ((x, y, expected), actual) in golden.enumerate_pixels().zip(image.pixels()) {
+        let differs = expected
+            .0
+            .iter()
+            .zip(&actual.0)
+            .any(|(&expected, &actual)| {
+                (i16::from(expected) - i16::from(actual)).abs() > i16::from(tolerance)
+            });
+
+        if differs {
+            return Err(format!(
+                "pixel ({}, {}) in '{}' is {:?}, but the rendered pixel is {:?}",
+                x, y, filename, expected.0, actual.0
+            ));
+        }
+    }
+
+    Ok(())
+}
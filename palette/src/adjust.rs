@@ -0,0 +1,350 @@
+//! Tone and color adjustments, similar to what photo editors offer.
+//!
+//! The adjustments in this module work on linear RGB, where they have a
+//! physical meaning. [`ColorMatrix`](struct.ColorMatrix.html) covers the ones
+//! that can be described as a 3x3 matrix, such as white balance and sepia
+//! toning, and can be combined into a single matrix before applying them.
+//! [`Curve`](struct.Curve.html) is a tone curve that can be applied per
+//! channel or to the lightness, and [`Levels`](struct.Levels.html) remaps
+//! the black point, white point and gamma. [`Cdl`](struct.Cdl.html) is an
+//! ASC CDL correction, that can be read from and written to CDL files, and
+//! [`LiftGammaGain`](struct.LiftGammaGain.html) is the lift, gamma and gain
+//! of color wheels in grading tools.
+//! [`SelectiveColor`](struct.SelectiveColor.html) is the exception, and
+//! adjusts the colors within a hue range in Oklch.
+//!
+//! ```
+//! use palette::adjust::ColorMatrix;
+//! use palette::LinSrgb;
+//!
+//! let warm_sepia = ColorMatrix::sepia(0.5).then(&ColorMatrix::temperature(0.3));
+//! let color = warm_sepia.apply(LinSrgb::new(0.2, 0.4, 0.6));
+//!
+//! assert!(color.red > color.blue);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::chromatic_adaptation::{adaptation_matrix, Method, TransformMatrix};
+use crate::chromaticity::{correlated_color_temperature, planckian_uv_1960, xyz_to_uv_1960};
+use crate::encoding::Linear;
+use crate::matrix::{matrix_inverse, multiply_3x3, rgb_to_xyz_matrix, Mat3};
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Xyz};
+
+pub use self::cdl::{cdl_corrections, Cdl, CdlCorrections, ParseCdlError};
+#[cfg(feature = "std")]
+pub use self::curve::{BakedCurve, Curve};
+pub use self::levels::Levels;
+pub use self::lift_gamma_gain::LiftGammaGain;
+pub use self::selective::SelectiveColor;
+
+mod cdl;
+#[cfg(feature = "std")]
+mod curve;
+mod levels;
+mod lift_gamma_gain;
+mod selective;
+
+/// The change in mired (micro reciprocal degrees) for a temperature shift of
+/// `1.0`.
+const MIRED_PER_TEMPERATURE: f64 = 100.0;
+
+/// The distance from the Planckian locus, in CIE 1960 UCS, for a tint shift of
+/// `1.0`.
+const DUV_PER_TINT: f64 = 0.02;
+
+/// A linear transformation of the colors in the RGB space `S`.
+///
+/// Each color is treated as a column vector of its linear red, green and blue
+/// components and multiplied with a 3x3 matrix. Matrices can be combined with
+/// [`then`](#method.then), which makes it cheap to apply a chain of
+/// adjustments to many colors.
+///
+/// The result may be outside the `0.0` to `1.0` range, even if the input
+/// isn't. It's up to the caller to clamp it, or to use something like
+/// [`SoftClip`](../rgb/struct.SoftClip.html).
+#[derive(Debug)]
+pub struct ColorMatrix<S, T = f32> {
+    matrix: Mat3<T>,
+    space: PhantomData<S>,
+}
+
+impl<S, T> ColorMatrix<S, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    /// Create a color matrix from a row major 3x3 matrix.
+    pub fn from_matrix(matrix: Mat3<T>) -> Self {
+        ColorMatrix {
+            matrix,
+            space: PhantomData,
+        }
+    }
+
+    /// A matrix that leaves all colors unchanged.
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::from_matrix([o, z, z, z, o, z, z, z, o])
+    }
+
+    /// Make the colors warmer for a positive `amount`, and cooler for a
+    /// negative `amount`.
+    ///
+    /// This is the same as `temperature_tint(amount, 0.0)`.
+    pub fn temperature(amount: T) -> Self {
+        Self::temperature_tint(amount, T::zero())
+    }
+
+    /// Make the colors more magenta for a positive `amount`, and more green
+    /// for a negative `amount`.
+    ///
+    /// This is the same as `temperature_tint(0.0, amount)`.
+    pub fn tint(amount: T) -> Self {
+        Self::temperature_tint(T::zero(), amount)
+    }
+
+    /// Shift the temperature and tint of the colors.
+    ///
+    /// The white balance is changed by adapting the white point of the space
+    /// to a new white point, using the Bradford method. A `temperature` of
+    /// `1.0` moves the white point 100 mired toward warmer light along the
+    /// Planckian locus, which turns a D65 white into roughly the color of 4000
+    /// K light. A `tint` of `1.0` moves it 0.02 Δuv away from the locus,
+    /// toward magenta. Negative values go in the opposite directions, toward
+    /// cooler light and green.
+    ///
+    /// The luminance of white is preserved, but strongly shifted colors may
+    /// end up with components above `1.0`.
+    pub fn temperature_tint(temperature: T, tint: T) -> Self {
+        let white: Xyz<S::WhitePoint, T> = S::WhitePoint::get_xyz();
+        let (white_u, white_v) = xyz_to_uv_1960(&white);
+
+        let sum = white.x + white.y + white.z;
+        let reference =
+            from_f64::<T>(1e6) / correlated_color_temperature(white.x / sum, white.y / sum);
+        let target = (reference + temperature * from_f64(MIRED_PER_TEMPERATURE))
+            .max(from_f64(1e6 / 15000.0))
+            .min(from_f64(1e6 / 1000.0));
+
+        let (reference_u, reference_v) = planckian_uv_1960(from_f64::<T>(1e6) / reference);
+        let (target_u, target_v) = planckian_uv_1960(from_f64::<T>(1e6) / target);
+        let (normal_u, normal_v) = planckian_normal(from_f64::<T>(1e6) / target);
+        let duv = -tint * from_f64(DUV_PER_TINT);
+
+        let u = white_u + target_u - reference_u + normal_u * duv;
+        let v = white_v + target_v - reference_v + normal_v * duv;
+        let new_white = uv_to_xyz::<S::WhitePoint, T>(u, v);
+
+        Self::adapt(&white, &new_white)
+    }
+
+    /// Correct the white balance of colors that were captured under the
+    /// light `illuminant`, so that it becomes the white point of the space.
+    ///
+    /// The illuminant can, for example, be measured from a neutral patch or
+    /// estimated with [`estimate_illuminant`](../white_balance/fn.estimate_illuminant.html).
+    /// Its luminance doesn't matter. The correction uses the Bradford method.
+    pub fn white_balance(illuminant: Xyz<S::WhitePoint, T>) -> Self {
+        let illuminant = illuminant / illuminant.y;
+        Self::adapt(&illuminant, &S::WhitePoint::get_xyz())
+    }
+
+    /// A matrix that adapts colors from the white `source` to the white
+    /// `destination`, using the Bradford method.
+    fn adapt(source: &Xyz<S::WhitePoint, T>, destination: &Xyz<S::WhitePoint, T>) -> Self {
+        let cone_response =
+            <Method as TransformMatrix<S::WhitePoint, S::WhitePoint, T>>::get_cone_response(
+                &Method::Bradford,
+            );
+        let adaptation = adaptation_matrix(&cone_response, source, destination);
+
+        let to_xyz = rgb_to_xyz_matrix::<S, T>();
+        let from_xyz = matrix_inverse(&to_xyz);
+        Self::from_matrix(multiply_3x3(&from_xyz, &multiply_3x3(&adaptation, &to_xyz)))
+    }
+
+    /// Tone the colors in sepia, where `amount` goes from `0.0` for no change
+    /// to `1.0` for full sepia.
+    ///
+    /// This uses the same matrix as the `sepia()` function in CSS filter
+    /// effects.
+    pub fn sepia(amount: T) -> Self {
+        #[rustfmt::skip]
+        let sepia = [
+            0.393, 0.769, 0.189,
+            0.349, 0.686, 0.168,
+            0.272, 0.534, 0.131,
+        ];
+
+        let mut matrix = Self::identity().matrix;
+        for (element, &sepia) in matrix.iter_mut().zip(&sepia) {
+            *element = *element + (from_f64::<T>(sepia) - *element) * amount;
+        }
+
+        Self::from_matrix(matrix)
+    }
+
+    /// Combine two matrices into one that applies `self` first and `next`
+    /// after it.
+    pub fn then(&self, next: &Self) -> Self {
+        Self::from_matrix(multiply_3x3(&next.matrix, &self.matrix))
+    }
+
+    /// The row major 3x3 matrix.
+    pub fn matrix(&self) -> Mat3<T> {
+        self.matrix
+    }
+
+    /// Transform a linear RGB color.
+    pub fn apply(&self, color: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T> {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = self.matrix;
+
+        Rgb::new(
+            m0 * color.red + m1 * color.green + m2 * color.blue,
+            m3 * color.red + m4 * color.green + m5 * color.blue,
+            m6 * color.red + m7 * color.green + m8 * color.blue,
+        )
+    }
+
+    /// Transform each color in a slice of linear RGB colors.
+    pub fn apply_slice(&self, colors: &mut [Rgb<Linear<S>, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl<S, T: Clone> Clone for ColorMatrix<S, T> {
+    fn clone(&self) -> Self {
+        ColorMatrix {
+            matrix: self.matrix.clone(),
+            space: PhantomData,
+        }
+    }
+}
+
+impl<S, T: Copy> Copy for ColorMatrix<S, T> {}
+
+impl<S, T> Default for ColorMatrix<S, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// A color with luminance `1.0` and the CIE 1960 UCS coordinates `u` and `v`.
+fn uv_to_xyz<Wp: WhitePoint, T: FloatComponent>(u: T, v: T) -> Xyz<Wp, T> {
+    let denominator = u * from_f64(2.0) - v * from_f64(8.0) + from_f64(4.0);
+    let x = u * from_f64(3.0) / denominator;
+    let y = v * from_f64(2.0) / denominator;
+
+    Xyz::with_wp(x / y, T::one(), (T::one() - x - y) / y)
+}
+
+/// The unit normal of the Planckian locus at the temperature `t`, pointing
+/// toward green.
+fn planckian_normal<T: FloatComponent>(t: T) -> (T, T) {
+    let step = from_f64::<T>(10.0);
+    let (u1, v1) = planckian_uv_1960(t - step);
+    let (u2, v2) = planckian_uv_1960(t + step);
+    let (du, dv) = (u2 - u1, v2 - v1);
+    let length = (du * du + dv * dv).sqrt();
+
+    (dv / length, -du / length)
+}
+
+#[cfg(test)]
+mod test {
+    use super::ColorMatrix;
+    use crate::convert::FromColorUnclamped;
+    use crate::{FromColor, Lab, LinSrgb, Xyz};
+
+    #[test]
+    fn neutral_adjustments() {
+        let color = LinSrgb::new(0.2, 0.5, 0.8);
+
+        assert_relative_eq!(
+            ColorMatrix::temperature(0.0).apply(color),
+            color,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(ColorMatrix::tint(0.0).apply(color), color, epsilon = 1e-6);
+        assert_relative_eq!(ColorMatrix::sepia(0.0).apply(color), color);
+        assert_relative_eq!(ColorMatrix::identity().apply(color), color);
+    }
+
+    #[test]
+    fn temperature() {
+        let white = LinSrgb::new(1.0f64, 1.0, 1.0);
+
+        let warm = ColorMatrix::temperature(0.5).apply(white);
+        assert!(warm.red > 1.0 && warm.blue < 1.0);
+        assert_relative_eq!(Xyz::from_color(warm).y, 1.0, epsilon = 1e-9);
+
+        let cool = ColorMatrix::temperature(-0.5).apply(white);
+        assert!(cool.red < 1.0 && cool.blue > 1.0);
+
+        let warmer = ColorMatrix::temperature(1.0).apply(white);
+        assert!(warmer.blue < warm.blue);
+    }
+
+    #[test]
+    fn tint() {
+        let white = LinSrgb::new(1.0f64, 1.0, 1.0);
+
+        let magenta = Lab::from_color(ColorMatrix::tint(1.0).apply(white));
+        assert!(magenta.a > 5.0);
+
+        let green = Lab::from_color(ColorMatrix::tint(-1.0).apply(white));
+        assert!(green.a < -5.0);
+    }
+
+    #[test]
+    fn white_balance() {
+        let white = LinSrgb::new(1.0f64, 1.0, 1.0);
+        let cast = LinSrgb::new(1.0, 0.8, 0.6);
+        let luminance = Xyz::from_color(cast).y;
+        let correction = ColorMatrix::white_balance(Xyz::from_color(cast) * 3.0);
+
+        assert_relative_eq!(correction.apply(cast), white * luminance, epsilon = 1e-6);
+
+        let warm = ColorMatrix::temperature(0.4);
+        let undo = ColorMatrix::white_balance(Xyz::from_color_unclamped(warm.apply(white)));
+        assert_relative_eq!(
+            warm.then(&undo).apply(LinSrgb::new(0.3, 0.6, 0.1)),
+            LinSrgb::new(0.3, 0.6, 0.1),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn sepia() {
+        let white = LinSrgb::new(1.0, 1.0, 1.0);
+        let sepia = ColorMatrix::sepia(1.0).apply(white);
+
+        assert_relative_eq!(sepia, LinSrgb::new(1.351, 1.203, 0.937), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn combined() {
+        let color = LinSrgb::new(0.2, 0.5, 0.8);
+        let first = ColorMatrix::sepia(0.7);
+        let second = ColorMatrix::temperature_tint(-0.4, 0.2);
+
+        assert_relative_eq!(
+            first.then(&second).apply(color),
+            second.apply(first.apply(color)),
+            epsilon = 1e-6
+        );
+
+        let mut colors = [color, LinSrgb::new(0.9, 0.1, 0.3)];
+        second.apply_slice(&mut colors);
+        assert_eq!(colors[0], second.apply(color));
+    }
+}
@@ -0,0 +1,473 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{from_f64, FloatComponent};
+
+/// An ASC Color Decision List (CDL) correction.
+///
+/// The ASC CDL is a format for exchanging primary grades between editing,
+/// grading and compositing tools. Each channel is adjusted with a slope,
+/// offset and power, as `(x * slope + offset) ^ power`, and the saturation
+/// is then adjusted around the Rec. 709 luma. The default is a correction
+/// that doesn't change anything.
+///
+/// Corrections can be parsed from `.cc`, `.cdl` and `.ccc` files, and are
+/// formatted as `<ColorCorrection>` elements:
+///
+/// ```
+/// use palette::adjust::Cdl;
+/// use palette::LinSrgb;
+///
+/// let cdl: Cdl = r#"
+///     <ColorCorrection id="shot_12">
+///         <SOPNode>
+///             <Slope>1.2 1.0 0.9</Slope>
+///             <Offset>0.01 0.0 -0.01</Offset>
+///             <Power>1.0 1.0 1.0</Power>
+///         </SOPNode>
+///         <SatNode>
+///             <Saturation>0.8</Saturation>
+///         </SatNode>
+///     </ColorCorrection>
+/// "#.parse().unwrap();
+///
+/// let color = cdl.apply(LinSrgb::new(0.5, 0.5, 0.5));
+/// assert!(color.red > color.green && color.green > color.blue);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cdl<T = f32> {
+    /// The slope of the red, green and blue channels.
+    pub slope: [T; 3],
+    /// The offset of the red, green and blue channels.
+    pub offset: [T; 3],
+    /// The power of the red, green and blue channels.
+    pub power: [T; 3],
+    /// The saturation, where `0.0` gives grays and `1.0` leaves the colors
+    /// unchanged.
+    pub saturation: T,
+}
+
+impl<T: FloatComponent> Cdl<T> {
+    /// Create a correction that doesn't change anything.
+    pub fn new() -> Self {
+        Cdl {
+            slope: [T::one(); 3],
+            offset: [T::zero(); 3],
+            power: [T::one(); 3],
+            saturation: T::one(),
+        }
+    }
+
+    /// Apply the correction to an RGB color, as it's stored.
+    ///
+    /// The values are clamped to the `0.0` to `1.0` range before the power
+    /// and after the saturation, as the ASC CDL specification says.
+    pub fn apply<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        let clamp = |x: T| x.max(T::zero()).min(T::one());
+        let [red, green, blue] = self.apply_channels(color, clamp);
+        Rgb::new(clamp(red), clamp(green), clamp(blue))
+    }
+
+    /// Apply the correction to an RGB color, without clamping it.
+    ///
+    /// This is useful for scene referred linear values, that may be above
+    /// `1.0`. Values below `0.0` are not affected by the power.
+    pub fn apply_unclamped<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        let [red, green, blue] = self.apply_channels(color, |x| x);
+        Rgb::new(red, green, blue)
+    }
+
+    /// Apply the correction to each color in a slice of RGB colors.
+    pub fn apply_slice<S: RgbStandard>(&self, colors: &mut [Rgb<S, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+
+    fn apply_channels<S: RgbStandard>(&self, color: Rgb<S, T>, clamp: impl Fn(T) -> T) -> [T; 3] {
+        let mut channels = [color.red, color.green, color.blue];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            let value = clamp(*channel * self.slope[i] + self.offset[i]);
+            if value > T::zero() {
+                *channel = powf(value, self.power[i]);
+            } else {
+                *channel = value;
+            }
+        }
+
+        let [red, green, blue] = channels;
+        let luma = from_f64::<T>(0.2126) * red
+            + from_f64::<T>(0.7152) * green
+            + from_f64::<T>(0.0722) * blue;
+
+        [
+            luma + self.saturation * (red - luma),
+            luma + self.saturation * (green - luma),
+            luma + self.saturation * (blue - luma),
+        ]
+    }
+}
+
+impl<T: FloatComponent> Default for Cdl<T> {
+    fn default() -> Self {
+        Cdl::new()
+    }
+}
+
+/// Parses the first `<ColorCorrection>` element. Use
+/// [`cdl_corrections`](fn.cdl_corrections.html) to get all of them, and
+/// their IDs.
+impl<T: FloatComponent + FromStr> FromStr for Cdl<T> {
+    type Err = ParseCdlError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match cdl_corrections(source).next() {
+            Some(result) => result.map(|(_, cdl)| cdl),
+            None => Err(ParseCdlError::MissingCorrection),
+        }
+    }
+}
+
+/// Formats the correction as a `<ColorCorrection>` element.
+impl<T: FloatComponent + fmt::Display> fmt::Display for Cdl<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [slope_r, slope_g, slope_b] = self.slope;
+        let [offset_r, offset_g, offset_b] = self.offset;
+        let [power_r, power_g, power_b] = self.power;
+
+        writeln!(f, "<ColorCorrection>")?;
+        writeln!(f, "  <SOPNode>")?;
+        writeln!(f, "    <Slope>{} {} {}</Slope>", slope_r, slope_g, slope_b)?;
+        writeln!(
+            f,
+            "    <Offset>{} {} {}</Offset>",
+            offset_r, offset_g, offset_b
+        )?;
+        writeln!(f, "    <Power>{} {} {}</Power>", power_r, power_g, power_b)?;
+        writeln!(f, "  </SOPNode>")?;
+        writeln!(f, "  <SatNode>")?;
+        writeln!(f, "    <Saturation>{}</Saturation>", self.saturation)?;
+        writeln!(f, "  </SatNode>")?;
+        write!(f, "</ColorCorrection>")
+    }
+}
+
+/// Parse each `<ColorCorrection>` element in a `.cc`, `.cdl` or `.ccc` file,
+/// together with its `id` attribute.
+///
+/// This is a small parser for the CDL elements, and not a complete XML
+/// parser. Missing `<SOPNode>` and `<SatNode>` values are left unchanged.
+///
+/// ```
+/// use palette::adjust::{cdl_corrections, Cdl};
+///
+/// let source = r#"
+///     <ColorCorrectionCollection xmlns="urn:ASC:CDL:v1.2">
+///         <ColorCorrection id="a"><SatNode><Saturation>0.5</Saturation></SatNode></ColorCorrection>
+///         <ColorCorrection id="b"><SOPNode><Slope>2 2 2</Slope></SOPNode></ColorCorrection>
+///     </ColorCorrectionCollection>
+/// "#;
+///
+/// let corrections: Vec<(Option<&str>, Cdl<f64>)> = cdl_corrections(source)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(corrections[0].0, Some("a"));
+/// assert_eq!(corrections[0].1.saturation, 0.5);
+/// assert_eq!(corrections[1].1.slope, [2.0; 3]);
+/// ```
+pub fn cdl_corrections<T: FloatComponent + FromStr>(source: &str) -> CdlCorrections<'_, T> {
+    CdlCorrections {
+        source,
+        value: core::marker::PhantomData,
+    }
+}
+
+/// An iterator over the corrections in a CDL file. See
+/// [`cdl_corrections`](fn.cdl_corrections.html).
+#[derive(Clone, Debug)]
+pub struct CdlCorrections<'a, T> {
+    source: &'a str,
+    value: core::marker::PhantomData<T>,
+}
+
+impl<'a, T: FloatComponent + FromStr> Iterator for CdlCorrections<'a, T> {
+    type Item = Result<(Option<&'a str>, Cdl<T>), ParseCdlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (attributes, content, rest) = match find_element(self.source, "ColorCorrection") {
+            Ok(Some(element)) => element,
+            Ok(None) => return None,
+            Err(error) => {
+                self.source = "";
+                return Some(Err(error));
+            }
+        };
+        self.source = rest;
+
+        Some(parse_correction(content).map(|cdl| (find_id(attributes), cdl)))
+    }
+}
+
+fn parse_correction<T: FloatComponent + FromStr>(content: &str) -> Result<Cdl<T>, ParseCdlError> {
+    let mut cdl = Cdl::new();
+
+    if let Some((_, sop, _)) = find_element(content, "SOPNode")? {
+        let mut channels = [
+            ("Slope", &mut cdl.slope),
+            ("Offset", &mut cdl.offset),
+            ("Power", &mut cdl.power),
+        ];
+        for (name, values) in channels.iter_mut() {
+            if let Some((_, text, _)) = find_element(sop, name)? {
+                parse_values(text, &mut values[..], name)?;
+            }
+        }
+    }
+
+    if let Some((_, sat, _)) = find_element(content, "SatNode")? {
+        if let Some((_, text, _)) = find_element(sat, "Saturation")? {
+            parse_values(
+                text,
+                core::slice::from_mut(&mut cdl.saturation),
+                "Saturation",
+            )?;
+        }
+    }
+
+    Ok(cdl)
+}
+
+fn parse_values<T: FromStr>(
+    text: &str,
+    values: &mut [T],
+    element: &'static str,
+) -> Result<(), ParseCdlError> {
+    let mut numbers = text.split_whitespace();
+    for value in values {
+        *value = numbers
+            .next()
+            .and_then(|number| number.parse().ok())
+            .ok_or(ParseCdlError::InvalidValues { element })?;
+    }
+
+    if numbers.next().is_some() {
+        return Err(ParseCdlError::InvalidValues { element });
+    }
+
+    Ok(())
+}
+
+/// Find the first element called `name`, and return its attributes, its
+/// content and the text after it.
+fn find_element<'a>(
+    source: &'a str,
+    name: &'static str,
+) -> Result<Option<(&'a str, &'a str, &'a str)>, ParseCdlError> {
+    let mut search = source;
+    let start = loop {
+        let index = match search.find('<') {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        search = &search[index + 1..];
+
+        // Skip elements that only start with the same name, such as
+        // `ColorCorrectionCollection`.
+        if let Some(after_name) = search.strip_prefix(name) {
+            if after_name.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+                break after_name;
+            }
+        }
+    };
+
+    let end_of_tag = start
+        .find('>')
+        .ok_or(ParseCdlError::UnclosedElement { element: name })?;
+    let attributes = &start[..end_of_tag];
+    if let Some(attributes) = attributes.strip_suffix('/') {
+        return Ok(Some((attributes, "", &start[end_of_tag + 1..])));
+    }
+
+    let content = &start[end_of_tag + 1..];
+    let closing =
+        find_closing_tag(content, name).ok_or(ParseCdlError::UnclosedElement { element: name })?;
+
+    Ok(Some((
+        attributes,
+        &content[..closing.0],
+        &content[closing.1..],
+    )))
+}
+
+/// Find the start and end of `</name>` in `content`.
+fn find_closing_tag(content: &str, name: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    while let Some(index) = content[offset..].find("</") {
+        let start = offset + index;
+        let after = content[start + 2..]
+            .strip_prefix(name)
+            .and_then(|rest| rest.trim_start().strip_prefix('>'));
+        if let Some(after) = after {
+            return Some((start, content.len() - after.len()));
+        }
+
+        offset = start + 2;
+    }
+
+    None
+}
+
+fn find_id(attributes: &str) -> Option<&str> {
+    let mut rest = attributes;
+    while let Some(index) = rest.find("id") {
+        let before = &rest[..index];
+        rest = &rest[index + 2..];
+        if !before.is_empty() && !before.ends_with(char::is_whitespace) {
+            continue;
+        }
+
+        let value = rest.trim_start().strip_prefix('=')?.trim_start();
+        let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')?;
+        let value = &value[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+
+    None
+}
+
+/// The error type for parsing ASC CDL corrections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseCdlError {
+    /// There is no `<ColorCorrection>` element.
+    MissingCorrection,
+    /// An element is not closed.
+    UnclosedElement {
+        /// The name of the element.
+        element: &'static str,
+    },
+    /// An element doesn't have the right number of values, or they are not
+    /// numbers.
+    InvalidValues {
+        /// The name of the element.
+        element: &'static str,
+    },
+}
+
+impl fmt::Display for ParseCdlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCdlError::MissingCorrection => write!(f, "missing ColorCorrection element"),
+            ParseCdlError::UnclosedElement { element } => {
+                write!(f, "the {} element is not closed", element)
+            }
+            ParseCdlError::InvalidValues { element } => {
+                write!(f, "invalid values in the {} element", element)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCdlError {}
+
+#[cfg(test)]
+mod test {
+    use super::{cdl_corrections, Cdl, ParseCdlError};
+    use crate::LinSrgb;
+
+    #[test]
+    fn identity() {
+        let color = LinSrgb::new(0.1, 0.5, 0.9);
+
+        assert_relative_eq!(Cdl::new().apply(color), color, epsilon = 1e-6);
+        assert_relative_eq!(
+            Cdl::new().apply_unclamped(LinSrgb::new(-0.5, 0.5, 2.0)),
+            LinSrgb::new(-0.5, 0.5, 2.0),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn slope_offset_power_saturation() {
+        let cdl = Cdl::<f64> {
+            slope: [2.0, 1.0, 0.5],
+            offset: [0.0, 0.1, 0.0],
+            power: [1.0, 1.0, 2.0],
+            saturation: 1.0,
+        };
+        let color = cdl.apply(LinSrgb::new(0.25, 0.25, 0.5));
+        assert_relative_eq!(color, LinSrgb::new(0.5, 0.35, 0.0625));
+
+        let clamped = cdl.apply(LinSrgb::new(0.75, 0.95, 0.5));
+        assert_relative_eq!(clamped.red, 1.0);
+        assert_relative_eq!(clamped.green, 1.0);
+
+        let gray = Cdl::<f64> {
+            saturation: 0.0,
+            ..Cdl::new()
+        }
+        .apply(LinSrgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(gray, LinSrgb::new(0.2126, 0.2126, 0.2126));
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let cdl = Cdl::<f64> {
+            slope: [1.5, 1.0, 0.75],
+            offset: [0.125, 0.0, -0.25],
+            power: [1.0, 2.0, 0.5],
+            saturation: 0.5,
+        };
+
+        let parsed: Cdl<f64> = cdl.to_string().parse().unwrap();
+        assert_eq!(parsed, cdl);
+
+        let source = r#"<?xml version="1.0"?>
+            <ColorDecisionList>
+                <ColorDecision>
+                    <ColorCorrection id='first'/>
+                </ColorDecision>
+                <ColorDecision>
+                    <ColorCorrection data-id="x" id = "second">
+                        <SOPNode><Power>2 2 2</Power></SOPNode>
+                    </ColorCorrection>
+                </ColorDecision>
+            </ColorDecisionList>"#;
+        let corrections: Vec<_> = cdl_corrections::<f64>(source)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(corrections.len(), 2);
+        assert_eq!(corrections[0], (Some("first"), Cdl::new()));
+        assert_eq!(corrections[1].0, Some("second"));
+        assert_eq!(corrections[1].1.power, [2.0; 3]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |source: &str| source.parse::<Cdl<f64>>().unwrap_err();
+
+        assert_eq!(
+            parse("<ColorDecisionList/>"),
+            ParseCdlError::MissingCorrection
+        );
+        assert_eq!(
+            parse("<ColorCorrection><SatNode></ColorCorrection>"),
+            ParseCdlError::UnclosedElement { element: "SatNode" }
+        );
+        assert_eq!(
+            parse("<ColorCorrection><SOPNode><Slope>1 1</Slope></SOPNode></ColorCorrection>"),
+            ParseCdlError::InvalidValues { element: "Slope" }
+        );
+        assert_eq!(
+            parse(
+                "<ColorCorrection><SatNode><Saturation>a</Saturation></SatNode></ColorCorrection>"
+            ),
+            ParseCdlError::InvalidValues {
+                element: "Saturation"
+            }
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use crate::rgb::{Rgb, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Lab};
+
+/// A tone curve, as found in the curves tool of photo editors.
+///
+/// The curve is a monotone cubic spline through a set of control points,
+/// which means that it doesn't overshoot between them. It's flat outside the
+/// first and last point. Both the input and the output are normally in the
+/// `0.0` to `1.0` range.
+///
+/// A curve can be applied to each channel of an RGB color, as it's stored, or
+/// to the L\* component of L\*a\*b\*, which changes the lightness without
+/// touching the hue and chroma. Evaluating the spline is relatively
+/// expensive, so [`bake`](#method.bake) and [`bake_u8`](#method.bake_u8) can
+/// turn it into a lookup table when it's applied to many colors.
+///
+/// This is only available if the `std` feature is enabled (this is the
+/// default).
+///
+/// ```
+/// use palette::adjust::Curve;
+/// use palette::Srgb;
+///
+/// // A classic S-curve that increases the contrast.
+/// let curve = Curve::new(&[(0.0, 0.0), (0.25, 0.2), (0.75, 0.8), (1.0, 1.0)]);
+/// let color = curve.apply(Srgb::new(0.2, 0.5, 0.9));
+///
+/// assert!(color.red < 0.2);
+/// assert!(color.blue > 0.9);
+///
+/// let table = curve.bake_u8();
+/// assert_eq!(table[0], 0);
+/// assert_eq!(table[255], 255);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Curve<T = f32> {
+    points: Vec<(T, T)>,
+    tangents: Vec<T>,
+}
+
+impl<T: FloatComponent> Curve<T> {
+    /// Create a curve through the `(input, output)` control points. They don't
+    /// need to be sorted.
+    ///
+    /// Panics if there are fewer than two points, or if two points have the
+    /// same input.
+    pub fn new(points: &[(T, T)]) -> Self {
+        assert!(points.len() >= 2, "a curve needs at least two points");
+
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| {
+            a.0.partial_cmp(&b.0)
+                .expect("curve points can't have NaN inputs")
+        });
+        assert!(
+            points.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "curve points must have distinct inputs"
+        );
+
+        let tangents = monotone_tangents(&points);
+        Curve { points, tangents }
+    }
+
+    /// A curve that leaves all values in the `0.0` to `1.0` range unchanged.
+    pub fn identity() -> Self {
+        Curve::new(&[(T::zero(), T::zero()), (T::one(), T::one())])
+    }
+
+    /// The control points, sorted by input.
+    pub fn points(&self) -> &[(T, T)] {
+        &self.points
+    }
+
+    /// Apply the curve to a single value.
+    pub fn apply_component(&self, x: T) -> T {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if x <= first.0 {
+            return first.1;
+        }
+        if x >= last.0 {
+            return last.1;
+        }
+
+        let index = self.points.iter().rposition(|p| p.0 <= x).unwrap_or(0);
+        let (x0, y0) = self.points[index];
+        let (x1, y1) = self.points[index + 1];
+        let (m0, m1) = (self.tangents[index], self.tangents[index + 1]);
+
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two: T = from_f64(2.0);
+        let three: T = from_f64(3.0);
+
+        (two * t3 - three * t2 + T::one()) * y0
+            + (t3 - two * t2 + t) * h * m0
+            + (three * t2 - two * t3) * y1
+            + (t3 - t2) * h * m1
+    }
+
+    /// Apply the curve to each channel of an RGB color, as it's stored.
+    pub fn apply<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        Rgb::new(
+            self.apply_component(color.red),
+            self.apply_component(color.green),
+            self.apply_component(color.blue),
+        )
+    }
+
+    /// Apply the curve to the lightness of a color, where L\* from `0.0` to
+    /// `100.0` is mapped to the `0.0` to `1.0` range of the curve.
+    pub fn apply_lightness<Wp: WhitePoint>(&self, color: Lab<Wp, T>) -> Lab<Wp, T> {
+        let hundred: T = from_f64(100.0);
+
+        Lab {
+            l: self.apply_component(color.l / hundred) * hundred,
+            ..color
+        }
+    }
+
+    /// Sample the curve into a lookup table with `size` evenly spaced entries
+    /// over the `0.0` to `1.0` range.
+    ///
+    /// Panics if `size` is less than 2.
+    pub fn bake(&self, size: usize) -> BakedCurve<T> {
+        assert!(size >= 2, "a baked curve needs at least two entries");
+
+        let last = from_f64::<T>((size - 1) as f64);
+        let table = (0..size)
+            .map(|i| self.apply_component(from_f64::<T>(i as f64) / last))
+            .collect();
+
+        BakedCurve { table }
+    }
+
+    /// Sample the curve into a table for 8 bit components, to be indexed by
+    /// the input value.
+    pub fn bake_u8(&self) -> [u8; 256] {
+        let mut table = [0; 256];
+
+        for (i, entry) in table.iter_mut().enumerate() {
+            let output = self.apply_component(from_f64::<T>(i as f64 / 255.0));
+            *entry = (output.max(T::zero()).min(T::one()) * from_f64(255.0))
+                .round()
+                .to_u8()
+                .unwrap_or(0);
+        }
+
+        table
+    }
+}
+
+impl<T: FloatComponent> Default for Curve<T> {
+    fn default() -> Self {
+        Curve::identity()
+    }
+}
+
+/// A [`Curve`](struct.Curve.html) that has been sampled into a lookup table.
+///
+/// Values between the samples are linearly interpolated, and values outside
+/// the `0.0` to `1.0` range get the value at the nearest end.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BakedCurve<T = f32> {
+    table: Vec<T>,
+}
+
+impl<T: FloatComponent> BakedCurve<T> {
+    /// The sampled values.
+    pub fn table(&self) -> &[T] {
+        &self.table
+    }
+
+    /// Apply the baked curve to a single value.
+    pub fn apply_component(&self, x: T) -> T {
+        let last = self.table.len() - 1;
+        let position = x.max(T::zero()).min(T::one()) * from_f64(last as f64);
+        let index = position.floor().to_usize().unwrap_or(0).min(last - 1);
+        let t = position - from_f64(index as f64);
+
+        self.table[index] + (self.table[index + 1] - self.table[index]) * t
+    }
+
+    /// Apply the baked curve to each channel of an RGB color, as it's stored.
+    pub fn apply<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        Rgb::new(
+            self.apply_component(color.red),
+            self.apply_component(color.green),
+            self.apply_component(color.blue),
+        )
+    }
+
+    /// Apply the baked curve to the lightness of a color. See
+    /// [`Curve::apply_lightness`](struct.Curve.html#method.apply_lightness).
+    pub fn apply_lightness<Wp: WhitePoint>(&self, color: Lab<Wp, T>) -> Lab<Wp, T> {
+        let hundred: T = from_f64(100.0);
+
+        Lab {
+            l: self.apply_component(color.l / hundred) * hundred,
+            ..color
+        }
+    }
+
+    /// Apply the baked curve to each color in a slice of RGB colors.
+    pub fn apply_slice<S: RgbStandard>(&self, colors: &mut [Rgb<S, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+/// Calculate the tangents of a monotone cubic spline, using the method by
+/// Fritsch and Carlson.
+fn monotone_tangents<T: FloatComponent>(points: &[(T, T)]) -> Vec<T> {
+    let slopes: Vec<T> = points
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+        .collect();
+
+    let mut tangents = Vec::with_capacity(points.len());
+    tangents.push(slopes[0]);
+    for pair in slopes.windows(2) {
+        if pair[0] * pair[1] <= T::zero() {
+            tangents.push(T::zero());
+        } else {
+            tangents.push((pair[0] + pair[1]) / from_f64(2.0));
+        }
+    }
+    tangents.push(slopes[slopes.len() - 1]);
+
+    for (k, &slope) in slopes.iter().enumerate() {
+        if slope == T::zero() {
+            tangents[k] = T::zero();
+            tangents[k + 1] = T::zero();
+            continue;
+        }
+
+        let a = tangents[k] / slope;
+        let b = tangents[k + 1] / slope;
+        let length_squared = a * a + b * b;
+        if length_squared > from_f64(9.0) {
+            let scale = from_f64::<T>(3.0) / length_squared.sqrt();
+            tangents[k] = scale * a * slope;
+            tangents[k + 1] = scale * b * slope;
+        }
+    }
+
+    tangents
+}
+
+#[cfg(test)]
+mod test {
+    use super::Curve;
+    use crate::{Lab, Srgb};
+
+    #[test]
+    fn passes_through_points() {
+        let points = [(0.0, 0.1), (0.3, 0.2), (0.6, 0.9), (1.0, 0.95)];
+        let curve = Curve::<f64>::new(&points);
+
+        for &(x, y) in &points {
+            assert_relative_eq!(curve.apply_component(x), y);
+        }
+        assert_relative_eq!(curve.apply_component(-1.0), 0.1);
+        assert_relative_eq!(curve.apply_component(2.0), 0.95);
+    }
+
+    #[test]
+    fn monotone() {
+        let curve = Curve::<f64>::new(&[(0.0, 0.0), (0.1, 0.5), (0.2, 0.52), (1.0, 1.0)]);
+        let mut previous = 0.0;
+
+        for i in 0..=1000 {
+            let y = curve.apply_component(i as f64 / 1000.0);
+            assert!(y >= previous, "{} < {}", y, previous);
+            previous = y;
+        }
+
+        let flat = Curve::<f64>::new(&[(0.0, 0.0), (0.4, 0.5), (0.6, 0.5), (1.0, 1.0)]);
+        assert_relative_eq!(flat.apply_component(0.5), 0.5);
+    }
+
+    #[test]
+    fn identity() {
+        let curve = Curve::identity();
+        let color = Srgb::new(0.1, 0.5, 0.8);
+
+        assert_relative_eq!(curve.apply(color), color);
+        assert_eq!(curve.bake_u8()[77], 77);
+    }
+
+    #[test]
+    fn lightness() {
+        let curve = Curve::new(&[(0.0, 0.0), (0.5, 0.7), (1.0, 1.0)]);
+        let color = curve.apply_lightness(Lab::new(50.0, 20.0, -30.0));
+
+        assert_relative_eq!(color, Lab::new(70.0, 20.0, -30.0), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn baked() {
+        let curve = Curve::<f64>::new(&[(1.0, 1.0), (0.0, 0.0), (0.25, 0.15), (0.75, 0.85)]);
+        let baked = curve.bake(1024);
+
+        assert_eq!(baked.table().len(), 1024);
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            assert_relative_eq!(
+                baked.apply_component(x),
+                curve.apply_component(x),
+                epsilon = 1e-4
+            );
+        }
+
+        let mut colors = [Srgb::new(0.2, 0.4, 0.6)];
+        baked.apply_slice(&mut colors);
+        assert_relative_eq!(
+            colors[0],
+            curve.apply(Srgb::new(0.2, 0.4, 0.6)),
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_inputs() {
+        Curve::new(&[(0.5, 0.0), (0.5, 1.0)]);
+    }
+}
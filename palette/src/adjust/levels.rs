@@ -0,0 +1,209 @@
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Linear;
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{FloatComponent, Xyz};
+
+/// A levels adjustment, as found in photo editors.
+///
+/// Values are remapped in three steps:
+///
+/// 1. The input black and white points are stretched to `0.0` and `1.0`, and
+///    anything outside them is clipped.
+/// 2. The gamma is applied as `x^(1 / gamma)`, so a gamma above `1.0`
+///    brightens the mid tones and a gamma below `1.0` darkens them.
+/// 3. The result is compressed into the range between the output black and
+///    white points.
+///
+/// The default is an adjustment that leaves values in the `0.0` to `1.0` range
+/// unchanged.
+///
+/// ```
+/// use palette::adjust::Levels;
+/// use palette::Srgb;
+///
+/// let levels = Levels::new().input(0.1, 0.9).gamma(1.2);
+/// let color = levels.apply(Srgb::new(0.05, 0.5, 0.95));
+///
+/// assert_eq!(color.red, 0.0);
+/// assert!(color.green > 0.5);
+/// assert_eq!(color.blue, 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Levels<T = f32> {
+    input_black: T,
+    input_white: T,
+    gamma: T,
+    output_black: T,
+    output_white: T,
+}
+
+impl<T: FloatComponent> Levels<T> {
+    /// Create a levels adjustment that doesn't change anything.
+    pub fn new() -> Self {
+        Levels {
+            input_black: T::zero(),
+            input_white: T::one(),
+            gamma: T::one(),
+            output_black: T::zero(),
+            output_white: T::one(),
+        }
+    }
+
+    /// Set the input black and white points.
+    ///
+    /// Panics unless `black < white`.
+    pub fn input(mut self, black: T, white: T) -> Self {
+        assert!(black < white);
+
+        self.input_black = black;
+        self.input_white = white;
+        self
+    }
+
+    /// Set the gamma for the mid tones.
+    ///
+    /// Panics unless `gamma > 0.0`.
+    pub fn gamma(mut self, gamma: T) -> Self {
+        assert!(gamma > T::zero());
+
+        self.gamma = gamma;
+        self
+    }
+
+    /// Set the output black and white points. A black point above the white
+    /// point inverts the values.
+    pub fn output(mut self, black: T, white: T) -> Self {
+        self.output_black = black;
+        self.output_white = white;
+        self
+    }
+
+    /// Apply the adjustment to a single value.
+    pub fn apply_component(&self, x: T) -> T {
+        let normalized = ((x - self.input_black) / (self.input_white - self.input_black))
+            .max(T::zero())
+            .min(T::one());
+        let adjusted = powf(normalized, T::one() / self.gamma);
+
+        self.output_black + (self.output_white - self.output_black) * adjusted
+    }
+
+    /// Apply the adjustment to each channel of an RGB color, as it's stored.
+    ///
+    /// This is how levels usually work in photo editors, where the channels
+    /// are gamma encoded. Unequal channels may change hue and saturation.
+    pub fn apply<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        Rgb::new(
+            self.apply_component(color.red),
+            self.apply_component(color.green),
+            self.apply_component(color.blue),
+        )
+    }
+
+    /// Apply the adjustment to the luminance of an RGB color, while keeping
+    /// its chromaticity.
+    ///
+    /// The relative luminance is encoded with the transfer function of `S`,
+    /// so that the adjustment has the same effect on grays as
+    /// [`apply`](#method.apply). The linear channels are then scaled by the
+    /// change in luminance.
+    pub fn apply_luminance<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        let linear = color.into_linear();
+        let luminance = Xyz::from_color_unclamped(linear).y;
+
+        let encoded = self.apply_component(encode_gray::<S, T>(luminance));
+        let new_luminance = Rgb::<S, T>::new(encoded, encoded, encoded)
+            .into_linear()
+            .red;
+
+        if luminance > T::zero() {
+            Rgb::from_linear(linear * (new_luminance / luminance))
+        } else {
+            Rgb::from_linear(Rgb::<Linear<S::Space>, T>::new(
+                new_luminance,
+                new_luminance,
+                new_luminance,
+            ))
+        }
+    }
+
+    /// Apply the adjustment to each channel of each color in a slice of RGB
+    /// colors.
+    pub fn apply_slice<S: RgbStandard>(&self, colors: &mut [Rgb<S, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl<T: FloatComponent> Default for Levels<T> {
+    fn default() -> Self {
+        Levels::new()
+    }
+}
+
+/// Encode a linear gray with the luminance `y`, and return its component
+/// value.
+fn encode_gray<S: RgbStandard, T: FloatComponent>(y: T) -> T {
+    Rgb::<S, T>::from_linear(Rgb::<Linear<S::Space>, T>::new(y, y, y)).red
+}
+
+#[cfg(test)]
+mod test {
+    use super::Levels;
+    use crate::{FromColor, LinSrgb, Srgb, Xyz, Yxy};
+
+    #[test]
+    fn identity() {
+        let color = Srgb::new(0.1, 0.5, 0.9);
+
+        assert_relative_eq!(Levels::new().apply(color), color);
+        assert_relative_eq!(Levels::new().apply_luminance(color), color, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn points_and_gamma() {
+        let levels = Levels::<f64>::new().input(0.2, 0.6).output(0.1, 0.5);
+
+        assert_relative_eq!(levels.apply_component(0.0), 0.1);
+        assert_relative_eq!(levels.apply_component(0.4), 0.3);
+        assert_relative_eq!(levels.apply_component(1.0), 0.5);
+
+        let levels = Levels::<f64>::new().gamma(2.0);
+        assert_relative_eq!(levels.apply_component(0.25), 0.5);
+
+        let inverted = Levels::<f64>::new().output(1.0, 0.0);
+        assert_relative_eq!(inverted.apply_component(0.25), 0.75);
+    }
+
+    #[test]
+    fn luminance_keeps_chromaticity() {
+        let levels = Levels::new().input(0.0, 0.8).gamma(0.8);
+        let color = Srgb::new(0.2, 0.4, 0.3);
+        let adjusted = levels.apply_luminance(color);
+
+        let before = Yxy::from_color(color);
+        let after = Yxy::from_color(adjusted);
+        assert_relative_eq!(before.x, after.x, epsilon = 1e-5);
+        assert_relative_eq!(before.y, after.y, epsilon = 1e-5);
+
+        let gray = Srgb::new(0.4, 0.4, 0.4);
+        assert_relative_eq!(
+            levels.apply_luminance(gray),
+            levels.apply(gray),
+            epsilon = 1e-5
+        );
+
+        let black = levels
+            .output(0.1, 1.0)
+            .apply_luminance(LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(Xyz::from_color(black).y, 0.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_input_points() {
+        Levels::new().input(0.5, 0.5);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::FloatComponent;
+
+/// A lift, gamma and gain adjustment, like the color wheels of grading
+/// tools.
+///
+/// Each channel is adjusted in three steps:
+///
+/// 1. The lift raises the shadows, while keeping `1.0` in place, as
+///    `x + lift * (1 - x)`.
+/// 2. The gain scales the result, which mostly affects the highlights.
+/// 3. The gamma is applied as `x^(1 / gamma)`, so a gamma above `1.0`
+///    brightens the mid tones.
+///
+/// Different values per channel tint the shadows, mid tones and highlights
+/// in different directions, which gives a split toning effect. The default
+/// is an adjustment that doesn't change anything.
+///
+/// ```
+/// use palette::adjust::LiftGammaGain;
+/// use palette::LinSrgb;
+///
+/// // Blue shadows and warm highlights.
+/// let grade = LiftGammaGain {
+///     lift: [0.0, 0.0, 0.05],
+///     gain: [1.1, 1.0, 0.9],
+///     ..LiftGammaGain::new()
+/// };
+///
+/// let shadow = grade.apply(LinSrgb::new(0.02, 0.02, 0.02));
+/// let highlight = grade.apply(LinSrgb::new(0.8, 0.8, 0.8));
+///
+/// assert!(shadow.blue > shadow.red);
+/// assert!(highlight.red > highlight.blue);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LiftGammaGain<T = f32> {
+    /// The lift of the red, green and blue channels.
+    pub lift: [T; 3],
+    /// The gamma of the red, green and blue channels.
+    pub gamma: [T; 3],
+    /// The gain of the red, green and blue channels.
+    pub gain: [T; 3],
+}
+
+impl<T: FloatComponent> LiftGammaGain<T> {
+    /// Create an adjustment that doesn't change anything.
+    pub fn new() -> Self {
+        LiftGammaGain {
+            lift: [T::zero(); 3],
+            gamma: [T::one(); 3],
+            gain: [T::one(); 3],
+        }
+    }
+
+    /// Apply the adjustment to a single value of the channel with the index
+    /// `channel`, where `0` is red, `1` is green and `2` is blue.
+    ///
+    /// Values at or below `0.0`, after the lift and gain, are not affected by
+    /// the gamma.
+    pub fn apply_component(&self, channel: usize, x: T) -> T {
+        let lifted = x + self.lift[channel] * (T::one() - x);
+        let value = lifted * self.gain[channel];
+
+        if value > T::zero() {
+            powf(value, T::one() / self.gamma[channel])
+        } else {
+            value
+        }
+    }
+
+    /// Apply the adjustment to an RGB color, as it's stored.
+    pub fn apply<S: RgbStandard>(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        Rgb::new(
+            self.apply_component(0, color.red),
+            self.apply_component(1, color.green),
+            self.apply_component(2, color.blue),
+        )
+    }
+
+    /// Apply the adjustment to each color in a slice of RGB colors.
+    pub fn apply_slice<S: RgbStandard>(&self, colors: &mut [Rgb<S, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl<T: FloatComponent> Default for LiftGammaGain<T> {
+    fn default() -> Self {
+        LiftGammaGain::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LiftGammaGain;
+    use crate::LinSrgb;
+
+    #[test]
+    fn identity() {
+        let color = LinSrgb::new(0.1, 0.5, 2.0);
+
+        assert_relative_eq!(LiftGammaGain::new().apply(color), color);
+    }
+
+    #[test]
+    fn lift_gamma_gain() {
+        let grade = LiftGammaGain::<f64> {
+            lift: [0.2, 0.0, 0.0],
+            gamma: [1.0, 2.0, 1.0],
+            gain: [1.0, 1.0, 0.5],
+        };
+
+        assert_relative_eq!(grade.apply_component(0, 0.0), 0.2);
+        assert_relative_eq!(grade.apply_component(0, 1.0), 1.0);
+        assert_relative_eq!(grade.apply_component(1, 0.25), 0.5);
+        assert_relative_eq!(grade.apply_component(2, 0.8), 0.4);
+        assert_relative_eq!(grade.apply_component(1, -0.1), -0.1);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::convert::{FromColor, IntoColor};
+use crate::{from_f64, FloatComponent, OklabHue, Oklch};
+
+/// The chroma, in Oklch, below which colors are gradually left out of the
+/// adjustment. Their hue is too unstable to select them by.
+const NEUTRAL_CHROMA: f64 = 0.03;
+
+/// An adjustment of the colors within a hue range, like the HSL panel of
+/// photo editors.
+///
+/// The colors are selected by their hue in [`Oklch`](../struct.Oklch.html),
+/// where the hues are perceptually even, and the adjustment is done there
+/// too. Colors within `range` degrees around the center hue get the full
+/// adjustment, which then fades out smoothly over the `feather` width on
+/// both sides, to avoid hard edges in gradients. Colors close to gray are
+/// faded out as well.
+///
+/// ```
+/// use palette::adjust::SelectiveColor;
+/// use palette::{FromColor, Oklch, Srgb};
+///
+/// // Make the blues deeper and darker, while leaving everything else alone.
+/// let blues = SelectiveColor::new(265.0, 40.0)
+///     .saturation(0.3)
+///     .lightness(-0.2);
+///
+/// let mut colors = [Srgb::new(0.2, 0.4, 0.8), Srgb::new(0.8, 0.4, 0.2)];
+/// blues.apply_slice(&mut colors);
+///
+/// assert!(Oklch::from_color(colors[0]).l < Oklch::from_color(Srgb::new(0.2, 0.4, 0.8)).l);
+/// assert_eq!(colors[1], Srgb::new(0.8, 0.4, 0.2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectiveColor<T = f32>
+where
+    T: FloatComponent,
+{
+    hue: OklabHue<T>,
+    range: T,
+    feather: T,
+    hue_shift: T,
+    saturation: T,
+    lightness: T,
+}
+
+impl<T: FloatComponent> SelectiveColor<T> {
+    /// Select the hues within `range` degrees around `hue`, with a feather
+    /// width of 30°. The selection doesn't adjust anything until the hue
+    /// shift, saturation or lightness is set.
+    ///
+    /// Panics if `range` is negative.
+    pub fn new<H: Into<OklabHue<T>>>(hue: H, range: T) -> Self {
+        assert!(range >= T::zero());
+
+        SelectiveColor {
+            hue: hue.into(),
+            range,
+            feather: from_f64(30.0),
+            hue_shift: T::zero(),
+            saturation: T::zero(),
+            lightness: T::zero(),
+        }
+    }
+
+    /// Set the width, in degrees, of the fade out on each side of the range.
+    /// A width of `0.0` gives a hard edge.
+    ///
+    /// Panics if `feather` is negative.
+    pub fn feather(mut self, feather: T) -> Self {
+        assert!(feather >= T::zero());
+
+        self.feather = feather;
+        self
+    }
+
+    /// Set how many degrees the selected hues are rotated.
+    pub fn hue_shift(mut self, degrees: T) -> Self {
+        self.hue_shift = degrees;
+        self
+    }
+
+    /// Set the relative change in chroma, where `-1.0` removes all of it and
+    /// `1.0` doubles it.
+    pub fn saturation(mut self, amount: T) -> Self {
+        self.saturation = amount;
+        self
+    }
+
+    /// Set the relative change in lightness, where `-1.0` makes the colors
+    /// black and `1.0` doubles their lightness.
+    pub fn lightness(mut self, amount: T) -> Self {
+        self.lightness = amount;
+        self
+    }
+
+    /// How much a color is affected by the adjustment, from `0.0` to `1.0`.
+    /// It can be used as a mask for other edits.
+    pub fn weight(&self, color: Oklch<T>) -> T {
+        let distance = (color.hue - self.hue).to_degrees().abs();
+        let outside = distance - self.range / from_f64(2.0);
+
+        let hue_weight = if outside <= T::zero() {
+            T::one()
+        } else if outside >= self.feather {
+            T::zero()
+        } else {
+            smoothstep(T::one() - outside / self.feather)
+        };
+
+        let chroma_weight = smoothstep(color.chroma / from_f64(NEUTRAL_CHROMA));
+
+        hue_weight * chroma_weight
+    }
+
+    /// Adjust a single color in Oklch.
+    pub fn apply_oklch(&self, color: Oklch<T>) -> Oklch<T> {
+        let weight = self.weight(color);
+        if weight == T::zero() {
+            return color;
+        }
+
+        Oklch::new(
+            color.l * (T::one() + self.lightness * weight),
+            color.chroma * (T::one() + self.saturation * weight),
+            color.hue + self.hue_shift * weight,
+        )
+    }
+
+    /// Adjust a single color by converting it to Oklch and back.
+    pub fn apply<C>(&self, color: C) -> C
+    where
+        C: FromColor<Oklch<T>> + IntoColor<Oklch<T>>,
+    {
+        C::from_color(self.apply_oklch(color.into_color()))
+    }
+
+    /// Adjust each color in a slice. Colors outside the selection are left
+    /// exactly as they are.
+    pub fn apply_slice<C>(&self, colors: &mut [C])
+    where
+        C: FromColor<Oklch<T>> + IntoColor<Oklch<T>> + Copy,
+    {
+        for color in colors {
+            let oklch: Oklch<T> = (*color).into_color();
+            if self.weight(oklch) > T::zero() {
+                *color = C::from_color(self.apply_oklch(oklch));
+            }
+        }
+    }
+}
+
+/// A smooth ramp from `0.0` to `1.0`, for `x` from `0.0` to `1.0`.
+fn smoothstep<T: FloatComponent>(x: T) -> T {
+    let x = x.max(T::zero()).min(T::one());
+    x * x * (from_f64::<T>(3.0) - from_f64::<T>(2.0) * x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::SelectiveColor;
+    use crate::Oklch;
+
+    #[test]
+    fn weight_falloff() {
+        let selection = SelectiveColor::<f64>::new(120.0, 40.0).feather(20.0);
+
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.1, 120.0)), 1.0);
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.1, 140.0)), 1.0);
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.1, 150.0)), 0.5);
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.1, 90.0)), 0.5);
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.1, 160.0)), 0.0);
+        assert_relative_eq!(selection.weight(Oklch::new(0.6, 0.0, 120.0)), 0.0);
+
+        let wrapping = SelectiveColor::<f64>::new(350.0, 40.0);
+        assert_relative_eq!(wrapping.weight(Oklch::new(0.6, 0.1, 5.0)), 1.0);
+    }
+
+    #[test]
+    fn adjustments() {
+        let selection = SelectiveColor::<f64>::new(120.0, 40.0)
+            .hue_shift(10.0)
+            .saturation(0.5)
+            .lightness(-0.5);
+
+        let adjusted = selection.apply_oklch(Oklch::new(0.6, 0.1, 130.0));
+        assert_relative_eq!(adjusted.l, 0.3);
+        assert_relative_eq!(adjusted.chroma, 0.15);
+        assert_relative_eq!(adjusted.hue.to_degrees(), 140.0);
+
+        let outside = Oklch::new(0.6, 0.1, 300.0);
+        assert_eq!(selection.apply_oklch(outside), outside);
+    }
+}
@@ -4,8 +4,10 @@ use crate::Component;
 pub use palette_derive::WithAlpha;
 
 pub use self::alpha::*;
+pub use self::opacity::Opacity;
 
 mod alpha;
+mod opacity;
 
 /// A trait for color types that can have or be given transparency (alpha channel).
 ///
@@ -14,11 +14,30 @@ use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::encoding::pixel::RawPixel;
 use crate::float::Float;
 use crate::{
-    clamp, Blend, Clamp, Component, ComponentWise, GetHue, Hue, Mix, Pixel, Saturate, Shade,
-    WithAlpha,
+    clamp, Blend, CheckedArithmetic, Chroma, Clamp, Complement, Component, ComponentArray,
+    ComponentWise, GetHue, Hue, Invert, Mix, NegateLightness, Pixel, Saturate,
+    SaturatingArithmetic, Shade, WithAlpha, WrappingArithmetic,
 };
 
 /// An alpha component wrapper for colors.
+///
+/// `Alpha` wrapped colors can be converted to any color type their color can
+/// be converted to, with or without transparency. The alpha component is
+/// carried through unchanged, so there's no need to split and rewrap it:
+///
+/// ```
+/// use palette::{FromColor, Hsla, Laba, Srgba};
+///
+/// let lab = Laba::new(50.0, 20.0, -30.0, 0.25);
+/// let hsl = Hsla::from_color(lab);
+/// let rgb = Srgba::from_color(hsl);
+/// assert_eq!(rgb.alpha, 0.25);
+/// ```
+///
+/// Encoded RGB and luminance colors can also be converted to and from
+/// [`PreAlpha`](crate::blend::PreAlpha) with `into_linear_premultiplied` and
+/// `from_linear_premultiplied`, which decode the components before
+/// premultiplying them.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 #[repr(C)]
@@ -107,6 +126,33 @@ impl<C, T> DerefMut for Alpha<C, T> {
     }
 }
 
+impl<C: Invert, T: Clone> Invert for Alpha<C, T> {
+    fn invert(&self) -> Alpha<C, T> {
+        Alpha {
+            color: self.color.invert(),
+            alpha: self.alpha.clone(),
+        }
+    }
+}
+
+impl<C: Complement, T: Clone> Complement for Alpha<C, T> {
+    fn complement(&self) -> Alpha<C, T> {
+        Alpha {
+            color: self.color.complement(),
+            alpha: self.alpha.clone(),
+        }
+    }
+}
+
+impl<C: NegateLightness, T: Clone> NegateLightness for Alpha<C, T> {
+    fn negate_lightness(&self) -> Alpha<C, T> {
+        Alpha {
+            color: self.color.negate_lightness(),
+            alpha: self.alpha.clone(),
+        }
+    }
+}
+
 impl<C: Mix> Mix for Alpha<C, C::Scalar> {
     type Scalar = C::Scalar;
 
@@ -178,6 +224,18 @@ impl<C: Saturate> Saturate for Alpha<C, C::Scalar> {
     }
 }
 
+impl<C: Chroma, T> Chroma for Alpha<C, T> {
+    type Scalar = C::Scalar;
+
+    fn chroma(&self) -> C::Scalar {
+        self.color.chroma()
+    }
+
+    fn set_chroma(&mut self, chroma: C::Scalar) {
+        self.color.set_chroma(chroma);
+    }
+}
+
 impl<C: Clamp, T: Component> Clamp for Alpha<C, T> {
     fn is_within_bounds(&self) -> bool {
         self.color.is_within_bounds() && self.alpha >= T::zero() && self.alpha <= T::max_intensity()
@@ -234,6 +292,19 @@ unsafe impl<T, C: Pixel<T>> Pixel<T> for Alpha<C, T> {
     const CHANNELS: usize = C::CHANNELS + 1;
 }
 
+macro_rules! impl_component_array {
+    ($($color_channels: expr => $channels: expr),+) => {
+        $(
+            unsafe impl<T, C: ComponentArray<T, $color_channels>> ComponentArray<T, $channels>
+                for Alpha<C, T>
+            {
+            }
+        )+
+    };
+}
+
+impl_component_array!(1 => 2, 2 => 3, 3 => 4, 4 => 5);
+
 impl<C: Default, T: Component> Default for Alpha<C, T> {
     fn default() -> Alpha<C, T> {
         Alpha {
@@ -371,6 +442,126 @@ impl<T: SubAssign + Copy, C: SubAssign<T>> SubAssign<T> for Alpha<C, T> {
     }
 }
 
+impl<C, T> SaturatingArithmetic for Alpha<C, T>
+where
+    C: SaturatingArithmetic,
+    T: num_traits::Saturating,
+{
+    fn saturating_add(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.saturating_add(other.color),
+            alpha: num_traits::Saturating::saturating_add(self.alpha, other.alpha),
+        }
+    }
+
+    fn saturating_sub(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.saturating_sub(other.color),
+            alpha: num_traits::Saturating::saturating_sub(self.alpha, other.alpha),
+        }
+    }
+}
+
+impl<C, T> SaturatingArithmetic<T> for Alpha<C, T>
+where
+    C: SaturatingArithmetic<T>,
+    T: num_traits::Saturating + Clone,
+{
+    fn saturating_add(self, c: T) -> Self {
+        Alpha {
+            color: self.color.saturating_add(c.clone()),
+            alpha: num_traits::Saturating::saturating_add(self.alpha, c),
+        }
+    }
+
+    fn saturating_sub(self, c: T) -> Self {
+        Alpha {
+            color: self.color.saturating_sub(c.clone()),
+            alpha: num_traits::Saturating::saturating_sub(self.alpha, c),
+        }
+    }
+}
+
+impl<C, T> CheckedArithmetic for Alpha<C, T>
+where
+    C: CheckedArithmetic,
+    T: num_traits::CheckedAdd + num_traits::CheckedSub,
+{
+    fn checked_add(self, other: Alpha<C, T>) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_add(other.color)?,
+            alpha: num_traits::CheckedAdd::checked_add(&self.alpha, &other.alpha)?,
+        })
+    }
+
+    fn checked_sub(self, other: Alpha<C, T>) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_sub(other.color)?,
+            alpha: num_traits::CheckedSub::checked_sub(&self.alpha, &other.alpha)?,
+        })
+    }
+}
+
+impl<C, T> CheckedArithmetic<T> for Alpha<C, T>
+where
+    C: CheckedArithmetic<T>,
+    T: num_traits::CheckedAdd + num_traits::CheckedSub + Clone,
+{
+    fn checked_add(self, c: T) -> Option<Self> {
+        Some(Alpha {
+            alpha: num_traits::CheckedAdd::checked_add(&self.alpha, &c)?,
+            color: self.color.checked_add(c)?,
+        })
+    }
+
+    fn checked_sub(self, c: T) -> Option<Self> {
+        Some(Alpha {
+            alpha: num_traits::CheckedSub::checked_sub(&self.alpha, &c)?,
+            color: self.color.checked_sub(c)?,
+        })
+    }
+}
+
+impl<C, T> WrappingArithmetic for Alpha<C, T>
+where
+    C: WrappingArithmetic,
+    T: num_traits::WrappingAdd + num_traits::WrappingSub,
+{
+    fn wrapping_add(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.wrapping_add(other.color),
+            alpha: num_traits::WrappingAdd::wrapping_add(&self.alpha, &other.alpha),
+        }
+    }
+
+    fn wrapping_sub(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.wrapping_sub(other.color),
+            alpha: num_traits::WrappingSub::wrapping_sub(&self.alpha, &other.alpha),
+        }
+    }
+}
+
+impl<C, T> WrappingArithmetic<T> for Alpha<C, T>
+where
+    C: WrappingArithmetic<T>,
+    T: num_traits::WrappingAdd + num_traits::WrappingSub,
+{
+    fn wrapping_add(self, c: T) -> Self {
+        Alpha {
+            alpha: num_traits::WrappingAdd::wrapping_add(&self.alpha, &c),
+            color: self.color.wrapping_add(c),
+        }
+    }
+
+    fn wrapping_sub(self, c: T) -> Self {
+        Alpha {
+            alpha: num_traits::WrappingSub::wrapping_sub(&self.alpha, &c),
+            color: self.color.wrapping_sub(c),
+        }
+    }
+}
+
 impl<C: Mul, T: Float> Mul for Alpha<C, T> {
     type Output = Alpha<C::Output, <T as Mul>::Output>;
 
@@ -606,8 +797,74 @@ where
 
 #[cfg(test)]
 mod test {
+    use crate::blend::PreAlpha;
     use crate::encoding::Srgb;
     use crate::rgb::Rgba;
+    use crate::{ComponentArray, FromColor, Hsva, Laba, LinSrgb, SrgbLumaa, Srgba};
+
+    #[test]
+    fn component_array() {
+        let color = Rgba::<Srgb, u8>::from_array([1, 2, 3, 4]);
+        assert_eq!(color, Rgba::new(1, 2, 3, 4));
+        assert_eq!(color.into_array(), [1, 2, 3, 4]);
+
+        let sum = color.zip_components(Rgba::new(10, 20, 30, 40), |a, b| a + b);
+        assert_eq!(sum, Rgba::new(11, 22, 33, 44));
+
+        let mut luma = SrgbLumaa::new(0.5f32, 1.0);
+        luma.as_array_mut()[1] = 0.25;
+        assert_eq!(luma.as_array(), &[0.5, 0.25]);
+
+        let mut arrays = [[1u8, 2, 3, 4], [5, 6, 7, 8]];
+        Rgba::<Srgb, u8>::from_array_slice_mut(&mut arrays)[1].alpha = 9;
+        let colors = Rgba::<Srgb, u8>::from_array_slice(&arrays);
+        assert_eq!(colors, &[Rgba::new(1, 2, 3, 4), Rgba::new(5, 6, 7, 9)]);
+        assert_eq!(
+            Rgba::into_array_slice(colors),
+            &[[1, 2, 3, 4], [5, 6, 7, 9]]
+        );
+    }
+
+    #[test]
+    fn conversion_keeps_alpha() {
+        let color = Srgba::new(0.8f64, 0.3, 0.1, 0.4);
+        let lab = Laba::from_color(color);
+        let hsv = Hsva::from_color(lab);
+        assert_eq!(lab.alpha, 0.4);
+        assert_eq!(hsv.alpha, 0.4);
+        assert_relative_eq!(Srgba::from_color(hsv), color, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn linear_premultiplied() {
+        let color = Srgba::new(0.8f64, 0.5, 0.0, 0.5);
+        let premultiplied = color.into_linear_premultiplied();
+        let linear = color.into_linear();
+        assert_relative_eq!(premultiplied.color, linear.color * 0.5);
+        assert_relative_eq!(
+            Srgba::from_linear_premultiplied(premultiplied),
+            color,
+            epsilon = 1e-12
+        );
+
+        let transparent = PreAlpha {
+            color: LinSrgb::new(0.0, 0.0, 0.0),
+            alpha: 0.0,
+        };
+        assert_eq!(
+            Srgba::<f64>::from_linear_premultiplied(transparent),
+            Srgba::new(0.0, 0.0, 0.0, 0.0)
+        );
+
+        let luma = SrgbLumaa::new(0.5f64, 0.25);
+        let premultiplied = luma.into_linear_premultiplied();
+        assert_relative_eq!(premultiplied.luma, luma.into_linear().luma * 0.25);
+        assert_relative_eq!(
+            SrgbLumaa::from_linear_premultiplied(premultiplied),
+            luma,
+            epsilon = 1e-12
+        );
+    }
 
     #[test]
     fn lower_hex() {
@@ -0,0 +1,269 @@
+use core::ops::{Add, Mul, MulAssign, Sub};
+
+use num_traits::Zero;
+
+use crate::{clamp, from_f64, Component, FloatComponent, FromComponent};
+
+/// An alpha value that is always within its valid range.
+///
+/// A plain `f32` doesn't tell if it's an alpha value from `0.0` to `1.0`, a
+/// percentage or something else. `Opacity` is clamped to the range of its
+/// component type when it's created, and combines with other opacities by
+/// multiplying them, like stacked semi-transparent layers do. It can be used
+/// on its own, or as the alpha component of [`Alpha`](crate::Alpha):
+///
+/// ```
+/// use palette::{FromColor, Hsl, Srgb, Opacity, WithAlpha};
+///
+/// let layer = Opacity::from_percent(80.0);
+/// let group = Opacity::new(0.5);
+/// assert_eq!((layer * group).value(), 0.4);
+///
+/// let color = Srgb::new(0.8, 0.2, 0.1).with_alpha(layer * group);
+/// let hsl = palette::Alpha::<Hsl, _>::from_color(color);
+/// assert_eq!(hsl.alpha, Opacity::new(0.4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Opacity<T>(T);
+
+impl<T: Component> Opacity<T> {
+    /// Create an opacity, clamping `value` to the range from `0` to
+    /// `T::max_intensity()`.
+    pub fn new(value: T) -> Self {
+        Opacity(clamp(value, T::zero(), T::max_intensity()))
+    }
+
+    /// A fully transparent opacity.
+    pub fn transparent() -> Self {
+        Opacity(T::zero())
+    }
+
+    /// A fully opaque opacity.
+    pub fn opaque() -> Self {
+        Opacity(T::max_intensity())
+    }
+
+    /// The opacity value, from `0` to `T::max_intensity()`.
+    pub fn value(self) -> T {
+        self.0
+    }
+
+    /// Check if the opacity is fully transparent.
+    pub fn is_transparent(self) -> bool {
+        self.0 == T::zero()
+    }
+
+    /// Check if the opacity is fully opaque.
+    pub fn is_opaque(self) -> bool {
+        self.0 == T::max_intensity()
+    }
+
+    /// Create an opacity from an 8 bit alpha value, where `255` is fully
+    /// opaque.
+    pub fn from_u8(value: u8) -> Self
+    where
+        T: FromComponent<u8>,
+    {
+        Self::from_format(Opacity(value))
+    }
+
+    /// Convert into another component type.
+    ///
+    /// ```
+    /// use palette::Opacity;
+    ///
+    /// assert_eq!(Opacity::new(1.0f32).into_format::<u8>(), Opacity::new(255));
+    /// ```
+    pub fn into_format<U>(self) -> Opacity<U>
+    where
+        U: Component + FromComponent<T>,
+    {
+        Opacity(U::from_component(self.0))
+    }
+
+    /// Convert from another component type.
+    pub fn from_format<U>(opacity: Opacity<U>) -> Self
+    where
+        U: Component,
+        T: FromComponent<U>,
+    {
+        Opacity(T::from_component(opacity.0))
+    }
+}
+
+impl<T: FloatComponent> Opacity<T> {
+    /// Create an opacity from a percentage, from `0.0` to `100.0`.
+    pub fn from_percent(percent: T) -> Self {
+        Self::new(percent / from_f64(100.0))
+    }
+
+    /// The opacity as a percentage, from `0.0` to `100.0`.
+    pub fn to_percent(self) -> T {
+        self.0 * from_f64(100.0)
+    }
+
+    /// The transparency that is left, which is `1.0 - opacity`.
+    pub fn complement(self) -> Self {
+        Opacity(T::one() - self.0)
+    }
+
+    /// The combined opacity of this layer on top of `below`, as with the
+    /// "source over" compositing operator.
+    ///
+    /// ```
+    /// use palette::Opacity;
+    ///
+    /// let combined = Opacity::new(0.5).over(Opacity::new(0.5));
+    /// assert_eq!(combined, Opacity::new(0.75));
+    /// ```
+    pub fn over(self, below: Self) -> Self {
+        Opacity(self.0 + below.0 * (T::one() - self.0))
+    }
+}
+
+impl<T> Component for Opacity<T>
+where
+    T: Component + Sub<Output = T>,
+{
+    fn max_intensity() -> Self {
+        Self::opaque()
+    }
+}
+
+impl<T: Component> Default for Opacity<T> {
+    fn default() -> Self {
+        Self::opaque()
+    }
+}
+
+impl<T> Zero for Opacity<T>
+where
+    T: Component + Sub<Output = T>,
+{
+    fn zero() -> Self {
+        Self::transparent()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_transparent()
+    }
+}
+
+/// Adding opacities saturates at fully opaque, also for integer components.
+impl<T> Add for Opacity<T>
+where
+    T: Component + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // Compare with the headroom first, so integers don't overflow.
+        if other.0 >= T::max_intensity() - self.0 {
+            Self::opaque()
+        } else {
+            Opacity(self.0 + other.0)
+        }
+    }
+}
+
+/// Multiplying opacities gives the opacity of the layers stacked on top of
+/// each other.
+impl<T: FloatComponent> Mul for Opacity<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Opacity(self.0 * other.0)
+    }
+}
+
+impl<T: FloatComponent> MulAssign for Opacity<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: FloatComponent> Mul<T> for Opacity<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.0 * factor)
+    }
+}
+
+impl<T: Component> From<T> for Opacity<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Opacity;
+    use crate::{Alpha, FromColor, Hsv, Srgb, WithAlpha};
+
+    #[test]
+    fn clamping() {
+        assert_eq!(Opacity::new(1.5f32).value(), 1.0);
+        assert_eq!(Opacity::new(-0.5f32).value(), 0.0);
+        assert_eq!(Opacity::from_percent(250.0f64), Opacity::opaque());
+        assert_eq!(Opacity::new(0.5f32) * 3.0, Opacity::opaque());
+        assert_eq!(Opacity::new(0.75f32) + Opacity::new(0.5), Opacity::opaque());
+        assert!(Opacity::<u8>::default().is_opaque());
+    }
+
+    #[test]
+    fn integer_addition() {
+        assert_eq!(
+            Opacity::<u8>::new(200) + Opacity::new(100),
+            Opacity::opaque()
+        );
+        assert_eq!(
+            Opacity::<u8>::new(200) + Opacity::new(55),
+            Opacity::opaque()
+        );
+        assert_eq!(
+            Opacity::<u8>::new(200) + Opacity::new(54),
+            Opacity::new(254)
+        );
+        assert_eq!(
+            Opacity::<u16>::opaque() + Opacity::opaque(),
+            Opacity::opaque()
+        );
+        assert_eq!(
+            Opacity::<u8>::new(0) + Opacity::new(0),
+            Opacity::transparent()
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Opacity::<f64>::from_u8(255), Opacity::opaque());
+        assert_eq!(Opacity::<f64>::from_u8(0), Opacity::transparent());
+        assert_eq!(Opacity::new(0.5f64).into_format::<u8>(), Opacity::new(128));
+        assert_eq!(Opacity::new(0.25f64).to_percent(), 25.0);
+        assert_eq!(Opacity::new(0.25f64).complement().value(), 0.75);
+    }
+
+    #[test]
+    fn combination() {
+        let a = Opacity::new(0.5f64);
+        let b = Opacity::new(0.25f64);
+        assert_eq!(a * b, Opacity::new(0.125));
+        assert_eq!(a * Opacity::opaque(), a);
+        assert_eq!(a * Opacity::transparent(), Opacity::transparent());
+        assert_eq!(b.over(a), Opacity::new(0.625));
+        assert_eq!(a.over(Opacity::opaque()), Opacity::opaque());
+    }
+
+    #[test]
+    fn as_alpha() {
+        let color = Srgb::new(1.0f64, 0.5, 0.0).with_alpha(Opacity::new(0.5));
+        let hsv = Alpha::<Hsv<_, f64>, _>::from_color(color);
+        assert_eq!(hsv.alpha, Opacity::new(0.5));
+
+        let (_, alpha) = hsv.split();
+        assert_eq!(alpha.value(), 0.5);
+    }
+}
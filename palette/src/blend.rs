@@ -40,10 +40,12 @@ use crate::float::Float;
 use crate::ComponentWise;
 
 pub use self::blend::Blend;
+pub use self::buffer::{blend_slices, BlendMode};
 pub use self::equations::{Equation, Equations, Parameter, Parameters};
 pub use self::pre_alpha::PreAlpha;
 
 mod blend;
+mod buffer;
 mod equations;
 mod pre_alpha;
 
@@ -0,0 +1,128 @@
+use crate::blend::{Blend, BlendFunction, Equations, PreAlpha};
+use crate::float::Float;
+use crate::ComponentWise;
+
+/// A blend mode for [`blend_slices`](fn.blend_slices.html).
+///
+/// Each mode, except for `Equations`, corresponds to the method with the same
+/// name in [`Blend`](trait.Blend.html).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlendMode {
+    /// [`Blend::over`](trait.Blend.html#method.over).
+    Over,
+    /// [`Blend::inside`](trait.Blend.html#method.inside).
+    Inside,
+    /// [`Blend::outside`](trait.Blend.html#method.outside).
+    Outside,
+    /// [`Blend::atop`](trait.Blend.html#method.atop).
+    Atop,
+    /// [`Blend::xor`](trait.Blend.html#method.xor).
+    Xor,
+    /// [`Blend::plus`](trait.Blend.html#method.plus).
+    Plus,
+    /// [`Blend::multiply`](trait.Blend.html#method.multiply).
+    Multiply,
+    /// [`Blend::screen`](trait.Blend.html#method.screen).
+    Screen,
+    /// [`Blend::overlay`](trait.Blend.html#method.overlay).
+    Overlay,
+    /// [`Blend::darken`](trait.Blend.html#method.darken).
+    Darken,
+    /// [`Blend::lighten`](trait.Blend.html#method.lighten).
+    Lighten,
+    /// [`Blend::dodge`](trait.Blend.html#method.dodge).
+    Dodge,
+    /// [`Blend::burn`](trait.Blend.html#method.burn).
+    Burn,
+    /// [`Blend::hard_light`](trait.Blend.html#method.hard_light).
+    HardLight,
+    /// [`Blend::soft_light`](trait.Blend.html#method.soft_light).
+    SoftLight,
+    /// [`Blend::difference`](trait.Blend.html#method.difference).
+    Difference,
+    /// [`Blend::exclusion`](trait.Blend.html#method.exclusion).
+    Exclusion,
+    /// A set of OpenGL style blending equations.
+    Equations(Equations),
+}
+
+/// Blend each color in `source` with the color at the same index in
+/// `destination`, and write the result to `destination`.
+///
+/// The colors are premultiplied, and typically linear, so they can be blended
+/// without converting them back and forth for each pixel. The blend mode is
+/// picked once for the whole buffer, rather than once per pixel, which lets
+/// the compiler optimize the inner loop for each mode.
+///
+/// Panics if `source` and `destination` have different lengths.
+///
+/// ```
+/// use palette::blend::{blend_slices, BlendMode, PreAlpha};
+/// use palette::{LinSrgb, LinSrgba};
+///
+/// let mut layer: Vec<PreAlpha<LinSrgb, f32>> = vec![
+///     LinSrgba::new(1.0, 0.0, 0.0, 1.0).into(),
+///     LinSrgba::new(0.0, 0.0, 1.0, 1.0).into(),
+/// ];
+/// let overlay: Vec<PreAlpha<LinSrgb, f32>> = vec![
+///     LinSrgba::new(0.0, 1.0, 0.0, 0.5).into(),
+///     LinSrgba::new(0.0, 0.0, 0.0, 0.0).into(),
+/// ];
+///
+/// blend_slices(&mut layer, &overlay, BlendMode::Over);
+///
+/// assert_eq!(LinSrgba::from(layer[0]), LinSrgba::new(0.5, 0.5, 0.0, 1.0));
+/// assert_eq!(LinSrgba::from(layer[1]), LinSrgba::new(0.0, 0.0, 1.0, 1.0));
+/// ```
+pub fn blend_slices<C, T>(
+    destination: &mut [PreAlpha<C, T>],
+    source: &[PreAlpha<C, T>],
+    mode: BlendMode,
+) where
+    C: Blend<Color = C> + ComponentWise<Scalar = T> + Clone,
+    T: Float,
+{
+    assert_eq!(
+        destination.len(),
+        source.len(),
+        "the source and destination buffers must have the same length"
+    );
+
+    match mode {
+        BlendMode::Over => blend_with(destination, source, Blend::over),
+        BlendMode::Inside => blend_with(destination, source, Blend::inside),
+        BlendMode::Outside => blend_with(destination, source, Blend::outside),
+        BlendMode::Atop => blend_with(destination, source, Blend::atop),
+        BlendMode::Xor => blend_with(destination, source, Blend::xor),
+        BlendMode::Plus => blend_with(destination, source, Blend::plus),
+        BlendMode::Multiply => blend_with(destination, source, Blend::multiply),
+        BlendMode::Screen => blend_with(destination, source, Blend::screen),
+        BlendMode::Overlay => blend_with(destination, source, Blend::overlay),
+        BlendMode::Darken => blend_with(destination, source, Blend::darken),
+        BlendMode::Lighten => blend_with(destination, source, Blend::lighten),
+        BlendMode::Dodge => blend_with(destination, source, Blend::dodge),
+        BlendMode::Burn => blend_with(destination, source, Blend::burn),
+        BlendMode::HardLight => blend_with(destination, source, Blend::hard_light),
+        BlendMode::SoftLight => blend_with(destination, source, Blend::soft_light),
+        BlendMode::Difference => blend_with(destination, source, Blend::difference),
+        BlendMode::Exclusion => blend_with(destination, source, Blend::exclusion),
+        BlendMode::Equations(equations) => {
+            blend_with(destination, source, |source, destination| {
+                equations.apply_to(source, destination)
+            })
+        }
+    }
+}
+
+/// The inner loop of `blend_slices`, which is monomorphized for each mode.
+#[inline(always)]
+fn blend_with<C, T, F>(destination: &mut [PreAlpha<C, T>], source: &[PreAlpha<C, T>], blend: F)
+where
+    C: Clone,
+    T: Float,
+    F: Fn(PreAlpha<C, T>, PreAlpha<C, T>) -> PreAlpha<C, T>,
+{
+    for (destination, source) in destination.iter_mut().zip(source) {
+        *destination = blend(source.clone(), destination.clone());
+    }
+}
@@ -389,3 +389,38 @@ fn exclusion() {
 
     assert_relative_eq!(LinSrgba::new(0.5, 0.0, 0.3, 1.0), a.difference(b));
 }
+
+#[test]
+fn blend_slices() {
+    use crate::blend::{blend_slices, BlendMode, Equations, Parameter};
+
+    let sources = [
+        LinSrgba::new(0.2, 0.5, 0.1, 0.8),
+        LinSrgba::new(0.6, 0.3, 0.5, 0.1),
+        LinSrgba::new(1.0, 1.0, 1.0, 0.0),
+    ];
+    let destinations = [
+        LinSrgba::new(0.6, 0.3, 0.5, 0.1),
+        LinSrgba::new(0.0, 0.0, 1.0, 1.0),
+        LinSrgba::new(0.5, 0.5, 0.5, 0.5),
+    ];
+
+    let source: Vec<PreAlpha<LinSrgb, f32>> = sources.iter().map(|&c| c.into()).collect();
+    let mut destination: Vec<PreAlpha<LinSrgb, f32>> =
+        destinations.iter().map(|&c| c.into()).collect();
+    blend_slices(&mut destination, &source, BlendMode::Overlay);
+
+    for ((result, &a), &b) in destination.iter().zip(&sources).zip(&destinations) {
+        assert_relative_eq!(LinSrgba::from(*result), a.overlay(b));
+    }
+
+    let equations =
+        Equations::from_parameters(Parameter::SourceAlpha, Parameter::OneMinusSourceAlpha);
+    let mut destination: Vec<PreAlpha<LinSrgb, f32>> =
+        destinations.iter().map(|&c| c.into()).collect();
+    blend_slices(&mut destination, &source, BlendMode::Equations(equations));
+
+    for ((result, &a), &b) in destination.iter().zip(&sources).zip(&destinations) {
+        assert_relative_eq!(LinSrgba::from(*result), a.blend(b, equations));
+    }
+}
@@ -0,0 +1,470 @@
+//! The CIECAM02 and CAM16 color appearance models.
+//!
+//! This is the shared implementation of the models, which is used for
+//! deriving other color spaces, such as `Cam02Ucs`, `Cam16Ucs` and `Hct`, and
+//! by the public [`cam16`](crate::cam16) module. The colors are given
+//! relative to the adopted white, where the white has a luminance of `1.0`.
+
+use core::f64::consts::PI;
+use core::marker::PhantomData;
+
+use crate::math::{atan2, cbrt, cos, exp, ln, powf, sin_cos};
+use crate::matrix::{matrix_inverse, multiply_3x3, multiply_xyz, Mat3};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// The CAT02 chromatic adaptation matrix.
+#[rustfmt::skip]
+const CAT02: [f64; 9] = [
+    0.7328, 0.4296, -0.1624,
+    -0.7036, 1.6975, 0.0061,
+    0.0030, 0.0136, 0.9834,
+];
+
+/// The Hunt-Pointer-Estevez cone response matrix.
+#[rustfmt::skip]
+const HUNT_POINTER_ESTEVEZ: [f64; 9] = [
+    0.38971, 0.68898, -0.07868,
+    -0.22981, 1.18340, 0.04641,
+    0.0, 0.0, 1.0,
+];
+
+/// The CAM16 chromatic adaptation matrix, which is also its cone response
+/// matrix.
+#[rustfmt::skip]
+const M16: [f64; 9] = [
+    0.401288, 0.650173, -0.051461,
+    -0.250268, 1.204414, 0.045854,
+    -0.002079, 0.048952, 0.953127,
+];
+
+/// A color appearance model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Model {
+    /// CIECAM02, with CAT02 for the chromatic adaptation and the
+    /// Hunt-Pointer-Estevez cone responses.
+    Ciecam02,
+    /// CAM16, with M16 for both the chromatic adaptation and the cone
+    /// responses.
+    Cam16,
+}
+
+/// The surround of a viewing field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Surround {
+    /// The factor for the degree of adaptation, `F`.
+    pub f: f64,
+    /// The impact of the surround, `c`.
+    pub c: f64,
+    /// The chromatic induction factor, `N_c`.
+    pub n_c: f64,
+}
+
+impl Surround {
+    /// An average surround, such as when viewing surface colors.
+    pub const AVERAGE: Surround = Surround {
+        f: 1.0,
+        c: 0.69,
+        n_c: 1.0,
+    };
+
+    /// A dim surround, such as when viewing television.
+    pub const DIM: Surround = Surround {
+        f: 0.9,
+        c: 0.59,
+        n_c: 0.9,
+    };
+
+    /// A dark surround, such as when viewing projected images in a dark room.
+    pub const DARK: Surround = Surround {
+        f: 0.8,
+        c: 0.525,
+        n_c: 0.8,
+    };
+}
+
+/// The appearance correlates of a color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Correlates<T> {
+    /// The lightness, `J`.
+    pub lightness: T,
+    /// The chroma, `C`.
+    pub chroma: T,
+    /// The colorfulness, `M`.
+    pub colorfulness: T,
+    /// The hue angle, `h`, in radians.
+    pub hue: T,
+}
+
+/// The precomputed parameters of a set of viewing conditions.
+pub(crate) struct ViewingConditions<Wp, T> {
+    adaptation: Mat3<T>,
+    inv_adaptation: Mat3<T>,
+    adapted_to_cones: Mat3<T>,
+    cones_to_adapted: Mat3<T>,
+    discounting: [T; 3],
+    luminance_adaptation: T,
+    background_factor: T,
+    exponent: T,
+    impact: T,
+    induction: T,
+    chromatic_induction: T,
+    white_achromatic: T,
+    white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> ViewingConditions<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    /// Viewing conditions for `model`, with the adopted `white`, the
+    /// luminance of the adapting field `adapting_luminance`, in cd/m², and
+    /// the relative luminance of the background `background_luminance`, from
+    /// `0.0` to `100.0`.
+    pub fn new(
+        model: Model,
+        white: Xyz<Wp, T>,
+        adapting_luminance: T,
+        background_luminance: T,
+        surround: Surround,
+    ) -> Self {
+        Self::with_discounting(
+            model,
+            white,
+            adapting_luminance,
+            background_luminance,
+            surround,
+            false,
+        )
+    }
+
+    /// The same as `new`, but fully adapted to the white if `discounting` is
+    /// `true`, as when the illuminant is discounted.
+    pub fn with_discounting(
+        model: Model,
+        white: Xyz<Wp, T>,
+        adapting_luminance: T,
+        background_luminance: T,
+        surround: Surround,
+        discounting: bool,
+    ) -> Self {
+        let hundred = from_f64::<T>(100.0);
+        let (adaptation, adapted_to_cones) = match model {
+            Model::Ciecam02 => {
+                let adaptation = matrix(&CAT02);
+                let hpe = matrix(&HUNT_POINTER_ESTEVEZ);
+                (adaptation, multiply_3x3(&hpe, &matrix_inverse(&adaptation)))
+            }
+            Model::Cam16 => {
+                let mut identity = [T::zero(); 9];
+                identity[0] = T::one();
+                identity[4] = T::one();
+                identity[8] = T::one();
+                (matrix(&M16), identity)
+            }
+        };
+        let inv_adaptation = matrix_inverse(&adaptation);
+        let cones_to_adapted = matrix_inverse(&adapted_to_cones);
+
+        let white = white * (hundred / white.y);
+        let degree = from_f64::<T>(surround.f)
+            * (T::one()
+                - from_f64::<T>(1.0 / 3.6)
+                    * exp((-adapting_luminance - from_f64(42.0)) / from_f64(92.0)));
+        let degree = if discounting {
+            T::one()
+        } else {
+            degree.max(T::zero()).min(T::one())
+        };
+
+        let white_response: Xyz<Wp, T> = multiply_xyz(&adaptation, &white);
+        let discount = |response: T| degree * hundred / response + T::one() - degree;
+        let discounting = [
+            discount(white_response.x),
+            discount(white_response.y),
+            discount(white_response.z),
+        ];
+
+        let k = T::one() / (from_f64::<T>(5.0) * adapting_luminance + T::one());
+        let k4 = k * k * k * k;
+        let luminance_adaptation = from_f64::<T>(0.2) * k4 * from_f64(5.0) * adapting_luminance
+            + from_f64::<T>(0.1)
+                * (T::one() - k4)
+                * (T::one() - k4)
+                * cbrt(from_f64::<T>(5.0) * adapting_luminance);
+
+        let background_factor = background_luminance / hundred;
+        let induction = from_f64::<T>(0.725) * powf(background_factor, from_f64(-0.2));
+
+        let mut conditions = ViewingConditions {
+            adaptation,
+            inv_adaptation,
+            adapted_to_cones,
+            cones_to_adapted,
+            discounting,
+            luminance_adaptation,
+            background_factor,
+            exponent: from_f64::<T>(surround.c) * (from_f64::<T>(1.48) + background_factor.sqrt()),
+            impact: from_f64(surround.c),
+            induction,
+            chromatic_induction: from_f64::<T>(surround.n_c) * induction,
+            white_achromatic: T::one(),
+            white_point: PhantomData,
+        };
+
+        conditions.white_achromatic = conditions.achromatic(conditions.adapted_response(white));
+        conditions
+    }
+
+    /// The default viewing conditions of CAM02-UCS and CAM16-UCS, with the
+    /// white point `Wp`, an adapting field of 64 lux, a background with a
+    /// relative luminance of 20 and an average surround.
+    pub fn ucs_default(model: Model) -> Self {
+        ViewingConditions::new(
+            model,
+            Wp::get_xyz(),
+            from_f64(64.0 / PI * 0.2),
+            from_f64(20.0),
+            Surround::AVERAGE,
+        )
+    }
+
+    /// The appearance correlates of `color`.
+    pub fn correlates(&self, color: Xyz<Wp, T>) -> Correlates<T> {
+        let [red, green, blue] = self.adapted_response(color * from_f64::<T>(100.0));
+
+        let a = red - from_f64::<T>(12.0 / 11.0) * green + blue / from_f64(11.0);
+        let b = (red + green - from_f64::<T>(2.0) * blue) / from_f64(9.0);
+        let hue = atan2(b, a);
+
+        let achromatic = self.achromatic([red, green, blue]).max(T::zero());
+        let lightness =
+            from_f64::<T>(100.0) * powf(achromatic / self.white_achromatic, self.exponent);
+
+        let denominator = red + green + from_f64::<T>(21.0 / 20.0) * blue;
+        let t = self.eccentricity_factor(hue) * (a * a + b * b).sqrt() / denominator;
+        let chroma =
+            powf(t, from_f64(0.9)) * (lightness / from_f64(100.0)).sqrt() * self.chroma_factor();
+
+        Correlates {
+            lightness,
+            chroma,
+            colorfulness: self.colorfulness(chroma),
+            hue,
+        }
+    }
+
+    /// The color with the lightness `lightness`, the chroma `chroma` and the
+    /// hue angle `hue`, in radians.
+    pub fn color_from_correlates(&self, lightness: T, chroma: T, hue: T) -> Xyz<Wp, T> {
+        let lightness = lightness.max(T::zero());
+        let chroma = chroma.max(T::zero());
+        let t = if lightness > T::zero() {
+            powf(
+                chroma / ((lightness / from_f64(100.0)).sqrt() * self.chroma_factor()),
+                from_f64(1.0 / 0.9),
+            )
+        } else {
+            T::zero()
+        };
+
+        let achromatic =
+            self.white_achromatic * powf(lightness / from_f64(100.0), T::one() / self.exponent);
+        let p2 = achromatic / self.induction + from_f64(0.305);
+
+        // Solving the definition of `t` for the distance from the achromatic
+        // point, using that `a` and `b` follow from the hue angle.
+        let (sin, cos) = sin_cos(hue);
+        let distance = t * p2
+            / (self.eccentricity_factor(hue)
+                + t * (from_f64::<T>(671.0) * cos + from_f64::<T>(6588.0) * sin)
+                    / from_f64(1403.0));
+        let a = distance * cos;
+        let b = distance * sin;
+
+        let response = |p: f64, a_factor: f64, b_factor: f64| {
+            let adapted =
+                (from_f64::<T>(p) * p2 + from_f64::<T>(a_factor) * a + from_f64::<T>(b_factor) * b)
+                    / from_f64(1403.0)
+                    - from_f64(0.1);
+            adapted.signum() * from_f64::<T>(100.0) / self.luminance_adaptation
+                * powf(
+                    from_f64::<T>(27.13) * adapted.abs() / (from_f64::<T>(400.0) - adapted.abs()),
+                    from_f64(1.0 / 0.42),
+                )
+        };
+
+        let cones = Xyz::<Wp, T>::with_wp(
+            response(460.0, 451.0, 288.0),
+            response(460.0, -891.0, -261.0),
+            response(460.0, -220.0, -6300.0),
+        );
+        let adapted: Xyz<Wp, T> = multiply_xyz(&self.cones_to_adapted, &cones);
+        let [dr, dg, db] = self.discounting;
+        let response = Xyz::<Wp, T>::with_wp(adapted.x / dr, adapted.y / dg, adapted.z / db);
+
+        multiply_xyz::<Wp, Wp, T>(&self.inv_adaptation, &response) / from_f64::<T>(100.0)
+    }
+
+    /// The colorfulness, `M`, of a color with the chroma `chroma`.
+    pub fn colorfulness(&self, chroma: T) -> T {
+        chroma * powf(self.luminance_adaptation, from_f64(0.25))
+    }
+
+    /// The chroma, `C`, of a color with the colorfulness `colorfulness`.
+    pub fn chroma(&self, colorfulness: T) -> T {
+        colorfulness / powf(self.luminance_adaptation, from_f64(0.25))
+    }
+
+    /// The brightness, `Q`, of a color with the lightness `lightness`.
+    pub fn brightness(&self, lightness: T) -> T {
+        from_f64::<T>(4.0) / self.impact
+            * (lightness / from_f64(100.0)).sqrt()
+            * (self.white_achromatic + from_f64(4.0))
+            * powf(self.luminance_adaptation, from_f64(0.25))
+    }
+
+    /// The post-adaptation cone responses of `color`, which is scaled to
+    /// have a white luminance of `100.0`.
+    fn adapted_response(&self, color: Xyz<Wp, T>) -> [T; 3] {
+        let response: Xyz<Wp, T> = multiply_xyz(&self.adaptation, &color);
+        let [dr, dg, db] = self.discounting;
+        let adapted = Xyz::<Wp, T>::with_wp(response.x * dr, response.y * dg, response.z * db);
+        let cones: Xyz<Wp, T> = multiply_xyz(&self.adapted_to_cones, &adapted);
+
+        let compress = |cone: T| {
+            let power = powf(
+                self.luminance_adaptation * cone.abs() / from_f64(100.0),
+                from_f64(0.42),
+            );
+            cone.signum() * from_f64::<T>(400.0) * power / (power + from_f64(27.13)) + from_f64(0.1)
+        };
+
+        [compress(cones.x), compress(cones.y), compress(cones.z)]
+    }
+
+    /// The achromatic response of the post-adaptation cone responses.
+    fn achromatic(&self, [red, green, blue]: [T; 3]) -> T {
+        (from_f64::<T>(2.0) * red + green + blue / from_f64(20.0) - from_f64(0.305))
+            * self.induction
+    }
+
+    /// The eccentricity factor for the hue angle `hue`, in radians, including
+    /// the constant factors of `t`.
+    fn eccentricity_factor(&self, hue: T) -> T {
+        let eccentricity = from_f64::<T>(0.25) * (cos(hue + from_f64(2.0)) + from_f64(3.8));
+        from_f64::<T>(50000.0 / 13.0) * self.chromatic_induction * eccentricity
+    }
+
+    /// The factor that depends on the background in the definition of chroma.
+    fn chroma_factor(&self) -> T {
+        powf(
+            from_f64::<T>(1.64) - powf(from_f64::<T>(0.29), self.background_factor),
+            from_f64(0.73),
+        )
+    }
+}
+
+/// The uniform color space coordinates, `(J', a', b')`, of a color with the
+/// lightness `lightness`, the colorfulness `colorfulness` and the hue angle
+/// `hue`, in radians. This is the same for CAM02-UCS and CAM16-UCS.
+pub(crate) fn ucs_from_correlates<T: FloatComponent>(
+    lightness: T,
+    colorfulness: T,
+    hue: T,
+) -> (T, T, T) {
+    let j = from_f64::<T>(1.7) * lightness / (T::one() + from_f64::<T>(0.007) * lightness);
+    let m = ln(T::one() + from_f64::<T>(0.0228) * colorfulness) / from_f64(0.0228);
+    let (sin, cos) = sin_cos(hue);
+
+    (j, m * cos, m * sin)
+}
+
+/// The lightness, colorfulness and hue angle, in radians, of a color with the
+/// uniform color space coordinates `(J', a', b')`.
+pub(crate) fn correlates_from_ucs<T: FloatComponent>(j: T, a: T, b: T) -> (T, T, T) {
+    let lightness = j / (from_f64::<T>(1.7) - from_f64::<T>(0.007) * j);
+    let m = (a * a + b * b).sqrt();
+    let colorfulness = (exp(from_f64::<T>(0.0228) * m) - T::one()) / from_f64(0.0228);
+
+    (lightness, colorfulness, atan2(b, a))
+}
+
+/// Convert a matrix of `f64` constants to `T`.
+fn matrix<T: FloatComponent>(m: &[f64; 9]) -> Mat3<T> {
+    let mut result = [T::zero(); 9];
+    for (result, &value) in result.iter_mut().zip(m) {
+        *result = from_f64(value);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Model, Surround, ViewingConditions};
+    use crate::white_point::{A, D65};
+    use crate::Xyz;
+
+    fn conditions(white: (f64, f64, f64), adapting_luminance: f64) -> ViewingConditions<D65, f64> {
+        let white = Xyz::with_wp(white.0, white.1, white.2) / 100.0;
+        ViewingConditions::new(
+            Model::Ciecam02,
+            white,
+            adapting_luminance,
+            20.0,
+            Surround::AVERAGE,
+        )
+    }
+
+    #[test]
+    fn reference_values() {
+        // Worked examples from CIE 159:2004.
+        let d65 = conditions((95.05, 100.0, 108.88), 318.31);
+        let correlates = d65.correlates(Xyz::with_wp(0.1901, 0.2000, 0.2178));
+        assert_relative_eq!(correlates.lightness, 41.731, epsilon = 1e-3);
+        assert_relative_eq!(correlates.colorfulness, 0.1088, epsilon = 1e-3);
+        assert_relative_eq!(correlates.hue.to_degrees(), 219.048 - 360.0, epsilon = 1e-2);
+
+        let d65 = conditions((95.05, 100.0, 108.88), 31.83);
+        let correlates = d65.correlates(Xyz::with_wp(0.5706, 0.4306, 0.3196));
+        assert_relative_eq!(correlates.lightness, 65.96, epsilon = 1e-2);
+        assert_relative_eq!(correlates.chroma, 48.57, epsilon = 2e-2);
+        assert_relative_eq!(correlates.colorfulness, 41.67, epsilon = 2e-2);
+        assert_relative_eq!(correlates.hue.to_degrees(), 19.6, epsilon = 0.1);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let white = Xyz::<D65, f64>::with_wp(0.95047, 1.0, 1.08883);
+        let cam16 = ViewingConditions::new(Model::Cam16, white, 11.72, 18.42, Surround::AVERAGE);
+        let d65 = ViewingConditions::<D65, f64>::ucs_default(Model::Ciecam02);
+        let a = ViewingConditions::<A, f64>::ucs_default(Model::Ciecam02);
+
+        for &(x, y, z) in &[
+            (0.1901, 0.2, 0.2178),
+            (0.5706, 0.4306, 0.3196),
+            (0.0353, 0.0656, 0.0214),
+            (0.95047, 1.0, 1.08883),
+            (0.0, 0.0, 0.0),
+        ] {
+            let correlates = d65.correlates(Xyz::with_wp(x, y, z));
+            let xyz =
+                d65.color_from_correlates(correlates.lightness, correlates.chroma, correlates.hue);
+            assert_relative_eq!(xyz, Xyz::with_wp(x, y, z), epsilon = 1e-9);
+
+            let correlates = a.correlates(Xyz::with_wp(x, y, z));
+            let xyz =
+                a.color_from_correlates(correlates.lightness, correlates.chroma, correlates.hue);
+            assert_relative_eq!(xyz, Xyz::with_wp(x, y, z), epsilon = 1e-9);
+
+            let correlates = cam16.correlates(Xyz::with_wp(x, y, z));
+            let xyz = cam16.color_from_correlates(
+                correlates.lightness,
+                correlates.chroma,
+                correlates.hue,
+            );
+            assert_relative_eq!(xyz, Xyz::with_wp(x, y, z), epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::cam::{correlates_from_ucs, ucs_from_correlates, Model, ViewingConditions};
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::math::atan2;
+use crate::white_point::{WhitePoint, D65};
+use crate::{
+    clamp, from_f64, Alpha, Clamp, ColorDifference, Component, ComponentWise, FloatComponent,
+    GetHue, LabHue, Mix, Pixel, Shade, Xyz,
+};
+
+/// CAM02-UCS with an alpha component. See the [`Cam02Ucsa`
+/// implementation in `Alpha`](crate::Alpha#Cam02Ucsa).
+pub type Cam02Ucsa<Wp = D65, T = f32> = Alpha<Cam02Ucs<Wp, T>, T>;
+
+/// The CAM02-UCS color space, J'a'b'.
+///
+/// CAM02-UCS is a uniform color space, derived from the CIECAM02 color
+/// appearance model. It's more perceptually uniform than CIE L\*a\*b\*, which
+/// makes the Euclidean distance between two colors a good measure of how
+/// different they look. This is why it's often used for clustering colors
+/// and for designing color maps.
+///
+/// The colors are converted with the default viewing conditions of
+/// CAM02-UCS: the white point `Wp` as the adopted white, an adapting field
+/// with a luminance of 64/π·0.2 cd/m², a background with a relative luminance
+/// of 20 and an average surround.
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "Wp",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Cam02Ucs<Wp = D65, T = f32>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    /// J' is the lightness of the color. 0.0 gives absolute black and 100.0
+    /// gives the white of the white point.
+    pub j: T,
+
+    /// a' goes from green, when negative, to red, when positive.
+    pub a: T,
+
+    /// b' goes from blue, when negative, to yellow, when positive.
+    pub b: T,
+
+    /// The white point associated with the color's illuminant and observer.
+    /// D65 for 2 degree observer is used by default.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> Copy for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+}
+
+impl<Wp, T> Clone for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn clone(&self) -> Cam02Ucs<Wp, T> {
+        *self
+    }
+}
+
+impl<T> Cam02Ucs<D65, T>
+where
+    T: FloatComponent,
+{
+    /// CAM02-UCS with white point D65.
+    pub fn new(j: T, a: T, b: T) -> Cam02Ucs<D65, T> {
+        Cam02Ucs {
+            j,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    /// CAM02-UCS.
+    pub fn with_wp(j: T, a: T, b: T) -> Cam02Ucs<Wp, T> {
+        Cam02Ucs {
+            j,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+
+    /// Convert to a `(J', a', b')` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.j, self.a, self.b)
+    }
+
+    /// Convert from a `(J', a', b')` tuple.
+    pub fn from_components((j, a, b): (T, T, T)) -> Self {
+        Self::with_wp(j, a, b)
+    }
+
+    /// Return the `j` value minimum.
+    pub fn min_j() -> T {
+        T::zero()
+    }
+
+    /// Return the `j` value maximum.
+    pub fn max_j() -> T {
+        from_f64(100.0)
+    }
+}
+
+impl<Wp, T> PartialEq for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + PartialEq,
+    Wp: WhitePoint,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.j == other.j && self.a == other.a && self.b == other.b
+    }
+}
+
+impl<Wp, T> Eq for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + Eq,
+    Wp: WhitePoint,
+{
+}
+
+///<span id="Cam02Ucsa"></span>[`Cam02Ucsa`](crate::Cam02Ucsa) implementations.
+impl<T, A> Alpha<Cam02Ucs<D65, T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// CAM02-UCS and transparency and white point D65.
+    pub fn new(j: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Cam02Ucs::new(j, a, b),
+            alpha,
+        }
+    }
+}
+
+///<span id="Cam02Ucsa"></span>[`Cam02Ucsa`](crate::Cam02Ucsa) implementations.
+impl<Wp, T, A> Alpha<Cam02Ucs<Wp, T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+    Wp: WhitePoint,
+{
+    /// CAM02-UCS and transparency.
+    pub fn with_wp(j: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Cam02Ucs::with_wp(j, a, b),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(J', a', b', alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.j, self.a, self.b, self.alpha)
+    }
+
+    /// Convert from a `(J', a', b', alpha)` tuple.
+    pub fn from_components((j, a, b, alpha): (T, T, T, A)) -> Self {
+        Self::with_wp(j, a, b, alpha)
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam02Ucs<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Cam02Ucs<Wp, T>) -> Self {
+        color
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Xyz<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        let correlates = ViewingConditions::ucs_default(Model::Ciecam02).correlates(color);
+        Self::from_components(ucs_from_correlates(
+            correlates.lightness,
+            correlates.colorfulness,
+            correlates.hue,
+        ))
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam02Ucs<Wp, T>> for Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Cam02Ucs<Wp, T>) -> Self {
+        let (lightness, colorfulness, hue) = correlates_from_ucs(color.j, color.a, color.b);
+
+        let conditions = ViewingConditions::ucs_default(Model::Ciecam02);
+        conditions.color_from_correlates(lightness, conditions.chroma(colorfulness), hue)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<(T, T, T)> for Cam02Ucs<Wp, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> Into<(T, T, T)> for Cam02Ucs<Wp, T> {
+    fn into(self) -> (T, T, T) {
+        self.into_components()
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent, A: Component> From<(T, T, T, A)>
+    for Alpha<Cam02Ucs<Wp, T>, A>
+{
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, T, A)>
+    for Alpha<Cam02Ucs<Wp, T>, A>
+{
+    fn into(self) -> (T, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<Wp, T> Clamp for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn is_within_bounds(&self) -> bool {
+        self.j >= T::zero() && self.j <= from_f64(100.0)
+    }
+
+    fn clamp(&self) -> Cam02Ucs<Wp, T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.j = clamp(self.j, T::zero(), from_f64(100.0));
+    }
+}
+
+impl<Wp, T> Mix for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Cam02Ucs<Wp, T>, factor: T) -> Cam02Ucs<Wp, T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Cam02Ucs {
+            j: self.j + factor * (other.j - self.j),
+            a: self.a + factor * (other.a - self.a),
+            b: self.b + factor * (other.b - self.b),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Shade for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Cam02Ucs<Wp, T> {
+        let difference = if factor >= T::zero() {
+            from_f64::<T>(100.0) - self.j
+        } else {
+            self.j
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Cam02Ucs {
+            j: (self.j + delta).max(T::zero()),
+            a: self.a,
+            b: self.b,
+            white_point: PhantomData,
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Cam02Ucs<Wp, T> {
+        Cam02Ucs {
+            j: (self.j + from_f64::<T>(100.0) * amount).max(T::zero()),
+            a: self.a,
+            b: self.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> GetHue for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Hue = LabHue<T>;
+
+    fn get_hue(&self) -> Option<LabHue<T>> {
+        if self.a == T::zero() && self.b == T::zero() {
+            None
+        } else {
+            Some(LabHue::from_radians(atan2(self.b, self.a)))
+        }
+    }
+}
+
+impl<Wp, T> ColorDifference for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    /// The Euclidean distance between the two colors, ΔE'.
+    fn get_color_difference(&self, other: &Cam02Ucs<Wp, T>) -> Self::Scalar {
+        let j = self.j - other.j;
+        let a = self.a - other.a;
+        let b = self.b - other.b;
+
+        (j * j + a * a + b * b).sqrt()
+    }
+}
+
+impl<Wp, T> ComponentWise for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn component_wise<F: FnMut(T, T) -> T>(
+        &self,
+        other: &Cam02Ucs<Wp, T>,
+        mut f: F,
+    ) -> Cam02Ucs<Wp, T> {
+        Cam02Ucs {
+            j: f(self.j, other.j),
+            a: f(self.a, other.a),
+            b: f(self.b, other.b),
+            white_point: PhantomData,
+        }
+    }
+
+    fn component_wise_self<F: FnMut(T) -> T>(&self, mut f: F) -> Cam02Ucs<Wp, T> {
+        Cam02Ucs {
+            j: f(self.j),
+            a: f(self.a),
+            b: f(self.b),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Default for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn default() -> Cam02Ucs<Wp, T> {
+        Cam02Ucs::with_wp(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<Wp, T> Add<Cam02Ucs<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam02Ucs<Wp, T>;
+
+    fn add(self, other: Cam02Ucs<Wp, T>) -> Self::Output {
+        Cam02Ucs {
+            j: self.j + other.j,
+            a: self.a + other.a,
+            b: self.b + other.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Add<T> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam02Ucs<Wp, T>;
+
+    fn add(self, c: T) -> Self::Output {
+        Cam02Ucs {
+            j: self.j + c,
+            a: self.a + c,
+            b: self.b + c,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> AddAssign<Cam02Ucs<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + AddAssign,
+    Wp: WhitePoint,
+{
+    fn add_assign(&mut self, other: Cam02Ucs<Wp, T>) {
+        self.j += other.j;
+        self.a += other.a;
+        self.b += other.b;
+    }
+}
+
+impl<Wp, T> AddAssign<T> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + AddAssign,
+    Wp: WhitePoint,
+{
+    fn add_assign(&mut self, c: T) {
+        self.j += c;
+        self.a += c;
+        self.b += c;
+    }
+}
+
+impl<Wp, T> Sub<Cam02Ucs<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam02Ucs<Wp, T>;
+
+    fn sub(self, other: Cam02Ucs<Wp, T>) -> Self::Output {
+        Cam02Ucs {
+            j: self.j - other.j,
+            a: self.a - other.a,
+            b: self.b - other.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Sub<T> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam02Ucs<Wp, T>;
+
+    fn sub(self, c: T) -> Self::Output {
+        Cam02Ucs {
+            j: self.j - c,
+            a: self.a - c,
+            b: self.b - c,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> SubAssign<Cam02Ucs<Wp, T>> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + SubAssign,
+    Wp: WhitePoint,
+{
+    fn sub_assign(&mut self, other: Cam02Ucs<Wp, T>) {
+        self.j -= other.j;
+        self.a -= other.a;
+        self.b -= other.b;
+    }
+}
+
+impl<Wp, T> SubAssign<T> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent + SubAssign,
+    Wp: WhitePoint,
+{
+    fn sub_assign(&mut self, c: T) {
+        self.j -= c;
+        self.a -= c;
+        self.b -= c;
+    }
+}
+
+impl<Wp, T, P> AsRef<P> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<Wp, T, P> AsMut<P> for Cam02Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Zeroable for Cam02Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent + bytemuck::Zeroable,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Pod for Cam02Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent + bytemuck::Pod,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cam02Ucs;
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{ColorDifference, LinSrgb, Srgb, Xyz};
+
+    #[test]
+    fn white_and_black() {
+        let white = Cam02Ucs::from_color_unclamped(Xyz::<D65, f64>::with_wp(0.95047, 1.0, 1.08883));
+        assert_relative_eq!(white.j, 100.0, epsilon = 1e-9);
+        assert!(white.a.hypot(white.b) < 3.0);
+
+        let black = Cam02Ucs::from_color_unclamped(Xyz::<D65, f64>::with_wp(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Cam02Ucs::new(0.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.9, 0.9, 0.1),
+            (0.5, 0.5, 0.5),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let ucs = Cam02Ucs::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(ucs), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn lightness_mapping() {
+        // J = 41.73 from the CIECAM02 worked example in CIE 159.
+        let lightness: f64 = 41.73109113;
+        let j = 1.7 * lightness / (1.0 + 0.007 * lightness);
+        assert_relative_eq!(j, 54.90433134, epsilon = 1e-6);
+
+        let mut previous = 0.0;
+        for &luminance in &[0.01, 0.05, 0.2, 0.5, 0.9] {
+            let gray: Xyz<D65, f64> = D65::get_xyz() * luminance;
+            let ucs = Cam02Ucs::from_color_unclamped(gray);
+            assert!(ucs.j > previous && ucs.j < 100.0);
+            previous = ucs.j;
+        }
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = Cam02Ucs::from_color_unclamped(Srgb::<f64>::new(0.5, 0.3, 0.2).into_linear());
+        let b = Cam02Ucs::from_color_unclamped(Srgb::<f64>::new(0.5, 0.3, 0.25).into_linear());
+        let difference = a.get_color_difference(&b);
+
+        assert_relative_eq!(difference, (a - b).j.hypot((a - b).a.hypot((a - b).b)));
+        assert!(difference > 1.0 && difference < 10.0);
+        assert_eq!(a.get_color_difference(&a), 0.0);
+    }
+}
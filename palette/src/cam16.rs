@@ -0,0 +1,343 @@
+//! The CAM16 color appearance model.
+//!
+//! A color appearance model predicts how a color looks under a set of
+//! viewing conditions, such as the brightness of the room and the color of
+//! the background. CAM16 describes the appearance with a number of
+//! correlates, which are collected in [`Cam16`](struct.Cam16.html):
+//!
+//! * The lightness, `J`, and the brightness, `Q`.
+//! * The chroma, `C`, the colorfulness, `M`, and the saturation, `s`.
+//! * The hue angle, `h`.
+//!
+//! The viewing conditions are described by [`Parameters`](struct.Parameters.html),
+//! which are baked into [`BakedParameters`](struct.BakedParameters.html) once,
+//! and then used for converting any number of colors:
+//!
+//! ```
+//! use palette::cam16::{Cam16, Parameters, Surround};
+//! use palette::white_point::D65;
+//! use palette::{FromColor, Srgb, Xyz};
+//!
+//! let parameters = Parameters {
+//!     adapting_luminance: 40.0,
+//!     surround: Surround::Dim,
+//!     ..Parameters::<D65, f64>::default()
+//! }
+//! .bake();
+//!
+//! let xyz = Xyz::from_color(Srgb::new(0.8f64, 0.3, 0.1).into_linear());
+//! let cam16 = Cam16::from_xyz(xyz, &parameters);
+//! assert!(cam16.hue > 20.0 && cam16.hue < 60.0);
+//!
+//! let back = cam16.into_xyz(&parameters);
+//! assert!((back.y - xyz.y).abs() < 1e-9);
+//! ```
+//!
+//! [`Cam16Ucs`](crate::Cam16Ucs) is the uniform color space that is derived
+//! from CAM16, which is useful for measuring color differences and for
+//! mixing colors. It's a regular color type, that uses the default
+//! parameters, and it can also be converted to and from `Cam16` with other
+//! parameters.
+
+use core::f64::consts::PI;
+
+use crate::cam::{correlates_from_ucs, ucs_from_correlates, Model, ViewingConditions};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, Cam16Ucs, FloatComponent, Xyz};
+
+/// The surround of the viewing field, which is the area outside the
+/// background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surround {
+    /// An average surround, such as when viewing surface colors in daylight.
+    Average,
+    /// A dim surround, such as when watching television in a dim room.
+    Dim,
+    /// A dark surround, such as when viewing projected images in a dark room.
+    Dark,
+}
+
+impl Surround {
+    fn factors(self) -> crate::cam::Surround {
+        match self {
+            Surround::Average => crate::cam::Surround::AVERAGE,
+            Surround::Dim => crate::cam::Surround::DIM,
+            Surround::Dark => crate::cam::Surround::DARK,
+        }
+    }
+}
+
+/// The viewing conditions of CAM16.
+///
+/// The default conditions are the same as for
+/// [`Cam16Ucs`](crate::Cam16Ucs): the white point `Wp` as the adopted white,
+/// an adapting field with a luminance of 64/π·0.2 cd/m², which is 20% of the
+/// luminance of a white surface lit by 64 lux, a background with a relative
+/// luminance of 20 and an average surround.
+#[derive(Debug)]
+pub struct Parameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    /// The adopted white. Only its chromaticity matters, since the colors
+    /// are given relative to its luminance.
+    pub white_point: Xyz<Wp, T>,
+
+    /// The luminance of the adapting field, in cd/m². It's often 20% of the
+    /// luminance of a white object in the scene.
+    pub adapting_luminance: T,
+
+    /// The relative luminance of the background, where `100.0` is the
+    /// luminance of the white point.
+    pub background_luminance: T,
+
+    /// The surround of the viewing field.
+    pub surround: Surround,
+
+    /// Set to `true` if the illuminant is discounted, which means that the
+    /// viewer is fully adapted to the white point.
+    pub discounting: bool,
+}
+
+impl<Wp, T> Copy for Parameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+}
+
+impl<Wp, T> Clone for Parameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn clone(&self) -> Parameters<Wp, T> {
+        *self
+    }
+}
+
+impl<Wp, T> Parameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    /// Precompute the values that the conversions need.
+    pub fn bake(self) -> BakedParameters<Wp, T> {
+        BakedParameters {
+            conditions: ViewingConditions::with_discounting(
+                Model::Cam16,
+                self.white_point,
+                self.adapting_luminance,
+                self.background_luminance,
+                self.surround.factors(),
+                self.discounting,
+            ),
+        }
+    }
+}
+
+impl<Wp, T> Default for Parameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        Parameters {
+            white_point: Wp::get_xyz(),
+            adapting_luminance: from_f64(64.0 / PI * 0.2),
+            background_luminance: from_f64(20.0),
+            surround: Surround::Average,
+            discounting: false,
+        }
+    }
+}
+
+/// Viewing conditions with precomputed values, for converting colors with
+/// [`Cam16`](struct.Cam16.html).
+pub struct BakedParameters<Wp, T> {
+    conditions: ViewingConditions<Wp, T>,
+}
+
+impl<Wp, T> From<Parameters<Wp, T>> for BakedParameters<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from(parameters: Parameters<Wp, T>) -> Self {
+        parameters.bake()
+    }
+}
+
+/// The CAM16 appearance correlates of a color.
+///
+/// The correlates depend on each other, so changing one of them without
+/// updating the others gives an inconsistent set. The conversion back to
+/// XYZ uses the lightness, the chroma and the hue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cam16<T> {
+    /// The lightness, `J`, which is `100.0` for the white point.
+    pub lightness: T,
+
+    /// The chroma, `C`, which is the colorfulness relative to the brightness
+    /// of the white point.
+    pub chroma: T,
+
+    /// The hue angle, `h`, in degrees, from `0.0` to `360.0`.
+    pub hue: T,
+
+    /// The brightness, `Q`, which is the absolute counterpart to the
+    /// lightness.
+    pub brightness: T,
+
+    /// The colorfulness, `M`, which is the absolute counterpart to the
+    /// chroma.
+    pub colorfulness: T,
+
+    /// The saturation, `s`, which is the colorfulness relative to the
+    /// brightness of the color itself.
+    pub saturation: T,
+}
+
+impl<T: FloatComponent> Cam16<T> {
+    /// Find the appearance of `color` under the viewing conditions in
+    /// `parameters`.
+    pub fn from_xyz<Wp: WhitePoint>(
+        color: Xyz<Wp, T>,
+        parameters: &BakedParameters<Wp, T>,
+    ) -> Self {
+        let conditions = &parameters.conditions;
+        let correlates = conditions.correlates(color);
+
+        Self::from_correlates(
+            conditions,
+            correlates.lightness,
+            correlates.chroma,
+            correlates.hue,
+        )
+    }
+
+    /// Find the color that has this appearance under the viewing conditions
+    /// in `parameters`. Only the lightness, the chroma and the hue are used.
+    pub fn into_xyz<Wp: WhitePoint>(self, parameters: &BakedParameters<Wp, T>) -> Xyz<Wp, T> {
+        parameters.conditions.color_from_correlates(
+            self.lightness,
+            self.chroma,
+            self.hue.to_radians(),
+        )
+    }
+
+    /// Convert `color` from CAM16-UCS, with the viewing conditions in
+    /// `parameters`.
+    pub fn from_ucs<Wp: WhitePoint>(
+        color: Cam16Ucs<Wp, T>,
+        parameters: &BakedParameters<Wp, T>,
+    ) -> Self {
+        let conditions = &parameters.conditions;
+        let (lightness, colorfulness, hue) = correlates_from_ucs(color.j, color.a, color.b);
+
+        Self::from_correlates(conditions, lightness, conditions.chroma(colorfulness), hue)
+    }
+
+    /// Convert into CAM16-UCS, using the lightness, the colorfulness and the
+    /// hue.
+    pub fn into_ucs<Wp: WhitePoint>(self) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs::from_components(ucs_from_correlates(
+            self.lightness,
+            self.colorfulness,
+            self.hue.to_radians(),
+        ))
+    }
+
+    /// Fill in the other correlates from the lightness, the chroma and the
+    /// hue angle, in radians.
+    fn from_correlates<Wp: WhitePoint>(
+        conditions: &ViewingConditions<Wp, T>,
+        lightness: T,
+        chroma: T,
+        hue: T,
+    ) -> Self {
+        let brightness = conditions.brightness(lightness);
+        let colorfulness = conditions.colorfulness(chroma);
+        let saturation = if brightness > T::zero() {
+            from_f64::<T>(100.0) * (colorfulness / brightness).sqrt()
+        } else {
+            T::zero()
+        };
+
+        let mut hue = hue.to_degrees();
+        if hue < T::zero() {
+            hue = hue + from_f64(360.0);
+        }
+
+        Cam16 {
+            lightness,
+            chroma,
+            hue,
+            brightness,
+            colorfulness,
+            saturation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cam16, Parameters, Surround};
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::D65;
+    use crate::{Cam16Ucs, LinSrgb, Xyz};
+
+    #[test]
+    fn reference_values() {
+        let parameters = Parameters {
+            white_point: Xyz::<D65, f64>::with_wp(0.9505, 1.0, 1.0888),
+            adapting_luminance: 318.31,
+            background_luminance: 20.0,
+            surround: Surround::Average,
+            discounting: false,
+        }
+        .bake();
+
+        let cam16 = Cam16::from_xyz(Xyz::with_wp(0.1901, 0.2, 0.2178), &parameters);
+        assert_relative_eq!(cam16.lightness, 41.73120791, epsilon = 1e-3);
+        assert_relative_eq!(cam16.chroma, 0.10335574, epsilon = 1e-3);
+        assert_relative_eq!(cam16.hue, 217.06795977, epsilon = 1e-2);
+        assert_relative_eq!(cam16.brightness, 195.37170899, epsilon = 1e-2);
+        assert_relative_eq!(cam16.colorfulness, 0.10743677, epsilon = 1e-3);
+        assert_relative_eq!(cam16.saturation, 2.34501507, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &surround in &[Surround::Average, Surround::Dim, Surround::Dark] {
+            for &discounting in &[false, true] {
+                let parameters = Parameters {
+                    surround,
+                    discounting,
+                    ..Parameters::<D65, f64>::default()
+                }
+                .bake();
+
+                let color: Xyz<D65, f64> = Xyz::from_color_unclamped(LinSrgb::new(0.2, 0.6, 0.9));
+                let cam16 = Cam16::from_xyz(color, &parameters);
+                assert_relative_eq!(cam16.into_xyz(&parameters), color, epsilon = 1e-9);
+
+                let ucs: Cam16Ucs<D65, f64> = cam16.into_ucs();
+                let back = Cam16::from_ucs(ucs, &parameters);
+                assert_relative_eq!(back.lightness, cam16.lightness, epsilon = 1e-9);
+                assert_relative_eq!(back.chroma, cam16.chroma, epsilon = 1e-9);
+                assert_relative_eq!(back.hue, cam16.hue, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn default_matches_ucs() {
+        let parameters = Parameters::<D65, f64>::default().bake();
+        let color: Xyz<D65, f64> = Xyz::from_color_unclamped(LinSrgb::new(0.7, 0.2, 0.4));
+
+        let ucs: Cam16Ucs<D65, f64> = Cam16::from_xyz(color, &parameters).into_ucs();
+        assert_relative_eq!(ucs, Cam16Ucs::from_color_unclamped(color), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,621 @@
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::cam::{correlates_from_ucs, ucs_from_correlates, Model, ViewingConditions};
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::math::atan2;
+use crate::white_point::{WhitePoint, D65};
+use crate::{
+    clamp, from_f64, Alpha, Clamp, ColorDifference, Component, ComponentWise, FloatComponent,
+    GetHue, LabHue, Mix, Pixel, Shade, Xyz,
+};
+
+/// CAM16-UCS with an alpha component. See the [`Cam16Ucsa`
+/// implementation in `Alpha`](crate::Alpha#Cam16Ucsa).
+pub type Cam16Ucsa<Wp = D65, T = f32> = Alpha<Cam16Ucs<Wp, T>, T>;
+
+/// The CAM16-UCS color space, J'a'b'.
+///
+/// CAM16-UCS is a uniform color space, derived from the CAM16 color
+/// appearance model in the same way as [`Cam02Ucs`](crate::Cam02Ucs) is
+/// derived from CIECAM02. CAM16 fixes some of the numerical problems of
+/// CIECAM02, such as negative cone responses for saturated blue colors. The
+/// Euclidean distance between two colors is a good measure of how different
+/// they look, and mixing colors gives perceptually even steps.
+///
+/// The colors are converted with the default viewing conditions of
+/// CAM16-UCS: the white point `Wp` as the adopted white, an adapting field
+/// with a luminance of 64/π·0.2 cd/m², a background with a relative luminance
+/// of 20 and an average surround. See the [`cam16`](crate::cam16) module for
+/// converting with other viewing conditions.
+///
+/// ```
+/// use palette::{FromColor, Cam16Ucs, LinSrgb, Mix};
+///
+/// let red = Cam16Ucs::from_color(LinSrgb::new(1.0f64, 0.0, 0.0));
+/// let blue = Cam16Ucs::from_color(LinSrgb::new(0.0f64, 0.0, 1.0));
+/// let purple = LinSrgb::from_color(red.mix(&blue, 0.5));
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "Wp",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Cam16Ucs<Wp = D65, T = f32>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    /// J' is the lightness of the color. 0.0 gives absolute black and 100.0
+    /// gives the white of the white point.
+    pub j: T,
+
+    /// a' goes from green, when negative, to red, when positive.
+    pub a: T,
+
+    /// b' goes from blue, when negative, to yellow, when positive.
+    pub b: T,
+
+    /// The white point associated with the color's illuminant and observer.
+    /// D65 for 2 degree observer is used by default.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> Copy for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+}
+
+impl<Wp, T> Clone for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn clone(&self) -> Cam16Ucs<Wp, T> {
+        *self
+    }
+}
+
+impl<T> Cam16Ucs<D65, T>
+where
+    T: FloatComponent,
+{
+    /// CAM16-UCS with white point D65.
+    pub fn new(j: T, a: T, b: T) -> Cam16Ucs<D65, T> {
+        Cam16Ucs {
+            j,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    /// CAM16-UCS.
+    pub fn with_wp(j: T, a: T, b: T) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs {
+            j,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+
+    /// Convert to a `(J', a', b')` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.j, self.a, self.b)
+    }
+
+    /// Convert from a `(J', a', b')` tuple.
+    pub fn from_components((j, a, b): (T, T, T)) -> Self {
+        Self::with_wp(j, a, b)
+    }
+
+    /// Return the `j` value minimum.
+    pub fn min_j() -> T {
+        T::zero()
+    }
+
+    /// Return the `j` value maximum.
+    pub fn max_j() -> T {
+        from_f64(100.0)
+    }
+}
+
+impl<Wp, T> PartialEq for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + PartialEq,
+    Wp: WhitePoint,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.j == other.j && self.a == other.a && self.b == other.b
+    }
+}
+
+impl<Wp, T> Eq for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + Eq,
+    Wp: WhitePoint,
+{
+}
+
+///<span id="Cam16Ucsa"></span>[`Cam16Ucsa`](crate::Cam16Ucsa) implementations.
+impl<T, A> Alpha<Cam16Ucs<D65, T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// CAM16-UCS and transparency and white point D65.
+    pub fn new(j: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Cam16Ucs::new(j, a, b),
+            alpha,
+        }
+    }
+}
+
+///<span id="Cam16Ucsa"></span>[`Cam16Ucsa`](crate::Cam16Ucsa) implementations.
+impl<Wp, T, A> Alpha<Cam16Ucs<Wp, T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+    Wp: WhitePoint,
+{
+    /// CAM16-UCS and transparency.
+    pub fn with_wp(j: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Cam16Ucs::with_wp(j, a, b),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(J', a', b', alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.j, self.a, self.b, self.alpha)
+    }
+
+    /// Convert from a `(J', a', b', alpha)` tuple.
+    pub fn from_components((j, a, b, alpha): (T, T, T, A)) -> Self {
+        Self::with_wp(j, a, b, alpha)
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Cam16Ucs<Wp, T>) -> Self {
+        color
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Xyz<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        let correlates = ViewingConditions::ucs_default(Model::Cam16).correlates(color);
+        Self::from_components(ucs_from_correlates(
+            correlates.lightness,
+            correlates.colorfulness,
+            correlates.hue,
+        ))
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16Ucs<Wp, T>> for Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Cam16Ucs<Wp, T>) -> Self {
+        let (lightness, colorfulness, hue) = correlates_from_ucs(color.j, color.a, color.b);
+
+        let conditions = ViewingConditions::ucs_default(Model::Cam16);
+        conditions.color_from_correlates(lightness, conditions.chroma(colorfulness), hue)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<(T, T, T)> for Cam16Ucs<Wp, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> Into<(T, T, T)> for Cam16Ucs<Wp, T> {
+    fn into(self) -> (T, T, T) {
+        self.into_components()
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent, A: Component> From<(T, T, T, A)>
+    for Alpha<Cam16Ucs<Wp, T>, A>
+{
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, T, A)>
+    for Alpha<Cam16Ucs<Wp, T>, A>
+{
+    fn into(self) -> (T, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<Wp, T> Clamp for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn is_within_bounds(&self) -> bool {
+        self.j >= T::zero() && self.j <= from_f64(100.0)
+    }
+
+    fn clamp(&self) -> Cam16Ucs<Wp, T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.j = clamp(self.j, T::zero(), from_f64(100.0));
+    }
+}
+
+impl<Wp, T> Mix for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Cam16Ucs<Wp, T>, factor: T) -> Cam16Ucs<Wp, T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Cam16Ucs {
+            j: self.j + factor * (other.j - self.j),
+            a: self.a + factor * (other.a - self.a),
+            b: self.b + factor * (other.b - self.b),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Shade for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Cam16Ucs<Wp, T> {
+        let difference = if factor >= T::zero() {
+            from_f64::<T>(100.0) - self.j
+        } else {
+            self.j
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Cam16Ucs {
+            j: (self.j + delta).max(T::zero()),
+            a: self.a,
+            b: self.b,
+            white_point: PhantomData,
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs {
+            j: (self.j + from_f64::<T>(100.0) * amount).max(T::zero()),
+            a: self.a,
+            b: self.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> GetHue for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Hue = LabHue<T>;
+
+    fn get_hue(&self) -> Option<LabHue<T>> {
+        if self.a == T::zero() && self.b == T::zero() {
+            None
+        } else {
+            Some(LabHue::from_radians(atan2(self.b, self.a)))
+        }
+    }
+}
+
+impl<Wp, T> ColorDifference for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    /// The Euclidean distance between the two colors, ΔE'.
+    fn get_color_difference(&self, other: &Cam16Ucs<Wp, T>) -> Self::Scalar {
+        let j = self.j - other.j;
+        let a = self.a - other.a;
+        let b = self.b - other.b;
+
+        (j * j + a * a + b * b).sqrt()
+    }
+}
+
+impl<Wp, T> ComponentWise for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn component_wise<F: FnMut(T, T) -> T>(
+        &self,
+        other: &Cam16Ucs<Wp, T>,
+        mut f: F,
+    ) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs {
+            j: f(self.j, other.j),
+            a: f(self.a, other.a),
+            b: f(self.b, other.b),
+            white_point: PhantomData,
+        }
+    }
+
+    fn component_wise_self<F: FnMut(T) -> T>(&self, mut f: F) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs {
+            j: f(self.j),
+            a: f(self.a),
+            b: f(self.b),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Default for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn default() -> Cam16Ucs<Wp, T> {
+        Cam16Ucs::with_wp(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<Wp, T> Add<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam16Ucs<Wp, T>;
+
+    fn add(self, other: Cam16Ucs<Wp, T>) -> Self::Output {
+        Cam16Ucs {
+            j: self.j + other.j,
+            a: self.a + other.a,
+            b: self.b + other.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Add<T> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam16Ucs<Wp, T>;
+
+    fn add(self, c: T) -> Self::Output {
+        Cam16Ucs {
+            j: self.j + c,
+            a: self.a + c,
+            b: self.b + c,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> AddAssign<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + AddAssign,
+    Wp: WhitePoint,
+{
+    fn add_assign(&mut self, other: Cam16Ucs<Wp, T>) {
+        self.j += other.j;
+        self.a += other.a;
+        self.b += other.b;
+    }
+}
+
+impl<Wp, T> AddAssign<T> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + AddAssign,
+    Wp: WhitePoint,
+{
+    fn add_assign(&mut self, c: T) {
+        self.j += c;
+        self.a += c;
+        self.b += c;
+    }
+}
+
+impl<Wp, T> Sub<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam16Ucs<Wp, T>;
+
+    fn sub(self, other: Cam16Ucs<Wp, T>) -> Self::Output {
+        Cam16Ucs {
+            j: self.j - other.j,
+            a: self.a - other.a,
+            b: self.b - other.b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Sub<T> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Cam16Ucs<Wp, T>;
+
+    fn sub(self, c: T) -> Self::Output {
+        Cam16Ucs {
+            j: self.j - c,
+            a: self.a - c,
+            b: self.b - c,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> SubAssign<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + SubAssign,
+    Wp: WhitePoint,
+{
+    fn sub_assign(&mut self, other: Cam16Ucs<Wp, T>) {
+        self.j -= other.j;
+        self.a -= other.a;
+        self.b -= other.b;
+    }
+}
+
+impl<Wp, T> SubAssign<T> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent + SubAssign,
+    Wp: WhitePoint,
+{
+    fn sub_assign(&mut self, c: T) {
+        self.j -= c;
+        self.a -= c;
+        self.b -= c;
+    }
+}
+
+impl<Wp, T, P> AsRef<P> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<Wp, T, P> AsMut<P> for Cam16Ucs<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Zeroable for Cam16Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent + bytemuck::Zeroable,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Pod for Cam16Ucs<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent + bytemuck::Pod,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cam16Ucs;
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{Cam02Ucs, ColorDifference, LinSrgb, Xyz};
+
+    #[test]
+    fn white_and_black() {
+        let white = Cam16Ucs::from_color_unclamped(Xyz::<D65, f64>::with_wp(0.95047, 1.0, 1.08883));
+        assert_relative_eq!(white.j, 100.0, epsilon = 1e-9);
+        assert!(white.a.hypot(white.b) < 3.0);
+
+        let black = Cam16Ucs::from_color_unclamped(Xyz::<D65, f64>::with_wp(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Cam16Ucs::new(0.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.9, 0.9, 0.1),
+            (0.5, 0.5, 0.5),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let ucs = Cam16Ucs::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(ucs), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn close_to_cam02_ucs() {
+        for &luminance in &[0.05, 0.2, 0.5, 0.9] {
+            let gray: Xyz<D65, f64> = D65::get_xyz() * luminance;
+            let cam16 = Cam16Ucs::from_color_unclamped(gray);
+            let cam02 = Cam02Ucs::from_color_unclamped(gray);
+            assert_relative_eq!(cam16.j, cam02.j, epsilon = 1e-3);
+        }
+
+        let color = LinSrgb::<f64>::new(0.8, 0.4, 0.2);
+        let cam16 = Cam16Ucs::from_color_unclamped(color);
+        let cam02 = Cam02Ucs::from_color_unclamped(color);
+        assert!((cam16.a - cam02.a).hypot(cam16.b - cam02.b) < 5.0);
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = Cam16Ucs::from_color_unclamped(LinSrgb::<f64>::new(0.2, 0.1, 0.05));
+        let b = Cam16Ucs::from_color_unclamped(LinSrgb::<f64>::new(0.2, 0.1, 0.07));
+        let difference = a.get_color_difference(&b);
+
+        assert!(difference > 1.0 && difference < 10.0);
+        assert_eq!(a.get_color_difference(&a), 0.0);
+    }
+}
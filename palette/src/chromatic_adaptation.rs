@@ -9,6 +9,14 @@
 //! This library provides three methods for chromatic adaptation Bradford (which
 //! is the default), VonKries and XyzScaling
 //!
+//! There are also two transforms that take the viewing conditions into
+//! account. [`Cmccat2000`](struct.Cmccat2000.html) adapts partially, depending
+//! on the luminance of the adapting fields, and is used with
+//! `adapt_from_using` and `adapt_into_using`, like the methods above.
+//! [`Ciecat94`](struct.Ciecat94.html) is a nonlinear transform that also
+//! depends on the illuminance and the background, and has its own `adapt`
+//! method.
+//!
 //! ```
 //! use palette::Xyz;
 //! use palette::white_point::{A, C};
@@ -26,7 +34,8 @@
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::float::Float;
 use crate::from_f64;
-use crate::matrix::{multiply_3x3, multiply_xyz, Mat3};
+use crate::math::{log10, powf};
+use crate::matrix::{matrix_inverse, multiply_3x3, multiply_xyz, Mat3};
 use crate::white_point::WhitePoint;
 use crate::{FloatComponent, Xyz};
 
@@ -64,26 +73,42 @@ where
     fn generate_transform_matrix(&self) -> Mat3<T> {
         let s_wp: Xyz<Swp, T> = Swp::get_xyz();
         let t_wp: Xyz<Dwp, T> = Dwp::get_xyz();
-        let adapt = self.get_cone_response();
+        adaptation_matrix(&self.get_cone_response(), &s_wp, &t_wp)
+    }
+}
 
-        let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, &s_wp);
-        let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, &t_wp);
-        let z = T::zero();
-        let resp = [
-            resp_dst.x / resp_src.x,
-            z,
-            z,
-            z,
-            resp_dst.y / resp_src.y,
-            z,
-            z,
-            z,
-            resp_dst.z / resp_src.z,
-        ];
+/// Generates a 3x3 transformation matrix that adapts colors from the white
+/// point `s_wp` to `t_wp`, using the cone response matrices `adapt`.
+///
+/// This is the same as `TransformMatrix::generate_transform_matrix`, but with
+/// white points that are only known at runtime.
+pub(crate) fn adaptation_matrix<Swp, Dwp, T>(
+    adapt: &ConeResponseMatrices<T>,
+    s_wp: &Xyz<Swp, T>,
+    t_wp: &Xyz<Dwp, T>,
+) -> Mat3<T>
+where
+    T: FloatComponent,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, s_wp);
+    let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, t_wp);
+    let z = T::zero();
+    let resp = [
+        resp_dst.x / resp_src.x,
+        z,
+        z,
+        z,
+        resp_dst.y / resp_src.y,
+        z,
+        z,
+        z,
+        resp_dst.z / resp_src.z,
+    ];
 
-        let tmp = multiply_3x3(&resp, &adapt.ma);
-        multiply_3x3(&adapt.inv_ma, &tmp)
-    }
+    let tmp = multiply_3x3(&resp, &adapt.ma);
+    multiply_3x3(&adapt.inv_ma, &tmp)
 }
 
 impl<Swp, Dwp, T> TransformMatrix<Swp, Dwp, T> for Method
@@ -141,6 +166,239 @@ where
     }
 }
 
+/// The CMCCAT2000 chromatic adaptation transform.
+///
+/// The degree of adaptation depends on the luminance of the two adapting
+/// fields, in cd/m², and on the surround. The adaptation is complete when
+/// both fields are bright and the surround is average, and partial otherwise.
+///
+/// ```
+/// use palette::chromatic_adaptation::{AdaptInto, Cmccat2000};
+/// use palette::white_point::{A, D65};
+/// use palette::Xyz;
+///
+/// let color = Xyz::<A, f64>::with_wp(0.3, 0.25, 0.1);
+/// let adapted: Xyz<D65, f64> = color.adapt_into_using(Cmccat2000::new(200.0, 200.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cmccat2000<T = f32> {
+    /// The luminance of the source adapting field, in cd/m².
+    pub source_luminance: T,
+
+    /// The luminance of the destination adapting field, in cd/m².
+    pub destination_luminance: T,
+
+    /// The surround factor, which is `1.0` for an average surround and `0.8`
+    /// for a dim or dark surround.
+    pub surround: T,
+}
+
+impl<T: FloatComponent> Cmccat2000<T> {
+    /// Create a transform for adapting fields with the luminances
+    /// `source_luminance` and `destination_luminance`, in cd/m², in an
+    /// average surround.
+    pub fn new(source_luminance: T, destination_luminance: T) -> Self {
+        Cmccat2000 {
+            source_luminance,
+            destination_luminance,
+            surround: T::one(),
+        }
+    }
+
+    /// The degree of adaptation, from `0.0` for no adaptation to `1.0` for
+    /// complete adaptation.
+    pub fn degree_of_adaptation(&self) -> T {
+        let sum = self.source_luminance + self.destination_luminance;
+        let degree = self.surround
+            * (from_f64::<T>(0.08) * log10(sum / from_f64(2.0)) + from_f64(0.76)
+                - from_f64::<T>(0.45) * (self.source_luminance - self.destination_luminance) / sum);
+
+        degree.max(T::zero()).min(T::one())
+    }
+}
+
+impl<Swp, Dwp, T> TransformMatrix<Swp, Dwp, T> for Cmccat2000<T>
+where
+    T: FloatComponent,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    fn get_cone_response(&self) -> ConeResponseMatrices<T> {
+        cmccat2000_cone_response()
+    }
+
+    fn generate_transform_matrix(&self) -> Mat3<T> {
+        let adapt = cmccat2000_cone_response();
+        let resp_src: Xyz<Swp, T> = multiply_xyz(&adapt.ma, &Swp::get_xyz::<Swp, T>());
+        let resp_dst: Xyz<Dwp, T> = multiply_xyz(&adapt.ma, &Dwp::get_xyz::<Dwp, T>());
+
+        let degree = self.degree_of_adaptation();
+        let scale = |source: T, destination: T| degree * destination / source + T::one() - degree;
+        let z = T::zero();
+        let resp = [
+            scale(resp_src.x, resp_dst.x),
+            z,
+            z,
+            z,
+            scale(resp_src.y, resp_dst.y),
+            z,
+            z,
+            z,
+            scale(resp_src.z, resp_dst.z),
+        ];
+
+        let tmp = multiply_3x3(&resp, &adapt.ma);
+        multiply_3x3(&adapt.inv_ma, &tmp)
+    }
+}
+
+/// The cone response matrices of CMCCAT2000.
+#[rustfmt::skip]
+pub(crate) fn cmccat2000_cone_response<T: FloatComponent>() -> ConeResponseMatrices<T> {
+    let ma = [
+        from_f64(0.7982), from_f64(0.3389), from_f64(-0.1371),
+        from_f64(-0.5918), from_f64(1.5512), from_f64(0.0406),
+        from_f64(0.0008), from_f64(0.0239), from_f64(0.9753),
+    ];
+
+    ConeResponseMatrices {
+        inv_ma: matrix_inverse(&ma),
+        ma,
+    }
+}
+
+/// The CIE 1994 (CIECAT94) chromatic adaptation transform.
+///
+/// This nonlinear transform from CIE 109 takes the illuminance of the two
+/// scenes and the luminance factor of their achromatic background into
+/// account. It's meant for object colors, with a background luminance factor
+/// of at least `0.18`.
+///
+/// ```
+/// use palette::chromatic_adaptation::Ciecat94;
+/// use palette::white_point::{A, D65};
+/// use palette::Xyz;
+///
+/// let color = Xyz::<A, f64>::with_wp(0.28, 0.2126, 0.0527);
+/// let adapted: Xyz<D65, f64> = Ciecat94::new(0.2, 1000.0, 1000.0).adapt(color);
+/// assert!((adapted.x - 0.2404).abs() < 1e-4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ciecat94<T = f32> {
+    /// The luminance factor of the achromatic background, from `0.18` to
+    /// `1.0`.
+    pub background_luminance: T,
+
+    /// The illuminance of the source scene, in lux.
+    pub source_illuminance: T,
+
+    /// The illuminance of the destination scene, in lux.
+    pub destination_illuminance: T,
+
+    /// The noise term, which is `1.0` in CIE 109.
+    pub noise: T,
+}
+
+impl<T: FloatComponent> Ciecat94<T> {
+    /// Create a transform for scenes with the background luminance factor
+    /// `background_luminance` and the illuminances `source_illuminance` and
+    /// `destination_illuminance`, in lux.
+    pub fn new(background_luminance: T, source_illuminance: T, destination_illuminance: T) -> Self {
+        Ciecat94 {
+            background_luminance,
+            source_illuminance,
+            destination_illuminance,
+            noise: T::one(),
+        }
+    }
+
+    /// The relative cone responses of the white point `white`.
+    fn intermediate_values<Wp: WhitePoint>(white: &Xyz<Wp, T>) -> [T; 3] {
+        let sum = white.x + white.y + white.z;
+        let (x, y) = (white.x / sum, white.y / sum);
+
+        [
+            (from_f64::<T>(0.48105) * x + from_f64::<T>(0.78841) * y - from_f64(0.08081)) / y,
+            (from_f64::<T>(-0.27200) * x + from_f64::<T>(1.11962) * y + from_f64(0.04570)) / y,
+            from_f64::<T>(0.91822) * (T::one() - x - y) / y,
+        ]
+    }
+
+    /// The exponential factors of the cone responses in a scene with the
+    /// `illuminance`.
+    fn exponential_factors(&self, intermediate: &[T; 3], illuminance: T) -> [T; 3] {
+        let scale = from_f64::<T>(100.0) * self.background_luminance * illuminance
+            / from_f64(100.0 * core::f64::consts::PI);
+        let factor = |response: T, a: f64, b: f64, exponent: f64| {
+            let power = powf(response * scale, from_f64(exponent));
+            (from_f64::<T>(a) + from_f64::<T>(b) * power) / (from_f64::<T>(a) + power)
+        };
+
+        [
+            factor(intermediate[0], 6.469, 6.362, 0.4495),
+            factor(intermediate[1], 6.469, 6.362, 0.4495),
+            factor(intermediate[2], 8.414, 8.091, 0.5128) * from_f64(0.7844),
+        ]
+    }
+}
+
+impl<T: FloatComponent> Ciecat94<T> {
+    /// Adapt `color` from the white point `Swp` to `Dwp`.
+    ///
+    /// The transform is nonlinear, so it can't be expressed as a
+    /// [`TransformMatrix`](trait.TransformMatrix.html) and used with
+    /// `adapt_from_using` or `adapt_into_using`.
+    pub fn adapt<Swp, Dwp, C, D>(&self, color: C) -> D
+    where
+        Swp: WhitePoint,
+        Dwp: WhitePoint,
+        C: IntoColorUnclamped<Xyz<Swp, T>>,
+        D: FromColorUnclamped<Xyz<Dwp, T>>,
+    {
+        let color: Xyz<Swp, T> = color.into_color_unclamped();
+        let cone_response =
+            <Method as TransformMatrix<Swp, Dwp, T>>::get_cone_response(&Method::VonKries);
+        let hundred = from_f64::<T>(100.0);
+        let background = self.background_luminance * hundred;
+        let noise = self.noise;
+
+        let source = Self::intermediate_values(&Swp::get_xyz::<Swp, T>());
+        let destination = Self::intermediate_values(&Dwp::get_xyz::<Dwp, T>());
+        let source_factors = self.exponential_factors(&source, self.source_illuminance);
+        let destination_factors =
+            self.exponential_factors(&destination, self.destination_illuminance);
+
+        let ratio = |intermediate: T| {
+            (background * intermediate + noise) / (from_f64::<T>(20.0) * intermediate + noise)
+        };
+        let ratio_power = |intermediate: T, exponent: f64, factor: T| {
+            powf(ratio(intermediate), from_f64::<T>(exponent) * factor)
+        };
+        let k = ratio_power(source[0], 2.0 / 3.0, source_factors[0])
+            / ratio_power(destination[0], 2.0 / 3.0, destination_factors[0])
+            * ratio_power(source[1], 1.0 / 3.0, source_factors[1])
+            / ratio_power(destination[1], 1.0 / 3.0, destination_factors[1]);
+
+        let response: Xyz<Swp, T> = multiply_xyz(&cone_response.ma, &(color * hundred));
+        let response = [response.x, response.y, response.z];
+        let mut adapted = [T::zero(); 3];
+        for (i, adapted) in adapted.iter_mut().enumerate() {
+            let exponent = source_factors[i] / destination_factors[i];
+            *adapted = (background * destination[i] + noise)
+                * powf(k, T::one() / destination_factors[i])
+                * powf(
+                    (response[i] + noise) / (background * source[i] + noise),
+                    exponent,
+                )
+                - noise;
+        }
+
+        let adapted = Xyz::<Dwp, T>::with_wp(adapted[0], adapted[1], adapted[2]);
+        let adapted: Xyz<Dwp, T> = multiply_xyz(&cone_response.inv_ma, &adapted) / hundred;
+        D::from_color_unclamped(adapted)
+    }
+}
+
 /// Trait to convert color from one reference white point to another
 ///
 /// Converts a color from the source white point (Swp) to the destination white
@@ -211,8 +469,8 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
-    use crate::white_point::{A, C, D50, D65};
+    use super::{AdaptFrom, AdaptInto, Ciecat94, Cmccat2000, Method, TransformMatrix};
+    use crate::white_point::{D65Degree10, A, C, D50, D65};
     use crate::Xyz;
 
     #[test]
@@ -287,4 +545,47 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn cmccat2000() {
+        let input = Xyz::<A, f64>::with_wp(0.2248, 0.2274, 0.0854);
+
+        let output: Xyz<D65Degree10, f64> = input.adapt_into_using(Cmccat2000::new(200.0, 200.0));
+        assert_relative_eq!(
+            output,
+            Xyz::with_wp(0.197234358, 0.230172451, 0.247206512),
+            epsilon = 1e-8
+        );
+
+        let output: Xyz<D65Degree10, f64> = input.adapt_into_using(Cmccat2000::new(100.0, 500.0));
+        assert_relative_eq!(
+            output,
+            Xyz::with_wp(0.195601657, 0.230336662, 0.256790243),
+            epsilon = 1e-8
+        );
+
+        let none = Cmccat2000 {
+            source_luminance: 200.0,
+            destination_luminance: 200.0,
+            surround: 0.0,
+        };
+        let unchanged: Xyz<D65, f64> = input.adapt_into_using(none);
+        assert_relative_eq!(unchanged.x, input.x, epsilon = 1e-9);
+        assert_relative_eq!(unchanged.y, input.y, epsilon = 1e-9);
+        assert_relative_eq!(unchanged.z, input.z, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn ciecat94() {
+        let input = Xyz::<A, f64>::with_wp(0.28, 0.2126, 0.0527);
+        let output: Xyz<D65, f64> = Ciecat94::new(0.2, 1000.0, 1000.0).adapt(input);
+        assert_relative_eq!(
+            output,
+            Xyz::with_wp(0.240381683, 0.211562201, 0.176416281),
+            epsilon = 1e-7
+        );
+
+        let unchanged: Xyz<A, f64> = Ciecat94::new(0.2, 1000.0, 1000.0).adapt(input);
+        assert_relative_eq!(unchanged, input, epsilon = 1e-7);
+    }
 }
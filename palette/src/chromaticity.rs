@@ -0,0 +1,439 @@
+//! Helpers for drawing chromaticity diagrams.
+//!
+//! A chromaticity diagram shows all colors that the eye can see, without
+//! their luminance. The CIE 1931 xy diagram is the classic one, while the CIE
+//! 1976 u'v' diagram distributes the colors more evenly. The spectral locus,
+//! which is the curved outline of the diagram, is where the colors of single
+//! wavelengths are. The straight line between its ends is the line of
+//! purples.
+//!
+//! The colors inside the diagram can't all be shown on a screen, so
+//! [`xy_to_display_color`](fn.xy_to_display_color.html) approximates them
+//! with colors in sRGB. The same goes for the colors of single wavelengths,
+//! which [`wavelength_to_display_color`](fn.wavelength_to_display_color.html)
+//! renders.
+//!
+//! The module also has the Planckian locus, where the colors of black body
+//! radiators are, and the CIE daylight locus. They are handy for drawing the
+//! white point tracks of calibration tools.
+//!
+//! ```
+//! use palette::chromaticity::{spectral_locus_xy, xy_to_display_color};
+//!
+//! // The outline of the diagram, every 5 nm.
+//! let outline: Vec<(f64, f64)> = spectral_locus_xy(5.0).collect();
+//! assert_eq!(outline.len(), 65);
+//!
+//! // The color of a point in the green corner.
+//! let green = xy_to_display_color(0.2, 0.7);
+//! assert!(green.green > green.red && green.green > green.blue);
+//! ```
+
+use crate::cmf;
+use crate::convert::FromColorUnclamped;
+use crate::white_point::{WhitePoint, D65};
+use crate::{from_f64, FloatComponent, LinSrgb, Srgb, Xyz};
+
+/// The shortest wavelength on the spectral locus, in nanometers.
+const LOCUS_START: f64 = 380.0;
+
+/// The longest wavelength on the spectral locus, in nanometers. The
+/// chromaticity is practically constant above it.
+const LOCUS_END: f64 = 700.0;
+
+const PLANCKIAN_MIN: f64 = 1000.0;
+const PLANCKIAN_MAX: f64 = 15000.0;
+const DAYLIGHT_MIN: f64 = 4000.0;
+const DAYLIGHT_MAX: f64 = 25000.0;
+
+/// Convert CIE 1931 xy coordinates to CIE 1976 u'v' coordinates.
+pub fn xy_to_uv<T: FloatComponent>(x: T, y: T) -> (T, T) {
+    let denominator = from_f64::<T>(-2.0) * x + from_f64::<T>(12.0) * y + from_f64(3.0);
+
+    (
+        from_f64::<T>(4.0) * x / denominator,
+        from_f64::<T>(9.0) * y / denominator,
+    )
+}
+
+/// Convert CIE 1976 u'v' coordinates to CIE 1931 xy coordinates.
+pub fn uv_to_xy<T: FloatComponent>(u: T, v: T) -> (T, T) {
+    let denominator = from_f64::<T>(6.0) * u - from_f64::<T>(16.0) * v + from_f64(12.0);
+
+    (
+        from_f64::<T>(9.0) * u / denominator,
+        from_f64::<T>(4.0) * v / denominator,
+    )
+}
+
+/// Get a displayable color for a point in the CIE 1931 xy diagram.
+///
+/// The chromaticity is converted to linear sRGB, where negative components
+/// are clipped to `0.0`, and then scaled so that the largest component is
+/// `1.0`. Colors inside the sRGB gamut keep their chromaticity, while colors
+/// outside it become the closest color the clipping gives. Points with a `y`
+/// of `0.0` or less are black.
+pub fn xy_to_display_color<T: FloatComponent>(x: T, y: T) -> Srgb<T> {
+    if y <= T::zero() {
+        return Srgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    let xyz = Xyz::<D65, T>::with_wp(x / y, T::one(), (T::one() - x - y) / y);
+    let linear = LinSrgb::from_color_unclamped(xyz);
+    let linear = LinSrgb::new(
+        linear.red.max(T::zero()),
+        linear.green.max(T::zero()),
+        linear.blue.max(T::zero()),
+    );
+
+    let max = linear.red.max(linear.green).max(linear.blue);
+    if max <= T::zero() {
+        return Srgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    Srgb::from_linear(linear / max)
+}
+
+/// Get a displayable color for a point in the CIE 1976 u'v' diagram.
+///
+/// See [`xy_to_display_color`](fn.xy_to_display_color.html) for how the color
+/// is calculated.
+pub fn uv_to_display_color<T: FloatComponent>(u: T, v: T) -> Srgb<T> {
+    let (x, y) = uv_to_xy(u, v);
+    xy_to_display_color(x, y)
+}
+
+/// The CIE 1931 xy coordinates of light with a single `wavelength`, in
+/// nanometers, for the 2° standard observer.
+///
+/// Wavelengths outside the 380 nm to 780 nm range are clamped to it.
+pub fn spectral_xy<T: FloatComponent>(wavelength: T) -> (T, T) {
+    let wavelength = wavelength
+        .to_f64()
+        .unwrap_or(LOCUS_START)
+        .clamp(cmf::START, cmf::END);
+    let [x, y, z] = cmf::cie_1931(wavelength);
+    let sum = x + y + z;
+
+    (from_f64(x / sum), from_f64(y / sum))
+}
+
+/// Get a displayable sRGB color for light with a single `wavelength`, in
+/// nanometers.
+///
+/// Spectral colors are more saturated than anything sRGB can show, so the
+/// color is desaturated with white in linear space, just enough to bring it
+/// into the gamut. This keeps its hue, unlike clipping. The result is then
+/// scaled so that its largest component is `1.0`, which means that colors
+/// that are hard to see, near the ends of the spectrum, are as bright as the
+/// others. Multiply the linear color with the luminance of
+/// [`Xyz::from_wavelength`](../struct.Xyz.html#method.from_wavelength) to
+/// fade them out instead.
+///
+/// Wavelengths outside the 380 nm to 780 nm range are black.
+pub fn wavelength_to_display_color<T: FloatComponent>(wavelength: T) -> Srgb<T> {
+    let xyz = Xyz::<D65, T>::from_wavelength(wavelength);
+    let linear = LinSrgb::from_color_unclamped(xyz);
+
+    let min = linear.red.min(linear.green).min(linear.blue).min(T::zero());
+    let linear = LinSrgb::new(linear.red - min, linear.green - min, linear.blue - min);
+
+    let max = linear.red.max(linear.green).max(linear.blue);
+    if max <= T::zero() {
+        return Srgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    Srgb::from_linear(linear / max)
+}
+
+/// The points of the spectral locus in the CIE 1931 xy diagram, from 380 nm
+/// to 700 nm in steps of `step` nanometers.
+///
+/// The last point is always at 700 nm, even if `step` doesn't divide the
+/// range evenly. Draw a line from the last point to the first to close the
+/// diagram with the line of purples.
+///
+/// Panics if `step` isn't positive.
+pub fn spectral_locus_xy<T: FloatComponent>(step: T) -> impl Iterator<Item = (T, T)> {
+    sample_range(from_f64(LOCUS_START), from_f64(LOCUS_END), step).map(spectral_xy)
+}
+
+/// The points of the spectral locus in the CIE 1976 u'v' diagram. See
+/// [`spectral_locus_xy`](fn.spectral_locus_xy.html) for details.
+///
+/// Panics if `step` isn't positive.
+pub fn spectral_locus_uv<T: FloatComponent>(step: T) -> impl Iterator<Item = (T, T)> {
+    spectral_locus_xy(step).map(|(x, y)| xy_to_uv(x, y))
+}
+
+/// Estimate the correlated color temperature, in kelvin, of a chromaticity in
+/// the CIE 1931 xy diagram.
+///
+/// This uses McCamy's approximation, which is accurate to a few kelvin
+/// between 2856 K and 6504 K, for chromaticities close to the Planckian
+/// locus.
+pub fn correlated_color_temperature<T: FloatComponent>(x: T, y: T) -> T {
+    let n = (x - from_f64(0.3320)) / (from_f64::<T>(0.1858) - y);
+
+    from_f64::<T>(449.0) * n * n * n
+        + from_f64::<T>(3525.0) * n * n
+        + from_f64::<T>(6823.3) * n
+        + from_f64(5520.33)
+}
+
+/// The CIE 1931 xy coordinates of a black body radiator with the
+/// `temperature`, in kelvin.
+///
+/// This uses Krystek's approximation, which is valid from 1000 K to 15000 K.
+/// Temperatures outside that range are clamped to it.
+pub fn planckian_xy<T: FloatComponent>(temperature: T) -> (T, T) {
+    let temperature = temperature
+        .max(from_f64(PLANCKIAN_MIN))
+        .min(from_f64(PLANCKIAN_MAX));
+    let (u, v) = planckian_uv_1960(temperature);
+    let denominator = from_f64::<T>(2.0) * u - from_f64::<T>(8.0) * v + from_f64(4.0);
+
+    (
+        from_f64::<T>(3.0) * u / denominator,
+        from_f64::<T>(2.0) * v / denominator,
+    )
+}
+
+/// The CIE 1931 xy coordinates of the CIE daylight illuminant with the
+/// correlated color `temperature`, in kelvin.
+///
+/// The daylight locus is defined from 4000 K to 25000 K. Temperatures outside
+/// that range are clamped to it.
+pub fn daylight_xy<T: FloatComponent>(temperature: T) -> (T, T) {
+    let t = temperature
+        .max(from_f64(DAYLIGHT_MIN))
+        .min(from_f64(DAYLIGHT_MAX));
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= from_f64(7000.0) {
+        from_f64::<T>(-4.6070e9) / t3
+            + from_f64::<T>(2.9678e6) / t2
+            + from_f64::<T>(0.09911e3) / t
+            + from_f64(0.244063)
+    } else {
+        from_f64::<T>(-2.0064e9) / t3
+            + from_f64::<T>(1.9018e6) / t2
+            + from_f64::<T>(0.24748e3) / t
+            + from_f64(0.237040)
+    };
+    let y = from_f64::<T>(-3.0) * x * x + from_f64::<T>(2.870) * x - from_f64(0.275);
+
+    (x, y)
+}
+
+/// The points of the Planckian locus in the CIE 1931 xy diagram, from `start`
+/// to `end` kelvin in steps of `step` kelvin.
+///
+/// The last point is always at `end`, even if `step` doesn't divide the range
+/// evenly. See [`planckian_xy`](fn.planckian_xy.html) for the valid range.
+///
+/// Panics unless `start <= end` and `step` is positive.
+pub fn planckian_locus_xy<T: FloatComponent>(
+    start: T,
+    end: T,
+    step: T,
+) -> impl Iterator<Item = (T, T)> {
+    sample_range(start, end, step).map(planckian_xy)
+}
+
+/// The points of the Planckian locus in the CIE 1976 u'v' diagram. See
+/// [`planckian_locus_xy`](fn.planckian_locus_xy.html) for details.
+///
+/// Panics unless `start <= end` and `step` is positive.
+pub fn planckian_locus_uv<T: FloatComponent>(
+    start: T,
+    end: T,
+    step: T,
+) -> impl Iterator<Item = (T, T)> {
+    planckian_locus_xy(start, end, step).map(|(x, y)| xy_to_uv(x, y))
+}
+
+/// The points of the CIE daylight locus in the CIE 1931 xy diagram, from
+/// `start` to `end` kelvin in steps of `step` kelvin.
+///
+/// The last point is always at `end`, even if `step` doesn't divide the range
+/// evenly. See [`daylight_xy`](fn.daylight_xy.html) for the valid range.
+///
+/// Panics unless `start <= end` and `step` is positive.
+pub fn daylight_locus_xy<T: FloatComponent>(
+    start: T,
+    end: T,
+    step: T,
+) -> impl Iterator<Item = (T, T)> {
+    sample_range(start, end, step).map(daylight_xy)
+}
+
+/// The points of the CIE daylight locus in the CIE 1976 u'v' diagram. See
+/// [`daylight_locus_xy`](fn.daylight_locus_xy.html) for details.
+///
+/// Panics unless `start <= end` and `step` is positive.
+pub fn daylight_locus_uv<T: FloatComponent>(
+    start: T,
+    end: T,
+    step: T,
+) -> impl Iterator<Item = (T, T)> {
+    daylight_locus_xy(start, end, step).map(|(x, y)| xy_to_uv(x, y))
+}
+
+/// The CIE 1960 UCS coordinates of a black body radiator with the
+/// `temperature`, in kelvin, using the approximation by Krystek.
+pub(crate) fn planckian_uv_1960<T: FloatComponent>(temperature: T) -> (T, T) {
+    let t = temperature;
+    let t2 = t * t;
+    let u = (from_f64::<T>(0.860117757)
+        + from_f64::<T>(1.54118254e-4) * t
+        + from_f64::<T>(1.28641212e-7) * t2)
+        / (T::one() + from_f64::<T>(8.42420235e-4) * t + from_f64::<T>(7.08145163e-7) * t2);
+    let v = (from_f64::<T>(0.317398726)
+        + from_f64::<T>(4.22806245e-5) * t
+        + from_f64::<T>(4.20481691e-8) * t2)
+        / (T::one() - from_f64::<T>(2.89741816e-5) * t + from_f64::<T>(1.61456053e-7) * t2);
+
+    (u, v)
+}
+
+/// The CIE 1960 UCS coordinates of a color.
+pub(crate) fn xyz_to_uv_1960<Wp: WhitePoint, T: FloatComponent>(color: &Xyz<Wp, T>) -> (T, T) {
+    let denominator = color.x + color.y * from_f64(15.0) + color.z * from_f64(3.0);
+    (
+        color.x * from_f64(4.0) / denominator,
+        color.y * from_f64(6.0) / denominator,
+    )
+}
+
+/// Evenly spaced values from `start` to `end`, where the last value is always
+/// `end`.
+fn sample_range<T: FloatComponent>(start: T, end: T, step: T) -> impl Iterator<Item = T> {
+    assert!(start <= end, "the start must not be after the end");
+    assert!(step > T::zero(), "the step must be positive");
+
+    let count = ((end - start) / step).ceil().to_usize().unwrap_or(0);
+    (0..=count).map(move |i| (start + from_f64::<T>(i as f64) * step).min(end))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        correlated_color_temperature, daylight_locus_uv, daylight_locus_xy, daylight_xy,
+        planckian_locus_uv, planckian_locus_xy, planckian_xy, spectral_locus_uv, spectral_locus_xy,
+        spectral_xy, uv_to_display_color, uv_to_xy, wavelength_to_display_color,
+        xy_to_display_color, xy_to_uv,
+    };
+    use crate::Srgb;
+
+    #[test]
+    fn uv_round_trip() {
+        let (u, v) = xy_to_uv(0.3127f64, 0.3290);
+        assert_relative_eq!(u, 0.1978, epsilon = 1e-4);
+        assert_relative_eq!(v, 0.4683, epsilon = 1e-4);
+
+        let (x, y) = uv_to_xy(u, v);
+        assert_relative_eq!(x, 0.3127, epsilon = 1e-12);
+        assert_relative_eq!(y, 0.3290, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn spectral_locus() {
+        let (x, y) = spectral_xy(520.0f64);
+        assert_relative_eq!(x, 0.0743, epsilon = 1e-4);
+        assert_relative_eq!(y, 0.8338, epsilon = 1e-4);
+
+        let points: Vec<(f64, f64)> = spectral_locus_xy(30.0).collect();
+        assert_eq!(points.len(), 12);
+        assert_eq!(points[0], spectral_xy(380.0));
+        assert_eq!(points[11], spectral_xy(700.0));
+
+        let uv: Vec<(f64, f64)> = spectral_locus_uv(30.0).collect();
+        assert_eq!(uv[5], xy_to_uv(points[5].0, points[5].1));
+    }
+
+    #[test]
+    fn display_colors() {
+        assert_relative_eq!(
+            xy_to_display_color(0.3127f64, 0.3290),
+            Srgb::new(1.0, 1.0, 1.0),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            xy_to_display_color(0.64f64, 0.33),
+            Srgb::new(1.0, 0.0, 0.0),
+            epsilon = 1e-3
+        );
+
+        let (u, v) = xy_to_uv(0.15f64, 0.06);
+        assert_relative_eq!(
+            uv_to_display_color(u, v),
+            xy_to_display_color(0.15, 0.06),
+            epsilon = 1e-12
+        );
+        assert_eq!(xy_to_display_color(0.5f64, 0.0), Srgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn planckian_locus() {
+        let (x, y) = planckian_xy(2856.0f64);
+        assert_relative_eq!(x, 0.4476, epsilon = 1e-3);
+        assert_relative_eq!(y, 0.4074, epsilon = 1e-3);
+        assert_relative_eq!(correlated_color_temperature(x, y), 2856.0, epsilon = 5.0);
+
+        assert_eq!(planckian_xy(500.0f64), planckian_xy(1000.0));
+
+        let points: Vec<(f64, f64)> = planckian_locus_xy(2000.0, 10000.0, 3000.0).collect();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[3], planckian_xy(10000.0));
+        assert!(points.windows(2).all(|pair| pair[1].0 < pair[0].0));
+
+        let uv: Vec<(f64, f64)> = planckian_locus_uv(2000.0, 10000.0, 3000.0).collect();
+        assert_eq!(uv[1], xy_to_uv(points[1].0, points[1].1));
+    }
+
+    #[test]
+    fn daylight_locus() {
+        let (x, y) = daylight_xy(6504.0f64);
+        assert_relative_eq!(x, 0.3127, epsilon = 1e-4);
+        assert_relative_eq!(y, 0.3291, epsilon = 1e-4);
+
+        let (x, y) = daylight_xy(5003.0f64);
+        assert_relative_eq!(x, 0.3457, epsilon = 1e-4);
+        assert_relative_eq!(y, 0.3585, epsilon = 1e-4);
+
+        let points: Vec<(f64, f64)> = daylight_locus_xy(4000.0, 25000.0, 1000.0).collect();
+        assert_eq!(points.len(), 22);
+
+        let uv: Vec<(f64, f64)> = daylight_locus_uv(4000.0, 25000.0, 1000.0).collect();
+        assert_eq!(uv[21], xy_to_uv(points[21].0, points[21].1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reversed_range() {
+        planckian_locus_xy(5000.0, 4000.0, 100.0).count();
+    }
+
+    #[test]
+    fn wavelength_colors() {
+        let red = wavelength_to_display_color(650.0f64);
+        assert_relative_eq!(red.red, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(red.green, 0.0, epsilon = 1e-12);
+        assert!(red.blue < red.red);
+
+        let green = wavelength_to_display_color(520.0f64);
+        assert_relative_eq!(green.green, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(green.red, 0.0, epsilon = 1e-12);
+
+        let blue = wavelength_to_display_color(460.0f64);
+        assert_relative_eq!(blue.blue, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(blue.green, 0.0, epsilon = 1e-12);
+
+        assert_eq!(
+            wavelength_to_display_color(300.0f64),
+            Srgb::new(0.0, 0.0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! Clustering of colors, using k-means or k-medoids.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! The algorithms are generic over any color space that implements
+//! [`ColorPoint`](crate::ColorPoint), which makes it possible to choose the
+//! space that gives the most meaningful distances for the task. L\*a\*b\* is
+//! usually a good choice for perceptual tasks, such as finding the dominant
+//! colors of an image, while linear RGB may be better for things that should
+//! preserve the average light intensity.
+//!
+//! ```
+//! use palette::cluster::Clustering;
+//! use palette::{FromColor, Lab, Srgb};
+//!
+//! let pixels = [
+//!     Srgb::new(0.9, 0.1, 0.1),
+//!     Srgb::new(0.1, 0.1, 0.9),
+//!     Srgb::new(0.8, 0.1, 0.2),
+//!     Srgb::new(0.2, 0.1, 0.8),
+//!     Srgb::new(0.9, 0.2, 0.1),
+//! ];
+//! let colors: Vec<Lab> = pixels.iter().map(|&c| Lab::from_color(c)).collect();
+//!
+//! let clusters = Clustering::new(2).k_means(&colors);
+//!
+//! assert_eq!(clusters.centroids.len(), 2);
+//! assert_eq!(clusters.assignments, [0, 1, 0, 1, 0]);
+//! assert_eq!(clusters.sizes(), [3, 2]);
+//! ```
+
+use crate::{from_f64, ColorPoint, FromF64};
+
+/// The strategy for picking the initial centroids.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Seeding<C> {
+    /// Pick `k` colors that are evenly spaced in the input slice.
+    Evenly,
+
+    /// Start with the first color and repeatedly pick the color that is
+    /// farthest away from the already picked ones.
+    ///
+    /// This is deterministic and spreads out the initial centroids well, but
+    /// it's sensitive to outliers. Fewer than `k` centroids are picked if
+    /// there aren't enough distinct colors.
+    Farthest,
+
+    /// Start from a custom set of centroids. Their number is used instead of
+    /// `k`.
+    Centroids(Vec<C>),
+}
+
+/// The result of a clustering.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clusters<C> {
+    /// The center of each cluster. These are averages for k-means and input
+    /// colors for k-medoids.
+    pub centroids: Vec<C>,
+
+    /// The index of the centroid that each input color belongs to.
+    pub assignments: Vec<usize>,
+
+    /// The number of iterations that were performed before the clusters
+    /// stopped changing, or the iteration limit was reached.
+    pub iterations: usize,
+}
+
+impl<C> Clusters<C> {
+    /// Count the number of colors in each cluster.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.centroids.len()];
+
+        for &cluster in &self.assignments {
+            sizes[cluster] += 1;
+        }
+
+        sizes
+    }
+}
+
+/// Settings for clustering a set of colors.
+///
+/// See the [module documentation](index.html) for an example.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clustering<C> {
+    k: usize,
+    max_iterations: usize,
+    seeding: Seeding<C>,
+}
+
+impl<C> Clustering<C> {
+    /// Create settings for finding `k` clusters, using `Seeding::Farthest` and
+    /// at most 100 iterations.
+    ///
+    /// Panics if `k` is 0.
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0);
+
+        Clustering {
+            k,
+            max_iterations: 100,
+            seeding: Seeding::Farthest,
+        }
+    }
+
+    /// Set the maximum number of iterations.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the strategy for picking the initial centroids.
+    ///
+    /// Panics if `seeding` is `Seeding::Centroids` with an empty list.
+    pub fn seeding(mut self, seeding: Seeding<C>) -> Self {
+        if let Seeding::Centroids(ref centroids) = seeding {
+            assert!(!centroids.is_empty());
+        }
+
+        self.seeding = seeding;
+        self
+    }
+}
+
+impl<C> Clustering<C>
+where
+    C: ColorPoint + Clone,
+    C::Scalar: FromF64,
+{
+    /// Group the colors using k-means clustering, where each centroid is the
+    /// average of the colors in its cluster.
+    ///
+    /// A centroid that loses all of its colors keeps its previous position.
+    pub fn k_means(&self, colors: &[C]) -> Clusters<C> {
+        self.run(colors, mean)
+    }
+
+    /// Group the colors using k-medoids clustering, where each centroid is the
+    /// member of its cluster with the smallest total distance to the others.
+    ///
+    /// This is slower than k-means, but the centroids are always colors from
+    /// the input and it's less sensitive to outliers.
+    pub fn k_medoids(&self, colors: &[C]) -> Clusters<C> {
+        self.run(colors, medoid)
+    }
+
+    fn run<F>(&self, colors: &[C], mut update: F) -> Clusters<C>
+    where
+        F: FnMut(&[&C]) -> C,
+    {
+        let mut centroids = self.seed(colors);
+        let mut assignments = vec![0; colors.len()];
+        let mut iterations = 0;
+
+        if colors.is_empty() {
+            return Clusters {
+                centroids,
+                assignments,
+                iterations,
+            };
+        }
+
+        let mut members = Vec::with_capacity(colors.len());
+
+        while iterations < self.max_iterations {
+            let changed = assign(colors, &centroids, &mut assignments);
+            if iterations > 0 && !changed {
+                break;
+            }
+
+            for (index, centroid) in centroids.iter_mut().enumerate() {
+                members.clear();
+                members.extend(
+                    colors
+                        .iter()
+                        .zip(&assignments)
+                        .filter(|&(_, &cluster)| cluster == index)
+                        .map(|(color, _)| color),
+                );
+
+                if !members.is_empty() {
+                    *centroid = update(&members);
+                }
+            }
+
+            iterations += 1;
+        }
+
+        assign(colors, &centroids, &mut assignments);
+
+        Clusters {
+            centroids,
+            assignments,
+            iterations,
+        }
+    }
+
+    fn seed(&self, colors: &[C]) -> Vec<C> {
+        let k = self.k.min(colors.len());
+
+        match self.seeding {
+            Seeding::Evenly => (0..k)
+                .map(|i| colors[i * colors.len() / k].clone())
+                .collect(),
+            Seeding::Farthest => {
+                let mut centroids = Vec::with_capacity(k);
+                let first = match colors.first() {
+                    Some(first) => first,
+                    None => return centroids,
+                };
+                let mut distances: Vec<_> =
+                    colors.iter().map(|c| c.distance_squared(first)).collect();
+                centroids.push(first.clone());
+
+                while centroids.len() < k {
+                    let (index, &distance) = distances
+                        .iter()
+                        .enumerate()
+                        .fold(
+                            None,
+                            |farthest: Option<(usize, &C::Scalar)>, (i, d)| match farthest {
+                                Some((_, max)) if max >= d => farthest,
+                                _ => Some((i, d)),
+                            },
+                        )
+                        .expect("there should be at least one color");
+
+                    if distance <= from_f64(0.0) {
+                        break;
+                    }
+
+                    let centroid = &colors[index];
+                    for (distance, color) in distances.iter_mut().zip(colors) {
+                        let new_distance = color.distance_squared(centroid);
+                        if new_distance < *distance {
+                            *distance = new_distance;
+                        }
+                    }
+                    centroids.push(centroid.clone());
+                }
+
+                centroids
+            }
+            Seeding::Centroids(ref centroids) => centroids.clone(),
+        }
+    }
+}
+
+/// Assign each color to its closest centroid and report if any assignment
+/// changed.
+fn assign<C: ColorPoint>(colors: &[C], centroids: &[C], assignments: &mut [usize]) -> bool {
+    let mut changed = false;
+
+    for (color, assignment) in colors.iter().zip(assignments) {
+        let closest = closest(color, centroids);
+        if closest != *assignment {
+            *assignment = closest;
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+fn closest<C: ColorPoint>(color: &C, centroids: &[C]) -> usize {
+    let mut closest = 0;
+    let mut min_distance = color.distance_squared(&centroids[0]);
+
+    for (index, centroid) in centroids.iter().enumerate().skip(1) {
+        let distance = color.distance_squared(centroid);
+        if distance < min_distance {
+            closest = index;
+            min_distance = distance;
+        }
+    }
+
+    closest
+}
+
+fn mean<C>(members: &[&C]) -> C
+where
+    C: ColorPoint + Clone,
+    C::Scalar: FromF64,
+{
+    let mut mean = members[0].clone();
+
+    for (i, &color) in members.iter().enumerate().skip(1) {
+        let count = (i + 1) as f64;
+        mean = mean.linear_combination(
+            from_f64((count - 1.0) / count),
+            color,
+            from_f64(1.0 / count),
+        );
+    }
+
+    mean
+}
+
+fn medoid<C>(members: &[&C]) -> C
+where
+    C: ColorPoint + Clone,
+{
+    let mut medoid = members[0];
+    let mut min_total = None;
+
+    for &candidate in members {
+        let mut total = candidate.distance(members[0]);
+        for &color in &members[1..] {
+            total = total + candidate.distance(color);
+        }
+
+        match min_total {
+            Some(min) if min <= total => {}
+            _ => {
+                medoid = candidate;
+                min_total = Some(total);
+            }
+        }
+    }
+
+    medoid.clone()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Clustering, Seeding};
+    use crate::{ColorPoint, Lab, Xyz};
+
+    fn two_groups() -> Vec<Lab> {
+        vec![
+            Lab::new(20.0, 10.0, 10.0),
+            Lab::new(80.0, -10.0, -10.0),
+            Lab::new(22.0, 12.0, 8.0),
+            Lab::new(82.0, -12.0, -8.0),
+            Lab::new(18.0, 8.0, 12.0),
+            Lab::new(78.0, -8.0, -12.0),
+        ]
+    }
+
+    #[test]
+    fn k_means() {
+        let clusters = Clustering::new(2).k_means(&two_groups());
+
+        assert_eq!(clusters.assignments, [0, 1, 0, 1, 0, 1]);
+        assert_relative_eq!(
+            clusters.centroids[0],
+            Lab::new(20.0, 10.0, 10.0),
+            epsilon = 1e-4
+        );
+        assert_relative_eq!(
+            clusters.centroids[1],
+            Lab::new(80.0, -10.0, -10.0),
+            epsilon = 1e-4
+        );
+        assert_eq!(clusters.iterations, 1);
+    }
+
+    #[test]
+    fn k_medoids() {
+        let colors = two_groups();
+        let clusters = Clustering::new(2).k_medoids(&colors);
+
+        assert_eq!(clusters.assignments, [0, 1, 0, 1, 0, 1]);
+        assert!(clusters.centroids.iter().all(|c| colors.contains(c)));
+        assert_eq!(clusters.centroids, [colors[0], colors[1]]);
+    }
+
+    #[test]
+    fn evenly_seeded() {
+        let clusters = Clustering::new(2)
+            .seeding(Seeding::Evenly)
+            .k_means(&two_groups());
+
+        assert_eq!(clusters.sizes(), [3, 3]);
+        assert_ne!(clusters.assignments[0], clusters.assignments[1]);
+    }
+
+    #[test]
+    fn custom_centroids() {
+        let clusters = Clustering::new(1)
+            .seeding(Seeding::Centroids(vec![
+                Lab::new(100.0, 0.0, 0.0),
+                Lab::new(0.0, 0.0, 0.0),
+                Lab::new(50.0, 100.0, 100.0),
+            ]))
+            .k_means(&two_groups());
+
+        assert_eq!(clusters.centroids.len(), 3);
+        assert_eq!(clusters.sizes(), [3, 3, 0]);
+        assert_eq!(clusters.centroids[2], Lab::new(50.0, 100.0, 100.0));
+    }
+
+    #[test]
+    fn too_few_colors() {
+        let colors = [Xyz::new(0.5, 0.5, 0.5), Xyz::new(0.5, 0.5, 0.5)];
+        let clusters = Clustering::new(5).k_means(&colors);
+
+        assert_eq!(clusters.centroids.len(), 1);
+        assert_eq!(clusters.assignments, [0, 0]);
+
+        let clusters = Clustering::<Xyz>::new(5).k_means(&[]);
+        assert!(clusters.centroids.is_empty());
+        assert!(clusters.assignments.is_empty());
+    }
+
+    #[test]
+    fn iteration_limit() {
+        let colors: Vec<_> = (0..20)
+            .map(|i| Lab::new(i as f32 * 5.0, (i * i % 7) as f32, 0.0))
+            .collect();
+        let clusters = Clustering::new(4).max_iterations(1).k_means(&colors);
+
+        assert_eq!(clusters.iterations, 1);
+        for (color, &cluster) in colors.iter().zip(&clusters.assignments) {
+            let distance = color.distance(&clusters.centroids[cluster]);
+            assert!(clusters
+                .centroids
+                .iter()
+                .all(|centroid| color.distance(centroid) >= distance));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Color matching functions for the standard observers.
+
+/// The first wavelength, in nanometers, in the color matching function
+/// tables.
+pub(crate) const START: f64 = 380.0;
+
+/// The distance, in nanometers, between the entries in the color matching
+/// function tables.
+pub(crate) const STEP: f64 = 5.0;
+
+/// The last wavelength, in nanometers, in the color matching function tables.
+pub(crate) const END: f64 = 780.0;
+
+/// The CIE 1931 2° standard observer, as `[x̄, ȳ, z̄]` from 380 nm to 780 nm in
+/// steps of 5 nm.
+#[rustfmt::skip]
+pub(crate) const CIE_1931: [[f64; 3]; 81] = [
+    [0.001368, 0.000039, 0.006450], // 380 nm
+    [0.002236, 0.000064, 0.010550], // 385 nm
+    [0.004243, 0.000120, 0.020050], // 390 nm
+    [0.007650, 0.000217, 0.036210], // 395 nm
+    [0.014310, 0.000396, 0.067850], // 400 nm
+    [0.023190, 0.000640, 0.110200], // 405 nm
+    [0.043510, 0.001210, 0.207400], // 410 nm
+    [0.077630, 0.002180, 0.371300], // 415 nm
+    [0.134380, 0.004000, 0.645600], // 420 nm
+    [0.214770, 0.007300, 1.039050], // 425 nm
+    [0.283900, 0.011600, 1.385600], // 430 nm
+    [0.328500, 0.016840, 1.622960], // 435 nm
+    [0.348280, 0.023000, 1.747060], // 440 nm
+    [0.348060, 0.029800, 1.782600], // 445 nm
+    [0.336200, 0.038000, 1.772110], // 450 nm
+    [0.318700, 0.048000, 1.744100], // 455 nm
+    [0.290800, 0.060000, 1.669200], // 460 nm
+    [0.251100, 0.073900, 1.528100], // 465 nm
+    [0.195360, 0.090980, 1.287640], // 470 nm
+    [0.142100, 0.112600, 1.041900], // 475 nm
+    [0.095640, 0.139020, 0.812950], // 480 nm
+    [0.057950, 0.169300, 0.616200], // 485 nm
+    [0.032010, 0.208020, 0.465180], // 490 nm
+    [0.014700, 0.258600, 0.353300], // 495 nm
+    [0.004900, 0.323000, 0.272000], // 500 nm
+    [0.002400, 0.407300, 0.212300], // 505 nm
+    [0.009300, 0.503000, 0.158200], // 510 nm
+    [0.029100, 0.608200, 0.111700], // 515 nm
+    [0.063270, 0.710000, 0.078250], // 520 nm
+    [0.109600, 0.793200, 0.057250], // 525 nm
+    [0.165500, 0.862000, 0.042160], // 530 nm
+    [0.225750, 0.914850, 0.029840], // 535 nm
+    [0.290400, 0.954000, 0.020300], // 540 nm
+    [0.359700, 0.980300, 0.013400], // 545 nm
+    [0.433450, 0.994950, 0.008750], // 550 nm
+    [0.512050, 1.000000, 0.005750], // 555 nm
+    [0.594500, 0.995000, 0.003900], // 560 nm
+    [0.678400, 0.978600, 0.002750], // 565 nm
+    [0.762100, 0.952000, 0.002100], // 570 nm
+    [0.842500, 0.915400, 0.001800], // 575 nm
+    [0.916300, 0.870000, 0.001650], // 580 nm
+    [0.978600, 0.816300, 0.001400], // 585 nm
+    [1.026300, 0.757000, 0.001100], // 590 nm
+    [1.056700, 0.694900, 0.001000], // 595 nm
+    [1.062200, 0.631000, 0.000800], // 600 nm
+    [1.045600, 0.566800, 0.000600], // 605 nm
+    [1.002600, 0.503000, 0.000340], // 610 nm
+    [0.938400, 0.441200, 0.000240], // 615 nm
+    [0.854450, 0.381000, 0.000190], // 620 nm
+    [0.751400, 0.321000, 0.000100], // 625 nm
+    [0.642400, 0.265000, 0.000050], // 630 nm
+    [0.541900, 0.217000, 0.000030], // 635 nm
+    [0.447900, 0.175000, 0.000020], // 640 nm
+    [0.360800, 0.138200, 0.000010], // 645 nm
+    [0.283500, 0.107000, 0.000000], // 650 nm
+    [0.218700, 0.081600, 0.000000], // 655 nm
+    [0.164900, 0.061000, 0.000000], // 660 nm
+    [0.121200, 0.044580, 0.000000], // 665 nm
+    [0.087400, 0.032000, 0.000000], // 670 nm
+    [0.063600, 0.023200, 0.000000], // 675 nm
+    [0.046770, 0.017000, 0.000000], // 680 nm
+    [0.032900, 0.011920, 0.000000], // 685 nm
+    [0.022700, 0.008210, 0.000000], // 690 nm
+    [0.015840, 0.005723, 0.000000], // 695 nm
+    [0.011359, 0.004102, 0.000000], // 700 nm
+    [0.008111, 0.002929, 0.000000], // 705 nm
+    [0.005790, 0.002091, 0.000000], // 710 nm
+    [0.004109, 0.001484, 0.000000], // 715 nm
+    [0.002899, 0.001047, 0.000000], // 720 nm
+    [0.002049, 0.000740, 0.000000], // 725 nm
+    [0.001440, 0.000520, 0.000000], // 730 nm
+    [0.001000, 0.000361, 0.000000], // 735 nm
+    [0.000690, 0.000249, 0.000000], // 740 nm
+    [0.000476, 0.000172, 0.000000], // 745 nm
+    [0.000332, 0.000120, 0.000000], // 750 nm
+    [0.000235, 0.000085, 0.000000], // 755 nm
+    [0.000166, 0.000060, 0.000000], // 760 nm
+    [0.000117, 0.000042, 0.000000], // 765 nm
+    [0.000083, 0.000030, 0.000000], // 770 nm
+    [0.000059, 0.000021, 0.000000], // 775 nm
+    [0.000042, 0.000015, 0.000000], // 780 nm
+];
+
+/// The distance, in nanometers, between the entries in the CIE 1964 table.
+#[cfg(feature = "std")]
+pub(crate) const CIE_1964_STEP: f64 = 10.0;
+
+/// The CIE 1964 10° supplementary standard observer, as `[x̄₁₀, ȳ₁₀, z̄₁₀]`
+/// from 380 nm to 780 nm in steps of 10 nm.
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+pub(crate) const CIE_1964: [[f64; 3]; 41] = [
+    [0.000160, 0.000017, 0.000705], // 380 nm
+    [0.002362, 0.000253, 0.010482], // 390 nm
+    [0.019110, 0.002004, 0.086011], // 400 nm
+    [0.084736, 0.008756, 0.389366], // 410 nm
+    [0.204492, 0.021391, 0.972542], // 420 nm
+    [0.314679, 0.038676, 1.553480], // 430 nm
+    [0.383734, 0.062077, 1.967280], // 440 nm
+    [0.370702, 0.089456, 1.994800], // 450 nm
+    [0.302273, 0.128201, 1.745370], // 460 nm
+    [0.195618, 0.185190, 1.317560], // 470 nm
+    [0.080507, 0.253589, 0.772125], // 480 nm
+    [0.016172, 0.339133, 0.415254], // 490 nm
+    [0.003816, 0.460777, 0.218502], // 500 nm
+    [0.037465, 0.606741, 0.112044], // 510 nm
+    [0.117749, 0.761757, 0.060709], // 520 nm
+    [0.236491, 0.875211, 0.030451], // 530 nm
+    [0.376772, 0.961988, 0.013676], // 540 nm
+    [0.529826, 0.991761, 0.003988], // 550 nm
+    [0.705224, 0.997340, 0.000000], // 560 nm
+    [0.878655, 0.955552, 0.000000], // 570 nm
+    [1.014160, 0.868934, 0.000000], // 580 nm
+    [1.118520, 0.777405, 0.000000], // 590 nm
+    [1.123990, 0.658341, 0.000000], // 600 nm
+    [1.030480, 0.527963, 0.000000], // 610 nm
+    [0.856297, 0.398057, 0.000000], // 620 nm
+    [0.647467, 0.283493, 0.000000], // 630 nm
+    [0.431567, 0.179828, 0.000000], // 640 nm
+    [0.268329, 0.107633, 0.000000], // 650 nm
+    [0.152568, 0.060281, 0.000000], // 660 nm
+    [0.081261, 0.031800, 0.000000], // 670 nm
+    [0.040851, 0.015905, 0.000000], // 680 nm
+    [0.019941, 0.007749, 0.000000], // 690 nm
+    [0.009577, 0.003718, 0.000000], // 700 nm
+    [0.004553, 0.001768, 0.000000], // 710 nm
+    [0.002175, 0.000846, 0.000000], // 720 nm
+    [0.001045, 0.000407, 0.000000], // 730 nm
+    [0.000508, 0.000199, 0.000000], // 740 nm
+    [0.000251, 0.000098, 0.000000], // 750 nm
+    [0.000126, 0.000050, 0.000000], // 760 nm
+    [0.000063, 0.000025, 0.000000], // 770 nm
+    [0.000032, 0.000013, 0.000000], // 780 nm
+];
+
+/// Look up the CIE 1931 color matching functions at `wavelength`, in
+/// nanometers, with linear interpolation between the table entries.
+///
+/// The functions are zero outside the 380 nm to 780 nm range.
+pub(crate) fn cie_1931(wavelength: f64) -> [f64; 3] {
+    let position = (wavelength - START) / STEP;
+    let last = (CIE_1931.len() - 1) as f64;
+
+    if !(position >= 0.0 && position <= last) {
+        return [0.0; 3];
+    }
+
+    let index = (position as usize).min(CIE_1931.len() - 2);
+    let t = position - index as f64;
+    let (a, b) = (CIE_1931[index], CIE_1931[index + 1]);
+
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cie_1931, CIE_1931, STEP};
+
+    #[test]
+    fn equal_energy_white() {
+        let mut sum = [0.0; 3];
+        for entry in CIE_1931.iter() {
+            for (sum, value) in sum.iter_mut().zip(entry) {
+                *sum += value * STEP;
+            }
+        }
+
+        assert_relative_eq!(sum[0], sum[1], max_relative = 1e-4);
+        assert_relative_eq!(sum[2], sum[1], max_relative = 1e-4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn equal_energy_white_1964() {
+        use super::{CIE_1964, CIE_1964_STEP};
+
+        let mut sum = [0.0; 3];
+        for entry in CIE_1964.iter() {
+            for (sum, value) in sum.iter_mut().zip(entry) {
+                *sum += value * CIE_1964_STEP;
+            }
+        }
+
+        assert_relative_eq!(sum[0], sum[1], max_relative = 1e-4);
+        assert_relative_eq!(sum[2], sum[1], max_relative = 1e-4);
+    }
+
+    #[test]
+    fn interpolation() {
+        assert_eq!(cie_1931(555.0), [0.512050, 1.0, 0.005750]);
+        assert_relative_eq!(cie_1931(557.5)[1], 0.9975);
+        assert_eq!(cie_1931(780.0), [0.000042, 0.000015, 0.0]);
+        assert_eq!(cie_1931(379.0), [0.0; 3]);
+        assert_eq!(cie_1931(781.0), [0.0; 3]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::component::FloatComponent;
 use crate::from_f64;
+use crate::math::{atan2, cos, exp, sin};
 
 /// A trait for calculating the color difference between two colors.
 pub trait ColorDifference {
@@ -10,6 +11,42 @@ pub trait ColorDifference {
     fn get_color_difference(&self, other: &Self) -> Self::Scalar;
 }
 
+/// The CIE color difference formulas, which are often called ΔE\*.
+///
+/// They are implemented for [`Lab`](struct.Lab.html) and
+/// [`Lch`](struct.Lch.html), and other colors can be converted to either of
+/// them first. A difference of about `1.0` is just noticeable.
+///
+/// ```
+/// use palette::{CieDifference, FromColor, Lab, Srgb};
+///
+/// let a: Lab = Lab::from_color(Srgb::new(0.8, 0.2, 0.1));
+/// let b: Lab = Lab::from_color(Srgb::new(0.8, 0.25, 0.1));
+///
+/// assert!(a.delta_e_ciede2000(&b) < a.delta_e_cie76(&b));
+/// assert_eq!(a.delta_e_cie94(&a), 0.0);
+/// ```
+pub trait CieDifference {
+    /// The type of the calculated color difference.
+    type Scalar: FloatComponent;
+
+    /// The CIE76 difference, which is the Euclidean distance in CIE
+    /// L\*a\*b\*. It's fast, but overestimates the differences between
+    /// saturated colors.
+    fn delta_e_cie76(&self, other: &Self) -> Self::Scalar;
+
+    /// The CIE94 difference, with the weights for graphic arts. It's not
+    /// symmetric, since the weights depend on the chroma of `self`, which is
+    /// the reference color.
+    fn delta_e_cie94(&self, other: &Self) -> Self::Scalar;
+
+    /// The CIEDE2000 difference. It's the most accurate of the CIE formulas,
+    /// and the slowest. It's the same as
+    /// [`ColorDifference::get_color_difference`](trait.ColorDifference.html#tymethod.get_color_difference)
+    /// for `Lab`.
+    fn delta_e_ciede2000(&self, other: &Self) -> Self::Scalar;
+}
+
 /// Container of components necessary to calculate CIEDE color difference
 pub struct LabColorDiff<T: FloatComponent> {
     /// Lab color lightness
@@ -45,7 +82,7 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
         if b == T::zero() && a_prime == T::zero() {
             from_f64(0.0)
         } else {
-            let result = b.atan2(a_prime).to_degrees();
+            let result = atan2(b, a_prime).to_degrees();
             if result < T::zero() {
                 result + from_f64(360.0)
             } else {
@@ -70,7 +107,7 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
 
     let delta_big_h_prime = from_f64::<T>(2.0)
         * (c_one_prime * c_two_prime).sqrt()
-        * (delta_h_prime / from_f64(2.0) * pi_over_180).sin();
+        * sin(delta_h_prime / from_f64(2.0) * pi_over_180);
     let h_bar_prime = if c_one_prime == T::zero() || c_two_prime == T::zero() {
         h_one_prime + h_two_prime
     } else if h_prime_difference > from_f64(180.0) {
@@ -83,10 +120,10 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
     let c_bar_prime = (c_one_prime + c_two_prime) / from_f64(2.0);
 
     let t: T = from_f64::<T>(1.0)
-        - from_f64::<T>(0.17) * ((h_bar_prime - from_f64(30.0)) * pi_over_180).cos()
-        + from_f64::<T>(0.24) * ((h_bar_prime * from_f64(2.0)) * pi_over_180).cos()
-        + from_f64::<T>(0.32) * ((h_bar_prime * from_f64(3.0) + from_f64(6.0)) * pi_over_180).cos()
-        - from_f64::<T>(0.20) * ((h_bar_prime * from_f64(4.0) - from_f64(63.0)) * pi_over_180).cos();
+        - from_f64::<T>(0.17) * cos((h_bar_prime - from_f64(30.0)) * pi_over_180)
+        + from_f64::<T>(0.24) * cos((h_bar_prime * from_f64(2.0)) * pi_over_180)
+        + from_f64::<T>(0.32) * cos((h_bar_prime * from_f64(3.0) + from_f64(6.0)) * pi_over_180)
+        - from_f64::<T>(0.20) * cos((h_bar_prime * from_f64(4.0) - from_f64(63.0)) * pi_over_180);
     let s_l = from_f64::<T>(1.0)
         + ((from_f64::<T>(0.015) * (l_bar - from_f64(50.0)) * (l_bar - from_f64(50.0)))
             / ((l_bar - from_f64(50.0)) * (l_bar - from_f64(50.0)) + from_f64(20.0)).sqrt());
@@ -94,13 +131,14 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
     let s_h = from_f64::<T>(1.0) + from_f64::<T>(0.015) * c_bar_prime * t;
 
     let delta_theta = from_f64::<T>(30.0)
-        * (-(((h_bar_prime - from_f64(275.0)) / from_f64(25.0))
-            * ((h_bar_prime - from_f64(275.0)) / from_f64(25.0))))
-        .exp();
+        * exp(
+            -(((h_bar_prime - from_f64(275.0)) / from_f64(25.0))
+                * ((h_bar_prime - from_f64(275.0)) / from_f64(25.0))),
+        );
     let c_bar_prime_pow_seven = c_bar_prime.powi(7);
     let r_c: T = from_f64::<T>(2.0)
         * (c_bar_prime_pow_seven / (c_bar_prime_pow_seven + twenty_five_pow_seven)).sqrt();
-    let r_t = -r_c * (from_f64::<T>(2.0) * delta_theta * pi_over_180).sin();
+    let r_t = -r_c * sin(from_f64::<T>(2.0) * delta_theta * pi_over_180);
 
     let one = from_f64::<T>(1.0);
     let k_l = one;
@@ -115,3 +153,35 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
         + (r_t * delta_c_prime * delta_big_h_prime) / (k_c * s_c * k_h * s_h))
         .sqrt()
 }
+
+/// Calculate the CIE76 color difference for two colors in Lab color space.
+pub fn get_cie76_difference<T: FloatComponent>(
+    this: &LabColorDiff<T>,
+    other: &LabColorDiff<T>,
+) -> T {
+    let delta_l = this.l - other.l;
+    let delta_a = this.a - other.a;
+    let delta_b = this.b - other.b;
+
+    (delta_l * delta_l + delta_a * delta_a + delta_b * delta_b).sqrt()
+}
+
+/// Calculate the CIE94 color difference for two colors in Lab color space,
+/// with the graphic arts weights and `this` as the reference color.
+pub fn get_cie94_difference<T: FloatComponent>(
+    this: &LabColorDiff<T>,
+    other: &LabColorDiff<T>,
+) -> T {
+    let delta_l = this.l - other.l;
+    let delta_chroma = this.chroma - other.chroma;
+    let delta_a = this.a - other.a;
+    let delta_b = this.b - other.b;
+    let delta_hue_squared =
+        (delta_a * delta_a + delta_b * delta_b - delta_chroma * delta_chroma).max(T::zero());
+
+    let s_c = T::one() + from_f64::<T>(0.045) * this.chroma;
+    let s_h = T::one() + from_f64::<T>(0.015) * this.chroma;
+
+    let chroma_term = delta_chroma / s_c;
+    (delta_l * delta_l + chroma_term * chroma_term + delta_hue_squared / (s_h * s_h)).sqrt()
+}
@@ -0,0 +1,443 @@
+//! Color rendering of light sources.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! The CIE color rendering index (CRI) from CIE 13.3 measures how faithfully
+//! a light source shows the colors of objects, compared to a reference
+//! illuminant with the same correlated color temperature. Each of the 14 test
+//! color samples gets a special index, from R1 to R14, where `100.0` is a
+//! perfect match. The general index, Ra, is the average of the first eight.
+//! R9, a saturated red, is often reported together with Ra, since it's where
+//! many white LEDs fall short.
+//!
+//! ```
+//! use palette::color_rendering::ColorRendering;
+//! use palette::spectrum::Spd;
+//!
+//! // A light source with the same power at every wavelength.
+//! let flat = Spd::new(380.0, 5.0, vec![1.0f64; 81]);
+//! let rendering = ColorRendering::from_spd(&flat);
+//!
+//! assert!(rendering.general_index > 90.0);
+//! assert!(rendering.r9() > 80.0);
+//! ```
+
+use crate::chromaticity::xyz_to_uv_1960;
+use crate::cmf;
+use crate::math::{cbrt, hypot};
+use crate::spectrum::Spd;
+use crate::white_point::E;
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// The lowest correlated color temperature, in kelvin, that is searched for.
+const MIN_TEMPERATURE: f64 = 1000.0;
+
+/// The highest correlated color temperature, in kelvin, that is searched
+/// for.
+const MAX_TEMPERATURE: f64 = 25000.0;
+
+/// Reference illuminants below this temperature, in kelvin, are black body
+/// radiators, and CIE daylight illuminants above it.
+const DAYLIGHT_THRESHOLD: f64 = 5000.0;
+
+/// The reflectance of the CIE 13.3 test color samples, with their Munsell
+/// notation, from 380 nm to 780 nm in steps of 5 nm.
+#[rustfmt::skip]
+#[allow(clippy::approx_constant)]
+const TEST_COLOR_SAMPLES: [[f64; 81]; 14] = [
+    // TCS01: 7.5 R 6/4, light grayish red
+    [
+        0.219, 0.239, 0.252, 0.256, 0.256, 0.254, 0.252, 0.248, 0.244,
+        0.240, 0.237, 0.232, 0.230, 0.226, 0.225, 0.222, 0.220, 0.218,
+        0.216, 0.214, 0.214, 0.214, 0.216, 0.218, 0.223, 0.225, 0.226,
+        0.226, 0.225, 0.225, 0.227, 0.230, 0.236, 0.245, 0.253, 0.262,
+        0.272, 0.283, 0.298, 0.318, 0.341, 0.367, 0.390, 0.409, 0.424,
+        0.435, 0.442, 0.448, 0.450, 0.451, 0.451, 0.451, 0.451, 0.451,
+        0.450, 0.450, 0.451, 0.451, 0.453, 0.455, 0.458, 0.462, 0.464,
+        0.466, 0.466, 0.466, 0.466, 0.466, 0.466, 0.467, 0.467, 0.467,
+        0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467,
+    ],
+    // TCS02: 5 Y 6/4, dark grayish yellow
+    [
+        0.070, 0.079, 0.089, 0.101, 0.111, 0.116, 0.118, 0.120, 0.121,
+        0.122, 0.122, 0.122, 0.123, 0.124, 0.127, 0.128, 0.131, 0.134,
+        0.138, 0.143, 0.150, 0.159, 0.174, 0.190, 0.207, 0.225, 0.242,
+        0.253, 0.260, 0.264, 0.267, 0.269, 0.272, 0.276, 0.282, 0.289,
+        0.299, 0.309, 0.322, 0.329, 0.335, 0.339, 0.341, 0.341, 0.342,
+        0.342, 0.342, 0.341, 0.341, 0.339, 0.339, 0.338, 0.338, 0.337,
+        0.336, 0.335, 0.334, 0.332, 0.332, 0.331, 0.331, 0.330, 0.329,
+        0.328, 0.328, 0.327, 0.326, 0.325, 0.324, 0.324, 0.324, 0.323,
+        0.322, 0.321, 0.320, 0.318, 0.316, 0.315, 0.315, 0.314, 0.314,
+    ],
+    // TCS03: 5 GY 6/8, strong yellow green
+    [
+        0.065, 0.068, 0.070, 0.072, 0.073, 0.073, 0.074, 0.074, 0.074,
+        0.073, 0.073, 0.073, 0.073, 0.073, 0.074, 0.075, 0.077, 0.080,
+        0.085, 0.094, 0.109, 0.126, 0.148, 0.172, 0.198, 0.221, 0.241,
+        0.260, 0.278, 0.302, 0.339, 0.370, 0.392, 0.399, 0.400, 0.393,
+        0.380, 0.365, 0.349, 0.332, 0.315, 0.299, 0.285, 0.272, 0.264,
+        0.257, 0.252, 0.247, 0.241, 0.235, 0.229, 0.224, 0.220, 0.217,
+        0.216, 0.216, 0.219, 0.224, 0.230, 0.238, 0.251, 0.269, 0.288,
+        0.312, 0.340, 0.366, 0.390, 0.412, 0.431, 0.447, 0.460, 0.472,
+        0.481, 0.488, 0.493, 0.497, 0.500, 0.502, 0.505, 0.510, 0.516,
+    ],
+    // TCS04: 2.5 G 6/6, moderate yellowish green
+    [
+        0.074, 0.083, 0.093, 0.105, 0.116, 0.121, 0.124, 0.126, 0.128,
+        0.131, 0.135, 0.139, 0.144, 0.151, 0.161, 0.172, 0.186, 0.205,
+        0.229, 0.254, 0.281, 0.308, 0.332, 0.352, 0.370, 0.383, 0.390,
+        0.394, 0.395, 0.392, 0.385, 0.377, 0.367, 0.354, 0.341, 0.327,
+        0.312, 0.296, 0.280, 0.263, 0.247, 0.229, 0.214, 0.198, 0.185,
+        0.175, 0.169, 0.164, 0.160, 0.156, 0.154, 0.152, 0.151, 0.149,
+        0.148, 0.148, 0.148, 0.149, 0.151, 0.154, 0.158, 0.162, 0.165,
+        0.168, 0.170, 0.171, 0.170, 0.168, 0.166, 0.164, 0.164, 0.165,
+        0.168, 0.172, 0.177, 0.181, 0.185, 0.189, 0.195, 0.200, 0.206,
+    ],
+    // TCS05: 10 BG 6/4, light bluish green
+    [
+        0.295, 0.306, 0.310, 0.312, 0.313, 0.315, 0.319, 0.322, 0.326,
+        0.330, 0.334, 0.339, 0.346, 0.352, 0.360, 0.369, 0.381, 0.394,
+        0.403, 0.410, 0.415, 0.418, 0.419, 0.417, 0.413, 0.409, 0.403,
+        0.396, 0.389, 0.381, 0.372, 0.363, 0.353, 0.342, 0.331, 0.320,
+        0.308, 0.296, 0.284, 0.271, 0.260, 0.247, 0.232, 0.220, 0.210,
+        0.200, 0.194, 0.189, 0.185, 0.183, 0.181, 0.180, 0.177, 0.175,
+        0.175, 0.175, 0.175, 0.177, 0.180, 0.183, 0.186, 0.189, 0.192,
+        0.195, 0.199, 0.200, 0.199, 0.198, 0.196, 0.195, 0.195, 0.196,
+        0.197, 0.200, 0.203, 0.205, 0.208, 0.212, 0.215, 0.217, 0.219,
+    ],
+    // TCS06: 5 PB 6/8, light blue
+    [
+        0.151, 0.203, 0.265, 0.339, 0.410, 0.464, 0.492, 0.508, 0.517,
+        0.524, 0.531, 0.538, 0.544, 0.551, 0.556, 0.556, 0.554, 0.549,
+        0.541, 0.531, 0.519, 0.504, 0.488, 0.469, 0.450, 0.431, 0.414,
+        0.395, 0.377, 0.358, 0.341, 0.325, 0.309, 0.293, 0.279, 0.265,
+        0.253, 0.241, 0.234, 0.227, 0.225, 0.222, 0.221, 0.220, 0.220,
+        0.220, 0.220, 0.220, 0.223, 0.227, 0.233, 0.239, 0.244, 0.251,
+        0.258, 0.263, 0.268, 0.273, 0.278, 0.281, 0.283, 0.286, 0.291,
+        0.296, 0.302, 0.313, 0.325, 0.338, 0.351, 0.364, 0.376, 0.389,
+        0.401, 0.413, 0.425, 0.436, 0.447, 0.458, 0.469, 0.477, 0.485,
+    ],
+    // TCS07: 2.5 P 6/8, light violet
+    [
+        0.378, 0.459, 0.524, 0.546, 0.551, 0.555, 0.559, 0.560, 0.561,
+        0.558, 0.556, 0.551, 0.544, 0.535, 0.522, 0.506, 0.488, 0.469,
+        0.448, 0.429, 0.408, 0.385, 0.363, 0.341, 0.324, 0.311, 0.301,
+        0.291, 0.283, 0.273, 0.265, 0.260, 0.257, 0.257, 0.259, 0.260,
+        0.260, 0.258, 0.256, 0.254, 0.254, 0.259, 0.270, 0.284, 0.302,
+        0.324, 0.344, 0.362, 0.377, 0.389, 0.400, 0.410, 0.420, 0.429,
+        0.438, 0.445, 0.452, 0.457, 0.462, 0.466, 0.468, 0.470, 0.473,
+        0.477, 0.483, 0.489, 0.496, 0.503, 0.511, 0.518, 0.525, 0.532,
+        0.539, 0.546, 0.553, 0.559, 0.565, 0.570, 0.575, 0.578, 0.581,
+    ],
+    // TCS08: 10 P 6/8, light reddish purple
+    [
+        0.130, 0.175, 0.238, 0.330, 0.403, 0.453, 0.476, 0.484, 0.484,
+        0.479, 0.472, 0.465, 0.456, 0.446, 0.436, 0.426, 0.415, 0.402,
+        0.390, 0.378, 0.365, 0.352, 0.341, 0.329, 0.318, 0.308, 0.297,
+        0.289, 0.282, 0.276, 0.270, 0.264, 0.261, 0.258, 0.255, 0.253,
+        0.253, 0.255, 0.260, 0.268, 0.282, 0.302, 0.331, 0.370, 0.418,
+        0.474, 0.526, 0.571, 0.608, 0.635, 0.656, 0.670, 0.681, 0.689,
+        0.698, 0.704, 0.711, 0.716, 0.721, 0.725, 0.729, 0.732, 0.735,
+        0.738, 0.740, 0.742, 0.744, 0.746, 0.748, 0.749, 0.751, 0.752,
+        0.753, 0.754, 0.755, 0.756, 0.757, 0.758, 0.759, 0.760, 0.761,
+    ],
+    // TCS09: 4.5 R 4/13, strong red
+    [
+        0.066, 0.062, 0.058, 0.055, 0.052, 0.052, 0.051, 0.050, 0.050,
+        0.049, 0.048, 0.047, 0.046, 0.044, 0.042, 0.041, 0.038, 0.035,
+        0.033, 0.031, 0.030, 0.029, 0.028, 0.028, 0.028, 0.029, 0.030,
+        0.030, 0.031, 0.031, 0.032, 0.032, 0.033, 0.034, 0.035, 0.037,
+        0.041, 0.044, 0.048, 0.052, 0.060, 0.076, 0.102, 0.136, 0.190,
+        0.256, 0.336, 0.418, 0.505, 0.581, 0.641, 0.682, 0.717, 0.740,
+        0.758, 0.770, 0.781, 0.790, 0.797, 0.803, 0.809, 0.814, 0.819,
+        0.824, 0.828, 0.830, 0.831, 0.833, 0.835, 0.836, 0.836, 0.837,
+        0.838, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839, 0.839,
+    ],
+    // TCS10: 5 Y 8/10, strong yellow
+    [
+        0.050, 0.054, 0.059, 0.063, 0.066, 0.067, 0.068, 0.069, 0.069,
+        0.070, 0.072, 0.073, 0.076, 0.078, 0.083, 0.088, 0.095, 0.103,
+        0.113, 0.125, 0.142, 0.162, 0.189, 0.219, 0.262, 0.305, 0.365,
+        0.416, 0.465, 0.509, 0.546, 0.581, 0.610, 0.634, 0.653, 0.666,
+        0.678, 0.687, 0.693, 0.698, 0.701, 0.704, 0.705, 0.705, 0.706,
+        0.707, 0.707, 0.707, 0.708, 0.708, 0.710, 0.711, 0.712, 0.714,
+        0.716, 0.718, 0.720, 0.722, 0.725, 0.729, 0.731, 0.735, 0.739,
+        0.742, 0.746, 0.748, 0.749, 0.751, 0.753, 0.754, 0.755, 0.755,
+        0.755, 0.755, 0.756, 0.757, 0.758, 0.759, 0.759, 0.759, 0.759,
+    ],
+    // TCS11: 4.5 G 5/8, strong green
+    [
+        0.111, 0.121, 0.127, 0.129, 0.127, 0.121, 0.116, 0.112, 0.108,
+        0.105, 0.104, 0.104, 0.105, 0.106, 0.110, 0.115, 0.123, 0.134,
+        0.148, 0.167, 0.192, 0.219, 0.252, 0.291, 0.325, 0.347, 0.356,
+        0.353, 0.346, 0.333, 0.314, 0.294, 0.271, 0.248, 0.227, 0.206,
+        0.188, 0.170, 0.153, 0.138, 0.125, 0.114, 0.106, 0.100, 0.096,
+        0.092, 0.090, 0.087, 0.085, 0.083, 0.082, 0.080, 0.079, 0.078,
+        0.078, 0.078, 0.078, 0.081, 0.083, 0.088, 0.093, 0.102, 0.112,
+        0.125, 0.141, 0.161, 0.182, 0.203, 0.223, 0.242, 0.257, 0.270,
+        0.282, 0.292, 0.302, 0.310, 0.314, 0.317, 0.323, 0.330, 0.334,
+    ],
+    // TCS12: 3 PB 3/11, strong blue
+    [
+        0.120, 0.103, 0.090, 0.082, 0.076, 0.068, 0.064, 0.065, 0.075,
+        0.093, 0.123, 0.160, 0.207, 0.256, 0.300, 0.331, 0.346, 0.347,
+        0.341, 0.328, 0.307, 0.282, 0.257, 0.230, 0.204, 0.178, 0.154,
+        0.129, 0.109, 0.090, 0.075, 0.062, 0.051, 0.041, 0.035, 0.029,
+        0.025, 0.022, 0.019, 0.017, 0.017, 0.017, 0.016, 0.016, 0.016,
+        0.016, 0.016, 0.016, 0.016, 0.016, 0.018, 0.018, 0.018, 0.018,
+        0.019, 0.020, 0.023, 0.024, 0.026, 0.030, 0.035, 0.043, 0.056,
+        0.074, 0.097, 0.128, 0.166, 0.210, 0.257, 0.305, 0.354, 0.401,
+        0.446, 0.485, 0.520, 0.551, 0.577, 0.599, 0.618, 0.633, 0.645,
+    ],
+    // TCS13: 5 YR 8/4, light yellowish pink (skin)
+    [
+        0.104, 0.127, 0.161, 0.211, 0.264, 0.313, 0.341, 0.352, 0.359,
+        0.361, 0.364, 0.365, 0.367, 0.369, 0.372, 0.374, 0.376, 0.379,
+        0.384, 0.389, 0.397, 0.405, 0.416, 0.429, 0.443, 0.454, 0.461,
+        0.466, 0.469, 0.471, 0.474, 0.476, 0.483, 0.490, 0.506, 0.526,
+        0.553, 0.582, 0.618, 0.651, 0.680, 0.701, 0.717, 0.729, 0.736,
+        0.742, 0.745, 0.747, 0.748, 0.748, 0.748, 0.748, 0.748, 0.748,
+        0.748, 0.748, 0.747, 0.747, 0.747, 0.747, 0.747, 0.746, 0.746,
+        0.746, 0.745, 0.744, 0.743, 0.744, 0.745, 0.748, 0.750, 0.750,
+        0.749, 0.748, 0.748, 0.747, 0.747, 0.747, 0.747, 0.747, 0.746,
+    ],
+    // TCS14: 5 GY 4/4, moderate olive green (leaf)
+    [
+        0.036, 0.036, 0.037, 0.038, 0.039, 0.039, 0.040, 0.041, 0.042,
+        0.042, 0.043, 0.044, 0.044, 0.045, 0.045, 0.046, 0.047, 0.048,
+        0.050, 0.052, 0.055, 0.057, 0.062, 0.067, 0.075, 0.083, 0.092,
+        0.100, 0.108, 0.121, 0.133, 0.142, 0.150, 0.154, 0.155, 0.152,
+        0.147, 0.140, 0.133, 0.125, 0.118, 0.112, 0.106, 0.101, 0.098,
+        0.095, 0.093, 0.090, 0.089, 0.087, 0.086, 0.085, 0.084, 0.084,
+        0.084, 0.084, 0.085, 0.087, 0.092, 0.096, 0.102, 0.110, 0.123,
+        0.137, 0.152, 0.169, 0.188, 0.205, 0.219, 0.233, 0.244, 0.252,
+        0.259, 0.265, 0.270, 0.274, 0.279, 0.282, 0.284, 0.286, 0.289,
+    ],
+];
+
+/// The color rendering indices of a light source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorRendering<T = f32> {
+    /// The general color rendering index, Ra, which is the average of the
+    /// first eight special indices.
+    pub general_index: T,
+
+    /// The special color rendering indices, from R1 to R14, one for each test
+    /// color sample.
+    pub special_indices: [T; 14],
+
+    /// The correlated color temperature of the light source, in kelvin.
+    pub correlated_color_temperature: T,
+
+    /// The distance, in the CIE 1960 UCS diagram, between the light source and
+    /// the Planckian locus. The indices aren't meaningful when it's larger
+    /// than `5.4e-3`, since the light source isn't really white then.
+    pub chromaticity_distance: T,
+}
+
+impl<T: FloatComponent> ColorRendering<T> {
+    /// Calculate the color rendering indices of the light source `test`.
+    ///
+    /// The reference illuminant is a black body radiator below 5000 K and a
+    /// CIE daylight illuminant from 5000 K, with the correlated color
+    /// temperature of `test`. The temperature is searched for between 1000 K
+    /// and 25000 K.
+    pub fn from_spd(test: &Spd<T>) -> Self {
+        let (temperature, _) = correlated_color_temperature(test);
+        let reference = if temperature < from_f64(DAYLIGHT_THRESHOLD) {
+            Spd::blackbody(temperature)
+        } else {
+            Spd::daylight(temperature)
+        };
+
+        Self::with_reference(test, &reference)
+    }
+
+    /// Calculate the color rendering indices of the light source `test`,
+    /// compared to the light source `reference`.
+    pub fn with_reference(test: &Spd<T>, reference: &Spd<T>) -> Self {
+        let (temperature, distance) = correlated_color_temperature(test);
+        let special_indices = special_indices(test, reference);
+        let general_index = special_indices[..8]
+            .iter()
+            .fold(T::zero(), |sum, &index| sum + index)
+            / from_f64(8.0);
+
+        ColorRendering {
+            general_index,
+            special_indices,
+            correlated_color_temperature: temperature,
+            chromaticity_distance: distance,
+        }
+    }
+
+    /// The special color rendering index of the saturated red test color
+    /// sample, R9.
+    pub fn r9(&self) -> T {
+        self.special_indices[8]
+    }
+}
+
+/// The correlated color temperature of a light source, and its distance from
+/// the Planckian locus in the CIE 1960 UCS diagram.
+fn correlated_color_temperature<T: FloatComponent>(light: &Spd<T>) -> (T, T) {
+    let (u, v) = xyz_to_uv_1960(&light.to_xyz::<E>());
+    let distance = |temperature: T| {
+        let (planck_u, planck_v) = xyz_to_uv_1960(&Spd::blackbody(temperature).to_xyz::<E>());
+        hypot(u - planck_u, v - planck_v)
+    };
+
+    // The distance changes more evenly with the reciprocal temperature, so
+    // that's where the golden section search is made.
+    let ratio = from_f64::<T>(0.5 * (5.0f64.sqrt() - 1.0));
+    let mut low = from_f64::<T>(1e6 / MAX_TEMPERATURE);
+    let mut high = from_f64::<T>(1e6 / MIN_TEMPERATURE);
+    let million = from_f64::<T>(1e6);
+
+    for _ in 0..64 {
+        let a = high - (high - low) * ratio;
+        let b = low + (high - low) * ratio;
+
+        if distance(million / a) < distance(million / b) {
+            high = b;
+        } else {
+            low = a;
+        }
+    }
+
+    let temperature = million / ((low + high) / from_f64(2.0));
+    (temperature, distance(temperature))
+}
+
+/// The special color rendering indices of `test`, compared to `reference`.
+fn special_indices<T: FloatComponent>(test: &Spd<T>, reference: &Spd<T>) -> [T; 14] {
+    let hundred = from_f64::<T>(100.0);
+    let (test_u, test_v) = xyz_to_uv_1960(&test.to_xyz::<E>());
+    let (reference_u, reference_v) = xyz_to_uv_1960(&reference.to_xyz::<E>());
+    let (test_c, test_d) = von_kries_coefficients(test_u, test_v);
+    let (reference_c, reference_d) = von_kries_coefficients(reference_u, reference_v);
+
+    let uvw = |color: Xyz<E, T>, u: T, v: T| {
+        let w = from_f64::<T>(25.0) * cbrt(color.y * hundred) - from_f64(17.0);
+        [
+            from_f64::<T>(13.0) * w * (u - reference_u),
+            from_f64::<T>(13.0) * w * (v - reference_v),
+            w,
+        ]
+    };
+
+    let mut indices = [T::zero(); 14];
+    for (index, sample) in indices.iter_mut().zip(TEST_COLOR_SAMPLES.iter()) {
+        let sample = Spd::new(
+            from_f64(cmf::START),
+            from_f64(cmf::STEP),
+            sample.iter().map(|&value| from_f64(value)).collect(),
+        );
+
+        let color = sample.reflectance_to_xyz::<E>(reference);
+        let (u, v) = xyz_to_uv_1960(&color);
+        let expected = uvw(color, u, v);
+
+        // Adapt the color under the test light source to the white of the
+        // reference illuminant.
+        let color = sample.reflectance_to_xyz::<E>(test);
+        let (u, v) = xyz_to_uv_1960(&color);
+        let (c, d) = von_kries_coefficients(u, v);
+        let c = reference_c / test_c * c;
+        let d = reference_d / test_d * d;
+        let denominator = from_f64::<T>(16.518) + from_f64::<T>(1.481) * c - d;
+        let u = (from_f64::<T>(10.872) + from_f64::<T>(0.404) * c - from_f64::<T>(4.0) * d)
+            / denominator;
+        let v = from_f64::<T>(5.520) / denominator;
+        let actual = uvw(color, u, v);
+
+        let difference = ((expected[0] - actual[0]).powi(2)
+            + (expected[1] - actual[1]).powi(2)
+            + (expected[2] - actual[2]).powi(2))
+        .sqrt();
+        *index = hundred - from_f64::<T>(4.6) * difference;
+    }
+
+    indices
+}
+
+/// The `c` and `d` coefficients of the von Kries transform in CIE 13.3.
+fn von_kries_coefficients<T: FloatComponent>(u: T, v: T) -> (T, T) {
+    (
+        (from_f64::<T>(4.0) - u - from_f64::<T>(10.0) * v) / v,
+        (from_f64::<T>(1.708) * v + from_f64(0.404) - from_f64::<T>(1.481) * u) / v,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::ColorRendering;
+    use crate::spectrum::Spd;
+
+    /// CIE F2, a cool white fluorescent lamp.
+    #[rustfmt::skip]
+    const F2: [f64; 81] = [
+        1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62, 5.06,
+        34.98, 11.81, 6.27, 6.63, 6.93, 7.19, 7.40, 7.54, 7.62, 7.65, 7.62,
+        7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47, 8.04, 8.88, 10.01,
+        24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47, 22.79, 19.29, 18.66,
+        17.73, 16.54, 15.21, 13.80, 12.36, 10.95, 9.65, 8.40, 7.32, 6.31, 5.43,
+        4.68, 4.02, 3.45, 2.96, 2.55, 2.19, 1.89, 1.64, 1.53, 1.27, 1.10, 0.99,
+        0.88, 0.76, 0.68, 0.61, 0.56, 0.54, 0.51, 0.47, 0.47, 0.43, 0.46, 0.47,
+        0.40, 0.33, 0.27,
+    ];
+
+    #[test]
+    fn fluorescent() {
+        let f2 = Spd::new(380.0, 5.0, F2.to_vec());
+        let rendering = ColorRendering::from_spd(&f2);
+
+        assert_relative_eq!(
+            rendering.correlated_color_temperature,
+            4230.0,
+            epsilon = 10.0
+        );
+        assert!(rendering.chromaticity_distance < 5.4e-3);
+        assert_relative_eq!(rendering.general_index, 64.0, epsilon = 0.5);
+
+        let published = [56.0, 77.0, 90.0, 57.0, 59.0, 67.0, 74.0, 33.0, -84.0];
+        for (&index, &published) in rendering.special_indices.iter().zip(&published) {
+            assert_relative_eq!(index, published, epsilon = 1.5);
+        }
+        assert_relative_eq!(rendering.r9(), -84.0, epsilon = 1.5);
+    }
+
+    #[test]
+    fn reference_illuminants() {
+        for &temperature in &[2856.0, 4000.0] {
+            let rendering = ColorRendering::from_spd(&Spd::blackbody(temperature));
+            assert_relative_eq!(
+                rendering.correlated_color_temperature,
+                temperature,
+                epsilon = 0.5
+            );
+            assert!(rendering.chromaticity_distance < 1e-6);
+            assert_relative_eq!(rendering.general_index, 100.0, epsilon = 1e-6);
+        }
+
+        let rendering = ColorRendering::from_spd(&Spd::daylight(6504.0f64));
+        assert_relative_eq!(
+            rendering.correlated_color_temperature,
+            6500.0,
+            epsilon = 10.0
+        );
+        assert_relative_eq!(rendering.general_index, 100.0, epsilon = 0.1);
+        for &index in &rendering.special_indices {
+            assert_relative_eq!(index, 100.0, epsilon = 0.2);
+        }
+    }
+
+    #[test]
+    fn with_reference() {
+        let light = Spd::blackbody(3000.0f64);
+        let rendering = ColorRendering::with_reference(&light, &light);
+        assert_relative_eq!(rendering.general_index, 100.0, epsilon = 1e-9);
+        assert_relative_eq!(rendering.r9(), 100.0, epsilon = 1e-9);
+    }
+}
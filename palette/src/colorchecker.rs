@@ -0,0 +1,302 @@
+//! Reference values for the ColorChecker chart, and color correction fitting.
+//!
+//! The ColorChecker Classic is a chart with 24 painted patches, that is made
+//! by X-Rite, and later Calibrite. Photographing it and comparing the
+//! measured colors with the reference values of the patches is a common way
+//! to calibrate cameras. [`Edition`](enum.Edition.html) has the reference
+//! values as CIE L\*a\*b\* under D50, and
+//! [`fit_matrix`](fn.fit_matrix.html) finds the 3x3 matrix that maps the
+//! measured colors to the references with the least squared error:
+//!
+//! ```
+//! use palette::colorchecker::{apply_matrix, fit_matrix, Edition};
+//! use palette::white_point::D50;
+//! use palette::{FromColor, Xyz};
+//!
+//! let reference: Vec<_> = Edition::After2014
+//!     .reference::<f64>()
+//!     .iter()
+//!     .map(|&lab| Xyz::<D50, f64>::from_color(lab).into_components())
+//!     .collect();
+//!
+//! // A camera that sees the patches with a bit too much red.
+//! let measured: Vec<_> = reference
+//!     .iter()
+//!     .map(|&(x, y, z)| (x * 1.2, y, z * 0.9))
+//!     .collect();
+//!
+//! let matrix = fit_matrix(&measured, &reference).unwrap();
+//! let (x, y, z) = apply_matrix(&matrix, measured[0]);
+//! assert!((x - reference[0].0).abs() < 1e-9);
+//! assert!((y - reference[0].1).abs() < 1e-9);
+//! assert!((z - reference[0].2).abs() < 1e-9);
+//! ```
+//!
+//! The patches are in the order they have on the chart, row by row, from
+//! the top left. [`PATCH_NAMES`](constant.PATCH_NAMES.html) has their names.
+//!
+//! [`PolynomialCorrection`](struct.PolynomialCorrection.html) fits
+//! polynomial and root-polynomial corrections, which can follow non-linear
+//! camera responses more closely than a matrix. It's only available if the
+//! `std` feature is enabled.
+
+use crate::error::FitError;
+use crate::matrix::{matrix_inverse, Mat3};
+use crate::white_point::D50;
+use crate::{from_f64, FloatComponent, Lab};
+
+#[cfg(feature = "std")]
+pub use self::polynomial::{Expansion, PolynomialCorrection};
+
+#[cfg(feature = "std")]
+mod polynomial;
+
+/// The names of the patches, in chart order.
+pub const PATCH_NAMES: [&str; 24] = [
+    "dark skin",
+    "light skin",
+    "blue sky",
+    "foliage",
+    "blue flower",
+    "bluish green",
+    "orange",
+    "purplish blue",
+    "moderate red",
+    "purple",
+    "yellow green",
+    "orange yellow",
+    "blue",
+    "green",
+    "red",
+    "yellow",
+    "magenta",
+    "cyan",
+    "white 9.5",
+    "neutral 8",
+    "neutral 6.5",
+    "neutral 5",
+    "neutral 3.5",
+    "black 2",
+];
+
+/// The reference values of charts made before November 2014.
+const BEFORE_2014: [[f64; 3]; 24] = [
+    [37.986, 13.555, 14.059],
+    [65.711, 18.13, 17.81],
+    [49.927, -4.88, -21.925],
+    [43.139, -13.095, 21.905],
+    [55.112, 8.844, -25.399],
+    [70.719, -33.397, -0.199],
+    [62.661, 36.067, 57.096],
+    [40.02, 10.41, -45.964],
+    [51.124, 48.239, 16.248],
+    [30.325, 22.976, -21.587],
+    [72.532, -23.709, 57.255],
+    [71.941, 19.363, 67.857],
+    [28.778, 14.179, -50.297],
+    [55.261, -38.342, 31.37],
+    [42.101, 53.378, 28.19],
+    [81.733, 4.039, 79.819],
+    [51.935, 49.986, -14.574],
+    [51.038, -28.631, -28.638],
+    [96.539, -0.425, 1.186],
+    [81.257, -0.638, -0.335],
+    [66.766, -0.734, -0.504],
+    [50.867, -0.153, -0.27],
+    [35.656, -0.421, -1.231],
+    [20.461, -0.079, -0.973],
+];
+
+/// The reference values of charts made after November 2014.
+const AFTER_2014: [[f64; 3]; 24] = [
+    [37.54, 14.37, 14.92],
+    [64.66, 19.27, 17.5],
+    [49.32, -3.82, -22.54],
+    [43.46, -12.74, 22.72],
+    [54.94, 9.61, -24.79],
+    [70.48, -32.26, -0.37],
+    [62.73, 35.83, 56.5],
+    [39.43, 10.75, -45.17],
+    [50.57, 48.64, 16.67],
+    [30.1, 22.54, -20.87],
+    [71.77, -24.13, 58.19],
+    [71.51, 18.24, 67.37],
+    [28.37, 15.42, -49.8],
+    [54.38, -39.72, 32.27],
+    [42.43, 51.05, 28.62],
+    [81.8, 2.67, 80.41],
+    [50.63, 51.28, -14.12],
+    [49.57, -29.71, -28.32],
+    [95.19, -1.03, 2.93],
+    [81.29, -0.57, 0.44],
+    [66.89, -0.75, -0.06],
+    [50.76, -0.13, 0.14],
+    [35.63, -0.46, -0.48],
+    [20.64, 0.07, -0.46],
+];
+
+/// An edition of the ColorChecker Classic chart.
+///
+/// The pigments were changed in November 2014, which moved some of the
+/// patches by more than one ΔE\*ab, so the reference values should match
+/// the chart that was photographed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Edition {
+    /// Charts made before November 2014, with the values that X-Rite
+    /// published in 2005.
+    Before2014,
+    /// Charts made after November 2014, with the values that X-Rite
+    /// published for the new formulation.
+    After2014,
+}
+
+impl Edition {
+    /// The reference values of the 24 patches, in chart order.
+    pub fn reference<T: FloatComponent>(self) -> [Lab<D50, T>; 24] {
+        let values = match self {
+            Edition::Before2014 => &BEFORE_2014,
+            Edition::After2014 => &AFTER_2014,
+        };
+
+        let mut reference = [Lab::with_wp(T::zero(), T::zero(), T::zero()); 24];
+        for (lab, &[l, a, b]) in reference.iter_mut().zip(values) {
+            *lab = Lab::with_wp(from_f64(l), from_f64(a), from_f64(b));
+        }
+
+        reference
+    }
+}
+
+/// Find the 3x3 matrix that maps the `measured` colors to the `reference`
+/// colors with the least squared error.
+///
+/// The colors are given as components, such as from `into_components`, and
+/// should be linear, such as linear RGB or XYZ. The matrix is row major and
+/// can be applied with [`apply_matrix`](fn.apply_matrix.html).
+///
+/// Returns an error if the slices have different lengths, if there are
+/// fewer than three colors, or if the measured colors don't span all three
+/// dimensions.
+pub fn fit_matrix<T: FloatComponent>(
+    measured: &[(T, T, T)],
+    reference: &[(T, T, T)],
+) -> Result<Mat3<T>, FitError> {
+    if measured.len() != reference.len() {
+        return Err(FitError::MismatchedLength {
+            measured: measured.len(),
+            reference: reference.len(),
+        });
+    }
+
+    if measured.len() < 3 {
+        return Err(FitError::TooFewColors {
+            needed: 3,
+            found: measured.len(),
+        });
+    }
+
+    // The normal equations, with one right hand side per output component.
+    let mut gram = [T::zero(); 9];
+    let mut right = [T::zero(); 9];
+    for (&(m0, m1, m2), &(r0, r1, r2)) in measured.iter().zip(reference) {
+        let m = [m0, m1, m2];
+        let r = [r0, r1, r2];
+        for row in 0..3 {
+            for column in 0..3 {
+                gram[row * 3 + column] = gram[row * 3 + column] + m[row] * m[column];
+                right[row * 3 + column] = right[row * 3 + column] + r[row] * m[column];
+            }
+        }
+    }
+
+    let determinant = gram[0] * (gram[4] * gram[8] - gram[5] * gram[7])
+        - gram[1] * (gram[3] * gram[8] - gram[5] * gram[6])
+        + gram[2] * (gram[3] * gram[7] - gram[4] * gram[6]);
+    if !determinant.is_normal() {
+        return Err(FitError::Singular);
+    }
+
+    // Each row of the result is the inverse Gram matrix times the
+    // corresponding row of `right`. The Gram matrix is symmetric.
+    let inverse = matrix_inverse(&gram);
+    let mut matrix = [T::zero(); 9];
+    for row in 0..3 {
+        for column in 0..3 {
+            matrix[row * 3 + column] = (0..3).fold(T::zero(), |sum, k| {
+                sum + right[row * 3 + k] * inverse[k * 3 + column]
+            });
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// Multiply `color` with a row major 3x3 matrix, such as from
+/// [`fit_matrix`](fn.fit_matrix.html).
+pub fn apply_matrix<T: FloatComponent>(matrix: &Mat3<T>, color: (T, T, T)) -> (T, T, T) {
+    let (c0, c1, c2) = color;
+    (
+        matrix[0] * c0 + matrix[1] * c1 + matrix[2] * c2,
+        matrix[3] * c0 + matrix[4] * c1 + matrix[5] * c2,
+        matrix[6] * c0 + matrix[7] * c1 + matrix[8] * c2,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_matrix, fit_matrix, Edition, PATCH_NAMES};
+    use crate::error::FitError;
+    use crate::white_point::D50;
+    use crate::Lab;
+
+    #[test]
+    fn reference_values() {
+        let before = Edition::Before2014.reference::<f64>();
+        let after = Edition::After2014.reference::<f64>();
+        assert_eq!(PATCH_NAMES[1], "light skin");
+        assert_eq!(before[1], Lab::<D50, f64>::with_wp(65.711, 18.13, 17.81));
+        assert_eq!(after[23], Lab::<D50, f64>::with_wp(20.64, 0.07, -0.46));
+    }
+
+    #[test]
+    fn fit_exact_matrix() {
+        let expected = [0.8, 0.15, 0.05, 0.1, 0.7, 0.2, 0.0, 0.1, 0.9];
+        let reference: Vec<_> = Edition::After2014
+            .reference::<f64>()
+            .iter()
+            .map(|lab| (lab.l, lab.a + 128.0, lab.b + 128.0))
+            .collect();
+        let inverse = crate::matrix::matrix_inverse(&expected);
+        let measured: Vec<_> = reference
+            .iter()
+            .map(|&color| apply_matrix(&inverse, color))
+            .collect();
+
+        let matrix = fit_matrix(&measured, &reference).unwrap();
+        for (&found, &expected) in matrix.iter().zip(&expected) {
+            assert_relative_eq!(found, expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn fit_errors() {
+        let colors = [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)];
+        assert_eq!(
+            fit_matrix(&colors, &colors[..2]),
+            Err(FitError::MismatchedLength {
+                measured: 3,
+                reference: 2
+            })
+        );
+        assert_eq!(
+            fit_matrix(&colors[..2], &colors[..2]),
+            Err(FitError::TooFewColors {
+                needed: 3,
+                found: 2
+            })
+        );
+
+        let flat = [(1.0, 1.0, 0.0), (2.0, 2.0, 0.0), (0.5, 0.5, 0.0)];
+        assert_eq!(fit_matrix(&flat, &colors), Err(FitError::Singular));
+    }
+}
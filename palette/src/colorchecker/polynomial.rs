@@ -0,0 +1,388 @@
+use crate::error::FitError;
+use crate::math::powf;
+use crate::{from_f64, FloatComponent};
+
+/// The terms that the components of a color are expanded into, before they
+/// are mapped to the corrected color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expansion {
+    /// All products of the components, up to the degree, such as `r`, `rg`
+    /// and `r²` for degree 2.
+    Polynomial,
+    /// The products of the components, up to the degree, raised to one over
+    /// their own degree, such as `r` and `√(rg)` for degree 2.
+    ///
+    /// Each term scales linearly with the intensity of the color, so the
+    /// correction works the same for any exposure. This is the
+    /// root-polynomial color correction of Finlayson, Mackiewicz and
+    /// Hurlbert (2015). Negative components are treated as zero.
+    RootPolynomial,
+}
+
+/// A polynomial or root-polynomial color correction, which maps colors to
+/// the weighted sums of their expanded terms.
+///
+/// It's a generalization of the 3x3 matrix from
+/// [`fit_matrix`](fn.fit_matrix.html), which is the same as a correction of
+/// degree 1. Higher degrees can follow the non-linear responses of real
+/// cameras more closely, but they need more colors to fit and may
+/// overshoot for colors that are far from the fitted ones.
+///
+/// ```
+/// use palette::colorchecker::{Expansion, PolynomialCorrection};
+///
+/// let measured = [
+///     (0.1f64, 0.1, 0.1),
+///     (0.8, 0.2, 0.1),
+///     (0.2, 0.7, 0.2),
+///     (0.1, 0.2, 0.9),
+///     (0.5, 0.5, 0.1),
+///     (0.3, 0.1, 0.6),
+///     (0.9, 0.9, 0.8),
+///     (0.4, 0.6, 0.7),
+/// ];
+///
+/// // A camera that mixes a bit of green into red, and of red into blue.
+/// let reference: Vec<_> = measured
+///     .iter()
+///     .map(|&(r, g, b)| (r + 0.2 * (r * g).sqrt(), g, b + 0.1 * (r * b).sqrt()))
+///     .collect();
+///
+/// let correction =
+///     PolynomialCorrection::fit(Expansion::RootPolynomial, 2, &measured, &reference).unwrap();
+/// assert_eq!(correction.terms(), 6);
+///
+/// let (r, g, b) = correction.apply((0.6, 0.3, 0.4));
+/// assert!((r - (0.6 + 0.2 * 0.18f64.sqrt())).abs() < 1e-9);
+/// assert!((g - 0.3).abs() < 1e-9);
+/// assert!((b - (0.4 + 0.1 * 0.24f64.sqrt())).abs() < 1e-9);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolynomialCorrection<T> {
+    expansion: Expansion,
+    degree: u32,
+    /// The exponents of the components in each term.
+    exponents: Vec<[u32; 3]>,
+    /// The weights of each term, for each output component.
+    coefficients: Vec<[T; 3]>,
+}
+
+impl<T: FloatComponent> PolynomialCorrection<T> {
+    /// Find the correction that maps the `measured` colors to the
+    /// `reference` colors with the least squared error.
+    ///
+    /// The colors are given as components, such as from `into_components`,
+    /// and should be linear. Returns an error if the slices have different
+    /// lengths, if there are fewer colors than terms, or if the measured
+    /// colors don't determine a unique correction.
+    ///
+    /// Panics if `degree` is `0`.
+    pub fn fit(
+        expansion: Expansion,
+        degree: u32,
+        measured: &[(T, T, T)],
+        reference: &[(T, T, T)],
+    ) -> Result<Self, FitError> {
+        assert!(degree > 0, "the degree must be at least 1");
+
+        if measured.len() != reference.len() {
+            return Err(FitError::MismatchedLength {
+                measured: measured.len(),
+                reference: reference.len(),
+            });
+        }
+
+        let exponents = exponents(expansion, degree);
+        let terms = exponents.len();
+        if measured.len() < terms {
+            return Err(FitError::TooFewColors {
+                needed: terms,
+                found: measured.len(),
+            });
+        }
+
+        // The normal equations, with one right hand side per output
+        // component, stored next to each other in each row.
+        let width = terms + 3;
+        let mut system = vec![T::zero(); terms * width];
+        let mut expanded = vec![T::zero(); terms];
+        for (&color, &(r0, r1, r2)) in measured.iter().zip(reference) {
+            expand(expansion, &exponents, color, &mut expanded);
+            for (row, &x) in expanded.iter().enumerate() {
+                let row = &mut system[row * width..(row + 1) * width];
+                for (value, &y) in row.iter_mut().zip(&expanded) {
+                    *value = *value + x * y;
+                }
+                row[terms] = row[terms] + x * r0;
+                row[terms + 1] = row[terms + 1] + x * r1;
+                row[terms + 2] = row[terms + 2] + x * r2;
+            }
+        }
+
+        let solution = solve(&mut system, terms)?;
+        Ok(PolynomialCorrection {
+            expansion,
+            degree,
+            exponents,
+            coefficients: solution,
+        })
+    }
+
+    /// Correct `color`.
+    pub fn apply(&self, color: (T, T, T)) -> (T, T, T) {
+        let mut result = [T::zero(); 3];
+        for (exponents, coefficients) in self.exponents.iter().zip(&self.coefficients) {
+            let term = term(self.expansion, *exponents, color);
+            for (result, &coefficient) in result.iter_mut().zip(coefficients) {
+                *result = *result + coefficient * term;
+            }
+        }
+
+        let [r, g, b] = result;
+        (r, g, b)
+    }
+
+    /// The kind of terms that the colors are expanded into.
+    pub fn expansion(&self) -> Expansion {
+        self.expansion
+    }
+
+    /// The highest degree of the terms.
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    /// The number of terms, which is also the smallest number of colors
+    /// that can be fitted.
+    pub fn terms(&self) -> usize {
+        self.exponents.len()
+    }
+}
+
+/// The exponents of the components in each term, ordered by degree.
+fn exponents(expansion: Expansion, degree: u32) -> Vec<[u32; 3]> {
+    let mut exponents = Vec::new();
+    for total in 1..=degree {
+        for r in (0..=total).rev() {
+            for g in (0..=total - r).rev() {
+                let b = total - r - g;
+
+                // Roots of powers of lower degree terms are the same as the
+                // lower degree terms, such as √(r²) = r.
+                if expansion == Expansion::RootPolynomial && gcd(gcd(r, g), b) > 1 {
+                    continue;
+                }
+
+                exponents.push([r, g, b]);
+            }
+        }
+    }
+
+    exponents
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn term<T: FloatComponent>(expansion: Expansion, exponents: [u32; 3], color: (T, T, T)) -> T {
+    let (r, g, b) = color;
+    let [er, eg, eb] = exponents;
+
+    match expansion {
+        Expansion::Polynomial => r.powi(er as i32) * g.powi(eg as i32) * b.powi(eb as i32),
+        Expansion::RootPolynomial => {
+            let product = r.max(T::zero()).powi(er as i32)
+                * g.max(T::zero()).powi(eg as i32)
+                * b.max(T::zero()).powi(eb as i32);
+
+            match er + eg + eb {
+                1 => product,
+                2 => product.sqrt(),
+                degree => powf(product, T::one() / from_f64(f64::from(degree))),
+            }
+        }
+    }
+}
+
+fn expand<T: FloatComponent>(
+    expansion: Expansion,
+    exponents: &[[u32; 3]],
+    color: (T, T, T),
+    expanded: &mut [T],
+) {
+    for (value, &exponents) in expanded.iter_mut().zip(exponents) {
+        *value = term(expansion, exponents, color);
+    }
+}
+
+/// Solve a linear system with three right hand sides, using Gaussian
+/// elimination with partial pivoting. `system` has `size` rows, with the
+/// `size` coefficients followed by the three right hand sides.
+fn solve<T: FloatComponent>(system: &mut [T], size: usize) -> Result<Vec<[T; 3]>, FitError> {
+    let width = size + 3;
+    let largest = (0..size)
+        .map(|i| system[i * width + i].abs())
+        .fold(T::zero(), T::max);
+    let tolerance = largest * T::epsilon() * from_f64(size as f64);
+
+    for column in 0..size {
+        let pivot_row = (column..size)
+            .max_by(|&a, &b| {
+                let a = system[a * width + column].abs();
+                let b = system[b * width + column].abs();
+                a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .expect("there should be at least one row left");
+
+        let pivot = system[pivot_row * width + column];
+        if pivot.abs() <= tolerance || pivot.is_nan() {
+            return Err(FitError::Singular);
+        }
+
+        for index in 0..width {
+            system.swap(column * width + index, pivot_row * width + index);
+        }
+
+        for row in column + 1..size {
+            let factor = system[row * width + column] / pivot;
+            for index in column..width {
+                system[row * width + index] =
+                    system[row * width + index] - factor * system[column * width + index];
+            }
+        }
+    }
+
+    let mut solution = vec![[T::zero(); 3]; size];
+    for row in (0..size).rev() {
+        for output in 0..3 {
+            let mut value = system[row * width + size + output];
+            for (column, solved) in solution.iter().enumerate().skip(row + 1) {
+                value = value - system[row * width + column] * solved[output];
+            }
+            solution[row][output] = value / system[row * width + row];
+        }
+    }
+
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{exponents, Expansion, PolynomialCorrection};
+    use crate::colorchecker::fit_matrix;
+    use crate::error::FitError;
+
+    const MEASURED: [(f64, f64, f64); 12] = [
+        (0.05, 0.05, 0.05),
+        (0.8, 0.2, 0.1),
+        (0.2, 0.7, 0.2),
+        (0.1, 0.2, 0.9),
+        (0.5, 0.5, 0.1),
+        (0.3, 0.1, 0.6),
+        (0.9, 0.9, 0.8),
+        (0.4, 0.6, 0.7),
+        (0.7, 0.4, 0.3),
+        (0.2, 0.3, 0.4),
+        (0.6, 0.8, 0.3),
+        (0.35, 0.25, 0.15),
+    ];
+
+    #[test]
+    fn term_counts() {
+        let counts = |expansion| -> Vec<_> {
+            (1..=4)
+                .map(|degree| exponents(expansion, degree).len())
+                .collect()
+        };
+
+        assert_eq!(counts(Expansion::Polynomial), [3, 9, 19, 34]);
+        assert_eq!(counts(Expansion::RootPolynomial), [3, 6, 13, 22]);
+    }
+
+    #[test]
+    fn degree_one_is_matrix() {
+        let reference: Vec<_> = MEASURED
+            .iter()
+            .map(|&(r, g, b)| (r * r, 0.5 * g + 0.1 * b, b.sqrt()))
+            .collect();
+
+        let matrix = fit_matrix(&MEASURED, &reference).unwrap();
+        for &expansion in &[Expansion::Polynomial, Expansion::RootPolynomial] {
+            let correction =
+                PolynomialCorrection::fit(expansion, 1, &MEASURED, &reference).unwrap();
+            let (r, g, b) = correction.apply(MEASURED[3]);
+            let (mr, mg, mb) = crate::colorchecker::apply_matrix(&matrix, MEASURED[3]);
+            assert_relative_eq!(r, mr, epsilon = 1e-9);
+            assert_relative_eq!(g, mg, epsilon = 1e-9);
+            assert_relative_eq!(b, mb, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn fits_polynomial() {
+        let reference: Vec<_> = MEASURED
+            .iter()
+            .map(|&(r, g, b)| (r + 0.3 * r * g, g - 0.2 * b * b, b))
+            .collect();
+
+        let correction =
+            PolynomialCorrection::fit(Expansion::Polynomial, 2, &MEASURED, &reference).unwrap();
+        assert_eq!(correction.degree(), 2);
+        assert_eq!(correction.terms(), 9);
+
+        let (r, g, b) = correction.apply((0.5, 0.4, 0.3));
+        assert_relative_eq!(r, 0.5 + 0.3 * 0.5 * 0.4, epsilon = 1e-9);
+        assert_relative_eq!(g, 0.4 - 0.2 * 0.3 * 0.3, epsilon = 1e-9);
+        assert_relative_eq!(b, 0.3, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn root_polynomial_is_exposure_invariant() {
+        let reference: Vec<_> = MEASURED
+            .iter()
+            .map(|&(r, g, b)| (r + 0.2 * (g * b).sqrt(), g, b))
+            .collect();
+        let correction =
+            PolynomialCorrection::fit(Expansion::RootPolynomial, 2, &MEASURED, &reference).unwrap();
+
+        let (r1, g1, b1) = correction.apply((0.2, 0.3, 0.1));
+        let (r2, g2, b2) = correction.apply((0.4, 0.6, 0.2));
+        assert_relative_eq!(r1 * 2.0, r2, epsilon = 1e-9);
+        assert_relative_eq!(g1 * 2.0, g2, epsilon = 1e-9);
+        assert_relative_eq!(b1 * 2.0, b2, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            PolynomialCorrection::fit(Expansion::Polynomial, 2, &MEASURED[..8], &MEASURED[..8]),
+            Err(FitError::TooFewColors {
+                needed: 9,
+                found: 8
+            })
+        );
+        assert_eq!(
+            PolynomialCorrection::fit(Expansion::Polynomial, 1, &MEASURED, &MEASURED[..8]),
+            Err(FitError::MismatchedLength {
+                measured: 12,
+                reference: 8
+            })
+        );
+
+        let gray: Vec<_> = (0..12)
+            .map(|i| {
+                let value = f64::from(i) / 11.0;
+                (value, value, value)
+            })
+            .collect();
+        assert_eq!(
+            PolynomialCorrection::fit(Expansion::Polynomial, 1, &gray, &gray),
+            Err(FitError::Singular)
+        );
+    }
+}
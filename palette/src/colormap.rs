@@ -0,0 +1,211 @@
+//! Perceptually uniform colormaps for data visualization.
+//!
+//! The colormaps are the ones from matplotlib: viridis, inferno, magma and
+//! plasma, by Nathaniel J. Smith, Stefan van der Walt, and (in the case of
+//! viridis) Eric Firing, and Turbo, by Anton Mikhailov. Each of them is baked
+//! into a table of 256 sRGB colors, that is interpolated when it's sampled,
+//! so they can be used without the standard library.
+//!
+//! ```
+//! use palette::colormap;
+//! use palette::Srgb;
+//!
+//! let low: Srgb<u8> = colormap::VIRIDIS.get(0.0).into_format();
+//! let high: Srgb<u8> = colormap::VIRIDIS.get(1.0).into_format();
+//! assert_eq!(low, Srgb::new(68, 1, 84));
+//! assert_eq!(high, Srgb::new(253, 231, 37));
+//!
+//! assert_eq!(colormap::from_name("magma"), Some(colormap::MAGMA));
+//! ```
+//!
+//! The colormaps of matplotlib are released under the CC0 license public
+//! domain dedication, and Turbo under the Apache 2.0 license. Turbo is baked
+//! from its polynomial approximation.
+//!
+//! The cividis colormap of matplotlib is not included yet. Unlike the others,
+//! it's not among the gradients in `build/svg_gradients_mpl.txt` that the
+//! tables are baked from, and it doesn't have a polynomial approximation like
+//! Turbo, so its table has to be added to the build data first.
+
+use crate::{from_f64, FloatComponent, Srgb};
+
+#[cfg(feature = "std")]
+use crate::{Gradient, LinSrgb};
+
+include!(concat!(env!("OUT_DIR"), "/colormaps.rs"));
+
+/// The viridis colormap, from dark blue, through green, to yellow.
+pub const VIRIDIS: Colormap = Colormap {
+    name: "viridis",
+    colors: &VIRIDIS_COLORS,
+};
+
+/// The inferno colormap, from black, through red, to light yellow.
+pub const INFERNO: Colormap = Colormap {
+    name: "inferno",
+    colors: &INFERNO_COLORS,
+};
+
+/// The magma colormap, from black, through purple and red, to light yellow.
+pub const MAGMA: Colormap = Colormap {
+    name: "magma",
+    colors: &MAGMA_COLORS,
+};
+
+/// The plasma colormap, from dark blue, through pink, to yellow.
+pub const PLASMA: Colormap = Colormap {
+    name: "plasma",
+    colors: &PLASMA_COLORS,
+};
+
+/// The Turbo colormap, a rainbow from dark blue, through green, to dark red.
+///
+/// It's not as perceptually uniform as the others, but it has more distinct
+/// steps, which makes it suitable for things like depth and disparity maps.
+pub const TURBO: Colormap = Colormap {
+    name: "turbo",
+    colors: &TURBO_COLORS,
+};
+
+/// Get a colormap by its lower case name, such as `"viridis"`.
+pub fn from_name(name: &str) -> Option<Colormap> {
+    match name {
+        "viridis" => Some(VIRIDIS),
+        "inferno" => Some(INFERNO),
+        "magma" => Some(MAGMA),
+        "plasma" => Some(PLASMA),
+        "turbo" => Some(TURBO),
+        _ => None,
+    }
+}
+
+/// A colormap, as a table of evenly spaced sRGB colors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Colormap {
+    name: &'static str,
+    colors: &'static [[f32; 3]],
+}
+
+impl Colormap {
+    /// The lower case name of the colormap.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The colors of the table, from the start of the colormap to the end.
+    pub fn colors(&self) -> impl ExactSizeIterator<Item = Srgb> + DoubleEndedIterator {
+        self.colors
+            .iter()
+            .map(|&[red, green, blue]| Srgb::new(red, green, blue))
+    }
+
+    /// Sample the colormap at `position`, from `0.0` to `1.0`. Positions
+    /// outside that range are clamped, and positions between the colors of
+    /// the table are interpolated in linear sRGB, like in the gradient from
+    /// [`to_gradient`](#method.to_gradient).
+    pub fn get<T: FloatComponent>(&self, position: T) -> Srgb<T> {
+        let last = self.colors.len() - 1;
+        let position = position.max(T::zero()).min(T::one()) * from_f64(last as f64);
+        let index = position.floor().to_usize().unwrap_or(0).min(last - 1);
+        let factor = position - from_f64(index as f64);
+
+        let color = |index: usize| {
+            let [red, green, blue] = self.colors[index];
+            Srgb::new(
+                from_f64::<T>(f64::from(red)),
+                from_f64(f64::from(green)),
+                from_f64(f64::from(blue)),
+            )
+        };
+
+        // The colors of the table are returned as they are, without the
+        // rounding errors of going through linear sRGB.
+        if factor == T::zero() {
+            return color(index);
+        } else if factor == T::one() {
+            return color(index + 1);
+        }
+
+        let start = color(index).into_linear();
+        let end = color(index + 1).into_linear();
+        Srgb::from_linear(start + (end - start) * factor)
+    }
+
+    /// Sample the colormap from `1.0` to `0.0` instead, like the `_r`
+    /// variants of matplotlib.
+    pub fn get_reversed<T: FloatComponent>(&self, position: T) -> Srgb<T> {
+        self.get(T::one() - position)
+    }
+
+    /// Create a gradient from the colors of the table, in linear sRGB.
+    ///
+    /// This is only available if the `std` feature is enabled (this is the
+    /// default).
+    #[cfg(feature = "std")]
+    pub fn to_gradient(&self) -> Gradient<LinSrgb> {
+        Gradient::from_colors(self.colors())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_name, INFERNO, MAGMA, PLASMA, TURBO, VIRIDIS};
+    use crate::{FromColor, Lab, LinSrgb, Srgb};
+
+    #[test]
+    fn tables() {
+        for colormap in &[VIRIDIS, INFERNO, MAGMA, PLASMA, TURBO] {
+            assert_eq!(colormap.colors().len(), 256);
+            assert_eq!(from_name(colormap.name()), Some(*colormap));
+        }
+
+        assert_eq!(from_name("jet"), None);
+    }
+
+    #[test]
+    fn increasing_lightness() {
+        for colormap in &[VIRIDIS, INFERNO, MAGMA, PLASMA] {
+            let lightness: Vec<f32> = colormap
+                .colors()
+                .map(|color| Lab::from_color(color.into_linear()).l)
+                .collect();
+
+            for pair in lightness.windows(2) {
+                assert!(pair[0] < pair[1], "{}", colormap.name());
+            }
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let first = VIRIDIS.colors().next().unwrap();
+        let second = VIRIDIS.colors().nth(1).unwrap();
+        let last = VIRIDIS.colors().last().unwrap();
+
+        assert_eq!(VIRIDIS.get(0.0f32), first);
+        assert_eq!(VIRIDIS.get(-1.0f32), first);
+        assert_eq!(VIRIDIS.get(2.0f32), last);
+        assert_eq!(VIRIDIS.get_reversed(0.0f32), last);
+        assert_relative_eq!(
+            VIRIDIS.get(0.5f32 / 255.0),
+            Srgb::from_linear((first.into_linear() + second.into_linear()) / 2.0),
+            epsilon = 1e-6
+        );
+
+        let turbo: Srgb<u8> = TURBO.get(0.5f32).into_format();
+        assert!(turbo.green > turbo.red && turbo.green > turbo.blue);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gradient() {
+        let gradient = MAGMA.to_gradient();
+        for &position in &[0.0, 0.3, 0.5001, 0.75, 1.0] {
+            assert_relative_eq!(
+                gradient.get(position),
+                LinSrgb::from_color(MAGMA.get(position)),
+                epsilon = 1e-5
+            );
+        }
+    }
+}
@@ -4,6 +4,11 @@ use crate::float::Float;
 use crate::{clamp, FromF64};
 
 /// Common trait for color components.
+///
+/// This is implemented for the primitive float and unsigned integer types, but
+/// it can also be implemented for custom number types. See
+/// [`FloatComponent`](crate::FloatComponent) for how to use such types with
+/// the color spaces that require floating point components.
 pub trait Component: Copy + Zero + PartialOrd {
     /// The highest displayable value this component type can reach. Higher
     /// values are allowed, but they may be lowered to this before
@@ -12,6 +17,38 @@ pub trait Component: Copy + Zero + PartialOrd {
 }
 
 /// Common trait for floating point color components.
+///
+/// `FloatComponent` is automatically implemented for any type that implements
+/// [`Component`](crate::Component), [`Float`](crate::float::Float) and
+/// [`FromF64`](crate::FromF64). That makes it possible to use custom number
+/// types, and not only `f32` and `f64`, as color components. Some examples are:
+///
+/// * Dual numbers, for forward mode automatic differentiation. This makes it
+///   possible to calculate the derivative of any conversion or operation, and
+///   use it for optimizing colors with gradient descent.
+/// * Interval types, for tracking the accumulated error or uncertainty through
+///   a chain of conversions.
+/// * Fixed point or software float types, for platforms without a floating
+///   point unit.
+///
+/// The requirements for such a type are:
+///
+/// * `Component`, where `max_intensity` should return `1.0`. The type's
+///   `PartialOrd` implementation is used when clamping and checking bounds, so
+///   it should compare the value the type represents (the real part of a dual
+///   number, for example).
+/// * `Float`, which is the general float math interface. Not every function is
+///   used by the conversions, but they should all behave like their `f32` and
+///   `f64` counterparts.
+/// * `FromF64`, which is used for creating all constants. Conversion
+///   constants are stored as `f64` values and are converted to the component
+///   type where they are used, so this conversion should be exact for types
+///   that are at least as precise as `f64`.
+///
+/// Note that some conversions branch on the component values, such as when
+/// the hue is calculated or when a transfer function switches between its
+/// linear and non-linear segments. Dual numbers and interval types will follow
+/// the branch that is taken by the value they represent.
 pub trait FloatComponent: Component + Float + FromF64 {}
 
 impl<T: Component + Float + FromF64> FloatComponent for T {}
@@ -90,6 +127,152 @@ impl<T: Component> IntoComponent<T> for T {
     }
 }
 
+/// Converts a float color component into an unsigned integer component, with
+/// a chosen [`Rounding`](crate::Rounding) mode.
+///
+/// The exact formula is `round(clamp(x * MAX, 0, MAX))`, where `MAX` is the
+/// maximum value of the integer type and `round` is the rounding mode. The
+/// calculation is done in `f32` for `f32` to `u8` and `u16`, and in `f64`
+/// otherwise. `NaN` becomes `MAX`. Using [`RoundNearest`](crate::RoundNearest)
+/// gives the same result as [`IntoComponent`](crate::IntoComponent).
+///
+/// ```
+/// use palette::{IntoComponentRounded, RoundFloor, RoundNearest};
+///
+/// assert_eq!(IntoComponentRounded::<u8>::into_component_rounded(0.999f32, &mut RoundNearest), 255);
+/// assert_eq!(IntoComponentRounded::<u8>::into_component_rounded(0.999f32, &mut RoundFloor), 254);
+/// ```
+pub trait IntoComponentRounded<T: Component>: IntoComponent<T> {
+    /// Converts `self` into `T`, while performing the appropriate scaling
+    /// and clamping, and rounding with `rounding`.
+    fn into_component_rounded<R: Rounding + ?Sized>(self, rounding: &mut R) -> T;
+}
+
+/// A rounding mode for converting float components to integers.
+///
+/// The values are already scaled to the range of the integer type and
+/// clamped to be at least `0.0`. The result is clamped to the range of the
+/// integer type again after rounding, and any fractional part is truncated.
+pub trait Rounding {
+    /// Round an `f32` value.
+    fn round_f32(&mut self, value: f32) -> f32;
+
+    /// Round an `f64` value.
+    fn round_f64(&mut self, value: f64) -> f64;
+}
+
+impl<R: Rounding + ?Sized> Rounding for &mut R {
+    #[inline]
+    fn round_f32(&mut self, value: f32) -> f32 {
+        (**self).round_f32(value)
+    }
+
+    #[inline]
+    fn round_f64(&mut self, value: f64) -> f64 {
+        (**self).round_f64(value)
+    }
+}
+
+/// Round to the nearest integer, with ties rounded to the nearest even
+/// integer. This is what [`IntoComponent`](crate::IntoComponent) does.
+///
+/// The rounding is done as `(x + 2^23) - 2^23` in `f32` and as
+/// `(x + 2^52) - 2^52` in `f64`, which gives the same result on all platforms
+/// with IEEE 754 arithmetic. Larger values are already integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundNearest;
+
+impl Rounding for RoundNearest {
+    #[inline]
+    fn round_f32(&mut self, value: f32) -> f32 {
+        let offset = f32::from_bits(C23);
+        if value < offset {
+            (value + offset) - offset
+        } else {
+            value
+        }
+    }
+
+    #[inline]
+    fn round_f64(&mut self, value: f64) -> f64 {
+        let offset = f64::from_bits(C52);
+        if value < offset {
+            (value + offset) - offset
+        } else {
+            value
+        }
+    }
+}
+
+/// Round down to the nearest integer, as in `x.floor()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundFloor;
+
+impl Rounding for RoundFloor {
+    #[inline]
+    fn round_f32(&mut self, value: f32) -> f32 {
+        value.floor()
+    }
+
+    #[inline]
+    fn round_f64(&mut self, value: f64) -> f64 {
+        value.floor()
+    }
+}
+
+/// Round up or down at random, as in `(x + u).floor()`, where `u` is a
+/// random number from the contained function.
+///
+/// The function should return uniformly distributed numbers in the range
+/// `[0.0, 1.0)`. The probability of rounding up is then the fractional part
+/// of the value, so the rounding error averages out to zero over many values.
+/// Using a seeded random number generator makes the result reproducible. It's
+/// called once per component, in the order the components are converted.
+///
+/// ```
+/// use palette::{RoundStochastic, Srgb};
+///
+/// // A deterministic sequence, as a stand-in for a seeded generator.
+/// let mut offsets = [0.2, 0.7, 0.9].iter().cycle().copied();
+/// let mut rounding = RoundStochastic(move || offsets.next().unwrap());
+///
+/// let color = Srgb::new(0.5f64, 0.5, 0.5).into_format_rounded::<u8, _>(&mut rounding);
+/// assert_eq!(color, Srgb::new(127, 128, 128));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundStochastic<F>(pub F);
+
+impl<F: FnMut() -> f64> Rounding for RoundStochastic<F> {
+    #[inline]
+    fn round_f32(&mut self, value: f32) -> f32 {
+        (value + (self.0)() as f32).floor()
+    }
+
+    #[inline]
+    fn round_f64(&mut self, value: f64) -> f64 {
+        (value + (self.0)()).floor()
+    }
+}
+
+/// Helper for picking the right `Rounding` method for the intermediate type.
+trait RoundWith: Sized {
+    fn round_with<R: Rounding + ?Sized>(self, rounding: &mut R) -> Self;
+}
+
+impl RoundWith for f32 {
+    #[inline]
+    fn round_with<R: Rounding + ?Sized>(self, rounding: &mut R) -> Self {
+        rounding.round_f32(self)
+    }
+}
+
+impl RoundWith for f64 {
+    #[inline]
+    fn round_with<R: Rounding + ?Sized>(self, rounding: &mut R) -> Self {
+        rounding.round_f64(self)
+    }
+}
+
 // C23 = 2^23, in f32
 // C52 = 2^52, in f64
 const C23: u32 = 0x4b00_0000;
@@ -215,6 +398,23 @@ macro_rules! convert_uint_to_uint {
     };
 }
 
+macro_rules! convert_float_to_uint_rounded {
+    ($float: ident; $(via $temporary: ident ($($target: ident),+);)*) => {
+        $(
+            $(
+                impl IntoComponentRounded<$target> for $float {
+                    #[inline]
+                    fn into_component_rounded<R: Rounding + ?Sized>(self, rounding: &mut R) -> $target {
+                        let max = $target::max_intensity() as $temporary;
+                        let scaled = (self as $temporary * max).min(max).max(0.0);
+                        scaled.round_with(rounding).min(max).max(0.0) as $target
+                    }
+                }
+            )+
+        )*
+    };
+}
+
 impl IntoComponent<f64> for f32 {
     #[inline]
     fn into_component(self) -> f64 {
@@ -231,6 +431,9 @@ impl IntoComponent<f32> for f64 {
 }
 convert_double_to_uint!(f64; direct (u8, u16, u32, u64, u128););
 
+convert_float_to_uint_rounded!(f32; via f32 (u8, u16); via f64 (u32, u64, u128););
+convert_float_to_uint_rounded!(f64; via f64 (u8, u16, u32, u64, u128););
+
 convert_uint_to_uint!(u8; via f32 (u16); via f64 (u32, u64, u128););
 
 convert_uint_to_float!(u16; via f32 (f32); via f64 (f64););
@@ -245,11 +448,70 @@ convert_uint_to_uint!(u64; via f64 (u8, u16, u32, u128););
 convert_uint_to_float!(u128; via f64 (f32, f64););
 convert_uint_to_uint!(u128; via f64 (u8, u16, u32, u64););
 
+/// A marker for component conversions that don't lose any information.
+///
+/// `U: IntoComponentLossless<T>` means that converting a `U` into `T` with
+/// [`IntoComponent`](crate::IntoComponent), and back into `U`, gives the
+/// original value. This is the case when `T` has at least as much precision
+/// as `U`, such as when converting from `u8` to `f32`, but not when reducing
+/// the bit depth.
+///
+/// ```
+/// use palette::{IntoComponent, IntoComponentLossless};
+///
+/// fn widen<T: IntoComponentLossless<f32>>(component: T) -> f32 {
+///     component.into_component()
+/// }
+///
+/// assert_eq!(widen(255u8), 1.0);
+/// ```
+pub trait IntoComponentLossless<T: Component>: IntoComponent<T> {}
+
+impl<T: Component> IntoComponentLossless<T> for T {}
+
+macro_rules! impl_lossless_components {
+    ($($source: ident => ($($target: ident),+);)*) => {
+        $(
+            $(
+                impl IntoComponentLossless<$target> for $source {}
+            )+
+        )*
+    };
+}
+
+impl_lossless_components! {
+    u8 => (u16, u32, f32, f64);
+    u16 => (u32, f32, f64);
+    u32 => (f64);
+    f32 => (f64);
+}
+
 #[cfg(test)]
 mod test {
-    use crate::IntoComponent;
+    use crate::{IntoComponent, IntoComponentRounded, RoundFloor, RoundNearest, RoundStochastic};
     use approx::assert_relative_eq;
 
+    #[test]
+    fn lossless_round_trip() {
+        for value in 0..=u8::MAX {
+            let float: f32 = value.into_component();
+            let double: f64 = value.into_component();
+            let wide: u16 = value.into_component();
+            let wider: u32 = value.into_component();
+            assert_eq!(IntoComponent::<u8>::into_component(float), value);
+            assert_eq!(IntoComponent::<u8>::into_component(double), value);
+            assert_eq!(IntoComponent::<u8>::into_component(wide), value);
+            assert_eq!(IntoComponent::<u8>::into_component(wider), value);
+        }
+
+        for value in 0..=u16::MAX {
+            let float: f32 = value.into_component();
+            let wide: u32 = value.into_component();
+            assert_eq!(IntoComponent::<u16>::into_component(float), value);
+            assert_eq!(IntoComponent::<u16>::into_component(wide), value);
+        }
+    }
+
     #[test]
     fn float_to_uint() {
         let data = vec![
@@ -359,4 +621,64 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn rounded_nearest_matches_into_component() {
+        for i in -100..=2000 {
+            let value = i as f32 / 1900.0;
+            let nearest: u8 = value.into_component_rounded(&mut RoundNearest);
+            assert_eq!(nearest, IntoComponent::<u8>::into_component(value));
+            let nearest: u16 = value.into_component_rounded(&mut RoundNearest);
+            assert_eq!(nearest, IntoComponent::<u16>::into_component(value));
+
+            let value = f64::from(value);
+            let nearest: u8 = value.into_component_rounded(&mut RoundNearest);
+            assert_eq!(nearest, IntoComponent::<u8>::into_component(value));
+            let nearest: u32 = value.into_component_rounded(&mut RoundNearest);
+            assert_eq!(nearest, IntoComponent::<u32>::into_component(value));
+        }
+
+        let nan: u8 = std::f32::NAN.into_component_rounded(&mut RoundNearest);
+        assert_eq!(nan, 255);
+        let max: u64 = 1.0f64.into_component_rounded(&mut RoundNearest);
+        assert_eq!(max, std::u64::MAX);
+    }
+
+    #[test]
+    fn rounded_floor() {
+        let data = [-0.3f32, 0.0, 0.0039, 0.004, 0.5, 0.999, 1.0, 1.5];
+        let expected = [0u8, 0, 0, 1, 127, 254, 255, 255];
+
+        for (&d, &e) in data.iter().zip(&expected) {
+            let floor: u8 = d.into_component_rounded(&mut RoundFloor);
+            assert_eq!(floor, e);
+            let floor: u8 = f64::from(d).into_component_rounded(&mut RoundFloor);
+            assert_eq!(floor, e);
+        }
+    }
+
+    #[test]
+    fn rounded_stochastic() {
+        let mut offset = 0.0;
+        let mut rounding = RoundStochastic(|| {
+            offset = (offset + 0.01) % 1.0;
+            offset
+        });
+
+        // 0.3 of the way between 127 and 128 should round up about 30% of
+        // the time.
+        let value = 127.3f64 / 255.0;
+        let sum: u32 = (0..1000)
+            .map(|_| {
+                u32::from(IntoComponentRounded::<u8>::into_component_rounded(
+                    value,
+                    &mut rounding,
+                ))
+            })
+            .sum();
+        assert!((sum as f64 / 1000.0 - 127.3).abs() < 0.01);
+
+        let max: u8 = 1.0f32.into_component_rounded(&mut RoundStochastic(|| 0.999));
+        assert_eq!(max, 255);
+    }
 }
@@ -275,11 +275,17 @@
 //! ```
 
 use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
 
 #[doc(hidden)]
 pub use palette_derive::FromColorUnclamped;
 
-use crate::Clamp;
+use crate::encoding::Linear;
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::white_point::{WhitePoint, D65};
+use crate::{
+    Alpha, Clamp, Component, FloatComponent, Hsl, Hsv, Lab, Lch, Lchuv, Luv, Oklab, Oklch, Xyz, Yxy,
+};
 
 /// The error type for a color conversion that converted a color into a color
 /// with invalid values.
@@ -521,6 +527,335 @@ where
     }
 }
 
+/// A marker for conversions that don't lose any information.
+///
+/// `U: FromColorLossless<T>` means that converting a `T` into `U` with
+/// [`FromColorUnclamped`](crate::convert::FromColorUnclamped), and back into
+/// `T`, gives the original color, apart from floating point rounding. This is
+/// the case for conversions that only apply invertible operations, such as
+/// matrices between linear spaces and reference white preserving transforms.
+///
+/// Conversions that aren't marked may lose information, for example by:
+///
+/// * Clamping, which is what [`FromColor`](crate::convert::FromColor) does.
+/// * Going through a nonlinear transfer function, which isn't invertible for
+///   every value outside the gamut.
+/// * Going to a polar space, or to a space with a hue, and back, since the hue
+///   is lost when the chroma or saturation is `0`.
+/// * Going to a space with fewer components, such as `Luma`.
+/// * Reducing the bit depth of the components, which is marked separately by
+///   [`IntoComponentLossless`](crate::IntoComponentLossless).
+///
+/// Using [`into_color_lossless`](crate::convert::IntoColorLossless) instead
+/// of the other conversion methods makes sure that a pipeline doesn't
+/// silently lose information, since lossy conversions don't compile:
+///
+/// ```
+/// use palette::convert::IntoColorLossless;
+/// use palette::{LinSrgb, Oklab, Xyz};
+///
+/// let xyz: Xyz = LinSrgb::new(0.8f32, 0.3, 0.1).into_color_lossless();
+/// let oklab: Oklab = xyz.into_color_lossless();
+/// ```
+///
+/// ```compile_fail
+/// use palette::convert::IntoColorLossless;
+/// use palette::{Hsv, LinSrgb};
+///
+/// // The hue is lost if the saturation is 0.
+/// let rgb: LinSrgb = Hsv::new(120.0f32, 0.5, 0.5).into_color_lossless();
+/// ```
+pub trait FromColorLossless<T>: FromColorUnclamped<T> {
+    /// Convert from T, without losing any information.
+    #[inline]
+    fn from_color_lossless(color: T) -> Self {
+        Self::from_color_unclamped(color)
+    }
+}
+
+/// A trait for converting a color into another, without losing any
+/// information.
+///
+/// `U: IntoColorLossless<T>` is implemented for every type
+/// `T: FromColorLossless<U>`.
+///
+/// See [`FromColorLossless`](crate::convert::FromColorLossless) for more
+/// details.
+pub trait IntoColorLossless<T>: Sized {
+    /// Convert into T, without losing any information.
+    fn into_color_lossless(self) -> T;
+}
+
+impl<T, U> IntoColorLossless<U> for T
+where
+    U: FromColorLossless<T>,
+{
+    #[inline]
+    fn into_color_lossless(self) -> U {
+        U::from_color_lossless(self)
+    }
+}
+
+impl<C1, C2, T> FromColorLossless<Alpha<C1, T>> for Alpha<C2, T>
+where
+    C2: FromColorLossless<C1>,
+    T: Component,
+{
+}
+
+impl<S1, S2, T> FromColorLossless<Rgb<Linear<S2>, T>> for Rgb<Linear<S1>, T>
+where
+    S1: RgbSpace,
+    S2: RgbSpace<WhitePoint = S1::WhitePoint>,
+    T: FloatComponent,
+{
+}
+
+impl<S, T> FromColorLossless<Rgb<Linear<S>, T>> for Xyz<S::WhitePoint, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+}
+
+impl<S, T> FromColorLossless<Xyz<S::WhitePoint, T>> for Rgb<Linear<S>, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+}
+
+impl<S, T> FromColorLossless<Rgb<S, T>> for Hsl<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+}
+
+impl<S, T> FromColorLossless<Rgb<S, T>> for Hsv<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Xyz<Wp, T>> for Yxy<Wp, T> {}
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Xyz<Wp, T>> for Lab<Wp, T> {}
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Lab<Wp, T>> for Xyz<Wp, T> {}
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Lab<Wp, T>> for Lch<Wp, T> {}
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Xyz<Wp, T>> for Luv<Wp, T> {}
+impl<Wp: WhitePoint, T: FloatComponent> FromColorLossless<Luv<Wp, T>> for Lchuv<Wp, T> {}
+impl<T: FloatComponent> FromColorLossless<Xyz<D65, T>> for Oklab<T> {}
+impl<T: FloatComponent> FromColorLossless<Oklab<T>> for Xyz<D65, T> {}
+impl<T: FloatComponent> FromColorLossless<Oklab<T>> for Oklch<T> {}
+
+/// Convert `source` into the color type of `destination`, and write the result
+/// to `destination`.
+///
+/// This is the same as `*destination = U::from_color(*source)`, but makes it
+/// possible to convert into memory that is already allocated, such as an
+/// element in a pool, without moving the source. The source is copied, since
+/// colors are small `Copy` types.
+///
+/// ```
+/// use palette::convert::from_color_into;
+/// use palette::{Hsl, Srgb};
+///
+/// let source = Hsl::new(0.0, 1.0, 0.5);
+/// let mut destination = Srgb::new(0.0, 0.0, 0.0);
+/// from_color_into(&source, &mut destination);
+///
+/// assert_eq!(destination, Srgb::new(1.0, 0.0, 0.0));
+/// ```
+#[inline]
+pub fn from_color_into<T, U>(source: &T, destination: &mut U)
+where
+    T: Copy,
+    U: FromColor<T>,
+{
+    *destination = U::from_color(*source);
+}
+
+/// Convert `source` into the color type of `destination`, without clamping,
+/// and write the result to `destination`.
+///
+/// See [`from_color_into`](crate::convert::from_color_into) for more details.
+#[inline]
+pub fn from_color_unclamped_into<T, U>(source: &T, destination: &mut U)
+where
+    T: Copy,
+    U: FromColorUnclamped<T>,
+{
+    *destination = U::from_color_unclamped(*source);
+}
+
+/// Convert each color in `source` into the color type of `destination`, and
+/// write the results to the same positions in `destination`.
+///
+/// This doesn't allocate, so a buffer can be reused for every frame in a real
+/// time application.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+///
+/// ```
+/// use palette::convert::from_color_slice_into;
+/// use palette::{Hsv, Srgb};
+///
+/// let source = [Hsv::new(0.0, 1.0, 1.0), Hsv::new(120.0, 1.0, 1.0)];
+/// let mut destination = [Srgb::default(); 2];
+/// from_color_slice_into(&source, &mut destination);
+///
+/// assert_eq!(destination, [Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 1.0, 0.0)]);
+/// ```
+pub fn from_color_slice_into<T, U>(source: &[T], destination: &mut [U])
+where
+    T: Copy,
+    U: FromColor<T>,
+{
+    assert_eq!(
+        source.len(),
+        destination.len(),
+        "source and destination slices have different lengths"
+    );
+
+    for (source, destination) in source.iter().zip(destination) {
+        from_color_into(source, destination);
+    }
+}
+
+/// Convert each color in `source` into the color type of `destination`,
+/// without clamping, and write the results to the same positions in
+/// `destination`.
+///
+/// See [`from_color_slice_into`](crate::convert::from_color_slice_into) for
+/// more details.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+pub fn from_color_unclamped_slice_into<T, U>(source: &[T], destination: &mut [U])
+where
+    T: Copy,
+    U: FromColorUnclamped<T>,
+{
+    assert_eq!(
+        source.len(),
+        destination.len(),
+        "source and destination slices have different lengths"
+    );
+
+    for (source, destination) in source.iter().zip(destination) {
+        from_color_unclamped_into(source, destination);
+    }
+}
+
+/// An extension trait for converting the colors in an iterator lazily.
+///
+/// It's implemented for every iterator, and its methods wrap the iterator in a
+/// [`ConvertedIter`](crate::convert::ConvertedIter) that converts each color
+/// as it's iterated over. This composes with the other iterator adaptors,
+/// without collecting the colors into an intermediate buffer.
+///
+/// The colors are taken by value, so iterators over references, such as
+/// `slice.iter()`, need to be turned into iterators over values with
+/// `.copied()` or `.cloned()` first.
+///
+/// ```
+/// use palette::convert::ConvertIter;
+/// use palette::{Lab, Srgb};
+///
+/// let pixels = [Srgb::new(1.0f32, 1.0, 1.0), Srgb::new(0.0, 0.0, 0.0)];
+///
+/// let lightness: Vec<f32> = pixels
+///     .iter()
+///     .copied()
+///     .map(Srgb::into_linear)
+///     .convert::<Lab>()
+///     .map(|lab| lab.l)
+///     .collect();
+///
+/// assert_eq!(lightness.len(), 2);
+/// assert!((lightness[0] - 100.0).abs() < 1e-3);
+/// assert!(lightness[1].abs() < 1e-3);
+/// ```
+pub trait ConvertIter: Iterator + Sized {
+    /// Convert each color with [`FromColor`](crate::convert::FromColor).
+    #[inline]
+    fn convert<U>(self) -> ConvertedIter<Self, U>
+    where
+        U: FromColor<Self::Item>,
+    {
+        ConvertedIter {
+            iter: self,
+            convert: U::from_color,
+        }
+    }
+
+    /// Convert each color with
+    /// [`FromColorUnclamped`](crate::convert::FromColorUnclamped).
+    #[inline]
+    fn convert_unclamped<U>(self) -> ConvertedIter<Self, U>
+    where
+        U: FromColorUnclamped<Self::Item>,
+    {
+        ConvertedIter {
+            iter: self,
+            convert: U::from_color_unclamped,
+        }
+    }
+}
+
+impl<I: Iterator> ConvertIter for I {}
+
+/// An iterator that converts the colors of another iterator.
+///
+/// See [`ConvertIter`](crate::convert::ConvertIter) for how to create it.
+#[derive(Clone)]
+pub struct ConvertedIter<I: Iterator, U> {
+    iter: I,
+    convert: fn(I::Item) -> U,
+}
+
+impl<I: Iterator, U> Iterator for ConvertedIter<I, U> {
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(self.convert)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<U> {
+        self.iter.nth(n).map(self.convert)
+    }
+}
+
+impl<I: DoubleEndedIterator, U> DoubleEndedIterator for ConvertedIter<I, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<U> {
+        self.iter.next_back().map(self.convert)
+    }
+}
+
+impl<I: ExactSizeIterator, U> ExactSizeIterator for ConvertedIter<I, U> {}
+
+impl<I: FusedIterator, U> FusedIterator for ConvertedIter<I, U> {}
+
+impl<I: Iterator + fmt::Debug, U> fmt::Debug for ConvertedIter<I, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConvertedIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::marker::PhantomData;
@@ -947,4 +1282,99 @@ mod tests {
         let _hwb: Hwb<_, f64> = color.into_color();
         let _luma: Luma<Linear<crate::white_point::E>, f64> = color.into_color();
     }
+
+    #[test]
+    fn convert_into_existing_storage() {
+        use super::{
+            from_color_into, from_color_slice_into, from_color_unclamped_into,
+            from_color_unclamped_slice_into,
+        };
+        use crate::Srgb;
+
+        let mut rgb = Srgb::new(0.0f32, 0.0, 0.0);
+        from_color_into(&Hsv::new(240.0, 1.0, 1.0), &mut rgb);
+        assert_eq!(rgb, Srgb::new(0.0, 0.0, 1.0));
+        from_color_into(&Hsv::new(0.0, 0.0, 1.5), &mut rgb);
+        assert_eq!(rgb, Srgb::new(1.0, 1.0, 1.0));
+        from_color_unclamped_into(&Hsv::new(0.0, 0.0, 1.5), &mut rgb);
+        assert_eq!(rgb, Srgb::new(1.5, 1.5, 1.5));
+
+        let source = [Hsv::new(0.0, 0.0, 1.5), Hsv::new(0.0, 1.0, 0.5)];
+        let mut destination = [Srgb::new(0.0f32, 0.0, 0.0); 2];
+        from_color_slice_into(&source, &mut destination);
+        assert_eq!(
+            destination,
+            [Srgb::new(1.0, 1.0, 1.0), Srgb::new(0.5, 0.0, 0.0)]
+        );
+        from_color_unclamped_slice_into(&source, &mut destination);
+        assert_eq!(
+            destination,
+            [Srgb::new(1.5, 1.5, 1.5), Srgb::new(0.5, 0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn convert_slice_length_mismatch() {
+        let source = [Hsv::new(0.0f32, 0.0, 1.0)];
+        let mut destination = [crate::Srgb::new(0.0f32, 0.0, 0.0); 2];
+        super::from_color_slice_into(&source, &mut destination);
+    }
+
+    #[test]
+    fn converted_iter() {
+        use super::ConvertIter;
+        use crate::Srgb;
+
+        let colors = [
+            Hsv::new(0.0f32, 1.0, 1.0),
+            Hsv::new(120.0, 1.0, 1.5),
+            Hsv::new(240.0, 1.0, 1.0),
+        ];
+
+        let mut iter = colors.iter().copied().convert::<Srgb>();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Srgb::new(1.0, 0.0, 0.0)));
+        assert_eq!(iter.next_back(), Some(Srgb::new(0.0, 0.0, 1.0)));
+        assert_eq!(iter.next(), Some(Srgb::new(0.0, 1.0, 0.0)));
+        assert_eq!(iter.next(), None);
+
+        let unclamped: Vec<Srgb> = colors
+            .iter()
+            .copied()
+            .convert_unclamped()
+            .skip(1)
+            .take(1)
+            .collect();
+        assert_eq!(unclamped, vec![Srgb::new(0.0, 1.5, 0.0)]);
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        use super::IntoColorLossless;
+        use crate::{LinSrgb, LinSrgba, Oklab, Oklch, Srgb};
+
+        let rgb = LinSrgb::new(0.8f64, 0.3, 0.1);
+
+        let xyz: Xyz<_, f64> = rgb.into_color_lossless();
+        let lch: Lch<_, f64> =
+            IntoColorLossless::<Lab<_, f64>>::into_color_lossless(xyz).into_color_lossless();
+        let back: LinSrgb<f64> = Xyz::<_, f64>::from_color_unclamped(lch).into_color_lossless();
+        assert_relative_eq!(back, rgb, epsilon = 1e-12);
+
+        let oklch: Oklch<f64> =
+            IntoColorLossless::<Oklab<f64>>::into_color_lossless(xyz).into_color_lossless();
+        assert_relative_eq!(Oklch::from_color_unclamped(rgb), oklch, epsilon = 1e-12);
+
+        let hsv: Hsv<_, f64> = Srgb::new(0.8f64, 0.3, 0.1).into_color_lossless();
+        assert_relative_eq!(
+            Srgb::from_color_unclamped(hsv),
+            Srgb::new(0.8, 0.3, 0.1),
+            epsilon = 1e-12
+        );
+
+        let rgba = LinSrgba::new(0.8f64, 0.3, 0.1, 0.5);
+        let xyza: Alpha<Xyz<_, f64>, f64> = rgba.into_color_lossless();
+        assert_eq!(xyza.alpha, 0.5);
+    }
 }
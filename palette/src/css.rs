@@ -0,0 +1,433 @@
+//! Helpers for implementing CSS color features.
+//!
+//! The functions in this module follow the definitions in the CSS Color
+//! specifications, to make it easier to implement a styling engine on top of
+//! palette. Colors are given as floating point sRGB, which may be outside the
+//! `0.0` to `1.0` range for colors that are outside the sRGB gamut.
+//!
+//! [`parse_color`](fn.parse_color.html) parses color values, like
+//! `rgb(255 128 0 / 50%)`, [`color_mix`](fn.color_mix.html) mixes two colors,
+//! like `color-mix()`, and [`relative_color`](fn.relative_color.html)
+//! evaluates relative color expressions, like `lch(from red calc(l + 10) c h)`.
+//! [`HexColor`](struct.HexColor.html) and
+//! [`RgbFunction`](struct.RgbFunction.html) format 8 bit colors as hex codes
+//! and `rgb()` functions.
+//!
+//! ```
+//! use palette::css::{color_mix, HueInterpolation, InterpolationSpace};
+//! use palette::Srgba;
+//!
+//! // color-mix(in lch longer hue, red 40%, blue)
+//! let red = Srgba::new(1.0f64, 0.0, 0.0, 1.0);
+//! let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+//! let space = InterpolationSpace::Lch(HueInterpolation::Longer);
+//! let mixed = color_mix(space, (red, Some(0.4)), (blue, None)).unwrap();
+//! assert!(mixed.green > mixed.red);
+//! ```
+
+use crate::chromatic_adaptation::AdaptInto;
+use crate::convert::FromColorUnclamped;
+use crate::encoding::{Linear, Srgb};
+use crate::rgb::Rgb;
+use crate::white_point::{D50, D65};
+use crate::{from_f64, Alpha, FloatComponent, Hsl, Hwb, Lab, LabHue, Lch, RgbHue, Srgba, Xyz};
+
+pub use self::format::{HexColor, RgbFunction};
+pub use self::parse::{parse_color, ParseColorError};
+pub use self::relative::{relative_color, RelativeColorError};
+
+mod format;
+mod parse;
+mod relative;
+
+/// A color space that colors can be interpolated in, as in the
+/// `<color-interpolation-method>` of CSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolationSpace {
+    /// `srgb`, with gamma encoded components.
+    Srgb,
+    /// `srgb-linear`, with linear components.
+    SrgbLinear,
+    /// `lab`, which is CIE L\*a\*b\* with the D50 white point.
+    Lab,
+    /// `xyz-d50`, which is CIE XYZ with the D50 white point.
+    XyzD50,
+    /// `xyz` or `xyz-d65`, which is CIE XYZ with the D65 white point.
+    XyzD65,
+    /// `hsl`, with the given method for interpolating the hue.
+    Hsl(HueInterpolation),
+    /// `hwb`, with the given method for interpolating the hue.
+    Hwb(HueInterpolation),
+    /// `lch`, which is CIE L\*C\*h° with the D50 white point, with the given
+    /// method for interpolating the hue.
+    Lch(HueInterpolation),
+}
+
+/// The way hues are interpolated in a polar color space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HueInterpolation {
+    /// `shorter`, which takes the shortest way around the hue circle. This
+    /// is the default in CSS.
+    Shorter,
+    /// `longer`, which takes the longest way around the hue circle.
+    Longer,
+    /// `increasing`, where the hue always increases.
+    Increasing,
+    /// `decreasing`, where the hue always decreases.
+    Decreasing,
+}
+
+/// Mix two colors, as `color-mix(in <space>, <color1> <p1>, <color2> <p2>)`
+/// in CSS Color 5.
+///
+/// The percentages are given as fractions, where `0.5` is `50%`, and `None`
+/// is an omitted percentage. They are normalized the same way as in CSS:
+///
+/// * If both are omitted, they are both `0.5`.
+/// * If one is omitted, it's `1.0` minus the other.
+/// * If they don't add up to `1.0`, they are scaled so they do. If their sum
+///   was less than `1.0`, the alpha of the result is multiplied by the sum.
+///
+/// The result is `None` if a percentage is outside the range `0.0` to `1.0`,
+/// or if both are `0.0`, which makes the function invalid in CSS.
+///
+/// The colors are interpolated with premultiplied alpha. A hue that is
+/// powerless, such as the hue of a gray color, is treated as missing and
+/// takes the hue of the other color.
+pub fn color_mix<T: FloatComponent>(
+    space: InterpolationSpace,
+    (color1, percentage1): (Srgba<T>, Option<T>),
+    (color2, percentage2): (Srgba<T>, Option<T>),
+) -> Option<Srgba<T>> {
+    let half = from_f64::<T>(0.5);
+    let (percentage1, percentage2) = match (percentage1, percentage2) {
+        (None, None) => (half, half),
+        (Some(p1), None) => (p1, T::one() - p1),
+        (None, Some(p2)) => (T::one() - p2, p2),
+        (Some(p1), Some(p2)) => (p1, p2),
+    };
+
+    let valid = |p: T| p >= T::zero() && p <= T::one();
+    if !valid(percentage1) || !valid(percentage2) {
+        return None;
+    }
+
+    let sum = percentage1 + percentage2;
+    if sum <= T::zero() {
+        return None;
+    }
+
+    let factor = percentage2 / sum;
+    let alpha_multiplier = sum.min(T::one());
+
+    let (mut components1, hue1) = to_components(space, color1.color);
+    let (mut components2, hue2) = to_components(space, color2.color);
+
+    if let (Some(index), Some(method)) = (hue1.or(hue2), hue_interpolation(space)) {
+        let (h1, h2) = match (hue1, hue2) {
+            (Some(_), Some(_)) => (components1[index], components2[index]),
+            (Some(_), None) => (components1[index], components1[index]),
+            (None, Some(_)) => (components2[index], components2[index]),
+            (None, None) => (T::zero(), T::zero()),
+        };
+        let (h1, h2) = fix_hues(method, h1, h2);
+        components1[index] = h1;
+        components2[index] = h2;
+    }
+
+    let alpha = color1.alpha + factor * (color2.alpha - color1.alpha);
+    let hue_index = hue_interpolation(space).map(|_| hue_index(space));
+
+    let mut mixed = [T::zero(); 3];
+    for (i, mixed) in mixed.iter_mut().enumerate() {
+        let (c1, c2) = (components1[i], components2[i]);
+        *mixed = if Some(i) == hue_index {
+            c1 + factor * (c2 - c1)
+        } else {
+            let premultiplied =
+                c1 * color1.alpha + factor * (c2 * color2.alpha - c1 * color1.alpha);
+            if alpha > T::zero() {
+                premultiplied / alpha
+            } else {
+                premultiplied
+            }
+        };
+    }
+
+    Some(Alpha {
+        color: from_components(space, mixed),
+        alpha: alpha * alpha_multiplier,
+    })
+}
+
+/// The hue interpolation method of `space`, if it's a polar space.
+fn hue_interpolation(space: InterpolationSpace) -> Option<HueInterpolation> {
+    match space {
+        InterpolationSpace::Hsl(method)
+        | InterpolationSpace::Hwb(method)
+        | InterpolationSpace::Lch(method) => Some(method),
+        _ => None,
+    }
+}
+
+/// The index of the hue in the components of a polar space.
+fn hue_index(space: InterpolationSpace) -> usize {
+    match space {
+        InterpolationSpace::Lch(_) => 2,
+        _ => 0,
+    }
+}
+
+/// Adjust two hues, in degrees, so that a linear interpolation between them
+/// follows `method`.
+pub(crate) fn fix_hues<T: FloatComponent>(method: HueInterpolation, h1: T, h2: T) -> (T, T) {
+    let full_turn = from_f64::<T>(360.0);
+    let half_turn = from_f64::<T>(180.0);
+    let difference = h2 - h1;
+
+    match method {
+        HueInterpolation::Shorter if difference > half_turn => (h1 + full_turn, h2),
+        HueInterpolation::Shorter if difference < -half_turn => (h1, h2 + full_turn),
+        HueInterpolation::Longer if difference > T::zero() && difference < half_turn => {
+            (h1 + full_turn, h2)
+        }
+        HueInterpolation::Longer if difference > -half_turn && difference <= T::zero() => {
+            (h1, h2 + full_turn)
+        }
+        HueInterpolation::Increasing if h2 < h1 => (h1, h2 + full_turn),
+        HueInterpolation::Decreasing if h1 < h2 => (h1 + full_turn, h2),
+        _ => (h1, h2),
+    }
+}
+
+/// The components of `color` in `space`, and the index of its hue, if the
+/// space has a hue and it's not powerless.
+fn to_components<T: FloatComponent>(
+    space: InterpolationSpace,
+    color: Rgb<Srgb, T>,
+) -> ([T; 3], Option<usize>) {
+    let rectangular = |x: T, y: T, z: T| ([x, y, z], None);
+
+    match space {
+        InterpolationSpace::Srgb => rectangular(color.red, color.green, color.blue),
+        InterpolationSpace::SrgbLinear => {
+            let color = color.into_linear();
+            rectangular(color.red, color.green, color.blue)
+        }
+        InterpolationSpace::Lab => {
+            let color = Lab::<D50, T>::from_color_unclamped(xyz_d50(color));
+            rectangular(color.l, color.a, color.b)
+        }
+        InterpolationSpace::XyzD50 => {
+            let color = xyz_d50(color);
+            rectangular(color.x, color.y, color.z)
+        }
+        InterpolationSpace::XyzD65 => {
+            let color = Xyz::<D65, T>::from_color_unclamped(color);
+            rectangular(color.x, color.y, color.z)
+        }
+        InterpolationSpace::Hsl(_) => {
+            let color = Hsl::<Srgb, T>::from_color_unclamped(color);
+            let powerless = color.saturation == T::zero();
+            let hue = color.hue.to_positive_degrees();
+            (
+                [hue, color.saturation, color.lightness],
+                none_if(powerless, 0),
+            )
+        }
+        InterpolationSpace::Hwb(_) => {
+            let color = Hwb::<Srgb, T>::from_color_unclamped(color);
+            let powerless = color.whiteness + color.blackness >= T::one();
+            let hue = color.hue.to_positive_degrees();
+            (
+                [hue, color.whiteness, color.blackness],
+                none_if(powerless, 0),
+            )
+        }
+        InterpolationSpace::Lch(_) => {
+            let color = Lch::<D50, T>::from_color_unclamped(xyz_d50(color));
+            let powerless = color.chroma <= from_f64(1e-4);
+            let hue = color.hue.to_positive_degrees();
+            ([color.l, color.chroma, hue], none_if(powerless, 2))
+        }
+    }
+}
+
+/// Convert `components` in `space` back to sRGB.
+fn from_components<T: FloatComponent>(
+    space: InterpolationSpace,
+    [c1, c2, c3]: [T; 3],
+) -> Rgb<Srgb, T> {
+    match space {
+        InterpolationSpace::Srgb => Rgb::new(c1, c2, c3),
+        InterpolationSpace::SrgbLinear => Rgb::from_linear(Rgb::<Linear<Srgb>, T>::new(c1, c2, c3)),
+        InterpolationSpace::Lab => from_d50(Xyz::from_color_unclamped(Lab::<D50, T>::with_wp(
+            c1, c2, c3,
+        ))),
+        InterpolationSpace::XyzD50 => from_d50(Xyz::with_wp(c1, c2, c3)),
+        InterpolationSpace::XyzD65 => Rgb::from_color_unclamped(Xyz::<D65, T>::with_wp(c1, c2, c3)),
+        InterpolationSpace::Hsl(_) => {
+            Rgb::from_color_unclamped(Hsl::<Srgb, T>::new(RgbHue::from_degrees(c1), c2, c3))
+        }
+        InterpolationSpace::Hwb(_) => {
+            Rgb::from_color_unclamped(Hwb::<Srgb, T>::new(RgbHue::from_degrees(c1), c2, c3))
+        }
+        InterpolationSpace::Lch(_) => from_d50(Xyz::from_color_unclamped(Lch::<D50, T>::with_wp(
+            c1,
+            c2,
+            LabHue::from_degrees(c3),
+        ))),
+    }
+}
+
+fn none_if(powerless: bool, index: usize) -> Option<usize> {
+    if powerless {
+        None
+    } else {
+        Some(index)
+    }
+}
+
+/// Convert sRGB to XYZ with the D50 white point, which CSS uses for Lab and
+/// LCH.
+fn xyz_d50<T: FloatComponent>(color: Rgb<Srgb, T>) -> Xyz<D50, T> {
+    Xyz::<D65, T>::from_color_unclamped(color).adapt_into()
+}
+
+fn from_d50<T: FloatComponent>(color: Xyz<D50, T>) -> Rgb<Srgb, T> {
+    let color: Xyz<D65, T> = color.adapt_into();
+    Rgb::from_color_unclamped(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{color_mix, HueInterpolation, InterpolationSpace};
+    use crate::Srgba;
+
+    fn mix(
+        space: InterpolationSpace,
+        color1: (Srgba<f64>, Option<f64>),
+        color2: (Srgba<f64>, Option<f64>),
+    ) -> Srgba<f64> {
+        color_mix(space, color1, color2).unwrap()
+    }
+
+    #[test]
+    fn percentages() {
+        let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+        let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+        let space = InterpolationSpace::Srgb;
+
+        assert_relative_eq!(
+            mix(space, (red, None), (blue, None)),
+            Srgba::new(0.5, 0.0, 0.5, 1.0)
+        );
+        assert_relative_eq!(
+            mix(space, (red, Some(0.25)), (blue, None)),
+            Srgba::new(0.25, 0.0, 0.75, 1.0)
+        );
+        assert_relative_eq!(
+            mix(space, (red, None), (blue, Some(0.25))),
+            Srgba::new(0.75, 0.0, 0.25, 1.0)
+        );
+        assert_relative_eq!(
+            mix(space, (red, Some(0.5)), (blue, Some(1.0))),
+            mix(space, (red, Some(1.0 / 3.0)), (blue, None))
+        );
+        assert_relative_eq!(
+            mix(space, (red, Some(0.2)), (blue, Some(0.2))),
+            Srgba::new(0.5, 0.0, 0.5, 0.4)
+        );
+
+        assert_eq!(color_mix(space, (red, Some(0.0)), (blue, Some(0.0))), None);
+        assert_eq!(color_mix(space, (red, Some(1.5)), (blue, None)), None);
+        assert_eq!(color_mix(space, (red, Some(-0.5)), (blue, Some(0.5))), None);
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        let red = Srgba::new(1.0, 0.0, 0.0, 0.25);
+        let blue = Srgba::new(0.0, 0.0, 1.0, 0.75);
+        let mixed = mix(InterpolationSpace::Srgb, (red, None), (blue, None));
+        assert_relative_eq!(mixed, Srgba::new(0.25, 0.0, 0.75, 0.5));
+
+        let transparent = Srgba::new(0.0, 0.0, 0.0, 0.0);
+        let mixed = mix(InterpolationSpace::Srgb, (transparent, None), (blue, None));
+        assert_relative_eq!(mixed, Srgba::new(0.0, 0.0, 1.0, 0.375));
+    }
+
+    #[test]
+    fn hue_interpolation() {
+        let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+        let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+
+        // Red is 0°, blue is 240°. The shorter way goes through magenta and
+        // the longer way goes through green.
+        let shorter = mix(
+            InterpolationSpace::Hsl(HueInterpolation::Shorter),
+            (red, None),
+            (blue, None),
+        );
+        assert_relative_eq!(shorter, Srgba::new(1.0, 0.0, 1.0, 1.0), epsilon = 1e-9);
+        let longer = mix(
+            InterpolationSpace::Hsl(HueInterpolation::Longer),
+            (red, None),
+            (blue, None),
+        );
+        assert_relative_eq!(longer, Srgba::new(0.0, 1.0, 0.0, 1.0), epsilon = 1e-9);
+        let increasing = mix(
+            InterpolationSpace::Hsl(HueInterpolation::Increasing),
+            (red, None),
+            (blue, None),
+        );
+        assert_relative_eq!(increasing, longer, epsilon = 1e-9);
+        let decreasing = mix(
+            InterpolationSpace::Hsl(HueInterpolation::Decreasing),
+            (red, None),
+            (blue, None),
+        );
+        assert_relative_eq!(decreasing, shorter, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn powerless_hue() {
+        let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+        let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+
+        let mixed = mix(
+            InterpolationSpace::Hsl(HueInterpolation::Shorter),
+            (white, None),
+            (blue, None),
+        );
+        assert_relative_eq!(mixed, Srgba::new(0.625, 0.625, 0.875, 1.0), epsilon = 1e-9);
+
+        let lch = mix(
+            InterpolationSpace::Lch(HueInterpolation::Shorter),
+            (white, None),
+            (blue, None),
+        );
+        assert!(lch.blue > lch.red && lch.red > 0.5);
+    }
+
+    #[test]
+    fn roundtrip() {
+        // The chromatic adaptation to D50 is not perfectly reversible.
+        let color = Srgba::new(0.2, 0.6, 0.4, 0.8);
+        for &space in &[
+            InterpolationSpace::Srgb,
+            InterpolationSpace::SrgbLinear,
+            InterpolationSpace::Lab,
+            InterpolationSpace::XyzD50,
+            InterpolationSpace::XyzD65,
+            InterpolationSpace::Hsl(HueInterpolation::Shorter),
+            InterpolationSpace::Hwb(HueInterpolation::Decreasing),
+            InterpolationSpace::Lch(HueInterpolation::Increasing),
+        ] {
+            assert_relative_eq!(
+                mix(space, (color, None), (color, None)),
+                color,
+                epsilon = 1e-6
+            );
+        }
+    }
+}
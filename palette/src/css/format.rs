@@ -0,0 +1,173 @@
+use core::fmt;
+
+use crate::Srgba;
+
+/// Formats a color as a CSS hex code, such as `#ff8040`.
+///
+/// The alpha is only included if the color is not fully opaque, such as in
+/// `#ff804080`. The hex code can be parsed again with `FromStr` or
+/// [`parse_color`](fn.parse_color.html). Any type that converts into
+/// `Srgba<u8>`, such as `Srgb<u8>` or [`Packed`](../struct.Packed.html),
+/// can be formatted:
+///
+/// ```
+/// use palette::css::HexColor;
+/// use palette::{Packed, Srgb, Srgba};
+///
+/// assert_eq!(HexColor::new(Srgb::new(255u8, 128, 64)).to_string(), "#ff8040");
+/// assert_eq!(HexColor::new(Srgba::new(255u8, 128, 64, 128)).to_string(), "#ff804080");
+///
+/// let packed: Packed = Srgb::new(255u8, 128, 64).into();
+/// assert_eq!(format!("{:X}", HexColor::new(packed)), "#FF8040");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HexColor(Srgba<u8>);
+
+impl HexColor {
+    /// Prepare `color` for formatting.
+    pub fn new<C: Into<Srgba<u8>>>(color: C) -> Self {
+        HexColor(color.into())
+    }
+}
+
+impl fmt::Display for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.alpha == u8::MAX {
+            write!(f, "#{:x}", self.0.color)
+        } else {
+            write!(f, "#{:x}", self.0)
+        }
+    }
+}
+
+impl fmt::UpperHex for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.alpha == u8::MAX {
+            write!(f, "#{:X}", self.0.color)
+        } else {
+            write!(f, "#{:X}", self.0)
+        }
+    }
+}
+
+/// Formats a color as a CSS `rgb()` or `rgba()` function, such as
+/// `rgba(255, 128, 64, 0.2)`.
+///
+/// The comma separated syntax is used, since it's supported everywhere.
+/// Opaque colors are written as `rgb()`, and other colors as `rgba()`, with
+/// the alpha rounded to the fewest decimals that still give the same 8 bit
+/// value:
+///
+/// ```
+/// use palette::css::RgbFunction;
+/// use palette::{Srgb, Srgba};
+///
+/// assert_eq!(RgbFunction::new(Srgb::new(255u8, 128, 64)).to_string(), "rgb(255, 128, 64)");
+/// assert_eq!(
+///     RgbFunction::new(Srgba::new(255u8, 128, 64, 51)).to_string(),
+///     "rgba(255, 128, 64, 0.2)"
+/// );
+/// assert_eq!(
+///     RgbFunction::new(Srgba::new(255u8, 128, 64, 128)).to_string(),
+///     "rgba(255, 128, 64, 0.502)"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RgbFunction(Srgba<u8>);
+
+impl RgbFunction {
+    /// Prepare `color` for formatting.
+    pub fn new<C: Into<Srgba<u8>>>(color: C) -> Self {
+        RgbFunction(color.into())
+    }
+}
+
+impl fmt::Display for RgbFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (red, green, blue, alpha) = self.0.into_components();
+        if alpha == u8::MAX {
+            return write!(f, "rgb({}, {}, {})", red, green, blue);
+        }
+
+        write!(f, "rgba({}, {}, {}, ", red, green, blue)?;
+        if alpha == 0 {
+            return f.write_str("0)");
+        }
+
+        // Find the fewest decimals that round to the same value, and write
+        // them as an integer with leading zeros. Values that are exactly
+        // halfway between two 8 bit values are avoided, since they may
+        // round either way.
+        let alpha = i32::from(alpha);
+        let mut scale = 10;
+        let mut decimals = 1;
+        loop {
+            let scaled = (alpha * scale * 2 + 255) / 510;
+            let error = (scaled * 255 - alpha * scale).abs();
+            if decimals == 3 || error * 2 < scale {
+                let mut scaled = scaled;
+                let mut decimals = decimals;
+                while scaled % 10 == 0 {
+                    scaled /= 10;
+                    decimals -= 1;
+                }
+                return write!(f, "0.{:0width$})", scaled, width = decimals);
+            }
+
+            scale *= 10;
+            decimals += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HexColor, RgbFunction};
+    use crate::css::parse_color;
+    use crate::{Packed, Srgba};
+
+    #[test]
+    fn hex() {
+        let color = Srgba::new(1u8, 35, 171, 255);
+        assert_eq!(HexColor::new(color).to_string(), "#0123ab");
+        assert_eq!(format!("{:X}", HexColor::new(color)), "#0123AB");
+
+        let color = Srgba::new(1u8, 35, 171, 0);
+        assert_eq!(HexColor::new(color).to_string(), "#0123ab00");
+
+        let packed: Packed = Srgba::new(1u8, 35, 171, 205).into();
+        assert_eq!(HexColor::new(packed).to_string(), "#0123abcd");
+    }
+
+    #[test]
+    fn rgb_function() {
+        let format = |alpha| RgbFunction::new(Srgba::new(1u8, 2, 3, alpha)).to_string();
+
+        assert_eq!(format(255), "rgb(1, 2, 3)");
+        assert_eq!(format(0), "rgba(1, 2, 3, 0)");
+        assert_eq!(format(51), "rgba(1, 2, 3, 0.2)");
+        assert_eq!(format(64), "rgba(1, 2, 3, 0.25)");
+        assert_eq!(format(1), "rgba(1, 2, 3, 0.004)");
+        assert_eq!(format(254), "rgba(1, 2, 3, 0.996)");
+    }
+
+    #[test]
+    fn roundtrip() {
+        for alpha in 0..=255 {
+            let color = Srgba::new(12u8, 200, 255, alpha);
+            for formatted in &[
+                HexColor::new(color).to_string(),
+                RgbFunction::new(color).to_string(),
+            ] {
+                let parsed: Srgba<u8> = parse_color::<f64>(formatted).unwrap().into_format();
+                assert_eq!(parsed, color, "{}", formatted);
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use core::fmt;
+
+use crate::rgb::FromHexError;
+use crate::{from_f64, Alpha, FloatComponent, Srgba};
+
+use super::relative::{ColorFunction, Parser, RelativeColorError, Token};
+
+/// Parse a CSS color value, such as `#ff8000`, `rgb(255 128 0 / 50%)`,
+/// `hsl(30, 100%, 50%)` or `orange`.
+///
+/// The supported syntaxes are:
+///
+/// * Hex codes with 3, 4, 6 or 8 digits, such as `#f80` or `#ff800080`.
+/// * The color functions `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`,
+///   `lab()`, `lch()`, `oklab()` and `oklch()`, with space separated channels and an optional
+///   `/ alpha`. Channel values can be numbers, percentages, angles, `none`
+///   and `calc()` expressions of those.
+/// * The legacy, comma separated syntax for `rgb()`, `rgba()`, `hsl()` and
+///   `hsla()`, such as `rgba(255, 128, 0, 0.5)`.
+/// * `transparent`, and the named colors, if the `named_from_str` feature is
+///   enabled. Names are case insensitive.
+///
+/// The alpha is clamped to the range `0.0` to `1.0`, while the other
+/// channels are kept as they are, like for
+/// [`relative_color`](fn.relative_color.html).
+///
+/// ```
+/// use palette::css::parse_color;
+/// use palette::Srgba;
+///
+/// let color: Srgba<f64> = parse_color("rgba(255, 0, 0, 0.5)").unwrap();
+/// assert_eq!(color, Srgba::new(1.0, 0.0, 0.0, 0.5));
+///
+/// let color: Srgba<f64> = parse_color("hsl(120deg 100% 25%)").unwrap();
+/// assert_eq!(color, Srgba::new(0.0, 0.5, 0.0, 1.0));
+///
+/// let color: Srgba<u8> = parse_color::<f32>("#ff8000").unwrap().into_format();
+/// assert_eq!(color, Srgba::new(255, 128, 0, 255));
+/// ```
+pub fn parse_color<T: FloatComponent>(input: &str) -> Result<Srgba<T>, ParseColorError> {
+    let input = input.trim();
+
+    if input.starts_with('#') {
+        let color: Srgba<u8> = input.parse().map_err(ParseColorError::InvalidHex)?;
+        return Ok(from_u8(color));
+    }
+
+    let mut parser = Parser::new(input);
+    let color = match parser.next()? {
+        Some(Token::Ident(name)) => named_color(name)?,
+        Some(Token::Function(name)) => {
+            color_function(&mut parser, ColorFunction::from_name(name)?)?
+        }
+        _ => return Err(ParseColorError::InvalidSyntax),
+    };
+
+    if parser.next()?.is_some() {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+
+    Ok(color)
+}
+
+/// The error type for parsing CSS colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The color is a hex code, but it couldn't be parsed.
+    InvalidHex(FromHexError),
+    /// The color is a name, but not one of the known color names.
+    UnknownName,
+    /// The color uses a function that isn't supported.
+    UnsupportedFunction,
+    /// The color is not valid CSS color syntax.
+    InvalidSyntax,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidHex(error) => error.fmt(f),
+            ParseColorError::UnknownName => write!(f, "unknown color name"),
+            ParseColorError::UnsupportedFunction => write!(f, "unsupported color function"),
+            ParseColorError::InvalidSyntax => write!(f, "invalid color syntax"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseColorError::InvalidHex(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<RelativeColorError> for ParseColorError {
+    fn from(error: RelativeColorError) -> Self {
+        match error {
+            RelativeColorError::UnsupportedFunction => ParseColorError::UnsupportedFunction,
+            // Channel keywords are only valid in relative colors.
+            RelativeColorError::UnknownKeyword | RelativeColorError::InvalidSyntax => {
+                ParseColorError::InvalidSyntax
+            }
+        }
+    }
+}
+
+/// Parse the arguments of a color function, after its name.
+fn color_function<T: FloatComponent>(
+    parser: &mut Parser<'_>,
+    function: ColorFunction,
+) -> Result<Srgba<T>, ParseColorError> {
+    let mut values = [T::zero(); 3];
+    values[0] = parser.channel(function, None, 0)?;
+
+    let legacy = parser.peek()? == Some(Token::Delim(','));
+    if legacy && function != ColorFunction::Rgb && function != ColorFunction::Hsl {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+
+    for (index, value) in values.iter_mut().enumerate().skip(1) {
+        if legacy && parser.next()? != Some(Token::Delim(',')) {
+            return Err(ParseColorError::InvalidSyntax);
+        }
+        *value = parser.channel(function, None, index)?;
+    }
+
+    let alpha_separator = if legacy { ',' } else { '/' };
+    let alpha = match parser.next()? {
+        Some(Token::Delim(separator)) if separator == alpha_separator => {
+            let alpha = parser.channel(function, None, 3)?;
+            parser.expect_close()?;
+            alpha
+        }
+        Some(Token::CloseParen) => T::one(),
+        _ => return Err(ParseColorError::InvalidSyntax),
+    };
+
+    Ok(Alpha {
+        color: function.to_rgb(values),
+        alpha: alpha.max(T::zero()).min(T::one()),
+    })
+}
+
+fn named_color<T: FloatComponent>(name: &str) -> Result<Srgba<T>, ParseColorError> {
+    if name.eq_ignore_ascii_case("transparent") {
+        return Ok(Srgba::new(T::zero(), T::zero(), T::zero(), T::zero()));
+    }
+
+    #[cfg(feature = "named_from_str")]
+    {
+        // The longest name is "lightgoldenrodyellow".
+        let mut buffer = [0u8; 24];
+        if let Some(lowercase) = buffer.get_mut(..name.len()) {
+            lowercase.copy_from_slice(name.as_bytes());
+            lowercase.make_ascii_lowercase();
+
+            let color = core::str::from_utf8(lowercase)
+                .ok()
+                .and_then(crate::named::from_str);
+            if let Some(color) = color {
+                return Ok(from_u8(color.into()));
+            }
+        }
+    }
+
+    Err(ParseColorError::UnknownName)
+}
+
+fn from_u8<T: FloatComponent>(color: Srgba<u8>) -> Srgba<T> {
+    let (red, green, blue, alpha) = color.into_format::<f64, f64>().into_components();
+    Srgba::new(
+        from_f64(red),
+        from_f64(green),
+        from_f64(blue),
+        from_f64(alpha),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_color, ParseColorError};
+    use crate::rgb::FromHexError;
+    use crate::Srgba;
+
+    fn parse(input: &str) -> Srgba<u8> {
+        parse_color::<f64>(input).unwrap().into_format()
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse("#f80"), Srgba::new(255, 136, 0, 255));
+        assert_eq!(parse("#f808"), Srgba::new(255, 136, 0, 136));
+        assert_eq!(parse(" #ff8000 "), Srgba::new(255, 128, 0, 255));
+        assert_eq!(parse("#ff800080"), Srgba::new(255, 128, 0, 128));
+    }
+
+    #[test]
+    fn functions() {
+        let orange = Srgba::new(255, 128, 0, 255);
+        let translucent_orange = Srgba::new(255, 128, 0, 128);
+
+        assert_eq!(parse("rgb(255 128 0)"), orange);
+        assert_eq!(parse("rgb(255, 128, 0)"), orange);
+        assert_eq!(parse("RGB(100% 50.2% 0%)"), orange);
+        assert_eq!(parse("rgba(255, 128, 0, 0.5)"), translucent_orange);
+        assert_eq!(parse("rgb(255 128 0 / 50%)"), translucent_orange);
+        assert_eq!(parse("rgb(255 128 none / calc(1 / 2))"), translucent_orange);
+        assert_eq!(parse("hsl(30.12, 100%, 50%)"), orange);
+        assert_eq!(parse("hsla(30.12deg 100 50 / 0.5)"), translucent_orange);
+        assert_eq!(parse("hwb(0.5turn 0% 0%)"), Srgba::new(0, 255, 255, 255));
+        assert_eq!(parse("lab(100 0 0)"), Srgba::new(255, 255, 255, 255));
+        assert_eq!(parse("lch(0 0 0 / 2)"), Srgba::new(0, 0, 0, 255));
+        assert_eq!(parse("oklab(100% 0 0)"), Srgba::new(255, 255, 255, 255));
+        assert_eq!(
+            parse("oklab(0.627955 0.224863 0.125846)"),
+            Srgba::new(255, 0, 0, 255)
+        );
+        assert_eq!(
+            parse("OKLCH(62.7955% 0.257683 29.2339deg / 50%)"),
+            Srgba::new(255, 0, 0, 128)
+        );
+        assert_eq!(
+            parse("oklch(0.627955 64.42% calc(29.2339 + 360))"),
+            Srgba::new(255, 0, 0, 255)
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(parse("transparent"), Srgba::new(0, 0, 0, 0));
+
+        #[cfg(feature = "named_from_str")]
+        {
+            assert_eq!(parse("orange"), Srgba::new(255, 165, 0, 255));
+            assert_eq!(
+                parse("LightGoldenrodYellow"),
+                Srgba::new(250, 250, 210, 255)
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let error = |input| parse_color::<f64>(input).unwrap_err();
+
+        assert_eq!(
+            error("#ff800"),
+            ParseColorError::InvalidHex(FromHexError::InvalidLength(5))
+        );
+        assert_eq!(error("not-a-color"), ParseColorError::UnknownName);
+        assert_eq!(
+            error("color(srgb 1 0.5 0)"),
+            ParseColorError::UnsupportedFunction
+        );
+        assert_eq!(error("rgb(r g b)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(255, 128 0)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(255 128 0, 1)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("lab(50, 10, 10)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("oklch(0.5, 0.1, 30)"), ParseColorError::InvalidSyntax);
+        assert_eq!(error("rgb(255 128 0) red"), ParseColorError::InvalidSyntax);
+        assert_eq!(error(""), ParseColorError::InvalidSyntax);
+    }
+}
@@ -0,0 +1,750 @@
+use core::fmt;
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Srgb;
+use crate::rgb::Rgb;
+use crate::white_point::{D50, D65};
+use crate::{
+    from_f64, Alpha, FloatComponent, Hsl, Hwb, Lab, LabHue, Lch, Oklab, OklabHue, Oklch, RgbHue,
+    Srgba, Xyz,
+};
+
+use super::{from_d50, xyz_d50};
+
+/// Evaluate a CSS relative color expression, such as
+/// `lch(from var(--accent) calc(l + 10) c h)`, with `origin` as its origin
+/// color.
+///
+/// The origin color in the expression is skipped without being parsed, since
+/// resolving it, for example from a custom property, is up to the caller.
+/// The supported color functions are `rgb()`, `rgba()`, `hsl()`, `hsla()`,
+/// `hwb()`, `lab()`, `lch()`, `oklab()` and `oklch()`. Channel values can be numbers, percentages,
+/// angles, the channel keywords of the color function, `none`, and `calc()`
+/// expressions of those. Channel keywords have the same ranges as in CSS,
+/// such as `0` to `255` for `r`, `g` and `b`, `0` to `100` for `s` and `l`
+/// in `hsl()`, and `0` to `1` for `l` in `oklab()` and `oklch()`.
+///
+/// ```
+/// use palette::css::relative_color;
+/// use palette::Srgba;
+///
+/// let origin = Srgba::new(1.0f64, 0.0, 0.0, 1.0);
+/// let color = relative_color("rgb(from red b g r / calc(alpha / 2))", origin).unwrap();
+/// assert_eq!(color, Srgba::new(0.0, 0.0, 1.0, 0.5));
+/// ```
+pub fn relative_color<T: FloatComponent>(
+    expression: &str,
+    origin: Srgba<T>,
+) -> Result<Srgba<T>, RelativeColorError> {
+    let mut parser = Parser::new(expression);
+
+    let function = match parser.next()? {
+        Some(Token::Function(name)) => ColorFunction::from_name(name)?,
+        _ => return Err(RelativeColorError::InvalidSyntax),
+    };
+
+    match parser.next()? {
+        Some(Token::Ident(from)) if from.eq_ignore_ascii_case("from") => {}
+        _ => return Err(RelativeColorError::InvalidSyntax),
+    }
+    parser.skip_component()?;
+
+    let channels = function.channels(origin);
+    let mut values = [T::zero(); 3];
+    for (index, value) in values.iter_mut().enumerate() {
+        *value = parser.channel(function, Some(&channels), index)?;
+    }
+
+    let alpha = match parser.next()? {
+        Some(Token::Delim('/')) => {
+            let alpha = parser.channel(function, Some(&channels), 3)?;
+            parser.expect_close()?;
+            alpha
+        }
+        Some(Token::CloseParen) => T::one(),
+        _ => return Err(RelativeColorError::InvalidSyntax),
+    };
+
+    if parser.next()?.is_some() {
+        return Err(RelativeColorError::InvalidSyntax);
+    }
+
+    Ok(Alpha {
+        color: function.to_rgb(values),
+        alpha,
+    })
+}
+
+/// The error type for evaluating CSS relative color expressions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelativeColorError {
+    /// The expression uses a color function that isn't supported.
+    UnsupportedFunction,
+    /// The expression refers to a keyword that isn't a channel of the color
+    /// function.
+    UnknownKeyword,
+    /// The expression is not valid relative color syntax.
+    InvalidSyntax,
+}
+
+impl fmt::Display for RelativeColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelativeColorError::UnsupportedFunction => write!(f, "unsupported color function"),
+            RelativeColorError::UnknownKeyword => write!(f, "unknown channel keyword"),
+            RelativeColorError::InvalidSyntax => write!(f, "invalid relative color syntax"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RelativeColorError {}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum ColorFunction {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+impl ColorFunction {
+    pub(super) fn from_name(name: &str) -> Result<Self, RelativeColorError> {
+        let functions = [
+            ("rgb", ColorFunction::Rgb),
+            ("rgba", ColorFunction::Rgb),
+            ("hsl", ColorFunction::Hsl),
+            ("hsla", ColorFunction::Hsl),
+            ("hwb", ColorFunction::Hwb),
+            ("lab", ColorFunction::Lab),
+            ("lch", ColorFunction::Lch),
+            ("oklab", ColorFunction::Oklab),
+            ("oklch", ColorFunction::Oklch),
+        ];
+
+        functions
+            .iter()
+            .find(|(function_name, _)| function_name.eq_ignore_ascii_case(name))
+            .map(|&(_, function)| function)
+            .ok_or(RelativeColorError::UnsupportedFunction)
+    }
+
+    /// The channel keywords, in order, with `alpha` last.
+    fn keywords(self) -> [&'static str; 4] {
+        match self {
+            ColorFunction::Rgb => ["r", "g", "b", "alpha"],
+            ColorFunction::Hsl => ["h", "s", "l", "alpha"],
+            ColorFunction::Hwb => ["h", "w", "b", "alpha"],
+            ColorFunction::Lab => ["l", "a", "b", "alpha"],
+            ColorFunction::Lch | ColorFunction::Oklch => ["l", "c", "h", "alpha"],
+            ColorFunction::Oklab => ["l", "a", "b", "alpha"],
+        }
+    }
+
+    /// What `100%` means for each channel, or `None` for hues.
+    fn percentage_references(self) -> [Option<f64>; 4] {
+        match self {
+            ColorFunction::Rgb => [Some(255.0), Some(255.0), Some(255.0), Some(1.0)],
+            ColorFunction::Hsl | ColorFunction::Hwb => [None, Some(100.0), Some(100.0), Some(1.0)],
+            ColorFunction::Lab => [Some(100.0), Some(125.0), Some(125.0), Some(1.0)],
+            ColorFunction::Lch => [Some(100.0), Some(150.0), None, Some(1.0)],
+            ColorFunction::Oklab => [Some(1.0), Some(0.4), Some(0.4), Some(1.0)],
+            ColorFunction::Oklch => [Some(1.0), Some(0.4), None, Some(1.0)],
+        }
+    }
+
+    /// The values of the channel keywords for `origin`.
+    fn channels<T: FloatComponent>(self, origin: Srgba<T>) -> [T; 4] {
+        let hundred = from_f64::<T>(100.0);
+        let color = origin.color;
+
+        let [c1, c2, c3] = match self {
+            ColorFunction::Rgb => {
+                let max = from_f64::<T>(255.0);
+                [color.red * max, color.green * max, color.blue * max]
+            }
+            ColorFunction::Hsl => {
+                let hsl = Hsl::<Srgb, T>::from_color_unclamped(color);
+                [
+                    hsl.hue.to_positive_degrees(),
+                    hsl.saturation * hundred,
+                    hsl.lightness * hundred,
+                ]
+            }
+            ColorFunction::Hwb => {
+                let hwb = Hwb::<Srgb, T>::from_color_unclamped(color);
+                [
+                    hwb.hue.to_positive_degrees(),
+                    hwb.whiteness * hundred,
+                    hwb.blackness * hundred,
+                ]
+            }
+            ColorFunction::Lab => {
+                let lab = Lab::<D50, T>::from_color_unclamped(xyz_d50(color));
+                [lab.l, lab.a, lab.b]
+            }
+            ColorFunction::Lch => {
+                let lch = Lch::<D50, T>::from_color_unclamped(xyz_d50(color));
+                [lch.l, lch.chroma, lch.hue.to_positive_degrees()]
+            }
+            ColorFunction::Oklab => {
+                let oklab = Oklab::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(color));
+                [oklab.l, oklab.a, oklab.b]
+            }
+            ColorFunction::Oklch => {
+                let oklch = Oklch::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(color));
+                [oklch.l, oklch.chroma, oklch.hue.to_positive_degrees()]
+            }
+        };
+
+        [c1, c2, c3, origin.alpha]
+    }
+
+    pub(super) fn to_rgb<T: FloatComponent>(self, [c1, c2, c3]: [T; 3]) -> Rgb<Srgb, T> {
+        let hundred = from_f64::<T>(100.0);
+
+        match self {
+            ColorFunction::Rgb => {
+                let max = from_f64::<T>(255.0);
+                Rgb::new(c1 / max, c2 / max, c3 / max)
+            }
+            ColorFunction::Hsl => Rgb::from_color_unclamped(Hsl::<Srgb, T>::new(
+                RgbHue::from_degrees(c1),
+                c2 / hundred,
+                c3 / hundred,
+            )),
+            ColorFunction::Hwb => Rgb::from_color_unclamped(Hwb::<Srgb, T>::new(
+                RgbHue::from_degrees(c1),
+                c2 / hundred,
+                c3 / hundred,
+            )),
+            ColorFunction::Lab => from_d50(Xyz::from_color_unclamped(Lab::<D50, T>::with_wp(
+                c1, c2, c3,
+            ))),
+            ColorFunction::Lch => from_d50(Xyz::from_color_unclamped(Lch::<D50, T>::with_wp(
+                c1,
+                c2,
+                LabHue::from_degrees(c3),
+            ))),
+            ColorFunction::Oklab => Rgb::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(
+                Oklab::new(c1, c2, c3),
+            )),
+            ColorFunction::Oklch => Rgb::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(
+                Oklch::new(c1, c2, OklabHue::from_degrees(c3)),
+            )),
+        }
+    }
+}
+
+/// The deepest nesting of parentheses and `calc()` that is parsed, to keep
+/// untrusted input from overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Token<'a> {
+    Ident(&'a str),
+    /// A function name, including the opening parenthesis.
+    Function(&'a str),
+    Hash,
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, &'a str),
+    Delim(char),
+    OpenParen,
+    CloseParen,
+}
+
+/// A tokenizer and parser for a subset of CSS syntax.
+pub(super) struct Parser<'a> {
+    input: &'a str,
+    peeked: Option<Token<'a>>,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            peeked: None,
+            depth: 0,
+        }
+    }
+
+    pub(super) fn peek(&mut self) -> Result<Option<Token<'a>>, RelativeColorError> {
+        if self.peeked.is_none() {
+            self.peeked = self.tokenize()?;
+        }
+
+        Ok(self.peeked)
+    }
+
+    pub(super) fn next(&mut self) -> Result<Option<Token<'a>>, RelativeColorError> {
+        match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.tokenize(),
+        }
+    }
+
+    pub(super) fn expect_close(&mut self) -> Result<(), RelativeColorError> {
+        match self.next()? {
+            Some(Token::CloseParen) => Ok(()),
+            _ => Err(RelativeColorError::InvalidSyntax),
+        }
+    }
+
+    fn tokenize(&mut self) -> Result<Option<Token<'a>>, RelativeColorError> {
+        self.input = self.input.trim_start();
+        let bytes = self.input.as_bytes();
+
+        let first = match bytes.first() {
+            Some(&first) => first,
+            None => return Ok(None),
+        };
+        let second = bytes.get(1).copied();
+        let third = bytes.get(2).copied();
+
+        let starts_number = |first: u8, second: Option<u8>, third: Option<u8>| match first {
+            b'0'..=b'9' => true,
+            b'.' => is_digit(second),
+            b'+' | b'-' => match second {
+                Some(b'0'..=b'9') => true,
+                Some(b'.') => is_digit(third),
+                _ => false,
+            },
+            _ => false,
+        };
+        let starts_ident = |first: u8, second: Option<u8>| match first {
+            b'-' => matches!(second, Some(c) if is_name_start(c) || c == b'-'),
+            c => is_name_start(c),
+        };
+
+        let token = if starts_number(first, second, third) {
+            let (number, rest) = split_number(self.input);
+            let number = number
+                .parse()
+                .map_err(|_| RelativeColorError::InvalidSyntax)?;
+            self.input = rest;
+
+            if let Some(rest) = self.input.strip_prefix('%') {
+                self.input = rest;
+                Token::Percentage(number)
+            } else if starts_ident(
+                self.input.as_bytes().first().copied().unwrap_or(b' '),
+                self.input.as_bytes().get(1).copied(),
+            ) {
+                let (unit, rest) = split_name(self.input);
+                self.input = rest;
+                Token::Dimension(number, unit)
+            } else {
+                Token::Number(number)
+            }
+        } else if starts_ident(first, second) {
+            let (name, rest) = split_name(self.input);
+            if let Some(rest) = rest.strip_prefix('(') {
+                self.input = rest;
+                Token::Function(name)
+            } else {
+                self.input = rest;
+                Token::Ident(name)
+            }
+        } else if first == b'#' {
+            let (_, rest) = split_name(&self.input[1..]);
+            self.input = rest;
+            Token::Hash
+        } else {
+            let character = self
+                .input
+                .chars()
+                .next()
+                .expect("the input should not be empty");
+            self.input = &self.input[character.len_utf8()..];
+
+            match character {
+                '(' => Token::OpenParen,
+                ')' => Token::CloseParen,
+                character => Token::Delim(character),
+            }
+        };
+
+        Ok(Some(token))
+    }
+
+    /// Skip a single component value, such as a keyword or a function with
+    /// its arguments.
+    fn skip_component(&mut self) -> Result<(), RelativeColorError> {
+        let mut depth = match self.next()? {
+            Some(Token::Function(_)) | Some(Token::OpenParen) => 1,
+            Some(Token::CloseParen) | None => return Err(RelativeColorError::InvalidSyntax),
+            Some(_) => return Ok(()),
+        };
+
+        while depth > 0 {
+            match self.next()? {
+                Some(Token::Function(_)) | Some(Token::OpenParen) => depth += 1,
+                Some(Token::CloseParen) => depth -= 1,
+                Some(_) => {}
+                None => return Err(RelativeColorError::InvalidSyntax),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the value of the channel at `index`. Channel keywords are only
+    /// allowed if the values of the channels are given.
+    pub(super) fn channel<T: FloatComponent>(
+        &mut self,
+        function: ColorFunction,
+        channels: Option<&[T; 4]>,
+        index: usize,
+    ) -> Result<T, RelativeColorError> {
+        let context = Context {
+            keywords: function.keywords(),
+            channels,
+            percentage_reference: function.percentage_references()[index],
+        };
+
+        let value = match self.next()? {
+            Some(Token::Ident(none)) if none.eq_ignore_ascii_case("none") => T::zero(),
+            Some(token) => self.value(token, &context)?,
+            None => return Err(RelativeColorError::InvalidSyntax),
+        };
+
+        // NaN becomes zero and infinities are clamped, as in CSS.
+        Ok(if value.is_nan() {
+            T::zero()
+        } else {
+            value.max(T::min_value()).min(T::max_value())
+        })
+    }
+
+    /// Parse a sum inside parentheses, up to the closing parenthesis.
+    fn nested_sum<T: FloatComponent>(
+        &mut self,
+        context: &Context<T>,
+    ) -> Result<T, RelativeColorError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(RelativeColorError::InvalidSyntax);
+        }
+
+        self.depth += 1;
+        let value = self.sum(context);
+        self.depth -= 1;
+
+        let value = value?;
+        self.expect_close()?;
+        Ok(value)
+    }
+
+    /// Parse a sum of products, as in `calc()`.
+    fn sum<T: FloatComponent>(&mut self, context: &Context<T>) -> Result<T, RelativeColorError> {
+        let mut sum = self.product(context)?;
+
+        loop {
+            match self.peek()? {
+                Some(Token::Delim('+')) => {
+                    self.next()?;
+                    sum = sum + self.product(context)?;
+                }
+                Some(Token::Delim('-')) => {
+                    self.next()?;
+                    sum = sum - self.product(context)?;
+                }
+                _ => return Ok(sum),
+            }
+        }
+    }
+
+    fn product<T: FloatComponent>(
+        &mut self,
+        context: &Context<T>,
+    ) -> Result<T, RelativeColorError> {
+        let mut product = self.operand(context)?;
+
+        loop {
+            match self.peek()? {
+                Some(Token::Delim('*')) => {
+                    self.next()?;
+                    product = product * self.operand(context)?;
+                }
+                Some(Token::Delim('/')) => {
+                    self.next()?;
+                    product = product / self.operand(context)?;
+                }
+                _ => return Ok(product),
+            }
+        }
+    }
+
+    fn operand<T: FloatComponent>(
+        &mut self,
+        context: &Context<T>,
+    ) -> Result<T, RelativeColorError> {
+        match self.next()? {
+            Some(Token::OpenParen) => self.nested_sum(context),
+            Some(Token::Ident(name)) if name.eq_ignore_ascii_case("pi") => {
+                Ok(from_f64(core::f64::consts::PI))
+            }
+            Some(Token::Ident(name)) if name.eq_ignore_ascii_case("e") => {
+                Ok(from_f64(core::f64::consts::E))
+            }
+            Some(token) => self.value(token, context),
+            None => Err(RelativeColorError::InvalidSyntax),
+        }
+    }
+
+    /// Resolve a single value, which may be a `calc()` expression.
+    fn value<T: FloatComponent>(
+        &mut self,
+        token: Token<'a>,
+        context: &Context<T>,
+    ) -> Result<T, RelativeColorError> {
+        match token {
+            Token::Number(number) => Ok(from_f64(number)),
+            Token::Percentage(percentage) => context
+                .percentage_reference
+                .map(|reference| from_f64(percentage / 100.0 * reference))
+                .ok_or(RelativeColorError::InvalidSyntax),
+            Token::Dimension(number, unit) if context.percentage_reference.is_none() => {
+                let degrees_per_unit = [
+                    ("deg", 1.0),
+                    ("grad", 0.9),
+                    ("rad", 180.0 / core::f64::consts::PI),
+                    ("turn", 360.0),
+                ];
+
+                degrees_per_unit
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+                    .map(|&(_, factor)| from_f64(number * factor))
+                    .ok_or(RelativeColorError::InvalidSyntax)
+            }
+            Token::Ident(keyword) => context
+                .keywords
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(keyword))
+                .and_then(|index| Some(context.channels?[index]))
+                .ok_or(RelativeColorError::UnknownKeyword),
+            Token::Function(name) if name.eq_ignore_ascii_case("calc") => self.nested_sum(context),
+            Token::Function(_) => Err(RelativeColorError::UnsupportedFunction),
+            _ => Err(RelativeColorError::InvalidSyntax),
+        }
+    }
+}
+
+/// What keywords and percentages resolve to for a channel.
+struct Context<'c, T> {
+    keywords: [&'static str; 4],
+    channels: Option<&'c [T; 4]>,
+    percentage_reference: Option<f64>,
+}
+
+fn is_name_start(character: u8) -> bool {
+    character.is_ascii_alphabetic() || character == b'_' || !character.is_ascii()
+}
+
+fn is_name(character: u8) -> bool {
+    is_name_start(character) || character.is_ascii_digit() || character == b'-'
+}
+
+fn is_digit(character: Option<u8>) -> bool {
+    matches!(character, Some(b'0'..=b'9'))
+}
+
+fn split_name(input: &str) -> (&str, &str) {
+    let end = input
+        .bytes()
+        .position(|c| !is_name(c))
+        .unwrap_or(input.len());
+    input.split_at(end)
+}
+
+fn split_number(input: &str) -> (&str, &str) {
+    let bytes = input.as_bytes();
+    let mut end = 0;
+
+    if let Some(b'+') | Some(b'-') = bytes.first() {
+        end += 1;
+    }
+    while is_digit(bytes.get(end).copied()) {
+        end += 1;
+    }
+    if bytes.get(end) == Some(&b'.') && is_digit(bytes.get(end + 1).copied()) {
+        end += 1;
+        while is_digit(bytes.get(end).copied()) {
+            end += 1;
+        }
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(end) {
+        let exponent_start = match bytes.get(end + 1) {
+            Some(b'+') | Some(b'-') => end + 2,
+            _ => end + 1,
+        };
+        if is_digit(bytes.get(exponent_start).copied()) {
+            end = exponent_start;
+            while is_digit(bytes.get(end).copied()) {
+                end += 1;
+            }
+        }
+    }
+
+    input.split_at(end)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{relative_color, RelativeColorError};
+    use crate::{FromColor, Oklch, Srgba};
+
+    const ORIGIN: Srgba<f64> = Srgba {
+        color: crate::rgb::Rgb {
+            red: 0.2,
+            green: 0.4,
+            blue: 0.6,
+            standard: core::marker::PhantomData,
+        },
+        alpha: 0.8,
+    };
+
+    #[test]
+    fn identity() {
+        for expression in &[
+            "rgb(from var(--x) r g b / alpha)",
+            "hsl(from #336699 h s l / alpha)",
+            "hwb(from rgb(51 102 153) h w b / alpha)",
+            "lab(from currentColor l a b / alpha)",
+            "LCH(FROM blue L C H / ALPHA)",
+            "oklab(from red l a b / alpha)",
+            "oklch(from red l c h / alpha)",
+        ] {
+            let color = relative_color(expression, ORIGIN).unwrap();
+            assert_relative_eq!(color, ORIGIN, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn calc() {
+        let color = relative_color("rgb(from red calc(r * 2) calc((g + b) / 2) 0%)", ORIGIN);
+        assert_relative_eq!(
+            color.unwrap(),
+            Srgba::new(0.4, 0.5, 0.0, 1.0),
+            epsilon = 1e-9
+        );
+
+        let color = relative_color("rgb(from red calc(r + 10%) 127.5 2.55e2 / 50%)", ORIGIN);
+        assert_relative_eq!(
+            color.unwrap(),
+            Srgba::new(0.3, 0.5, 1.0, 0.5),
+            epsilon = 1e-9
+        );
+
+        let color = relative_color("hsl(from red calc(h + 0.5turn) s l)", ORIGIN).unwrap();
+        let expected = relative_color("hsl(from red calc(h + 180) s l)", ORIGIN).unwrap();
+        assert_relative_eq!(color, expected, epsilon = 1e-9);
+        assert_relative_eq!(color, Srgba::new(0.6, 0.4, 0.2, 1.0), epsilon = 1e-9);
+
+        let color = relative_color("hsl(from red none 0 calc(l - .1 * 100))", ORIGIN).unwrap();
+        assert_relative_eq!(color, Srgba::new(0.3, 0.3, 0.3, 1.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn calc_non_finite() {
+        let color = relative_color("rgb(from red calc(0 / 0) g calc(b / 0))", ORIGIN).unwrap();
+        assert_eq!(color.red, 0.0);
+        assert_eq!(color.blue, f64::MAX / 255.0);
+
+        let color = relative_color("hsl(from red calc(h / 0) s l)", ORIGIN).unwrap();
+        assert!(!color.red.is_nan() && !color.green.is_nan() && !color.blue.is_nan());
+    }
+
+    #[test]
+    fn calc_nesting() {
+        let nested = |depth: usize| {
+            let expression = format!(
+                "rgb(from red calc({}r{}) g b)",
+                "(".repeat(depth),
+                ")".repeat(depth)
+            );
+            relative_color(&expression, ORIGIN)
+        };
+
+        assert_relative_eq!(nested(32).unwrap(), ORIGIN.color.into(), epsilon = 1e-9);
+        assert_eq!(
+            nested(100_000).unwrap_err(),
+            RelativeColorError::InvalidSyntax
+        );
+    }
+
+    #[test]
+    fn lightness() {
+        let lighter = relative_color("lch(from red calc(l + 10) c h)", ORIGIN).unwrap();
+        let darker = relative_color("lch(from red calc(l - 10) c h)", ORIGIN).unwrap();
+        assert!(lighter.red > ORIGIN.red && lighter.blue > ORIGIN.blue);
+        assert!(darker.red < ORIGIN.red && darker.blue < ORIGIN.blue);
+    }
+
+    #[test]
+    fn oklab() {
+        let lighter = relative_color("oklch(from var(--x) calc(l + .1) c h)", ORIGIN).unwrap();
+        let origin = Oklch::from_color(ORIGIN.color.into_linear());
+        let color = Oklch::from_color(lighter.color.into_linear());
+        assert_relative_eq!(color.l, origin.l + 0.1, epsilon = 1e-9);
+        assert_relative_eq!(color.chroma, origin.chroma, epsilon = 1e-9);
+        assert_relative_eq!(color.hue, origin.hue, epsilon = 1e-6);
+
+        let gray = relative_color("oklab(from red l 0 0%)", ORIGIN).unwrap();
+        assert_relative_eq!(gray.red, gray.green, epsilon = 1e-9);
+        assert_relative_eq!(gray.green, gray.blue, epsilon = 1e-9);
+
+        let hue = relative_color("oklch(from red l c calc(h + 0.5turn))", ORIGIN).unwrap();
+        let flipped =
+            relative_color("oklab(from red l calc(a * -1) calc(b * -1))", ORIGIN).unwrap();
+        assert_relative_eq!(hue, flipped, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |expression| relative_color(expression, ORIGIN).unwrap_err();
+
+        assert_eq!(
+            error("color(from red srgb r g b)"),
+            RelativeColorError::UnsupportedFunction
+        );
+        assert_eq!(
+            error("oklab(from red l c h)"),
+            RelativeColorError::UnknownKeyword
+        );
+        assert_eq!(
+            error("rgb(from red l c h)"),
+            RelativeColorError::UnknownKeyword
+        );
+        assert_eq!(error("rgb(red r g b)"), RelativeColorError::InvalidSyntax);
+        assert_eq!(
+            error("rgb(from red r g)"),
+            RelativeColorError::InvalidSyntax
+        );
+        assert_eq!(
+            error("rgb(from red r g b"),
+            RelativeColorError::InvalidSyntax
+        );
+        assert_eq!(
+            error("rgb(from red r g b) x"),
+            RelativeColorError::InvalidSyntax
+        );
+        assert_eq!(
+            error("rgb(from red calc(r g b)"),
+            RelativeColorError::InvalidSyntax
+        );
+        assert_eq!(
+            error("hsl(from red 10% s l)"),
+            RelativeColorError::InvalidSyntax
+        );
+        assert_eq!(
+            error("rgb(from red 10deg g b)"),
+            RelativeColorError::InvalidSyntax
+        );
+    }
+}
@@ -0,0 +1,833 @@
+//! Per-pixel color differences between images, and between palettes.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! A [`DifferenceMap`](struct.DifferenceMap.html) compares two images of the
+//! same size, pixel by pixel, and keeps the ΔE of each pixel together with
+//! summary statistics. The values can be shown as a heat map, to see where
+//! two images differ, and the statistics are what image quality reports
+//! usually list.
+//!
+//! ```
+//! use palette::difference::{DeltaE, DifferenceMap};
+//! use palette::Srgb;
+//!
+//! let reference = vec![Srgb::new(0.8, 0.2, 0.1); 16];
+//! let mut compressed = reference.clone();
+//! compressed[3] = Srgb::new(0.75, 0.25, 0.1);
+//!
+//! let map = DifferenceMap::new(&reference, &compressed, DeltaE::Ciede2000);
+//! assert_eq!(map.values()[0], 0.0);
+//! assert!(map.values()[3] > 1.0);
+//! assert_eq!(map.max(), map.values()[3]);
+//! ```
+//!
+//! [`PaletteMatch`](struct.PaletteMatch.html) compares two palettes instead,
+//! by matching their colors one to one, which is useful for checking a
+//! design against brand colors, or for finding similar palettes.
+//!
+//! [`WindowStatistics`](struct.WindowStatistics.html) has the luminance,
+//! contrast and structure terms of SSIM, for metrics that compare local
+//! patterns instead of single pixels.
+
+use core::cmp::Ordering;
+
+use crate::white_point::D65;
+use crate::{from_f64, CieDifference, ColorPoint, FloatComponent, FromColor, Lab, Oklab};
+
+/// A color difference metric, for comparing pixels.
+///
+/// All metrics except `Oklab` are in the units of CIE L\*a\*b\*, where a
+/// difference of about 1.0 is just noticeable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeltaE {
+    /// The Euclidean distance in CIE L\*a\*b\*. It's fast, but overestimates
+    /// differences between saturated colors.
+    Cie76,
+
+    /// The CIE94 difference, with the weights for graphic arts. It's not
+    /// symmetric, since the weights depend on the chroma of the reference
+    /// color.
+    Cie94,
+
+    /// The CIEDE2000 difference. It's the most accurate of the CIE metrics,
+    /// and the slowest.
+    Ciede2000,
+
+    /// The Euclidean distance in [`Oklab`](../struct.Oklab.html). A
+    /// difference of about 0.02 is just noticeable.
+    Oklab,
+}
+
+impl DeltaE {
+    /// Calculate the difference between the `reference` color and `other`.
+    pub fn difference<T>(self, reference: Lab<D65, T>, other: Lab<D65, T>) -> T
+    where
+        T: FloatComponent,
+    {
+        match self {
+            DeltaE::Cie76 => reference.delta_e_cie76(&other),
+            DeltaE::Cie94 => reference.delta_e_cie94(&other),
+            DeltaE::Ciede2000 => reference.delta_e_ciede2000(&other),
+            DeltaE::Oklab => Oklab::from_color(reference).distance(&Oklab::from_color(other)),
+        }
+    }
+}
+
+/// The ΔE of each pixel in two images, with summary statistics.
+///
+/// See the [module documentation](index.html) for an example.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DifferenceMap<T = f32> {
+    values: Vec<T>,
+    mean: T,
+    percentile_95: T,
+    max: T,
+}
+
+impl<T> DifferenceMap<T>
+where
+    T: FloatComponent,
+{
+    /// Compare each pixel in `reference` with the pixel at the same index in
+    /// `other`, using `metric`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    pub fn new<C>(reference: &[C], other: &[C], metric: DeltaE) -> Self
+    where
+        C: Copy,
+        Lab<D65, T>: FromColor<C>,
+    {
+        assert_eq!(
+            reference.len(),
+            other.len(),
+            "the images must have the same number of pixels"
+        );
+
+        let values = reference
+            .iter()
+            .zip(other)
+            .map(|(&reference, &other)| {
+                metric.difference(Lab::from_color(reference), Lab::from_color(other))
+            })
+            .collect();
+
+        Self::from_values(values)
+    }
+
+    /// Wrap already calculated differences and calculate the statistics.
+    pub fn from_values(values: Vec<T>) -> Self {
+        let mut map = DifferenceMap {
+            values,
+            mean: T::zero(),
+            percentile_95: T::zero(),
+            max: T::zero(),
+        };
+
+        if !map.values.is_empty() {
+            let sum = map.values.iter().fold(T::zero(), |sum, &value| sum + value);
+            map.mean = sum / from_f64(map.values.len() as f64);
+            map.max = map
+                .values
+                .iter()
+                .fold(T::zero(), |max, &value| max.max(value));
+            map.percentile_95 = map.percentile(from_f64(95.0));
+        }
+
+        map
+    }
+
+    /// The difference of each pixel, in the same order as the images.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Consume the map and return the difference of each pixel.
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+
+    /// The mean difference, or `0.0` for empty images.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// The 95th percentile of the differences, or `0.0` for empty images.
+    pub fn percentile_95(&self) -> T {
+        self.percentile_95
+    }
+
+    /// The largest difference, or `0.0` for empty images.
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// The smallest difference that `percentile` percent of the pixels are
+    /// at or below, from `0.0` to `100.0`. Returns `0.0` for empty images.
+    ///
+    /// This sorts a copy of the values, so it's best to call it once for
+    /// each percentile.
+    pub fn percentile(&self, percentile: T) -> T {
+        if self.values.is_empty() {
+            return T::zero();
+        }
+
+        let mut sorted = self.values.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        // The nearest rank method.
+        let fraction = crate::clamp(percentile / from_f64(100.0), T::zero(), T::one());
+        let rank = (fraction * from_f64(sorted.len() as f64))
+            .ceil()
+            .to_usize()
+            .unwrap_or(0);
+
+        sorted[rank.max(1).min(sorted.len()) - 1]
+    }
+}
+
+/// The closest one-to-one matching between the colors of two palettes, and
+/// how similar the palettes are.
+///
+/// The colors are paired so that the total ΔE of the pairs is as small as
+/// possible, with the Hungarian algorithm. Unlike matching each color with
+/// its nearest neighbor, two colors can't be matched with the same color.
+/// If the palettes have different sizes, the extra colors of the larger one
+/// are left unmatched.
+///
+/// ```
+/// use palette::difference::{DeltaE, PaletteMatch};
+/// use palette::Srgb;
+///
+/// let brand = [
+///     Srgb::new(0.9, 0.1, 0.1),
+///     Srgb::new(0.1, 0.3, 0.8),
+///     Srgb::new(0.95, 0.95, 0.9),
+/// ];
+/// let design = [
+///     Srgb::new(0.1, 0.31, 0.8),
+///     Srgb::new(0.95, 0.95, 0.9),
+///     Srgb::new(0.8, 0.3, 0.1),
+/// ];
+///
+/// let matched = PaletteMatch::new(&brand, &design, DeltaE::Ciede2000);
+/// assert_eq!(matched.pairs()[0].1, 2);
+/// assert_eq!(matched.pairs()[1].1, 0);
+///
+/// // Two of the three colors are within a ΔE of 2.0.
+/// assert_eq!(matched.similarity(2.0), 2.0 / 3.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteMatch<T = f32> {
+    pairs: Vec<(usize, usize, T)>,
+    unmatched_reference: Vec<usize>,
+    unmatched_other: Vec<usize>,
+}
+
+impl<T> PaletteMatch<T>
+where
+    T: FloatComponent,
+{
+    /// Match the colors in `reference` with the colors in `other`, using
+    /// `metric`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any difference is NaN or infinite, such as for colors with
+    /// NaN components.
+    pub fn new<C>(reference: &[C], other: &[C], metric: DeltaE) -> Self
+    where
+        C: Copy,
+        Lab<D65, T>: FromColor<C>,
+    {
+        let reference: Vec<Lab<D65, T>> = reference
+            .iter()
+            .map(|&color| Lab::from_color(color))
+            .collect();
+        let other: Vec<Lab<D65, T>> = other.iter().map(|&color| Lab::from_color(color)).collect();
+
+        let differences: Vec<T> = reference
+            .iter()
+            .flat_map(|&reference| {
+                other
+                    .iter()
+                    .map(move |&other| metric.difference(reference, other))
+            })
+            .collect();
+
+        Self::from_differences(&differences, reference.len(), other.len())
+    }
+
+    /// Match the colors of two palettes from already calculated differences,
+    /// where `differences[i * other_len + j]` is the difference between the
+    /// reference color `i` and the other color `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there aren't `reference_len * other_len` differences, or if
+    /// any of them is NaN or infinite.
+    pub fn from_differences(differences: &[T], reference_len: usize, other_len: usize) -> Self {
+        assert_eq!(
+            differences.len(),
+            reference_len * other_len,
+            "there must be a difference for each pair of colors"
+        );
+        assert!(
+            differences.iter().all(|difference| difference.is_finite()),
+            "the differences must be finite"
+        );
+
+        // The algorithm assigns each row to a column, so the smaller palette
+        // is used for the rows.
+        let transposed = reference_len > other_len;
+        let (rows, columns) = if transposed {
+            (other_len, reference_len)
+        } else {
+            (reference_len, other_len)
+        };
+        let cost = |row: usize, column: usize| {
+            if transposed {
+                differences[column * other_len + row]
+            } else {
+                differences[row * other_len + column]
+            }
+        };
+
+        let assignment = min_cost_assignment(rows, columns, cost);
+
+        let mut pairs: Vec<(usize, usize, T)> = assignment
+            .iter()
+            .enumerate()
+            .map(|(row, &column)| {
+                let difference = cost(row, column);
+                if transposed {
+                    (column, row, difference)
+                } else {
+                    (row, column, difference)
+                }
+            })
+            .collect();
+        pairs.sort_by_key(|&(reference, _, _)| reference);
+
+        let mut matched_reference = vec![false; reference_len];
+        let mut matched_other = vec![false; other_len];
+        for &(reference, other, _) in &pairs {
+            matched_reference[reference] = true;
+            matched_other[other] = true;
+        }
+        let unmatched = |matched: Vec<bool>| {
+            matched
+                .into_iter()
+                .enumerate()
+                .filter(|&(_, matched)| !matched)
+                .map(|(index, _)| index)
+                .collect()
+        };
+
+        PaletteMatch {
+            pairs,
+            unmatched_reference: unmatched(matched_reference),
+            unmatched_other: unmatched(matched_other),
+        }
+    }
+
+    /// The matched pairs, as the index in the reference palette, the index
+    /// in the other palette and their difference, in the order of the
+    /// reference palette.
+    pub fn pairs(&self) -> &[(usize, usize, T)] {
+        &self.pairs
+    }
+
+    /// The indices of the reference colors that have no match, because the
+    /// reference palette is larger.
+    pub fn unmatched_reference(&self) -> &[usize] {
+        &self.unmatched_reference
+    }
+
+    /// The indices of the other colors that have no match, because the other
+    /// palette is larger.
+    pub fn unmatched_other(&self) -> &[usize] {
+        &self.unmatched_other
+    }
+
+    /// The sum of the differences of the matched pairs.
+    pub fn total(&self) -> T {
+        self.pairs
+            .iter()
+            .fold(T::zero(), |sum, &(_, _, difference)| sum + difference)
+    }
+
+    /// The mean difference of the matched pairs, or `0.0` if nothing was
+    /// matched.
+    pub fn mean(&self) -> T {
+        if self.pairs.is_empty() {
+            return T::zero();
+        }
+
+        self.total() / from_f64(self.pairs.len() as f64)
+    }
+
+    /// The largest difference of the matched pairs, or `0.0` if nothing was
+    /// matched.
+    pub fn max(&self) -> T {
+        self.pairs
+            .iter()
+            .fold(T::zero(), |max, &(_, _, difference)| max.max(difference))
+    }
+
+    /// The similarity of the palettes, as the fraction of the colors in the
+    /// larger palette that are matched with a difference of at most
+    /// `tolerance`. It's `1.0` if the palettes are the same, or if both are
+    /// empty.
+    pub fn similarity(&self, tolerance: T) -> T {
+        let total = self.pairs.len() + self.unmatched_reference.len() + self.unmatched_other.len();
+        if total == 0 {
+            return T::one();
+        }
+
+        let within = self
+            .pairs
+            .iter()
+            .filter(|&&(_, _, difference)| difference <= tolerance)
+            .count();
+
+        from_f64::<T>(within as f64) / from_f64(total as f64)
+    }
+}
+
+/// Assign each of the `rows` to a different one of the `columns`, with the
+/// smallest total cost, and return the column of each row. There must not be
+/// more rows than columns.
+///
+/// This is the Hungarian algorithm with potentials, which takes time in
+/// proportion to `rows² * columns`.
+fn min_cost_assignment<T, F>(rows: usize, columns: usize, cost: F) -> Vec<usize>
+where
+    T: FloatComponent,
+    F: Fn(usize, usize) -> T,
+{
+    debug_assert!(rows <= columns);
+
+    // The potentials of the rows and columns, and the row that is assigned
+    // to each column. Index 0 is a placeholder, so rows and columns start at
+    // 1, and a row of 0 means that the column is free.
+    let mut row_potential = vec![T::zero(); rows + 1];
+    let mut column_potential = vec![T::zero(); columns + 1];
+    let mut column_row = vec![0; columns + 1];
+    let mut previous = vec![0; columns + 1];
+
+    for row in 1..=rows {
+        column_row[0] = row;
+        let mut column = 0;
+        let mut min_slack = vec![T::infinity(); columns + 1];
+        let mut used = vec![false; columns + 1];
+
+        // Grow a tree of alternating paths until it reaches a free column.
+        loop {
+            used[column] = true;
+            let current_row = column_row[column];
+            let mut delta = T::infinity();
+            let mut next_column = 0;
+
+            for candidate in 1..=columns {
+                if used[candidate] {
+                    continue;
+                }
+
+                let slack = cost(current_row - 1, candidate - 1)
+                    - row_potential[current_row]
+                    - column_potential[candidate];
+                if slack < min_slack[candidate] {
+                    min_slack[candidate] = slack;
+                    previous[candidate] = column;
+                }
+                if min_slack[candidate] < delta {
+                    delta = min_slack[candidate];
+                    next_column = candidate;
+                }
+            }
+
+            for candidate in 0..=columns {
+                if used[candidate] {
+                    row_potential[column_row[candidate]] =
+                        row_potential[column_row[candidate]] + delta;
+                    column_potential[candidate] = column_potential[candidate] - delta;
+                } else {
+                    min_slack[candidate] = min_slack[candidate] - delta;
+                }
+            }
+
+            column = next_column;
+            if column_row[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the assignments along the path back to the new row.
+        while column != 0 {
+            let previous_column = previous[column];
+            column_row[column] = column_row[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut assignment = vec![0; rows];
+    for (column, &row) in column_row.iter().enumerate().skip(1) {
+        if row != 0 {
+            assignment[row - 1] = column - 1;
+        }
+    }
+
+    assignment
+}
+
+/// The statistics of the same window in two single channel images, and the
+/// luminance, contrast and structure terms of SSIM.
+///
+/// The terms are the building blocks for SSIM and similar metrics, which
+/// compare local patterns rather than single pixels. The channel is picked
+/// by the caller, such as `luma` of a [`Luma`](../luma/struct.Luma.html)
+/// image or `l` of a [`Lab`](../struct.Lab.html) image, so the metric can
+/// work on linear or perceptual values as needed.
+///
+/// ```
+/// use palette::difference::WindowStatistics;
+/// use palette::SrgbLuma;
+///
+/// let reference: Vec<SrgbLuma> = (0..64).map(|i| SrgbLuma::new((i % 8) as f32 / 7.0)).collect();
+/// let noisy: Vec<SrgbLuma> = reference
+///     .iter()
+///     .enumerate()
+///     .map(|(i, color)| SrgbLuma::new(color.luma + if i % 2 == 0 { 0.02 } else { -0.02 }))
+///     .collect();
+///
+/// let windows = WindowStatistics::windows(&reference, &noisy, 8, 4, 4, |color| color.luma);
+/// assert_eq!(windows.len(), 4);
+///
+/// let ssim = windows.iter().map(|window| window.ssim(1.0)).sum::<f32>() / 4.0;
+/// assert!(ssim > 0.9 && ssim < 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowStatistics<T = f32> {
+    /// The mean of the reference window.
+    pub mean_reference: T,
+
+    /// The mean of the other window.
+    pub mean_other: T,
+
+    /// The variance of the reference window.
+    pub variance_reference: T,
+
+    /// The variance of the other window.
+    pub variance_other: T,
+
+    /// The covariance between the two windows.
+    pub covariance: T,
+}
+
+impl<T> WindowStatistics<T>
+where
+    T: FloatComponent,
+{
+    /// Calculate the statistics of two windows, as the channel values at the
+    /// same positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the windows are empty or have different lengths.
+    pub fn new(reference: &[T], other: &[T]) -> Self {
+        assert_eq!(
+            reference.len(),
+            other.len(),
+            "the windows must have the same number of pixels"
+        );
+        assert!(!reference.is_empty(), "the windows must not be empty");
+
+        let count: T = from_f64(reference.len() as f64);
+        let sum = |values: &[T]| values.iter().fold(T::zero(), |sum, &value| sum + value);
+        let mean_reference = sum(reference) / count;
+        let mean_other = sum(other) / count;
+
+        let mut statistics = WindowStatistics {
+            mean_reference,
+            mean_other,
+            variance_reference: T::zero(),
+            variance_other: T::zero(),
+            covariance: T::zero(),
+        };
+
+        for (&reference, &other) in reference.iter().zip(other) {
+            let reference = reference - mean_reference;
+            let other = other - mean_other;
+
+            statistics.variance_reference = statistics.variance_reference + reference * reference;
+            statistics.variance_other = statistics.variance_other + other * other;
+            statistics.covariance = statistics.covariance + reference * other;
+        }
+
+        statistics.variance_reference = statistics.variance_reference / count;
+        statistics.variance_other = statistics.variance_other / count;
+        statistics.covariance = statistics.covariance / count;
+
+        statistics
+    }
+
+    /// Calculate the statistics of `size` × `size` windows in two images
+    /// with `width` columns, moving the windows `step` pixels at a time. The
+    /// value of each pixel is picked with `channel`. The windows are listed
+    /// row by row, and windows that would go past the edge are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the images have different lengths, if `width`, `size` or
+    /// `step` is 0, or if the length of the images is not a multiple of
+    /// `width`.
+    pub fn windows<C, F>(
+        reference: &[C],
+        other: &[C],
+        width: usize,
+        size: usize,
+        step: usize,
+        mut channel: F,
+    ) -> Vec<Self>
+    where
+        F: FnMut(&C) -> T,
+    {
+        assert_eq!(
+            reference.len(),
+            other.len(),
+            "the images must have the same number of pixels"
+        );
+        assert!(width > 0 && size > 0 && step > 0);
+
+        let height = reference.len() / width;
+        assert!(
+            height * width == reference.len(),
+            "the image length must be a multiple of the width"
+        );
+
+        let reference: Vec<T> = reference.iter().map(&mut channel).collect();
+        let other: Vec<T> = other.iter().map(&mut channel).collect();
+
+        let mut windows = Vec::new();
+        let mut reference_window = Vec::with_capacity(size * size);
+        let mut other_window = Vec::with_capacity(size * size);
+
+        for top in (0..height.saturating_sub(size - 1)).step_by(step) {
+            for left in (0..width.saturating_sub(size - 1)).step_by(step) {
+                reference_window.clear();
+                other_window.clear();
+
+                for row in top..top + size {
+                    let range = row * width + left..row * width + left + size;
+                    reference_window.extend_from_slice(&reference[range.clone()]);
+                    other_window.extend_from_slice(&other[range]);
+                }
+
+                windows.push(Self::new(&reference_window, &other_window));
+            }
+        }
+
+        windows
+    }
+
+    /// The luminance term, `(2 μx μy + c1) / (μx² + μy² + c1)`, which
+    /// compares the means.
+    pub fn luminance(&self, c1: T) -> T {
+        let two: T = from_f64(2.0);
+        (two * self.mean_reference * self.mean_other + c1)
+            / (self.mean_reference * self.mean_reference + self.mean_other * self.mean_other + c1)
+    }
+
+    /// The contrast term, `(2 σx σy + c2) / (σx² + σy² + c2)`, which compares
+    /// the standard deviations.
+    pub fn contrast(&self, c2: T) -> T {
+        let two: T = from_f64(2.0);
+        (two * self.variance_reference.sqrt() * self.variance_other.sqrt() + c2)
+            / (self.variance_reference + self.variance_other + c2)
+    }
+
+    /// The structure term, `(σxy + c3) / (σx σy + c3)`, which is the
+    /// correlation between the windows.
+    pub fn structure(&self, c3: T) -> T {
+        (self.covariance + c3) / (self.variance_reference.sqrt() * self.variance_other.sqrt() + c3)
+    }
+
+    /// The SSIM index of the windows, with the constants from the original
+    /// paper, `c1 = (0.01 L)²`, `c2 = (0.03 L)²` and `c3 = c2 / 2`, where `L`
+    /// is the `dynamic_range` of the channel, such as `1.0` for `luma` or
+    /// `100.0` for `l` of `Lab`.
+    pub fn ssim(&self, dynamic_range: T) -> T {
+        let k1 = from_f64::<T>(0.01) * dynamic_range;
+        let k2 = from_f64::<T>(0.03) * dynamic_range;
+        let c2 = k2 * k2;
+
+        self.luminance(k1 * k1) * self.contrast(c2) * self.structure(c2 / from_f64(2.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DeltaE, DifferenceMap, PaletteMatch, WindowStatistics};
+    use crate::white_point::D65;
+    use crate::{Lab, Srgb, SrgbLuma};
+
+    #[test]
+    fn metrics() {
+        let a = Lab::<D65, f64>::new(50.0, 2.6772, -79.7751);
+        let b = Lab::new(50.0, 0.0, -82.7485);
+
+        assert_relative_eq!(DeltaE::Cie76.difference(a, b), 4.0011, epsilon = 1e-4);
+        assert_relative_eq!(DeltaE::Ciede2000.difference(a, b), 2.0425, epsilon = 1e-4);
+        assert_relative_eq!(DeltaE::Cie94.difference(a, b), 1.3950, epsilon = 1e-4);
+        assert!(DeltaE::Oklab.difference(a, b) > 0.0);
+
+        for &metric in &[
+            DeltaE::Cie76,
+            DeltaE::Cie94,
+            DeltaE::Ciede2000,
+            DeltaE::Oklab,
+        ] {
+            assert_eq!(metric.difference(a, a), 0.0);
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let map = DifferenceMap::from_values((1..=20).map(f64::from).collect());
+
+        assert_relative_eq!(map.mean(), 10.5);
+        assert_relative_eq!(map.max(), 20.0);
+        assert_relative_eq!(map.percentile_95(), 19.0);
+        assert_relative_eq!(map.percentile(50.0), 10.0);
+        assert_relative_eq!(map.percentile(0.0), 1.0);
+        assert_relative_eq!(map.percentile(100.0), 20.0);
+
+        let empty = DifferenceMap::<f64>::from_values(Vec::new());
+        assert_eq!(empty.mean(), 0.0);
+        assert_eq!(empty.percentile_95(), 0.0);
+    }
+
+    #[test]
+    fn images() {
+        let reference = vec![
+            Srgb::new(1.0f64, 1.0, 1.0),
+            Srgb::new(0.5, 0.5, 0.5),
+            Srgb::new(0.2, 0.4, 0.6),
+        ];
+        let other = vec![
+            Srgb::new(1.0, 1.0, 1.0),
+            Srgb::new(0.45, 0.5, 0.5),
+            Srgb::new(0.2, 0.4, 0.6),
+        ];
+
+        let map = DifferenceMap::new(&reference, &other, DeltaE::Cie76);
+        assert_eq!(map.values()[0], 0.0);
+        assert!(map.values()[1] > 1.0);
+        assert_eq!(map.values()[2], 0.0);
+        assert_relative_eq!(map.mean(), map.values()[1] / 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_sizes() {
+        let reference = vec![Srgb::new(1.0f64, 1.0, 1.0); 4];
+        let other = vec![Srgb::new(1.0f64, 1.0, 1.0); 3];
+        DifferenceMap::new(&reference, &other, DeltaE::Cie76);
+    }
+
+    #[test]
+    fn palette_match() {
+        // Matching each row with its cheapest column would pick column 0
+        // twice.
+        let differences = [1.0, 2.0, 9.0, 2.0, 9.0, 9.0, 9.0, 9.0, 3.0];
+        let matched = PaletteMatch::from_differences(&differences, 3, 3);
+        assert_eq!(matched.pairs(), &[(0, 1, 2.0), (1, 0, 2.0), (2, 2, 3.0)]);
+        assert_relative_eq!(matched.total(), 7.0);
+        assert_relative_eq!(matched.max(), 3.0);
+        assert_relative_eq!(matched.similarity(2.0), 2.0 / 3.0);
+
+        // The extra reference color is left out.
+        let differences = [5.0, 1.0, 2.0, 6.0, 7.0, 3.0];
+        let matched = PaletteMatch::from_differences(&differences, 3, 2);
+        assert_eq!(matched.pairs(), &[(0, 1, 1.0), (1, 0, 2.0)]);
+        assert_eq!(matched.unmatched_reference(), &[2]);
+        assert!(matched.unmatched_other().is_empty());
+        assert_relative_eq!(matched.mean(), 1.5);
+        assert_relative_eq!(matched.similarity(10.0), 2.0 / 3.0);
+
+        let empty = PaletteMatch::<f64>::from_differences(&[], 0, 2);
+        assert!(empty.pairs().is_empty());
+        assert_eq!(empty.unmatched_other(), &[0, 1]);
+        assert_eq!(empty.similarity(1.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn palette_match_nan() {
+        PaletteMatch::from_differences(&[1.0, f64::NAN, f64::NAN, f64::NAN], 2, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn palette_match_infinite() {
+        PaletteMatch::from_differences(&[1.0, f64::INFINITY], 1, 2);
+    }
+
+    #[test]
+    fn palettes() {
+        let palette = vec![
+            Srgb::new(1.0f64, 0.0, 0.0),
+            Srgb::new(0.0, 1.0, 0.0),
+            Srgb::new(0.0, 0.0, 1.0),
+        ];
+        let shuffled = vec![palette[2], palette[0], palette[1]];
+
+        let matched = PaletteMatch::new(&palette, &shuffled, DeltaE::Ciede2000);
+        assert_eq!(matched.pairs(), &[(0, 1, 0.0), (1, 2, 0.0), (2, 0, 0.0)]);
+        assert_eq!(matched.similarity(0.0), 1.0);
+    }
+
+    #[test]
+    fn window_statistics() {
+        let statistics = WindowStatistics::new(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 6.0, 8.0]);
+        assert_relative_eq!(statistics.mean_reference, 2.5);
+        assert_relative_eq!(statistics.mean_other, 5.0);
+        assert_relative_eq!(statistics.variance_reference, 1.25);
+        assert_relative_eq!(statistics.variance_other, 5.0);
+        assert_relative_eq!(statistics.covariance, 2.5);
+
+        // The structure is the same, and only the scale differs.
+        assert_relative_eq!(statistics.structure(0.0), 1.0);
+        assert_relative_eq!(statistics.contrast(0.0), 0.8);
+        assert_relative_eq!(statistics.luminance(0.0), 0.8);
+
+        let same = WindowStatistics::new(&[0.1, 0.5, 0.9], &[0.1, 0.5, 0.9]);
+        assert_relative_eq!(same.ssim(1.0), 1.0);
+    }
+
+    #[test]
+    fn windows() {
+        let reference: Vec<_> = (0..5 * 4)
+            .map(|i| SrgbLuma::new(f64::from(i) / 20.0))
+            .collect();
+        let inverted: Vec<_> = reference
+            .iter()
+            .map(|color| SrgbLuma::new(1.0 - color.luma))
+            .collect();
+
+        let windows = WindowStatistics::windows(&reference, &inverted, 5, 2, 1, |c| c.luma);
+        assert_eq!(windows.len(), 4 * 3);
+        assert_relative_eq!(windows[0].mean_reference, (0.0 + 1.0 + 5.0 + 6.0) / 80.0);
+
+        for window in &windows {
+            assert_relative_eq!(window.structure(0.0), -1.0, epsilon = 1e-12);
+        }
+
+        let windows = WindowStatistics::windows(&reference, &inverted, 5, 3, 2, |c| c.luma);
+        assert_eq!(windows.len(), 2);
+    }
+}
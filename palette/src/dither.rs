@@ -0,0 +1,314 @@
+//! Dithering, for showing colors with more precision than the output can
+//! represent.
+//!
+//! [`TemporalDither`](struct.TemporalDither.html) alternates between the two
+//! closest output levels from one frame to the next, so the average over a
+//! few frames is closer to the intended color. This is what display panels
+//! with 6 bits per channel do to show 8 bit colors, and it's also useful for
+//! LEDs driven with low resolution PWM.
+//!
+//! ```
+//! use palette::dither::TemporalDither;
+//! use palette::Srgb;
+//!
+//! // Show 8 bit colors on a 6 bit panel, with a cycle of 4 frames.
+//! let dither = TemporalDither::new(6, 4);
+//! let color = Srgb::new(0.5, 0.25, 0.8);
+//!
+//! for frame in 0..4 {
+//!     let output: Srgb<u16> = dither.dither_rgb(color, frame);
+//!     assert!(output.red <= 63);
+//! }
+//! ```
+//!
+//! # Flicker
+//!
+//! Switching between two levels makes a pixel flicker at the frame rate
+//! divided by the length of the cycle. The flicker is faint, since the levels
+//! are next to each other, but it becomes visible when it's slower than
+//! roughly 30 to 60 Hz. Keep the cycle short enough for the frame rate, such
+//! as at most 4 frames at 120 Hz or 2 frames at 60 Hz. The frames that get the
+//! higher level are spread as evenly as possible within the cycle, to keep
+//! the flicker as fast as it can be.
+//!
+//! Neighboring pixels that flicker in sync are easier to notice than pixels
+//! that flicker out of phase. Adding an offset to the frame number for each
+//! pixel, such as `frame + x + 2 * y`, spreads the flicker out over the
+//! image.
+//!
+//! # Ordered Dithering
+//!
+//! [`OrderedDither`](struct.OrderedDither.html) dithers in space instead of
+//! time, with a [`ThresholdMatrix`](struct.ThresholdMatrix.html) that is
+//! tiled over the image. The matrices can be Bayer matrices or blue noise,
+//! and they can also be used on their own, for example as halftone screens
+//! or in shaders. These are only available if the `std` feature is enabled
+//! (this is the default).
+//!
+//! # Blue Noise
+//!
+//! The `blue_noise` feature embeds a tile of blue noise, as
+//! [`BlueNoise`](struct.BlueNoise.html), for stochastic rounding and other
+//! operations that need noise. It can also be used as the threshold matrix
+//! of an ordered dither, or as per pixel frame offsets for temporal
+//! dithering, so they share the same noise.
+
+use crate::rgb::{Rgb, RgbStandard};
+use crate::tile::{for_each_tile_mut, TileSize};
+use crate::{clamp, from_f64, FloatComponent};
+
+#[cfg(feature = "blue_noise")]
+pub use self::blue_noise::BlueNoise;
+#[cfg(feature = "std")]
+pub use self::threshold::{OrderedDither, ThresholdMatrix};
+
+#[cfg(feature = "blue_noise")]
+mod blue_noise;
+#[cfg(feature = "std")]
+mod threshold;
+
+/// Temporal dithering to a lower bit depth.
+///
+/// See the [module documentation](index.html) for more details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TemporalDither {
+    bits: u32,
+    frames: u32,
+}
+
+impl TemporalDither {
+    /// Create a temporal dither for outputs with `bits` bits, and a cycle of
+    /// `frames` frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in `1..=16`, or if `frames` is `0`.
+    pub fn new(bits: u32, frames: u32) -> Self {
+        assert!(
+            (1..=16).contains(&bits),
+            "the number of bits must be between 1 and 16"
+        );
+        assert!(frames > 0, "the cycle must be at least one frame long");
+
+        TemporalDither { bits, frames }
+    }
+
+    /// The number of bits in the output.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The number of frames in a cycle.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// The largest output level.
+    pub fn max_level(&self) -> u16 {
+        ((1u32 << self.bits) - 1) as u16
+    }
+
+    /// Quantize `value`, from `[0.0, 1.0]`, to an output level for `frame`.
+    /// The average of the levels over a whole cycle is as close to `value`
+    /// as the cycle length allows.
+    ///
+    /// ```
+    /// use palette::dither::TemporalDither;
+    ///
+    /// // 2 bits have the levels 0, 1, 2 and 3. 0.5 is between 1 and 2.
+    /// let dither = TemporalDither::new(2, 2);
+    /// assert_eq!(dither.quantize(0.5, 0), 1);
+    /// assert_eq!(dither.quantize(0.5, 1), 2);
+    /// ```
+    pub fn quantize<T: FloatComponent>(&self, value: T, frame: u32) -> u16 {
+        let max_level: T = from_f64(f64::from(self.max_level()));
+        let scaled = clamp(value, T::zero(), T::one()) * max_level;
+        let base = scaled.floor();
+
+        // The number of frames in the cycle that should use the next level.
+        let frames: T = from_f64(f64::from(self.frames));
+        let raised = ((scaled - base) * frames)
+            .round()
+            .to_u32()
+            .unwrap_or(0)
+            .min(self.frames);
+
+        let base = base.to_u16().unwrap_or(0);
+        if raised == 0 || base == self.max_level() {
+            return base;
+        }
+
+        // Spread the raised frames evenly over the cycle, the same way as a
+        // line is drawn with Bresenham's algorithm.
+        let position = u64::from(frame % self.frames);
+        let frames = u64::from(self.frames);
+        let raised = u64::from(raised);
+        if (position + 1) * raised / frames > position * raised / frames {
+            base + 1
+        } else {
+            base
+        }
+    }
+
+    /// Quantize each component of `color` to an output level for `frame`.
+    pub fn dither_rgb<S, T>(&self, color: Rgb<S, T>, frame: u32) -> Rgb<S, u16>
+    where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        Rgb::new(
+            self.quantize(color.red, frame),
+            self.quantize(color.green, frame),
+            self.quantize(color.blue, frame),
+        )
+    }
+
+    /// Quantize each pixel in `input`, which is an image with rows of `width`
+    /// pixels, and write the result to `output`. The frame number of each
+    /// pixel is offset by `x + 2 * y`, to spread out the flicker, as
+    /// described in the [module documentation](index.html#flicker).
+    ///
+    /// Panics if `input` and `output` have different lengths, or if `width`
+    /// is `0`.
+    ///
+    /// ```
+    /// use palette::dither::TemporalDither;
+    /// use palette::Srgb;
+    ///
+    /// let dither = TemporalDither::new(1, 2);
+    /// let input = vec![Srgb::new(0.5, 0.5, 0.5); 4];
+    /// let mut output = vec![Srgb::new(0, 0, 0); 4];
+    /// dither.dither_rgb_image(&input, &mut output, 2, 0);
+    ///
+    /// // Neighbors are out of phase.
+    /// assert_eq!(output[0].red, 0);
+    /// assert_eq!(output[1].red, 1);
+    /// assert_eq!(output[2].red, 0);
+    /// ```
+    pub fn dither_rgb_image<S, T>(
+        &self,
+        input: &[Rgb<S, T>],
+        output: &mut [Rgb<S, u16>],
+        width: usize,
+        frame: u32,
+    ) where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        assert_eq!(
+            input.len(),
+            output.len(),
+            "the input and output buffers must have the same length"
+        );
+        assert!(width > 0, "the image must be at least one pixel wide");
+
+        let size = TileSize::for_type::<Rgb<S, T>>();
+        for_each_tile_mut(output, width, size, |tile, rows| {
+            for (y, row) in (tile.y..).zip(rows) {
+                let input = &input[y * width + tile.x..];
+                for ((x, output), &input) in (tile.x..).zip(row).zip(input) {
+                    *output = self.dither_rgb(input, frame.wrapping_add(pixel_offset(x, y)));
+                }
+            }
+        });
+
+        // A partial last row is not part of any tile.
+        let y = output.len() / width;
+        let start = y * width;
+        for (x, (output, &input)) in output[start..].iter_mut().zip(&input[start..]).enumerate() {
+            *output = self.dither_rgb(input, frame.wrapping_add(pixel_offset(x, y)));
+        }
+    }
+}
+
+/// The frame offset of the pixel at `(x, y)`.
+fn pixel_offset(x: usize, y: usize) -> u32 {
+    (x as u32).wrapping_add((y as u32).wrapping_mul(2))
+}
+
+#[cfg(test)]
+mod test {
+    use super::TemporalDither;
+    use crate::Srgb;
+
+    #[test]
+    fn cycle_average() {
+        let dither = TemporalDither::new(6, 4);
+
+        for &value in &[0.0f64, 0.1, 0.3, 0.5, 0.77, 0.99, 1.0] {
+            let sum: u32 = (0..4)
+                .map(|frame| u32::from(dither.quantize(value, frame)))
+                .sum();
+            let average = f64::from(sum) / 4.0 / 63.0;
+
+            // The error is at most half of a step between averages.
+            assert!(
+                (average - value).abs() <= 0.5 / 63.0 / 4.0 + 1e-12,
+                "{} was {}",
+                value,
+                average
+            );
+        }
+    }
+
+    #[test]
+    fn levels_are_neighbors() {
+        let dither = TemporalDither::new(4, 5);
+
+        for step in 0..=100 {
+            let value = f64::from(step) / 100.0;
+            let levels: Vec<u16> = (0..5).map(|frame| dither.quantize(value, frame)).collect();
+            let min = *levels.iter().min().unwrap();
+            let max = *levels.iter().max().unwrap();
+
+            assert!(max - min <= 1);
+            assert!(max <= 15);
+        }
+    }
+
+    #[test]
+    fn spread_evenly() {
+        // Half of the frames are raised, and they alternate.
+        let dither = TemporalDither::new(1, 4);
+        let levels: Vec<u16> = (0..8).map(|frame| dither.quantize(0.5f32, frame)).collect();
+        assert_eq!(levels, vec![0, 1, 0, 1, 0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn clamped() {
+        let dither = TemporalDither::new(8, 2);
+        assert_eq!(dither.quantize(-0.5f32, 0), 0);
+        assert_eq!(dither.quantize(1.5f32, 1), 255);
+        assert_eq!(dither.quantize(1.0f32, 1), 255);
+    }
+
+    #[test]
+    fn rgb() {
+        let dither = TemporalDither::new(8, 1);
+        let color = dither.dither_rgb(Srgb::new(1.0f32, 0.5, 0.0), 0);
+        assert_eq!(color, Srgb::new(255u16, 128, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_bits() {
+        TemporalDither::new(17, 2);
+    }
+
+    #[test]
+    fn image() {
+        let dither = TemporalDither::new(2, 3);
+        let width = 50;
+        let input: Vec<_> = (0..width * 40 + 7)
+            .map(|i| Srgb::new((i % 97) as f64 / 96.0, 0.5, (i % 13) as f64 / 12.0))
+            .collect();
+        let mut output = vec![Srgb::new(0, 0, 0); input.len()];
+        dither.dither_rgb_image(&input, &mut output, width, 5);
+
+        for (i, (&input, &output)) in input.iter().zip(&output).enumerate() {
+            let (x, y) = (i % width, i / width);
+            let frame = 5 + x as u32 + 2 * y as u32;
+            assert_eq!(output, dither.dither_rgb(input, frame));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{from_f64, FloatComponent};
+
+include!(concat!(env!("OUT_DIR"), "/blue_noise.rs"));
+
+/// The fractional part of the golden ratio, as a 64 bit fixed point number,
+/// for offsetting the noise from one frame to the next.
+const GOLDEN_FRACTION: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A tile of blue noise that is embedded in the library.
+///
+/// The tile is 64x64 pixels, and it's generated with the void and cluster
+/// method, like [`ThresholdMatrix::blue_noise`](struct.ThresholdMatrix.html#method.blue_noise).
+/// It's tiled over larger images without visible seams. Blue noise only has
+/// high frequencies, so the errors it hides end up as fine grain instead of
+/// the blotches that white noise from a random number generator gives.
+///
+/// The same noise can be shared between dithering, stochastic rounding and
+/// other random looking operations, without generating it first:
+///
+/// ```
+/// use palette::dither::{BlueNoise, TemporalDither};
+/// use palette::Srgb;
+///
+/// // A value between 0.0 and 1.0 for each pixel.
+/// let noise: f32 = BlueNoise::sample(10, 20);
+/// assert!(noise > 0.0 && noise < 1.0);
+///
+/// // Rounds up or down, with a chance that matches the fraction.
+/// let rounded = BlueNoise::stochastic_round(2.25f32, 10, 20, 0);
+/// assert!(rounded == 2.0 || rounded == 3.0);
+///
+/// // Spreads the flicker of temporal dithering with blue noise.
+/// let dither = TemporalDither::new(6, 4);
+/// let offset = u32::from(BlueNoise::rank(10, 20));
+/// let output: Srgb<u16> = dither.dither_rgb(Srgb::new(0.5, 0.25, 0.8), 3 + offset);
+/// ```
+///
+/// This is only available if the `blue_noise` feature is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlueNoise;
+
+impl BlueNoise {
+    /// The width and height of the tile.
+    pub const SIZE: usize = 64;
+
+    /// The rank, from `0` to `4095`, of the pixel at `(x, y)`. Each rank
+    /// appears once in the tile.
+    pub fn rank(x: usize, y: usize) -> u16 {
+        RANKS[(y % Self::SIZE) * Self::SIZE + x % Self::SIZE]
+    }
+
+    /// The noise at `(x, y)`, as a value in `(0.0, 1.0)`. The values of the
+    /// tile are evenly spaced, with the same distance to `0.0` and `1.0`.
+    pub fn sample<T: FloatComponent>(x: usize, y: usize) -> T {
+        from_f64(sample_f64(x, y))
+    }
+
+    /// The noise at `(x, y)` for the frame `frame`, as a value in
+    /// `(0.0, 1.0)`.
+    ///
+    /// Each frame is offset by the golden ratio, so every frame is blue noise
+    /// and the values of each pixel are spread evenly over time.
+    pub fn sample_frame<T: FloatComponent>(x: usize, y: usize, frame: u32) -> T {
+        let offset = u64::from(frame).wrapping_mul(GOLDEN_FRACTION) >> 11;
+        let mut value = sample_f64(x, y) + offset as f64 / (1u64 << 53) as f64;
+        if value >= 1.0 {
+            value -= 1.0;
+        }
+
+        from_f64(value)
+    }
+
+    /// Round `value` up or down to a whole number, for the pixel at `(x, y)`
+    /// in the frame `frame`. It's rounded up with a chance that is equal to
+    /// its fractional part, so the average of an area, or of a pixel over
+    /// time, stays close to `value`.
+    pub fn stochastic_round<T: FloatComponent>(value: T, x: usize, y: usize, frame: u32) -> T {
+        (value + Self::sample_frame(x, y, frame)).floor()
+    }
+}
+
+/// The noise at `(x, y)`.
+fn sample_f64(x: usize, y: usize) -> f64 {
+    let area = (BlueNoise::SIZE * BlueNoise::SIZE) as f64;
+    (f64::from(BlueNoise::rank(x, y)) + 0.5) / area
+}
+
+#[cfg(test)]
+mod test {
+    use super::BlueNoise;
+
+    #[test]
+    fn ranks() {
+        let mut ranks: Vec<u16> = (0..64 * 64)
+            .map(|i| BlueNoise::rank(i % 64, i / 64))
+            .collect();
+        ranks.sort();
+        assert!(ranks.into_iter().eq(0..4096));
+
+        assert_eq!(BlueNoise::rank(70, 130), BlueNoise::rank(6, 2));
+    }
+
+    #[test]
+    fn frames() {
+        assert_eq!(
+            BlueNoise::sample_frame::<f64>(3, 4, 0),
+            BlueNoise::sample::<f64>(3, 4)
+        );
+
+        // The values of a pixel are spread over time.
+        let mut values: Vec<f64> = (0..16)
+            .map(|frame| BlueNoise::sample_frame(3, 4, frame))
+            .collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for pair in values.windows(2) {
+            assert!(pair[1] - pair[0] < 0.2);
+        }
+    }
+
+    #[test]
+    fn stochastic_round() {
+        let sum: f64 = (0..64 * 64)
+            .map(|i| BlueNoise::stochastic_round(1.3, i % 64, i / 64, 0))
+            .sum();
+        assert_relative_eq!(sum / 4096.0, 1.3, epsilon = 1e-3);
+
+        assert_eq!(BlueNoise::stochastic_round(2.0f32, 5, 5, 7), 2.0);
+    }
+}
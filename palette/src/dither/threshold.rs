@@ -0,0 +1,463 @@
+#[cfg(feature = "blue_noise")]
+use super::BlueNoise;
+use crate::math::exp;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{clamp, from_f64, FloatComponent};
+
+/// The standard deviation, in pixels, of the filter that measures how
+/// clustered the pixels of a blue noise pattern are.
+const BLUE_NOISE_SIGMA: f64 = 1.5;
+
+/// The seed of the xorshift sequence for the initial blue noise pattern.
+const BLUE_NOISE_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// A square matrix of thresholds for ordered dithering and halftoning.
+///
+/// Each cell has a unique rank, from `0` to `size² - 1`, and the matrix is
+/// tiled over the image. A pixel is raised to the next output level if its
+/// remaining fraction is above the threshold of its cell, so the order of the
+/// ranks decides the pattern that each shade gets.
+///
+/// ```
+/// use palette::dither::ThresholdMatrix;
+///
+/// let bayer = ThresholdMatrix::bayer(2);
+/// assert_eq!(bayer.ranks(), &[0, 2, 3, 1]);
+/// assert_eq!(bayer.threshold::<f32>(1, 1), 0.375);
+///
+/// // The matrix repeats.
+/// assert_eq!(bayer.rank(3, 2), bayer.rank(1, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdMatrix {
+    size: usize,
+    ranks: Vec<u32>,
+}
+
+impl ThresholdMatrix {
+    /// Create a Bayer matrix, where each shade is made of regular, cross
+    /// hatched patterns.
+    ///
+    /// Bayer matrices are fast to generate and give a very even result, but
+    /// the patterns are easy to see. Larger matrices give more distinct
+    /// shades.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two.
+    pub fn bayer(size: usize) -> Self {
+        assert!(
+            size.is_power_of_two(),
+            "the size of a Bayer matrix must be a power of two"
+        );
+
+        // Each step makes the matrix twice as large, by repeating it in the
+        // four quadrants, in the order of the 2x2 matrix.
+        let mut ranks = vec![0];
+        let mut current = 1;
+        while current < size {
+            let next = current * 2;
+            let mut larger = vec![0; next * next];
+            for y in 0..next {
+                for x in 0..next {
+                    let quadrant = match (x >= current, y >= current) {
+                        (false, false) => 0,
+                        (true, false) => 2,
+                        (false, true) => 3,
+                        (true, true) => 1,
+                    };
+                    larger[y * next + x] =
+                        4 * ranks[(y % current) * current + x % current] + quadrant;
+                }
+            }
+
+            ranks = larger;
+            current = next;
+        }
+
+        ThresholdMatrix { size, ranks }
+    }
+
+    /// Create a blue noise matrix with the void and cluster method, where
+    /// each shade is an even but irregular scattering of pixels.
+    ///
+    /// Blue noise has no visible patterns, and the scattering is still even
+    /// when the matrix is tiled, so it's usually the better looking choice.
+    /// The generation takes time in proportion to `size⁴`, so larger matrices
+    /// should be generated once and kept. The result is always the same for
+    /// the same `size`.
+    ///
+    /// ```
+    /// use palette::dither::ThresholdMatrix;
+    ///
+    /// let noise = ThresholdMatrix::blue_noise(16);
+    ///
+    /// let mut ranks = noise.ranks().to_vec();
+    /// ranks.sort();
+    /// assert!(ranks.into_iter().eq(0..256));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    pub fn blue_noise(size: usize) -> Self {
+        assert!(size > 0, "the matrix must be at least one cell wide");
+
+        let area = size * size;
+        let mut pattern = Pattern::new(size);
+
+        // Start with a tenth of the pixels, scattered with a fixed
+        // pseudorandom sequence, and move pixels from the tightest clusters
+        // to the largest voids until it's even. Starting from a regular
+        // pattern would make the result regular too.
+        let initial = (area / 10).max(1);
+        let mut state = BLUE_NOISE_SEED;
+        let mut placed = 0;
+        while placed < initial {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let index = (state % area as u64) as usize;
+            if !pattern.set[index] {
+                pattern.toggle(index);
+                placed += 1;
+            }
+        }
+
+        for _ in 0..area {
+            let cluster = pattern.tightest_cluster();
+            pattern.toggle(cluster);
+            let void = pattern.largest_void();
+            pattern.toggle(void);
+
+            if void == cluster {
+                break;
+            }
+        }
+
+        // The initial pixels are ranked by removing them from the tightest
+        // clusters, and the rest by filling the largest voids.
+        let mut ranks = vec![0; area];
+
+        let mut removed = pattern.clone();
+        for rank in (0..initial).rev() {
+            let cluster = removed.tightest_cluster();
+            removed.toggle(cluster);
+            ranks[cluster] = rank as u32;
+        }
+
+        for rank in initial..area {
+            let void = pattern.largest_void();
+            pattern.toggle(void);
+            ranks[void] = rank as u32;
+        }
+
+        ThresholdMatrix { size, ranks }
+    }
+
+    /// Create a 64x64 matrix from the embedded [`BlueNoise`](struct.BlueNoise.html)
+    /// tile, without generating it.
+    ///
+    /// This is only available if the `blue_noise` feature is enabled.
+    #[cfg(feature = "blue_noise")]
+    pub fn embedded_blue_noise() -> Self {
+        let size = BlueNoise::SIZE;
+        let ranks = (0..size * size)
+            .map(|index| u32::from(BlueNoise::rank(index % size, index / size)))
+            .collect();
+
+        ThresholdMatrix { size, ranks }
+    }
+
+    /// The number of cells along each side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The ranks of the cells, row by row.
+    pub fn ranks(&self) -> &[u32] {
+        &self.ranks
+    }
+
+    /// The rank of the cell at `(x, y)`, with the matrix tiled over the
+    /// image.
+    pub fn rank(&self, x: usize, y: usize) -> u32 {
+        self.ranks[(y % self.size) * self.size + x % self.size]
+    }
+
+    /// The threshold of the cell at `(x, y)`, with the matrix tiled over the
+    /// image. The thresholds are evenly spaced in `(0.0, 1.0)`, with the
+    /// same distance to `0.0` and `1.0`.
+    pub fn threshold<T: FloatComponent>(&self, x: usize, y: usize) -> T {
+        let area = (self.size * self.size) as f64;
+        from_f64((f64::from(self.rank(x, y)) + 0.5) / area)
+    }
+
+    /// The thresholds of all cells, row by row.
+    pub fn thresholds<T: FloatComponent>(&self) -> Vec<T> {
+        let area = (self.size * self.size) as f64;
+        self.ranks
+            .iter()
+            .map(|&rank| from_f64((f64::from(rank) + 0.5) / area))
+            .collect()
+    }
+}
+
+/// A binary pattern, and how close each pixel is to the set pixels, for the
+/// void and cluster method.
+#[derive(Clone)]
+struct Pattern {
+    size: usize,
+    kernel: Vec<f64>,
+    set: Vec<bool>,
+    energy: Vec<f64>,
+}
+
+impl Pattern {
+    fn new(size: usize) -> Self {
+        let area = size * size;
+        let distance = |offset: usize| offset.min(size - offset) as f64;
+
+        let mut kernel = Vec::with_capacity(area);
+        for dy in 0..size {
+            for dx in 0..size {
+                let squared = distance(dx).powi(2) + distance(dy).powi(2);
+                kernel.push(exp(-squared / (2.0 * BLUE_NOISE_SIGMA * BLUE_NOISE_SIGMA)));
+            }
+        }
+
+        Pattern {
+            size,
+            kernel,
+            set: vec![false; area],
+            energy: vec![0.0; area],
+        }
+    }
+
+    /// Set or clear the pixel at `index`.
+    fn toggle(&mut self, index: usize) {
+        let size = self.size;
+        let (px, py) = (index % size, index / size);
+        let sign = if self.set[index] { -1.0 } else { 1.0 };
+        self.set[index] = !self.set[index];
+
+        for (i, energy) in self.energy.iter_mut().enumerate() {
+            let dx = (i % size + size - px) % size;
+            let dy = (i / size + size - py) % size;
+            *energy += sign * self.kernel[dy * size + dx];
+        }
+    }
+
+    /// The set pixel with the most set pixels around it.
+    fn tightest_cluster(&self) -> usize {
+        self.find(true, |energy, best| energy > best)
+    }
+
+    /// The clear pixel with the fewest set pixels around it.
+    fn largest_void(&self) -> usize {
+        self.find(false, |energy, best| energy < best)
+    }
+
+    /// The first pixel that is `set` and has the best energy.
+    fn find(&self, set: bool, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best: Option<(usize, f64)> = None;
+        for (index, (&is_set, &energy)) in self.set.iter().zip(&self.energy).enumerate() {
+            if is_set != set {
+                continue;
+            }
+
+            match best {
+                Some((_, best_energy)) if !better(energy, best_energy) => {}
+                _ => best = Some((index, energy)),
+            }
+        }
+
+        best.map(|(index, _)| index)
+            .expect("the pattern should not be all set or all clear")
+    }
+}
+
+/// Ordered dithering to a lower bit depth, with a threshold matrix.
+///
+/// Each pixel is rounded up or down depending on the threshold of its cell,
+/// so an area with the same color averages out to that color. Unlike error
+/// diffusion, each pixel is independent of the others, and the result
+/// doesn't change from one frame to the next.
+///
+/// ```
+/// use palette::dither::{OrderedDither, ThresholdMatrix};
+/// use palette::Srgb;
+///
+/// let dither = OrderedDither::new(1, ThresholdMatrix::bayer(2));
+/// let gray = Srgb::new(0.5f32, 0.5, 0.5);
+///
+/// // Half of the cells are raised, in a checkerboard pattern.
+/// assert_eq!(dither.dither_rgb(gray, 0, 0), Srgb::new(1, 1, 1));
+/// assert_eq!(dither.dither_rgb(gray, 1, 0), Srgb::new(0, 0, 0));
+/// assert_eq!(dither.dither_rgb(gray, 1, 1), Srgb::new(1, 1, 1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderedDither {
+    bits: u32,
+    matrix: ThresholdMatrix,
+}
+
+impl OrderedDither {
+    /// Create an ordered dither for outputs with `bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in `1..=16`.
+    pub fn new(bits: u32, matrix: ThresholdMatrix) -> Self {
+        assert!(
+            (1..=16).contains(&bits),
+            "the number of bits must be between 1 and 16"
+        );
+
+        OrderedDither { bits, matrix }
+    }
+
+    /// The number of bits in the output.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The threshold matrix.
+    pub fn matrix(&self) -> &ThresholdMatrix {
+        &self.matrix
+    }
+
+    /// The largest output level.
+    pub fn max_level(&self) -> u16 {
+        ((1u32 << self.bits) - 1) as u16
+    }
+
+    /// Quantize `value`, from `[0.0, 1.0]`, to an output level for the pixel
+    /// at `(x, y)`.
+    pub fn quantize<T: FloatComponent>(&self, value: T, x: usize, y: usize) -> u16 {
+        let max_level: T = from_f64(f64::from(self.max_level()));
+        let scaled = clamp(value, T::zero(), T::one()) * max_level;
+        let base = scaled.floor();
+
+        let level = base.to_u16().unwrap_or(0);
+        if scaled - base > self.matrix.threshold(x, y) {
+            level + 1
+        } else {
+            level
+        }
+    }
+
+    /// Quantize each component of `color` to an output level for the pixel
+    /// at `(x, y)`.
+    pub fn dither_rgb<S, T>(&self, color: Rgb<S, T>, x: usize, y: usize) -> Rgb<S, u16>
+    where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        Rgb::new(
+            self.quantize(color.red, x, y),
+            self.quantize(color.green, x, y),
+            self.quantize(color.blue, x, y),
+        )
+    }
+
+    /// Quantize each pixel in `input`, which is an image with rows of `width`
+    /// pixels, and write the result to `output`.
+    ///
+    /// Panics if `input` and `output` have different lengths, or if `width`
+    /// is `0`.
+    pub fn dither_rgb_image<S, T>(
+        &self,
+        input: &[Rgb<S, T>],
+        output: &mut [Rgb<S, u16>],
+        width: usize,
+    ) where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        assert_eq!(
+            input.len(),
+            output.len(),
+            "the input and output buffers must have the same length"
+        );
+        assert!(width > 0, "the image must be at least one pixel wide");
+
+        for (i, (output, &input)) in output.iter_mut().zip(input).enumerate() {
+            *output = self.dither_rgb(input, i % width, i / width);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{OrderedDither, ThresholdMatrix};
+
+    #[test]
+    fn bayer() {
+        assert_eq!(ThresholdMatrix::bayer(1).ranks(), &[0]);
+        assert_eq!(
+            ThresholdMatrix::bayer(4).ranks(),
+            &[0, 8, 2, 10, 12, 4, 14, 6, 3, 11, 1, 9, 15, 7, 13, 5]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn bayer_not_power_of_two() {
+        ThresholdMatrix::bayer(6);
+    }
+
+    #[test]
+    fn blue_noise() {
+        let noise = ThresholdMatrix::blue_noise(16);
+        assert_eq!(noise, ThresholdMatrix::blue_noise(16));
+
+        let mut ranks = noise.ranks().to_vec();
+        ranks.sort();
+        assert!(ranks.into_iter().eq(0..256));
+
+        // The first cells are spread out, even across the edges.
+        let first: Vec<_> = (0..256).filter(|&i| noise.ranks()[i] < 16).collect();
+        for &a in &first {
+            for &b in &first {
+                let distance = |a: usize, b: usize| {
+                    let d = a.max(b) - a.min(b);
+                    d.min(16 - d)
+                };
+                let (dx, dy) = (distance(a % 16, b % 16), distance(a / 16, b / 16));
+                assert!(a == b || dx * dx + dy * dy >= 4, "{} and {}", a, b);
+            }
+        }
+    }
+
+    #[cfg(feature = "blue_noise")]
+    #[test]
+    fn embedded_blue_noise() {
+        use crate::dither::BlueNoise;
+
+        let noise = ThresholdMatrix::embedded_blue_noise();
+        assert_eq!(noise.size(), 64);
+        assert_eq!(
+            noise.threshold::<f64>(70, 3),
+            BlueNoise::sample::<f64>(6, 3)
+        );
+    }
+
+    #[test]
+    fn average() {
+        for matrix in [ThresholdMatrix::bayer(8), ThresholdMatrix::blue_noise(8)] {
+            let dither = OrderedDither::new(2, matrix);
+
+            for step in 0..=64 {
+                let value = f64::from(step) / 64.0;
+                let sum: u32 = (0..64)
+                    .map(|i| u32::from(dither.quantize(value, i % 8, i / 8)))
+                    .sum();
+                let average = f64::from(sum) / 64.0 / 3.0;
+
+                assert!((average - value).abs() <= 0.5 / 64.0 / 3.0 + 1e-12);
+            }
+        }
+    }
+}
@@ -1,13 +1,18 @@
 //! Various encoding traits, types and standards.
 
+use crate::alpha::Alpha;
 use crate::float::Float;
-use crate::FromF64;
+use crate::luma::{Luma, LumaStandard};
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{Component, FromF64};
 
 pub use self::gamma::{F2p2, Gamma};
 pub use self::linear::Linear;
 pub use self::srgb::Srgb;
 
 pub mod gamma;
+pub mod lab;
+pub mod lch;
 pub mod linear;
 pub mod pixel;
 pub mod srgb;
@@ -20,3 +25,151 @@ pub trait TransferFn: 'static {
     /// Convert the color component `x` into linear space.
     fn into_linear<T: Float + FromF64>(x: T) -> T;
 }
+
+/// A marker for transfer functions that leave the components in linear space.
+///
+/// This is implemented for [`LinearFn`](linear/struct.LinearFn.html), and
+/// makes it possible to require linear input in generic code without naming
+/// a specific standard. See [`IsLinear`](trait.IsLinear.html) for the
+/// corresponding color level marker.
+pub trait LinearTransferFn: TransferFn {}
+
+/// A marker for transfer functions that encode the components in a nonlinear
+/// way, such as sRGB or gamma encoding.
+pub trait NonLinearTransferFn: TransferFn {}
+
+/// A marker for colors with linear components.
+///
+/// Operations like blending, mixing and averaging are only physically
+/// meaningful for linear colors. This trait can be used as a bound to
+/// statically reject gamma encoded input, which is an easy mistake to make
+/// since `Srgb` and `LinSrgb` have the same shape:
+///
+/// ```
+/// use palette::encoding::IsLinear;
+/// use palette::{LinSrgb, Mix};
+///
+/// fn average<C: IsLinear + Mix<Scalar = f32>>(a: C, b: C) -> C {
+///     a.mix(&b, 0.5)
+/// }
+///
+/// let gray = average(LinSrgb::new(0.0, 0.0, 0.0), LinSrgb::new(1.0, 1.0, 1.0));
+/// assert_eq!(gray, LinSrgb::new(0.5, 0.5, 0.5));
+/// ```
+///
+/// Passing an encoded color to the same function fails to compile:
+///
+/// ```compile_fail
+/// use palette::encoding::IsLinear;
+/// use palette::{Srgb, Mix};
+///
+/// fn average<C: IsLinear + Mix<Scalar = f32>>(a: C, b: C) -> C {
+///     a.mix(&b, 0.5)
+/// }
+///
+/// average(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+/// ```
+///
+/// It's implemented for RGB and luma colors with a
+/// [`LinearTransferFn`](trait.LinearTransferFn.html), and for their
+/// transparent versions.
+pub trait IsLinear {}
+
+/// A marker for colors with nonlinearly encoded components.
+///
+/// This is the counterpart of [`IsLinear`](trait.IsLinear.html), and can be
+/// used to require colors that are ready to be stored or displayed. It's
+/// implemented for RGB and luma colors with a
+/// [`NonLinearTransferFn`](trait.NonLinearTransferFn.html), and for their
+/// transparent versions.
+pub trait IsEncoded {}
+
+impl<S, T> IsLinear for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component,
+    S::TransferFn: LinearTransferFn,
+{
+}
+
+impl<S, T> IsLinear for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component,
+    S::TransferFn: LinearTransferFn,
+{
+}
+
+impl<C: IsLinear, T> IsLinear for Alpha<C, T> {}
+
+impl<S, T> IsEncoded for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component,
+    S::TransferFn: NonLinearTransferFn,
+{
+}
+
+impl<S, T> IsEncoded for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component,
+    S::TransferFn: NonLinearTransferFn,
+{
+}
+
+impl<C: IsEncoded, T> IsEncoded for Alpha<C, T> {}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn assert_linear<C: IsLinear + ?Sized>(_color: &C) {}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn assert_encoded<C: IsEncoded + ?Sized>(_color: &C) {}
+
+/// Assert that one or more colors have linear components.
+///
+/// The check is done by the type system, using the
+/// [`IsLinear`](encoding/trait.IsLinear.html) trait, so it has no runtime
+/// cost and the expressions are only borrowed. It's meant as a lint-like
+/// guard at the start of functions that would give wrong results for gamma
+/// encoded input, but can't use a trait bound.
+///
+/// ```
+/// use palette::{debug_assert_linear, LinSrgb, LinSrgba};
+///
+/// let a = LinSrgb::new(0.2, 0.4, 0.6);
+/// let b = LinSrgba::new(0.2, 0.4, 0.6, 0.5);
+/// debug_assert_linear!(a, b);
+/// ```
+///
+/// ```compile_fail
+/// use palette::{debug_assert_linear, Srgb};
+///
+/// debug_assert_linear!(Srgb::new(0.2, 0.4, 0.6));
+/// ```
+#[macro_export]
+macro_rules! debug_assert_linear {
+    ($($color:expr),+ $(,)?) => {
+        $($crate::encoding::assert_linear(&$color);)+
+    };
+}
+
+/// Assert that one or more colors have nonlinearly encoded components.
+///
+/// This is the counterpart of
+/// [`debug_assert_linear`](macro.debug_assert_linear.html), using the
+/// [`IsEncoded`](encoding/trait.IsEncoded.html) trait.
+///
+/// ```
+/// use palette::{debug_assert_encoded, Srgb};
+///
+/// debug_assert_encoded!(Srgb::new(0.2, 0.4, 0.6));
+/// ```
+#[macro_export]
+macro_rules! debug_assert_encoded {
+    ($($color:expr),+ $(,)?) => {
+        $($crate::encoding::assert_encoded(&$color);)+
+    };
+}
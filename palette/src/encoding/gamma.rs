@@ -2,9 +2,10 @@
 
 use core::marker::PhantomData;
 
-use crate::encoding::TransferFn;
+use crate::encoding::{NonLinearTransferFn, TransferFn};
 use crate::float::Float;
 use crate::luma::LumaStandard;
+use crate::math::powf;
 use crate::rgb::{RgbSpace, RgbStandard};
 use crate::white_point::WhitePoint;
 use crate::{from_f64, FromF64};
@@ -42,14 +43,16 @@ pub struct GammaFn<N: Number = F2p2>(PhantomData<N>);
 
 impl<N: Number> TransferFn for GammaFn<N> {
     fn into_linear<T: Float + FromF64>(x: T) -> T {
-        x.powf(T::one() / from_f64(N::VALUE))
+        powf(x, T::one() / from_f64(N::VALUE))
     }
 
     fn from_linear<T: Float + FromF64>(x: T) -> T {
-        x.powf(from_f64(N::VALUE))
+        powf(x, from_f64(N::VALUE))
     }
 }
 
+impl<N: Number> NonLinearTransferFn for GammaFn<N> {}
+
 /// A type level float constant.
 pub trait Number: 'static {
     /// The represented number.
@@ -0,0 +1,286 @@
+//! Integer encodings of L\*a\*b\* colors.
+//!
+//! Image files and ICC profiles store L\*a\*b\* colors as 8 or 16 bit
+//! integers, with different offsets and scaling depending on the format. The
+//! types in this module describe those encodings, and are used with
+//! [`Lab::from_format`](../../struct.Lab.html#method.from_format) and
+//! [`Lab::into_format`](../../struct.Lab.html#method.into_format), like the
+//! component types of `Rgb::into_format`:
+//!
+//! ```
+//! use palette::encoding::lab::{IccLab8, TiffCieLab8};
+//! use palette::Lab;
+//!
+//! // A pixel from a TIFF file with the CIELab photometric interpretation.
+//! let lab: Lab = Lab::from_format::<TiffCieLab8>([128, 20, 236]);
+//! assert_eq!(lab.a, 20.0);
+//! assert_eq!(lab.b, -20.0);
+//!
+//! // The same color with the ICC encoding.
+//! assert_eq!(lab.into_format::<IccLab8>(), [128, 148, 108]);
+//! ```
+//!
+//! Encoding rounds to the nearest integer and clamps the values to what the
+//! encoding can represent. L\*a\*b\* colors with other white points than D50
+//! are encoded as they are, so any chromatic adaptation has to be done first.
+
+use crate::{from_f64, FloatComponent};
+
+/// An integer encoding of L\*a\*b\* colors.
+pub trait LabEncoding {
+    /// The integer type of the encoded components.
+    type Component: Copy;
+
+    /// Encode the L\*, a\* and b\* values.
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [Self::Component; 3];
+
+    /// Decode L\*, a\* and b\* values.
+    fn decode<T: FloatComponent>(encoded: [Self::Component; 3]) -> [T; 3];
+}
+
+/// The 8 bit encoding from ICC profiles, which is also used by the ICCLab
+/// photometric interpretation in TIFF.
+///
+/// L\* is scaled from `0.0..=100.0` to `0..=255`, and a\* and b\* are offset
+/// by `128`, so `-128.0..=127.0` becomes `0..=255`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IccLab8;
+
+impl LabEncoding for IccLab8 {
+    type Component = u8;
+
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [u8; 3] {
+        let offset = from_f64::<T>(128.0);
+        [
+            encode_u8(l * from_f64(2.55)),
+            encode_u8(a + offset),
+            encode_u8(b + offset),
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, a, b]: [u8; 3]) -> [T; 3] {
+        [
+            from_f64(f64::from(l) / 2.55),
+            from_f64(f64::from(a) - 128.0),
+            from_f64(f64::from(b) - 128.0),
+        ]
+    }
+}
+
+/// The 16 bit encoding from version 4 ICC profiles.
+///
+/// L\* is scaled from `0.0..=100.0` to `0..=65535`, and a\* and b\* are
+/// offset by `128` and scaled from `0.0..=255.0` to `0..=65535`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IccLab16;
+
+impl LabEncoding for IccLab16 {
+    type Component = u16;
+
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [u16; 3] {
+        let offset = from_f64::<T>(128.0);
+        let ab_scale = from_f64::<T>(65535.0 / 255.0);
+        [
+            encode_u16(l * from_f64(655.35)),
+            encode_u16((a + offset) * ab_scale),
+            encode_u16((b + offset) * ab_scale),
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, a, b]: [u16; 3]) -> [T; 3] {
+        let ab_scale = 255.0 / 65535.0;
+        [
+            from_f64(f64::from(l) / 655.35),
+            from_f64(f64::from(a) * ab_scale - 128.0),
+            from_f64(f64::from(b) * ab_scale - 128.0),
+        ]
+    }
+}
+
+/// The legacy 16 bit encoding from version 2 ICC profiles, which is also
+/// used by the ICCLab photometric interpretation in TIFF.
+///
+/// L\* is scaled from `0.0..=100.0` to `0..=65280` (`0xff00`), and a\* and
+/// b\* are offset by `128` and multiplied by `256`, so `0.0` becomes `32768`
+/// (`0x8000`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IccLab16Legacy;
+
+impl LabEncoding for IccLab16Legacy {
+    type Component = u16;
+
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [u16; 3] {
+        let offset = from_f64::<T>(128.0);
+        let ab_scale = from_f64::<T>(256.0);
+        [
+            encode_u16(l * from_f64(652.8)),
+            encode_u16((a + offset) * ab_scale),
+            encode_u16((b + offset) * ab_scale),
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, a, b]: [u16; 3]) -> [T; 3] {
+        [
+            from_f64(f64::from(l) / 652.8),
+            from_f64(f64::from(a) / 256.0 - 128.0),
+            from_f64(f64::from(b) / 256.0 - 128.0),
+        ]
+    }
+}
+
+/// The 8 bit encoding from the CIELab photometric interpretation in TIFF.
+///
+/// L\* is scaled from `0.0..=100.0` to `0..=255`, and a\* and b\* are stored
+/// as signed two's complement integers, so `-128.0..=127.0` becomes
+/// `128..=255` followed by `0..=127`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TiffCieLab8;
+
+impl LabEncoding for TiffCieLab8 {
+    type Component = u8;
+
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [u8; 3] {
+        let offset = from_f64::<T>(128.0);
+        [
+            encode_u8(l * from_f64(2.55)),
+            encode_u8(a + offset) ^ 0x80,
+            encode_u8(b + offset) ^ 0x80,
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, a, b]: [u8; 3]) -> [T; 3] {
+        [
+            from_f64(f64::from(l) / 2.55),
+            from_f64(f64::from(a as i8)),
+            from_f64(f64::from(b as i8)),
+        ]
+    }
+}
+
+/// The 16 bit encoding from the CIELab photometric interpretation in TIFF.
+///
+/// L\* is scaled from `0.0..=100.0` to `0..=65535`, and a\* and b\* are
+/// multiplied by `256` and stored as signed two's complement integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TiffCieLab16;
+
+impl LabEncoding for TiffCieLab16 {
+    type Component = u16;
+
+    fn encode<T: FloatComponent>(l: T, a: T, b: T) -> [u16; 3] {
+        let offset = from_f64::<T>(128.0);
+        let ab_scale = from_f64::<T>(256.0);
+        [
+            encode_u16(l * from_f64(655.35)),
+            encode_u16((a + offset) * ab_scale) ^ 0x8000,
+            encode_u16((b + offset) * ab_scale) ^ 0x8000,
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, a, b]: [u16; 3]) -> [T; 3] {
+        [
+            from_f64(f64::from(l) / 655.35),
+            from_f64(f64::from(a as i16) / 256.0),
+            from_f64(f64::from(b as i16) / 256.0),
+        ]
+    }
+}
+
+fn encode_u8<T: FloatComponent>(value: T) -> u8 {
+    value
+        .max(T::zero())
+        .min(from_f64(255.0))
+        .round()
+        .to_u8()
+        .unwrap_or(0)
+}
+
+fn encode_u16<T: FloatComponent>(value: T) -> u16 {
+    value
+        .max(T::zero())
+        .min(from_f64(65535.0))
+        .round()
+        .to_u16()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IccLab16, IccLab16Legacy, IccLab8, LabEncoding, TiffCieLab16, TiffCieLab8};
+
+    fn decode<E: LabEncoding>(encoded: [E::Component; 3]) -> [f64; 3] {
+        E::decode(encoded)
+    }
+
+    #[test]
+    fn icc_lab_8() {
+        assert_eq!(IccLab8::encode(100.0, 0.0, 0.0), [255, 128, 128]);
+        assert_eq!(IccLab8::encode(0.0, -128.0, 127.0), [0, 0, 255]);
+        assert_eq!(IccLab8::encode(150.0, -200.0, 200.0), [255, 0, 255]);
+        assert_eq!(decode::<IccLab8>([255, 0, 255]), [100.0, -128.0, 127.0]);
+    }
+
+    #[test]
+    fn icc_lab_16() {
+        assert_eq!(IccLab16::encode(100.0, 0.0, 0.0), [65535, 32896, 32896]);
+        assert_eq!(IccLab16::encode(0.0, -128.0, 127.0), [0, 0, 65535]);
+        assert_eq!(
+            decode::<IccLab16>([65535, 0, 65535]),
+            [100.0, -128.0, 127.0]
+        );
+    }
+
+    #[test]
+    fn icc_lab_16_legacy() {
+        assert_eq!(
+            IccLab16Legacy::encode(100.0, 0.0, 0.0),
+            [0xff00, 0x8000, 0x8000]
+        );
+        assert_eq!(IccLab16Legacy::encode(0.0, -128.0, 127.0), [0, 0, 0xff00]);
+        assert_eq!(
+            decode::<IccLab16Legacy>([0xff00, 0x8000, 0xffff]),
+            [100.0, 0.0, 127.99609375]
+        );
+    }
+
+    #[test]
+    fn tiff_cie_lab_8() {
+        assert_eq!(TiffCieLab8::encode(100.0, 0.0, 0.0), [255, 0, 0]);
+        assert_eq!(TiffCieLab8::encode(20.0, -1.0, 127.0), [51, 255, 127]);
+        assert_eq!(TiffCieLab8::encode(0.0, -128.0, -300.0), [0, 128, 128]);
+        assert_eq!(decode::<TiffCieLab8>([0, 255, 127]), [0.0, -1.0, 127.0]);
+    }
+
+    #[test]
+    fn tiff_cie_lab_16() {
+        assert_eq!(TiffCieLab16::encode(100.0, 0.0, 0.0), [65535, 0, 0]);
+        assert_eq!(TiffCieLab16::encode(0.0, -1.0, 1.0), [0, 0xff00, 0x0100]);
+        assert_eq!(
+            decode::<TiffCieLab16>([0, 0x8000, 0x7fff]),
+            [0.0, -128.0, 127.99609375]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for l in 0..=255u8 {
+            for &ab in &[0u8, 1, 127, 128, 254, 255] {
+                let encoded = [l, ab, 255 - ab];
+                let [l, a, b] = decode::<IccLab8>(encoded);
+                assert_eq!(IccLab8::encode(l, a, b), encoded);
+                let [l, a, b] = decode::<TiffCieLab8>(encoded);
+                assert_eq!(TiffCieLab8::encode(l, a, b), encoded);
+            }
+        }
+
+        for &value in &[0u16, 1, 0x7fff, 0x8000, 0xff00, 0xfffe] {
+            let encoded = [value, value, 0xfffe - value];
+            let [l, a, b] = decode::<IccLab16>(encoded);
+            assert_eq!(IccLab16::encode(l, a, b), encoded);
+            let [l, a, b] = decode::<IccLab16Legacy>(encoded);
+            assert_eq!(IccLab16Legacy::encode(l, a, b), encoded);
+            let [l, a, b] = decode::<TiffCieLab16>(encoded);
+            assert_eq!(TiffCieLab16::encode(l, a, b), encoded);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Integer encodings of L\*C\*h° colors.
+//!
+//! Image files store L\*a\*b\* rather than L\*C\*h°, so the encodings in
+//! [`encoding::lab`](../lab/index.html) are the ones to use for files. The
+//! types in this module are compact encodings for storing L\*C\*h° colors
+//! directly, such as in lookup tables and GPU buffers, where the hue should
+//! be kept as it is. They are used with
+//! [`Lch::from_format`](../../struct.Lch.html#method.from_format) and
+//! [`Lch::into_format`](../../struct.Lch.html#method.into_format):
+//!
+//! ```
+//! use palette::encoding::lch::Lch8;
+//! use palette::Lch;
+//!
+//! let lch: Lch = Lch::from_format::<Lch8>([255, 50, 64]);
+//! assert_eq!(lch.l, 100.0);
+//! assert_eq!(lch.chroma, 50.0);
+//! assert_eq!(lch.hue, 90.0);
+//!
+//! assert_eq!(lch.into_format::<Lch8>(), [255, 50, 64]);
+//! ```
+//!
+//! L\* and chroma are scaled like L\* and a\* in the ICC encodings, and the
+//! hue is divided into as many steps as the integer type has values, so it
+//! wraps around at a full turn. Encoding rounds to the nearest integer and
+//! clamps L\* and chroma to what the encoding can represent.
+
+use crate::{from_f64, FloatComponent, LabHue};
+
+/// An integer encoding of L\*C\*h° colors.
+pub trait LchEncoding {
+    /// The integer type of the encoded components.
+    type Component: Copy;
+
+    /// Encode the L\*, C\* and h° values.
+    fn encode<T: FloatComponent>(l: T, chroma: T, hue: LabHue<T>) -> [Self::Component; 3];
+
+    /// Decode L\*, C\* and h° values.
+    fn decode<T: FloatComponent>(encoded: [Self::Component; 3]) -> (T, T, LabHue<T>);
+}
+
+/// An 8 bit encoding, where L\* is scaled from `0.0..=100.0` to `0..=255`,
+/// chroma is stored as it is in `0..=255`, and the hue is divided into 256
+/// steps, so `0` is red and `64` is 90°.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lch8;
+
+impl LchEncoding for Lch8 {
+    type Component = u8;
+
+    fn encode<T: FloatComponent>(l: T, chroma: T, hue: LabHue<T>) -> [u8; 3] {
+        [
+            encode_u8(l * from_f64(2.55)),
+            encode_u8(chroma),
+            encode_hue(hue, 256.0) as u8,
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, chroma, hue]: [u8; 3]) -> (T, T, LabHue<T>) {
+        (
+            from_f64(f64::from(l) / 2.55),
+            from_f64(f64::from(chroma)),
+            LabHue::from_degrees(from_f64(f64::from(hue) * 360.0 / 256.0)),
+        )
+    }
+}
+
+/// A 16 bit encoding, where L\* is scaled from `0.0..=100.0` to `0..=65535`,
+/// chroma is scaled from `0.0..=255.0` to `0..=65535`, and the hue is divided
+/// into 65536 steps, so `0` is red and `16384` is 90°.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lch16;
+
+impl LchEncoding for Lch16 {
+    type Component = u16;
+
+    fn encode<T: FloatComponent>(l: T, chroma: T, hue: LabHue<T>) -> [u16; 3] {
+        [
+            encode_u16(l * from_f64(655.35)),
+            encode_u16(chroma * from_f64(65535.0 / 255.0)),
+            encode_hue(hue, 65536.0) as u16,
+        ]
+    }
+
+    fn decode<T: FloatComponent>([l, chroma, hue]: [u16; 3]) -> (T, T, LabHue<T>) {
+        (
+            from_f64(f64::from(l) / 655.35),
+            from_f64(f64::from(chroma) * 255.0 / 65535.0),
+            LabHue::from_degrees(from_f64(f64::from(hue) * 360.0 / 65536.0)),
+        )
+    }
+}
+
+/// The hue as a number of steps from red, where `steps` is a full turn.
+fn encode_hue<T: FloatComponent>(hue: LabHue<T>, steps: f64) -> u32 {
+    let steps_per_degree = from_f64::<T>(steps / 360.0);
+    let hue = (hue.to_positive_degrees() * steps_per_degree)
+        .round()
+        .to_u32()
+        .unwrap_or(0);
+
+    // A hue just below 360° rounds up to a full turn.
+    hue % steps as u32
+}
+
+fn encode_u8<T: FloatComponent>(value: T) -> u8 {
+    value
+        .max(T::zero())
+        .min(from_f64(255.0))
+        .round()
+        .to_u8()
+        .unwrap_or(0)
+}
+
+fn encode_u16<T: FloatComponent>(value: T) -> u16 {
+    value
+        .max(T::zero())
+        .min(from_f64(65535.0))
+        .round()
+        .to_u16()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Lch16, Lch8, LchEncoding};
+    use crate::LabHue;
+
+    fn decode<E: LchEncoding>(encoded: [E::Component; 3]) -> (f64, f64, f64) {
+        let (l, chroma, hue) = E::decode(encoded);
+        (l, chroma, hue.to_positive_degrees())
+    }
+
+    #[test]
+    fn lch_8() {
+        assert_eq!(Lch8::encode(100.0, 0.0, LabHue::from(0.0)), [255, 0, 0]);
+        assert_eq!(
+            Lch8::encode(60.0, 80.4, LabHue::from(-90.0)),
+            [153, 80, 192]
+        );
+        assert_eq!(
+            Lch8::encode(150.0, 300.0, LabHue::from(359.9)),
+            [255, 255, 0]
+        );
+        assert_eq!(decode::<Lch8>([0, 255, 128]), (0.0, 255.0, 180.0));
+    }
+
+    #[test]
+    fn lch_16() {
+        assert_eq!(
+            Lch16::encode(100.0, 255.0, LabHue::from(90.0)),
+            [65535, 65535, 16384]
+        );
+        assert_eq!(Lch16::encode(0.0, -1.0, LabHue::from(360.0)), [0, 0, 0]);
+        assert_eq!(decode::<Lch16>([65535, 0, 49152]), (100.0, 0.0, 270.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in 0..=255u8 {
+            let encoded = [value, 255 - value, value];
+            let (l, chroma, hue) = Lch8::decode::<f64>(encoded);
+            assert_eq!(Lch8::encode(l, chroma, hue), encoded);
+        }
+
+        for &value in &[0u16, 1, 0x7fff, 0x8000, 0xff00, 0xffff] {
+            let encoded = [value, 0xffff - value, value];
+            let (l, chroma, hue) = Lch16::decode::<f64>(encoded);
+            assert_eq!(Lch16::encode(l, chroma, hue), encoded);
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use core::marker::PhantomData;
 
-use crate::encoding::TransferFn;
+use crate::encoding::{LinearTransferFn, TransferFn};
 use crate::float::Float;
 use crate::luma::LumaStandard;
 use crate::rgb::{RgbSpace, RgbStandard};
@@ -37,3 +37,5 @@ impl TransferFn for LinearFn {
         x
     }
 }
+
+impl LinearTransferFn for LinearFn {}
@@ -241,3 +241,134 @@ pub unsafe trait Pixel<T>: Sized {
         unsafe { ::core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, new_length) }
     }
 }
+
+/// Colors that consist of exactly `N` components of type `T`, and can be
+/// converted to and from `[T; N]`.
+///
+/// This is a fixed size version of [`Pixel`](crate::Pixel), which makes it
+/// possible to write operations that work on the components of any color type
+/// once, instead of for each type. The fields of the color are still used as
+/// usual, since they are laid out in the same order as the array.
+///
+/// `ComponentArray` is implemented together with `Pixel` when `Pixel` is
+/// derived, and for `Alpha` when the color has up to four components.
+///
+/// ```rust
+/// use palette::{ComponentArray, Pixel, Srgb, Srgba};
+///
+/// // Works for any color with any number of channels.
+/// fn halve<C: ComponentArray<f32, N>, const N: usize>(color: C) -> C {
+///     color.map_components(|component| component / 2.0)
+/// }
+///
+/// assert_eq!(halve(Srgb::new(1.0, 0.5, 0.0)), Srgb::new(0.5, 0.25, 0.0));
+/// assert_eq!(halve(Srgba::new(1.0, 0.5, 0.0, 1.0)).into_array(), [0.5, 0.25, 0.0, 0.5]);
+///
+/// #[derive(PartialEq, Debug, Pixel)]
+/// #[repr(C)]
+/// struct MyCmyk {
+///     cyan: f32,
+///     magenta: f32,
+///     yellow: f32,
+///     key: f32,
+/// }
+///
+/// let cmyk = MyCmyk::from_array([0.2, 0.4, 0.6, 0.8]);
+/// assert_eq!(halve(cmyk).as_array(), &[0.1, 0.2, 0.3, 0.4]);
+/// ```
+///
+/// # Safety
+///
+/// In addition to the requirements of `Pixel`, `Self::CHANNELS` has to be
+/// `N`, so that `Self` has the same memory layout as `[T; N]`.
+pub unsafe trait ComponentArray<T, const N: usize>: Pixel<T> {
+    /// Convert into an array of components.
+    #[inline]
+    fn into_array(self) -> [T; N] {
+        self.into_raw()
+    }
+
+    /// Convert from an array of components.
+    #[inline]
+    fn from_array(array: [T; N]) -> Self {
+        assert_eq!(N, Self::CHANNELS);
+        assert_eq!(
+            ::core::mem::size_of::<[T; N]>(),
+            ::core::mem::size_of::<Self>()
+        );
+
+        let converted = unsafe { ::core::ptr::read(&array as *const [T; N] as *const Self) };
+        ::core::mem::forget(array);
+
+        converted
+    }
+
+    /// Cast as a reference to an array of components.
+    #[inline]
+    fn as_array(&self) -> &[T; N] {
+        self.as_raw()
+    }
+
+    /// Cast as a mutable reference to an array of components.
+    #[inline]
+    fn as_array_mut(&mut self) -> &mut [T; N] {
+        self.as_raw_mut()
+    }
+
+    /// Apply `f` to each component, in order.
+    #[inline]
+    fn map_components<F: FnMut(T) -> T>(self, mut f: F) -> Self
+    where
+        T: Copy,
+    {
+        let mut array = self.into_array();
+        for component in array.iter_mut() {
+            *component = f(*component);
+        }
+
+        Self::from_array(array)
+    }
+
+    /// Apply `f` to each pair of components from `self` and `other`, in
+    /// order.
+    #[inline]
+    fn zip_components<F: FnMut(T, T) -> T>(self, other: Self, mut f: F) -> Self
+    where
+        T: Copy,
+    {
+        let mut array = self.into_array();
+        for (component, &other) in array.iter_mut().zip(other.as_array()) {
+            *component = f(*component, other);
+        }
+
+        Self::from_array(array)
+    }
+
+    /// Cast a slice of component arrays to a slice of colors.
+    #[inline]
+    fn from_array_slice(slice: &[[T; N]]) -> &[Self] {
+        assert_eq!(N, Self::CHANNELS);
+        unsafe { ::core::slice::from_raw_parts(slice.as_ptr() as *const Self, slice.len()) }
+    }
+
+    /// Cast a mutable slice of component arrays to a mutable slice of colors.
+    #[inline]
+    fn from_array_slice_mut(slice: &mut [[T; N]]) -> &mut [Self] {
+        assert_eq!(N, Self::CHANNELS);
+        unsafe { ::core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self, slice.len()) }
+    }
+
+    /// Cast a slice of colors to a slice of component arrays.
+    #[inline]
+    fn into_array_slice(slice: &[Self]) -> &[[T; N]] {
+        assert_eq!(N, Self::CHANNELS);
+        unsafe { ::core::slice::from_raw_parts(slice.as_ptr() as *const [T; N], slice.len()) }
+    }
+
+    /// Cast a mutable slice of colors to a mutable slice of component arrays.
+    #[inline]
+    fn into_array_slice_mut(slice: &mut [Self]) -> &mut [[T; N]] {
+        assert_eq!(N, Self::CHANNELS);
+        unsafe { ::core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut [T; N], slice.len()) }
+    }
+}
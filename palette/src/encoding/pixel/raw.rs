@@ -7,20 +7,8 @@ pub unsafe trait RawPixelSized<T>: Sized {
     const CHANNELS: usize;
 }
 
-unsafe impl<T> RawPixelSized<T> for [T; 1] {
-    const CHANNELS: usize = 1;
-}
-
-unsafe impl<T> RawPixelSized<T> for [T; 2] {
-    const CHANNELS: usize = 2;
-}
-
-unsafe impl<T> RawPixelSized<T> for [T; 3] {
-    const CHANNELS: usize = 3;
-}
-
-unsafe impl<T> RawPixelSized<T> for [T; 4] {
-    const CHANNELS: usize = 4;
+unsafe impl<T, const N: usize> RawPixelSized<T> for [T; N] {
+    const CHANNELS: usize = N;
 }
 
 /// A contiguous sequence of pixel channels.
@@ -1,8 +1,9 @@
 //! The sRGB standard.
 
-use crate::encoding::TransferFn;
+use crate::encoding::{NonLinearTransferFn, TransferFn};
 use crate::float::Float;
 use crate::luma::LumaStandard;
+use crate::math::powf;
 use crate::rgb::{Primaries, RgbSpace, RgbStandard};
 use crate::white_point::{WhitePoint, D65};
 use crate::{from_f64, FromF64};
@@ -45,7 +46,10 @@ impl TransferFn for Srgb {
         if x <= from_f64(0.04045) {
             x * from_f64::<T>(12.92).recip()
         } else {
-            ((x + from_f64(0.055)) * from_f64::<T>(1.055).recip()).powf(from_f64(2.4))
+            powf(
+                (x + from_f64(0.055)) * from_f64::<T>(1.055).recip(),
+                from_f64(2.4),
+            )
         }
     }
 
@@ -53,7 +57,9 @@ impl TransferFn for Srgb {
         if x <= from_f64(0.0031308) {
             x * from_f64(12.92)
         } else {
-            x.powf(T::one() / from_f64(2.4)) * from_f64(1.055) - from_f64(0.055)
+            powf(x, T::one() / from_f64(2.4)) * from_f64(1.055) - from_f64(0.055)
         }
     }
 }
+
+impl NonLinearTransferFn for Srgb {}
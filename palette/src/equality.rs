@@ -3,8 +3,8 @@ use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use crate::float::Float;
 use crate::white_point::WhitePoint;
 use crate::{
-    from_f64, FloatComponent, FromF64, Hsluv, Lab, LabHue, Lch, Lchuv, Luv, LuvHue, RgbHue, Xyz,
-    Yxy,
+    from_f64, Cam02Ucs, Cam16Ucs, FloatComponent, FromF64, Hsluv, Lab, LabHue, Lch, Lchuv, Luv,
+    LuvHue, OklabHue, RgbHue, Xyz, Yxy,
 };
 
 macro_rules! impl_eq {
@@ -67,6 +67,8 @@ macro_rules! impl_eq {
 impl_eq!(Xyz, [x, y, z]);
 impl_eq!(Yxy, [y, x, luma]);
 impl_eq!(Lab, [l, a, b]);
+impl_eq!(Cam02Ucs, [j, a, b]);
+impl_eq!(Cam16Ucs, [j, a, b]);
 impl_eq!(Luv, [l, u, v]);
 impl_eq!(Lch, [l, chroma, hue]);
 impl_eq!(Lchuv, [l, chroma, hue]);
@@ -153,3 +155,4 @@ macro_rules! impl_eq_hue {
 impl_eq_hue!(LabHue);
 impl_eq_hue!(RgbHue);
 impl_eq_hue!(LuvHue);
+impl_eq_hue!(OklabHue);
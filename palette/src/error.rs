@@ -0,0 +1,334 @@
+//! Error types for the fallible parts of the library.
+//!
+//! Each fallible operation returns its own, specific error type, so it's
+//! possible to match on exactly what went wrong. They are all collected in
+//! this module, together with [`Error`](enum.Error.html), which can hold any
+//! of them. The `?` operator converts the specific errors into `Error`, which
+//! is useful when a function calls more than one kind of fallible operation:
+//!
+//! ```
+//! use palette::error::Error;
+//! use palette::{Gradient, LinSrgb, Srgb};
+//!
+//! fn parse_gradient(start: &str, end: &str) -> Result<Gradient<LinSrgb>, Error> {
+//!     let start: Srgb<u8> = start.parse()?;
+//!     let end: Srgb<u8> = end.parse()?;
+//!
+//!     let gradient = Gradient::try_with_domain(vec![
+//!         (0.0, start.into_format().into_linear()),
+//!         (1.0, end.into_format().into_linear()),
+//!     ])?;
+//!     Ok(gradient)
+//! }
+//!
+//! assert!(parse_gradient("#ff8000", "#0080ff").is_ok());
+//! assert!(matches!(parse_gradient("#ff8000", "#xyz"), Err(Error::Hex(_))));
+//! ```
+//!
+//! None of the errors depend on the standard library, except for the parts
+//! that read files. They implement `std::error::Error` when the `std` feature
+//! is enabled.
+
+use core::fmt;
+
+pub use crate::adjust::ParseCdlError;
+pub use crate::convert::OutOfBounds;
+pub use crate::css::{ParseColorError, RelativeColorError};
+#[cfg(feature = "std")]
+pub use crate::gradient::gimp::ParseGgrError;
+pub use crate::rgb::FromHexError;
+
+/// The error type for creating gradients from invalid stops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientError {
+    /// The gradient has no stops.
+    Empty,
+    /// A stop has a position that is NaN or infinite.
+    NonFinitePosition {
+        /// The index of the stop.
+        index: usize,
+    },
+    /// A stop has a lower position than the stop before it.
+    UnorderedPositions {
+        /// The index of the stop.
+        index: usize,
+    },
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GradientError::Empty => write!(f, "a gradient must contain at least one color"),
+            GradientError::NonFinitePosition { index } => {
+                write!(f, "the position of stop {} is not finite", index)
+            }
+            GradientError::UnorderedPositions { index } => write!(
+                f,
+                "the position of stop {} is lower than the position of stop {}",
+                index,
+                index - 1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GradientError {}
+
+/// The error type for creating or reading lookup tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutError {
+    /// The table size is not supported, such as a 3D table with fewer than 2,
+    /// or more than 256, entries along each axis.
+    InvalidSize(usize),
+    /// The table doesn't have as many entries as its size needs.
+    WrongLength {
+        /// The number of entries that the size needs.
+        expected: usize,
+        /// The number of entries that were found.
+        found: usize,
+    },
+    /// A line in a table file couldn't be parsed.
+    InvalidLine {
+        /// The line number, starting at 1.
+        line: usize,
+    },
+    /// A table file couldn't be read or written.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for LutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LutError::InvalidSize(size) => write!(f, "unsupported lookup table size {}", size),
+            LutError::WrongLength { expected, found } => {
+                write!(f, "expected {} table entries, found {}", expected, found)
+            }
+            LutError::InvalidLine { line } => write!(f, "invalid table data on line {}", line),
+            #[cfg(feature = "std")]
+            LutError::Io(kind) => write!(f, "failed to access the table file: {:?}", kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LutError {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for LutError {
+    fn from(error: std::io::Error) -> Self {
+        LutError::Io(error.kind())
+    }
+}
+
+/// The error type for fitting color corrections to pairs of colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FitError {
+    /// There are not as many reference colors as measured colors.
+    MismatchedLength {
+        /// The number of measured colors.
+        measured: usize,
+        /// The number of reference colors.
+        reference: usize,
+    },
+    /// There are too few colors for the number of unknowns.
+    TooFewColors {
+        /// The smallest number of colors that is needed.
+        needed: usize,
+        /// The number of colors that were given.
+        found: usize,
+    },
+    /// The measured colors don't determine a unique solution, such as when
+    /// they all lie in a plane.
+    Singular,
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FitError::MismatchedLength {
+                measured,
+                reference,
+            } => write!(
+                f,
+                "expected as many reference colors as measured colors, found {} and {}",
+                reference, measured
+            ),
+            FitError::TooFewColors { needed, found } => write!(
+                f,
+                "expected at least {} colors for the fit, found {}",
+                needed, found
+            ),
+            FitError::Singular => write!(f, "the measured colors don't determine a unique fit"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FitError {}
+
+/// The error type for generating shader code.
+#[cfg(feature = "shader")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShaderError {
+    /// The conversion chain has fewer than two spaces.
+    TooShort,
+    /// Two neighboring spaces in the chain don't have a direct conversion.
+    UnsupportedStep {
+        /// The space to convert from.
+        from: crate::graph::Space,
+        /// The space to convert to.
+        to: crate::graph::Space,
+    },
+}
+
+#[cfg(feature = "shader")]
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ShaderError::TooShort => write!(f, "a conversion chain needs at least two spaces"),
+            ShaderError::UnsupportedStep { from, to } => write!(
+                f,
+                "there is no direct conversion from {} to {}",
+                from.name(),
+                to.name()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "shader")]
+impl std::error::Error for ShaderError {}
+
+/// Any of the errors in the library.
+///
+/// The specific error types convert into `Error` with `From`, so it can be
+/// used with the `?` operator. The color in an
+/// [`OutOfBounds`](struct.OutOfBounds.html) error is left out, to keep
+/// `Error` free from type parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A hex code couldn't be parsed.
+    Hex(FromHexError),
+    /// A CSS color couldn't be parsed.
+    ParseColor(ParseColorError),
+    /// A CSS relative color expression couldn't be evaluated.
+    RelativeColor(RelativeColorError),
+    /// An ASC CDL correction couldn't be parsed.
+    Cdl(ParseCdlError),
+    /// A GIMP gradient file couldn't be parsed.
+    #[cfg(feature = "std")]
+    Ggr(ParseGgrError),
+    /// A gradient had invalid stops.
+    Gradient(GradientError),
+    /// A lookup table was invalid, or couldn't be read.
+    Lut(LutError),
+    /// A color correction couldn't be fitted.
+    Fit(FitError),
+    /// Shader code couldn't be generated.
+    #[cfg(feature = "shader")]
+    Shader(ShaderError),
+    /// A color conversion gave a color outside its valid range.
+    OutOfBounds,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Hex(error) => error.fmt(f),
+            Error::ParseColor(error) => error.fmt(f),
+            Error::RelativeColor(error) => error.fmt(f),
+            Error::Cdl(error) => error.fmt(f),
+            #[cfg(feature = "std")]
+            Error::Ggr(error) => error.fmt(f),
+            Error::Gradient(error) => error.fmt(f),
+            Error::Lut(error) => error.fmt(f),
+            Error::Fit(error) => error.fmt(f),
+            #[cfg(feature = "shader")]
+            Error::Shader(error) => error.fmt(f),
+            Error::OutOfBounds => write!(f, "color conversion is out of bounds"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Hex(error) => Some(error),
+            Error::ParseColor(error) => Some(error),
+            Error::RelativeColor(error) => Some(error),
+            Error::Cdl(error) => Some(error),
+            Error::Ggr(error) => Some(error),
+            Error::Gradient(error) => Some(error),
+            Error::Lut(error) => Some(error),
+            Error::Fit(error) => Some(error),
+            #[cfg(feature = "shader")]
+            Error::Shader(error) => Some(error),
+            Error::OutOfBounds => None,
+        }
+    }
+}
+
+impl From<FromHexError> for Error {
+    fn from(error: FromHexError) -> Self {
+        Error::Hex(error)
+    }
+}
+
+impl From<ParseColorError> for Error {
+    fn from(error: ParseColorError) -> Self {
+        Error::ParseColor(error)
+    }
+}
+
+impl From<RelativeColorError> for Error {
+    fn from(error: RelativeColorError) -> Self {
+        Error::RelativeColor(error)
+    }
+}
+
+impl From<ParseCdlError> for Error {
+    fn from(error: ParseCdlError) -> Self {
+        Error::Cdl(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParseGgrError> for Error {
+    fn from(error: ParseGgrError) -> Self {
+        Error::Ggr(error)
+    }
+}
+
+impl From<GradientError> for Error {
+    fn from(error: GradientError) -> Self {
+        Error::Gradient(error)
+    }
+}
+
+impl From<LutError> for Error {
+    fn from(error: LutError) -> Self {
+        Error::Lut(error)
+    }
+}
+
+impl From<FitError> for Error {
+    fn from(error: FitError) -> Self {
+        Error::Fit(error)
+    }
+}
+
+#[cfg(feature = "shader")]
+impl From<ShaderError> for Error {
+    fn from(error: ShaderError) -> Self {
+        Error::Shader(error)
+    }
+}
+
+impl<T> From<OutOfBounds<T>> for Error {
+    fn from(_: OutOfBounds<T>) -> Self {
+        Error::OutOfBounds
+    }
+}
@@ -0,0 +1,970 @@
+//! Gamut boundaries of RGB spaces.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! The gamut of an RGB space is the set of colors it can represent. Its
+//! boundary is irregular when seen in a perceptual space, such as L\*C\*h° or
+//! Oklch, which makes it expensive to find out how much chroma a color can
+//! have without leaving the gamut. A
+//! [`GamutBoundary`](struct.GamutBoundary.html) is a precomputed description
+//! of the boundary that answers those questions with a table lookup.
+//!
+//! ```
+//! use palette::encoding::Srgb;
+//! use palette::gamut::GamutBoundary;
+//! use palette::{LabHue, Lch};
+//!
+//! let boundary = GamutBoundary::<Srgb>::new();
+//!
+//! // The most saturated red that sRGB can show at a lightness of 50.
+//! let max_chroma = boundary.max_chroma(50.0, LabHue::from_degrees(40.0));
+//! assert!(max_chroma > 95.0 && max_chroma < 105.0);
+//!
+//! let color = Lch::new(50.0, 120.0, 40.0);
+//! assert!(!boundary.contains(&color));
+//! assert!(boundary.contains(&boundary.clip_chroma(color)));
+//! ```
+//!
+//! Colors can also be brought into the gamut with one of the strategies in
+//! [`ClipMode`](enum.ClipMode.html), using
+//! [`GamutBoundary::clip`](struct.GamutBoundary.html#method.clip).
+//!
+//! For visualization, [`GamutMesh`](struct.GamutMesh.html) is a triangle mesh
+//! of the surface of the gamut in any color space.
+
+use core::marker::PhantomData;
+
+#[cfg(feature = "random")]
+use rand::Rng;
+
+#[cfg(feature = "random")]
+use crate::math::{atan2, hypot};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Linear;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::WhitePoint as WhitePointTrait;
+use crate::{from_f64, FloatComponent, LabHue, Lch, Mix, OklabHue, Oklch};
+
+/// The white point of the RGB space `S`.
+type WhitePoint<S> = <S as RgbSpace>::WhitePoint;
+
+/// A cylindrical color space that a [`GamutBoundary`](struct.GamutBoundary.html)
+/// can be described in.
+///
+/// It's implemented for [`Lch`](../struct.Lch.html) and
+/// [`Oklch`](../struct.Oklch.html).
+pub trait PolarSpace: Copy {
+    /// The type of the lightness and chroma.
+    type Scalar: FloatComponent;
+
+    /// The type of the hue.
+    type Hue: Copy;
+
+    /// The lightness of white, such as `100.0` for `Lch` and `1.0` for
+    /// `Oklch`.
+    fn max_lightness() -> Self::Scalar;
+
+    /// The chroma of a typical saturated color, which is where the search
+    /// for the boundary starts.
+    fn typical_chroma() -> Self::Scalar;
+
+    /// Create a color from its lightness, chroma and hue.
+    fn from_polar(l: Self::Scalar, chroma: Self::Scalar, hue: Self::Hue) -> Self;
+
+    /// The lightness, chroma and hue of the color.
+    fn to_polar(self) -> (Self::Scalar, Self::Scalar, Self::Hue);
+
+    /// Create a hue from an angle in degrees.
+    fn hue_from_degrees(degrees: Self::Scalar) -> Self::Hue;
+
+    /// The angle of a hue in degrees, from `0.0` to `360.0`.
+    fn hue_to_positive_degrees(hue: Self::Hue) -> Self::Scalar;
+}
+
+impl<Wp, T> PolarSpace for Lch<Wp, T>
+where
+    Wp: WhitePointTrait,
+    T: FloatComponent,
+{
+    type Scalar = T;
+    type Hue = LabHue<T>;
+
+    fn max_lightness() -> T {
+        from_f64(100.0)
+    }
+
+    fn typical_chroma() -> T {
+        from_f64(100.0)
+    }
+
+    fn from_polar(l: T, chroma: T, hue: LabHue<T>) -> Self {
+        Lch {
+            l,
+            chroma,
+            hue,
+            white_point: PhantomData,
+        }
+    }
+
+    fn to_polar(self) -> (T, T, LabHue<T>) {
+        (self.l, self.chroma, self.hue)
+    }
+
+    fn hue_from_degrees(degrees: T) -> LabHue<T> {
+        LabHue::from_degrees(degrees)
+    }
+
+    fn hue_to_positive_degrees(hue: LabHue<T>) -> T {
+        hue.to_positive_degrees()
+    }
+}
+
+impl<T> PolarSpace for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+    type Hue = OklabHue<T>;
+
+    fn max_lightness() -> T {
+        T::one()
+    }
+
+    fn typical_chroma() -> T {
+        from_f64(0.4)
+    }
+
+    fn from_polar(l: T, chroma: T, hue: OklabHue<T>) -> Self {
+        Oklch { l, chroma, hue }
+    }
+
+    fn to_polar(self) -> (T, T, OklabHue<T>) {
+        (self.l, self.chroma, self.hue)
+    }
+
+    fn hue_from_degrees(degrees: T) -> OklabHue<T> {
+        OklabHue::from_degrees(degrees)
+    }
+
+    fn hue_to_positive_degrees(hue: OklabHue<T>) -> T {
+        hue.to_positive_degrees()
+    }
+}
+
+/// A strategy for bringing a color into the gamut of an RGB space.
+///
+/// All modes keep colors that are already inside the gamut unchanged, and all
+/// except `RayTrace` keep the hue. Grays with a lightness below black or above
+/// white end up at black or white. They differ in how they trade lightness for
+/// chroma, including for colors with a lightness outside that range:
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::gamut::{ClipMode, GamutBoundary};
+/// use palette::Lch;
+///
+/// let boundary = GamutBoundary::<Srgb>::new();
+/// let color = Lch::new(90.0, 80.0, 260.0);
+///
+/// let chroma = boundary.clip(color, ClipMode::Chroma);
+/// assert_eq!(chroma.l, 90.0);
+///
+/// let cusp = boundary.clip(color, ClipMode::Cusp);
+/// assert!(cusp.l < 90.0 && cusp.chroma > chroma.chroma);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipMode {
+    /// Reduce the chroma while keeping the lightness, moving the color
+    /// straight toward the gray axis.
+    ///
+    /// This keeps the lightness contrast between colors, but very light or
+    /// dark colors may lose most of their chroma. Colors with a lightness
+    /// below black or above white have their lightness clamped first, so
+    /// they end up at black or white.
+    Chroma,
+
+    /// Change the lightness while keeping the chroma, moving the color toward
+    /// the lightness of the cusp.
+    ///
+    /// This keeps the colorfulness, at the cost of lightness contrast. Colors
+    /// with more chroma than the cusp have no valid lightness, and are
+    /// projected toward the cusp's lightness on the gray axis, as with
+    /// `Cusp`. The lightness isn't clamped first, so colors with a lightness
+    /// below black or above white also move toward the cusp and keep their
+    /// chroma, if possible.
+    Lightness,
+
+    /// Move the color toward the gray with the same lightness as the cusp,
+    /// which is the most saturated color with the same hue.
+    ///
+    /// This changes both lightness and chroma, and is a good compromise that
+    /// keeps more chroma than `Chroma` for very light or dark colors. The
+    /// lightness isn't clamped first, so colors with a lightness below black
+    /// or above white end up on the boundary between them and the cusp,
+    /// rather than at black or white.
+    Cusp,
+
+    /// Move the color along a straight line in linear RGB, toward the gray
+    /// with the same lightness, and stop at the surface of the RGB cube.
+    ///
+    /// This is the ray tracing approach from CSS Color 4. It's exact and
+    /// fast, since it doesn't need any search, but lines in linear RGB don't
+    /// have a constant hue, so the hue may shift slightly. Colors with a
+    /// lightness below black or above white have their lightness clamped
+    /// first, and end up at black or white.
+    RayTrace,
+}
+
+/// A precomputed description of the gamut boundary of an RGB space, in a
+/// cylindrical color space, which is L\*C\*h° by default.
+///
+/// The boundary is stored as the maximum chroma for a grid of lightness and
+/// hue segments, where each value is found by searching along the chroma
+/// axis. Queries interpolate between the segments, so they are approximate,
+/// with an error that depends on the resolution of the grid. The default
+/// resolution is good enough for most gamut mapping and color picking.
+///
+/// The color space is chosen with the type parameter `C`, which can be any
+/// [`PolarSpace`](trait.PolarSpace.html), such as `Oklch`:
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::gamut::{ClipMode, GamutBoundary};
+/// use palette::Oklch;
+///
+/// let boundary = GamutBoundary::<Srgb, f32, Oklch>::new();
+/// let color = Oklch::new(0.7, 0.3, 150.0);
+///
+/// assert!(!boundary.contains(&color));
+/// let clipped = boundary.clip(color, ClipMode::Chroma);
+/// assert!(clipped.chroma < 0.3);
+/// ```
+///
+/// A `GamutBoundary` is meant to be computed once and reused, since building
+/// it requires a large number of color conversions.
+#[derive(Clone, Debug)]
+pub struct GamutBoundary<S, T = f32, C = Lch<WhitePoint<S>, T>>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    lightness_segments: usize,
+    hue_segments: usize,
+    max_chroma: Vec<T>,
+    space: PhantomData<(S, C)>,
+}
+
+impl<S, T, C> GamutBoundary<S, T, C>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T> + FromColorUnclamped<Rgb<Linear<S>, T>>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    /// Compute the boundary with 64 lightness segments and 180 hue segments.
+    pub fn new() -> Self {
+        Self::with_resolution(64, 180)
+    }
+
+    /// Compute the boundary with a custom number of lightness and hue
+    /// segments.
+    ///
+    /// Panics if any of the numbers are 0.
+    pub fn with_resolution(lightness_segments: usize, hue_segments: usize) -> Self {
+        assert!(lightness_segments > 0 && hue_segments > 0);
+
+        let mut max_chroma = Vec::with_capacity((lightness_segments + 1) * hue_segments);
+
+        for l_index in 0..=lightness_segments {
+            let l = C::max_lightness() * from_f64(l_index as f64 / lightness_segments as f64);
+
+            for h_index in 0..hue_segments {
+                let hue = from_f64::<T>(360.0 * h_index as f64 / hue_segments as f64);
+                max_chroma.push(search_max_chroma::<S, T, C>(l, C::hue_from_degrees(hue)));
+            }
+        }
+
+        GamutBoundary {
+            lightness_segments,
+            hue_segments,
+            max_chroma,
+            space: PhantomData,
+        }
+    }
+
+    /// Get the highest chroma that a color with lightness `l` and `hue` can
+    /// have without leaving the gamut.
+    pub fn max_chroma(&self, l: T, hue: C::Hue) -> T {
+        let l = l.max(T::zero()).min(C::max_lightness());
+        let l_position = l / C::max_lightness() * from_f64(self.lightness_segments as f64);
+        let l_index = l_position
+            .floor()
+            .to_usize()
+            .unwrap_or(0)
+            .min(self.lightness_segments - 1);
+        let l_factor = l_position - from_f64(l_index as f64);
+
+        let lower = self.max_chroma_at(l_index, hue);
+        let upper = self.max_chroma_at(l_index + 1, hue);
+
+        lower + (upper - lower) * l_factor
+    }
+
+    /// Check if `color` is inside the gamut, according to the boundary.
+    ///
+    /// The result may be wrong for colors that are very close to the
+    /// boundary, due to the limited resolution.
+    pub fn contains(&self, color: &C) -> bool {
+        let (l, chroma, hue) = color.to_polar();
+
+        l >= T::zero() && l <= C::max_lightness() && chroma <= self.max_chroma(l, hue)
+    }
+
+    /// Find the lightness and chroma of the most saturated color with the
+    /// given hue. This is the "cusp" of the gamut's cross section at `hue`.
+    pub fn cusp(&self, hue: C::Hue) -> (T, T) {
+        let mut cusp = (T::zero(), T::zero());
+
+        for l_index in 0..=self.lightness_segments {
+            let chroma = self.max_chroma_at(l_index, hue);
+            if chroma > cusp.1 {
+                let l =
+                    C::max_lightness() * from_f64(l_index as f64 / self.lightness_segments as f64);
+                cusp = (l, chroma);
+            }
+        }
+
+        cusp
+    }
+
+    /// Reduce the chroma of `color` to fit within the gamut, while keeping
+    /// its lightness and hue. Colors with a lightness below black or above
+    /// white are clamped to that range first.
+    pub fn clip_chroma(&self, color: C) -> C {
+        let (l, chroma, hue) = color.to_polar();
+        let l = l.max(T::zero()).min(C::max_lightness());
+        let max_chroma = self.max_chroma(l, hue);
+
+        C::from_polar(l, chroma.max(T::zero()).min(max_chroma), hue)
+    }
+
+    /// Bring `color` into the gamut, using the strategy in `mode`.
+    ///
+    /// Unlike [`clip_chroma`](#method.clip_chroma), this searches for the
+    /// exact boundary, using the precomputed boundary only to find the cusp.
+    /// See [`ClipMode`](enum.ClipMode.html) for how each mode treats colors
+    /// with a lightness below black or above white.
+    pub fn clip(&self, color: C, mode: ClipMode) -> C {
+        if is_in_gamut::<S, T, C>(color) {
+            return color;
+        }
+
+        let (l, chroma, hue) = color.to_polar();
+        let clamped_l = l.max(T::zero()).min(C::max_lightness());
+        let cusp_l = || self.cusp(hue).0;
+
+        match mode {
+            ClipMode::Chroma => project::<S, T, C>(color, clamped_l, T::zero()),
+            ClipMode::Lightness => {
+                let l = cusp_l();
+                let chroma = chroma.max(T::zero());
+
+                if is_in_gamut::<S, T, C>(C::from_polar(l, chroma, hue)) {
+                    project::<S, T, C>(color, l, chroma)
+                } else {
+                    project::<S, T, C>(color, l, T::zero())
+                }
+            }
+            ClipMode::Cusp => project::<S, T, C>(color, cusp_l(), T::zero()),
+            ClipMode::RayTrace => ray_trace::<S, T, C>(color, clamped_l),
+        }
+    }
+
+    /// Pick a random color from inside the gamut.
+    ///
+    /// The colors are uniformly distributed within the gamut's volume in the
+    /// rectangular form of the color space, such as L\*a\*b\* for `Lch`,
+    /// using rejection sampling against the boundary.
+    #[cfg(feature = "random")]
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> C {
+        let max_chroma = self
+            .max_chroma
+            .iter()
+            .fold(T::zero(), |max, &chroma| max.max(chroma));
+
+        loop {
+            let l = C::max_lightness() * from_f64(rng.gen::<f64>());
+            let a = max_chroma * from_f64(rng.gen::<f64>() * 2.0 - 1.0);
+            let b = max_chroma * from_f64(rng.gen::<f64>() * 2.0 - 1.0);
+
+            let chroma = hypot(a, b);
+            let degrees = atan2(b, a) * from_f64(180.0 / core::f64::consts::PI);
+            let hue = C::hue_from_degrees(degrees);
+
+            if chroma <= self.max_chroma(l, hue) {
+                return C::from_polar(l, chroma, hue);
+            }
+        }
+    }
+
+    /// Interpolate the maximum chroma along the hue axis, for the lightness
+    /// segment boundary at `l_index`.
+    fn max_chroma_at(&self, l_index: usize, hue: C::Hue) -> T {
+        let h_position =
+            C::hue_to_positive_degrees(hue) / from_f64(360.0) * from_f64(self.hue_segments as f64);
+        let h_index = h_position.floor().to_usize().unwrap_or(0) % self.hue_segments;
+        let h_factor = h_position - h_position.floor();
+
+        let row = &self.max_chroma[l_index * self.hue_segments..][..self.hue_segments];
+        let lower = row[h_index];
+        let upper = row[(h_index + 1) % self.hue_segments];
+
+        lower + (upper - lower) * h_factor
+    }
+}
+
+impl<S, T, C> Default for GamutBoundary<S, T, C>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T> + FromColorUnclamped<Rgb<Linear<S>, T>>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A triangle mesh of the surface of an RGB gamut, with the vertices in the
+/// color space `C`, for rendering gamut solids.
+///
+/// The mesh is made by dividing each face of the RGB cube into a grid of
+/// squares, with two triangles each, and converting the corners to `C`. The
+/// vertices on the edges of the cube are shared between the faces, so the
+/// mesh is closed. The triangles are wound counter-clockwise when seen from
+/// outside of the cube, and the winding carries over to spaces where the
+/// conversion preserves orientation, such as `Lab` and `Oklab` with the
+/// components as `(L, a, b)`.
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::gamut::GamutMesh;
+/// use palette::Oklab;
+///
+/// let mesh = GamutMesh::<Oklab>::new::<Srgb>(8);
+/// assert_eq!(mesh.vertices.len(), 6 * 8 * 8 + 2);
+/// assert_eq!(mesh.indices.len(), 3 * 12 * 8 * 8);
+///
+/// let positions: Vec<[f32; 3]> = mesh.vertices.iter().map(|c| [c.a, c.l, c.b]).collect();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GamutMesh<C> {
+    /// The vertices of the mesh.
+    pub vertices: Vec<C>,
+
+    /// The indices of the vertices in each triangle, three per triangle.
+    pub indices: Vec<u32>,
+}
+
+impl<C> GamutMesh<C>
+where
+    C: Mix,
+    C::Scalar: FloatComponent,
+{
+    /// Compute the mesh of the gamut of the RGB space `S`, where each face of
+    /// the RGB cube is divided into `resolution` × `resolution` squares.
+    ///
+    /// Panics if `resolution` is 0.
+    pub fn new<S>(resolution: usize) -> Self
+    where
+        S: RgbSpace,
+        C: FromColorUnclamped<Rgb<Linear<S>, C::Scalar>>,
+    {
+        assert!(resolution > 0);
+
+        let side = resolution + 1;
+        let mut vertex_indices = vec![u32::MAX; side * side * side];
+        let mut vertices = Vec::with_capacity(6 * resolution * resolution + 2);
+        let mut indices = Vec::with_capacity(36 * resolution * resolution);
+
+        let mut vertex = |corner: [usize; 3]| {
+            let slot = &mut vertex_indices[(corner[0] * side + corner[1]) * side + corner[2]];
+            if *slot == u32::MAX {
+                let component =
+                    |index: usize| from_f64::<C::Scalar>(corner[index] as f64 / resolution as f64);
+                let rgb = Rgb::<Linear<S>, _>::new(component(0), component(1), component(2));
+
+                *slot = vertices.len() as u32;
+                vertices.push(C::from_color_unclamped(rgb));
+            }
+
+            *slot
+        };
+
+        // The two axes along each face, in the order that makes the normal
+        // point out of the cube on the far side.
+        for &(axis, u, v) in &[(0, 1, 2), (1, 2, 0), (2, 0, 1)] {
+            for &(position, u, v) in &[(resolution, u, v), (0, v, u)] {
+                for step_u in 0..resolution {
+                    for step_v in 0..resolution {
+                        let mut corner = |offset_u: usize, offset_v: usize| {
+                            let mut corner = [0; 3];
+                            corner[axis] = position;
+                            corner[u] = step_u + offset_u;
+                            corner[v] = step_v + offset_v;
+                            vertex(corner)
+                        };
+
+                        let quad = [corner(0, 0), corner(1, 0), corner(1, 1), corner(0, 1)];
+                        indices.extend_from_slice(&[quad[0], quad[1], quad[2]]);
+                        indices.extend_from_slice(&[quad[0], quad[2], quad[3]]);
+                    }
+                }
+            }
+        }
+
+        GamutMesh { vertices, indices }
+    }
+
+    /// Iterate over the triangles of the mesh, as their three vertices.
+    pub fn triangles(&self) -> impl Iterator<Item = [&C; 3]> + '_ {
+        self.indices.chunks(3).map(move |triangle| {
+            [
+                &self.vertices[triangle[0] as usize],
+                &self.vertices[triangle[1] as usize],
+                &self.vertices[triangle[2] as usize],
+            ]
+        })
+    }
+}
+
+/// Check if a color is inside the gamut of `S`, with some tolerance for
+/// rounding errors.
+fn is_in_gamut<S, T, C>(color: C) -> bool
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    let rgb = Rgb::<Linear<S>, T>::from_color_unclamped(color);
+    let min = from_f64::<T>(-1e-5);
+    let max = from_f64::<T>(1.0 + 1e-5);
+
+    rgb.red >= min
+        && rgb.red <= max
+        && rgb.green >= min
+        && rgb.green <= max
+        && rgb.blue >= min
+        && rgb.blue <= max
+}
+
+/// Move `color` toward the in-gamut anchor point `(l, chroma)` with the same
+/// hue, and return the last point on the line that is inside the gamut.
+fn project<S, T, C>(color: C, l: T, chroma: T) -> C
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    let (color_l, color_chroma, hue) = color.to_polar();
+    let point = |t: T| {
+        C::from_polar(
+            l + (color_l - l) * t,
+            chroma + (color_chroma - chroma) * t,
+            hue,
+        )
+    };
+
+    let mut low = T::zero();
+    let mut high = T::one();
+
+    for _ in 0..24 {
+        let middle = (low + high) / from_f64(2.0);
+        if is_in_gamut::<S, T, C>(point(middle)) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    point(low)
+}
+
+/// Find where the line from the gray with lightness `l` to `color` leaves the
+/// RGB cube.
+fn ray_trace<S, T, C>(color: C, l: T) -> C
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T> + FromColorUnclamped<Rgb<Linear<S>, T>>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    let to_rgb = |color: C| Rgb::<Linear<S>, T>::from_color_unclamped(color);
+
+    let anchor = to_rgb(C::from_polar(l, T::zero(), color.to_polar().2));
+    let target = to_rgb(color);
+
+    let mut t = T::one();
+    for &(from, to) in &[
+        (anchor.red, target.red),
+        (anchor.green, target.green),
+        (anchor.blue, target.blue),
+    ] {
+        if to > T::one() {
+            t = t.min((T::one() - from) / (to - from));
+        } else if to < T::zero() {
+            t = t.min(from / (from - to));
+        }
+    }
+
+    let clamp = |x: T| x.max(T::zero()).min(T::one());
+    let rgb = Rgb::<Linear<S>, T>::new(
+        clamp(anchor.red + (target.red - anchor.red) * t),
+        clamp(anchor.green + (target.green - anchor.green) * t),
+        clamp(anchor.blue + (target.blue - anchor.blue) * t),
+    );
+
+    C::from_color_unclamped(rgb)
+}
+
+/// Find the highest chroma within the gamut of `S`, using a binary search.
+fn search_max_chroma<S, T, C>(l: T, hue: C::Hue) -> T
+where
+    S: RgbSpace,
+    T: FloatComponent,
+    C: PolarSpace<Scalar = T>,
+    Rgb<Linear<S>, T>: FromColorUnclamped<C>,
+{
+    let in_gamut = |chroma| is_in_gamut::<S, T, C>(C::from_polar(l, chroma, hue));
+
+    if !in_gamut(T::zero()) {
+        return T::zero();
+    }
+
+    let mut low = T::zero();
+    let mut high = C::typical_chroma();
+    let limit = C::typical_chroma() * from_f64(10.0);
+
+    // Wide gamut spaces may reach further than the initial guess.
+    while in_gamut(high) && high < limit {
+        low = high;
+        high = high + high;
+    }
+
+    for _ in 0..24 {
+        let middle = (low + high) / from_f64(2.0);
+        if in_gamut(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_in_gamut, ClipMode, GamutBoundary, GamutMesh};
+    use crate::encoding::Srgb;
+    use crate::{FromColor, Lab, LabHue, Lch, LinSrgb, OklabHue, Oklch};
+
+    #[test]
+    fn primaries_are_on_boundary() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+
+        for &primary in &[
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ] {
+            let lch = Lch::from_color(primary);
+            let max_chroma = boundary.max_chroma(lch.l, lch.hue);
+            assert_relative_eq!(max_chroma, lch.chroma, max_relative = 0.05);
+        }
+    }
+
+    #[test]
+    fn gray_axis() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+
+        assert_relative_eq!(
+            boundary.max_chroma(0.0, LabHue::from_degrees(120.0)),
+            0.0,
+            epsilon = 0.1
+        );
+        assert_relative_eq!(
+            boundary.max_chroma(100.0, LabHue::from_degrees(300.0)),
+            0.0,
+            epsilon = 0.1
+        );
+        assert!(boundary.contains(&Lch::new(50.0, 0.0, 0.0)));
+        assert!(!boundary.contains(&Lch::new(101.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn clip_chroma() {
+        let boundary = GamutBoundary::<Srgb, f64>::with_resolution(100, 360);
+
+        for hue in (0..36).map(|h| h as f64 * 10.0) {
+            for &l in &[10.0, 30.0, 50.0, 70.0, 90.0] {
+                let clipped = boundary.clip_chroma(Lch::new(l, 200.0, hue));
+                let inside = Lch::new(l, clipped.chroma * 0.98, hue);
+                let outside = Lch::new(l, clipped.chroma * 1.02 + 0.5, hue);
+
+                assert!(is_in_gamut::<Srgb, f64, _>(inside), "{:?}", inside);
+                assert!(!is_in_gamut::<Srgb, f64, _>(outside), "{:?}", outside);
+            }
+        }
+    }
+
+    #[test]
+    fn clip_modes() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+
+        for hue in (0..12).map(|h| h as f64 * 30.0) {
+            for &(l, chroma) in &[(5.0, 60.0), (50.0, 150.0), (95.0, 80.0), (120.0, 10.0)] {
+                let color = Lch::new(l, chroma, hue);
+
+                for &mode in &[
+                    ClipMode::Chroma,
+                    ClipMode::Lightness,
+                    ClipMode::Cusp,
+                    ClipMode::RayTrace,
+                ] {
+                    let clipped = boundary.clip(color, mode);
+                    assert!(
+                        is_in_gamut::<Srgb, f64, _>(clipped),
+                        "{:?} {:?}",
+                        mode,
+                        color
+                    );
+
+                    if mode != ClipMode::RayTrace && clipped.chroma > 1.0 {
+                        assert_relative_eq!(clipped.hue, color.hue, epsilon = 1e-6);
+                    }
+                }
+
+                let chroma = boundary.clip(color, ClipMode::Chroma);
+                assert_relative_eq!(chroma.l, l.min(100.0), epsilon = 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn clip_out_of_range_lightness() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+
+        for hue in (0..12).map(|h| h as f64 * 30.0) {
+            for &(l, black_or_white) in &[(-30.0, 0.0), (130.0, 100.0)] {
+                let gray = Lch::new(l, 0.0, hue);
+                let color = Lch::new(l, 40.0, hue);
+                let (cusp_l, _) = boundary.cusp(color.hue);
+
+                for &mode in &[
+                    ClipMode::Chroma,
+                    ClipMode::Lightness,
+                    ClipMode::Cusp,
+                    ClipMode::RayTrace,
+                ] {
+                    let clipped = boundary.clip(gray, mode);
+                    assert_relative_eq!(clipped.l, black_or_white, epsilon = 0.05);
+                    assert_relative_eq!(clipped.chroma, 0.0, epsilon = 0.05);
+                }
+
+                for &mode in &[ClipMode::Chroma, ClipMode::RayTrace] {
+                    let clipped = boundary.clip(color, mode);
+                    assert_relative_eq!(clipped.l, black_or_white, epsilon = 0.05);
+                    assert_relative_eq!(clipped.chroma, 0.0, epsilon = 0.05);
+                }
+
+                for &mode in &[ClipMode::Lightness, ClipMode::Cusp] {
+                    let clipped = boundary.clip(color, mode);
+                    assert!(is_in_gamut::<Srgb, f64, _>(clipped), "{:?}", mode);
+                    assert!(clipped.chroma > 1.0, "{:?} {:?}", mode, clipped);
+                    assert_relative_eq!(clipped.hue, color.hue, epsilon = 1e-6);
+
+                    let (low, high) = if l < cusp_l { (l, cusp_l) } else { (cusp_l, l) };
+                    assert!(clipped.l > low && clipped.l < high, "{:?}", mode);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clip_keeps_colors_in_gamut() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+        let color = Lch::new(60.0, 20.0, 120.0);
+
+        assert_eq!(boundary.clip(color, ClipMode::Chroma), color);
+        assert_eq!(boundary.clip(color, ClipMode::Lightness), color);
+        assert_eq!(boundary.clip(color, ClipMode::Cusp), color);
+        assert_eq!(boundary.clip(color, ClipMode::RayTrace), color);
+    }
+
+    #[test]
+    fn clip_lightness() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+        let yellow = Lch::from_color(LinSrgb::new(1.0, 1.0, 0.0));
+        let color = Lch::new(40.0, 60.0, yellow.hue);
+
+        let clipped = boundary.clip(color, ClipMode::Lightness);
+        assert_relative_eq!(clipped.chroma, 60.0, epsilon = 1e-6);
+        assert!(clipped.l > 40.0);
+    }
+
+    #[test]
+    fn cusp() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+        let blue = Lch::from_color(LinSrgb::new(0.0, 0.0, 1.0));
+        let (l, chroma) = boundary.cusp(blue.hue);
+
+        assert_relative_eq!(l, blue.l, epsilon = 2.0);
+        assert_relative_eq!(chroma, blue.chroma, max_relative = 0.05);
+    }
+
+    #[test]
+    fn oklch_boundary() {
+        let boundary = GamutBoundary::<Srgb, f64, Oklch<f64>>::with_resolution(100, 360);
+
+        // The hue of the blue primary curves slightly outside of the gamut on
+        // the way there, so the search stops before it.
+        for &primary in &[LinSrgb::new(1.0, 0.0, 0.0), LinSrgb::new(0.0, 1.0, 0.0)] {
+            let oklch = Oklch::from_color(primary);
+            let max_chroma = boundary.max_chroma(oklch.l, oklch.hue);
+            assert_relative_eq!(max_chroma, oklch.chroma, max_relative = 0.05);
+        }
+
+        let red = Oklch::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+        let (l, chroma) = boundary.cusp(red.hue);
+        assert_relative_eq!(l, red.l, epsilon = 0.02);
+        assert_relative_eq!(chroma, red.chroma, max_relative = 0.05);
+
+        assert_relative_eq!(
+            boundary.max_chroma(1.0, OklabHue::from_degrees(120.0)),
+            0.0,
+            epsilon = 1e-3
+        );
+        assert!(boundary.contains(&Oklch::new(0.5, 0.0, 0.0)));
+        assert!(!boundary.contains(&Oklch::new(1.01, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn oklch_clip() {
+        let boundary = GamutBoundary::<Srgb, f64, Oklch<f64>>::new();
+
+        for hue in (0..12).map(|h| h as f64 * 30.0) {
+            for &(l, chroma) in &[(0.05, 0.2), (0.5, 0.4), (0.95, 0.2), (1.2, 0.05)] {
+                let color = Oklch::new(l, chroma, hue);
+
+                for &mode in &[
+                    ClipMode::Chroma,
+                    ClipMode::Lightness,
+                    ClipMode::Cusp,
+                    ClipMode::RayTrace,
+                ] {
+                    let clipped = boundary.clip(color, mode);
+                    assert!(
+                        is_in_gamut::<Srgb, f64, _>(clipped),
+                        "{:?} {:?}",
+                        mode,
+                        color
+                    );
+
+                    if mode != ClipMode::RayTrace && clipped.chroma > 0.01 {
+                        assert_relative_eq!(clipped.hue, color.hue, epsilon = 1e-6);
+                    }
+                }
+
+                let clipped = boundary.clip_chroma(color);
+                assert!(clipped.chroma <= chroma);
+                assert_relative_eq!(clipped.l, l.min(1.0), epsilon = 1e-12);
+            }
+        }
+
+        let color = Oklch::new(0.6, 0.05, 200.0);
+        assert_eq!(boundary.clip(color, ClipMode::Cusp), color);
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn sample() {
+        let boundary = GamutBoundary::<Srgb, f64>::new();
+        let mut rng = rand_mt::Mt::new(1234);
+
+        for _ in 0..100 {
+            assert!(boundary.contains(&boundary.sample(&mut rng)));
+        }
+
+        let boundary = GamutBoundary::<Srgb, f64, Oklch<f64>>::new();
+        for _ in 0..100 {
+            assert!(boundary.contains(&boundary.sample(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn mesh_is_closed() {
+        let mesh = GamutMesh::<LinSrgb<f64>>::new::<Srgb>(3);
+        assert_eq!(mesh.vertices.len(), 6 * 3 * 3 + 2);
+        assert_eq!(mesh.indices.len(), 3 * 12 * 3 * 3);
+
+        // Each edge is in exactly two triangles, once in each direction.
+        let mut edges = Vec::new();
+        for triangle in mesh.indices.chunks(3) {
+            for index in 0..3 {
+                edges.push((triangle[index], triangle[(index + 1) % 3]));
+            }
+        }
+
+        for &(from, to) in &edges {
+            assert_eq!(edges.iter().filter(|&&edge| edge == (from, to)).count(), 1);
+            assert_eq!(edges.iter().filter(|&&edge| edge == (to, from)).count(), 1);
+        }
+    }
+
+    #[test]
+    fn mesh_faces_outward() {
+        let mesh = GamutMesh::<LinSrgb<f64>>::new::<Srgb>(2);
+
+        for [a, b, c] in mesh.triangles() {
+            let (a, b, c) = (
+                [a.red, a.green, a.blue],
+                [b.red, b.green, b.blue],
+                [c.red, c.green, c.blue],
+            );
+            let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            let normal = [
+                ab[1] * ac[2] - ab[2] * ac[1],
+                ab[2] * ac[0] - ab[0] * ac[2],
+                ab[0] * ac[1] - ab[1] * ac[0],
+            ];
+
+            let outward: f64 = (0..3)
+                .map(|index| normal[index] * (a[index] + b[index] + c[index] - 1.5))
+                .sum();
+            assert!(outward > 0.0);
+        }
+    }
+
+    #[test]
+    fn mesh_vertices() {
+        let mesh = GamutMesh::<Lab<_, f64>>::new::<Srgb>(4);
+
+        for &corner in &[
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ] {
+            let lab = Lab::from_color(corner);
+            assert!(mesh
+                .vertices
+                .iter()
+                .any(|vertex| (vertex.l - lab.l).abs() < 1e-9 && (vertex.a - lab.a).abs() < 1e-9));
+        }
+    }
+}
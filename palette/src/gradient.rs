@@ -3,18 +3,30 @@
 //! This module is only available if the `std` feature is enabled (this is the
 //! default).
 
-use core::cmp::max;
+use core::cmp::{max, Ordering};
+use core::fmt;
 use core::marker::PhantomData;
+use std::sync::Arc;
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num_traits::{One, Zero};
 
+use crate::css::fix_hues;
+use crate::error::GradientError;
 use crate::float::Float;
+use crate::transition::Ease;
 use crate::Mix;
-use crate::{from_f64, FromF64};
+use crate::{from_f64, FloatComponent, FromColor, FromF64, Hue};
 
+pub use crate::css::HueInterpolation;
+
+pub mod bezier;
+pub mod gimp;
 #[cfg(feature = "named_gradients")]
 pub mod named;
+pub mod preset;
+pub mod scale;
+pub mod svg;
 
 impl<C, T> From<T> for Gradient<C, T>
 where
@@ -22,7 +34,7 @@ where
     T: AsRef<[(C::Scalar, C)]>,
 {
     fn from(col: T) -> Self {
-        Gradient(col, PhantomData)
+        Gradient(col, PhantomData, Easings::linear())
     }
 }
 
@@ -34,8 +46,12 @@ where
 /// number of evenly spaced points using the `take` method. Any point outside
 /// the domain of the gradient will have the same color as the closest control
 /// point.
+///
+/// The colors are mixed linearly by default, but each segment between two
+/// control points can have an easing curve. See
+/// [`with_easing`](#method.with_easing).
 #[derive(Clone, Debug)]
-pub struct Gradient<C, T = Vec<(<C as Mix>::Scalar, C)>>(T, PhantomData<C>)
+pub struct Gradient<C, T = Vec<(<C as Mix>::Scalar, C)>>(T, PhantomData<C>, Easings<C::Scalar>)
 where
     C: Mix + Clone,
     T: AsRef<[(C::Scalar, C)]>;
@@ -48,6 +64,15 @@ where
     /// Get a color from the gradient. The color of the closest control point
     /// will be returned if `i` is outside the domain.
     pub fn get(&self, i: C::Scalar) -> C {
+        match self.segment(i) {
+            Ok((min_color, max_color, factor)) => min_color.mix(max_color, factor),
+            Err(color) => color.clone(),
+        }
+    }
+
+    /// Find the control points around `i` and the factor between them, or
+    /// the closest control point if `i` is outside the domain.
+    fn segment(&self, i: C::Scalar) -> Result<(&C, &C, C::Scalar), &C> {
         let &(mut min, ref min_color) = self
             .0
             .as_ref()
@@ -57,7 +82,7 @@ where
         let mut min_index = 0;
 
         if i <= min {
-            return min_color.clone();
+            return Err(min_color);
         }
 
         let &(mut max, ref max_color) = self
@@ -69,7 +94,7 @@ where
         let mut max_index = self.0.as_ref().len() - 1;
 
         if i >= max {
-            return max_color.clone();
+            return Err(max_color);
         }
 
         while min_index < max_index - 1 {
@@ -89,18 +114,57 @@ where
         }
 
         let factor = (i - min) / (max - min);
-
-        min_color.mix(max_color, factor)
+        Ok((min_color, max_color, self.2.ease(min_index, factor)))
     }
 
     /// Create a gradient of colors with custom spacing and domain. There must
     /// be at least one color and they are expected to be ordered by their
     /// position value.
+    ///
+    /// Panics if there are no colors. See
+    /// [`try_with_domain`](#method.try_with_domain) for a version that checks
+    /// the stops and returns an error instead.
     pub fn with_domain(colors: T) -> Gradient<C, T> {
         assert!(!colors.as_ref().is_empty());
 
         //Maybe sort the colors?
-        Gradient(colors, PhantomData)
+        Gradient(colors, PhantomData, Easings::linear())
+    }
+
+    /// Create a gradient of colors with custom spacing and domain, or an
+    /// error if there are no colors, or if the positions are not finite and
+    /// in increasing order.
+    ///
+    /// ```
+    /// use palette::error::GradientError;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let black = LinSrgb::new(0.0, 0.0, 0.0);
+    /// let white = LinSrgb::new(1.0, 1.0, 1.0);
+    ///
+    /// assert!(Gradient::try_with_domain(vec![(0.0, black), (1.0, white)]).is_ok());
+    /// assert_eq!(
+    ///     Gradient::try_with_domain(vec![(1.0, black), (0.0, white)]).unwrap_err(),
+    ///     GradientError::UnorderedPositions { index: 1 }
+    /// );
+    /// ```
+    pub fn try_with_domain(colors: T) -> Result<Gradient<C, T>, GradientError> {
+        let stops = colors.as_ref();
+        if stops.is_empty() {
+            return Err(GradientError::Empty);
+        }
+
+        for (index, &(position, _)) in stops.iter().enumerate() {
+            if !position.is_finite() {
+                return Err(GradientError::NonFinitePosition { index });
+            }
+
+            if index > 0 && position < stops[index - 1].0 {
+                return Err(GradientError::UnorderedPositions { index });
+            }
+        }
+
+        Ok(Gradient(colors, PhantomData, Easings::linear()))
     }
 
     /// Take `n` evenly spaced colors from the gradient, as an iterator. The
@@ -152,6 +216,61 @@ where
         }
     }
 
+    /// Use `easing` for every segment between two control points, instead of
+    /// mixing the colors linearly.
+    ///
+    /// The easing curve can be an [`Easing`](../transition/enum.Easing.html)
+    /// or a closure. It's applied to the factor between the two control
+    /// points around a position, so it affects [`get`](#method.get),
+    /// [`take`](#method.take) and slices alike.
+    ///
+    /// ```
+    /// use palette::transition::Easing;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let black = LinSrgb::new(0.0, 0.0, 0.0);
+    /// let white = LinSrgb::new(1.0, 1.0, 1.0);
+    ///
+    /// let smooth = Gradient::new(vec![black, white]).with_easing(Easing::Smoothstep);
+    /// assert_eq!(smooth.get(0.25), LinSrgb::new(0.15625, 0.15625, 0.15625));
+    ///
+    /// let squared = Gradient::new(vec![black, white]).with_easing(|x: f32| x * x);
+    /// let colors: Vec<_> = squared.take(3).collect();
+    /// assert_eq!(colors[1], LinSrgb::new(0.25, 0.25, 0.25));
+    /// ```
+    pub fn with_easing<E>(mut self, easing: E) -> Self
+    where
+        E: Ease<C::Scalar> + Send + Sync + 'static,
+    {
+        let segments = self.0.as_ref().len().saturating_sub(1);
+        let easing: SharedEase<C::Scalar> = Arc::new(easing);
+        self.2 = Easings(vec![Some(easing); segments]);
+        self
+    }
+
+    /// Use `easing` for the segment between the control points with the
+    /// indices `segment` and `segment + 1`. The other segments are not
+    /// changed.
+    ///
+    /// Panics if there is no such segment.
+    pub fn with_segment_easing<E>(mut self, segment: usize, easing: E) -> Self
+    where
+        E: Ease<C::Scalar> + Send + Sync + 'static,
+    {
+        let segments = self.0.as_ref().len().saturating_sub(1);
+        assert!(
+            segment < segments,
+            "the gradient has no segment {}",
+            segment
+        );
+
+        if self.2 .0.len() < segments {
+            self.2 .0.resize(segments, None);
+        }
+        self.2 .0[segment] = Some(Arc::new(easing));
+        self
+    }
+
     /// Get the limits of this gradient's domain.
     pub fn domain(&self) -> (C::Scalar, C::Scalar) {
         let &(min, _) = self
@@ -168,22 +287,308 @@ where
     }
 }
 
+impl<C, T> Gradient<C, T>
+where
+    C: Mix + Clone,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Create a new gradient by applying `function` to the color of each
+    /// control point. The positions stay the same.
+    ///
+    /// The function is only applied to the control points, so the new
+    /// gradient blends linearly between the results.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Shade};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.5, 0.0),
+    ///     LinSrgb::new(0.0, 0.5, 1.0),
+    /// ]);
+    ///
+    /// let darker = gradient.map(|color| color.darken(0.5));
+    /// assert_eq!(darker.get(0.0), LinSrgb::new(0.5, 0.25, 0.0));
+    /// ```
+    pub fn map<D, F>(&self, mut function: F) -> Gradient<D>
+    where
+        D: Mix<Scalar = C::Scalar> + Clone,
+        F: FnMut(C) -> D,
+    {
+        let points = self
+            .0
+            .as_ref()
+            .iter()
+            .map(|(position, color)| (*position, function(color.clone())))
+            .collect();
+
+        Gradient(points, PhantomData, self.2.clone())
+    }
+
+    /// Create a new gradient where `other` follows after this one. The
+    /// domain of `other` is moved to start where this gradient's domain
+    /// ends, so the new domain is as long as both of them together. The
+    /// segments keep their easing curves.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let warm = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 1.0, 0.0),
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    /// ]);
+    /// let cold = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    ///     LinSrgb::new(0.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// let both = warm.concat(&cold);
+    /// assert_eq!(both.domain(), (0.0, 2.0));
+    /// assert_eq!(both.get(1.5), LinSrgb::new(0.0, 0.5, 1.0));
+    /// ```
+    pub fn concat<U>(&self, other: &Gradient<C, U>) -> Gradient<C>
+    where
+        U: AsRef<[(C::Scalar, C)]>,
+    {
+        let (_, end) = self.domain();
+        let (other_start, _) = other.domain();
+        let offset = end - other_start;
+
+        let points = self
+            .0
+            .as_ref()
+            .iter()
+            .cloned()
+            .chain(
+                other
+                    .0
+                    .as_ref()
+                    .iter()
+                    .map(|(position, color)| (*position + offset, color.clone())),
+            )
+            .collect();
+
+        let segments = self.0.as_ref().len() - 1;
+        Gradient(points, PhantomData, self.2.chain(segments, &other.2))
+    }
+
+    /// Create a new gradient that is this gradient mixed with `other`. A
+    /// `factor` of `0.0` gives this gradient, and `1.0` gives `other`.
+    ///
+    /// The new gradient has the control points of both gradients, and covers
+    /// both of their domains. It's linear between the control points, even
+    /// if the gradients have easing curves.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gray = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    /// let red = Gradient::new(vec![LinSrgb::new(1.0, 0.0, 0.0)]);
+    ///
+    /// let tinted = gray.blend_with(&red, 0.5);
+    /// assert_eq!(tinted.get(0.0), LinSrgb::new(0.5, 0.0, 0.0));
+    /// assert_eq!(tinted.get(1.0), LinSrgb::new(1.0, 0.5, 0.5));
+    /// ```
+    pub fn blend_with<U>(&self, other: &Gradient<C, U>, factor: C::Scalar) -> Gradient<C>
+    where
+        U: AsRef<[(C::Scalar, C)]>,
+    {
+        let mut positions: Vec<C::Scalar> = self
+            .0
+            .as_ref()
+            .iter()
+            .chain(other.0.as_ref())
+            .map(|&(position, _)| position)
+            .collect();
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        positions.dedup();
+
+        let points = positions
+            .into_iter()
+            .map(|position| {
+                let color = self.get(position).mix(&other.get(position), factor);
+                (position, color)
+            })
+            .collect();
+
+        Gradient(points, PhantomData, Easings::linear())
+    }
+}
+
+impl<C, T> Gradient<C, T>
+where
+    C: Mix + Hue + Clone,
+    C::Scalar: FloatComponent + Into<C::Hue>,
+    C::Hue: Into<C::Scalar>,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Get a color from the gradient, with the hue interpolated along
+    /// `path`, like in CSS gradients.
+    ///
+    /// [`get`](#method.get) mixes the colors, which takes the shortest way
+    /// around the hue circle. The other paths can be used to go the long way
+    /// around, or always in the same direction. Achromatic colors take the
+    /// hue of the color they are mixed with, as with `get`.
+    ///
+    /// ```
+    /// use palette::gradient::HueInterpolation;
+    /// use palette::{GetHue, Gradient, Lch};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     Lch::new(60.0, 50.0, 10.0),
+    ///     Lch::new(60.0, 50.0, 350.0),
+    /// ]);
+    ///
+    /// let shorter = gradient.get_with_hue_path(0.5, HueInterpolation::Shorter);
+    /// let longer = gradient.get_with_hue_path(0.5, HueInterpolation::Longer);
+    /// assert_eq!(shorter.get_hue().unwrap(), 0.0);
+    /// assert_eq!(longer.get_hue().unwrap(), 180.0);
+    /// ```
+    pub fn get_with_hue_path(&self, i: C::Scalar, path: HueInterpolation) -> C {
+        let (min_color, max_color, factor) = match self.segment(i) {
+            Ok(segment) => segment,
+            Err(color) => return color.clone(),
+        };
+
+        let mixed = min_color.mix(max_color, factor);
+        match (min_color.get_hue(), max_color.get_hue()) {
+            (Some(min_hue), Some(max_hue)) => {
+                let (min_hue, max_hue) = fix_hues(path, min_hue.into(), max_hue.into());
+                mixed.with_hue(min_hue + factor * (max_hue - min_hue))
+            }
+            _ => mixed,
+        }
+    }
+
+    /// Take `n` evenly spaced colors from the gradient, like
+    /// [`take`](#method.take), with the hue interpolated along `path`.
+    pub fn take_with_hue_path(
+        &self,
+        n: usize,
+        path: HueInterpolation,
+    ) -> impl ExactSizeIterator<Item = C> + DoubleEndedIterator + '_ {
+        let (min, max) = self.domain();
+        let step = if n > 1 {
+            (max - min) / from_f64((n - 1) as f64)
+        } else {
+            C::Scalar::zero()
+        };
+
+        (0..n).map(move |index| self.get_with_hue_path(min + step * from_f64(index as f64), path))
+    }
+}
+
 impl<C: Mix + Clone> Gradient<C> {
+    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0],
+    /// that interpolates in `C`, from colors of another type.
+    ///
+    /// The colors are converted once, when the gradient is created. Panics
+    /// if there are no colors.
+    ///
+    /// ```
+    /// use palette::{FromColor, Gradient, Oklab, Srgb};
+    ///
+    /// let gradient = Gradient::<Oklab>::from_colors(vec![
+    ///     Srgb::new(1.0, 0.0, 0.0),
+    ///     Srgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// let middle = Srgb::from_color(gradient.get(0.5));
+    /// assert!(middle.red > 0.5 && middle.blue > 0.5);
+    /// ```
+    pub fn from_colors<D, I>(colors: I) -> Gradient<C>
+    where
+        I: IntoIterator<Item = D>,
+        C: FromColor<D>,
+        C::Scalar: FromF64,
+    {
+        Self::new(colors.into_iter().map(C::from_color))
+    }
+
     /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0].
     /// There must be at least one color.
+    ///
+    /// Panics if there are no colors. See [`try_new`](#method.try_new) for a
+    /// version that returns an error instead.
     pub fn new<I: IntoIterator<Item = C>>(colors: I) -> Gradient<C>
+    where
+        C::Scalar: FromF64,
+    {
+        match Self::try_new(colors) {
+            Ok(gradient) => gradient,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0],
+    /// or an error if there are no colors.
+    pub fn try_new<I: IntoIterator<Item = C>>(colors: I) -> Result<Gradient<C>, GradientError>
     where
         C::Scalar: FromF64,
     {
         let mut points: Vec<_> = colors.into_iter().map(|c| (C::Scalar::zero(), c)).collect();
-        assert!(!points.is_empty());
+        if points.is_empty() {
+            return Err(GradientError::Empty);
+        }
         let step_size = C::Scalar::one() / from_f64(max(points.len() - 1, 1) as f64);
 
         for (i, &mut (ref mut p, _)) in points.iter_mut().enumerate() {
             *p = from_f64::<C::Scalar>(i as f64) * step_size;
         }
 
-        Gradient(points, PhantomData)
+        Ok(Gradient(points, PhantomData, Easings::linear()))
+    }
+}
+
+type SharedEase<T> = Arc<dyn Ease<T> + Send + Sync>;
+
+/// The easing curves of the segments of a gradient. Segments without a curve,
+/// including any after the end of the list, are linear.
+pub(crate) struct Easings<T>(Vec<Option<SharedEase<T>>>);
+
+impl<T> Easings<T> {
+    pub(crate) const fn linear() -> Self {
+        Easings(Vec::new())
+    }
+
+    fn ease(&self, segment: usize, factor: T) -> T {
+        match self.0.get(segment) {
+            Some(Some(easing)) => easing.ease(factor),
+            _ => factor,
+        }
+    }
+
+    /// The curves of `segments` segments, followed by a linear segment and
+    /// the curves of `other`.
+    fn chain(&self, segments: usize, other: &Easings<T>) -> Easings<T> {
+        if self.0.is_empty() && other.0.is_empty() {
+            return Easings::linear();
+        }
+
+        let mut easings = self.0.clone();
+        easings.resize(segments + 1, None);
+        easings.extend(other.0.iter().cloned());
+        Easings(easings)
+    }
+}
+
+impl<T> Clone for Easings<T> {
+    fn clone(&self) -> Self {
+        Easings(self.0.clone())
+    }
+}
+
+impl<T> fmt::Debug for Easings<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.0
+                    .iter()
+                    .map(|easing| if easing.is_some() { "eased" } else { "linear" }),
+            )
+            .finish()
     }
 }
 
@@ -529,7 +934,112 @@ where
 #[cfg(test)]
 mod test {
     use super::{Gradient, Range};
-    use crate::LinSrgb;
+    use crate::error::GradientError;
+    use crate::white_point::D65;
+    use crate::{FromColor, LinLuma, LinSrgb, Mix};
+
+    #[test]
+    fn segment_easing() {
+        use crate::transition::Easing;
+
+        let gradient = Gradient::new(vec![
+            LinLuma::<D65, f64>::new(0.0),
+            LinLuma::new(1.0),
+            LinLuma::new(0.0),
+        ])
+        .with_segment_easing(1, |x: f64| x * x);
+
+        assert_relative_eq!(gradient.get(0.25).luma, 0.5);
+        assert_relative_eq!(gradient.get(0.75).luma, 0.75);
+        let taken: Vec<_> = gradient.take(5).map(|color| color.luma).collect();
+        assert_relative_eq!(&taken[..], &[0.0, 0.5, 1.0, 0.75, 0.0][..]);
+        assert_relative_eq!(gradient.slice(0.5..).get(0.75).luma, 0.75);
+
+        let both = gradient.concat(&gradient.map(|color| color).with_easing(Easing::Smoothstep));
+        assert_relative_eq!(both.get(0.25).luma, 0.5);
+        assert_relative_eq!(both.get(0.75).luma, 0.75);
+        assert_relative_eq!(both.get(1.125).luma, 0.15625);
+        assert_relative_eq!(both.get(1.875).luma, 0.15625);
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_segment_easing() {
+        Gradient::new(vec![LinLuma::<D65, f64>::new(0.0), LinLuma::new(1.0)])
+            .with_segment_easing(1, |x: f64| x);
+    }
+
+    #[test]
+    fn hue_paths() {
+        use super::HueInterpolation;
+        use crate::{GetHue, Lch};
+
+        let gradient = Gradient::new(vec![
+            Lch::<D65, f64>::new(50.0, 40.0, 30.0),
+            Lch::new(50.0, 40.0, 90.0),
+        ]);
+        let hue = |path| {
+            let color = gradient.get_with_hue_path(0.25, path);
+            color.get_hue().unwrap().to_positive_degrees()
+        };
+
+        assert_relative_eq!(hue(HueInterpolation::Shorter), 45.0);
+        assert_relative_eq!(hue(HueInterpolation::Increasing), 45.0);
+        assert_relative_eq!(hue(HueInterpolation::Longer), 315.0);
+        assert_relative_eq!(hue(HueInterpolation::Decreasing), 315.0);
+
+        let taken: Vec<_> = gradient
+            .take_with_hue_path(3, HueInterpolation::Longer)
+            .map(|color| color.get_hue().unwrap().to_positive_degrees())
+            .collect();
+        assert_relative_eq!(taken[0], 30.0);
+        assert_relative_eq!(taken[1], 240.0);
+        assert_relative_eq!(taken[2], 90.0);
+
+        let gray = Gradient::new(vec![
+            Lch::<D65, f64>::new(50.0, 0.0, 0.0),
+            Lch::new(50.0, 40.0, 90.0),
+        ]);
+        let color = gray.get_with_hue_path(0.5, HueInterpolation::Longer);
+        assert_relative_eq!(color.get_hue().unwrap().to_positive_degrees(), 90.0);
+    }
+
+    #[test]
+    fn from_colors() {
+        use crate::{Lch, Srgb};
+
+        let gradient = Gradient::<Lch<D65, f64>>::from_colors(vec![
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        assert_relative_eq!(
+            Srgb::from_color(gradient.get(0.0)),
+            Srgb::new(1.0, 0.0, 0.0),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            gradient.get(0.5),
+            Lch::from_color(Srgb::new(1.0, 0.0, 0.0))
+                .mix(&Lch::from_color(Srgb::new(0.0, 0.0, 1.0)), 0.5),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn invalid_stops() {
+        let black = LinSrgb::new(0.0f64, 0.0, 0.0);
+
+        assert_eq!(
+            Gradient::<LinSrgb<f64>>::try_new(vec![]).unwrap_err(),
+            GradientError::Empty
+        );
+        assert_eq!(
+            Gradient::try_with_domain(vec![(0.0, black), (f64::NAN, black)]).unwrap_err(),
+            GradientError::NonFinitePosition { index: 1 }
+        );
+        assert!(Gradient::try_with_domain(vec![(0.0, black), (0.0, black)]).is_ok());
+    }
 
     #[test]
     fn range_clamp() {
@@ -598,6 +1108,68 @@ mod test {
         }
     }
 
+    #[test]
+    fn map() {
+        let g = Gradient::with_domain(vec![
+            (1.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (3.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ]);
+        let mapped = g.map(LinLuma::<D65, f64>::from_color);
+
+        assert_eq!(mapped.domain(), (1.0, 3.0));
+        assert_relative_eq!(
+            mapped.get(1.0),
+            LinLuma::<D65, f64>::from_color(LinSrgb::new(1.0, 0.0, 0.0))
+        );
+        assert_relative_eq!(
+            mapped.get(3.0),
+            LinLuma::<D65, f64>::from_color(LinSrgb::new(0.0, 0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn concat() {
+        let g1 = Gradient::with_domain(vec![
+            (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (1.0, LinSrgb::new(0.0, 1.0, 0.0)),
+        ]);
+        let g2 = Gradient::with_domain(vec![
+            (-1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+            (0.0, LinSrgb::new(1.0, 1.0, 1.0)),
+        ]);
+        let g = g1.concat(&g2);
+
+        assert_eq!(g.domain(), (0.0, 2.0));
+        assert_relative_eq!(g.get(0.5), LinSrgb::new(0.5, 0.5, 0.0));
+        assert_relative_eq!(g.get(1.5), LinSrgb::new(0.5, 0.5, 1.0));
+        assert_relative_eq!(g.get(2.0), LinSrgb::new(1.0, 1.0, 1.0));
+
+        // The end of `g1` and the start of `g2` meet at 1.0. The colors
+        // before it blend towards `g1`'s end color.
+        assert_relative_eq!(g.get(0.99), LinSrgb::new(0.01, 0.99, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn blend_with() {
+        let g1 = Gradient::with_domain(vec![
+            (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (1.0, LinSrgb::new(0.0, 1.0, 0.0)),
+        ]);
+        let g2 = Gradient::with_domain(vec![
+            (0.5, LinSrgb::new(0.0, 0.0, 1.0)),
+            (2.0, LinSrgb::new(0.0, 0.0, 0.0)),
+        ]);
+
+        let blended = g1.blend_with(&g2, 0.25);
+        assert_eq!(blended.domain(), (0.0, 2.0));
+        for &position in &[0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0] {
+            let expected = g1.get(position).mix(&g2.get(position), 0.25);
+            assert_relative_eq!(blended.get(position), expected, epsilon = 1e-12);
+        }
+
+        assert_relative_eq!(g1.blend_with(&g2, 0.0).get(0.3), g1.get(0.3));
+    }
+
     #[test]
     fn inclusive_take() {
         let g = Gradient::new(vec![
@@ -0,0 +1,156 @@
+//! Bézier curves through color spaces.
+//!
+//! A [`Gradient`](../struct.Gradient.html) blends linearly between its
+//! colors, which gives visible corners where the direction changes at each
+//! control point. A [`Bezier`](struct.Bezier.html) instead uses the colors as
+//! the control points of a Bézier curve. The curve starts at the first color
+//! and ends at the last, and is pulled towards the colors in between without
+//! passing through them. The result is a smooth ramp, which is popular for
+//! sequential color scales in data visualization.
+//!
+//! The curve is as smooth as the space it's made in, so a perceptually
+//! uniform space, such as [`Lab`](../../struct.Lab.html) or
+//! [`Oklab`](../../struct.Oklab.html), gives ramps with evenly changing
+//! lightness:
+//!
+//! ```
+//! use palette::gradient::bezier::Bezier;
+//! use palette::{FromColor, Oklab, Srgb};
+//!
+//! let ramp = Bezier::new(
+//!     [
+//!         Srgb::new(1.0, 1.0, 0.8),
+//!         Srgb::new(1.0, 0.4, 0.2),
+//!         Srgb::new(0.3, 0.0, 0.4),
+//!     ]
+//!     .iter()
+//!     .map(|&color| Oklab::from_color(color)),
+//! );
+//!
+//! let middle = Srgb::from_color(ramp.get(0.5));
+//! let colors: Vec<Srgb> = ramp
+//!     .to_gradient(16)
+//!     .take(5)
+//!     .map(Srgb::from_color)
+//!     .collect();
+//! ```
+
+use num_traits::{One, Zero};
+
+use crate::{clamp, from_f64, FloatComponent, Gradient, Mix};
+
+/// A Bézier curve with colors as control points.
+///
+/// See the [module documentation](index.html) for more details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bezier<C> {
+    colors: Vec<C>,
+}
+
+impl<C> Bezier<C>
+where
+    C: Mix + Clone,
+    C::Scalar: FloatComponent,
+{
+    /// Create a curve from its control points. The curve starts at the first
+    /// color and ends at the last.
+    ///
+    /// Panics if there are no colors.
+    pub fn new<I: IntoIterator<Item = C>>(colors: I) -> Self {
+        let colors: Vec<_> = colors.into_iter().collect();
+        assert!(
+            !colors.is_empty(),
+            "a Bezier curve must contain at least one color"
+        );
+
+        Bezier { colors }
+    }
+
+    /// The control points of the curve.
+    pub fn colors(&self) -> &[C] {
+        &self.colors
+    }
+
+    /// Get the color at `position`, from `0.0` at the start of the curve to
+    /// `1.0` at the end. Positions outside that range are clamped.
+    pub fn get(&self, position: C::Scalar) -> C {
+        let position = clamp(position, C::Scalar::zero(), C::Scalar::one());
+
+        // De Casteljau's algorithm, which mixes each pair of neighboring
+        // colors until only one is left.
+        let mut colors = self.colors.clone();
+        for length in (1..colors.len()).rev() {
+            for index in 0..length {
+                colors[index] = colors[index].mix(&colors[index + 1], position);
+            }
+        }
+
+        colors.swap_remove(0)
+    }
+
+    /// Create a [`Gradient`](../struct.Gradient.html) with `samples` evenly
+    /// spaced colors along the curve, including its ends.
+    ///
+    /// Panics if `samples` is less than `2`.
+    pub fn to_gradient(&self, samples: usize) -> Gradient<C> {
+        assert!(samples >= 2, "a Bezier curve needs at least two samples");
+
+        let last: C::Scalar = from_f64((samples - 1) as f64);
+        Gradient::new((0..samples).map(|i| self.get(from_f64::<C::Scalar>(i as f64) / last)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Bezier;
+    use crate::{LinSrgb, Mix};
+
+    #[test]
+    fn ends() {
+        let start = LinSrgb::new(1.0f64, 0.5, 0.0);
+        let end = LinSrgb::new(0.0, 0.2, 1.0);
+        let curve = Bezier::new(vec![start, LinSrgb::new(0.0, 1.0, 0.0), end]);
+
+        assert_eq!(curve.get(0.0), start);
+        assert_relative_eq!(curve.get(1.0), end, epsilon = 1e-12);
+        assert_eq!(curve.get(-1.0), start);
+        assert_relative_eq!(curve.get(2.0), end, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn quadratic() {
+        let colors = vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 0.0),
+        ];
+        let curve = Bezier::new(colors.clone());
+
+        // B(t) = (1 - t)^2 P0 + 2t(1 - t) P1 + t^2 P2
+        let t = 0.25;
+        let expected =
+            colors[0] * (1.0 - t) * (1.0 - t) + colors[1] * 2.0 * t * (1.0 - t) + colors[2] * t * t;
+        assert_relative_eq!(curve.get(t), expected, epsilon = 1e-12);
+
+        // Two colors make a straight line.
+        let line = Bezier::new(vec![colors[0], colors[2]]);
+        assert_relative_eq!(line.get(0.3), colors[0].mix(&colors[2], 0.3));
+
+        let single = Bezier::new(vec![colors[1]]);
+        assert_eq!(single.get(0.7), colors[1]);
+    }
+
+    #[test]
+    fn gradient() {
+        let curve = Bezier::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 0.0),
+        ]);
+        let gradient = curve.to_gradient(5);
+
+        for (i, color) in gradient.take(5).enumerate() {
+            assert_relative_eq!(color, curve.get(i as f64 / 4.0), epsilon = 1e-12);
+        }
+    }
+}
@@ -0,0 +1,506 @@
+//! Reading GIMP gradient files.
+//!
+//! GIMP stores its gradients in `.ggr` files, which are made of segments with
+//! a color at each end. The colors are blended with one of a few functions,
+//! around a midpoint that can be moved within the segment, and either in RGB
+//! or around the HSV hue circle. A [`GimpGradient`](struct.GimpGradient.html)
+//! keeps the segments as they are in the file, to give the same colors as
+//! GIMP, and can be turned into a [`Gradient`](../struct.Gradient.html) for
+//! everything else:
+//!
+//! ```
+//! use palette::gradient::gimp::GimpGradient;
+//! use palette::{LinSrgba, Srgba};
+//!
+//! let source = "GIMP Gradient
+//! Name: Fade to red
+//! 1
+//! 0.0 0.5 1.0 1.0 1.0 1.0 1.0 1.0 0.0 0.0 0.5 0 0
+//! ";
+//!
+//! let ggr: GimpGradient<f64> = source.parse().unwrap();
+//! assert_eq!(ggr.name.as_deref(), Some("Fade to red"));
+//! assert_eq!(ggr.get(0.5), Srgba::new(1.0, 0.5, 0.5, 0.75));
+//!
+//! let gradient = ggr.to_gradient(16);
+//! let middle: LinSrgba<f64> = gradient.get(0.5);
+//! ```
+//!
+//! The colors in the files are sRGB, and GIMP blends them without decoding
+//! them first. `to_gradient` samples each segment as GIMP would, and the
+//! gradient blends linearly between the samples.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Srgb as SrgbStandard;
+use crate::math::{ln, powf, sin};
+use crate::{clamp, from_f64, FloatComponent, Gradient, Hsv, LinSrgba, Srgb, Srgba};
+
+/// A gradient from a GIMP `.ggr` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GimpGradient<T: FloatComponent = f32> {
+    /// The name of the gradient, if the file has one.
+    pub name: Option<String>,
+    /// The segments of the gradient, ordered by their positions.
+    pub segments: Vec<Segment<T>>,
+}
+
+/// A segment of a [`GimpGradient`](struct.GimpGradient.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment<T: FloatComponent = f32> {
+    /// The position of the left end of the segment, in `0.0..=1.0`.
+    pub left: T,
+    /// The position of the midpoint of the segment, where the blend is half
+    /// way between the colors.
+    pub middle: T,
+    /// The position of the right end of the segment, in `0.0..=1.0`.
+    pub right: T,
+    /// The color at the left end of the segment.
+    pub left_color: Srgba<T>,
+    /// The color at the right end of the segment.
+    pub right_color: Srgba<T>,
+    /// The function that blends the colors.
+    pub blending: Blending,
+    /// The color space the colors are blended in.
+    pub coloring: Coloring,
+}
+
+/// The blending function of a gradient [`Segment`](struct.Segment.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Blending {
+    /// Straight lines from the ends to the midpoint.
+    Linear,
+    /// A power curve through the midpoint.
+    Curved,
+    /// A sine curve, which eases in and out of the ends.
+    Sine,
+    /// A quarter circle that starts steep.
+    SphereIncreasing,
+    /// A quarter circle that ends steep.
+    SphereDecreasing,
+    /// A hard step from one color to the other at the midpoint.
+    Step,
+}
+
+/// How the colors of a gradient [`Segment`](struct.Segment.html) are blended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coloring {
+    /// Each of the red, green and blue components is blended separately.
+    Rgb,
+    /// The colors are blended in HSV, with the hue going counterclockwise.
+    HsvCounterClockwise,
+    /// The colors are blended in HSV, with the hue going clockwise.
+    HsvClockwise,
+}
+
+impl<T: FloatComponent> GimpGradient<T> {
+    /// Get the color at `position`, in `0.0..=1.0`, the same way as GIMP
+    /// does. The colors of the ends are used for positions outside the
+    /// segments.
+    ///
+    /// Panics if there are no segments.
+    pub fn get(&self, position: T) -> Srgba<T> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| position <= segment.right)
+            .or_else(|| self.segments.last())
+            .expect("a GimpGradient must contain at least one segment");
+
+        segment.get(position)
+    }
+
+    /// Create a [`Gradient`](../struct.Gradient.html) with `samples` colors
+    /// from each segment, including its ends. Steps are kept sharp, no matter
+    /// the number of samples.
+    ///
+    /// Panics if there are no segments or `samples` is less than `2`.
+    pub fn to_gradient(&self, samples: usize) -> Gradient<LinSrgba<T>> {
+        assert!(samples >= 2, "each segment needs at least two samples");
+        assert!(
+            !self.segments.is_empty(),
+            "a GimpGradient must contain at least one segment"
+        );
+
+        let mut stops = Vec::with_capacity(self.segments.len() * samples);
+        for segment in &self.segments {
+            if segment.blending == Blending::Step {
+                stops.push((segment.left, segment.left_color.into_linear()));
+                stops.push((segment.middle, segment.left_color.into_linear()));
+                stops.push((segment.middle, segment.right_color.into_linear()));
+                stops.push((segment.right, segment.right_color.into_linear()));
+                continue;
+            }
+
+            let length = segment.right - segment.left;
+            for i in 0..samples {
+                let position =
+                    segment.left + length * from_f64(i as f64) / from_f64((samples - 1) as f64);
+                stops.push((position, segment.get(position).into_linear()));
+            }
+        }
+
+        Gradient::with_domain(stops)
+    }
+}
+
+impl<T: FloatComponent> Segment<T> {
+    /// Get the color at `position`, which is expected to be within the
+    /// segment.
+    pub fn get(&self, position: T) -> Srgba<T> {
+        let epsilon = from_f64::<T>(1e-10);
+        let length = self.right - self.left;
+        let (middle, position) = if length < epsilon {
+            (from_f64(0.5), from_f64(0.5))
+        } else {
+            (
+                (self.middle - self.left) / length,
+                clamp((position - self.left) / length, T::zero(), T::one()),
+            )
+        };
+
+        let factor = match self.blending {
+            Blending::Linear => linear_factor(middle, position),
+            Blending::Curved => {
+                let middle = middle.max(epsilon);
+                powf(position, ln(from_f64::<T>(0.5)) / ln(middle))
+            }
+            Blending::Sine => {
+                let factor = linear_factor(middle, position);
+                (sin(from_f64::<T>(core::f64::consts::PI) * (factor - from_f64(0.5))) + T::one())
+                    / from_f64(2.0)
+            }
+            Blending::SphereIncreasing => {
+                let factor = linear_factor(middle, position) - T::one();
+                (T::one() - factor * factor).sqrt()
+            }
+            Blending::SphereDecreasing => {
+                let factor = linear_factor(middle, position);
+                T::one() - (T::one() - factor * factor).sqrt()
+            }
+            Blending::Step => {
+                if position >= middle {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            }
+        };
+
+        let (left, right) = (self.left_color, self.right_color);
+        let alpha = left.alpha + (right.alpha - left.alpha) * factor;
+        let color = match self.coloring {
+            Coloring::Rgb => Srgb::new(
+                left.red + (right.red - left.red) * factor,
+                left.green + (right.green - left.green) * factor,
+                left.blue + (right.blue - left.blue) * factor,
+            ),
+            Coloring::HsvCounterClockwise | Coloring::HsvClockwise => {
+                let left = Hsv::<SrgbStandard, T>::from_color_unclamped(left.color);
+                let right = Hsv::<SrgbStandard, T>::from_color_unclamped(right.color);
+                let full_turn = from_f64::<T>(360.0);
+                let (left_hue, right_hue) = (
+                    left.hue.to_positive_degrees(),
+                    right.hue.to_positive_degrees(),
+                );
+
+                let hue = if self.coloring == Coloring::HsvCounterClockwise {
+                    let distance = if left_hue < right_hue {
+                        right_hue - left_hue
+                    } else {
+                        full_turn - (left_hue - right_hue)
+                    };
+                    left_hue + distance * factor
+                } else {
+                    let distance = if right_hue < left_hue {
+                        left_hue - right_hue
+                    } else {
+                        full_turn - (right_hue - left_hue)
+                    };
+                    left_hue - distance * factor
+                };
+
+                Srgb::from_color_unclamped(Hsv::<SrgbStandard, T>::new(
+                    hue,
+                    left.saturation + (right.saturation - left.saturation) * factor,
+                    left.value + (right.value - left.value) * factor,
+                ))
+            }
+        };
+
+        Srgba::from_components((color.red, color.green, color.blue, alpha))
+    }
+}
+
+/// The blend factor at `position`, with straight lines through the point
+/// `(middle, 0.5)`.
+fn linear_factor<T: FloatComponent>(middle: T, position: T) -> T {
+    let epsilon = from_f64::<T>(1e-10);
+    let half = from_f64::<T>(0.5);
+
+    if position <= middle {
+        if middle < epsilon {
+            T::zero()
+        } else {
+            half * position / middle
+        }
+    } else {
+        let position = position - middle;
+        let middle = T::one() - middle;
+        if middle < epsilon {
+            T::one()
+        } else {
+            half + half * position / middle
+        }
+    }
+}
+
+impl<T: FloatComponent + FromStr> FromStr for GimpGradient<T> {
+    type Err = ParseGgrError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut lines = source
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|&(_, line)| !line.is_empty());
+
+        match lines.next() {
+            Some((_, "GIMP Gradient")) => {}
+            _ => return Err(ParseGgrError::MissingHeader),
+        }
+
+        let (mut line_number, mut line) = lines.next().ok_or(ParseGgrError::MissingSegmentCount)?;
+        let mut name = None;
+        if let Some(value) = line.strip_prefix("Name:") {
+            name = Some(value.trim().to_owned());
+            let next = lines.next().ok_or(ParseGgrError::MissingSegmentCount)?;
+            line_number = next.0;
+            line = next.1;
+        }
+
+        let count: usize = line
+            .parse()
+            .map_err(|_| ParseGgrError::InvalidSegment { line: line_number })?;
+
+        let segments = lines
+            .take(count)
+            .map(|(line_number, line)| parse_segment(line_number, line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if segments.len() != count || count == 0 {
+            return Err(ParseGgrError::WrongSegmentCount {
+                expected: count,
+                found: segments.len(),
+            });
+        }
+
+        Ok(GimpGradient { name, segments })
+    }
+}
+
+fn parse_segment<T: FloatComponent + FromStr>(
+    line_number: usize,
+    line: &str,
+) -> Result<Segment<T>, ParseGgrError> {
+    let error = ParseGgrError::InvalidSegment { line: line_number };
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 13 {
+        return Err(error);
+    }
+
+    let mut numbers = [T::zero(); 11];
+    for (number, field) in numbers.iter_mut().zip(&fields) {
+        *number = field.parse().map_err(|_| error)?;
+    }
+    let [left, middle, right, r0, g0, b0, a0, r1, g1, b1, a1] = numbers;
+
+    let blending = match fields[11] {
+        "0" => Blending::Linear,
+        "1" => Blending::Curved,
+        "2" => Blending::Sine,
+        "3" => Blending::SphereIncreasing,
+        "4" => Blending::SphereDecreasing,
+        "5" => Blending::Step,
+        _ => return Err(error),
+    };
+    let coloring = match fields[12] {
+        "0" => Coloring::Rgb,
+        "1" => Coloring::HsvCounterClockwise,
+        "2" => Coloring::HsvClockwise,
+        _ => return Err(error),
+    };
+
+    Ok(Segment {
+        left,
+        middle,
+        right,
+        left_color: Srgba::new(r0, g0, b0, a0),
+        right_color: Srgba::new(r1, g1, b1, a1),
+        blending,
+        coloring,
+    })
+}
+
+/// The error type for parsing GIMP gradient files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseGgrError {
+    /// The file doesn't start with `GIMP Gradient`.
+    MissingHeader,
+    /// The file ends before the number of segments.
+    MissingSegmentCount,
+    /// The file has fewer segments than it says, or none at all.
+    WrongSegmentCount {
+        /// The number of segments the file says it has.
+        expected: usize,
+        /// The number of segments that were found.
+        found: usize,
+    },
+    /// A line couldn't be parsed as a segment, or as the number of segments.
+    InvalidSegment {
+        /// The line number, starting at 1.
+        line: usize,
+    },
+}
+
+impl fmt::Display for ParseGgrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseGgrError::MissingHeader => write!(f, "missing \"GIMP Gradient\" header"),
+            ParseGgrError::MissingSegmentCount => write!(f, "missing number of segments"),
+            ParseGgrError::WrongSegmentCount { expected, found } => {
+                write!(f, "expected {} segments, found {}", expected, found)
+            }
+            ParseGgrError::InvalidSegment { line } => write!(f, "invalid segment on line {}", line),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseGgrError {}
+
+#[cfg(test)]
+mod test {
+    use super::{Blending, Coloring, GimpGradient, ParseGgrError};
+    use crate::{LinSrgba, Srgba};
+
+    const RAINBOW: &str = "GIMP Gradient
+Name: Test
+3
+0.000000 0.250000 0.500000 1.000000 0.000000 0.000000 1.000000 0.000000 0.000000 1.000000 1.000000 0 1
+0.500000 0.600000 0.750000 0.000000 0.000000 0.000000 1.000000 1.000000 1.000000 1.000000 0.000000 5 0 0 0
+0.750000 0.800000 1.000000 0.000000 0.000000 0.000000 1.000000 1.000000 1.000000 1.000000 1.000000 1 0
+";
+
+    fn parse(source: &str) -> Result<GimpGradient<f64>, ParseGgrError> {
+        source.parse()
+    }
+
+    #[test]
+    fn parse_segments() {
+        let gradient = parse(RAINBOW).unwrap();
+        assert_eq!(gradient.name.as_deref(), Some("Test"));
+        assert_eq!(gradient.segments.len(), 3);
+
+        let segment = &gradient.segments[1];
+        assert_relative_eq!(segment.left, 0.5);
+        assert_relative_eq!(segment.middle, 0.6);
+        assert_relative_eq!(segment.right, 0.75);
+        assert_relative_eq!(segment.right_color, Srgba::new(1.0, 1.0, 1.0, 0.0));
+        assert_eq!(segment.blending, Blending::Step);
+        assert_eq!(segment.coloring, Coloring::Rgb);
+        assert_eq!(gradient.segments[0].coloring, Coloring::HsvCounterClockwise);
+
+        let unnamed = parse("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1 1 1 0 0\n").unwrap();
+        assert_eq!(unnamed.name, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse("GIMP Palette\n"), Err(ParseGgrError::MissingHeader));
+        assert_eq!(
+            parse("GIMP Gradient\nName: A\n"),
+            Err(ParseGgrError::MissingSegmentCount)
+        );
+        assert_eq!(
+            parse("GIMP Gradient\n2\n0 0.5 1 0 0 0 1 1 1 1 1 0 0\n"),
+            Err(ParseGgrError::WrongSegmentCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            parse("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1 1 1 9 0\n"),
+            Err(ParseGgrError::InvalidSegment { line: 3 })
+        );
+        assert_eq!(
+            parse("GIMP Gradient\n\nx\n"),
+            Err(ParseGgrError::InvalidSegment { line: 3 })
+        );
+    }
+
+    #[test]
+    fn blending() {
+        let gradient = parse(RAINBOW).unwrap();
+
+        // Red to blue, counterclockwise through green.
+        assert_relative_eq!(gradient.get(0.0), Srgba::new(1.0, 0.0, 0.0, 1.0));
+        assert_relative_eq!(gradient.get(0.25), Srgba::new(0.0, 1.0, 0.0, 1.0));
+        assert_relative_eq!(gradient.get(0.5), Srgba::new(0.0, 0.0, 1.0, 1.0));
+
+        // The step.
+        assert_relative_eq!(gradient.get(0.59), Srgba::new(0.0, 0.0, 0.0, 1.0));
+        assert_relative_eq!(gradient.get(0.6), Srgba::new(1.0, 1.0, 1.0, 0.0));
+
+        // The curved segment passes 0.5 at its midpoint.
+        assert_relative_eq!(gradient.get(0.8), Srgba::new(0.5, 0.5, 0.5, 1.0));
+        assert_relative_eq!(gradient.get(1.0), Srgba::new(1.0, 1.0, 1.0, 1.0));
+        assert_relative_eq!(gradient.get(2.0), Srgba::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn blending_functions() {
+        let mut gradient = parse("GIMP Gradient\n1\n0 0.5 1 0 0 0 1 1 1 1 1 0 0\n").unwrap();
+        let expected = [
+            (Blending::Linear, 0.25, 0.5),
+            (Blending::Curved, 0.25, 0.5),
+            (Blending::Sine, 0.1464466094067262, 0.5),
+            (
+                Blending::SphereIncreasing,
+                0.6614378277661477,
+                0.8660254037844386,
+            ),
+            (
+                Blending::SphereDecreasing,
+                0.031754163448145745,
+                0.1339745962155614,
+            ),
+            (Blending::Step, 0.0, 1.0),
+        ];
+
+        for &(blending, quarter, middle) in &expected {
+            gradient.segments[0].blending = blending;
+            assert_relative_eq!(gradient.get(0.25).red, quarter, epsilon = 1e-12);
+            assert_relative_eq!(gradient.get(0.5).red, middle, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn hsv_clockwise() {
+        let gradient = parse("GIMP Gradient\n1\n0 0.5 1 1 0 0 1 0 0 1 1 0 2\n").unwrap();
+        assert_relative_eq!(gradient.get(0.5), Srgba::new(1.0, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn to_gradient() {
+        let ggr = parse(RAINBOW).unwrap();
+        let gradient = ggr.to_gradient(32);
+
+        for &position in &[0.0, 0.1, 0.25, 0.45, 0.55, 0.65, 0.8, 0.9, 1.0] {
+            let expected: LinSrgba<f64> = ggr.get(position).into_linear();
+            assert_relative_eq!(gradient.get(position), expected, epsilon = 2e-2);
+        }
+    }
+}
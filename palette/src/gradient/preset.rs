@@ -0,0 +1,41 @@
+//! Gradients of the perceptually uniform colormaps in the
+//! [`colormap`](../../colormap/index.html) module.
+//!
+//! Each function creates a gradient with the 256 colors of the colormap, in
+//! linear sRGB. Use the constants in `colormap` for sampling without a
+//! gradient, or without the standard library.
+//!
+//! ```
+//! use palette::gradient::preset;
+//!
+//! let colors: Vec<_> = preset::viridis().take(10).collect();
+//! assert_eq!(colors.len(), 10);
+//! ```
+
+use crate::colormap;
+use crate::{Gradient, LinSrgb};
+
+/// The viridis colormap, from dark blue, through green, to yellow.
+pub fn viridis() -> Gradient<LinSrgb> {
+    colormap::VIRIDIS.to_gradient()
+}
+
+/// The inferno colormap, from black, through red, to light yellow.
+pub fn inferno() -> Gradient<LinSrgb> {
+    colormap::INFERNO.to_gradient()
+}
+
+/// The magma colormap, from black, through purple and red, to light yellow.
+pub fn magma() -> Gradient<LinSrgb> {
+    colormap::MAGMA.to_gradient()
+}
+
+/// The plasma colormap, from dark blue, through pink, to yellow.
+pub fn plasma() -> Gradient<LinSrgb> {
+    colormap::PLASMA.to_gradient()
+}
+
+/// The Turbo colormap, a rainbow from dark blue, through green, to dark red.
+pub fn turbo() -> Gradient<LinSrgb> {
+    colormap::TURBO.to_gradient()
+}
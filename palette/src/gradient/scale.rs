@@ -0,0 +1,152 @@
+//! Color scales with evenly changing lightness.
+//!
+//! A scale that blends between a few key colors often gets uneven lightness,
+//! since the keys rarely have evenly spaced lightness values. Steps near a
+//! key may look almost the same, and the scale may even get darker and
+//! lighter again, which is misleading in a colormap and makes it unreadable
+//! in gray scale. A [`LightnessScale`](struct.LightnessScale.html) follows
+//! the key colors in CIE L\*a\*b\*, but replaces the lightness with one that
+//! goes evenly from the first key to the last, like the `correctLightness`
+//! option in chroma.js.
+//!
+//! ```
+//! use palette::gradient::scale::LightnessScale;
+//! use palette::{FromColor, Lab, Srgb};
+//!
+//! let scale = LightnessScale::new(vec![
+//!     Lab::from_color(Srgb::new(1.0, 1.0, 0.6)),
+//!     Lab::from_color(Srgb::new(0.9, 0.3, 0.3)),
+//!     Lab::from_color(Srgb::new(0.1, 0.1, 0.4)),
+//! ]);
+//!
+//! let colors: Vec<Srgb> = scale.colors(9);
+//! let lightness: Vec<f32> = scale.colors::<Lab>(9).iter().map(|c| c.l).collect();
+//! assert!(lightness.windows(2).all(|pair| pair[1] < pair[0]));
+//! ```
+
+use crate::convert::FromColorUnclamped;
+use crate::math::powf;
+use crate::white_point::D65;
+use crate::{clamp, from_f64, FloatComponent, FromColor, Gradient, Lab};
+
+/// A scale that follows a set of key colors, with evenly changing lightness.
+///
+/// See the [module documentation](index.html) for more details.
+#[derive(Clone, Debug)]
+pub struct LightnessScale<T: FloatComponent = f32> {
+    keys: Gradient<Lab<D65, T>>,
+    start: T,
+    end: T,
+    gamma: T,
+}
+
+impl<T: FloatComponent> LightnessScale<T> {
+    /// Create a scale through the evenly spaced `keys`. The lightness goes
+    /// from the lightness of the first key to the lightness of the last.
+    ///
+    /// Panics if there are no keys.
+    pub fn new<I: IntoIterator<Item = Lab<D65, T>>>(keys: I) -> Self {
+        let keys: Vec<_> = keys.into_iter().collect();
+        let (start, end) = match (keys.first(), keys.last()) {
+            (Some(first), Some(last)) => (first.l, last.l),
+            _ => panic!("a LightnessScale must contain at least one color"),
+        };
+
+        LightnessScale {
+            keys: Gradient::new(keys),
+            start,
+            end,
+            gamma: T::one(),
+        }
+    }
+
+    /// Shape the scale with `gamma`. Positions are raised to the power of
+    /// `gamma`, so values above `1.0` spend more of the scale near the first
+    /// key, and values below `1.0` spend more of it near the last. The
+    /// default is `1.0`.
+    pub fn with_gamma(mut self, gamma: T) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Get the color at `position`, from `0.0` at the first key to `1.0` at
+    /// the last. Positions outside that range are clamped.
+    pub fn get(&self, position: T) -> Lab<D65, T> {
+        let position = powf(clamp(position, T::zero(), T::one()), self.gamma);
+
+        let mut color = self.keys.get(position);
+        color.l = self.start + (self.end - self.start) * position;
+        color
+    }
+
+    /// Create `steps` evenly spaced colors from the scale, including both
+    /// ends. A single step is the first key.
+    pub fn colors<C>(&self, steps: usize) -> Vec<C>
+    where
+        C: FromColor<Lab<D65, T>>,
+    {
+        let last: T = from_f64(steps.saturating_sub(1).max(1) as f64);
+        (0..steps)
+            .map(|i| C::from_color(self.get(from_f64::<T>(i as f64) / last)))
+            .collect()
+    }
+
+    /// Create `steps` evenly spaced colors from the scale, without clamping
+    /// the colors that are outside the gamut of `C`.
+    pub fn colors_unclamped<C>(&self, steps: usize) -> Vec<C>
+    where
+        C: FromColorUnclamped<Lab<D65, T>>,
+    {
+        let last: T = from_f64(steps.saturating_sub(1).max(1) as f64);
+        (0..steps)
+            .map(|i| C::from_color_unclamped(self.get(from_f64::<T>(i as f64) / last)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LightnessScale;
+    use crate::white_point::D65;
+    use crate::Lab;
+
+    fn keys() -> Vec<Lab<D65, f64>> {
+        vec![
+            Lab::new(90.0, 0.0, 60.0),
+            Lab::new(40.0, 60.0, 20.0),
+            Lab::new(60.0, -20.0, -40.0),
+            Lab::new(20.0, 10.0, -40.0),
+        ]
+    }
+
+    #[test]
+    fn even_lightness() {
+        let scale = LightnessScale::new(keys());
+        let colors: Vec<Lab<_, f64>> = scale.colors_unclamped(7);
+
+        assert_eq!(colors.len(), 7);
+        for (i, color) in colors.iter().enumerate() {
+            assert_relative_eq!(color.l, 90.0 - 70.0 * i as f64 / 6.0, epsilon = 1e-9);
+        }
+
+        // The other components follow the keys.
+        assert_relative_eq!(colors[2].a, 60.0, epsilon = 1e-9);
+        assert_relative_eq!(colors[4].b, -40.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn gamma() {
+        let scale = LightnessScale::new(keys()).with_gamma(2.0);
+        assert_relative_eq!(scale.get(0.5).l, 90.0 - 70.0 * 0.25, epsilon = 1e-9);
+        assert_relative_eq!(scale.get(1.0).l, 20.0, epsilon = 1e-9);
+        assert_relative_eq!(scale.get(-1.0).l, 90.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn single_step() {
+        let scale = LightnessScale::new(keys());
+        let colors: Vec<Lab<_, f64>> = scale.colors_unclamped(1);
+        assert_eq!(colors, vec![Lab::new(90.0, 0.0, 60.0)]);
+        assert!(scale.colors_unclamped::<Lab<_, f64>>(0).is_empty());
+    }
+}
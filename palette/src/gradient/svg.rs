@@ -0,0 +1,378 @@
+//! Conversion between gradients and SVG gradient stops.
+//!
+//! SVG describes the colors of `<linearGradient>` and `<radialGradient>`
+//! elements with `<stop>` elements, which have an offset, an sRGB color and
+//! an opacity. An [`SvgGradient`](struct.SvgGradient.html) holds the stops,
+//! and interpolates between them the same way as SVG renderers, with
+//! premultiplied alpha and in the color space from the `color-interpolation`
+//! property. It can be turned into a [`Gradient`](../struct.Gradient.html),
+//! or created from one to be exported:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::gradient::svg::{ColorInterpolation, SvgGradient, SvgStop};
+//! use palette::{Gradient, LinSrgba, Srgb};
+//!
+//! let svg = SvgGradient::new(
+//!     vec![
+//!         SvgStop::new(0.0, Srgb::new(1.0, 0.0, 0.0), 1.0),
+//!         SvgStop::new(1.0, Srgb::new(0.0, 0.0, 1.0), 0.0),
+//!     ],
+//!     ColorInterpolation::LinearRgb,
+//! );
+//!
+//! // The transparent end doesn't make the gradient bluer, since the colors
+//! // are premultiplied.
+//! let middle = svg.get(0.5);
+//! assert_relative_eq!(middle.color, Srgb::new(1.0, 0.0, 0.0));
+//! assert_relative_eq!(middle.alpha, 0.5);
+//!
+//! let exported = SvgGradient::from_gradient(&Gradient::new(vec![
+//!     LinSrgba::new(1.0, 1.0, 1.0, 1.0),
+//!     LinSrgba::new(0.0, 0.0, 0.0, 1.0),
+//! ]));
+//! assert_eq!(
+//!     exported.stops[0].to_string(),
+//!     r##"<stop offset="0" stop-color="#ffffff" stop-opacity="1"/>"##
+//! );
+//! ```
+
+use core::fmt;
+
+use crate::blend::PreAlpha;
+use crate::component::IntoComponent;
+use crate::{from_f64, FloatComponent, Gradient, LinSrgb, LinSrgba, Mix, Srgb, Srgba};
+
+/// A `<stop>` of an SVG gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgStop<T: FloatComponent = f32> {
+    /// The position of the stop, from `0.0` at the start of the gradient to
+    /// `1.0` at the end (the `offset` attribute).
+    pub offset: T,
+    /// The color of the stop (the `stop-color` property).
+    pub color: Srgb<T>,
+    /// The opacity of the stop, from `0.0` to `1.0` (the `stop-opacity`
+    /// property).
+    pub opacity: T,
+}
+
+impl<T: FloatComponent> SvgStop<T> {
+    /// Create a stop at `offset`, with `color` and `opacity`.
+    pub fn new(offset: T, color: Srgb<T>, opacity: T) -> Self {
+        SvgStop {
+            offset,
+            color,
+            opacity,
+        }
+    }
+}
+
+/// Writes the stop as an SVG `<stop>` element, with the color as a hex code.
+impl<T: FloatComponent + IntoComponent<u8> + fmt::Display> fmt::Display for SvgStop<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r##"<stop offset="{}" stop-color="#{:x}" stop-opacity="{}"/>"##,
+            self.offset,
+            self.color.into_format::<u8>(),
+            self.opacity
+        )
+    }
+}
+
+/// The color space that an SVG gradient is interpolated in, from the
+/// `color-interpolation` property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorInterpolation {
+    /// Interpolate the encoded sRGB values. This is the default in SVG.
+    Srgb,
+    /// Interpolate linear RGB values, which mixes light physically.
+    LinearRgb,
+}
+
+/// The stops of an SVG gradient, and the color space they are interpolated
+/// in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgGradient<T: FloatComponent = f32> {
+    /// The stops of the gradient, ordered by their offsets.
+    pub stops: Vec<SvgStop<T>>,
+    /// The color space of the interpolation.
+    pub interpolation: ColorInterpolation,
+}
+
+impl<T: FloatComponent> SvgGradient<T> {
+    /// Create a gradient from `stops`, interpolated in `interpolation`.
+    ///
+    /// The offsets are adjusted as SVG renderers do. They are clamped to
+    /// `0.0..=1.0`, and an offset that is smaller than the one before it
+    /// becomes the same as the one before it.
+    ///
+    /// Panics if `stops` is empty.
+    pub fn new(mut stops: Vec<SvgStop<T>>, interpolation: ColorInterpolation) -> Self {
+        assert!(!stops.is_empty(), "an SvgGradient needs at least one stop");
+
+        let mut previous = T::zero();
+        for stop in &mut stops {
+            stop.offset = stop.offset.max(previous).min(T::one());
+            previous = stop.offset;
+        }
+
+        SvgGradient {
+            stops,
+            interpolation,
+        }
+    }
+
+    /// Create stops for `gradient`, interpolated in linear RGB. The domain of
+    /// the gradient is scaled to offsets from `0.0` to `1.0`.
+    ///
+    /// The stops are interpolated with premultiplied alpha, so the result
+    /// only looks the same as `gradient` if it's a gradient of premultiplied
+    /// colors, or if it has the same alpha everywhere.
+    pub fn from_gradient<C, G>(gradient: &Gradient<C, G>) -> Self
+    where
+        C: Mix<Scalar = T> + Clone,
+        G: AsRef<[(T, C)]>,
+        LinSrgba<T>: From<C>,
+    {
+        let (min, max) = gradient.domain();
+        let length = max - min;
+
+        let stops = gradient
+            .0
+            .as_ref()
+            .iter()
+            .map(|(position, color)| {
+                let offset = if length > T::zero() {
+                    (*position - min) / length
+                } else {
+                    T::zero()
+                };
+                let color = LinSrgba::from(color.clone());
+
+                SvgStop::new(offset, Srgb::from_linear(color.color), color.alpha)
+            })
+            .collect();
+
+        SvgGradient::new(stops, ColorInterpolation::LinearRgb)
+    }
+
+    /// Get the color at `offset`, the same way as an SVG renderer. The
+    /// colors of the first and last stops are used outside them.
+    pub fn get(&self, offset: T) -> Srgba<T> {
+        let first = &self.stops[0];
+        let last = &self.stops[self.stops.len() - 1];
+
+        if offset <= first.offset {
+            return first.into_srgba();
+        }
+
+        let next_index = match self.stops.iter().position(|stop| stop.offset > offset) {
+            Some(index) => index,
+            None => return last.into_srgba(),
+        };
+        let (from, to) = (&self.stops[next_index - 1], &self.stops[next_index]);
+        let factor = (offset - from.offset) / (to.offset - from.offset);
+
+        match self.interpolation {
+            ColorInterpolation::Srgb => {
+                let mix = premultiplied(from.color, from.opacity)
+                    .mix(&premultiplied(to.color, to.opacity), factor);
+                unpremultiply(mix)
+            }
+            ColorInterpolation::LinearRgb => {
+                let mix = from
+                    .premultiplied_linear()
+                    .mix(&to.premultiplied_linear(), factor);
+                let color: LinSrgba<T> = mix.into();
+                Srgba::from_linear(color)
+            }
+        }
+    }
+
+    /// Create a [`Gradient`](../struct.Gradient.html) of premultiplied
+    /// colors, with the domain from `0.0` to `1.0`.
+    ///
+    /// A gradient in linear RGB has the same stops. A gradient in sRGB has
+    /// `samples` colors between each pair of stops, including the stops, to
+    /// follow the sRGB interpolation.
+    ///
+    /// Panics if `samples` is less than `2`.
+    pub fn to_gradient(&self, samples: usize) -> Gradient<PreAlpha<LinSrgb<T>, T>> {
+        assert!(samples >= 2, "each span needs at least two samples");
+
+        let first = &self.stops[0];
+        let mut stops = vec![
+            (T::zero(), first.premultiplied_linear()),
+            (first.offset, first.premultiplied_linear()),
+        ];
+        for pair in self.stops.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+
+            if self.interpolation == ColorInterpolation::Srgb && to.offset > from.offset {
+                for i in 1..samples - 1 {
+                    let offset = from.offset
+                        + (to.offset - from.offset) * from_f64(i as f64)
+                            / from_f64((samples - 1) as f64);
+                    stops.push((offset, self.get(offset).into_linear().into()));
+                }
+            }
+
+            stops.push((to.offset, to.premultiplied_linear()));
+        }
+
+        let last = self.stops[self.stops.len() - 1].premultiplied_linear();
+        stops.push((T::one(), last));
+
+        Gradient::with_domain(stops)
+    }
+}
+
+impl<T: FloatComponent> SvgStop<T> {
+    fn into_srgba(self) -> Srgba<T> {
+        Srgba::from_components((
+            self.color.red,
+            self.color.green,
+            self.color.blue,
+            self.opacity,
+        ))
+    }
+
+    fn premultiplied_linear(&self) -> PreAlpha<LinSrgb<T>, T> {
+        self.into_srgba().into_linear().into()
+    }
+}
+
+/// Premultiply the encoded sRGB components. `PreAlpha` is only for linear
+/// colors, so the encoded components are stored as if they were linear.
+fn premultiplied<T: FloatComponent>(color: Srgb<T>, alpha: T) -> PreAlpha<LinSrgb<T>, T> {
+    PreAlpha {
+        color: LinSrgb::new(color.red * alpha, color.green * alpha, color.blue * alpha),
+        alpha,
+    }
+}
+
+/// The reverse of `premultiplied`.
+fn unpremultiply<T: FloatComponent>(color: PreAlpha<LinSrgb<T>, T>) -> Srgba<T> {
+    let color = LinSrgba::from(color);
+    Srgba::from_components((
+        color.color.red,
+        color.color.green,
+        color.color.blue,
+        color.alpha,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ColorInterpolation, SvgGradient, SvgStop};
+    use crate::blend::PreAlpha;
+    use crate::{Gradient, LinSrgb, LinSrgba, Srgb, Srgba};
+
+    fn stops() -> Vec<SvgStop<f64>> {
+        vec![
+            SvgStop::new(0.2, Srgb::new(1.0, 0.0, 0.0), 1.0),
+            SvgStop::new(0.6, Srgb::new(0.0, 0.0, 1.0), 0.5),
+            SvgStop::new(0.4, Srgb::new(0.0, 1.0, 0.0), 1.0),
+        ]
+    }
+
+    #[test]
+    fn offsets() {
+        let gradient = SvgGradient::new(stops(), ColorInterpolation::Srgb);
+        let offsets: Vec<_> = gradient.stops.iter().map(|stop| stop.offset).collect();
+        assert_eq!(offsets, [0.2, 0.6, 0.6]);
+
+        // The offsets are the same, so the last stop wins after them.
+        assert_relative_eq!(gradient.get(0.0), Srgba::new(1.0, 0.0, 0.0, 1.0));
+        assert_relative_eq!(gradient.get(0.6), Srgba::new(0.0, 1.0, 0.0, 1.0));
+        assert_relative_eq!(gradient.get(0.7), Srgba::new(0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn srgb_interpolation() {
+        let gradient = SvgGradient::new(
+            vec![
+                SvgStop::new(0.0, Srgb::new(1.0, 0.0, 0.0), 1.0),
+                SvgStop::new(1.0, Srgb::new(0.0, 0.0, 1.0), 0.0),
+            ],
+            ColorInterpolation::Srgb,
+        );
+        assert_relative_eq!(gradient.get(0.5), Srgba::new(1.0, 0.0, 0.0, 0.5));
+
+        let gradient = SvgGradient::new(
+            vec![
+                SvgStop::new(0.0, Srgb::new(1.0, 1.0, 1.0), 1.0),
+                SvgStop::new(1.0, Srgb::new(0.0, 0.0, 0.0), 1.0),
+            ],
+            ColorInterpolation::Srgb,
+        );
+        assert_relative_eq!(gradient.get(0.5), Srgba::new(0.5, 0.5, 0.5, 1.0));
+    }
+
+    #[test]
+    fn to_gradient() {
+        for &interpolation in &[ColorInterpolation::Srgb, ColorInterpolation::LinearRgb] {
+            let svg = SvgGradient::new(
+                vec![
+                    SvgStop::new(0.1, Srgb::new(1.0, 0.5, 0.0), 1.0),
+                    SvgStop::new(0.5, Srgb::new(0.0, 0.0, 1.0), 0.2),
+                    SvgStop::new(0.9, Srgb::new(0.2, 0.8, 0.2), 0.7),
+                ],
+                interpolation,
+            );
+            let gradient = svg.to_gradient(64);
+
+            for i in 0..=20 {
+                let offset = i as f64 / 20.0;
+                let expected: LinSrgba<f64> = svg.get(offset).into_linear();
+                let actual = LinSrgba::from(gradient.get(offset));
+                assert_relative_eq!(actual, expected, epsilon = 1e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn from_gradient() {
+        let gradient = Gradient::with_domain(vec![
+            (2.0, LinSrgba::new(1.0, 1.0, 1.0, 1.0)),
+            (4.0, LinSrgba::new(0.0, 0.0, 0.0, 0.5)),
+        ]);
+        let svg = SvgGradient::from_gradient(&gradient);
+        assert_eq!(svg.interpolation, ColorInterpolation::LinearRgb);
+        assert_eq!(svg.stops.len(), 2);
+        assert_relative_eq!(svg.stops[0].offset, 0.0);
+        assert_relative_eq!(svg.stops[0].color, Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(svg.stops[0].opacity, 1.0);
+        assert_relative_eq!(svg.stops[1].offset, 1.0);
+        assert_relative_eq!(svg.stops[1].color, Srgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(svg.stops[1].opacity, 0.5);
+
+        let premultiplied = Gradient::new(vec![
+            PreAlpha {
+                color: LinSrgb::new(0.5, 0.0, 0.0),
+                alpha: 0.5,
+            },
+            PreAlpha {
+                color: LinSrgb::new(0.0, 0.0, 1.0),
+                alpha: 1.0,
+            },
+        ]);
+        let svg = SvgGradient::from_gradient(&premultiplied);
+        for i in 0..=10 {
+            let offset = i as f64 / 10.0;
+            let expected = LinSrgba::from(premultiplied.get(offset));
+            let actual: LinSrgba<f64> = svg.get(offset).into_linear();
+            assert_relative_eq!(actual, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let stop = SvgStop::new(0.25, Srgb::new(1.0, 0.5, 0.0), 0.75);
+        assert_eq!(
+            stop.to_string(),
+            r##"<stop offset="0.25" stop-color="#ff8000" stop-opacity="0.75"/>"##
+        );
+    }
+}
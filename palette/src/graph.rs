@@ -0,0 +1,334 @@
+//! A description of the conversions between color spaces.
+//!
+//! The conversions in this library go through a number of intermediate
+//! spaces, such as converting from `Srgb` to `Oklab` by decoding to linear
+//! sRGB and converting to XYZ first. This module describes these conversions
+//! at runtime, as the spaces that are passed and the operations that are
+//! applied along the way. It's useful for tracking down where precision is
+//! lost, and it's what the [`shader`](../shader/index.html) module uses to
+//! generate code:
+//!
+//! ```
+//! use palette::graph::{path, Operation, Space};
+//!
+//! let steps = path(Space::Srgb, Space::Oklab).unwrap();
+//! let spaces: Vec<_> = steps.iter().map(|step| step.to).collect();
+//! assert_eq!(spaces, [Space::LinearSrgb, Space::Xyz, Space::Oklab]);
+//!
+//! assert_eq!(steps[0].operations, [Operation::SrgbDecode]);
+//! assert!(matches!(steps[1].operations[..], [Operation::Matrix(_)]));
+//!
+//! for step in &steps {
+//!     println!("{}", step);
+//! }
+//! ```
+//!
+//! All of the spaces use the D65 white point. This module is only available
+//! if the `std` feature is enabled.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::encoding;
+use crate::matrix::{matrix_inverse, rgb_to_xyz_matrix, Mat3};
+use crate::oklab::{LAB_TO_LMS, LMS_TO_LAB, LMS_TO_XYZ, XYZ_TO_LMS};
+use crate::white_point::{WhitePoint, D65};
+use crate::Xyz;
+
+/// A color space in the conversion graph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Space {
+    /// Nonlinear sRGB, as in [`Srgb`](../type.Srgb.html).
+    Srgb,
+    /// Linear sRGB, as in [`LinSrgb`](../type.LinSrgb.html).
+    LinearSrgb,
+    /// CIE XYZ, as in [`Xyz`](../struct.Xyz.html).
+    Xyz,
+    /// CIE L\*a\*b\*, as in [`Lab`](../struct.Lab.html).
+    Lab,
+    /// CIE L\*C\*h°, as in [`Lch`](../struct.Lch.html).
+    Lch,
+    /// Oklab, as in [`Oklab`](../struct.Oklab.html).
+    Oklab,
+    /// Oklch, as in [`Oklch`](../struct.Oklch.html).
+    Oklch,
+}
+
+impl Space {
+    /// The name of the space in snake case, such as `linear_srgb`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Space::Srgb => "srgb",
+            Space::LinearSrgb => "linear_srgb",
+            Space::Xyz => "xyz",
+            Space::Lab => "lab",
+            Space::Lch => "lch",
+            Space::Oklab => "oklab",
+            Space::Oklch => "oklch",
+        }
+    }
+
+    /// The spaces that this space converts directly to and from.
+    pub fn neighbors(self) -> &'static [Space] {
+        match self {
+            Space::Srgb => &[Space::LinearSrgb],
+            Space::LinearSrgb => &[Space::Srgb, Space::Xyz],
+            Space::Xyz => &[Space::LinearSrgb, Space::Lab, Space::Oklab],
+            Space::Lab => &[Space::Xyz, Space::Lch],
+            Space::Lch => &[Space::Lab],
+            Space::Oklab => &[Space::Xyz, Space::Oklch],
+            Space::Oklch => &[Space::Oklab],
+        }
+    }
+}
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An operation that is applied to the three components of a color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    /// Decode each component with the sRGB transfer function.
+    SrgbDecode,
+    /// Encode each component with the sRGB transfer function.
+    SrgbEncode,
+    /// Multiply with a row major 3x3 matrix.
+    Matrix(Mat3<f64>),
+    /// Take the cube root of each component, keeping the sign.
+    CubeRoot,
+    /// Cube each component.
+    Cube,
+    /// Convert from XYZ to CIE L\*a\*b\*, relative to a white point.
+    XyzToLab {
+        /// The white point, as XYZ.
+        white_point: [f64; 3],
+    },
+    /// Convert from CIE L\*a\*b\* to XYZ, relative to a white point.
+    LabToXyz {
+        /// The white point, as XYZ.
+        white_point: [f64; 3],
+    },
+    /// Convert the last two components from rectangular coordinates to
+    /// chroma and hue, with the hue in degrees.
+    ToPolar,
+    /// Convert the last two components from chroma and hue, with the hue in
+    /// degrees, to rectangular coordinates.
+    FromPolar,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::SrgbDecode => f.write_str("sRGB decode"),
+            Operation::SrgbEncode => f.write_str("sRGB encode"),
+            Operation::Matrix(m) => write!(
+                f,
+                "matrix [[{:?}, {:?}, {:?}], [{:?}, {:?}, {:?}], [{:?}, {:?}, {:?}]]",
+                m[0], m[1], m[2], m[3], m[4], m[5], m[6], m[7], m[8]
+            ),
+            Operation::CubeRoot => f.write_str("cube root"),
+            Operation::Cube => f.write_str("cube"),
+            Operation::XyzToLab { white_point } => {
+                write!(f, "XYZ to L*a*b* with white point {:?}", white_point)
+            }
+            Operation::LabToXyz { white_point } => {
+                write!(f, "L*a*b* to XYZ with white point {:?}", white_point)
+            }
+            Operation::ToPolar => f.write_str("to polar"),
+            Operation::FromPolar => f.write_str("from polar"),
+        }
+    }
+}
+
+/// A direct conversion from one space to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    /// The space to convert from.
+    pub from: Space,
+    /// The space to convert to.
+    pub to: Space,
+    /// The operations that make up the conversion, in the order they are
+    /// applied.
+    pub operations: Vec<Operation>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}:", self.from, self.to)?;
+        for (index, operation) in self.operations.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(f, "{}{}", separator, operation)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The direct conversion from `from` to `to`, or `None` if there isn't one.
+///
+/// The constants are the same as in the conversions in this library.
+pub fn step(from: Space, to: Space) -> Option<Step> {
+    let white_point: Xyz<D65, f64> = D65::get_xyz();
+    let white_point = [white_point.x, white_point.y, white_point.z];
+    let rgb_to_xyz = rgb_to_xyz_matrix::<encoding::Srgb, f64>();
+
+    let operations = match (from, to) {
+        (Space::Srgb, Space::LinearSrgb) => vec![Operation::SrgbDecode],
+        (Space::LinearSrgb, Space::Srgb) => vec![Operation::SrgbEncode],
+        (Space::LinearSrgb, Space::Xyz) => vec![Operation::Matrix(rgb_to_xyz)],
+        (Space::Xyz, Space::LinearSrgb) => vec![Operation::Matrix(matrix_inverse(&rgb_to_xyz))],
+        (Space::Xyz, Space::Lab) => vec![Operation::XyzToLab { white_point }],
+        (Space::Lab, Space::Xyz) => vec![Operation::LabToXyz { white_point }],
+        (Space::Xyz, Space::Oklab) => vec![
+            Operation::Matrix(XYZ_TO_LMS),
+            Operation::CubeRoot,
+            Operation::Matrix(LMS_TO_LAB),
+        ],
+        (Space::Oklab, Space::Xyz) => vec![
+            Operation::Matrix(LAB_TO_LMS),
+            Operation::Cube,
+            Operation::Matrix(LMS_TO_XYZ),
+        ],
+        (Space::Lab, Space::Lch) | (Space::Oklab, Space::Oklch) => vec![Operation::ToPolar],
+        (Space::Lch, Space::Lab) | (Space::Oklch, Space::Oklab) => vec![Operation::FromPolar],
+        _ => return None,
+    };
+
+    Some(Step {
+        from,
+        to,
+        operations,
+    })
+}
+
+/// The shortest sequence of direct conversions from `from` to `to`.
+///
+/// The path is empty if `from` and `to` are the same space, and `None` if
+/// there is no path between them.
+pub fn path(from: Space, to: Space) -> Option<Vec<Step>> {
+    // The previous space on the shortest path to each visited space.
+    let mut previous: Vec<(Space, Space)> = Vec::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(from);
+
+    while let Some(space) = queue.pop_front() {
+        if space == to {
+            break;
+        }
+
+        for &neighbor in space.neighbors() {
+            let visited = neighbor == from || previous.iter().any(|&(s, _)| s == neighbor);
+            if !visited {
+                previous.push((neighbor, space));
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    let mut spaces = vec![to];
+    let mut current = to;
+    while current != from {
+        let &(_, before) = previous.iter().find(|&&(s, _)| s == current)?;
+        spaces.push(before);
+        current = before;
+    }
+    spaces.reverse();
+
+    spaces
+        .windows(2)
+        .map(|pair| step(pair[0], pair[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{path, step, Operation, Space};
+    use crate::convert::IntoColor;
+    use crate::{Oklch, Srgb};
+
+    const ALL: [Space; 7] = [
+        Space::Srgb,
+        Space::LinearSrgb,
+        Space::Xyz,
+        Space::Lab,
+        Space::Lch,
+        Space::Oklab,
+        Space::Oklch,
+    ];
+
+    #[test]
+    fn neighbors_have_steps() {
+        for &from in &ALL {
+            for &to in &ALL {
+                let has_step = step(from, to).is_some();
+                assert_eq!(
+                    has_step,
+                    from.neighbors().contains(&to),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn paths() {
+        for &from in &ALL {
+            for &to in &ALL {
+                let steps = path(from, to).unwrap();
+                let mut current = from;
+                for step in &steps {
+                    assert_eq!(step.from, current);
+                    current = step.to;
+                }
+                assert_eq!(current, to);
+            }
+        }
+
+        assert!(path(Space::Lch, Space::Lch).unwrap().is_empty());
+        assert_eq!(path(Space::Lch, Space::Oklch).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn matches_conversion() {
+        fn apply(operation: Operation, c: [f64; 3]) -> [f64; 3] {
+            match operation {
+                Operation::Matrix(m) => [
+                    m[0] * c[0] + m[1] * c[1] + m[2] * c[2],
+                    m[3] * c[0] + m[4] * c[1] + m[5] * c[2],
+                    m[6] * c[0] + m[7] * c[1] + m[8] * c[2],
+                ],
+                Operation::SrgbDecode => {
+                    let decode = |x: f64| {
+                        if x <= 0.04045 {
+                            x / 12.92
+                        } else {
+                            ((x + 0.055) / 1.055).powf(2.4)
+                        }
+                    };
+                    [decode(c[0]), decode(c[1]), decode(c[2])]
+                }
+                Operation::CubeRoot => [c[0].cbrt(), c[1].cbrt(), c[2].cbrt()],
+                Operation::ToPolar => [c[0], c[1].hypot(c[2]), c[2].atan2(c[1]).to_degrees()],
+                operation => panic!("unexpected operation {}", operation),
+            }
+        }
+
+        let color = Srgb::new(0.8f64, 0.3, 0.1);
+        let mut components = [color.red, color.green, color.blue];
+        for step in path(Space::Srgb, Space::Oklch).unwrap() {
+            for operation in step.operations {
+                components = apply(operation, components);
+            }
+        }
+
+        let expected: Oklch<f64> = color.into_color();
+        assert_relative_eq!(components[0], expected.l, epsilon = 1e-12);
+        assert_relative_eq!(components[1], expected.chroma, epsilon = 1e-12);
+        assert_relative_eq!(components[2], expected.hue.to_degrees(), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,186 @@
+//! Conversion of colors to shades of gray.
+//!
+//! There are many ways to turn a color into a gray, and they don't agree on
+//! how light the result should be. The [`IntoGrayscale`](trait.IntoGrayscale.html)
+//! trait offers the common options through a single method, selected with
+//! [`GrayscaleMethod`](enum.GrayscaleMethod.html).
+//!
+//! ```
+//! use palette::grayscale::{GrayscaleMethod, IntoGrayscale};
+//! use palette::Srgb;
+//!
+//! let blue = Srgb::new(0.0, 0.0, 1.0);
+//!
+//! // Blue is dark, so a luminance based gray is dark too.
+//! let gray = blue.into_grayscale(GrayscaleMethod::Luminance);
+//! assert!(gray.red < 0.3);
+//!
+//! // The average of the components doesn't know that.
+//! let gray = blue.into_grayscale(GrayscaleMethod::Average);
+//! assert!(gray.red > 0.3);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Linear;
+use crate::math::sin;
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::{from_f64, Alpha, FloatComponent, Lab, Lch, Xyz};
+
+/// A method for calculating the shade of gray that represents a color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrayscaleMethod {
+    /// Keep the relative luminance of the color.
+    ///
+    /// This is the physically correct method, where the gray emits the same
+    /// amount of light as the original color. It's calculated in linear
+    /// space, using the luminance weights of the RGB primaries.
+    Luminance,
+
+    /// Use the average of the red, green and blue components, as they are
+    /// stored.
+    ///
+    /// This is fast and simple, but treats all primaries as equally bright,
+    /// which makes blue too light and green too dark.
+    Average,
+
+    /// Use the HSL lightness, which is the middle of the smallest and largest
+    /// component, as they are stored.
+    Lightness,
+
+    /// Keep the perceived lightness of the color, including the extra
+    /// brightness of saturated colors.
+    ///
+    /// This is the CIE L\*a\*b\* lightness, corrected for the
+    /// Helmholtz-Kohlrausch effect, using the model by Fairchild and Pirrotta.
+    /// Saturated colors appear brighter than grays with the same luminance,
+    /// so this method keeps more of the contrast between colors that would
+    /// otherwise end up as very similar grays.
+    PerceivedLightness,
+}
+
+/// Conversion into a gray color of the same type.
+///
+/// See the [module documentation](index.html) for an example.
+pub trait IntoGrayscale {
+    /// Convert `self` into a shade of gray, using `method`.
+    fn into_grayscale(self, method: GrayscaleMethod) -> Self;
+}
+
+impl<S, T> IntoGrayscale for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+    fn into_grayscale(self, method: GrayscaleMethod) -> Self {
+        let gray = match method {
+            GrayscaleMethod::Luminance => {
+                let luminance = Xyz::from_color_unclamped(self.into_linear()).y;
+                return linear_gray(luminance);
+            }
+            GrayscaleMethod::Average => (self.red + self.green + self.blue) / from_f64(3.0),
+            GrayscaleMethod::Lightness => {
+                let max = self.red.max(self.green).max(self.blue);
+                let min = self.red.min(self.green).min(self.blue);
+                (max + min) / from_f64(2.0)
+            }
+            GrayscaleMethod::PerceivedLightness => {
+                let lch = Lch::from_color_unclamped(Xyz::from_color_unclamped(self.into_linear()));
+                let l = perceived_lightness(lch.l, lch.chroma, lch.hue.to_radians());
+                let lab = Lab::<<S::Space as RgbSpace>::WhitePoint, T> {
+                    l: l.min(from_f64(100.0)),
+                    a: T::zero(),
+                    b: T::zero(),
+                    white_point: PhantomData,
+                };
+                return linear_gray(Xyz::from_color_unclamped(lab).y);
+            }
+        };
+
+        Rgb::new(gray, gray, gray)
+    }
+}
+
+impl<C: IntoGrayscale, T> IntoGrayscale for Alpha<C, T> {
+    fn into_grayscale(self, method: GrayscaleMethod) -> Self {
+        Alpha {
+            color: self.color.into_grayscale(method),
+            alpha: self.alpha,
+        }
+    }
+}
+
+/// Encode a linear gray with the luminance `y`.
+fn linear_gray<S, T>(y: T) -> Rgb<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+    Rgb::from_linear(Rgb::<Linear<S::Space>, T>::new(y, y, y))
+}
+
+/// The Fairchild-Pirrotta lightness, L\*\*, where `hue` is in radians.
+fn perceived_lightness<T: FloatComponent>(l: T, chroma: T, hue: T) -> T {
+    let hue_factor = from_f64::<T>(0.116)
+        * sin((hue - from_f64::<T>(90.0).to_radians()) / from_f64(2.0)).abs()
+        + from_f64(0.085);
+    let lightness_factor = from_f64::<T>(2.5) - from_f64::<T>(0.025) * l;
+
+    l + lightness_factor * hue_factor * chroma
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GrayscaleMethod, IntoGrayscale};
+    use crate::{FromColor, Lab, LinSrgb, Srgb, Srgba, Xyz};
+
+    #[test]
+    fn luminance() {
+        let color = Srgb::new(0.8, 0.4, 0.1);
+        let gray = color.into_grayscale(GrayscaleMethod::Luminance);
+
+        assert_relative_eq!(gray.red, gray.green);
+        assert_relative_eq!(gray.red, gray.blue);
+        assert_relative_eq!(
+            Xyz::from_color(gray).y,
+            Xyz::from_color(color).y,
+            epsilon = 1e-6
+        );
+
+        let primaries = LinSrgb::new(1.0, 1.0, 1.0);
+        let sum = LinSrgb::new(1.0, 0.0, 0.0).into_grayscale(GrayscaleMethod::Luminance)
+            + LinSrgb::new(0.0, 1.0, 0.0).into_grayscale(GrayscaleMethod::Luminance)
+            + LinSrgb::new(0.0, 0.0, 1.0).into_grayscale(GrayscaleMethod::Luminance);
+        assert_relative_eq!(sum, primaries, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn average_and_lightness() {
+        let color = Srgba::new(0.9, 0.3, 0.0, 0.5);
+
+        assert_relative_eq!(
+            color.into_grayscale(GrayscaleMethod::Average),
+            Srgba::new(0.4, 0.4, 0.4, 0.5)
+        );
+        assert_relative_eq!(
+            color.into_grayscale(GrayscaleMethod::Lightness),
+            Srgba::new(0.45, 0.45, 0.45, 0.5)
+        );
+    }
+
+    #[test]
+    fn perceived_lightness() {
+        let gray = Srgb::new(0.5, 0.5, 0.5);
+        assert_relative_eq!(
+            gray.into_grayscale(GrayscaleMethod::PerceivedLightness),
+            gray,
+            epsilon = 1e-6
+        );
+
+        let red = Srgb::new(1.0, 0.0, 0.0);
+        let perceived = Lab::from_color(red.into_grayscale(GrayscaleMethod::PerceivedLightness));
+        let luminance = Lab::from_color(red.into_grayscale(GrayscaleMethod::Luminance));
+        assert!(perceived.l > luminance.l + 10.0);
+    }
+}
@@ -0,0 +1,425 @@
+use core::f64::consts::PI;
+
+use crate::cam::{Model, Surround, ViewingConditions};
+use crate::convert::FromColorUnclamped;
+use crate::white_point::{WhitePoint, D65};
+use crate::{clamp, from_f64, Alpha, Clamp, Component, FloatComponent, Lab, LinSrgb, Xyz};
+
+/// HCT with an alpha component. See the [`Hcta` implementation in
+/// `Alpha`](crate::Alpha#Hcta).
+pub type Hcta<T = f32> = Alpha<Hct<T>, T>;
+
+/// The HCT color space, which is used for dynamic color in Material Design 3.
+///
+/// HCT combines the hue and chroma of the CAM16 color appearance model with
+/// the lightness of CIE L\*a\*b\*, which is called tone. The hue and chroma
+/// describe the color as it's perceived, while the tone is directly related
+/// to the luminance, and thereby to the contrast between two colors.
+///
+/// The colors are seen under the default viewing conditions of Material
+/// Design: a D65 white point, an adapting field with a luminance of
+/// 200/π·Y(50) cd/m², where Y(50) is the luminance of a tone of 50, a
+/// background with a tone of 50 and an average surround.
+///
+/// Not all combinations of hue, chroma and tone exist in sRGB. Converting
+/// `Hct` to any other color gives the sRGB color with the same hue and tone,
+/// and the highest chroma that is not larger than `chroma`. This is the way
+/// Material Design maps its colors into the sRGB gamut, and it means that
+/// converting an `Hct` color back and forth may reduce its chroma.
+///
+/// ```
+/// use palette::{FromColor, Hct, LinSrgb, Srgb};
+///
+/// let hct = Hct::from_color(Srgb::new(0.2f64, 0.4, 0.9).into_linear());
+/// let lighter = Hct::new(hct.hue, hct.chroma, 80.0);
+///
+/// let color = Srgb::from_linear(LinSrgb::from_color(lighter));
+/// assert!(color.blue > 0.9);
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Hct<T = f32>
+where
+    T: FloatComponent,
+{
+    /// The CAM16 hue angle of the color, in degrees, from 0.0 to 360.0.
+    pub hue: T,
+
+    /// The CAM16 chroma of the color. 0.0 gives a gray color, and the
+    /// highest chroma depends on the hue and tone.
+    pub chroma: T,
+
+    /// The tone of the color, which is the same as L\* in CIE L\*a\*b\*. 0.0
+    /// gives absolute black and 100.0 gives the brightest white.
+    pub tone: T,
+}
+
+impl<T> Copy for Hct<T> where T: FloatComponent {}
+
+impl<T> Clone for Hct<T>
+where
+    T: FloatComponent,
+{
+    fn clone(&self) -> Hct<T> {
+        *self
+    }
+}
+
+impl<T> Hct<T>
+where
+    T: FloatComponent,
+{
+    /// HCT.
+    pub fn new(hue: T, chroma: T, tone: T) -> Hct<T> {
+        Hct { hue, chroma, tone }
+    }
+
+    /// Convert to a `(hue, chroma, tone)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.hue, self.chroma, self.tone)
+    }
+
+    /// Convert from a `(hue, chroma, tone)` tuple.
+    pub fn from_components((hue, chroma, tone): (T, T, T)) -> Self {
+        Self::new(hue, chroma, tone)
+    }
+
+    /// Return the `tone` value minimum.
+    pub fn min_tone() -> T {
+        T::zero()
+    }
+
+    /// Return the `tone` value maximum.
+    pub fn max_tone() -> T {
+        from_f64(100.0)
+    }
+
+    /// Find the sRGB color with the hue and tone of this color, and the
+    /// highest chroma that is not larger than `self.chroma`.
+    ///
+    /// This is the same as converting to `LinSrgb`.
+    pub fn to_linear_srgb(self) -> LinSrgb<T> {
+        let hundred = from_f64::<T>(100.0);
+        let tone = clamp(self.tone, T::zero(), hundred);
+        let luminance = Xyz::<D65, T>::from_color_unclamped(Lab::new(tone, T::zero(), T::zero())).y;
+        let gray = LinSrgb::new(luminance, luminance, luminance);
+
+        if self.chroma <= from_f64(1e-4)
+            || tone <= from_f64(1e-4)
+            || tone >= hundred - from_f64(1e-4)
+        {
+            return gray;
+        }
+
+        let conditions = viewing_conditions::<T>();
+        let hue = self.hue.to_radians();
+
+        if let Some(color) = find_color(&conditions, hue, self.chroma, luminance) {
+            return color;
+        }
+
+        // The colors with low chroma are not always within the gamut, since
+        // the white point has some chroma in CAM16. Step down until a color
+        // is within the gamut, in steps of at most one chroma unit, and
+        // refine the boundary from there.
+        let steps = self.chroma.ceil().to_usize().unwrap_or(0).max(32);
+        let step = self.chroma / from_f64(steps as f64);
+        let mut outside = self.chroma;
+        for _ in 0..steps {
+            let chroma = (outside - step).max(T::zero());
+            if let Some(mut best) = find_color(&conditions, hue, chroma, luminance) {
+                let mut inside = chroma;
+                for _ in 0..16 {
+                    let middle = (inside + outside) / from_f64(2.0);
+                    if let Some(color) = find_color(&conditions, hue, middle, luminance) {
+                        best = color;
+                        inside = middle;
+                    } else {
+                        outside = middle;
+                    }
+                }
+
+                return best;
+            }
+
+            outside = chroma;
+        }
+
+        gray
+    }
+}
+
+impl<T> PartialEq for Hct<T>
+where
+    T: FloatComponent + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hue == other.hue && self.chroma == other.chroma && self.tone == other.tone
+    }
+}
+
+impl<T> Eq for Hct<T> where T: FloatComponent + Eq {}
+
+///<span id="Hcta"></span>[`Hcta`](crate::Hcta) implementations.
+impl<T, A> Alpha<Hct<T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// HCT and transparency.
+    pub fn new(hue: T, chroma: T, tone: T, alpha: A) -> Self {
+        Alpha {
+            color: Hct::new(hue, chroma, tone),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(hue, chroma, tone, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.hue, self.chroma, self.tone, self.alpha)
+    }
+
+    /// Convert from a `(hue, chroma, tone, alpha)` tuple.
+    pub fn from_components((hue, chroma, tone, alpha): (T, T, T, A)) -> Self {
+        Self::new(hue, chroma, tone, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Hct<T>> for Hct<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Hct<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Hct<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        let correlates = viewing_conditions().correlates(color);
+        let mut hue = correlates.hue.to_degrees();
+        if hue < T::zero() {
+            hue = hue + from_f64(360.0);
+        }
+
+        Hct {
+            hue,
+            chroma: correlates.chroma,
+            tone: Lab::<D65, T>::from_color_unclamped(color).l,
+        }
+    }
+}
+
+impl<T> FromColorUnclamped<Hct<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Hct<T>) -> Self {
+        Xyz::from_color_unclamped(color.to_linear_srgb())
+    }
+}
+
+impl<T: FloatComponent> From<(T, T, T)> for Hct<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent> Into<(T, T, T)> for Hct<T> {
+    fn into(self) -> (T, T, T) {
+        self.into_components()
+    }
+}
+
+impl<T: FloatComponent, A: Component> From<(T, T, T, A)> for Alpha<Hct<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent, A: Component> Into<(T, T, T, A)> for Alpha<Hct<T>, A> {
+    fn into(self) -> (T, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<T> Clamp for Hct<T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    fn is_within_bounds(&self) -> bool {
+        self.hue >= T::zero() && self.hue < from_f64(360.0) &&
+        self.chroma >= T::zero() &&
+        self.tone >= T::zero() && self.tone <= from_f64(100.0)
+    }
+
+    fn clamp(&self) -> Hct<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        let full_turn = from_f64::<T>(360.0);
+        self.hue = ((self.hue % full_turn) + full_turn) % full_turn;
+        self.chroma = self.chroma.max(T::zero());
+        self.tone = clamp(self.tone, T::zero(), from_f64(100.0));
+    }
+}
+
+impl<T> Default for Hct<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Hct<T> {
+        Hct::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Hct<T> where T: FloatComponent + bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Hct<T> where T: FloatComponent + bytemuck::Pod {}
+
+/// The default viewing conditions of Material Design.
+fn viewing_conditions<T: FloatComponent>() -> ViewingConditions<D65, T> {
+    // The luminance of L* = 50.
+    let background =
+        Xyz::<D65, T>::from_color_unclamped(Lab::new(from_f64(50.0), T::zero(), T::zero())).y
+            * from_f64(100.0);
+
+    ViewingConditions::new(
+        Model::Cam16,
+        D65::get_xyz(),
+        from_f64::<T>(200.0 / PI) * background / from_f64(100.0),
+        background,
+        Surround::AVERAGE,
+    )
+}
+
+/// Find the color with the CAM16 hue angle `hue`, in radians, the chroma
+/// `chroma` and the relative luminance `luminance`, if it's within the sRGB
+/// gamut.
+fn find_color<T: FloatComponent>(
+    conditions: &ViewingConditions<D65, T>,
+    hue: T,
+    chroma: T,
+    luminance: T,
+) -> Option<LinSrgb<T>> {
+    // The luminance grows roughly with the square of the lightness, which
+    // makes a good initial guess and Newton step.
+    let mut lightness = luminance.sqrt() * from_f64(100.0);
+
+    for _ in 0..12 {
+        let color = conditions.color_from_correlates(lightness, chroma, hue);
+        if color.y <= T::zero() {
+            return None;
+        }
+
+        if (color.y - luminance).abs() < from_f64(2e-5) {
+            let color = LinSrgb::from_color_unclamped(color);
+            let tolerance = from_f64::<T>(1e-4);
+            let within = |c: T| c >= -tolerance && c <= T::one() + tolerance;
+
+            return if within(color.red) && within(color.green) && within(color.blue) {
+                Some(LinSrgb::new(
+                    clamp(color.red, T::zero(), T::one()),
+                    clamp(color.green, T::zero(), T::one()),
+                    clamp(color.blue, T::zero(), T::one()),
+                ))
+            } else {
+                None
+            };
+        }
+
+        lightness = lightness - (color.y - luminance) * lightness / (from_f64::<T>(2.0) * color.y);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hct;
+    use crate::convert::FromColorUnclamped;
+    use crate::{Lab, LinSrgb, Srgb, Xyz};
+
+    fn hct(red: u8, green: u8, blue: u8) -> Hct<f64> {
+        let color = Srgb::new(red, green, blue)
+            .into_format::<f64>()
+            .into_linear();
+        Hct::from_color_unclamped(color)
+    }
+
+    #[test]
+    fn reference_values() {
+        // From the CAM16 tests of the Material color utilities.
+        let red = hct(255, 0, 0);
+        assert_relative_eq!(red.hue, 27.408, epsilon = 0.02);
+        assert_relative_eq!(red.chroma, 113.357, epsilon = 0.05);
+        assert_relative_eq!(red.tone, 53.24, epsilon = 0.01);
+
+        let green = hct(0, 255, 0);
+        assert_relative_eq!(green.hue, 142.139, epsilon = 0.02);
+        assert_relative_eq!(green.chroma, 108.410, epsilon = 0.05);
+
+        let blue = hct(0, 0, 255);
+        assert_relative_eq!(blue.hue, 282.788, epsilon = 0.05);
+        assert_relative_eq!(blue.chroma, 87.230, epsilon = 0.05);
+
+        let white = hct(255, 255, 255);
+        assert_relative_eq!(white.hue, 209.492, epsilon = 0.05);
+        assert_relative_eq!(white.chroma, 2.869, epsilon = 0.01);
+        assert_relative_eq!(white.tone, 100.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(red, green, blue) in &[
+            (255, 0, 0),
+            (0, 255, 0),
+            (0, 0, 255),
+            (66, 133, 244),
+            (128, 128, 128),
+            (250, 230, 20),
+        ] {
+            let color = Srgb::new(red, green, blue)
+                .into_format::<f64>()
+                .into_linear();
+            let result = LinSrgb::from_color_unclamped(hct(red, green, blue));
+            assert_relative_eq!(result, color, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        let color = Hct::new(27.0, 200.0, 60.0);
+        let srgb = color.to_linear_srgb();
+        for &c in &[srgb.red, srgb.green, srgb.blue] {
+            assert!(c >= 0.0 && c <= 1.0);
+        }
+
+        let mapped = Hct::from_color_unclamped(srgb);
+        assert_relative_eq!(mapped.tone, 60.0, epsilon = 0.01);
+        assert_relative_eq!(mapped.hue, 27.0, epsilon = 0.5);
+        assert!(mapped.chroma < 200.0 && mapped.chroma > 50.0);
+
+        let black = Xyz::from_color_unclamped(Hct::new(100.0, 50.0, 0.0));
+        assert_relative_eq!(black, Xyz::new(0.0, 0.0, 0.0));
+        let gray = Lab::from_color_unclamped(Hct::new(100.0, 0.0, 40.0));
+        assert_relative_eq!(gray, Lab::new(40.0, 0.0, 0.0), epsilon = 1e-9);
+    }
+}
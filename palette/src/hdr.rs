@@ -0,0 +1,190 @@
+//! Helpers for high dynamic range content.
+//!
+//! HDR video signals are usually encoded with the perceptual quantizer (PQ)
+//! transfer function from SMPTE ST 2084, which maps absolute luminance from
+//! `0` to `10000` cd/m² (nits) to a signal from `0.0` to `1.0`. The functions
+//! in this module convert between PQ signal values, absolute luminance and
+//! relative linear values, where `1.0` is a chosen reference white.
+//!
+//! Linear light can be either scene-referred, as in a render or a camera
+//! raw, or display-referred, relative to the peak luminance of a display. The
+//! [`SceneReferred`](struct.SceneReferred.html) and
+//! [`DisplayReferred`](struct.DisplayReferred.html) wrappers keep track of
+//! which one a color is, and tone mapping operators, implementing
+//! [`ToneMap`](trait.ToneMap.html), convert from the former to the latter.
+//! [`ExposureFusion`](struct.ExposureFusion.html) is an alternative that
+//! merges a series of differently exposed images directly into a displayable
+//! image.
+//!
+//! ```
+//! use palette::hdr::{nits_to_pq, pq_to_nits};
+//!
+//! let signal = nits_to_pq(1000.0f64);
+//! assert!((signal - 0.7518).abs() < 1e-4);
+//! assert!((pq_to_nits(signal) - 1000.0).abs() < 1e-6);
+//! ```
+
+use crate::encoding::Linear;
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::{from_f64, FloatComponent};
+
+#[cfg(feature = "std")]
+pub use self::fusion::ExposureFusion;
+pub use self::tone_map::{AcesFilmic, DisplayReferred, Reinhard, SceneReferred, ToneMap};
+
+#[cfg(feature = "std")]
+mod fusion;
+mod tone_map;
+
+/// The luminance, in cd/m², of a PQ signal value of `1.0`.
+pub const PQ_MAX_LUMINANCE: f64 = 10000.0;
+
+const M1: f64 = 2610.0 / 16384.0;
+const M2: f64 = 2523.0 / 4096.0 * 128.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 4096.0 * 32.0;
+const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// Convert a PQ signal value to absolute luminance in cd/m².
+///
+/// Signal values are clamped to the `0.0` to `1.0` range.
+pub fn pq_to_nits<T: FloatComponent>(signal: T) -> T {
+    let signal = signal.max(T::zero()).min(T::one());
+    let p = powf(signal, from_f64::<T>(1.0 / M2));
+    let numerator = (p - from_f64(C1)).max(T::zero());
+    let denominator = from_f64::<T>(C2) - from_f64::<T>(C3) * p;
+
+    powf(numerator / denominator, from_f64(1.0 / M1)) * from_f64(PQ_MAX_LUMINANCE)
+}
+
+/// Convert absolute luminance in cd/m² to a PQ signal value.
+///
+/// Luminance is clamped to the `0` to `10000` cd/m² range.
+pub fn nits_to_pq<T: FloatComponent>(nits: T) -> T {
+    let y = (nits / from_f64(PQ_MAX_LUMINANCE))
+        .max(T::zero())
+        .min(T::one());
+    let y_m1 = powf(y, from_f64(M1));
+
+    powf(
+        (from_f64::<T>(C1) + from_f64::<T>(C2) * y_m1) / (T::one() + from_f64::<T>(C3) * y_m1),
+        from_f64(M2),
+    )
+}
+
+/// Convert a relative linear value to a PQ signal value, where `1.0` is
+/// displayed at `reference_white` cd/m².
+pub fn linear_to_pq<T: FloatComponent>(value: T, reference_white: T) -> T {
+    nits_to_pq(value * reference_white)
+}
+
+/// Convert a PQ signal value to a relative linear value, where `1.0` is
+/// displayed at `reference_white` cd/m².
+pub fn pq_to_linear<T: FloatComponent>(signal: T, reference_white: T) -> T {
+    pq_to_nits(signal) / reference_white
+}
+
+/// The content light level of HDR content, as described in CTA-861.3.
+///
+/// The light level of a pixel is its largest component. Both levels are in
+/// cd/m².
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContentLightLevel<T = f32> {
+    /// The maximum content light level (MaxCLL), which is the light level of
+    /// the brightest pixel.
+    pub max_cll: T,
+
+    /// The maximum frame-average light level (MaxFALL), which is the highest
+    /// average light level of a frame.
+    pub max_fall: T,
+}
+
+impl<T: FloatComponent> ContentLightLevel<T> {
+    /// Measure a single frame of linear colors, where `1.0` is displayed at
+    /// `reference_white` cd/m².
+    ///
+    /// Negative components count as `0.0`. An empty frame has both levels at
+    /// `0.0`.
+    pub fn from_frame<S: RgbSpace>(frame: &[Rgb<Linear<S>, T>], reference_white: T) -> Self {
+        let mut max = T::zero();
+        let mut sum = T::zero();
+
+        for color in frame {
+            let level = color.red.max(color.green).max(color.blue).max(T::zero());
+            max = max.max(level);
+            sum = sum + level;
+        }
+
+        let average = if frame.is_empty() {
+            T::zero()
+        } else {
+            sum / from_f64(frame.len() as f64)
+        };
+
+        ContentLightLevel {
+            max_cll: max * reference_white,
+            max_fall: average * reference_white,
+        }
+    }
+
+    /// Combine the levels of two parts of the same content, such as two
+    /// frames of a video.
+    pub fn merge(self, other: Self) -> Self {
+        ContentLightLevel {
+            max_cll: self.max_cll.max(other.max_cll),
+            max_fall: self.max_fall.max(other.max_fall),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{linear_to_pq, nits_to_pq, pq_to_linear, pq_to_nits, ContentLightLevel};
+    use crate::LinSrgb;
+
+    #[test]
+    fn pq_reference_values() {
+        assert_relative_eq!(nits_to_pq(0.0f64), 7.309559e-7, epsilon = 1e-12);
+        assert_relative_eq!(nits_to_pq(100.0f64), 0.508078, epsilon = 1e-6);
+        assert_relative_eq!(nits_to_pq(1000.0f64), 0.751827, epsilon = 1e-6);
+        assert_relative_eq!(nits_to_pq(10000.0f64), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(nits_to_pq(20000.0f64), 1.0, epsilon = 1e-12);
+
+        assert_relative_eq!(pq_to_nits(0.0f64), 0.0);
+        assert_relative_eq!(pq_to_nits(1.0f64), 10000.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn pq_round_trip() {
+        for &nits in &[0.01f64, 0.5, 48.0, 203.0, 600.0, 4000.0] {
+            assert_relative_eq!(pq_to_nits(nits_to_pq(nits)), nits, max_relative = 1e-9);
+        }
+
+        let signal = linear_to_pq(2.0f64, 203.0);
+        assert_relative_eq!(signal, nits_to_pq(406.0));
+        assert_relative_eq!(pq_to_linear(signal, 203.0), 2.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn content_light_level() {
+        let frame = [
+            LinSrgb::new(1.0, 0.5, 0.0),
+            LinSrgb::new(0.2, 4.0, 0.1),
+            LinSrgb::new(-1.0, -1.0, -1.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ];
+        let level = ContentLightLevel::from_frame(&frame, 100.0);
+        assert_relative_eq!(level.max_cll, 400.0);
+        assert_relative_eq!(level.max_fall, 150.0);
+
+        let bright = ContentLightLevel::from_frame(&[LinSrgb::new(5.0, 0.0, 0.0)], 100.0);
+        let merged = level.merge(bright);
+        assert_relative_eq!(merged.max_cll, 500.0);
+        assert_relative_eq!(merged.max_fall, 500.0);
+
+        let empty = ContentLightLevel::<f32>::from_frame::<crate::encoding::Srgb>(&[], 100.0);
+        assert_eq!(empty.max_cll, 0.0);
+        assert_eq!(empty.max_fall, 0.0);
+    }
+}
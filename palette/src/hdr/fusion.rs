@@ -0,0 +1,409 @@
+use crate::math::{exp, powf};
+use crate::{from_f64, FloatComponent, LinSrgb, Srgb};
+
+/// Exposure fusion, as described by Mertens, Kautz and Van Reeth.
+///
+/// Exposure fusion merges a bracketed series of images of the same scene into
+/// a single image, where each part is taken from the images where it's best
+/// exposed. It doesn't need to know the exposure times or the camera
+/// response, and produces a displayable image directly, without tone mapping.
+///
+/// Each pixel in each image is weighted by three quality measures, which are
+/// raised to configurable exponents and multiplied:
+///
+/// * Contrast, which favors edges and texture.
+/// * Saturation, which favors vivid colors.
+/// * Well-exposedness, which favors values close to the middle of the range.
+///
+/// The images are blended with these weights in a Laplacian pyramid, to
+/// avoid seams where the weights change quickly. The measures and the
+/// blending are based on the sRGB encoded values, while the input and output
+/// are linear.
+///
+/// This is only available if the `std` feature is enabled (this is the
+/// default).
+///
+/// ```
+/// use palette::hdr::ExposureFusion;
+/// use palette::LinSrgb;
+///
+/// let dark = vec![LinSrgb::new(0.01, 0.01, 0.01), LinSrgb::new(0.2, 0.1, 0.05)];
+/// let bright = vec![LinSrgb::new(0.2, 0.2, 0.2), LinSrgb::new(1.0, 1.0, 1.0)];
+///
+/// // Gray pixels have no saturation, so only look at the exposure.
+/// let fused = ExposureFusion::new()
+///     .contrast(0.0)
+///     .saturation(0.0)
+///     .fuse(&[&dark, &bright], 2, 1);
+///
+/// // The first pixel is better exposed in the bright image and the second
+/// // pixel in the dark image.
+/// assert!(fused[0].red > 0.1);
+/// assert!(fused[1].blue < 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExposureFusion<T = f32> {
+    contrast: T,
+    saturation: T,
+    well_exposedness: T,
+}
+
+impl<T: FloatComponent> ExposureFusion<T> {
+    /// Create a fusion with all quality measure exponents set to `1.0`.
+    pub fn new() -> Self {
+        ExposureFusion {
+            contrast: T::one(),
+            saturation: T::one(),
+            well_exposedness: T::one(),
+        }
+    }
+
+    /// Set the exponent for the contrast measure. `0.0` disables it.
+    pub fn contrast(mut self, exponent: T) -> Self {
+        self.contrast = exponent;
+        self
+    }
+
+    /// Set the exponent for the saturation measure. `0.0` disables it.
+    pub fn saturation(mut self, exponent: T) -> Self {
+        self.saturation = exponent;
+        self
+    }
+
+    /// Set the exponent for the well-exposedness measure. `0.0` disables it.
+    pub fn well_exposedness(mut self, exponent: T) -> Self {
+        self.well_exposedness = exponent;
+        self
+    }
+
+    /// Fuse a series of aligned images, each with `width * height` pixels in
+    /// row major order.
+    ///
+    /// The result is in the `0.0` to `1.0` range.
+    ///
+    /// Panics if there are no images, or if any of them doesn't have
+    /// `width * height` pixels.
+    pub fn fuse(&self, images: &[&[LinSrgb<T>]], width: usize, height: usize) -> Vec<LinSrgb<T>> {
+        assert!(
+            !images.is_empty(),
+            "exposure fusion needs at least one image"
+        );
+        assert!(
+            images.iter().all(|image| image.len() == width * height),
+            "all images must have width * height pixels"
+        );
+
+        let levels = pyramid_levels(width, height);
+        let encoded: Vec<Vec<Srgb<T>>> = images
+            .iter()
+            .map(|image| {
+                image
+                    .iter()
+                    .map(|&color| Srgb::from_linear(color))
+                    .collect()
+            })
+            .collect();
+
+        let mut weights: Vec<Plane<T>> = encoded
+            .iter()
+            .map(|image| self.weights(image, width, height))
+            .collect();
+        normalize(&mut weights);
+
+        let mut result: Option<[Vec<Plane<T>>; 3]> = None;
+        for (image, weight) in encoded.iter().zip(&weights) {
+            let weight = gaussian_pyramid(weight.clone(), levels);
+            let channels = [
+                Plane::from_fn(width, height, |i| image[i].red),
+                Plane::from_fn(width, height, |i| image[i].green),
+                Plane::from_fn(width, height, |i| image[i].blue),
+            ];
+
+            let mut blended = Vec::with_capacity(3);
+            for channel in channels.iter() {
+                let laplacian = laplacian_pyramid(channel.clone(), levels);
+                let weighted: Vec<Plane<T>> = laplacian
+                    .into_iter()
+                    .zip(&weight)
+                    .map(|(level, weight)| level.zip_map(weight, |l, w| l * w))
+                    .collect();
+                blended.push(weighted);
+            }
+
+            result = Some(match result {
+                None => {
+                    let blue = blended.pop().expect("three channels");
+                    let green = blended.pop().expect("three channels");
+                    let red = blended.pop().expect("three channels");
+                    [red, green, blue]
+                }
+                Some(mut sums) => {
+                    for (sum, channel) in sums.iter_mut().zip(blended) {
+                        for (sum, level) in sum.iter_mut().zip(channel) {
+                            *sum = sum.zip_map(&level, |a, b| a + b);
+                        }
+                    }
+                    sums
+                }
+            });
+        }
+
+        let [red, green, blue] = result.expect("there should be at least one image");
+        let (red, green, blue) = (collapse(red), collapse(green), collapse(blue));
+        let clamp = |x: T| x.max(T::zero()).min(T::one());
+
+        (0..width * height)
+            .map(|i| {
+                Srgb::new(
+                    clamp(red.data[i]),
+                    clamp(green.data[i]),
+                    clamp(blue.data[i]),
+                )
+                .into_linear()
+            })
+            .collect()
+    }
+
+    /// Calculate the quality weight of each pixel in an encoded image.
+    fn weights(&self, image: &[Srgb<T>], width: usize, height: usize) -> Plane<T> {
+        let gray = Plane::from_fn(width, height, |i| {
+            let color = image[i];
+            (color.red + color.green + color.blue) / from_f64(3.0)
+        });
+        let sigma: T = from_f64(0.2);
+        let exposedness = |x: T| {
+            let offset = x - from_f64(0.5);
+            exp(-(offset * offset) / (from_f64::<T>(2.0) * sigma * sigma))
+        };
+
+        Plane::from_fn(width, height, |i| {
+            let (x, y) = ((i % width) as isize, (i / width) as isize);
+            let contrast =
+                (gray.get(x - 1, y) + gray.get(x + 1, y) + gray.get(x, y - 1) + gray.get(x, y + 1)
+                    - gray.get(x, y) * from_f64(4.0))
+                .abs();
+
+            let color = image[i];
+            let mean = gray.data[i];
+            let saturation = ((color.red - mean) * (color.red - mean)
+                + (color.green - mean) * (color.green - mean)
+                + (color.blue - mean) * (color.blue - mean))
+                / from_f64(3.0);
+            let saturation = saturation.sqrt();
+
+            let well_exposedness =
+                exposedness(color.red) * exposedness(color.green) * exposedness(color.blue);
+
+            powf(contrast, self.contrast)
+                * powf(saturation, self.saturation)
+                * powf(well_exposedness, self.well_exposedness)
+                + from_f64(1e-12)
+        })
+    }
+}
+
+impl<T: FloatComponent> Default for ExposureFusion<T> {
+    fn default() -> Self {
+        ExposureFusion::new()
+    }
+}
+
+/// A single channel image.
+#[derive(Clone)]
+struct Plane<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T: FloatComponent> Plane<T> {
+    fn from_fn<F: FnMut(usize) -> T>(width: usize, height: usize, f: F) -> Self {
+        Plane {
+            width,
+            height,
+            data: (0..width * height).map(f).collect(),
+        }
+    }
+
+    /// Get a pixel, where coordinates outside the plane are clamped to the
+    /// edge.
+    fn get(&self, x: isize, y: isize) -> T {
+        let x = x.max(0).min(self.width as isize - 1) as usize;
+        let y = y.max(0).min(self.height as isize - 1) as usize;
+        self.data[y * self.width + x]
+    }
+
+    fn zip_map<F: Fn(T, T) -> T>(&self, other: &Self, f: F) -> Self {
+        Plane {
+            width: self.width,
+            height: self.height,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        }
+    }
+
+    /// Blur with a 5 tap binomial filter and keep every other pixel.
+    fn reduce(&self) -> Self {
+        let kernel: [T; 5] = [
+            from_f64(1.0 / 16.0),
+            from_f64(4.0 / 16.0),
+            from_f64(6.0 / 16.0),
+            from_f64(4.0 / 16.0),
+            from_f64(1.0 / 16.0),
+        ];
+        let width = self.width / 2 + self.width % 2;
+        let height = self.height / 2 + self.height % 2;
+
+        let horizontal = Plane::from_fn(width, self.height, |i| {
+            let (x, y) = ((i % width * 2) as isize, (i / width) as isize);
+            kernel.iter().enumerate().fold(T::zero(), |sum, (k, &w)| {
+                sum + self.get(x + k as isize - 2, y) * w
+            })
+        });
+
+        Plane::from_fn(width, height, |i| {
+            let (x, y) = ((i % width) as isize, (i / width * 2) as isize);
+            kernel.iter().enumerate().fold(T::zero(), |sum, (k, &w)| {
+                sum + horizontal.get(x, y + k as isize - 2) * w
+            })
+        })
+    }
+
+    /// Scale up to `width` by `height` with bilinear interpolation.
+    fn expand(&self, width: usize, height: usize) -> Self {
+        let half: T = from_f64(0.5);
+
+        Plane::from_fn(width, height, |i| {
+            let x = (from_f64::<T>((i % width) as f64) + half) * half - half;
+            let y = (from_f64::<T>((i / width) as f64) + half) * half - half;
+            let (x0, y0) = (x.floor(), y.floor());
+            let (tx, ty) = (x - x0, y - y0);
+            let x0 = x0.to_isize().unwrap_or(0);
+            let y0 = y0.to_isize().unwrap_or(0);
+
+            let top = self.get(x0, y0) + (self.get(x0 + 1, y0) - self.get(x0, y0)) * tx;
+            let bottom =
+                self.get(x0, y0 + 1) + (self.get(x0 + 1, y0 + 1) - self.get(x0, y0 + 1)) * tx;
+            top + (bottom - top) * ty
+        })
+    }
+}
+
+/// The number of pyramid levels that fit in an image.
+fn pyramid_levels(width: usize, height: usize) -> usize {
+    let mut levels = 1;
+    let (mut width, mut height) = (width, height);
+
+    while width.min(height) > 1 {
+        width = width / 2 + width % 2;
+        height = height / 2 + height % 2;
+        levels += 1;
+    }
+
+    levels
+}
+
+/// Make the weights of each pixel sum to `1.0`.
+fn normalize<T: FloatComponent>(weights: &mut [Plane<T>]) {
+    for i in 0..weights[0].data.len() {
+        let sum = weights
+            .iter()
+            .fold(T::zero(), |sum, weight| sum + weight.data[i]);
+
+        for weight in weights.iter_mut() {
+            weight.data[i] = weight.data[i] / sum;
+        }
+    }
+}
+
+fn gaussian_pyramid<T: FloatComponent>(plane: Plane<T>, levels: usize) -> Vec<Plane<T>> {
+    let mut pyramid = Vec::with_capacity(levels);
+    pyramid.push(plane);
+
+    while pyramid.len() < levels {
+        let next = pyramid[pyramid.len() - 1].reduce();
+        pyramid.push(next);
+    }
+
+    pyramid
+}
+
+fn laplacian_pyramid<T: FloatComponent>(plane: Plane<T>, levels: usize) -> Vec<Plane<T>> {
+    let mut pyramid = gaussian_pyramid(plane, levels);
+
+    for i in 0..pyramid.len() - 1 {
+        let (width, height) = (pyramid[i].width, pyramid[i].height);
+        let expanded = pyramid[i + 1].expand(width, height);
+        pyramid[i] = pyramid[i].zip_map(&expanded, |a, b| a - b);
+    }
+
+    pyramid
+}
+
+fn collapse<T: FloatComponent>(mut pyramid: Vec<Plane<T>>) -> Plane<T> {
+    let mut result = pyramid
+        .pop()
+        .expect("a pyramid should have at least one level");
+
+    while let Some(level) = pyramid.pop() {
+        let expanded = result.expand(level.width, level.height);
+        result = level.zip_map(&expanded, |a, b| a + b);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collapse, laplacian_pyramid, ExposureFusion, Plane};
+    use crate::LinSrgb;
+
+    #[test]
+    fn pyramid_round_trip() {
+        let plane = Plane::from_fn(13, 7, |i| ((i * 7919) % 31) as f64 / 31.0);
+        let restored = collapse(laplacian_pyramid(plane.clone(), 4));
+
+        for (&a, &b) in plane.data.iter().zip(&restored.data) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn single_image() {
+        let image: Vec<_> = (0..20)
+            .map(|i| LinSrgb::new(i as f64 / 20.0, 0.5, 1.0 - i as f64 / 20.0))
+            .collect();
+        let fused = ExposureFusion::new().fuse(&[&image], 5, 4);
+
+        for (&a, &b) in image.iter().zip(&fused) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn picks_well_exposed_image() {
+        let under = vec![LinSrgb::new(0.001, 0.002, 0.001); 64];
+        let good = vec![LinSrgb::new(0.2, 0.25, 0.15); 64];
+        let over = vec![LinSrgb::new(1.0, 1.0, 0.98); 64];
+
+        let fused = ExposureFusion::new()
+            .contrast(0.0)
+            .fuse(&[&under, &good, &over], 8, 8);
+
+        for color in fused {
+            assert_relative_eq!(color, LinSrgb::new(0.2, 0.25, 0.15), epsilon = 0.02);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_sizes() {
+        let a = vec![LinSrgb::new(0.5, 0.5, 0.5); 4];
+        let b = vec![LinSrgb::new(0.5, 0.5, 0.5); 3];
+        ExposureFusion::new().fuse(&[&a, &b], 2, 2);
+    }
+}
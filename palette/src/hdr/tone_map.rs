@@ -0,0 +1,229 @@
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Linear;
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// Linear light as it was in a scene, such as a render or a camera raw.
+///
+/// Scene-referred values are relative to an exposure, rather than to a
+/// display, and have no upper limit. They need to be tone mapped into
+/// [`DisplayReferred`](struct.DisplayReferred.html) values before they can be
+/// shown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SceneReferred<C> {
+    /// The scene-referred color.
+    pub color: C,
+}
+
+impl<C> SceneReferred<C> {
+    /// Mark `color` as scene-referred.
+    pub fn new(color: C) -> Self {
+        SceneReferred { color }
+    }
+}
+
+impl<S, T> SceneReferred<Rgb<Linear<S>, T>>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    /// Change the exposure by a number of `stops`, where each stop doubles
+    /// the amount of light.
+    pub fn expose(self, stops: T) -> Self {
+        SceneReferred::new(self.color * powf(from_f64::<T>(2.0), stops))
+    }
+}
+
+/// Linear light as it's shown on a display with a known peak luminance.
+///
+/// The color is relative to the peak luminance, so `1.0` is the brightest
+/// the display can show.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayReferred<C, T = f32> {
+    /// The display-referred color, where `1.0` is the peak luminance.
+    pub color: C,
+
+    /// The peak luminance of the display, in cd/m².
+    pub peak_luminance: T,
+}
+
+impl<C, T> DisplayReferred<C, T> {
+    /// Mark `color` as display-referred, for a display that peaks at
+    /// `peak_luminance` cd/m².
+    pub fn new(color: C, peak_luminance: T) -> Self {
+        DisplayReferred {
+            color,
+            peak_luminance,
+        }
+    }
+}
+
+impl<S, T> DisplayReferred<Rgb<Linear<S>, T>, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    /// The absolute light output of each channel, in cd/m².
+    pub fn to_nits(&self) -> Rgb<Linear<S>, T> {
+        self.color * self.peak_luminance
+    }
+}
+
+/// A tone mapping operator, which turns scene-referred light into
+/// display-referred light.
+///
+/// Taking [`SceneReferred`](struct.SceneReferred.html) input makes it
+/// impossible to accidentally tone map a color that has already been tone
+/// mapped, or that was never in scene light to begin with.
+///
+/// ```
+/// use palette::hdr::{Reinhard, SceneReferred, ToneMap};
+/// use palette::LinSrgb;
+///
+/// let scene = SceneReferred::new(LinSrgb::new(3.0, 2.5, 2.0));
+/// let display = Reinhard::new(8.0, 400.0).tone_map(scene);
+///
+/// assert!(display.color.red < 1.0);
+/// assert_eq!(display.peak_luminance, 400.0);
+/// ```
+pub trait ToneMap<T: FloatComponent> {
+    /// Tone map a scene-referred linear RGB color.
+    fn tone_map<S: RgbSpace>(
+        &self,
+        scene: SceneReferred<Rgb<Linear<S>, T>>,
+    ) -> DisplayReferred<Rgb<Linear<S>, T>, T>;
+}
+
+/// The extended Reinhard operator, applied to the luminance.
+///
+/// Luminance at or above the white point is mapped to the peak of the
+/// display, and the chromaticity is kept. Saturated colors may still have
+/// single channels above `1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reinhard<T = f32> {
+    white: T,
+    peak_luminance: T,
+}
+
+impl<T: FloatComponent> Reinhard<T> {
+    /// Create an operator that maps the scene luminance `white` to the peak of
+    /// a display with the peak luminance `peak_luminance` cd/m².
+    ///
+    /// Panics unless `white` is positive.
+    pub fn new(white: T, peak_luminance: T) -> Self {
+        assert!(white > T::zero());
+
+        Reinhard {
+            white,
+            peak_luminance,
+        }
+    }
+}
+
+impl<T: FloatComponent> ToneMap<T> for Reinhard<T> {
+    fn tone_map<S: RgbSpace>(
+        &self,
+        scene: SceneReferred<Rgb<Linear<S>, T>>,
+    ) -> DisplayReferred<Rgb<Linear<S>, T>, T> {
+        let color = scene.color;
+        let luminance = Xyz::from_color_unclamped(color).y;
+
+        let color = if luminance > T::zero() {
+            let white_squared = self.white * self.white;
+            let mapped =
+                luminance * (T::one() + luminance / white_squared) / (T::one() + luminance);
+            color * (mapped.min(T::one()) / luminance)
+        } else {
+            Rgb::new(T::zero(), T::zero(), T::zero())
+        };
+
+        DisplayReferred::new(color, self.peak_luminance)
+    }
+}
+
+/// Narkowicz's fit of the ACES filmic curve, applied to each channel.
+///
+/// This desaturates very bright colors toward white, like film does, and
+/// always stays within the `0.0` to `1.0` range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AcesFilmic<T = f32> {
+    peak_luminance: T,
+}
+
+impl<T: FloatComponent> AcesFilmic<T> {
+    /// Create an operator for a display with the peak luminance
+    /// `peak_luminance` cd/m².
+    pub fn new(peak_luminance: T) -> Self {
+        AcesFilmic { peak_luminance }
+    }
+
+    fn curve(x: T) -> T {
+        let x = x.max(T::zero());
+        let numerator = x * (from_f64::<T>(2.51) * x + from_f64(0.03));
+        let denominator = x * (from_f64::<T>(2.43) * x + from_f64(0.59)) + from_f64(0.14);
+
+        (numerator / denominator).min(T::one())
+    }
+}
+
+impl<T: FloatComponent> ToneMap<T> for AcesFilmic<T> {
+    fn tone_map<S: RgbSpace>(
+        &self,
+        scene: SceneReferred<Rgb<Linear<S>, T>>,
+    ) -> DisplayReferred<Rgb<Linear<S>, T>, T> {
+        let color = scene.color;
+        let color = Rgb::new(
+            Self::curve(color.red),
+            Self::curve(color.green),
+            Self::curve(color.blue),
+        );
+
+        DisplayReferred::new(color, self.peak_luminance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AcesFilmic, Reinhard, SceneReferred, ToneMap};
+    use crate::convert::FromColorUnclamped;
+    use crate::{LinSrgb, Xyz};
+
+    #[test]
+    fn reinhard() {
+        let operator = Reinhard::new(4.0, 100.0);
+
+        let white = operator.tone_map(SceneReferred::new(LinSrgb::new(4.0, 4.0, 4.0)));
+        assert_relative_eq!(white.color, LinSrgb::new(1.0, 1.0, 1.0), epsilon = 1e-6);
+        assert_relative_eq!(
+            white.to_nits(),
+            LinSrgb::new(100.0, 100.0, 100.0),
+            epsilon = 1e-4
+        );
+
+        let color = LinSrgb::new(0.8, 0.4, 0.2);
+        let mapped = operator.tone_map(SceneReferred::new(color)).color;
+        assert!(Xyz::from_color_unclamped(mapped).y < Xyz::from_color_unclamped(color).y);
+        assert_relative_eq!(mapped.green / mapped.red, 0.5, epsilon = 1e-6);
+
+        let black = operator.tone_map(SceneReferred::new(LinSrgb::new(0.0, 0.0, 0.0)));
+        assert_eq!(black.color, LinSrgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn aces_filmic() {
+        let operator = AcesFilmic::new(1000.0);
+
+        let mapped = operator.tone_map(SceneReferred::new(LinSrgb::new(100.0, 0.18, -1.0)));
+        assert_relative_eq!(mapped.color.red, 1.0);
+        assert!(mapped.color.green > 0.1 && mapped.color.green < 0.3);
+        assert_relative_eq!(mapped.color.blue, 0.0);
+        assert_eq!(mapped.peak_luminance, 1000.0);
+    }
+
+    #[test]
+    fn expose() {
+        let scene = SceneReferred::new(LinSrgb::new(0.25, 0.5, 1.0)).expose(2.0);
+        assert_relative_eq!(scene.color, LinSrgb::new(1.0, 2.0, 4.0));
+    }
+}
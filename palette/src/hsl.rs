@@ -14,10 +14,11 @@ use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
 use crate::encoding::Srgb;
 use crate::float::Float;
+use crate::hues::mix_hues;
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, FloatComponent, FromF64, GetHue, Hsv,
-    Hue, Mix, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, Chroma, Clamp, Complement, Component, FloatComponent, FromF64, GetHue,
+    Hsv, Hue, Mix, NegateLightness, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
 };
 
 /// Linear HSL with an alpha component. See the [`Hsla` implementation in
@@ -266,7 +267,8 @@ where
         if max != min {
             let d = max - min;
             s = if sum > T::one() {
-                d / (from_f64::<T>(2.0) - sum)
+                // `2 - sum` would round to zero when both are close to 1.
+                d / ((T::one() - max) + (T::one() - min))
             } else {
                 d / sum
             };
@@ -375,10 +377,11 @@ where
 
     fn mix(&self, other: &Hsl<S, T>, factor: T) -> Hsl<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
 
         Hsl {
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             saturation: self.saturation + factor * (other.saturation - self.saturation),
             lightness: self.lightness + factor * (other.lightness - self.lightness),
             standard: PhantomData,
@@ -428,7 +431,8 @@ where
     type Hue = RgbHue<T>;
 
     fn get_hue(&self) -> Option<RgbHue<T>> {
-        if self.saturation <= T::zero() {
+        if self.saturation <= T::zero() || self.lightness <= T::zero() || self.lightness >= T::one()
+        {
             None
         } else {
             Some(self.hue)
@@ -494,6 +498,45 @@ where
     }
 }
 
+impl<S, T> Chroma for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.saturation
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.saturation = chroma;
+    }
+}
+
+impl<S, T> Complement for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn complement(&self) -> Hsl<S, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
+impl<S, T> NegateLightness for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn negate_lightness(&self) -> Hsl<S, T> {
+        Hsl {
+            lightness: from_f64::<T>(1.0) - self.lightness,
+            ..*self
+        }
+    }
+}
+
 impl<S, T> Default for Hsl<S, T>
 where
     T: FloatComponent,
@@ -709,13 +752,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
@@ -830,7 +870,23 @@ where
 #[cfg(test)]
 mod test {
     use super::Hsl;
-    use crate::{FromColor, Hsv, Srgb};
+    use crate::{FromColor, GetHue, Hsv, Mix, Srgb};
+
+    #[test]
+    fn achromatic_hue() {
+        let gray = Hsl::new(0.0, 0.0, 0.5);
+        let white = Hsl::new(120.0, 1.0, 1.0);
+        let blue = Hsl::new(240.0, 1.0, 0.5);
+
+        assert_eq!(gray.get_hue(), None);
+        assert_eq!(white.get_hue(), None);
+        assert_eq!(Hsl::new(0.0, 1.0, 0.0).get_hue(), None);
+        assert_eq!(blue.get_hue(), Some(240.0.into()));
+
+        assert_eq!(gray.mix(&blue, 0.25).hue, 240.0);
+        assert_eq!(blue.mix(&white, 0.75).hue, 240.0);
+        assert_eq!(gray.mix(&white, 0.5).hue, 60.0);
+    }
 
     #[test]
     fn red() {
@@ -852,6 +908,13 @@ mod test {
         assert_relative_eq!(a, c);
     }
 
+    #[test]
+    fn almost_white() {
+        let hsl = Hsl::from_color(Srgb::new(1.0, 1.0, 0.9999999999999999f64));
+        assert_eq!(hsl.saturation, 1.0);
+        assert_relative_eq!(Srgb::from_color(hsl), Srgb::new(1.0, 1.0, 1.0));
+    }
+
     #[test]
     fn green() {
         let a = Hsl::from_color(Srgb::new(0.0, 1.0, 0.0));
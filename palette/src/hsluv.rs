@@ -9,13 +9,15 @@ use rand::distributions::Distribution;
 use rand::Rng;
 
 use crate::encoding::pixel::RawPixel;
+use crate::hues::mix_hues;
 use crate::luv_bounds::LuvBounds;
 use crate::{
-    clamp, contrast_ratio,
+    clamp,
     convert::FromColorUnclamped,
+    from_f64,
     white_point::{WhitePoint, D65},
-    Alpha, Clamp, Component, FloatComponent, GetHue, Hue, Lchuv, LuvHue, Mix, Pixel,
-    RelativeContrast, Saturate, Shade, Xyz,
+    Alpha, Chroma, Clamp, Complement, Component, FloatComponent, GetHue, Hue, Lchuv, LuvHue, Mix,
+    NegateLightness, Pixel, RelativeContrast, Saturate, Shade, Xyz,
 };
 
 /// HSLuv with an alpha component. See the [`Hsluva` implementation in
@@ -221,12 +223,13 @@ where
         // convert the chroma to a saturation based on the max
         // saturation at a particular hue.
         let max_chroma = LuvBounds::from_lightness(color.l).max_chroma_at_hue(color.hue);
+        let saturation = if max_chroma > T::zero() {
+            color.chroma / max_chroma * T::from_f64(100.0)
+        } else {
+            T::zero()
+        };
 
-        Hsluv::with_wp(
-            color.hue,
-            color.chroma / max_chroma * T::from_f64(100.0),
-            color.l,
-        )
+        Hsluv::with_wp(color.hue, saturation, color.l)
     }
 }
 
@@ -294,10 +297,11 @@ where
 
     fn mix(&self, other: &Hsluv<Wp, T>, factor: T) -> Hsluv<Wp, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
 
         Hsluv {
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             saturation: self.saturation + factor * (other.saturation - self.saturation),
             l: self.l + factor * (other.l - self.l),
             white_point: PhantomData,
@@ -347,7 +351,7 @@ where
     type Hue = LuvHue<T>;
 
     fn get_hue(&self) -> Option<LuvHue<T>> {
-        if self.saturation <= T::zero() {
+        if self.saturation <= T::zero() || self.l <= T::zero() || self.l >= from_f64(100.0) {
             None
         } else {
             Some(self.hue)
@@ -421,6 +425,45 @@ where
     }
 }
 
+impl<Wp, T> Chroma for Hsluv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.saturation
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.saturation = chroma;
+    }
+}
+
+impl<Wp, T> Complement for Hsluv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn complement(&self) -> Hsluv<Wp, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
+impl<Wp, T> NegateLightness for Hsluv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn negate_lightness(&self) -> Hsluv<Wp, T> {
+        Hsluv {
+            l: from_f64::<T>(100.0) - self.l,
+            ..*self
+        }
+    }
+}
+
 impl<Wp, T> Default for Hsluv<Wp, T>
 where
     T: FloatComponent,
@@ -463,13 +506,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
@@ -591,6 +631,12 @@ mod test {
         }
     }
 
+    #[test]
+    fn almost_black() {
+        let hsluv = Hsluv::<D65, f64>::from_color(Lchuv::new(1e-300, 0.0, 0.0));
+        assert_eq!(hsluv.saturation, 0.0);
+    }
+
     #[test]
     fn ranges() {
         assert_ranges! {
@@ -639,6 +685,16 @@ mod test {
         }
     }
 
+    #[test]
+    fn chroma() {
+        use crate::Chroma;
+
+        let color = Hsluv::<D65, f64>::new(150.0, 40.0, 60.0);
+        assert_eq!(color.chroma(), 40.0);
+        assert_eq!(color.scale_chroma(0.5), Hsluv::new(150.0, 20.0, 60.0));
+        assert_eq!(color.with_chroma(0.0).saturation, 0.0);
+    }
+
     raw_pixel_conversion_tests!(Hsluv<D65>: hue, saturation, lightness);
     raw_pixel_conversion_fail_tests!(Hsluv<D65>: hue, saturation, lightness);
 
@@ -14,10 +14,11 @@ use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
 use crate::encoding::Srgb;
 use crate::float::Float;
+use crate::hues::mix_hues;
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, FloatComponent, FromColor, FromF64,
-    GetHue, Hsl, Hue, Hwb, Mix, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, Chroma, Clamp, Complement, Component, FloatComponent, FromColor,
+    FromF64, GetHue, Hsl, Hue, Hwb, Mix, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
 };
 
 /// Linear HSV with an alpha component. See the [`Hsva` implementation in
@@ -383,10 +384,11 @@ where
 
     fn mix(&self, other: &Hsv<S, T>, factor: T) -> Hsv<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
 
         Hsv {
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             saturation: self.saturation + factor * (other.saturation - self.saturation),
             value: self.value + factor * (other.value - self.value),
             standard: PhantomData,
@@ -502,6 +504,32 @@ where
     }
 }
 
+impl<S, T> Chroma for Hsv<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.saturation
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.saturation = chroma;
+    }
+}
+
+impl<S, T> Complement for Hsv<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn complement(&self) -> Hsv<S, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
 impl<S, T> Default for Hsv<S, T>
 where
     T: FloatComponent,
@@ -717,11 +745,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+    fn relative_luminance(&self) -> T {
+        Xyz::from_color(*self).y
     }
 }
 
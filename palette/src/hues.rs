@@ -9,7 +9,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::float::Float;
-use crate::{from_f64, FromF64};
+use crate::{from_f64, FromF64, GetHue};
 
 macro_rules! make_hues {
     ($($(#[$doc:meta])+ struct $name:ident;)+) => ($(
@@ -278,6 +278,12 @@ make_hues! {
     /// A hue type for the CIE L\*u\*v\* family of color spaces.
     struct LuvHue;
 
+    /// A hue type for the Oklab family of color spaces.
+    ///
+    /// It's measured in degrees, like [`LabHue`](crate::LabHue), but the
+    /// hues of Oklab are more perceptually uniform, especially for blue.
+    struct OklabHue;
+
     /// A hue type for the RGB family of color spaces.
     ///
     /// It's measured in degrees and uses the three additive primaries _red_,
@@ -285,6 +291,20 @@ make_hues! {
     struct RgbHue;
 }
 
+/// Pick the hues to mix between. The hue of an achromatic color, where
+/// `get_hue` returns `None`, is only a placeholder, so it takes the hue of the
+/// other color instead of sweeping the mix through unrelated hues.
+pub(crate) fn mix_hues<C: GetHue<Hue = H>, H>(color: &C, other: &C, hue: H, other_hue: H) -> (H, H)
+where
+    H: Copy,
+{
+    match (color.get_hue(), other.get_hue()) {
+        (None, Some(_)) => (other_hue, other_hue),
+        (Some(_), None) => (hue, hue),
+        _ => (hue, other_hue),
+    }
+}
+
 #[inline]
 fn normalize_angle<T: Float + FromF64>(deg: T) -> T {
     let c360 = from_f64(360.0);
@@ -375,6 +395,7 @@ macro_rules! impl_uniform {
 impl_uniform!(UniformLabHue, LabHue);
 impl_uniform!(UniformRgbHue, RgbHue);
 impl_uniform!(UniformLuvHue, LuvHue);
+impl_uniform!(UniformOklabHue, OklabHue);
 
 #[cfg(test)]
 mod test {
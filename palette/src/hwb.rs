@@ -14,10 +14,11 @@ use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
 use crate::encoding::Srgb;
 use crate::float::Float;
+use crate::hues::mix_hues;
 use crate::rgb::{RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, Alpha, Clamp, Component, FloatComponent, FromF64, GetHue, Hsv, Hue, Mix,
-    Pixel, RelativeContrast, RgbHue, Shade, Xyz,
+    clamp, from_f64, Alpha, Clamp, Complement, Component, FloatComponent, FromF64, GetHue, Hsv,
+    Hue, Mix, Pixel, RelativeContrast, RgbHue, Shade, Xyz,
 };
 
 /// Linear HWB with an alpha component. See the [`Hwba` implementation in
@@ -322,10 +323,11 @@ where
 
     fn mix(&self, other: &Hwb<S, T>, factor: T) -> Hwb<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
 
         Hwb {
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             whiteness: self.whiteness + factor * (other.whiteness - self.whiteness),
             blackness: self.blackness + factor * (other.blackness - self.blackness),
             standard: PhantomData,
@@ -413,6 +415,16 @@ where
     }
 }
 
+impl<S, T> Complement for Hwb<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn complement(&self) -> Hwb<S, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
 impl<S, T> Default for Hwb<S, T>
 where
     T: FloatComponent,
@@ -657,13 +669,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
@@ -0,0 +1,376 @@
+//! HSV and HSL with integer components.
+//!
+//! [`IntHsv`](struct.IntHsv.html) and [`IntHsl`](struct.IntHsl.html) are
+//! converted to and from `Rgb<S, u8>` with integer math only, for embedded
+//! targets without an FPU and for code that sorts or bins a lot of pixels.
+//! They are approximations of [`Hsv`](../struct.Hsv.html) and
+//! [`Hsl`](../struct.Hsl.html), with components that are rounded to the
+//! nearest step, and they can't be used with the rest of the library. A round
+//! trip from RGB may change the RGB components by up to 3, since the hue
+//! steps are coarse for saturated colors. `IntHsl` with a `u8` hue may change
+//! them by up to 4, since the rounded lightness can also move the smallest
+//! component by 1.
+//!
+//! The hue is either a `u8`, where `256` is a full turn, or a `u16` in
+//! degrees, from `0` to `359`:
+//!
+//! ```
+//! use palette::integer::IntHsv;
+//! use palette::Srgb;
+//!
+//! let orange = Srgb::new(255u8, 128, 0);
+//!
+//! let hsv: IntHsv = orange.into();
+//! assert_eq!((hsv.hue, hsv.saturation, hsv.value), (21, 255, 255));
+//!
+//! let hsv: IntHsv<_, u16> = orange.into();
+//! assert_eq!((hsv.hue, hsv.saturation, hsv.value), (30, 255, 255));
+//!
+//! assert_eq!(Srgb::from(hsv), orange);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::encoding::Srgb;
+use crate::rgb::{Rgb, RgbStandard};
+
+/// An integer hue, where a full turn is divided into a fixed number of
+/// steps.
+pub trait IntHue: Copy {
+    /// The number of steps in a full turn.
+    const STEPS: u32;
+
+    /// Create a hue from a number of steps, from `0` to `STEPS - 1`.
+    fn from_steps(steps: u32) -> Self;
+
+    /// The number of steps from red, from `0` to `STEPS - 1`.
+    fn to_steps(self) -> u32;
+}
+
+impl IntHue for u8 {
+    const STEPS: u32 = 256;
+
+    fn from_steps(steps: u32) -> Self {
+        steps as u8
+    }
+
+    fn to_steps(self) -> u32 {
+        u32::from(self)
+    }
+}
+
+impl IntHue for u16 {
+    const STEPS: u32 = 360;
+
+    fn from_steps(steps: u32) -> Self {
+        steps as u16
+    }
+
+    fn to_steps(self) -> u32 {
+        u32::from(self) % 360
+    }
+}
+
+/// HSV with integer components. See the [module
+/// documentation](index.html) for details.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IntHsv<S = Srgb, H = u8> {
+    /// The hue of the color, as an [`IntHue`](trait.IntHue.html).
+    pub hue: H,
+
+    /// The colorfulness of the color, from `0` to `255`.
+    pub saturation: u8,
+
+    /// The brightness of the color, from `0` to `255`.
+    pub value: u8,
+
+    /// The RGB standard of the color.
+    pub standard: PhantomData<S>,
+}
+
+impl<S, H: IntHue> IntHsv<S, H> {
+    /// Create an integer HSV color.
+    pub fn new(hue: H, saturation: u8, value: u8) -> Self {
+        IntHsv {
+            hue,
+            saturation,
+            value,
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, H: Copy> Copy for IntHsv<S, H> {}
+
+impl<S, H: Copy> Clone for IntHsv<S, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: RgbStandard, H: IntHue> From<Rgb<S, u8>> for IntHsv<S, H> {
+    fn from(color: Rgb<S, u8>) -> Self {
+        let (hue, min, max) = hue_min_max::<H>(color.red, color.green, color.blue);
+        let saturation = if max == 0 {
+            0
+        } else {
+            div_round(255 * (max - min), max)
+        };
+
+        IntHsv::new(hue, saturation as u8, max as u8)
+    }
+}
+
+impl<S: RgbStandard, H: IntHue> From<IntHsv<S, H>> for Rgb<S, u8> {
+    fn from(color: IntHsv<S, H>) -> Self {
+        let value = u32::from(color.value);
+        let chroma = div_round(value * u32::from(color.saturation), 255);
+
+        hue_to_rgb(color.hue, chroma, value - chroma)
+    }
+}
+
+/// HSL with integer components. See the [module
+/// documentation](index.html) for details.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IntHsl<S = Srgb, H = u8> {
+    /// The hue of the color, as an [`IntHue`](trait.IntHue.html).
+    pub hue: H,
+
+    /// The colorfulness of the color, from `0` to `255`.
+    pub saturation: u8,
+
+    /// The lightness of the color, from `0` to `255`.
+    pub lightness: u8,
+
+    /// The RGB standard of the color.
+    pub standard: PhantomData<S>,
+}
+
+impl<S, H: IntHue> IntHsl<S, H> {
+    /// Create an integer HSL color.
+    pub fn new(hue: H, saturation: u8, lightness: u8) -> Self {
+        IntHsl {
+            hue,
+            saturation,
+            lightness,
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, H: Copy> Copy for IntHsl<S, H> {}
+
+impl<S, H: Copy> Clone for IntHsl<S, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: RgbStandard, H: IntHue> From<Rgb<S, u8>> for IntHsl<S, H> {
+    fn from(color: Rgb<S, u8>) -> Self {
+        let (hue, min, max) = hue_min_max::<H>(color.red, color.green, color.blue);
+        let sum = max + min;
+
+        // The largest chroma for the lightness is 255 in the middle, and goes
+        // towards 0 at black and white.
+        let max_chroma = 255 - sum.abs_diff(255);
+        let saturation = if max_chroma == 0 {
+            0
+        } else {
+            div_round(255 * (max - min), max_chroma)
+        };
+
+        IntHsl::new(hue, saturation as u8, div_round(sum, 2) as u8)
+    }
+}
+
+impl<S: RgbStandard, H: IntHue> From<IntHsl<S, H>> for Rgb<S, u8> {
+    fn from(color: IntHsl<S, H>) -> Self {
+        let double_lightness = 2 * u32::from(color.lightness);
+        let max_chroma = 255 - double_lightness.abs_diff(255);
+        let chroma = div_round(max_chroma * u32::from(color.saturation), 255);
+        let min = (double_lightness - chroma) / 2;
+
+        hue_to_rgb(color.hue, chroma, min)
+    }
+}
+
+/// Divide and round to the nearest integer.
+fn div_round(numerator: u32, denominator: u32) -> u32 {
+    (numerator + denominator / 2) / denominator
+}
+
+/// The hue, smallest and largest component of an RGB color.
+fn hue_min_max<H: IntHue>(red: u8, green: u8, blue: u8) -> (H, u32, u32) {
+    let (red, green, blue) = (u32::from(red), u32::from(green), u32::from(blue));
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let chroma = max - min;
+
+    if chroma == 0 {
+        return (H::from_steps(0), min, max);
+    }
+
+    // The hue in sixths of a turn, where each sixth is `chroma` long.
+    let sixths = if max == red {
+        if green >= blue {
+            green - blue
+        } else {
+            6 * chroma - (blue - green)
+        }
+    } else if max == green {
+        2 * chroma + blue - red
+    } else {
+        4 * chroma + red - green
+    };
+
+    let hue = div_round(sixths * H::STEPS, 6 * chroma) % H::STEPS;
+    (H::from_steps(hue), min, max)
+}
+
+/// An RGB color with the hue, chroma and smallest component.
+fn hue_to_rgb<S: RgbStandard, H: IntHue>(hue: H, chroma: u32, min: u32) -> Rgb<S, u8> {
+    let sixths = hue.to_steps() * 6;
+    let rising = min + div_round(chroma * (sixths % H::STEPS), H::STEPS);
+    let falling = min + chroma - (rising - min);
+    let max = min + chroma;
+
+    let (red, green, blue) = match sixths / H::STEPS {
+        0 => (max, rising, min),
+        1 => (falling, max, min),
+        2 => (min, max, rising),
+        3 => (min, falling, max),
+        4 => (rising, min, max),
+        _ => (max, min, falling),
+    };
+
+    Rgb::new(
+        red.min(255) as u8,
+        green.min(255) as u8,
+        blue.min(255) as u8,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IntHsl, IntHsv};
+    use crate::{FromColor, Hsl, Hsv, Srgb};
+
+    fn colors() -> impl Iterator<Item = Srgb<u8>> {
+        (0..=255u8).step_by(5).flat_map(|red| {
+            (0..=255u8).step_by(5).flat_map(move |green| {
+                (0..=255u8)
+                    .step_by(5)
+                    .map(move |blue| Srgb::new(red, green, blue))
+            })
+        })
+    }
+
+    fn hue_difference(a: f64, b: f64) -> f64 {
+        let difference = (a - b).rem_euclid(360.0);
+        difference.min(360.0 - difference)
+    }
+
+    fn max_difference(a: Srgb<u8>, b: Srgb<u8>) -> u8 {
+        let difference = |a: u8, b: u8| if a > b { a - b } else { b - a };
+        difference(a.red, b.red)
+            .max(difference(a.green, b.green))
+            .max(difference(a.blue, b.blue))
+    }
+
+    #[test]
+    fn hsv_matches_float() {
+        for color in colors() {
+            let float = Hsv::from_color(color.into_format::<f64>());
+            let degrees: IntHsv<_, u16> = color.into();
+            let turns: IntHsv = color.into();
+
+            if float.saturation > 0.0 {
+                let hue = float.hue.to_positive_degrees();
+                assert!(hue_difference(f64::from(degrees.hue), hue) <= 0.5 + 1e-9);
+                assert!(hue_difference(f64::from(turns.hue) * 360.0 / 256.0, hue) <= 0.71 + 1e-9);
+            }
+            assert!((f64::from(degrees.saturation) - float.saturation * 255.0).abs() <= 0.5 + 1e-9);
+            assert!((f64::from(degrees.value) - float.value * 255.0).abs() <= 0.5 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn hsl_matches_float() {
+        for color in colors() {
+            let float = Hsl::from_color(color.into_format::<f64>());
+            let hsl: IntHsl<_, u16> = color.into();
+
+            if float.saturation > 0.0 {
+                let hue = float.hue.to_positive_degrees();
+                assert!(hue_difference(f64::from(hsl.hue), hue) <= 0.5 + 1e-9);
+            }
+            assert!((f64::from(hsl.saturation) - float.saturation * 255.0).abs() <= 0.5 + 1e-9);
+            assert!((f64::from(hsl.lightness) - float.lightness * 255.0).abs() <= 0.5 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        // The hue steps are up to 3 RGB steps apart for saturated colors, and
+        // HSL may round the lightness as well.
+        for color in colors() {
+            let degrees: IntHsv<_, u16> = color.into();
+            assert!(
+                max_difference(Srgb::from(degrees), color) <= 2,
+                "{:?} {:?}",
+                color,
+                Srgb::from(degrees)
+            );
+
+            let turns: IntHsv = color.into();
+            assert!(
+                max_difference(Srgb::from(turns), color) <= 3,
+                "{:?} {:?}",
+                color,
+                Srgb::from(turns)
+            );
+
+            let hsl: IntHsl<_, u16> = color.into();
+            assert!(
+                max_difference(Srgb::from(hsl), color) <= 3,
+                "{:?} {:?}",
+                color,
+                Srgb::from(hsl)
+            );
+
+            let hsl: IntHsl = color.into();
+            assert!(
+                max_difference(Srgb::from(hsl), color) <= 4,
+                "{:?} {:?}",
+                color,
+                Srgb::from(hsl)
+            );
+        }
+
+        let color = Srgb::new(0u8, 1, 255);
+        let hsl: IntHsl = color.into();
+        assert_eq!((hsl.hue, hsl.saturation, hsl.lightness), (170, 255, 128));
+        assert_eq!(Srgb::from(hsl), Srgb::new(1, 5, 255));
+    }
+
+    #[test]
+    fn primaries() {
+        let hsv = IntHsv::<_, u16>::new(120, 255, 255);
+        assert_eq!(Srgb::from(hsv), Srgb::new(0, 255, 0));
+
+        let hsv = IntHsv::<_, u8>::new(128, 255, 255);
+        assert_eq!(Srgb::from(hsv), Srgb::new(0, 255, 255));
+
+        let hsl: IntHsl<_, u16> = Srgb::new(0u8, 0, 255).into();
+        assert_eq!((hsl.hue, hsl.saturation, hsl.lightness), (240, 255, 128));
+        assert_eq!(
+            Srgb::from(IntHsl::<_, u16>::new(240, 255, 127)),
+            Srgb::new(0, 0, 254)
+        );
+
+        let gray: IntHsl = Srgb::new(77u8, 77, 77).into();
+        assert_eq!((gray.hue, gray.saturation, gray.lightness), (0, 0, 77));
+    }
+}
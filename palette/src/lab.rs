@@ -8,14 +8,18 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
-use crate::color_difference::ColorDifference;
-use crate::color_difference::{get_ciede_difference, LabColorDiff};
+use crate::color_difference::{
+    get_cie76_difference, get_cie94_difference, get_ciede_difference, LabColorDiff,
+};
+use crate::color_difference::{CieDifference, ColorDifference};
 use crate::convert::FromColorUnclamped;
+use crate::encoding::lab::LabEncoding;
 use crate::encoding::pixel::RawPixel;
+use crate::math::{atan2, cbrt, cos, sin};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, ComponentWise, FloatComponent,
-    GetHue, LabHue, Lch, Mix, Pixel, RelativeContrast, Shade, Xyz,
+    clamp, from_f64, Alpha, Clamp, ColorPoint, Complement, Component, ComponentWise,
+    FloatComponent, GetHue, LabHue, Lch, Mix, NegateLightness, Pixel, RelativeContrast, Shade, Xyz,
 };
 
 /// CIE L\*a\*b\* (CIELAB) with an alpha component. See the [`Laba`
@@ -121,6 +125,35 @@ where
         Self::with_wp(l, a, b)
     }
 
+    /// Decode a color from an integer encoding, such as the 8 bit encoding
+    /// from ICC profiles. See the [`encoding::lab`](crate::encoding::lab)
+    /// module for the available encodings.
+    ///
+    /// This is the L\*a\*b\* counterpart of
+    /// [`Rgb::from_format`](crate::rgb::Rgb::from_format), where the format is
+    /// an encoding instead of a component type.
+    ///
+    /// ```
+    /// use palette::encoding::lab::IccLab16;
+    /// use palette::Lab;
+    ///
+    /// let lab: Lab = Lab::from_format::<IccLab16>([65535, 32896, 32896]);
+    /// assert_eq!(lab, Lab::new(100.0, 0.0, 0.0));
+    /// ```
+    pub fn from_format<E: LabEncoding>(encoded: [E::Component; 3]) -> Self {
+        let [l, a, b] = E::decode(encoded);
+        Self::with_wp(l, a, b)
+    }
+
+    /// Encode the color with an integer encoding, such as the 8 bit encoding
+    /// from ICC profiles. The values are rounded to the nearest integer and
+    /// clamped to the range of the encoding. See the
+    /// [`encoding::lab`](crate::encoding::lab) module for the available
+    /// encodings.
+    pub fn into_format<E: LabEncoding>(self) -> [E::Component; 3] {
+        E::encode(self.l, self.a, self.b)
+    }
+
     /// Return the `l` value minimum.
     pub fn min_l() -> T {
         T::zero()
@@ -238,7 +271,7 @@ where
             let kappa: T = from_f64(841.0 / 108.0);
             let delta: T = from_f64(4.0 / 29.0);
             if c > epsilon {
-                c.cbrt()
+                cbrt(c)
             } else {
                 (kappa * c) + delta
             }
@@ -265,8 +298,8 @@ where
     fn from_color_unclamped(color: Lch<Wp, T>) -> Self {
         Lab {
             l: color.l,
-            a: color.chroma.max(T::zero()) * color.hue.to_radians().cos(),
-            b: color.chroma.max(T::zero()) * color.hue.to_radians().sin(),
+            a: color.chroma.max(T::zero()) * cos(color.hue.to_radians()),
+            b: color.chroma.max(T::zero()) * sin(color.hue.to_radians()),
             white_point: PhantomData,
         }
     }
@@ -385,7 +418,22 @@ where
         if self.a == T::zero() && self.b == T::zero() {
             None
         } else {
-            Some(LabHue::from_radians(self.b.atan2(self.a)))
+            Some(LabHue::from_radians(atan2(self.b, self.a)))
+        }
+    }
+}
+
+impl<Wp, T> Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn difference_params(&self) -> LabColorDiff<T> {
+        LabColorDiff {
+            l: self.l,
+            a: self.a,
+            b: self.b,
+            chroma: (self.a * self.a + self.b * self.b).sqrt(),
         }
     }
 }
@@ -401,20 +449,48 @@ where
         // Color difference calculation requires Lab and chroma components. This
         // function handles the conversion into those components which are then
         // passed to `get_ciede_difference()` where calculation is completed.
-        let self_params = LabColorDiff {
-            l: self.l,
-            a: self.a,
-            b: self.b,
-            chroma: (self.a * self.a + self.b * self.b).sqrt(),
-        };
-        let other_params = LabColorDiff {
-            l: other.l,
-            a: other.a,
-            b: other.b,
-            chroma: (other.a * other.a + other.b * other.b).sqrt(),
-        };
+        get_ciede_difference(&self.difference_params(), &other.difference_params())
+    }
+}
+
+impl<Wp, T> CieDifference for Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn delta_e_cie76(&self, other: &Lab<Wp, T>) -> T {
+        get_cie76_difference(&self.difference_params(), &other.difference_params())
+    }
+
+    fn delta_e_cie94(&self, other: &Lab<Wp, T>) -> T {
+        get_cie94_difference(&self.difference_params(), &other.difference_params())
+    }
 
-        get_ciede_difference(&self_params, &other_params)
+    fn delta_e_ciede2000(&self, other: &Lab<Wp, T>) -> T {
+        self.get_color_difference(other)
+    }
+}
+
+impl<Wp, T> ColorPoint for Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Lab<Wp, T>) -> T {
+        self.l * other.l + self.a * other.a + self.b * other.b
+    }
+
+    fn linear_combination(&self, a: T, other: &Lab<Wp, T>, b: T) -> Lab<Wp, T> {
+        Lab {
+            l: a * self.l + b * other.l,
+            a: a * self.a + b * other.a,
+            b: a * self.b + b * other.b,
+            white_point: PhantomData,
+        }
     }
 }
 
@@ -444,6 +520,33 @@ where
     }
 }
 
+impl<Wp, T> Complement for Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn complement(&self) -> Lab<Wp, T> {
+        Lab {
+            a: -self.a,
+            b: -self.b,
+            ..*self
+        }
+    }
+}
+
+impl<Wp, T> NegateLightness for Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn negate_lightness(&self) -> Lab<Wp, T> {
+        Lab {
+            l: from_f64::<T>(100.0) - self.l,
+            ..*self
+        }
+    }
+}
+
 impl<Wp, T> Default for Lab<Wp, T>
 where
     T: FloatComponent,
@@ -715,13 +818,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
@@ -884,6 +984,39 @@ mod test {
         assert_relative_eq!(Lab::<D65, f32>::max_b(), 127.0);
     }
 
+    #[test]
+    fn color_point() {
+        use crate::{ColorDifference, ColorPoint};
+
+        let a = Lab::<D65, f64>::new(60.0, 20.0, -30.0);
+        let b = Lab::<D65, f64>::new(40.0, -10.0, 10.0);
+
+        assert_relative_eq!(a.dot(&b), 1900.0);
+        assert_relative_eq!(a.norm_squared(), 4900.0);
+        assert_relative_eq!(a.distance(&b), b.distance(&a));
+        assert_relative_eq!(a.distance_squared(&b), 2900.0);
+        assert_relative_eq!(a.distance(&a), 0.0);
+        assert_relative_eq!(a.linear_combination(1.0, &b, -1.0).norm(), a.distance(&b));
+        assert!(a.distance(&b) > a.get_color_difference(&b));
+    }
+
+    #[test]
+    fn cie_differences() {
+        use crate::{CieDifference, Lch};
+
+        let a = Lab::<D65, f64>::new(50.0, 2.6772, -79.7751);
+        let b = Lab::<D65, f64>::new(50.0, 0.0, -82.7485);
+
+        assert_relative_eq!(a.delta_e_cie76(&b), 4.0011, epsilon = 1e-4);
+        assert_relative_eq!(a.delta_e_cie94(&b), 1.3950, epsilon = 1e-4);
+        assert_relative_eq!(a.delta_e_ciede2000(&b), 2.0425, epsilon = 1e-4);
+
+        let (lch_a, lch_b) = (Lch::from_color(a), Lch::from_color(b));
+        assert_relative_eq!(lch_a.delta_e_cie76(&lch_b), 4.0011, epsilon = 1e-4);
+        assert_relative_eq!(lch_a.delta_e_cie94(&lch_b), 1.3950, epsilon = 1e-4);
+        assert_relative_eq!(lch_a.delta_e_ciede2000(&lch_b), 2.0425, epsilon = 1e-4);
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
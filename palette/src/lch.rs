@@ -8,14 +8,17 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
-use crate::color_difference::ColorDifference;
 use crate::color_difference::{get_ciede_difference, LabColorDiff};
+use crate::color_difference::{CieDifference, ColorDifference};
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::encoding::lch::LchEncoding;
 use crate::encoding::pixel::RawPixel;
+use crate::hues::mix_hues;
+use crate::math::{cos, sin};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, FloatComponent, FromColor, GetHue,
-    Hue, Lab, LabHue, Mix, Pixel, RelativeContrast, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, Chroma, Clamp, Complement, Component, FloatComponent, FromColor,
+    GetHue, Hue, Lab, LabHue, Mix, NegateLightness, Pixel, RelativeContrast, Saturate, Shade, Xyz,
 };
 
 /// CIE L\*C\*h° with an alpha component. See the [`Lcha` implementation in
@@ -121,6 +124,35 @@ where
         Self::with_wp(l, chroma, hue)
     }
 
+    /// Decode a color from an integer encoding. See the
+    /// [`encoding::lch`](crate::encoding::lch) module for the available
+    /// encodings.
+    ///
+    /// This is the L\*C\*h° counterpart of
+    /// [`Rgb::from_format`](crate::rgb::Rgb::from_format), where the format is
+    /// an encoding instead of a component type.
+    ///
+    /// ```
+    /// use palette::encoding::lch::Lch16;
+    /// use palette::Lch;
+    ///
+    /// let lch: Lch = Lch::from_format::<Lch16>([65535, 0, 32768]);
+    /// assert_eq!(lch, Lch::new(100.0, 0.0, 180.0));
+    /// ```
+    pub fn from_format<E: LchEncoding>(encoded: [E::Component; 3]) -> Self {
+        let (l, chroma, hue) = E::decode(encoded);
+        Self::with_wp(l, chroma, hue)
+    }
+
+    /// Encode the color with an integer encoding. L\* and chroma are rounded
+    /// to the nearest integer and clamped to the range of the encoding, and
+    /// the hue is rounded to the nearest step. See the
+    /// [`encoding::lch`](crate::encoding::lch) module for the available
+    /// encodings.
+    pub fn into_format<E: LchEncoding>(self) -> [E::Component; 3] {
+        E::encode(self.l, self.chroma, self.hue)
+    }
+
     /// Return the `l` value minimum.
     pub fn min_l() -> T {
         T::zero()
@@ -303,11 +335,12 @@ where
 
     fn mix(&self, other: &Lch<Wp, T>, factor: T) -> Lch<Wp, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
         Lch {
             l: self.l + factor * (other.l - self.l),
             chroma: self.chroma + factor * (other.chroma - self.chroma),
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             white_point: PhantomData,
         }
     }
@@ -398,22 +431,22 @@ where
     fn get_color_difference(&self, other: &Lch<Wp, T>) -> Self::Scalar {
         // Prepare a* and b* from Lch components to calculate color difference
         let self_a = clamp(
-            self.chroma.max(T::zero()) * self.hue.to_radians().cos(),
+            self.chroma.max(T::zero()) * cos(self.hue.to_radians()),
             from_f64(-128.0),
             from_f64(127.0),
         );
         let self_b = clamp(
-            self.chroma.max(T::zero()) * self.hue.to_radians().sin(),
+            self.chroma.max(T::zero()) * sin(self.hue.to_radians()),
             from_f64(-128.0),
             from_f64(127.0),
         );
         let other_a = clamp(
-            other.chroma.max(T::zero()) * other.hue.to_radians().cos(),
+            other.chroma.max(T::zero()) * cos(other.hue.to_radians()),
             from_f64(-128.0),
             from_f64(127.0),
         );
         let other_b = clamp(
-            other.chroma.max(T::zero()) * other.hue.to_radians().sin(),
+            other.chroma.max(T::zero()) * sin(other.hue.to_radians()),
             from_f64(-128.0),
             from_f64(127.0),
         );
@@ -434,6 +467,27 @@ where
     }
 }
 
+/// The differences are calculated in `Lab`, without limiting the chroma.
+impl<Wp, T> CieDifference for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn delta_e_cie76(&self, other: &Lch<Wp, T>) -> T {
+        Lab::from_color_unclamped(*self).delta_e_cie76(&Lab::from_color_unclamped(*other))
+    }
+
+    fn delta_e_cie94(&self, other: &Lch<Wp, T>) -> T {
+        Lab::from_color_unclamped(*self).delta_e_cie94(&Lab::from_color_unclamped(*other))
+    }
+
+    fn delta_e_ciede2000(&self, other: &Lch<Wp, T>) -> T {
+        Lab::from_color_unclamped(*self).delta_e_ciede2000(&Lab::from_color_unclamped(*other))
+    }
+}
+
 impl<Wp, T> Saturate for Lch<Wp, T>
 where
     T: FloatComponent,
@@ -468,6 +522,45 @@ where
     }
 }
 
+impl<Wp, T> Chroma for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.chroma
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.chroma = chroma;
+    }
+}
+
+impl<Wp, T> Complement for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn complement(&self) -> Lch<Wp, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
+impl<Wp, T> NegateLightness for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn negate_lightness(&self) -> Lch<Wp, T> {
+        Lch {
+            l: from_f64::<T>(100.0) - self.l,
+            ..*self
+        }
+    }
+}
+
 impl<Wp, T> Default for Lch<Wp, T>
 where
     T: FloatComponent,
@@ -623,11 +716,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+    fn relative_luminance(&self) -> T {
+        Xyz::from_color(*self).y
     }
 }
 
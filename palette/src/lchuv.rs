@@ -10,11 +10,14 @@ use rand::Rng;
 
 use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
+use crate::hues::mix_hues;
 use crate::luv_bounds::LuvBounds;
+use crate::math::hypot;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, FloatComponent, FromColor, GetHue,
-    Hsluv, Hue, Luv, LuvHue, Mix, Pixel, RelativeContrast, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, Chroma, Clamp, Complement, Component, FloatComponent, FromColor,
+    GetHue, Hsluv, Hue, Luv, LuvHue, Mix, NegateLightness, Pixel, RelativeContrast, Saturate,
+    Shade, Xyz,
 };
 
 /// CIE L\*C\*uv h°uv with an alpha component. See the [`Lchuva` implementation in
@@ -218,7 +221,7 @@ where
     fn from_color_unclamped(color: Luv<Wp, T>) -> Self {
         Lchuv {
             l: color.l,
-            chroma: color.u.hypot(color.v),
+            chroma: hypot(color.u, color.v),
             hue: color.get_hue().unwrap_or(LuvHue::from(T::zero())),
             white_point: PhantomData,
         }
@@ -304,11 +307,12 @@ where
 
     fn mix(&self, other: &Lchuv<Wp, T>, factor: T) -> Lchuv<Wp, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
         Lchuv {
             l: self.l + factor * (other.l - self.l),
             chroma: self.chroma + factor * (other.chroma - self.chroma),
-            hue: self.hue + factor * hue_diff,
+            hue: hue + factor * hue_diff,
             white_point: PhantomData,
         }
     }
@@ -422,6 +426,45 @@ where
     }
 }
 
+impl<Wp, T> Chroma for Lchuv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.chroma
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.chroma = chroma;
+    }
+}
+
+impl<Wp, T> Complement for Lchuv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn complement(&self) -> Lchuv<Wp, T> {
+        self.shift_hue(from_f64::<T>(180.0))
+    }
+}
+
+impl<Wp, T> NegateLightness for Lchuv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn negate_lightness(&self) -> Lchuv<Wp, T> {
+        Lchuv {
+            l: from_f64::<T>(100.0) - self.l,
+            ..*self
+        }
+    }
+}
+
 impl<Wp, T> Default for Lchuv<Wp, T>
 where
     T: FloatComponent,
@@ -464,11 +507,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+    fn relative_luminance(&self) -> T {
+        Xyz::from_color(*self).y
     }
 }
 
@@ -0,0 +1,336 @@
+//! Conversion to RGBW and RGBWW LEDs and PWM levels.
+//!
+//! LED strips and DMX fixtures often have one or two white LEDs next to the
+//! red, green and blue ones. The white LEDs are more efficient and give a
+//! better white than mixing the three colors, so as much as possible of a
+//! color should be shown with them. [`WhiteLed`](struct.WhiteLed.html)
+//! describes the color and brightness of a white LED, and
+//! [`Rgbw`](struct.Rgbw.html) and [`Rgbww`](struct.Rgbww.html) split a color
+//! into the white and the remaining RGB part.
+//!
+//! The colors are linear sRGB, since that's the closest common color space
+//! to most RGB LEDs, and since the light of an LED is linear in its PWM duty
+//! cycle.
+//!
+//! ```
+//! use palette::led::{Rgbw, WhiteLed};
+//! use palette::LinSrgb;
+//!
+//! // A warm white LED, as bright as the RGB LEDs together.
+//! let white = WhiteLed::from_temperature(3000.0, 1.0);
+//!
+//! let color = LinSrgb::new(0.8, 0.5, 0.3);
+//! let rgbw = Rgbw::from_rgb(color, &white);
+//! assert!(rgbw.white > 0.0);
+//!
+//! // 8 bit levels for a DMX fixture, with a gamma of 1.0 for each channel.
+//! let levels = rgbw.to_pwm([1.0; 4], 8);
+//! assert!(levels.iter().all(|&level| level <= 255));
+//! ```
+
+use crate::chromaticity::planckian_xy;
+use crate::convert::FromColorUnclamped;
+use crate::white_point::D65;
+use crate::{clamp, from_f64, FloatComponent, LinSrgb, Xyz};
+
+/// The color of a white LED at full power, in linear sRGB.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WhiteLed<T: FloatComponent = f32> {
+    color: LinSrgb<T>,
+}
+
+impl<T: FloatComponent> WhiteLed<T> {
+    /// Describe a white LED by the CIE 1931 xy coordinates of its light, and
+    /// its `luminance` relative to the red, green and blue LEDs at full power
+    /// together.
+    pub fn from_xy(x: T, y: T, luminance: T) -> Self {
+        let xyz = Xyz::<D65, T>::with_wp(
+            x * luminance / y,
+            luminance,
+            (T::one() - x - y) * luminance / y,
+        );
+
+        WhiteLed::from_rgb(LinSrgb::from_color_unclamped(xyz))
+    }
+
+    /// Describe a white LED by its color temperature in kelvin, and its
+    /// `luminance` relative to the red, green and blue LEDs at full power
+    /// together. The light is assumed to be on the Planckian locus.
+    pub fn from_temperature(temperature: T, luminance: T) -> Self {
+        let (x, y) = planckian_xy(temperature);
+        WhiteLed::from_xy(x, y, luminance)
+    }
+
+    /// Describe a white LED by the linear sRGB color of its light. Negative
+    /// components are clamped to `0.0`.
+    pub fn from_rgb(color: LinSrgb<T>) -> Self {
+        WhiteLed {
+            color: LinSrgb::new(
+                color.red.max(T::zero()),
+                color.green.max(T::zero()),
+                color.blue.max(T::zero()),
+            ),
+        }
+    }
+
+    /// The color of the LED at full power.
+    pub fn color(&self) -> LinSrgb<T> {
+        self.color
+    }
+
+    /// The largest amount of this white, from `0.0` to `1.0`, that fits
+    /// within `color`.
+    fn amount_in(&self, color: LinSrgb<T>) -> T {
+        let mut amount = T::one();
+        let mut any = false;
+
+        let components = [
+            (color.red, self.color.red),
+            (color.green, self.color.green),
+            (color.blue, self.color.blue),
+        ];
+        for &(component, white) in &components {
+            if white > T::zero() {
+                amount = amount.min(component.max(T::zero()) / white);
+                any = true;
+            }
+        }
+
+        if any {
+            amount
+        } else {
+            T::zero()
+        }
+    }
+}
+
+/// The levels of a red, green, blue and white LED.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rgbw<T = f32> {
+    /// The level of the red LED.
+    pub red: T,
+
+    /// The level of the green LED.
+    pub green: T,
+
+    /// The level of the blue LED.
+    pub blue: T,
+
+    /// The level of the white LED.
+    pub white: T,
+}
+
+impl<T: FloatComponent> Rgbw<T> {
+    /// Split `color` into as much `white` as possible, and the remaining red,
+    /// green and blue.
+    pub fn from_rgb(color: LinSrgb<T>, white: &WhiteLed<T>) -> Self {
+        let amount = white.amount_in(color);
+        let white_color = white.color * amount;
+
+        Rgbw {
+            red: (color.red - white_color.red).max(T::zero()),
+            green: (color.green - white_color.green).max(T::zero()),
+            blue: (color.blue - white_color.blue).max(T::zero()),
+            white: amount,
+        }
+    }
+
+    /// The linear sRGB color of the light from the LEDs.
+    pub fn into_rgb(self, white: &WhiteLed<T>) -> LinSrgb<T> {
+        LinSrgb::new(self.red, self.green, self.blue) + white.color * self.white
+    }
+
+    /// Convert the levels to integer PWM duty cycles with `bits` bits, using
+    /// a `gamma` for each channel, in the order red, green, blue and white.
+    /// See [`pwm_level`](fn.pwm_level.html).
+    pub fn to_pwm(&self, gamma: [T; 4], bits: u32) -> [u16; 4] {
+        [
+            pwm_level(self.red, gamma[0], bits),
+            pwm_level(self.green, gamma[1], bits),
+            pwm_level(self.blue, gamma[2], bits),
+            pwm_level(self.white, gamma[3], bits),
+        ]
+    }
+}
+
+/// The levels of a red, green, blue, warm white and cool white LED.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rgbww<T = f32> {
+    /// The level of the red LED.
+    pub red: T,
+
+    /// The level of the green LED.
+    pub green: T,
+
+    /// The level of the blue LED.
+    pub blue: T,
+
+    /// The level of the warm white LED.
+    pub warm_white: T,
+
+    /// The level of the cool white LED.
+    pub cool_white: T,
+}
+
+impl<T: FloatComponent> Rgbww<T> {
+    /// Split `color` into as much white as possible, from a mix of `warm` and
+    /// `cool`, and the remaining red, green and blue.
+    ///
+    /// The mix of the two whites is chosen to cover as much of `color` as
+    /// possible, so a warm color gets more of the warm white and a cold color
+    /// gets more of the cool white.
+    pub fn from_rgb(color: LinSrgb<T>, warm: &WhiteLed<T>, cool: &WhiteLed<T>) -> Self {
+        let mixed = |cool_share: T| {
+            WhiteLed::from_rgb(warm.color * (T::one() - cool_share) + cool.color * cool_share)
+        };
+
+        // The amount of white is unimodal in the share of the cool white,
+        // since it's the smallest of one increasing or decreasing ratio per
+        // component. A golden section search finds the top.
+        let ratio: T = from_f64(0.618_033_988_749_894_9);
+        let (mut low, mut high) = (T::zero(), T::one());
+        for _ in 0..40 {
+            let left = high - (high - low) * ratio;
+            let right = low + (high - low) * ratio;
+
+            if mixed(left).amount_in(color) < mixed(right).amount_in(color) {
+                low = left;
+            } else {
+                high = right;
+            }
+        }
+
+        let cool_share = (low + high) / from_f64(2.0);
+        let rgbw = Rgbw::from_rgb(color, &mixed(cool_share));
+
+        Rgbww {
+            red: rgbw.red,
+            green: rgbw.green,
+            blue: rgbw.blue,
+            warm_white: rgbw.white * (T::one() - cool_share),
+            cool_white: rgbw.white * cool_share,
+        }
+    }
+
+    /// The linear sRGB color of the light from the LEDs.
+    pub fn into_rgb(self, warm: &WhiteLed<T>, cool: &WhiteLed<T>) -> LinSrgb<T> {
+        LinSrgb::new(self.red, self.green, self.blue)
+            + warm.color * self.warm_white
+            + cool.color * self.cool_white
+    }
+
+    /// Convert the levels to integer PWM duty cycles with `bits` bits, using
+    /// a `gamma` for each channel, in the order red, green, blue, warm white
+    /// and cool white. See [`pwm_level`](fn.pwm_level.html).
+    pub fn to_pwm(&self, gamma: [T; 5], bits: u32) -> [u16; 5] {
+        [
+            pwm_level(self.red, gamma[0], bits),
+            pwm_level(self.green, gamma[1], bits),
+            pwm_level(self.blue, gamma[2], bits),
+            pwm_level(self.warm_white, gamma[3], bits),
+            pwm_level(self.cool_white, gamma[4], bits),
+        ]
+    }
+}
+
+/// Convert a linear `level`, from `0.0` to `1.0`, to an integer PWM duty
+/// cycle with `bits` bits, after raising it to the power of `gamma`.
+///
+/// The light of an LED is close to linear in its duty cycle, so a `gamma` of
+/// `1.0` is correct for linear colors. Other values compensate for LEDs or
+/// drivers that aren't linear, or for a fixture that already applies a
+/// curve. DMX channels have 8 bits, or 16 bits when a coarse and a fine
+/// channel are combined.
+///
+/// Panics if `bits` is not in `1..=16`.
+pub fn pwm_level<T: FloatComponent>(level: T, gamma: T, bits: u32) -> u16 {
+    assert!(
+        (1..=16).contains(&bits),
+        "the number of bits must be between 1 and 16"
+    );
+
+    let max: T = from_f64(f64::from((1u32 << bits) - 1));
+    let level = crate::math::powf(clamp(level, T::zero(), T::one()), gamma);
+    (level * max).round().to_u16().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{pwm_level, Rgbw, Rgbww, WhiteLed};
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{FromColor, LinSrgb, Xyz};
+
+    #[test]
+    fn white_led_color() {
+        let white: Xyz<D65, f64> = D65::get_xyz();
+        let x = white.x / (white.x + white.y + white.z);
+        let y = white.y / (white.x + white.y + white.z);
+
+        // A D65 white LED as bright as the RGB LEDs is the same as all of them.
+        let led = WhiteLed::from_xy(x, y, 1.0);
+        assert_relative_eq!(led.color(), LinSrgb::new(1.0, 1.0, 1.0), epsilon = 1e-6);
+
+        // Warm white is red-ish.
+        let warm = WhiteLed::from_temperature(2700.0f64, 1.0).color();
+        assert!(warm.red > warm.green && warm.green > warm.blue);
+        assert_relative_eq!(Xyz::<D65, f64>::from_color(warm).y, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn rgbw() {
+        let led = WhiteLed::from_rgb(LinSrgb::new(1.0f64, 0.8, 0.6));
+        let color = LinSrgb::new(0.9, 0.6, 0.3);
+        let rgbw = Rgbw::from_rgb(color, &led);
+
+        // Blue is the limiting component.
+        assert_relative_eq!(rgbw.white, 0.5);
+        assert_relative_eq!(rgbw.blue, 0.0);
+        assert_relative_eq!(rgbw.into_rgb(&led), color);
+
+        // Bright whites are limited to the LED's full power.
+        let rgbw = Rgbw::from_rgb(LinSrgb::new(2.0, 2.0, 2.0), &led);
+        assert_relative_eq!(rgbw.white, 1.0);
+        assert_relative_eq!(rgbw.into_rgb(&led), LinSrgb::new(2.0, 2.0, 2.0));
+
+        // Colors without one of the components get no white.
+        let rgbw = Rgbw::from_rgb(LinSrgb::new(1.0, 0.0, 0.5), &led);
+        assert_relative_eq!(rgbw.white, 0.0);
+    }
+
+    #[test]
+    fn rgbww() {
+        let warm = WhiteLed::from_rgb(LinSrgb::new(1.0f64, 0.6, 0.2));
+        let cool = WhiteLed::from_rgb(LinSrgb::new(0.6, 0.8, 1.0));
+
+        let color = LinSrgb::new(0.8, 0.7, 0.6);
+        let rgbww = Rgbww::from_rgb(color, &warm, &cool);
+        assert!(rgbww.warm_white > 0.0 && rgbww.cool_white > 0.0);
+        assert_relative_eq!(rgbww.into_rgb(&warm, &cool), color, epsilon = 1e-9);
+
+        // The best mix removes at least as much as either white alone.
+        let total = rgbww.warm_white + rgbww.cool_white;
+        assert!(total + 1e-9 >= Rgbw::from_rgb(color, &warm).white);
+        assert!(total + 1e-9 >= Rgbw::from_rgb(color, &cool).white);
+
+        // A warm color only uses the warm white.
+        let rgbww = Rgbww::from_rgb(LinSrgb::new(1.0, 0.6, 0.2), &warm, &cool);
+        assert_relative_eq!(rgbww.warm_white, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(rgbww.cool_white, 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn pwm() {
+        assert_eq!(pwm_level(0.5f64, 1.0, 8), 128);
+        assert_eq!(pwm_level(0.5f64, 2.0, 8), 64);
+        assert_eq!(pwm_level(1.5f64, 1.0, 12), 4095);
+        assert_eq!(pwm_level(-1.0f64, 1.0, 16), 0);
+
+        let rgbw = Rgbw {
+            red: 1.0f64,
+            green: 0.5,
+            blue: 0.25,
+            white: 0.0,
+        };
+        assert_eq!(rgbw.to_pwm([1.0, 1.0, 2.0, 1.0], 8), [255, 128, 16, 0]);
+    }
+}
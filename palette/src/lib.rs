@@ -232,10 +232,13 @@ use float::Float;
 
 use luma::Luma;
 
-pub use alpha::{Alpha, WithAlpha};
+pub use alpha::{Alpha, Opacity, WithAlpha};
 pub use blend::Blend;
+pub use cam02_ucs::{Cam02Ucs, Cam02Ucsa};
+pub use cam16_ucs::{Cam16Ucs, Cam16Ucsa};
 #[cfg(feature = "std")]
 pub use gradient::Gradient;
+pub use hct::{Hct, Hcta};
 
 pub use hsl::{Hsl, Hsla};
 pub use hsluv::{Hsluv, Hsluva};
@@ -246,17 +249,25 @@ pub use lch::{Lch, Lcha};
 pub use lchuv::{Lchuv, Lchuva};
 pub use luma::{GammaLuma, GammaLumaa, LinLuma, LinLumaa, SrgbLuma, SrgbLumaa};
 pub use luv::{Luv, Luva};
-pub use rgb::{GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, Packed, RgbChannels, Srgb, Srgba};
+pub use okhsl::{Okhsl, Okhsla};
+pub use okhsv::{Okhsv, Okhsva};
+pub use oklab::{Oklab, Oklaba};
+pub use oklch::{Oklch, Oklcha};
+pub use rgb::{
+    lin_srgb, srgb, GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, Packed, RgbChannels, Srgb, Srgba,
+};
 pub use xyz::{Xyz, Xyza};
 pub use yxy::{Yxy, Yxya};
 
-pub use color_difference::ColorDifference;
+pub use color_difference::{CieDifference, ColorDifference};
 pub use component::*;
 pub use convert::{FromColor, IntoColor};
-pub use encoding::pixel::Pixel;
-pub use hues::{LabHue, LuvHue, RgbHue};
+pub use encoding::pixel::{ComponentArray, Pixel};
+pub use hues::{LabHue, LuvHue, OklabHue, RgbHue};
 pub use matrix::Mat3;
-pub use relative_contrast::{contrast_ratio, RelativeContrast};
+pub use relative_contrast::{
+    adjust_for_contrast, apca_contrast, contrast_ratio, ContrastMetric, RelativeContrast,
+};
 
 //Helper macro for checking ranges and clamping.
 #[cfg(test)]
@@ -412,17 +423,60 @@ macro_rules! assert_ranges {
 #[macro_use]
 mod macros;
 
+pub mod adjust;
 pub mod blend;
+pub mod cam16;
+pub mod chromaticity;
+#[cfg(feature = "std")]
+pub mod cluster;
+#[cfg(feature = "std")]
+pub mod color_rendering;
+pub mod colorchecker;
+pub mod colormap;
+pub mod css;
+#[cfg(feature = "std")]
+pub mod difference;
+pub mod dither;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod gamut;
 #[cfg(feature = "std")]
 pub mod gradient;
+#[cfg(feature = "std")]
+pub mod graph;
+pub mod grayscale;
+pub mod hdr;
+pub mod integer;
+pub mod led;
+#[cfg(feature = "std")]
+pub mod lut;
+pub mod material;
+#[cfg(feature = "std")]
+pub mod metamerism;
+pub mod naming;
+pub mod normalize;
+pub mod picker;
+pub mod reference;
+pub mod sort;
+#[cfg(feature = "std")]
+pub mod spectrum;
+pub mod tile;
+pub mod transition;
+pub mod ycbcr;
 
 #[cfg(feature = "named")]
 pub mod named;
 
+#[cfg(feature = "shader")]
+pub mod shader;
+
 #[cfg(feature = "random")]
 mod random_sampling;
 
 mod alpha;
+mod cam02_ucs;
+mod cam16_ucs;
+mod hct;
 mod hsl;
 mod hsluv;
 mod hsv;
@@ -432,21 +486,31 @@ mod lch;
 mod lchuv;
 pub mod luma;
 mod luv;
+mod okhsl;
+mod okhsv;
+mod oklab;
+mod oklch;
 pub mod rgb;
 mod xyz;
 mod yxy;
 
 mod hues;
 
+mod cam;
 pub mod chromatic_adaptation;
+mod cmf;
 mod color_difference;
 mod component;
 pub mod convert;
 pub mod encoding;
 mod equality;
 mod luv_bounds;
+mod math;
+mod ok_utils;
 mod relative_contrast;
+pub mod white_balance;
 pub mod white_point;
+pub mod whiteness;
 
 pub mod float;
 
@@ -607,7 +671,22 @@ pub trait GetHue {
     /// Calculate a hue if possible.
     ///
     /// Colors in the gray scale has no well defined hue and should preferably
-    /// return `None`.
+    /// return `None`. The types in this library return `None` for every
+    /// achromatic color, such as when the saturation or chroma is zero, or
+    /// when `Hsl` is black or white. Their `hue` field is only a placeholder
+    /// for those colors, which is ignored when they are mixed with a color
+    /// that has a hue:
+    ///
+    /// ```
+    /// use palette::{GetHue, Hsl, Mix};
+    ///
+    /// let gray = Hsl::new(0.0, 0.0, 0.5);
+    /// let blue = Hsl::new(240.0, 1.0, 0.5);
+    /// assert_eq!(gray.get_hue(), None);
+    ///
+    /// // The mix keeps the blue hue, instead of passing through magenta.
+    /// assert_eq!(gray.mix(&blue, 0.5).hue, 240.0);
+    /// ```
     fn get_hue(&self) -> Option<Self::Hue>;
 }
 
@@ -620,6 +699,86 @@ pub trait Hue: GetHue {
     fn shift_hue<H: Into<Self::Hue>>(&self, amount: H) -> Self;
 }
 
+/// Component inversion, as in a photo negative.
+///
+/// Each component is mirrored within its range, so `x` becomes
+/// `max_intensity - x`, in the space where the color is stored. Inverting an
+/// sRGB color therefore gives the same result as most image editors, while
+/// inverting a linear color gives the physically inverted light intensity.
+/// Transparency is not affected.
+///
+/// ```
+/// use palette::{Invert, Srgb};
+///
+/// assert_eq!(Srgb::new(255u8, 200, 0).invert(), Srgb::new(0, 55, 255));
+/// assert_eq!(Srgb::new(1.0, 0.25, 0.0).invert(), Srgb::new(0.0, 0.75, 1.0));
+/// ```
+pub trait Invert {
+    /// Return a copy of `self` where the components have been inverted.
+    fn invert(&self) -> Self;
+}
+
+/// The complementary color, with the opposite hue.
+///
+/// For colors with a hue component, this is a 180° rotation of the hue, and
+/// for the opponent spaces, L\*a\*b\* and L\*u\*v\*, it's the same as
+/// negating the color axes. RGB colors are mirrored around the middle of
+/// their smallest and largest component, which is the same as a 180° rotation
+/// in HSL or HSV.
+///
+/// The result depends on which space the hue is rotated in. Use
+/// [`complement_in`](fn.complement_in.html) to pick a different space.
+///
+/// ```
+/// use palette::{Complement, Hsl, Srgb};
+///
+/// assert_eq!(Hsl::new(30.0, 0.5, 0.4).complement(), Hsl::new(210.0, 0.5, 0.4));
+/// assert_eq!(Srgb::new(1.0, 0.5, 0.0).complement(), Srgb::new(0.0, 0.5, 1.0));
+/// ```
+pub trait Complement {
+    /// Return the complementary color of `self`.
+    fn complement(&self) -> Self;
+}
+
+/// Find the complementary color of `color`, by rotating its hue in the space
+/// `S`.
+///
+/// The result is converted back and clamped, in case the rotated color is
+/// outside the gamut of `color`'s space.
+///
+/// ```
+/// use palette::{complement_in, Lch, Srgb};
+///
+/// // The perceptual complement of orange, using L*C*h°.
+/// let blue: Srgb = complement_in::<Lch, _>(Srgb::new(1.0, 0.5, 0.0));
+/// assert!(blue.blue > blue.red);
+/// ```
+pub fn complement_in<S, C>(color: C) -> C
+where
+    S: Complement + FromColor<C>,
+    C: FromColor<S>,
+{
+    C::from_color(S::from_color(color).complement())
+}
+
+/// Lightness inversion, that keeps the hue and saturation.
+///
+/// This mirrors the lightness within its range, so dark colors become light
+/// and the other way around, which is useful for deriving a dark theme from a
+/// light one, or the other way around. It's only implemented for spaces with
+/// a lightness component, since the result depends on how lightness is
+/// defined. L\*C\*h° usually gives the most natural result.
+///
+/// ```
+/// use palette::{Lch, NegateLightness};
+///
+/// assert_eq!(Lch::new(20.0, 30.0, 120.0).negate_lightness(), Lch::new(80.0, 30.0, 120.0));
+/// ```
+pub trait NegateLightness {
+    /// Return a copy of `self` with its lightness mirrored.
+    fn negate_lightness(&self) -> Self;
+}
+
 /// A trait for colors where the saturation (or chroma) can be manipulated
 /// without conversion.
 ///
@@ -712,6 +871,50 @@ pub trait Saturate: Sized {
     }
 }
 
+/// Get and set the colorfulness of a color, with the meaning it has in the
+/// color's own color space.
+///
+/// The colorfulness is the saturation of `Hsl`, `Hsv`, `Hsluv`, `Okhsl` and
+/// `Okhsv`, and the chroma of `Lch`, `Lchuv` and `Oklch`. The values are not
+/// comparable between color spaces, but they are all `0.0` for gray scale
+/// colors and grow as the colors become more colorful. This makes it
+/// possible to write code that makes any of them more or less colorful:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{Chroma, Hsv, Oklch};
+///
+/// fn muted<C: Chroma<Scalar = f32>>(color: C) -> C {
+///     color.scale_chroma(0.5)
+/// }
+///
+/// assert_relative_eq!(muted(Hsv::new(30.0, 0.8, 1.0)).saturation, 0.4);
+/// assert_relative_eq!(muted(Oklch::new(0.7, 0.2, 30.0)).chroma, 0.1);
+/// ```
+pub trait Chroma: Sized {
+    /// The type of the chroma value.
+    type Scalar: Float;
+
+    /// Get the chroma or saturation of the color.
+    fn chroma(&self) -> Self::Scalar;
+
+    /// Set the chroma or saturation of the color. It's not clamped.
+    fn set_chroma(&mut self, chroma: Self::Scalar);
+
+    /// Return the color with its chroma or saturation set to `chroma`.
+    fn with_chroma(mut self, chroma: Self::Scalar) -> Self {
+        self.set_chroma(chroma);
+        self
+    }
+
+    /// Return the color with its chroma or saturation multiplied by
+    /// `factor`.
+    fn scale_chroma(self, factor: Self::Scalar) -> Self {
+        let chroma = self.chroma() * factor;
+        self.with_chroma(chroma)
+    }
+}
+
 /// Perform a unary or binary operation on each component of a color.
 pub trait ComponentWise {
     /// The scalar type for color components.
@@ -728,10 +931,166 @@ pub trait ComponentWise {
     fn component_wise_self<F: FnMut(Self::Scalar) -> Self::Scalar>(&self, f: F) -> Self;
 }
 
+/// Saturating addition and subtraction for colors with integer components.
+///
+/// The components are clamped to the bounds of their type, instead of
+/// overflowing, which makes it possible to do simple pixel math directly on
+/// `u8` values. The operations are done on the stored component values,
+/// regardless of the color's encoding, and are implemented both between two
+/// colors and between a color and a scalar.
+///
+/// ```
+/// use palette::{SaturatingArithmetic, Srgb};
+///
+/// let color = Srgb::new(200u8, 100, 10);
+///
+/// assert_eq!(color.saturating_add(100), Srgb::new(255, 200, 110));
+/// assert_eq!(color.saturating_sub(Srgb::new(20, 150, 5)), Srgb::new(180, 0, 5));
+/// ```
+pub trait SaturatingArithmetic<Rhs = Self> {
+    /// Add `other` to each component, saturating at the maximum value.
+    fn saturating_add(self, other: Rhs) -> Self;
+
+    /// Subtract `other` from each component, saturating at the minimum value.
+    fn saturating_sub(self, other: Rhs) -> Self;
+}
+
+/// Checked addition and subtraction for colors with integer components.
+///
+/// The result is `None` if any of the components overflow. See
+/// [`SaturatingArithmetic`](trait.SaturatingArithmetic.html) for the
+/// saturating alternative.
+///
+/// ```
+/// use palette::{CheckedArithmetic, Srgb};
+///
+/// let color = Srgb::new(200u8, 100, 10);
+///
+/// assert_eq!(color.checked_add(50), Some(Srgb::new(250, 150, 60)));
+/// assert_eq!(color.checked_add(100), None);
+/// assert_eq!(color.checked_sub(Srgb::new(20, 150, 5)), None);
+/// ```
+pub trait CheckedArithmetic<Rhs = Self>: Sized {
+    /// Add `other` to each component, or return `None` if any of them
+    /// overflow.
+    fn checked_add(self, other: Rhs) -> Option<Self>;
+
+    /// Subtract `other` from each component, or return `None` if any of them
+    /// overflow.
+    fn checked_sub(self, other: Rhs) -> Option<Self>;
+}
+
+/// Wrapping addition and subtraction for colors with integer components.
+///
+/// The components wrap around at the bounds of their type. See
+/// [`SaturatingArithmetic`](trait.SaturatingArithmetic.html) for the
+/// saturating alternative.
+///
+/// ```
+/// use palette::{Srgb, WrappingArithmetic};
+///
+/// let color = Srgb::new(200u8, 100, 10);
+///
+/// assert_eq!(color.wrapping_add(100), Srgb::new(44, 200, 110));
+/// assert_eq!(color.wrapping_sub(Srgb::new(20, 150, 5)), Srgb::new(180, 206, 5));
+/// ```
+pub trait WrappingArithmetic<Rhs = Self> {
+    /// Add `other` to each component, wrapping around at the maximum value.
+    fn wrapping_add(self, other: Rhs) -> Self;
+
+    /// Subtract `other` from each component, wrapping around at the minimum
+    /// value.
+    fn wrapping_sub(self, other: Rhs) -> Self;
+}
+
+/// Vector operations for colors in Cartesian color spaces.
+///
+/// Color spaces like CIE L\*a\*b\*, L\*u\*v\* and XYZ have components
+/// that can be treated as coordinates in a three dimensional space. This
+/// trait exposes the basic vector math for those spaces, so that algorithms
+/// like clustering or nearest neighbor search can be written once and used
+/// with any of them. The Euclidean distance in L\*a\*b\* is the same as the
+/// CIE76 color difference.
+///
+/// Cylindrical spaces, like HSL or LCh, are not included, since their hue
+/// component doesn't behave like a coordinate.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{ColorPoint, Lab};
+///
+/// let a = Lab::new(50.0, 10.0, -10.0);
+/// let b = Lab::new(50.0, 13.0, -6.0);
+///
+/// assert_relative_eq!(a.distance(&b), 5.0);
+/// assert_relative_eq!(a.distance_squared(&b), 25.0);
+/// assert_relative_eq!(
+///     a.linear_combination(0.5, &b, 0.5),
+///     Lab::new(50.0, 11.5, -8.0)
+/// );
+/// ```
+pub trait ColorPoint: Sized {
+    /// The type of the coordinates.
+    type Scalar: Float;
+
+    /// Calculate the dot product of this and an other color, when seen as
+    /// vectors.
+    fn dot(&self, other: &Self) -> Self::Scalar;
+
+    /// Calculate `a * self + b * other`, component by component.
+    ///
+    /// This is the building block for averages and centroids, such as
+    /// `a.linear_combination(0.5, &b, 0.5)` for the midpoint between `a` and
+    /// `b`.
+    fn linear_combination(&self, a: Self::Scalar, other: &Self, b: Self::Scalar) -> Self;
+
+    /// Calculate the squared Euclidean distance between this and an other
+    /// color.
+    ///
+    /// This is cheaper than [`distance`](#method.distance) and orders the
+    /// colors in the same way, which makes it the better choice when
+    /// comparing distances.
+    fn distance_squared(&self, other: &Self) -> Self::Scalar {
+        let one: Self::Scalar = num_traits::One::one();
+        self.linear_combination(one, other, -one).norm_squared()
+    }
+
+    /// Calculate the Euclidean distance between this and an other color.
+    fn distance(&self, other: &Self) -> Self::Scalar {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Calculate the squared Euclidean norm of this color, when seen as a
+    /// vector.
+    fn norm_squared(&self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    /// Calculate the Euclidean norm of this color, when seen as a vector.
+    fn norm(&self) -> Self::Scalar {
+        self.norm_squared().sqrt()
+    }
+}
+
 /// A trait for infallible conversion from `f64`. The conversion may be lossy.
+///
+/// This is used for creating the constants in conversions and operations, and
+/// should be implemented for custom
+/// [`FloatComponent`](crate::FloatComponent) types. A type that carries extra
+/// information, such as the derivative part of a dual number, would typically
+/// create a constant with no extra information.
 pub trait FromF64 {
     /// Creates a value from an `f64` constant.
     fn from_f64(c: f64) -> Self;
+
+    /// Returns the value as an `f64` if it's a primitive float, which is used
+    /// for selecting the math functions of the `strict_math` feature. Other
+    /// types use their own implementations of the functions.
+    #[doc(hidden)]
+    #[inline]
+    fn as_primitive_f64(&self) -> Option<f64> {
+        None
+    }
 }
 
 impl FromF64 for f32 {
@@ -739,6 +1098,11 @@ impl FromF64 for f32 {
     fn from_f64(c: f64) -> Self {
         c as f32
     }
+
+    #[inline]
+    fn as_primitive_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
 }
 
 impl FromF64 for f64 {
@@ -746,6 +1110,11 @@ impl FromF64 for f64 {
     fn from_f64(c: f64) -> Self {
         c
     }
+
+    #[inline]
+    fn as_primitive_f64(&self) -> Option<f64> {
+        Some(*self)
+    }
 }
 
 /// A convenience function to convert a constant number to Float Type
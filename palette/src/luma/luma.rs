@@ -12,14 +12,14 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::blend::PreAlpha;
-use crate::convert::FromColorUnclamped;
+use crate::convert::{FromColor, FromColorUnclamped};
 use crate::encoding::linear::LinearFn;
 use crate::encoding::pixel::RawPixel;
 use crate::encoding::{Linear, Srgb, TransferFn};
 use crate::luma::LumaStandard;
 use crate::{
-    clamp, contrast_ratio, Alpha, Blend, Clamp, Component, ComponentWise, FloatComponent,
-    FromComponent, Mix, Pixel, RelativeContrast, Shade, Xyz, Yxy,
+    clamp, Alpha, Blend, Clamp, Component, ComponentWise, FloatComponent, FromComponent,
+    IntoComponentRounded, Invert, Mix, Pixel, RelativeContrast, Rounding, Shade, Xyz, Yxy,
 };
 
 /// Luminance with an alpha component. See the [`Lumaa` implementation
@@ -99,6 +99,67 @@ where
         }
     }
 
+    /// Convert into an integer component type, with a chosen rounding mode.
+    ///
+    /// See [`IntoComponentRounded`](crate::IntoComponentRounded) for the exact
+    /// formula.
+    pub fn into_format_rounded<U, R>(self, rounding: &mut R) -> Luma<S, U>
+    where
+        U: Component,
+        T: IntoComponentRounded<U>,
+        R: Rounding + ?Sized,
+    {
+        Luma {
+            luma: self.luma.into_component_rounded(rounding),
+            standard: PhantomData,
+        }
+    }
+
+    /// Change the luma standard without changing the component value.
+    ///
+    /// This does *not* convert the color. It only changes how the value is
+    /// interpreted, which is useful when the actual standard is known from
+    /// somewhere else, such as the metadata of a texture.
+    #[inline]
+    pub fn reinterpret_standard<St: LumaStandard>(self) -> Luma<St, T> {
+        Luma {
+            luma: self.luma,
+            standard: PhantomData,
+        }
+    }
+
+    /// Cast a slice of colors to a slice of colors with another luma
+    /// standard, without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors.
+    ///
+    /// ```
+    /// use palette::{LinLuma, SrgbLuma};
+    ///
+    /// let texture = [SrgbLuma::new(0.5f32), SrgbLuma::new(1.0)];
+    /// let linear: &[LinLuma] = SrgbLuma::reinterpret_slice(&texture);
+    /// assert_eq!(linear[0], LinLuma::new(0.5));
+    /// ```
+    #[inline]
+    pub fn reinterpret_slice<St: LumaStandard>(colors: &[Self]) -> &[Luma<St, T>] {
+        // Safe because `Luma` is `#[repr(C)]` and the standard is a zero sized
+        // `PhantomData`, so the layout doesn't depend on it.
+        unsafe { core::slice::from_raw_parts(colors.as_ptr() as *const Luma<St, T>, colors.len()) }
+    }
+
+    /// Cast a mutable slice of colors to a mutable slice of colors with
+    /// another luma standard, without copying or changing the component
+    /// values.
+    ///
+    /// This does *not* convert the colors.
+    #[inline]
+    pub fn reinterpret_slice_mut<St: LumaStandard>(colors: &mut [Self]) -> &mut [Luma<St, T>] {
+        // Safe for the same reason as in `reinterpret_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(colors.as_mut_ptr() as *mut Luma<St, T>, colors.len())
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U>(color: Luma<S, U>) -> Self
     where
@@ -108,6 +169,44 @@ where
         color.into_format()
     }
 
+    /// Convert into another color space and component type in one step.
+    ///
+    /// The component is first converted to the floating point type `U`, which
+    /// is then used for converting into `C`. It's only a convenience wrapper
+    /// for `C::from_color(self.into_format::<U>())`, and isn't faster than
+    /// calling them separately, since the color space conversion needs
+    /// floating point components.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Lab, SrgbLuma};
+    ///
+    /// let lab: Lab = SrgbLuma::new(255u8).into_color_format();
+    /// assert_relative_eq!(lab, Lab::new(100.0, 0.0, 0.0), epsilon = 0.01);
+    /// ```
+    pub fn into_color_format<C, U>(self) -> C
+    where
+        U: FloatComponent + FromComponent<T>,
+        C: FromColor<Luma<S, U>>,
+    {
+        C::from_color(self.into_format())
+    }
+
+    /// Convert from another color space and component type in one step.
+    ///
+    /// The color is first converted into luma with the floating point
+    /// component type `U`, which is then converted to `T`. Like
+    /// [`into_color_format`](#method.into_color_format), it only chains the
+    /// two conversions.
+    pub fn from_color_format<C, U>(color: C) -> Self
+    where
+        U: FloatComponent,
+        T: FromComponent<U>,
+        Luma<S, U>: FromColor<C>,
+    {
+        Luma::<S, U>::from_color(color).into_format()
+    }
+
     /// Convert to a `(luma,)` tuple.
     pub fn into_components(self) -> (T,) {
         (self.luma,)
@@ -209,6 +308,70 @@ where
         Alpha::<Luma<S, U>, B>::new(U::from_component(self.luma), B::from_component(self.alpha))
     }
 
+    /// Convert into an integer component type, with a chosen rounding mode.
+    ///
+    /// The components are converted in the order luma, alpha. See
+    /// [`IntoComponentRounded`](crate::IntoComponentRounded) for the exact
+    /// formula.
+    pub fn into_format_rounded<U, B, R>(self, rounding: &mut R) -> Alpha<Luma<S, U>, B>
+    where
+        U: Component,
+        B: Component,
+        T: IntoComponentRounded<U>,
+        A: IntoComponentRounded<B>,
+        R: Rounding + ?Sized,
+    {
+        Alpha {
+            color: self.color.into_format_rounded(rounding),
+            alpha: self.alpha.into_component_rounded(rounding),
+        }
+    }
+
+    /// Change the luma standard without changing the component values.
+    ///
+    /// This does *not* convert the color.
+    #[inline]
+    pub fn reinterpret_standard<St: LumaStandard>(self) -> Alpha<Luma<St, T>, A> {
+        Alpha {
+            color: self.color.reinterpret_standard(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Cast a slice of colors to a slice of colors with another luma
+    /// standard, without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors.
+    #[inline]
+    pub fn reinterpret_slice<St: LumaStandard>(colors: &[Self]) -> &[Alpha<Luma<St, T>, A>] {
+        // Safe because `Alpha` and `Luma` are `#[repr(C)]` and the standard is
+        // a zero sized `PhantomData`, so the layout doesn't depend on it.
+        unsafe {
+            core::slice::from_raw_parts(
+                colors.as_ptr() as *const Alpha<Luma<St, T>, A>,
+                colors.len(),
+            )
+        }
+    }
+
+    /// Cast a mutable slice of colors to a mutable slice of colors with
+    /// another luma standard, without copying or changing the component
+    /// values.
+    ///
+    /// This does *not* convert the colors.
+    #[inline]
+    pub fn reinterpret_slice_mut<St: LumaStandard>(
+        colors: &mut [Self],
+    ) -> &mut [Alpha<Luma<St, T>, A>] {
+        // Safe for the same reason as in `reinterpret_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                colors.as_mut_ptr() as *mut Alpha<Luma<St, T>, A>,
+                colors.len(),
+            )
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U, B>(color: Alpha<Luma<S, U>, B>) -> Self
     where
@@ -220,6 +383,36 @@ where
         color.into_format()
     }
 
+    /// Convert into another color space and component type in one step.
+    ///
+    /// Both the luma and the alpha component are first converted to the
+    /// floating point type `U`, which is then used for converting into `C`.
+    /// This is a convenience wrapper for
+    /// `C::from_color(self.into_format::<U, U>())`, and does the same work.
+    pub fn into_color_format<C, U>(self) -> C
+    where
+        U: FloatComponent + FromComponent<T> + FromComponent<A>,
+        C: FromColor<Alpha<Luma<S, U>, U>>,
+    {
+        C::from_color(self.into_format())
+    }
+
+    /// Convert from another color space and component type in one step.
+    ///
+    /// The color is first converted into luma with the floating point
+    /// component type `U`, for both the luma and the alpha component, which
+    /// are then converted to `T` and `A`. This is a convenience wrapper for
+    /// those two conversions, and does the same work.
+    pub fn from_color_format<C, U>(color: C) -> Self
+    where
+        U: FloatComponent,
+        T: FromComponent<U>,
+        A: FromComponent<U>,
+        Alpha<Luma<S, U>, U>: FromColor<C>,
+    {
+        Alpha::<Luma<S, U>, U>::from_color(color).into_format()
+    }
+
     /// Convert to a `(luma, alpha)` tuple.
     pub fn into_components(self) -> (T, A) {
         (self.luma, self.alpha)
@@ -272,6 +465,24 @@ where
     }
 }
 
+impl<S, T> Alpha<Luma<S, T>, T>
+where
+    T: FloatComponent,
+    S: LumaStandard,
+{
+    /// Decode the color and premultiply it with its alpha. The luminance is
+    /// made linear first, since premultiplied colors are blended linearly.
+    pub fn into_linear_premultiplied(self) -> PreAlpha<Luma<Linear<S::WhitePoint>, T>, T> {
+        self.into_linear().into()
+    }
+
+    /// Unpremultiply linear luminance and encode it. This is the inverse of
+    /// [`into_linear_premultiplied`](#method.into_linear_premultiplied).
+    pub fn from_linear_premultiplied(color: PreAlpha<Luma<Linear<S::WhitePoint>, T>, T>) -> Self {
+        Self::from_linear(color.into())
+    }
+}
+
 impl<S1, S2, T> FromColorUnclamped<Luma<S2, T>> for Luma<S1, T>
 where
     S1: LumaStandard,
@@ -446,6 +657,19 @@ where
     }
 }
 
+impl<S, T> Invert for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component + Sub<Output = T>,
+{
+    fn invert(&self) -> Luma<S, T> {
+        Luma {
+            luma: T::max_intensity() - self.luma,
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Default for Luma<S, T>
 where
     T: Component,
@@ -560,6 +784,8 @@ where
     }
 }
 
+impl_integer_arithmetic!(Luma<S: LumaStandard>, [luma], standard);
+
 impl<S, T> Mul<Luma<S, T>> for Luma<S, T>
 where
     T: Component + Mul,
@@ -790,11 +1016,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let luma1 = self.into_linear();
-        let luma2 = other.into_linear();
-
-        contrast_ratio(luma1.luma, luma2.luma)
+    fn relative_luminance(&self) -> T {
+        self.into_linear().luma
     }
 }
 
@@ -0,0 +1,407 @@
+//! Three dimensional lookup tables, for baking color transforms.
+//!
+//! A [`Lut3d`](struct.Lut3d.html) stores the result of a transform for a
+//! lattice of linear sRGB colors, and interpolates between them. It's a
+//! common way to apply an expensive chain of adjustments to many colors, or
+//! to hand it over to other software, since the table can be written to and
+//! read from `.cube` files.
+//!
+//! [`Lut3d::fit`](struct.Lut3d.html#method.fit) samples any transform into a
+//! table, and reports how far the table is from the transform, as ΔE\*
+//! (CIEDE2000):
+//!
+//! ```
+//! use palette::lut::Lut3d;
+//! use palette::LinSrgb;
+//!
+//! // Brighten the red, and darken the blue.
+//! let transform = |color: LinSrgb<f64>| {
+//!     let red = color.red * (1.5 - 0.5 * color.red);
+//!     LinSrgb::new(red, color.green, color.blue * 0.9)
+//! };
+//!
+//! let fit = Lut3d::fit(17, 24, transform).unwrap();
+//! assert!(fit.max_error < 1.0);
+//! assert!(fit.mean_error < fit.max_error);
+//!
+//! let color = fit.lut.get(LinSrgb::new(0.3, 0.6, 0.9));
+//! assert!((color.blue - 0.81).abs() < 1e-9);
+//! ```
+//!
+//! This module is only available if the `std` feature is enabled (this is
+//! the default).
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::convert::FromColorUnclamped;
+use crate::error::LutError;
+use crate::white_point::D65;
+use crate::{from_f64, CieDifference, FloatComponent, Lab, LinSrgb};
+
+/// A three dimensional lookup table for linear sRGB colors.
+///
+/// The table has `size` entries along each axis, evenly spaced over the
+/// `0.0` to `1.0` range. They are stored with red changing the fastest, then
+/// green and then blue, as in `.cube` files. Colors between the entries are
+/// interpolated trilinearly, and the components are clamped to the `0.0` to
+/// `1.0` range before looking them up.
+///
+/// A table can be parsed from a `.cube` file with `FromStr`, and formatted
+/// as one with `Display`. Only 3D tables with the default domain, and at most
+/// 256 entries along each axis, are supported.
+///
+/// The entries of a `.cube` file are read and written as they are, without
+/// decoding or encoding them. Most `.cube` files are made for gamma or log
+/// encoded values, rather than linear values. To apply one of those to an
+/// `Srgb` color, look up its encoded components as they are, with
+/// `LinSrgb::new(color.red, color.green, color.blue)`, and treat the
+/// components of the result as encoded too.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lut3d<T = f32>
+where
+    T: FloatComponent,
+{
+    size: usize,
+    table: Vec<LinSrgb<T>>,
+}
+
+impl<T: FloatComponent> Lut3d<T> {
+    /// The largest supported number of entries along each axis, which is the
+    /// largest size that `.cube` files allow.
+    pub const MAX_SIZE: usize = 256;
+
+    /// Create a table from its entries, or return an error if `size` is less
+    /// than 2 or more than `MAX_SIZE`, or if there aren't `size³` entries.
+    pub fn new(size: usize, table: Vec<LinSrgb<T>>) -> Result<Self, LutError> {
+        let expected = Self::entries(size)?;
+        if table.len() != expected {
+            return Err(LutError::WrongLength {
+                expected,
+                found: table.len(),
+            });
+        }
+
+        Ok(Lut3d { size, table })
+    }
+
+    /// Create a table by sampling `transform` for each entry, or return an
+    /// error if `size` is less than 2 or more than `MAX_SIZE`.
+    pub fn from_fn<F>(size: usize, mut transform: F) -> Result<Self, LutError>
+    where
+        F: FnMut(LinSrgb<T>) -> LinSrgb<T>,
+    {
+        let mut table = Vec::with_capacity(Self::entries(size)?);
+        for blue in 0..size {
+            for green in 0..size {
+                for red in 0..size {
+                    table.push(transform(lattice_color(size, red, green, blue)));
+                }
+            }
+        }
+
+        Ok(Lut3d { size, table })
+    }
+
+    /// Sample `transform` into a table with `size` entries along each axis,
+    /// and measure the error of the table.
+    ///
+    /// The error is measured for a grid of `test_size` colors along each
+    /// axis, by comparing the output of the table with the output of
+    /// `transform`, as ΔE\* (CIEDE2000) in CIE L\*a\*b\*. A `test_size` that
+    /// doesn't line up with `size` tests colors between the entries, where
+    /// the error is the largest.
+    ///
+    /// Returns an error if `size` or `test_size` is less than 2 or more than
+    /// `MAX_SIZE`.
+    pub fn fit<F>(size: usize, test_size: usize, transform: F) -> Result<LutFit<T>, LutError>
+    where
+        F: Fn(LinSrgb<T>) -> LinSrgb<T>,
+    {
+        let count = Self::entries(test_size)?;
+        let lut = Self::from_fn(size, &transform)?;
+
+        let mut max_error = T::zero();
+        let mut sum = T::zero();
+        for blue in 0..test_size {
+            for green in 0..test_size {
+                for red in 0..test_size {
+                    let color = lattice_color(test_size, red, green, blue);
+                    let expected = Lab::<D65, T>::from_color_unclamped(transform(color));
+                    let found = Lab::from_color_unclamped(lut.get(color));
+
+                    let error = expected.delta_e_ciede2000(&found);
+                    max_error = max_error.max(error);
+                    sum = sum + error;
+                }
+            }
+        }
+
+        Ok(LutFit {
+            lut,
+            max_error,
+            mean_error: sum / from_f64(count as f64),
+        })
+    }
+
+    /// The number of entries along each axis.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The entries, with red changing the fastest.
+    pub fn table(&self) -> &[LinSrgb<T>] {
+        &self.table
+    }
+
+    /// Look up a color, with trilinear interpolation between the entries.
+    pub fn get(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let (red, red_t) = self.cell(color.red);
+        let (green, green_t) = self.cell(color.green);
+        let (blue, blue_t) = self.cell(color.blue);
+
+        let entry = |r: usize, g: usize, b: usize| self.table[(b * self.size + g) * self.size + r];
+        let lerp = |a: LinSrgb<T>, b: LinSrgb<T>, t: T| a + (b - a) * t;
+
+        let along_red = |g: usize, b: usize| lerp(entry(red, g, b), entry(red + 1, g, b), red_t);
+        let along_green = |b: usize| lerp(along_red(green, b), along_red(green + 1, b), green_t);
+
+        lerp(along_green(blue), along_green(blue + 1), blue_t)
+    }
+
+    /// Look up each color in a slice.
+    pub fn apply_slice(&self, colors: &mut [LinSrgb<T>]) {
+        for color in colors {
+            *color = self.get(*color);
+        }
+    }
+
+    /// The number of entries in a table with `size` entries along each axis,
+    /// or an error if the size isn't supported.
+    fn entries(size: usize) -> Result<usize, LutError> {
+        if !(2..=Self::MAX_SIZE).contains(&size) {
+            return Err(LutError::InvalidSize(size));
+        }
+
+        size.checked_mul(size)
+            .and_then(|area| area.checked_mul(size))
+            .ok_or(LutError::InvalidSize(size))
+    }
+
+    /// The index of the cell that contains `x`, and the position within it.
+    fn cell(&self, x: T) -> (usize, T) {
+        let last = self.size - 1;
+        let position = x.max(T::zero()).min(T::one()) * from_f64(last as f64);
+        let index = position.floor().to_usize().unwrap_or(0).min(last - 1);
+
+        (index, position - from_f64(index as f64))
+    }
+}
+
+/// The color at a lattice point of a table with `size` entries per axis.
+fn lattice_color<T: FloatComponent>(
+    size: usize,
+    red: usize,
+    green: usize,
+    blue: usize,
+) -> LinSrgb<T> {
+    let last = from_f64::<T>((size - 1) as f64);
+    LinSrgb::new(
+        from_f64::<T>(red as f64) / last,
+        from_f64::<T>(green as f64) / last,
+        from_f64::<T>(blue as f64) / last,
+    )
+}
+
+/// A lookup table from [`Lut3d::fit`](struct.Lut3d.html#method.fit), and
+/// its measured error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LutFit<T = f32>
+where
+    T: FloatComponent,
+{
+    /// The sampled table.
+    pub lut: Lut3d<T>,
+    /// The largest ΔE\* between the table and the transform.
+    pub max_error: T,
+    /// The mean ΔE\* between the table and the transform.
+    pub mean_error: T,
+}
+
+/// Formats the table as the contents of a `.cube` file.
+impl<T: FloatComponent + fmt::Display> fmt::Display for Lut3d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "LUT_3D_SIZE {}", self.size)?;
+        for color in &self.table {
+            writeln!(f, "{} {} {}", color.red, color.green, color.blue)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the contents of a `.cube` file.
+impl<T: FloatComponent + FromStr> FromStr for Lut3d<T> {
+    type Err = LutError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut size = None;
+        let mut table = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let error = LutError::InvalidLine { line: line_number };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let first = fields.next().unwrap_or("");
+            if first.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let values: Vec<&str> = fields.collect();
+                match first {
+                    "LUT_3D_SIZE" => {
+                        let value = match values[..] {
+                            [value] => value.parse().map_err(|_| error)?,
+                            _ => return Err(error),
+                        };
+                        Self::entries(value)?;
+                        size = Some(value);
+                    }
+                    "DOMAIN_MIN" if is_domain(&values, 0.0) => {}
+                    "DOMAIN_MAX" if is_domain(&values, 1.0) => {}
+                    "DOMAIN_MIN" | "DOMAIN_MAX" | "LUT_1D_SIZE" => return Err(error),
+                    // Other keywords, like `TITLE`, don't affect the table.
+                    _ => {}
+                }
+                continue;
+            }
+
+            let mut components = [T::zero(); 3];
+            let mut values = line.split_whitespace();
+            for component in &mut components {
+                *component = values
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or(error)?;
+            }
+            if values.next().is_some() {
+                return Err(error);
+            }
+
+            let [red, green, blue] = components;
+            table.push(LinSrgb::new(red, green, blue));
+        }
+
+        Lut3d::new(size.unwrap_or(0), table)
+    }
+}
+
+/// Check if the values of a `DOMAIN_MIN` or `DOMAIN_MAX` line are all
+/// `expected`.
+fn is_domain(values: &[&str], expected: f64) -> bool {
+    values.len() == 3
+        && values
+            .iter()
+            .all(|value| value.parse::<f64>().ok() == Some(expected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::Lut3d;
+    use crate::error::LutError;
+    use crate::LinSrgb;
+
+    #[test]
+    fn identity() {
+        let lut = Lut3d::<f64>::from_fn(5, |color| color).unwrap();
+        let color = LinSrgb::new(0.1, 0.55, 0.9);
+
+        assert_relative_eq!(lut.get(color), color, epsilon = 1e-12);
+        assert_relative_eq!(
+            lut.get(LinSrgb::new(-1.0, 0.5, 2.0)),
+            LinSrgb::new(0.0, 0.5, 1.0)
+        );
+        assert_eq!(lut.table()[1], LinSrgb::new(0.25, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fit_error() {
+        let transform =
+            |color: LinSrgb<f64>| LinSrgb::new(color.red * color.red, color.green, color.blue);
+
+        let coarse = Lut3d::fit(3, 10, transform).unwrap();
+        let fine = Lut3d::fit(33, 10, transform).unwrap();
+        assert!(coarse.max_error > fine.max_error);
+        assert!(fine.max_error < 0.5);
+        assert!(fine.mean_error <= fine.max_error);
+
+        let linear = Lut3d::fit(2, 10, |color: LinSrgb<f64>| color * 0.5).unwrap();
+        assert!(linear.max_error < 1e-9);
+    }
+
+    #[test]
+    fn cube_roundtrip() {
+        let lut = Lut3d::<f64>::from_fn(3, |color| color * 0.5).unwrap();
+        let parsed: Lut3d<f64> = lut.to_string().parse().unwrap();
+        assert_eq!(parsed, lut);
+
+        let source = "TITLE \"test\"\n# comment\nLUT_3D_SIZE 2\nDOMAIN_MIN 0.0 0.0 0.0\n\n\
+            0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+        let parsed: Lut3d<f64> = source.parse().unwrap();
+        assert_eq!(parsed, Lut3d::from_fn(2, |color| color).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        let parse = |source: &str| source.parse::<Lut3d<f64>>().unwrap_err();
+
+        assert_eq!(parse(""), LutError::InvalidSize(0));
+        assert_eq!(parse("LUT_3D_SIZE 1\n0 0 0"), LutError::InvalidSize(1));
+        assert_eq!(parse("LUT_3D_SIZE 257"), LutError::InvalidSize(257));
+        assert_eq!(
+            parse("LUT_3D_SIZE 100000\n0 0 0"),
+            LutError::InvalidSize(100000)
+        );
+        assert_eq!(
+            parse("LUT_3D_SIZE 3000000\n0 0 0"),
+            LutError::InvalidSize(3000000)
+        );
+        assert_eq!(
+            parse("LUT_3D_SIZE 2\n0 0 0"),
+            LutError::WrongLength {
+                expected: 8,
+                found: 1
+            }
+        );
+        assert_eq!(
+            parse("LUT_3D_SIZE 2\n0 0"),
+            LutError::InvalidLine { line: 2 }
+        );
+        assert_eq!(
+            parse("LUT_3D_SIZE 2\nDOMAIN_MAX 2 2 2"),
+            LutError::InvalidLine { line: 2 }
+        );
+        assert_eq!(
+            Lut3d::fit(2, 1, |color: LinSrgb<f64>| color).unwrap_err(),
+            LutError::InvalidSize(1)
+        );
+        assert_eq!(
+            Lut3d::new(usize::MAX, vec![LinSrgb::new(0.0f64, 0.0, 0.0)]).unwrap_err(),
+            LutError::InvalidSize(usize::MAX)
+        );
+        assert_eq!(
+            Lut3d::from_fn(3000000, |color: LinSrgb<f64>| color).unwrap_err(),
+            LutError::InvalidSize(3000000)
+        );
+    }
+
+    #[test]
+    fn max_size() {
+        let size = Lut3d::<f32>::MAX_SIZE;
+        let table = vec![LinSrgb::new(0.0, 0.0, 0.0); size * size * size];
+        let lut = Lut3d::new(size, table).unwrap();
+        assert_eq!(lut.size(), 256);
+    }
+}
@@ -10,10 +10,12 @@ use rand::Rng;
 
 use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
+use crate::math::{atan2, powf, sin_cos};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, ComponentWise, FloatComponent,
-    GetHue, Lchuv, LuvHue, Mix, Pixel, RelativeContrast, Shade, Xyz,
+    clamp, from_f64, Alpha, Clamp, ColorPoint, Complement, Component, ComponentWise,
+    FloatComponent, GetHue, Lchuv, LuvHue, Mix, NegateLightness, Pixel, RelativeContrast, Shade,
+    Xyz,
 };
 
 /// CIE L\*u\*v\* (CIELUV) with an alpha component. See the [`Luva`
@@ -227,7 +229,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Lchuv<Wp, T>) -> Self {
-        let (sin_hue, cos_hue) = color.hue.to_radians().sin_cos();
+        let (sin_hue, cos_hue) = sin_cos(color.hue.to_radians());
         let chroma = color.chroma.max(T::zero());
         Luv::with_wp(color.l, chroma * cos_hue, chroma * sin_hue)
     }
@@ -260,7 +262,7 @@ where
 
         let y_r = color.y / w.y;
         let l = if y_r > epsilon {
-            from_f64(116.0) * y_r.powf(from_f64(1.0 / 3.0)) - from_f64(16.0)
+            from_f64(116.0) * powf(y_r, from_f64(1.0 / 3.0)) - from_f64(16.0)
         } else {
             kappa * y_r
         };
@@ -387,7 +389,28 @@ where
         if self.u == T::zero() && self.v == T::zero() {
             None
         } else {
-            Some(LuvHue::from_radians(self.v.atan2(self.u)))
+            Some(LuvHue::from_radians(atan2(self.v, self.u)))
+        }
+    }
+}
+
+impl<Wp, T> ColorPoint for Luv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Luv<Wp, T>) -> T {
+        self.l * other.l + self.u * other.u + self.v * other.v
+    }
+
+    fn linear_combination(&self, a: T, other: &Luv<Wp, T>, b: T) -> Luv<Wp, T> {
+        Luv {
+            l: a * self.l + b * other.l,
+            u: a * self.u + b * other.u,
+            v: a * self.v + b * other.v,
+            white_point: PhantomData,
         }
     }
 }
@@ -418,6 +441,33 @@ where
     }
 }
 
+impl<Wp, T> Complement for Luv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn complement(&self) -> Luv<Wp, T> {
+        Luv {
+            u: -self.u,
+            v: -self.v,
+            ..*self
+        }
+    }
+}
+
+impl<Wp, T> NegateLightness for Luv<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn negate_lightness(&self) -> Luv<Wp, T> {
+        Luv {
+            l: from_f64::<T>(100.0) - self.l,
+            ..*self
+        }
+    }
+}
+
 impl<Wp, T> Default for Luv<Wp, T>
 where
     T: FloatComponent,
@@ -576,13 +626,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
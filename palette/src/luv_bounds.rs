@@ -1,8 +1,9 @@
 //! Utility functions for computing in-gamut regions for CIELuv color space.
+use crate::math::sin_cos;
 use crate::{FloatComponent, LuvHue};
 #[allow(unused)]
 use num_traits::Float;
-use num_traits::{Pow, ToPrimitive};
+use num_traits::Pow;
 
 /// Boundary line in the u-v plane of the Luv color space.
 struct BoundaryLine {
@@ -15,7 +16,7 @@ impl BoundaryLine {
     /// the signed length at which the ray intersects with the
     /// boundary.
     fn intersect_length_at_angle(&self, theta: f64) -> Option<f64> {
-        let (sin_theta, cos_theta) = theta.to_f64().unwrap().sin_cos();
+        let (sin_theta, cos_theta) = sin_cos(theta);
         let denom = sin_theta - self.slope * cos_theta;
         if denom.abs() > 1.0e-6 {
             Some(self.intercept / denom)
@@ -367,3 +367,131 @@ macro_rules! impl_color_sub {
 	}
     }
 }
+
+/// Implement `SaturatingArithmetic`, `CheckedArithmetic` and
+/// `WrappingArithmetic` for a color space with integer components.
+///
+/// Both scalars and color arithmetic are implemented.
+macro_rules! impl_integer_arithmetic {
+    ($self_ty: ident < $standard: ident : $standard_trait: ident > , [$($element: ident),+], $phantom: ident) => {
+        impl<$standard, T> crate::SaturatingArithmetic for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::Saturating,
+        {
+            fn saturating_add(self, other: $self_ty<$standard, T>) -> Self {
+                $self_ty {
+                    $( $element: num_traits::Saturating::saturating_add(self.$element, other.$element) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+
+            fn saturating_sub(self, other: $self_ty<$standard, T>) -> Self {
+                $self_ty {
+                    $( $element: num_traits::Saturating::saturating_sub(self.$element, other.$element) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<$standard, T> crate::SaturatingArithmetic<T> for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::Saturating,
+        {
+            fn saturating_add(self, c: T) -> Self {
+                $self_ty {
+                    $( $element: num_traits::Saturating::saturating_add(self.$element, c) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+
+            fn saturating_sub(self, c: T) -> Self {
+                $self_ty {
+                    $( $element: num_traits::Saturating::saturating_sub(self.$element, c) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<$standard, T> crate::CheckedArithmetic for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::CheckedAdd + num_traits::CheckedSub,
+        {
+            fn checked_add(self, other: $self_ty<$standard, T>) -> Option<Self> {
+                Some($self_ty {
+                    $( $element: num_traits::CheckedAdd::checked_add(&self.$element, &other.$element)? ),+,
+                    $phantom: PhantomData,
+                })
+            }
+
+            fn checked_sub(self, other: $self_ty<$standard, T>) -> Option<Self> {
+                Some($self_ty {
+                    $( $element: num_traits::CheckedSub::checked_sub(&self.$element, &other.$element)? ),+,
+                    $phantom: PhantomData,
+                })
+            }
+        }
+
+        impl<$standard, T> crate::CheckedArithmetic<T> for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::CheckedAdd + num_traits::CheckedSub,
+        {
+            fn checked_add(self, c: T) -> Option<Self> {
+                Some($self_ty {
+                    $( $element: num_traits::CheckedAdd::checked_add(&self.$element, &c)? ),+,
+                    $phantom: PhantomData,
+                })
+            }
+
+            fn checked_sub(self, c: T) -> Option<Self> {
+                Some($self_ty {
+                    $( $element: num_traits::CheckedSub::checked_sub(&self.$element, &c)? ),+,
+                    $phantom: PhantomData,
+                })
+            }
+        }
+
+        impl<$standard, T> crate::WrappingArithmetic for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::WrappingAdd + num_traits::WrappingSub,
+        {
+            fn wrapping_add(self, other: $self_ty<$standard, T>) -> Self {
+                $self_ty {
+                    $( $element: num_traits::WrappingAdd::wrapping_add(&self.$element, &other.$element) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+
+            fn wrapping_sub(self, other: $self_ty<$standard, T>) -> Self {
+                $self_ty {
+                    $( $element: num_traits::WrappingSub::wrapping_sub(&self.$element, &other.$element) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<$standard, T> crate::WrappingArithmetic<T> for $self_ty<$standard, T>
+        where
+            $standard: $standard_trait,
+            T: Component + num_traits::WrappingAdd + num_traits::WrappingSub,
+        {
+            fn wrapping_add(self, c: T) -> Self {
+                $self_ty {
+                    $( $element: num_traits::WrappingAdd::wrapping_add(&self.$element, &c) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+
+            fn wrapping_sub(self, c: T) -> Self {
+                $self_ty {
+                    $( $element: num_traits::WrappingSub::wrapping_sub(&self.$element, &c) ),+,
+                    $phantom: PhantomData,
+                }
+            }
+        }
+    };
+}
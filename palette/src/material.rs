@@ -0,0 +1,315 @@
+//! Dynamic color schemes from Material Design 3.
+//!
+//! A Material Design color scheme is derived from a single seed color. The
+//! hue and chroma of the seed, in the [`Hct`](../struct.Hct.html) color
+//! space, are used for a set of [`TonalPalette`](struct.TonalPalette.html)s,
+//! which are collected in a [`CorePalette`](struct.CorePalette.html). The
+//! colors of a [`Scheme`](struct.Scheme.html) are then picked from the tonal
+//! palettes, with tones that give enough contrast between the colors that
+//! are shown on top of each other.
+//!
+//! ```
+//! use palette::material::{CorePalette, Scheme};
+//! use palette::Srgb;
+//!
+//! let core = CorePalette::new(Srgb::new(0.26f64, 0.52, 0.96));
+//! let light = Scheme::light(&core);
+//! let dark = Scheme::dark(&core);
+//!
+//! assert!(light.primary.blue > light.primary.red);
+//! assert!(dark.surface.red < 0.2);
+//! ```
+
+use crate::convert::IntoColorUnclamped;
+use crate::{from_f64, FloatComponent, Hct, Srgb};
+
+/// Colors with the same hue and chroma, but different tones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TonalPalette<T = f32> {
+    /// The HCT hue of the palette, in degrees.
+    pub hue: T,
+
+    /// The HCT chroma of the palette. The colors in the palette may have
+    /// less chroma, if the color wouldn't be in the sRGB gamut otherwise.
+    pub chroma: T,
+}
+
+impl<T: FloatComponent> TonalPalette<T> {
+    /// Create a tonal palette with the hue `hue`, in degrees, and the chroma
+    /// `chroma`.
+    pub fn new(hue: T, chroma: T) -> Self {
+        TonalPalette { hue, chroma }
+    }
+
+    /// The color of the palette with the tone `tone`, from `0.0` to `100.0`.
+    ///
+    /// The color is mapped into the sRGB gamut by reducing its chroma, if
+    /// necessary.
+    pub fn tone(&self, tone: T) -> Srgb<T> {
+        Srgb::from_linear(Hct::new(self.hue, self.chroma, tone).to_linear_srgb())
+    }
+}
+
+/// The tonal palettes of a Material Design color scheme.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CorePalette<T = f32> {
+    /// The palette of the primary colors, with the hue of the seed color
+    /// and a chroma of at least 48.
+    pub primary: TonalPalette<T>,
+
+    /// The palette of the secondary colors, with the hue of the seed color
+    /// and a chroma of 16.
+    pub secondary: TonalPalette<T>,
+
+    /// The palette of the tertiary colors, with the hue of the seed color
+    /// rotated by 60° and a chroma of 24.
+    pub tertiary: TonalPalette<T>,
+
+    /// The palette of the neutral colors, with the hue of the seed color and
+    /// a chroma of 4.
+    pub neutral: TonalPalette<T>,
+
+    /// The palette of the neutral variant colors, with the hue of the seed
+    /// color and a chroma of 8.
+    pub neutral_variant: TonalPalette<T>,
+
+    /// The palette of the error colors, which is a red with a hue of 25 and a
+    /// chroma of 84.
+    pub error: TonalPalette<T>,
+}
+
+impl<T: FloatComponent> CorePalette<T> {
+    /// Derive the tonal palettes from the seed color `seed`.
+    pub fn new<C: IntoColorUnclamped<Hct<T>>>(seed: C) -> Self {
+        let seed: Hct<T> = seed.into_color_unclamped();
+        let hue = seed.hue;
+        let tertiary_hue = (hue + from_f64(60.0)) % from_f64(360.0);
+
+        CorePalette {
+            primary: TonalPalette::new(hue, seed.chroma.max(from_f64(48.0))),
+            secondary: TonalPalette::new(hue, from_f64(16.0)),
+            tertiary: TonalPalette::new(tertiary_hue, from_f64(24.0)),
+            neutral: TonalPalette::new(hue, from_f64(4.0)),
+            neutral_variant: TonalPalette::new(hue, from_f64(8.0)),
+            error: TonalPalette::new(from_f64(25.0), from_f64(84.0)),
+        }
+    }
+}
+
+/// The colors of a Material Design color scheme.
+///
+/// The `on_*` colors are meant for text and icons that are shown on top of
+/// the color with the same name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scheme<T = f32>
+where
+    T: FloatComponent,
+{
+    /// The color of prominent components, such as buttons.
+    pub primary: Srgb<T>,
+    /// The color of content on `primary`.
+    pub on_primary: Srgb<T>,
+    /// A less prominent version of `primary`.
+    pub primary_container: Srgb<T>,
+    /// The color of content on `primary_container`.
+    pub on_primary_container: Srgb<T>,
+    /// The color of less prominent components, such as filter chips.
+    pub secondary: Srgb<T>,
+    /// The color of content on `secondary`.
+    pub on_secondary: Srgb<T>,
+    /// A less prominent version of `secondary`.
+    pub secondary_container: Srgb<T>,
+    /// The color of content on `secondary_container`.
+    pub on_secondary_container: Srgb<T>,
+    /// A contrasting accent color.
+    pub tertiary: Srgb<T>,
+    /// The color of content on `tertiary`.
+    pub on_tertiary: Srgb<T>,
+    /// A less prominent version of `tertiary`.
+    pub tertiary_container: Srgb<T>,
+    /// The color of content on `tertiary_container`.
+    pub on_tertiary_container: Srgb<T>,
+    /// The color of errors.
+    pub error: Srgb<T>,
+    /// The color of content on `error`.
+    pub on_error: Srgb<T>,
+    /// A less prominent version of `error`.
+    pub error_container: Srgb<T>,
+    /// The color of content on `error_container`.
+    pub on_error_container: Srgb<T>,
+    /// The color behind scrollable content.
+    pub background: Srgb<T>,
+    /// The color of content on `background`.
+    pub on_background: Srgb<T>,
+    /// The color of surfaces, such as cards and menus.
+    pub surface: Srgb<T>,
+    /// The color of content on `surface`.
+    pub on_surface: Srgb<T>,
+    /// An alternative surface color.
+    pub surface_variant: Srgb<T>,
+    /// The color of content on `surface_variant`.
+    pub on_surface_variant: Srgb<T>,
+    /// The color of borders and dividers.
+    pub outline: Srgb<T>,
+    /// A less prominent version of `outline`.
+    pub outline_variant: Srgb<T>,
+    /// The color of shadows.
+    pub shadow: Srgb<T>,
+    /// The color of scrims behind modal components.
+    pub scrim: Srgb<T>,
+    /// A surface color that contrasts with `surface`, such as for snackbars.
+    pub inverse_surface: Srgb<T>,
+    /// The color of content on `inverse_surface`.
+    pub inverse_on_surface: Srgb<T>,
+    /// The color of primary content on `inverse_surface`.
+    pub inverse_primary: Srgb<T>,
+}
+
+impl<T: FloatComponent> Scheme<T> {
+    /// The light scheme of `core`.
+    pub fn light(core: &CorePalette<T>) -> Self {
+        Scheme::from_tones(core, &LIGHT_TONES)
+    }
+
+    /// The dark scheme of `core`.
+    pub fn dark(core: &CorePalette<T>) -> Self {
+        Scheme::from_tones(core, &DARK_TONES)
+    }
+
+    fn from_tones(core: &CorePalette<T>, tones: &SchemeTones) -> Self {
+        let [accent, on_accent, container, on_container] = tones.accent;
+        let tone = |palette: &TonalPalette<T>, tone: f64| palette.tone(from_f64(tone));
+
+        Scheme {
+            primary: tone(&core.primary, accent),
+            on_primary: tone(&core.primary, on_accent),
+            primary_container: tone(&core.primary, container),
+            on_primary_container: tone(&core.primary, on_container),
+            secondary: tone(&core.secondary, accent),
+            on_secondary: tone(&core.secondary, on_accent),
+            secondary_container: tone(&core.secondary, container),
+            on_secondary_container: tone(&core.secondary, on_container),
+            tertiary: tone(&core.tertiary, accent),
+            on_tertiary: tone(&core.tertiary, on_accent),
+            tertiary_container: tone(&core.tertiary, container),
+            on_tertiary_container: tone(&core.tertiary, on_container),
+            error: tone(&core.error, accent),
+            on_error: tone(&core.error, on_accent),
+            error_container: tone(&core.error, container),
+            on_error_container: tone(&core.error, tones.on_error_container),
+            background: tone(&core.neutral, tones.background),
+            on_background: tone(&core.neutral, tones.on_background),
+            surface: tone(&core.neutral, tones.background),
+            on_surface: tone(&core.neutral, tones.on_background),
+            surface_variant: tone(&core.neutral_variant, tones.surface_variant),
+            on_surface_variant: tone(&core.neutral_variant, tones.on_surface_variant),
+            outline: tone(&core.neutral_variant, tones.outline),
+            outline_variant: tone(&core.neutral_variant, tones.outline_variant),
+            shadow: tone(&core.neutral, 0.0),
+            scrim: tone(&core.neutral, 0.0),
+            inverse_surface: tone(&core.neutral, tones.inverse_surface),
+            inverse_on_surface: tone(&core.neutral, tones.inverse_on_surface),
+            inverse_primary: tone(&core.primary, tones.inverse_primary),
+        }
+    }
+}
+
+/// The tones of the colors in a scheme.
+struct SchemeTones {
+    /// The tones of the accent colors, the content on them, their
+    /// containers, and the content on the containers.
+    accent: [f64; 4],
+    on_error_container: f64,
+    background: f64,
+    on_background: f64,
+    surface_variant: f64,
+    on_surface_variant: f64,
+    outline: f64,
+    outline_variant: f64,
+    inverse_surface: f64,
+    inverse_on_surface: f64,
+    inverse_primary: f64,
+}
+
+const LIGHT_TONES: SchemeTones = SchemeTones {
+    accent: [40.0, 100.0, 90.0, 10.0],
+    on_error_container: 10.0,
+    background: 99.0,
+    on_background: 10.0,
+    surface_variant: 90.0,
+    on_surface_variant: 30.0,
+    outline: 50.0,
+    outline_variant: 80.0,
+    inverse_surface: 20.0,
+    inverse_on_surface: 95.0,
+    inverse_primary: 80.0,
+};
+
+const DARK_TONES: SchemeTones = SchemeTones {
+    accent: [80.0, 20.0, 30.0, 90.0],
+    on_error_container: 80.0,
+    background: 10.0,
+    on_background: 90.0,
+    surface_variant: 30.0,
+    on_surface_variant: 80.0,
+    outline: 60.0,
+    outline_variant: 30.0,
+    inverse_surface: 90.0,
+    inverse_on_surface: 20.0,
+    inverse_primary: 40.0,
+};
+
+#[cfg(test)]
+mod test {
+    use super::{CorePalette, Scheme, TonalPalette};
+    use crate::convert::FromColorUnclamped;
+    use crate::{Hct, Srgb};
+
+    fn assert_hex(color: Srgb<f64>, hex: u32) {
+        let color = color.into_format::<u8>();
+        let expected = [(hex >> 16) as u8, (hex >> 8) as u8, hex as u8];
+        for (&channel, &expected) in [color.red, color.green, color.blue].iter().zip(&expected) {
+            assert!(
+                (i16::from(channel) - i16::from(expected)).abs() <= 1,
+                "{:?} != {:06x}",
+                color,
+                hex
+            );
+        }
+    }
+
+    #[test]
+    fn tonal_palette() {
+        let palette = TonalPalette::new(270.0f64, 36.0);
+        assert_relative_eq!(palette.tone(0.0), Srgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(
+            palette.tone(100.0),
+            Srgb::new(1.0, 1.0, 1.0),
+            epsilon = 1e-9
+        );
+
+        let tone = Hct::from_color_unclamped(palette.tone(40.0));
+        assert_relative_eq!(tone.tone, 40.0, epsilon = 0.01);
+        assert_relative_eq!(tone.hue, 270.0, epsilon = 0.1);
+        assert_relative_eq!(tone.chroma, 36.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn blue_seed() {
+        // From the scheme tests of the Material color utilities.
+        let core = CorePalette::new(Srgb::new(0u8, 0, 255).into_format::<f64>());
+        let light = Scheme::light(&core);
+        assert_hex(light.primary, 0x343dff);
+        assert_hex(light.on_primary, 0xffffff);
+        assert_hex(light.primary_container, 0xe0e0ff);
+        assert_hex(light.on_primary_container, 0x00006e);
+        assert_hex(light.surface, 0xfffbff);
+        assert_hex(light.on_surface, 0x1b1b1f);
+
+        let dark = Scheme::dark(&core);
+        assert_hex(dark.primary, 0xbec2ff);
+        assert_hex(dark.on_primary, 0x0001ac);
+        assert_hex(dark.primary_container, 0x0000ef);
+        assert_hex(dark.surface, 0x1b1b1f);
+    }
+}
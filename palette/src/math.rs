@@ -0,0 +1,144 @@
+//! Transcendental functions for the color conversions.
+//!
+//! The results of functions like `powf` and `cbrt` depend on the platform's
+//! math library, which makes conversions slightly different between
+//! platforms and compilers. When the `strict_math` feature is enabled, these
+//! functions are instead calculated in `f64` with the implementations from
+//! `libm`, and rounded to `f32` or `f64`. This makes the conversions produce
+//! bit identical results everywhere, at the cost of some speed. Custom
+//! component types always use their own implementations.
+
+use crate::float::Float;
+use crate::FromF64;
+
+/// Raise `x` to the floating point power `y`.
+#[inline]
+pub(crate) fn powf<T: Float + FromF64>(x: T, y: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let (Some(x), Some(y)) = (x.as_primitive_f64(), y.as_primitive_f64()) {
+            return T::from_f64(libm::pow(x, y));
+        }
+    }
+
+    x.powf(y)
+}
+
+/// The cube root of `x`.
+#[inline]
+pub(crate) fn cbrt<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::cbrt(x));
+        }
+    }
+
+    x.cbrt()
+}
+
+/// `e` raised to the power of `x`.
+#[inline]
+pub(crate) fn exp<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::exp(x));
+        }
+    }
+
+    x.exp()
+}
+
+/// The natural logarithm of `x`.
+#[inline]
+pub(crate) fn ln<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::log(x));
+        }
+    }
+
+    x.ln()
+}
+
+/// The base 10 logarithm of `x`.
+#[inline]
+pub(crate) fn log10<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::log10(x));
+        }
+    }
+
+    x.log10()
+}
+
+/// The sine of `x`, in radians.
+#[inline]
+pub(crate) fn sin<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::sin(x));
+        }
+    }
+
+    x.sin()
+}
+
+/// The cosine of `x`, in radians.
+#[inline]
+pub(crate) fn cos<T: Float + FromF64>(x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            return T::from_f64(libm::cos(x));
+        }
+    }
+
+    x.cos()
+}
+
+/// The four quadrant arctangent of `y` and `x`, in radians.
+#[inline]
+pub(crate) fn atan2<T: Float + FromF64>(y: T, x: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let (Some(y), Some(x)) = (y.as_primitive_f64(), x.as_primitive_f64()) {
+            return T::from_f64(libm::atan2(y, x));
+        }
+    }
+
+    y.atan2(x)
+}
+
+/// The length of the hypotenuse of a right triangle with the sides `x` and
+/// `y`.
+#[inline]
+pub(crate) fn hypot<T: Float + FromF64>(x: T, y: T) -> T {
+    #[cfg(feature = "strict_math")]
+    {
+        if let (Some(x), Some(y)) = (x.as_primitive_f64(), y.as_primitive_f64()) {
+            return T::from_f64(libm::hypot(x, y));
+        }
+    }
+
+    x.hypot(y)
+}
+
+/// The sine and cosine of `x`, in radians.
+#[inline]
+pub(crate) fn sin_cos<T: Float + FromF64>(x: T) -> (T, T) {
+    #[cfg(feature = "strict_math")]
+    {
+        if let Some(x) = x.as_primitive_f64() {
+            let (sin, cos) = libm::sincos(x);
+            return (T::from_f64(sin), T::from_f64(cos));
+        }
+    }
+
+    x.sin_cos()
+}
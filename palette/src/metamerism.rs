@@ -0,0 +1,136 @@
+//! Metamerism and color inconstancy of surfaces.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! Two surfaces with different reflectance spectra can look the same under
+//! one light source and different under another. This is called metamerism,
+//! and [`metamerism_index`](fn.metamerism_index.html) measures how much a
+//! color match breaks when the light changes. A single surface can also
+//! change its appearance with the light, more than the adaptation of the eye
+//! makes up for, which [`color_inconstancy_index`](fn.color_inconstancy_index.html)
+//! measures.
+//!
+//! The reflectance spectra and the light sources are given as
+//! [`Spd`](../spectrum/struct.Spd.html) values.
+
+use crate::chromatic_adaptation::{adaptation_matrix, cmccat2000_cone_response};
+use crate::convert::FromColorUnclamped;
+use crate::matrix::multiply_xyz;
+use crate::spectrum::Spd;
+use crate::white_point::E;
+use crate::{ColorDifference, FloatComponent, Lab, Xyz};
+
+/// The special metamerism index for a change in illuminant, from CIE 15.
+///
+/// `sample` and `standard` are the reflectance spectra of two surfaces that
+/// are supposed to match under the light source `reference`. The index is
+/// their CIELAB color difference, ΔE\*ab, under the light source `test`. Any
+/// remaining difference under `reference` is subtracted first, so the index
+/// only shows the change caused by the light.
+pub fn metamerism_index<T: FloatComponent>(
+    sample: &Spd<T>,
+    standard: &Spd<T>,
+    reference: &Spd<T>,
+    test: &Spd<T>,
+) -> T {
+    let reference_difference = lab_under(sample, reference) - lab_under(standard, reference);
+    let test_difference = lab_under(sample, test) - lab_under(standard, test);
+    let difference = test_difference - reference_difference;
+
+    (difference.l * difference.l + difference.a * difference.a + difference.b * difference.b).sqrt()
+}
+
+/// The CMCCON02 color inconstancy index of a surface.
+///
+/// The color of the surface with the reflectance spectrum `sample` under the
+/// light source `test` is adapted to the light source `reference` with
+/// CMCCAT2000, assuming complete adaptation. The index is the CIEDE2000
+/// color difference between the adapted color and the color under
+/// `reference`. CMCCON02 uses CIE D65 as the reference.
+pub fn color_inconstancy_index<T: FloatComponent>(
+    sample: &Spd<T>,
+    test: &Spd<T>,
+    reference: &Spd<T>,
+) -> T {
+    let test_white: Xyz<E, T> = test.to_xyz();
+    let test_white = test_white / test_white.y;
+    let reference_white: Xyz<E, T> = reference.to_xyz();
+    let reference_white = reference_white / reference_white.y;
+
+    let adapt = adaptation_matrix(&cmccat2000_cone_response(), &test_white, &reference_white);
+
+    let color: Xyz<E, T> = sample.reflectance_to_xyz(test);
+    let adapted = lab_relative_to(multiply_xyz(&adapt, &color), reference_white);
+
+    adapted.get_color_difference(&lab_under(sample, reference))
+}
+
+/// The CIELAB color of a surface, relative to the white of the light source
+/// it's seen under.
+fn lab_under<T: FloatComponent>(surface: &Spd<T>, illuminant: &Spd<T>) -> Lab<E, T> {
+    let white: Xyz<E, T> = illuminant.to_xyz();
+    lab_relative_to(surface.reflectance_to_xyz(illuminant), white / white.y)
+}
+
+/// Convert `color` to CIELAB, relative to the white point `white`.
+///
+/// Dividing by the white point and converting with the equal energy white
+/// point, which is `1.0` in all components, gives the same result as
+/// converting with `white` as the white point.
+fn lab_relative_to<T: FloatComponent>(color: Xyz<E, T>, white: Xyz<E, T>) -> Lab<E, T> {
+    Lab::from_color_unclamped(color / white)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{color_inconstancy_index, metamerism_index};
+    use crate::spectrum::Spd;
+
+    fn flat(value: f64) -> Spd<f64> {
+        Spd::new(380.0, 10.0, vec![value; 41])
+    }
+
+    fn red() -> Spd<f64> {
+        Spd::new(380.0, 100.0, vec![0.05, 0.1, 0.8, 0.9, 0.9])
+    }
+
+    #[test]
+    fn metamerism() {
+        let d65 = Spd::daylight(6504.0);
+        let a = Spd::blackbody(2856.0);
+
+        assert_relative_eq!(metamerism_index(&red(), &red(), &d65, &a), 0.0);
+        assert_relative_eq!(
+            metamerism_index(&red(), &flat(0.4), &d65, &d65),
+            0.0,
+            epsilon = 1e-12
+        );
+
+        let index = metamerism_index(&red(), &flat(0.4), &d65, &a);
+        assert!(index > 1.0);
+        assert_relative_eq!(
+            metamerism_index(&flat(0.4), &red(), &d65, &a),
+            index,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn color_inconstancy() {
+        let d65 = Spd::daylight(6504.0);
+        let a = Spd::blackbody(2856.0);
+
+        assert_relative_eq!(
+            color_inconstancy_index(&flat(0.4), &a, &d65),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            color_inconstancy_index(&red(), &d65, &d65),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert!(color_inconstancy_index(&red(), &a, &d65) > 0.5);
+    }
+}
@@ -29,3 +29,323 @@ include!(concat!(env!("OUT_DIR"), "/named.rs"));
 pub fn from_str(name: &str) -> Option<crate::Srgb<u8>> {
     COLORS.get(name).cloned()
 }
+
+/// A CSS system color, as in CSS Color 4.
+///
+/// System colors are chosen by the user agent, often to match the operating
+/// system or a user preference, so their actual values are up to the
+/// renderer. [`default_color`](#method.default_color) gives values that are
+/// similar to what browsers use for a light color scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SystemColor {
+    /// `AccentColor`, the background of accented user interface controls.
+    AccentColor,
+    /// `AccentColorText`, the text of accented user interface controls.
+    AccentColorText,
+    /// `ActiveText`, the text of active links.
+    ActiveText,
+    /// `ButtonBorder`, the base border color of controls.
+    ButtonBorder,
+    /// `ButtonFace`, the background of push buttons.
+    ButtonFace,
+    /// `ButtonText`, the text of push buttons.
+    ButtonText,
+    /// `Canvas`, the background of application content or documents.
+    Canvas,
+    /// `CanvasText`, the text in application content or documents.
+    CanvasText,
+    /// `Field`, the background of input fields.
+    Field,
+    /// `FieldText`, the text in input fields.
+    FieldText,
+    /// `GrayText`, the text of disabled content.
+    GrayText,
+    /// `Highlight`, the background of selected text.
+    Highlight,
+    /// `HighlightText`, the selected text.
+    HighlightText,
+    /// `LinkText`, the text of links that haven't been visited.
+    LinkText,
+    /// `Mark`, the background of marked text, such as search results.
+    Mark,
+    /// `MarkText`, the marked text.
+    MarkText,
+    /// `SelectedItem`, the background of selected items, such as a checked
+    /// checkbox.
+    SelectedItem,
+    /// `SelectedItemText`, the text of selected items.
+    SelectedItemText,
+    /// `VisitedText`, the text of visited links.
+    VisitedText,
+}
+
+impl SystemColor {
+    /// Get a system color by its keyword, ignoring ASCII case.
+    ///
+    /// The deprecated system colors from CSS 2, such as `ThreeDFace` and
+    /// `WindowText`, are mapped to their replacements, the same way as in CSS
+    /// Color 4.
+    pub fn from_name(name: &str) -> Option<Self> {
+        use self::SystemColor::*;
+
+        let keywords = [
+            ("accentcolor", AccentColor),
+            ("accentcolortext", AccentColorText),
+            ("activetext", ActiveText),
+            ("buttonborder", ButtonBorder),
+            ("buttonface", ButtonFace),
+            ("buttontext", ButtonText),
+            ("canvas", Canvas),
+            ("canvastext", CanvasText),
+            ("field", Field),
+            ("fieldtext", FieldText),
+            ("graytext", GrayText),
+            ("highlight", Highlight),
+            ("highlighttext", HighlightText),
+            ("linktext", LinkText),
+            ("mark", Mark),
+            ("marktext", MarkText),
+            ("selecteditem", SelectedItem),
+            ("selecteditemtext", SelectedItemText),
+            ("visitedtext", VisitedText),
+            // Deprecated system colors.
+            ("activeborder", ButtonBorder),
+            ("activecaption", Canvas),
+            ("appworkspace", Canvas),
+            ("background", Canvas),
+            ("buttonhighlight", ButtonFace),
+            ("buttonshadow", ButtonFace),
+            ("captiontext", CanvasText),
+            ("inactiveborder", ButtonBorder),
+            ("inactivecaption", Canvas),
+            ("inactivecaptiontext", GrayText),
+            ("infobackground", Canvas),
+            ("infotext", CanvasText),
+            ("menu", Canvas),
+            ("menutext", CanvasText),
+            ("scrollbar", Canvas),
+            ("threeddarkshadow", ButtonBorder),
+            ("threedface", ButtonFace),
+            ("threedhighlight", ButtonBorder),
+            ("threedlightshadow", ButtonBorder),
+            ("threedshadow", ButtonBorder),
+            ("window", Canvas),
+            ("windowframe", ButtonBorder),
+            ("windowtext", CanvasText),
+        ];
+
+        keywords
+            .iter()
+            .find(|(keyword, _)| keyword.eq_ignore_ascii_case(name))
+            .map(|&(_, color)| color)
+    }
+
+    /// A default value for the system color, for a light color scheme.
+    pub fn default_color(self) -> crate::Srgb<u8> {
+        use self::SystemColor::*;
+
+        let (red, green, blue) = match self {
+            AccentColor => (0x00, 0x75, 0xff),
+            AccentColorText => (0xff, 0xff, 0xff),
+            ActiveText => (0xff, 0x00, 0x00),
+            ButtonBorder => (0x76, 0x76, 0x76),
+            ButtonFace => (0xef, 0xef, 0xef),
+            ButtonText => (0x00, 0x00, 0x00),
+            Canvas => (0xff, 0xff, 0xff),
+            CanvasText => (0x00, 0x00, 0x00),
+            Field => (0xff, 0xff, 0xff),
+            FieldText => (0x00, 0x00, 0x00),
+            GrayText => (0x6d, 0x6d, 0x6d),
+            Highlight => (0xb5, 0xd5, 0xff),
+            HighlightText => (0x00, 0x00, 0x00),
+            LinkText => (0x00, 0x00, 0xee),
+            Mark => (0xff, 0xff, 0x00),
+            MarkText => (0x00, 0x00, 0x00),
+            SelectedItem => (0x00, 0x75, 0xff),
+            SelectedItemText => (0xff, 0xff, 0xff),
+            VisitedText => (0x55, 0x1a, 0x8b),
+        };
+
+        crate::Srgb::new(red, green, blue)
+    }
+}
+
+/// A color keyword, as in the `<named-color>`, `<system-color>`,
+/// `currentColor` and `transparent` grammar of CSS and SVG. Can be toggled
+/// with the `"named_from_str"` Cargo feature.
+///
+/// `currentColor` and system colors depend on where they are used, so they
+/// are resolved with a [`KeywordResolver`](trait.KeywordResolver.html).
+///
+/// ```
+/// use palette::named::{Keyword, SystemColor};
+/// use palette::Srgb;
+///
+/// assert_eq!(Keyword::from_name("Olive"), Some(Keyword::Named(Srgb::new(128, 128, 0))));
+/// assert_eq!(Keyword::from_name("ButtonFace"), Some(Keyword::System(SystemColor::ButtonFace)));
+/// assert_eq!(Keyword::from_name("currentcolor"), Some(Keyword::CurrentColor));
+/// assert_eq!(Keyword::from_name("bluish"), None);
+/// ```
+#[cfg(feature = "named_from_str")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Keyword {
+    /// A named color, such as `olive`.
+    Named(crate::Srgb<u8>),
+    /// A system color, such as `Canvas`.
+    System(SystemColor),
+    /// `currentColor`, the value of the `color` property.
+    CurrentColor,
+    /// `transparent`, which is transparent black.
+    Transparent,
+}
+
+#[cfg(feature = "named_from_str")]
+impl Keyword {
+    /// Parse a color keyword, ignoring ASCII case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("currentcolor") {
+            return Some(Keyword::CurrentColor);
+        }
+
+        if name.eq_ignore_ascii_case("transparent") {
+            return Some(Keyword::Transparent);
+        }
+
+        // No named color is longer than this, so anything longer can't be one.
+        let mut buffer = [0u8; 32];
+        if name.len() <= buffer.len() {
+            let lowercase = &mut buffer[..name.len()];
+            lowercase.copy_from_slice(name.as_bytes());
+            lowercase.make_ascii_lowercase();
+
+            let named = core::str::from_utf8(lowercase).ok().and_then(from_str);
+            if let Some(color) = named {
+                return Some(Keyword::Named(color));
+            }
+        }
+
+        SystemColor::from_name(name).map(Keyword::System)
+    }
+
+    /// Resolve the keyword to a color, using `resolver` for `currentColor`
+    /// and system colors.
+    pub fn resolve<R: KeywordResolver + ?Sized>(self, resolver: &R) -> crate::Srgba<u8> {
+        match self {
+            Keyword::Named(color) => color.into(),
+            Keyword::System(color) => resolver.system_color(color).into(),
+            Keyword::CurrentColor => resolver.current_color(),
+            Keyword::Transparent => crate::Srgba::new(0, 0, 0, 0),
+        }
+    }
+}
+
+/// Resolves the color keywords that depend on their context. Can be toggled
+/// with the `"named_from_str"` Cargo feature.
+///
+/// This is the hook for letting a renderer decide what `currentColor` and
+/// the system colors are, while [`Keyword`](enum.Keyword.html) and
+/// [`resolve`](fn.resolve.html) handle the rest of the keyword grammar.
+///
+/// ```
+/// use palette::named::{resolve, KeywordResolver, SystemColor};
+/// use palette::{Srgb, Srgba};
+///
+/// struct DarkTheme {
+///     color: Srgba<u8>,
+/// }
+///
+/// impl KeywordResolver for DarkTheme {
+///     fn current_color(&self) -> Srgba<u8> {
+///         self.color
+///     }
+///
+///     fn system_color(&self, color: SystemColor) -> Srgb<u8> {
+///         match color {
+///             SystemColor::Canvas => Srgb::new(18, 18, 18),
+///             SystemColor::CanvasText => Srgb::new(255, 255, 255),
+///             color => color.default_color(),
+///         }
+///     }
+/// }
+///
+/// let theme = DarkTheme { color: Srgba::new(200, 100, 0, 255) };
+/// assert_eq!(resolve("currentColor", &theme), Some(Srgba::new(200, 100, 0, 255)));
+/// assert_eq!(resolve("WindowText", &theme), Some(Srgba::new(255, 255, 255, 255)));
+/// assert_eq!(resolve("transparent", &theme), Some(Srgba::new(0, 0, 0, 0)));
+/// ```
+#[cfg(feature = "named_from_str")]
+pub trait KeywordResolver {
+    /// The value of `currentColor`.
+    fn current_color(&self) -> crate::Srgba<u8>;
+
+    /// The value of a system color. Defaults to
+    /// [`SystemColor::default_color`](enum.SystemColor.html#method.default_color).
+    fn system_color(&self, color: SystemColor) -> crate::Srgb<u8> {
+        color.default_color()
+    }
+}
+
+/// Resolve the color keyword `name` to a color, or return `None` if it's not
+/// a color keyword. Can be toggled with the `"named_from_str"` Cargo feature.
+///
+/// This is the same as parsing it with
+/// [`Keyword::from_name`](enum.Keyword.html#method.from_name) and resolving it
+/// with [`Keyword::resolve`](enum.Keyword.html#method.resolve).
+#[cfg(feature = "named_from_str")]
+pub fn resolve<R: KeywordResolver + ?Sized>(name: &str, resolver: &R) -> Option<crate::Srgba<u8>> {
+    Keyword::from_name(name).map(|keyword| keyword.resolve(resolver))
+}
+
+#[cfg(all(test, feature = "named_from_str"))]
+mod test {
+    use super::{resolve, Keyword, KeywordResolver, SystemColor};
+    use crate::{Srgb, Srgba};
+
+    struct Resolver;
+
+    impl KeywordResolver for Resolver {
+        fn current_color(&self) -> Srgba<u8> {
+            Srgba::new(1, 2, 3, 4)
+        }
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(
+            Keyword::from_name("LightGoldenrodYellow"),
+            Some(Keyword::Named(Srgb::new(250, 250, 210)))
+        );
+        assert_eq!(
+            Keyword::from_name("CURRENTCOLOR"),
+            Some(Keyword::CurrentColor)
+        );
+        assert_eq!(
+            Keyword::from_name("Transparent"),
+            Some(Keyword::Transparent)
+        );
+        assert_eq!(
+            Keyword::from_name("threedface"),
+            Some(Keyword::System(SystemColor::ButtonFace))
+        );
+        assert_eq!(Keyword::from_name("not a color"), None);
+        assert_eq!(Keyword::from_name(""), None);
+    }
+
+    #[test]
+    fn resolver() {
+        assert_eq!(
+            resolve("currentcolor", &Resolver),
+            Some(Srgba::new(1, 2, 3, 4))
+        );
+        assert_eq!(
+            resolve("transparent", &Resolver),
+            Some(Srgba::new(0, 0, 0, 0))
+        );
+        assert_eq!(resolve("red", &Resolver), Some(Srgba::new(255, 0, 0, 255)));
+        assert_eq!(
+            resolve("Canvas", &Resolver),
+            Some(Srgba::new(255, 255, 255, 255))
+        );
+        assert_eq!(resolve("nope", &Resolver), None);
+    }
+}
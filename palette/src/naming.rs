@@ -0,0 +1,332 @@
+//! Coarse color names, for describing and searching colors in words.
+//!
+//! [`ColorName`](enum.ColorName.html) has the eleven basic color terms that
+//! most languages have words for, as described by Berlin and Kay. A color is
+//! classified by its CIE L\*a\*b\* values, where colors with a low chroma are
+//! black, gray or white, depending on their lightness, and the other colors
+//! get the name of the nearest prototype color. The prototypes approximate
+//! the focal colors and common variations that people pick in color naming
+//! surveys, so they can be used for generating alt texts, or for finding
+//! images with a certain dominating color:
+//!
+//! ```
+//! use palette::naming::{ColorName, NameHistogram};
+//! use palette::Srgb;
+//!
+//! assert_eq!(ColorName::classify(Srgb::new(1.0f32, 0.0, 0.0)), ColorName::Red);
+//! assert_eq!(ColorName::classify(Srgb::new(0.53f32, 0.81, 0.92)), ColorName::Blue);
+//! assert_eq!(ColorName::Blue.to_string(), "blue");
+//!
+//! let pixels = [
+//!     Srgb::new(0.1f32, 0.2, 0.8),
+//!     Srgb::new(0.2, 0.4, 0.9),
+//!     Srgb::new(0.9, 0.9, 0.9),
+//! ];
+//! let histogram = NameHistogram::from_colors(pixels.iter().copied());
+//!
+//! assert_eq!(histogram.dominant(), Some(ColorName::Blue));
+//! assert!(histogram.fraction(ColorName::Blue) > 0.5);
+//! ```
+//!
+//! The names are coarse by design, and the borders between them are fuzzy.
+//! Colors near a border, such as teal or maroon, may get either name.
+
+use core::fmt;
+
+use crate::white_point::D65;
+use crate::{from_f64, FloatComponent, FromColor, Lab};
+
+/// One of the eleven basic color names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColorName {
+    /// Red.
+    Red,
+    /// Orange.
+    Orange,
+    /// Yellow.
+    Yellow,
+    /// Green.
+    Green,
+    /// Blue.
+    Blue,
+    /// Purple.
+    Purple,
+    /// Pink.
+    Pink,
+    /// Brown.
+    Brown,
+    /// Black.
+    Black,
+    /// Gray.
+    Gray,
+    /// White.
+    White,
+}
+
+/// The prototype colors of the chromatic names, as D65 L\*a\*b\*.
+const PROTOTYPES: [(ColorName, [f64; 3]); 22] = [
+    (ColorName::Red, [43.0, 63.0, 40.0]),
+    (ColorName::Red, [28.0, 45.0, 25.0]),
+    (ColorName::Orange, [65.0, 36.0, 66.0]),
+    (ColorName::Yellow, [87.0, -8.0, 82.0]),
+    (ColorName::Yellow, [94.0, -10.0, 49.0]),
+    (ColorName::Green, [55.0, -48.0, 38.0]),
+    (ColorName::Green, [29.0, -31.0, 24.0]),
+    (ColorName::Green, [78.0, -35.0, 39.0]),
+    (ColorName::Green, [52.0, -16.0, 45.0]),
+    (ColorName::Blue, [41.0, 24.0, -62.0]),
+    (ColorName::Blue, [19.0, 20.0, -43.0]),
+    (ColorName::Blue, [22.0, 39.0, -63.0]),
+    (ColorName::Blue, [68.0, -3.0, -36.0]),
+    (ColorName::Blue, [61.0, -18.0, -30.0]),
+    (ColorName::Purple, [35.0, 47.0, -42.0]),
+    (ColorName::Purple, [20.0, 30.0, -28.0]),
+    (ColorName::Purple, [65.0, 30.0, -35.0]),
+    (ColorName::Pink, [72.0, 38.0, -1.0]),
+    (ColorName::Pink, [54.0, 71.0, -10.0]),
+    (ColorName::Brown, [36.0, 15.0, 28.0]),
+    (ColorName::Brown, [24.0, 11.0, 18.0]),
+    (ColorName::Brown, [57.0, 10.0, 28.0]),
+];
+
+/// The chroma where colors stop being black, gray or white.
+const ACHROMATIC_CHROMA: f64 = 10.0;
+
+/// The lightness below which an achromatic color is black.
+const BLACK_LIGHTNESS: f64 = 25.0;
+
+/// The lightness above which an achromatic color is white.
+const WHITE_LIGHTNESS: f64 = 88.0;
+
+impl ColorName {
+    /// All of the names, in the same order as they are declared.
+    pub const ALL: [ColorName; 11] = [
+        ColorName::Red,
+        ColorName::Orange,
+        ColorName::Yellow,
+        ColorName::Green,
+        ColorName::Blue,
+        ColorName::Purple,
+        ColorName::Pink,
+        ColorName::Brown,
+        ColorName::Black,
+        ColorName::Gray,
+        ColorName::White,
+    ];
+
+    /// Find the name of `color`.
+    pub fn classify<C, T>(color: C) -> Self
+    where
+        T: FloatComponent,
+        Lab<D65, T>: FromColor<C>,
+    {
+        Self::classify_lab(Lab::from_color(color))
+    }
+
+    /// Find the name of a color in L\*a\*b\*.
+    pub fn classify_lab<T: FloatComponent>(color: Lab<D65, T>) -> Self {
+        let chroma = (color.a * color.a + color.b * color.b).sqrt();
+
+        if chroma < from_f64(ACHROMATIC_CHROMA) {
+            return if color.l < from_f64(BLACK_LIGHTNESS) {
+                ColorName::Black
+            } else if color.l > from_f64(WHITE_LIGHTNESS) {
+                ColorName::White
+            } else {
+                ColorName::Gray
+            };
+        }
+
+        let mut closest = (ColorName::Gray, T::infinity());
+        for &(name, [l, a, b]) in &PROTOTYPES {
+            let dl = color.l - from_f64(l);
+            let da = color.a - from_f64(a);
+            let db = color.b - from_f64(b);
+            let distance = dl * dl + da * da + db * db;
+
+            if distance < closest.1 {
+                closest = (name, distance);
+            }
+        }
+
+        closest.0
+    }
+
+    /// The name in lowercase English, such as `"blue"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorName::Red => "red",
+            ColorName::Orange => "orange",
+            ColorName::Yellow => "yellow",
+            ColorName::Green => "green",
+            ColorName::Blue => "blue",
+            ColorName::Purple => "purple",
+            ColorName::Pink => "pink",
+            ColorName::Brown => "brown",
+            ColorName::Black => "black",
+            ColorName::Gray => "gray",
+            ColorName::White => "white",
+        }
+    }
+}
+
+impl fmt::Display for ColorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The number of colors with each name, such as the pixels of an image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NameHistogram {
+    counts: [usize; 11],
+}
+
+impl NameHistogram {
+    /// Create an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify and count each color in `colors`.
+    pub fn from_colors<I, C, T>(colors: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        T: FloatComponent,
+        Lab<D65, T>: FromColor<C>,
+    {
+        let mut histogram = Self::new();
+        for color in colors {
+            histogram.add(ColorName::classify(color));
+        }
+
+        histogram
+    }
+
+    /// Count one more color with `name`.
+    pub fn add(&mut self, name: ColorName) {
+        self.counts[name as usize] += 1;
+    }
+
+    /// The number of colors with `name`.
+    pub fn count(&self, name: ColorName) -> usize {
+        self.counts[name as usize]
+    }
+
+    /// The total number of colors.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// The fraction of the colors that have `name`, from `0.0` to `1.0`. It's
+    /// `0.0` if the histogram is empty.
+    pub fn fraction(&self, name: ColorName) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            0.0
+        } else {
+            self.count(name) as f64 / total as f64
+        }
+    }
+
+    /// The most common name, or `None` if the histogram is empty. Ties go to
+    /// the name that comes first in [`ColorName::ALL`](enum.ColorName.html#associatedconstant.ALL).
+    pub fn dominant(&self) -> Option<ColorName> {
+        let mut dominant = None;
+        let mut highest = 0;
+        for &name in &ColorName::ALL {
+            if self.count(name) > highest {
+                dominant = Some(name);
+                highest = self.count(name);
+            }
+        }
+
+        dominant
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ColorName, NameHistogram};
+    use crate::Srgb;
+
+    #[test]
+    fn common_colors() {
+        let colors: [(ColorName, &[(u8, u8, u8)]); 11] = [
+            (ColorName::Red, &[(255, 0, 0), (180, 0, 0), (220, 20, 60)]),
+            (
+                ColorName::Orange,
+                &[(255, 165, 0), (255, 140, 0), (255, 127, 80)],
+            ),
+            (
+                ColorName::Yellow,
+                &[(255, 255, 0), (255, 215, 0), (240, 230, 140)],
+            ),
+            (
+                ColorName::Green,
+                &[
+                    (0, 128, 0),
+                    (0, 255, 0),
+                    (34, 139, 34),
+                    (128, 128, 0),
+                    (144, 238, 144),
+                ],
+            ),
+            (
+                ColorName::Blue,
+                &[
+                    (0, 0, 255),
+                    (0, 0, 128),
+                    (135, 206, 235),
+                    (70, 130, 180),
+                    (30, 144, 255),
+                ],
+            ),
+            (ColorName::Purple, &[(128, 0, 128), (148, 0, 211)]),
+            (
+                ColorName::Pink,
+                &[(255, 192, 203), (255, 105, 180), (255, 20, 147)],
+            ),
+            (
+                ColorName::Brown,
+                &[(139, 69, 19), (160, 82, 45), (222, 184, 135)],
+            ),
+            (ColorName::Black, &[(0, 0, 0), (20, 20, 25)]),
+            (
+                ColorName::Gray,
+                &[(128, 128, 128), (169, 169, 169), (105, 105, 105)],
+            ),
+            (ColorName::White, &[(255, 255, 255), (250, 250, 245)]),
+        ];
+
+        for &(name, examples) in &colors {
+            for &(red, green, blue) in examples {
+                let color = Srgb::new(red, green, blue).into_format::<f64>();
+                assert_eq!(
+                    ColorName::classify(color),
+                    name,
+                    "({}, {}, {})",
+                    red,
+                    green,
+                    blue
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn histogram() {
+        let mut histogram = NameHistogram::new();
+        assert_eq!(histogram.dominant(), None);
+        assert_eq!(histogram.fraction(ColorName::Red), 0.0);
+
+        histogram.add(ColorName::Red);
+        histogram.add(ColorName::Green);
+        histogram.add(ColorName::Green);
+
+        assert_eq!(histogram.total(), 3);
+        assert_eq!(histogram.count(ColorName::Green), 2);
+        assert_eq!(histogram.dominant(), Some(ColorName::Green));
+        assert!((histogram.fraction(ColorName::Red) - 1.0 / 3.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,418 @@
+//! Component ranges and normalization to `[0.0, 1.0]`.
+//!
+//! The components of most color spaces have different ranges, such as
+//! `0.0..=100.0` for the L\* of [`Lab`](../struct.Lab.html) and
+//! `-128.0..=127.0` for its a\* and b\*. The range of each component is
+//! available as an associated constant for `f32` and `f64`, and the
+//! [`Normalize`](trait.Normalize.html) trait maps the components to and from
+//! `[0.0, 1.0]`, which is useful when colors are used as input to something
+//! that expects values within the same range, such as a neural network:
+//!
+//! ```
+//! use palette::normalize::Normalize;
+//! use palette::white_point::D65;
+//! use palette::Lab;
+//!
+//! assert_eq!(Lab::<D65, f32>::A_RANGE, -128.0..=127.0);
+//!
+//! let lab: Lab = Lab::new(50.0, -128.0, 127.0);
+//! assert_eq!(lab.normalize(), [0.5, 0.0, 1.0]);
+//! assert_eq!(Lab::denormalize([0.5, 0.0, 1.0]), lab);
+//! ```
+//!
+//! Some of the ranges don't cover every possible color, so normalized values
+//! may be outside `[0.0, 1.0]` for colors with extreme components. Hues are
+//! made positive before they are normalized, so `-90°` and `270°` are both
+//! normalized to `0.75`.
+
+use core::ops::RangeInclusive;
+
+use crate::luma::{Luma, LumaStandard};
+use crate::rgb::{Rgb, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{
+    from_f64, Alpha, FloatComponent, Hsl, Hsluv, Hsv, Hwb, Lab, LabHue, Lch, Lchuv, Luv, LuvHue,
+    RgbHue, Xyz, Yxy,
+};
+
+/// Map the components of a color to and from `[0.0, 1.0]`.
+///
+/// The components are scaled using the typical range of each component, as
+/// returned by [`component_ranges`](#tymethod.component_ranges). The alpha
+/// component of a transparent color is already in `[0.0, 1.0]` and is kept as
+/// it is.
+pub trait Normalize<T, const N: usize>: Sized {
+    /// The minimum and maximum value of each component, in the same order as
+    /// in the normalized array.
+    fn component_ranges() -> [(T, T); N];
+
+    /// Scale each component from its range to `[0.0, 1.0]`.
+    fn normalize(self) -> [T; N];
+
+    /// Scale each component from `[0.0, 1.0]` to its range.
+    fn denormalize(normalized: [T; N]) -> Self;
+}
+
+/// A component value that can be normalized.
+trait ComponentValue<T> {
+    fn into_value(self) -> T;
+}
+
+impl<T: FloatComponent> ComponentValue<T> for T {
+    fn into_value(self) -> T {
+        self
+    }
+}
+
+macro_rules! impl_component_value_hue {
+    ($($hue_ty: ident),+) => {
+        $(
+            impl<T: FloatComponent> ComponentValue<T> for $hue_ty<T> {
+                fn into_value(self) -> T {
+                    self.to_positive_degrees()
+                }
+            }
+        )+
+    };
+}
+
+impl_component_value_hue!(LabHue, LuvHue, RgbHue);
+
+macro_rules! impl_normalize {
+    (
+        $self_ty: ident < $param: ident : $param_bound: ident >,
+        $channels: expr,
+        [$($element: ident: ($min: expr, $max: expr)),+]
+    ) => {
+        impl<$param, T> Normalize<T, $channels> for $self_ty<$param, T>
+        where
+            T: FloatComponent,
+            $param: $param_bound,
+        {
+            fn component_ranges() -> [(T, T); $channels] {
+                [$(($min, $max)),+]
+            }
+
+            fn normalize(self) -> [T; $channels] {
+                let mut values = [$(ComponentValue::<T>::into_value(self.$element)),+];
+                for (value, &(min, max)) in values.iter_mut().zip(&Self::component_ranges()) {
+                    *value = (*value - min) / (max - min);
+                }
+                values
+            }
+
+            fn denormalize(normalized: [T; $channels]) -> Self {
+                let mut values = normalized;
+                for (value, &(min, max)) in values.iter_mut().zip(&Self::component_ranges()) {
+                    *value = *value * (max - min) + min;
+                }
+                let [$($element),+] = values;
+                Self::from_components(($($element,)+))
+            }
+        }
+    };
+}
+
+impl_normalize!(
+    Rgb<S: RgbStandard>,
+    3,
+    [
+        red: (Self::min_red(), Self::max_red()),
+        green: (Self::min_green(), Self::max_green()),
+        blue: (Self::min_blue(), Self::max_blue())
+    ]
+);
+impl_normalize!(
+    Luma<S: LumaStandard>,
+    1,
+    [luma: (Self::min_luma(), Self::max_luma())]
+);
+impl_normalize!(
+    Hsl<S: RgbStandard>,
+    3,
+    [
+        hue: (T::zero(), from_f64(360.0)),
+        saturation: (Self::min_saturation(), Self::max_saturation()),
+        lightness: (Self::min_lightness(), Self::max_lightness())
+    ]
+);
+impl_normalize!(
+    Hsv<S: RgbStandard>,
+    3,
+    [
+        hue: (T::zero(), from_f64(360.0)),
+        saturation: (Self::min_saturation(), Self::max_saturation()),
+        value: (Self::min_value(), Self::max_value())
+    ]
+);
+impl_normalize!(
+    Hwb<S: RgbStandard>,
+    3,
+    [
+        hue: (T::zero(), from_f64(360.0)),
+        whiteness: (Self::min_whiteness(), Self::max_whiteness()),
+        blackness: (Self::min_blackness(), Self::max_blackness())
+    ]
+);
+impl_normalize!(
+    Lab<Wp: WhitePoint>,
+    3,
+    [
+        l: (Self::min_l(), Self::max_l()),
+        a: (Self::min_a(), Self::max_a()),
+        b: (Self::min_b(), Self::max_b())
+    ]
+);
+impl_normalize!(
+    Lch<Wp: WhitePoint>,
+    3,
+    [
+        l: (Self::min_l(), Self::max_l()),
+        chroma: (Self::min_chroma(), Self::max_chroma()),
+        hue: (T::zero(), from_f64(360.0))
+    ]
+);
+impl_normalize!(
+    Luv<Wp: WhitePoint>,
+    3,
+    [
+        l: (Self::min_l(), Self::max_l()),
+        u: (Self::min_u(), Self::max_u()),
+        v: (Self::min_v(), Self::max_v())
+    ]
+);
+impl_normalize!(
+    Lchuv<Wp: WhitePoint>,
+    3,
+    [
+        l: (Self::min_l(), Self::max_l()),
+        chroma: (Self::min_chroma(), Self::max_chroma()),
+        hue: (T::zero(), from_f64(360.0))
+    ]
+);
+impl_normalize!(
+    Hsluv<Wp: WhitePoint>,
+    3,
+    [
+        hue: (T::zero(), from_f64(360.0)),
+        saturation: (Self::min_saturation(), Self::max_saturation()),
+        l: (Self::min_l(), Self::max_l())
+    ]
+);
+impl_normalize!(
+    Xyz<Wp: WhitePoint>,
+    3,
+    [
+        x: (Self::min_x(), Self::max_x()),
+        y: (Self::min_y(), Self::max_y()),
+        z: (Self::min_z(), Self::max_z())
+    ]
+);
+impl_normalize!(
+    Yxy<Wp: WhitePoint>,
+    3,
+    [
+        x: (Self::min_x(), Self::max_x()),
+        y: (Self::min_y(), Self::max_y()),
+        luma: (Self::min_luma(), Self::max_luma())
+    ]
+);
+
+macro_rules! impl_normalize_alpha {
+    ($($color_channels: expr => $channels: expr),+) => {
+        $(
+            impl<C, T> Normalize<T, $channels> for Alpha<C, T>
+            where
+                C: Normalize<T, $color_channels>,
+                T: FloatComponent,
+            {
+                fn component_ranges() -> [(T, T); $channels] {
+                    let mut ranges = [(T::zero(), T::max_intensity()); $channels];
+                    ranges[..$color_channels].copy_from_slice(&C::component_ranges());
+                    ranges
+                }
+
+                fn normalize(self) -> [T; $channels] {
+                    let mut values = [self.alpha; $channels];
+                    values[..$color_channels].copy_from_slice(&self.color.normalize());
+                    values
+                }
+
+                fn denormalize(normalized: [T; $channels]) -> Self {
+                    let mut color = [T::zero(); $color_channels];
+                    color.copy_from_slice(&normalized[..$color_channels]);
+                    Alpha {
+                        color: C::denormalize(color),
+                        alpha: normalized[$color_channels],
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_normalize_alpha!(1 => 2, 3 => 4);
+
+macro_rules! impl_ranges {
+    (
+        $self_ty: ident < $param: ident : $param_bound: ident >,
+        [$($element: ident: $name: ident = $min: literal ..= $max: literal),+]
+    ) => {
+        impl_ranges!(@impl f32, $self_ty<$param: $param_bound>, [$($element: $name = $min..=$max),+]);
+        impl_ranges!(@impl f64, $self_ty<$param: $param_bound>, [$($element: $name = $min..=$max),+]);
+    };
+    (
+        @impl $float: ident,
+        $self_ty: ident < $param: ident : $param_bound: ident >,
+        [$($element: ident: $name: ident = $min: literal ..= $max: literal),+]
+    ) => {
+        impl<$param: $param_bound> $self_ty<$param, $float> {
+            $(
+                #[doc = concat!("The typical range of `", stringify!($element), "`.")]
+                pub const $name: RangeInclusive<$float> = $min..=$max;
+            )+
+        }
+    };
+}
+
+impl_ranges!(
+    Rgb<S: RgbStandard>,
+    [
+        red: RED_RANGE = 0.0..=1.0,
+        green: GREEN_RANGE = 0.0..=1.0,
+        blue: BLUE_RANGE = 0.0..=1.0
+    ]
+);
+impl_ranges!(Luma<S: LumaStandard>, [luma: LUMA_RANGE = 0.0..=1.0]);
+impl_ranges!(
+    Hsl<S: RgbStandard>,
+    [
+        hue: HUE_RANGE = 0.0..=360.0,
+        saturation: SATURATION_RANGE = 0.0..=1.0,
+        lightness: LIGHTNESS_RANGE = 0.0..=1.0
+    ]
+);
+impl_ranges!(
+    Hsv<S: RgbStandard>,
+    [
+        hue: HUE_RANGE = 0.0..=360.0,
+        saturation: SATURATION_RANGE = 0.0..=1.0,
+        value: VALUE_RANGE = 0.0..=1.0
+    ]
+);
+impl_ranges!(
+    Hwb<S: RgbStandard>,
+    [
+        hue: HUE_RANGE = 0.0..=360.0,
+        whiteness: WHITENESS_RANGE = 0.0..=1.0,
+        blackness: BLACKNESS_RANGE = 0.0..=1.0
+    ]
+);
+impl_ranges!(
+    Lab<Wp: WhitePoint>,
+    [
+        l: L_RANGE = 0.0..=100.0,
+        a: A_RANGE = -128.0..=127.0,
+        b: B_RANGE = -128.0..=127.0
+    ]
+);
+impl_ranges!(
+    Lch<Wp: WhitePoint>,
+    [
+        l: L_RANGE = 0.0..=100.0,
+        chroma: CHROMA_RANGE = 0.0..=128.0,
+        hue: HUE_RANGE = 0.0..=360.0
+    ]
+);
+impl_ranges!(
+    Luv<Wp: WhitePoint>,
+    [
+        l: L_RANGE = 0.0..=100.0,
+        u: U_RANGE = -84.0..=176.0,
+        v: V_RANGE = -135.0..=108.0
+    ]
+);
+impl_ranges!(
+    Lchuv<Wp: WhitePoint>,
+    [
+        l: L_RANGE = 0.0..=100.0,
+        chroma: CHROMA_RANGE = 0.0..=180.0,
+        hue: HUE_RANGE = 0.0..=360.0
+    ]
+);
+impl_ranges!(
+    Hsluv<Wp: WhitePoint>,
+    [
+        hue: HUE_RANGE = 0.0..=360.0,
+        saturation: SATURATION_RANGE = 0.0..=100.0,
+        l: L_RANGE = 0.0..=100.0
+    ]
+);
+impl_ranges!(
+    Yxy<Wp: WhitePoint>,
+    [
+        x: X_RANGE = 0.0..=1.0,
+        y: Y_RANGE = 0.0..=1.0,
+        luma: LUMA_RANGE = 0.0..=1.0
+    ]
+);
+
+#[cfg(test)]
+mod test {
+    use super::Normalize;
+    use crate::white_point::D65;
+    use crate::{Hsl, Lch, LinSrgba, Luv, Srgb, SrgbLuma, WithAlpha, Xyz};
+
+    fn assert_ranges<C, const N: usize>(ranges: [core::ops::RangeInclusive<f64>; N])
+    where
+        C: Normalize<f64, N>,
+    {
+        for (range, (min, max)) in ranges.iter().zip(&C::component_ranges()) {
+            assert_eq!(range.start(), min);
+            assert_eq!(range.end(), max);
+        }
+    }
+
+    #[test]
+    fn constants_match_ranges() {
+        assert_ranges::<Srgb<f64>, 3>([
+            Srgb::<f64>::RED_RANGE,
+            Srgb::<f64>::GREEN_RANGE,
+            Srgb::<f64>::BLUE_RANGE,
+        ]);
+        assert_ranges::<SrgbLuma<f64>, 1>([SrgbLuma::<f64>::LUMA_RANGE]);
+        assert_ranges::<Lch<D65, f64>, 3>([
+            Lch::<D65, f64>::L_RANGE,
+            Lch::<D65, f64>::CHROMA_RANGE,
+            Lch::<D65, f64>::HUE_RANGE,
+        ]);
+        assert_ranges::<Luv<D65, f64>, 3>([
+            Luv::<D65, f64>::L_RANGE,
+            Luv::<D65, f64>::U_RANGE,
+            Luv::<D65, f64>::V_RANGE,
+        ]);
+    }
+
+    #[test]
+    fn hue() {
+        let hsl = Hsl::new(-90.0f64, 0.25, 1.0);
+        assert_eq!(hsl.normalize(), [0.75, 0.25, 1.0]);
+        assert_relative_eq!(Hsl::denormalize([0.75, 0.25, 1.0]), hsl);
+    }
+
+    #[test]
+    fn white_point() {
+        let xyz = Xyz::<D65, f64>::new(0.95047, 1.0, 1.08883);
+        assert_relative_eq!(xyz.normalize()[..], [1.0, 1.0, 1.0][..]);
+    }
+
+    #[test]
+    fn alpha() {
+        let color = LinSrgba::new(0.25f64, 0.5, 0.75, 0.1);
+        assert_eq!(color.normalize(), [0.25, 0.5, 0.75, 0.1]);
+        assert_eq!(LinSrgba::denormalize([0.25, 0.5, 0.75, 0.1]), color);
+
+        let lch = Lch::<D65, f64>::new(50.0, 64.0, 90.0).with_alpha(0.5);
+        assert_eq!(lch.normalize(), [0.5, 0.5, 0.25, 0.5]);
+    }
+}
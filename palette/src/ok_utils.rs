@@ -0,0 +1,282 @@
+//! The sRGB gamut calculations that Okhsv and Okhsl are built on. They are
+//! from Björn Ottosson's reference implementation, which has more details:
+//! https://bottosson.github.io/posts/colorpicker/
+
+use crate::math::cbrt;
+use crate::{from_f64, FloatComponent};
+
+/// The lightness and chroma of a point in Oklab.
+#[derive(Clone, Copy)]
+pub(crate) struct LC<T> {
+    pub l: T,
+    pub c: T,
+}
+
+/// The saturation `S = C / L` and `T = C / (1 - L)` of a point in Oklab.
+#[derive(Clone, Copy)]
+pub(crate) struct ST<T> {
+    pub s: T,
+    pub t: T,
+}
+
+/// The chroma values that the saturation of Okhsl is interpolated between.
+pub(crate) struct ChromaValues<T> {
+    pub zero: T,
+    pub mid: T,
+    pub max: T,
+}
+
+/// The Oklab to LMS coefficients for `a` and `b`, with `L = 1`.
+fn lms_coefficients<T: FloatComponent>(a: T, b: T) -> [T; 3] {
+    [
+        from_f64::<T>(0.3963377774) * a + from_f64::<T>(0.2158037573) * b,
+        from_f64::<T>(-0.1055613458) * a - from_f64::<T>(0.0638541728) * b,
+        from_f64::<T>(-0.0894841775) * a - from_f64::<T>(1.2914855480) * b,
+    ]
+}
+
+/// The rows of the LMS to linear sRGB matrix.
+const LMS_TO_LINEAR_SRGB: [[f64; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+/// Convert Oklab to linear sRGB.
+pub(crate) fn oklab_to_linear_srgb<T: FloatComponent>(l: T, a: T, b: T) -> [T; 3] {
+    let [k_l, k_m, k_s] = lms_coefficients(a, b);
+    let l_ = l + k_l;
+    let m_ = l + k_m;
+    let s_ = l + k_s;
+    let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
+
+    let mut rgb = [T::zero(); 3];
+    for (component, row) in rgb.iter_mut().zip(&LMS_TO_LINEAR_SRGB) {
+        *component = from_f64::<T>(row[0]) * lms[0]
+            + from_f64::<T>(row[1]) * lms[1]
+            + from_f64::<T>(row[2]) * lms[2];
+    }
+
+    rgb
+}
+
+/// Find the largest saturation, `S = C / L`, for the hue `(a, b)` that fits
+/// in sRGB. `a` and `b` must be normalized so that `a² + b² = 1`.
+fn max_saturation<T: FloatComponent>(a: T, b: T) -> T {
+    // The saturation is the largest where one of the RGB components goes
+    // below zero. The coefficients depend on which one does it first.
+    let (k, row) = if from_f64::<T>(-1.88170328) * a - from_f64::<T>(0.80936493) * b > T::one() {
+        (
+            [1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245],
+            LMS_TO_LINEAR_SRGB[0],
+        )
+    } else if from_f64::<T>(1.81444104) * a - from_f64::<T>(1.19445276) * b > T::one() {
+        (
+            [0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204],
+            LMS_TO_LINEAR_SRGB[1],
+        )
+    } else {
+        (
+            [
+                1.35733652,
+                -0.00915799,
+                -1.15130210,
+                -0.50559606,
+                0.00692167,
+            ],
+            LMS_TO_LINEAR_SRGB[2],
+        )
+    };
+
+    // A polynomial approximation...
+    let mut saturation = from_f64::<T>(k[0])
+        + from_f64::<T>(k[1]) * a
+        + from_f64::<T>(k[2]) * b
+        + from_f64::<T>(k[3]) * a * a
+        + from_f64::<T>(k[4]) * a * b;
+
+    // ...that is refined with one step of Halley's method.
+    let coefficients = lms_coefficients(a, b);
+    let (mut f, mut f1, mut f2) = (T::zero(), T::zero(), T::zero());
+    for (&k, &weight) in coefficients.iter().zip(&row) {
+        let weight = from_f64::<T>(weight);
+        let x = T::one() + saturation * k;
+
+        f = f + weight * x * x * x;
+        f1 = f1 + weight * from_f64::<T>(3.0) * k * x * x;
+        f2 = f2 + weight * from_f64::<T>(6.0) * k * k * x;
+    }
+
+    saturation = saturation - f * f1 / (f1 * f1 - from_f64::<T>(0.5) * f * f2);
+    saturation
+}
+
+/// Find the lightness and chroma of the cusp of the sRGB gamut for the hue
+/// `(a, b)`. `a` and `b` must be normalized so that `a² + b² = 1`.
+pub(crate) fn find_cusp<T: FloatComponent>(a: T, b: T) -> LC<T> {
+    let saturation = max_saturation(a, b);
+
+    // The color with the largest saturation, scaled so that its largest
+    // component is 1.
+    let [red, green, blue] = oklab_to_linear_srgb(T::one(), saturation * a, saturation * b);
+    let l = cbrt(T::one() / red.max(green).max(blue));
+
+    LC {
+        l,
+        c: l * saturation,
+    }
+}
+
+/// Find where the line from `(l0, 0)` to `(l1, c1)` leaves the sRGB gamut for
+/// the hue `(a, b)`, as a fraction `t` of the line. `a` and `b` must be
+/// normalized so that `a² + b² = 1`.
+pub(crate) fn find_gamut_intersection<T: FloatComponent>(
+    a: T,
+    b: T,
+    l1: T,
+    c1: T,
+    l0: T,
+    cusp: LC<T>,
+) -> T {
+    if (l1 - l0) * cusp.c - (cusp.l - l0) * c1 <= T::zero() {
+        // The lower half of the gamut is a straight line towards black.
+        return cusp.c * l0 / (c1 * cusp.l + cusp.c * (l0 - l1));
+    }
+
+    // The upper half is first approximated as a straight line towards
+    // white...
+    let mut t = cusp.c * (l0 - T::one()) / (c1 * (cusp.l - T::one()) + cusp.c * (l0 - l1));
+
+    // ...and refined with one step of Halley's method.
+    let coefficients = lms_coefficients(a, b);
+    let dl = l1 - l0;
+    let dc = c1;
+
+    let l = l0 * (T::one() - t) + t * l1;
+    let c = t * c1;
+
+    let mut lms = [T::zero(); 3];
+    let mut lms_dt = [T::zero(); 3];
+    let mut lms_dt2 = [T::zero(); 3];
+    for (index, &k) in coefficients.iter().enumerate() {
+        let x = l + c * k;
+        let x_dt = dl + dc * k;
+
+        lms[index] = x * x * x;
+        lms_dt[index] = from_f64::<T>(3.0) * x_dt * x * x;
+        lms_dt2[index] = from_f64::<T>(6.0) * x_dt * x_dt * x;
+    }
+
+    let mut step = T::infinity();
+    for row in &LMS_TO_LINEAR_SRGB {
+        let apply = |lms: &[T; 3]| {
+            from_f64::<T>(row[0]) * lms[0]
+                + from_f64::<T>(row[1]) * lms[1]
+                + from_f64::<T>(row[2]) * lms[2]
+        };
+
+        let value = apply(&lms) - T::one();
+        let derivative = apply(&lms_dt);
+        let derivative2 = apply(&lms_dt2);
+
+        let u = derivative / (derivative * derivative - from_f64::<T>(0.5) * value * derivative2);
+        if u >= T::zero() {
+            step = step.min(-value * u);
+        }
+    }
+
+    if step.is_finite() {
+        t = t + step;
+    }
+
+    t
+}
+
+/// The `S` and `T` values of the cusp.
+pub(crate) fn to_st<T: FloatComponent>(cusp: LC<T>) -> ST<T> {
+    ST {
+        s: cusp.c / cusp.l,
+        t: cusp.c / (T::one() - cusp.l),
+    }
+}
+
+/// A smooth approximation of the `S` and `T` values of the cusp, which are
+/// smaller than the actual values.
+fn st_mid<T: FloatComponent>(a: T, b: T) -> ST<T> {
+    let c = |value: f64| from_f64::<T>(value);
+
+    let s = c(0.11516993)
+        + T::one()
+            / (c(7.44778970)
+                + c(4.15901240) * b
+                + a * (c(-2.19557347)
+                    + c(1.75198401) * b
+                    + a * (c(-2.13704948) - c(10.02301043) * b
+                        + a * (c(-4.24894561) + c(5.38770819) * b + c(4.69891013) * a))));
+
+    let t = c(0.11239642)
+        + T::one()
+            / (c(1.61320320) - c(0.68124379) * b
+                + a * (c(0.40370612)
+                    + c(0.90148123) * b
+                    + a * (c(-0.27087943)
+                        + c(0.61223990) * b
+                        + a * (c(0.00299215) - c(0.45399568) * b - c(0.14661872) * a))));
+
+    ST { s, t }
+}
+
+/// The chroma values that the saturation of Okhsl is interpolated between,
+/// for the lightness `l` and the hue `(a, b)`. `l` must be between `0` and
+/// `1`, exclusive.
+pub(crate) fn chroma_values<T: FloatComponent>(l: T, a: T, b: T) -> ChromaValues<T> {
+    let cusp = find_cusp(a, b);
+    let max = find_gamut_intersection(a, b, l, T::one(), l, cusp);
+    let st_max = to_st(cusp);
+
+    // Compensates for the curved part of the gamut.
+    let k = max / (l * st_max.s).min((T::one() - l) * st_max.t);
+
+    // A soft minimum of the lines towards black and white, instead of a
+    // sharp triangle, gives a smooth chroma.
+    let mid = {
+        let st_mid = st_mid(a, b);
+        let c_a = l * st_mid.s;
+        let c_b = (T::one() - l) * st_mid.t;
+        let soft_min =
+            T::one() / (T::one() / (c_a * c_a * c_a * c_a) + T::one() / (c_b * c_b * c_b * c_b));
+
+        from_f64::<T>(0.9) * k * soft_min.sqrt().sqrt()
+    };
+
+    // The shape is the same for all hues here, with roughly the average `S`
+    // and `T` values.
+    let zero = {
+        let c_a = l * from_f64(0.4);
+        let c_b = (T::one() - l) * from_f64(0.8);
+
+        (T::one() / (T::one() / (c_a * c_a) + T::one() / (c_b * c_b))).sqrt()
+    };
+
+    ChromaValues { zero, mid, max }
+}
+
+/// The toe function that makes the lightness of Okhsv and Okhsl closer to
+/// L\* in CIE L\*a\*b\*.
+pub(crate) fn toe<T: FloatComponent>(x: T) -> T {
+    let k_1: T = from_f64(0.206);
+    let k_2: T = from_f64(0.03);
+    let k_3 = (T::one() + k_1) / (T::one() + k_2);
+    let y = k_3 * x - k_1;
+
+    from_f64::<T>(0.5) * (y + (y * y + from_f64::<T>(4.0) * k_2 * k_3 * x).sqrt())
+}
+
+/// The inverse of [`toe`](fn.toe.html).
+pub(crate) fn toe_inv<T: FloatComponent>(x: T) -> T {
+    let k_1: T = from_f64(0.206);
+    let k_2: T = from_f64(0.03);
+    let k_3 = (T::one() + k_1) / (T::one() + k_2);
+
+    (x * x + k_1 * x) / (k_3 * (x + k_2))
+}
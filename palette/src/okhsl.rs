@@ -0,0 +1,624 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::float::Float;
+use crate::hues::mix_hues;
+use crate::math::{atan2, sin_cos};
+use crate::ok_utils::{chroma_values, toe, toe_inv};
+use crate::white_point::D65;
+use crate::{
+    clamp, from_f64, Alpha, Chroma, Clamp, Component, FloatComponent, FromF64, GetHue, Hue, Mix,
+    Oklab, OklabHue, Pixel, Saturate, Shade, Xyz,
+};
+
+/// Okhsl with an alpha component. See the [`Okhsla` implementation in
+/// `Alpha`](crate::Alpha#Okhsla).
+pub type Okhsla<T = f32> = Alpha<Okhsl<T>, T>;
+
+/// The Okhsl color space.
+///
+/// Okhsl is an HSL-like color space by Björn Ottosson, built on
+/// [`Oklab`](crate::Oklab) and shaped after the sRGB gamut. Like
+/// [`Hsl`](crate::Hsl), every combination of saturation and lightness from
+/// 0.0 to 1.0 is a color within sRGB, or slightly outside near the
+/// approximated edge of the gamut. Unlike `Hsl`, the hue is perceptually
+/// uniform, and the lightness is close to the perceived lightness, so colors
+/// with the same lightness look about as light, regardless of hue and
+/// saturation. The saturation is less uniform, since it's stretched to fill
+/// the gamut.
+///
+/// ```
+/// use palette::{FromColor, Okhsl, Srgb};
+///
+/// let gray = Okhsl::from_color(Srgb::new(0.5f64, 0.5, 0.5).into_linear());
+/// assert!(gray.saturation.abs() < 1e-4);
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Okhsl<T = f32>
+where
+    T: FloatComponent,
+{
+    /// The hue of the color, in degrees. It's the same as the hue of
+    /// [`Oklab`](crate::Oklab).
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: OklabHue<T>,
+
+    /// The colorfulness of the color. 0.0 gives gray scale colors and 1.0
+    /// gives the most saturated color in sRGB for the hue and lightness.
+    pub saturation: T,
+
+    /// The perceived lightness of the color. 0.0 gives black and 1.0 gives
+    /// white.
+    pub lightness: T,
+}
+
+impl<T> Copy for Okhsl<T> where T: FloatComponent {}
+
+impl<T> Clone for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn clone(&self) -> Okhsl<T> {
+        *self
+    }
+}
+
+impl<T> Okhsl<T>
+where
+    T: FloatComponent,
+{
+    /// Okhsl.
+    pub fn new<H: Into<OklabHue<T>>>(hue: H, saturation: T, lightness: T) -> Okhsl<T> {
+        Okhsl {
+            hue: hue.into(),
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Convert to a `(hue, saturation, lightness)` tuple.
+    pub fn into_components(self) -> (OklabHue<T>, T, T) {
+        (self.hue, self.saturation, self.lightness)
+    }
+
+    /// Convert from a `(hue, saturation, lightness)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>((hue, saturation, lightness): (H, T, T)) -> Self {
+        Self::new(hue, saturation, lightness)
+    }
+
+    /// Return the `saturation` value minimum.
+    pub fn min_saturation() -> T {
+        T::zero()
+    }
+
+    /// Return the `saturation` value maximum.
+    pub fn max_saturation() -> T {
+        T::one()
+    }
+
+    /// Return the `lightness` value minimum.
+    pub fn min_lightness() -> T {
+        T::zero()
+    }
+
+    /// Return the `lightness` value maximum.
+    pub fn max_lightness() -> T {
+        T::one()
+    }
+}
+
+impl<T> PartialEq for Okhsl<T>
+where
+    T: FloatComponent + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hue == other.hue
+            && self.saturation == other.saturation
+            && self.lightness == other.lightness
+    }
+}
+
+impl<T> Eq for Okhsl<T> where T: FloatComponent + Eq {}
+
+///<span id="Okhsla"></span>[`Okhsla`](crate::Okhsla) implementations.
+impl<T, A> Alpha<Okhsl<T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// Okhsl and transparency.
+    pub fn new<H: Into<OklabHue<T>>>(hue: H, saturation: T, lightness: T, alpha: A) -> Self {
+        Alpha {
+            color: Okhsl::new(hue, saturation, lightness),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(hue, saturation, lightness, alpha)` tuple.
+    pub fn into_components(self) -> (OklabHue<T>, T, T, A) {
+        (self.hue, self.saturation, self.lightness, self.alpha)
+    }
+
+    /// Convert from a `(hue, saturation, lightness, alpha)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>(
+        (hue, saturation, lightness, alpha): (H, T, T, A),
+    ) -> Self {
+        Self::new(hue, saturation, lightness, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsl<T>> for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsl<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsl<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsl<T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        let chroma = (color.a * color.a + color.b * color.b).sqrt();
+        let lightness = toe(color.l);
+
+        if chroma <= T::zero() || color.l <= T::zero() || color.l >= T::one() {
+            let hue = if chroma > T::zero() {
+                atan2(color.b, color.a)
+            } else {
+                T::zero()
+            };
+            return Okhsl::new(OklabHue::from_radians(hue), T::zero(), lightness);
+        }
+
+        let (a, b) = (color.a / chroma, color.b / chroma);
+        let chroma_values = chroma_values(color.l, a, b);
+
+        // The inverse of the interpolation from saturation to chroma.
+        let mid = from_f64::<T>(0.8);
+        let mid_inv = from_f64::<T>(1.25);
+
+        let saturation = if chroma < chroma_values.mid {
+            let k_1 = mid * chroma_values.zero;
+            let k_2 = T::one() - k_1 / chroma_values.mid;
+
+            let t = chroma / (k_1 + k_2 * chroma);
+            t * mid
+        } else {
+            let k_0 = chroma_values.mid;
+            let k_1 = (T::one() - mid) * chroma_values.mid * chroma_values.mid * mid_inv * mid_inv
+                / chroma_values.zero;
+            let k_2 = T::one() - k_1 / (chroma_values.max - chroma_values.mid);
+
+            let t = (chroma - k_0) / (k_1 + k_2 * (chroma - k_0));
+            mid + (T::one() - mid) * t
+        };
+
+        Okhsl::new(
+            OklabHue::from_radians(atan2(color.b, color.a)),
+            saturation,
+            lightness,
+        )
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsl<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsl<T>) -> Self {
+        if color.lightness >= T::one() {
+            return Oklab::new(T::one(), T::zero(), T::zero());
+        } else if color.lightness <= T::zero() {
+            return Oklab::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let (b, a) = sin_cos(color.hue.to_radians());
+        let l = toe_inv(color.lightness);
+        let chroma_values = chroma_values(l, a, b);
+
+        // The saturation is interpolated between zero, a middle chroma at 0.8
+        // and the edge of the gamut at 1.0, with smooth transitions.
+        let mid = from_f64::<T>(0.8);
+        let mid_inv = from_f64::<T>(1.25);
+        let saturation = color.saturation;
+
+        let chroma = if saturation < mid {
+            let t = mid_inv * saturation;
+
+            let k_1 = mid * chroma_values.zero;
+            let k_2 = T::one() - k_1 / chroma_values.mid;
+
+            t * k_1 / (T::one() - k_2 * t)
+        } else {
+            let t = (saturation - mid) / (T::one() - mid);
+
+            let k_0 = chroma_values.mid;
+            let k_1 = (T::one() - mid) * chroma_values.mid * chroma_values.mid * mid_inv * mid_inv
+                / chroma_values.zero;
+            let k_2 = T::one() - k_1 / (chroma_values.max - chroma_values.mid);
+
+            k_0 + t * k_1 / (T::one() - k_2 * t)
+        };
+
+        Oklab::new(l, chroma * a, chroma * b)
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>> From<(H, T, T)> for Okhsl<T> {
+    fn from(components: (H, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent> Into<(OklabHue<T>, T, T)> for Okhsl<T> {
+    fn into(self) -> (OklabHue<T>, T, T) {
+        self.into_components()
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>, A: Component> From<(H, T, T, A)>
+    for Alpha<Okhsl<T>, A>
+{
+    fn from(components: (H, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent, A: Component> Into<(OklabHue<T>, T, T, A)> for Alpha<Okhsl<T>, A> {
+    fn into(self) -> (OklabHue<T>, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<T> Clamp for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    fn is_within_bounds(&self) -> bool {
+        self.saturation >= T::zero() && self.saturation <= T::one() &&
+        self.lightness >= T::zero() && self.lightness <= T::one()
+    }
+
+    fn clamp(&self) -> Okhsl<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.saturation = clamp(self.saturation, T::zero(), T::one());
+        self.lightness = clamp(self.lightness, T::zero(), T::one());
+    }
+}
+
+impl<T> Mix for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Okhsl<T>, factor: T) -> Okhsl<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
+
+        Okhsl {
+            hue: hue + factor * hue_diff,
+            saturation: self.saturation + factor * (other.saturation - self.saturation),
+            lightness: self.lightness + factor * (other.lightness - self.lightness),
+        }
+    }
+}
+
+impl<T> Shade for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Okhsl<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.lightness
+        } else {
+            self.lightness
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Okhsl {
+            hue: self.hue,
+            saturation: self.saturation,
+            lightness: (self.lightness + delta).max(T::zero()),
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Okhsl<T> {
+        Okhsl {
+            hue: self.hue,
+            saturation: self.saturation,
+            lightness: (self.lightness + amount).max(T::zero()),
+        }
+    }
+}
+
+impl<T> GetHue for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    type Hue = OklabHue<T>;
+
+    fn get_hue(&self) -> Option<OklabHue<T>> {
+        if self.saturation <= T::zero() || self.lightness <= T::zero() || self.lightness >= T::one()
+        {
+            None
+        } else {
+            Some(self.hue)
+        }
+    }
+}
+
+impl<T> Hue for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn with_hue<H: Into<Self::Hue>>(&self, hue: H) -> Okhsl<T> {
+        Okhsl {
+            hue: hue.into(),
+            saturation: self.saturation,
+            lightness: self.lightness,
+        }
+    }
+
+    fn shift_hue<H: Into<Self::Hue>>(&self, amount: H) -> Okhsl<T> {
+        Okhsl {
+            hue: self.hue + amount.into(),
+            saturation: self.saturation,
+            lightness: self.lightness,
+        }
+    }
+}
+
+impl<T> Saturate for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn saturate(&self, factor: T) -> Okhsl<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.saturation
+        } else {
+            self.saturation
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Okhsl {
+            hue: self.hue,
+            saturation: (self.saturation + delta).max(T::zero()),
+            lightness: self.lightness,
+        }
+    }
+
+    fn saturate_fixed(&self, amount: T) -> Okhsl<T> {
+        Okhsl {
+            hue: self.hue,
+            saturation: (self.saturation + amount).max(T::zero()),
+            lightness: self.lightness,
+        }
+    }
+}
+
+impl<T> Chroma for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.saturation
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.saturation = chroma;
+    }
+}
+
+impl<T> Default for Okhsl<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Okhsl<T> {
+        Okhsl::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T, P> AsRef<P> for Okhsl<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<T, P> AsMut<P> for Okhsl<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+impl<T> AbsDiffEq for Okhsl<T>
+where
+    T: FloatComponent + AbsDiffEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.hue.abs_diff_eq(&other.hue, epsilon)
+            && self.saturation.abs_diff_eq(&other.saturation, epsilon)
+            && self.lightness.abs_diff_eq(&other.lightness, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Okhsl<T>
+where
+    T: FloatComponent + RelativeEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.hue.relative_eq(&other.hue, epsilon, max_relative) &&
+            self.saturation.relative_eq(&other.saturation, epsilon, max_relative) &&
+            self.lightness.relative_eq(&other.lightness, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Okhsl<T>
+where
+    T: FloatComponent + UlpsEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.hue.ulps_eq(&other.hue, epsilon, max_ulps) &&
+            self.saturation.ulps_eq(&other.saturation, epsilon, max_ulps) &&
+            self.lightness.ulps_eq(&other.lightness, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Okhsl<T> where T: FloatComponent + bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Okhsl<T> where T: FloatComponent + bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Okhsl;
+    use crate::convert::FromColorUnclamped;
+    use crate::{Clamp, Lab, LinSrgb, Srgb};
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.9, 0.9, 0.1),
+            (0.5, 0.5, 0.5),
+            (0.01, 0.02, 0.0),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let okhsl = Okhsl::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(okhsl), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn gamut_edges() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 1.0),
+            (1.0, 0.0, 1.0),
+        ] {
+            let okhsl = Okhsl::from_color_unclamped(LinSrgb::<f64>::new(r, g, b));
+            assert_relative_eq!(okhsl.saturation, 1.0, epsilon = 1e-3);
+        }
+
+        let white = Okhsl::from_color_unclamped(LinSrgb::<f64>::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.saturation, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(white.lightness, 1.0, epsilon = 1e-4);
+
+        let black = Okhsl::from_color_unclamped(LinSrgb::<f64>::new(0.0, 0.0, 0.0));
+        assert_eq!(black, Okhsl::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn within_srgb() {
+        for hue in (0..360).step_by(15) {
+            for &saturation in &[0.0, 0.3, 0.7, 1.0] {
+                for &lightness in &[0.1, 0.5, 0.9] {
+                    let okhsl = Okhsl::new(f64::from(hue), saturation, lightness);
+                    assert!(okhsl.is_within_bounds());
+
+                    let color = Srgb::from_linear(LinSrgb::from_color_unclamped(okhsl));
+                    for &component in &[color.red, color.green, color.blue] {
+                        assert!(component > -2e-3 && component < 1.0 + 2e-3, "{:?}", okhsl);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lightness_is_close_to_lab() {
+        // The toe makes the lightness close to L*, for all hues.
+        for &(r, g, b) in &[(1.0, 0.0, 0.0), (0.0, 0.0, 1.0), (0.3, 0.3, 0.3)] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let okhsl = Okhsl::from_color_unclamped(color);
+            let lab = Lab::from_color_unclamped(color);
+
+            assert!((okhsl.lightness * 100.0 - lab.l).abs() < 10.0);
+        }
+    }
+}
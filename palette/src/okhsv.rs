@@ -0,0 +1,613 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::float::Float;
+use crate::hues::mix_hues;
+use crate::math::{atan2, cbrt, sin_cos};
+use crate::ok_utils::{find_cusp, oklab_to_linear_srgb, to_st, toe, toe_inv};
+use crate::white_point::D65;
+use crate::{
+    clamp, from_f64, Alpha, Chroma, Clamp, Component, FloatComponent, FromF64, GetHue, Hue, Mix,
+    Oklab, OklabHue, Pixel, Saturate, Shade, Xyz,
+};
+
+/// Okhsv with an alpha component. See the [`Okhsva` implementation in
+/// `Alpha`](crate::Alpha#Okhsva).
+pub type Okhsva<T = f32> = Alpha<Okhsv<T>, T>;
+
+/// The Okhsv color space.
+///
+/// Okhsv is an HSV-like color space by Björn Ottosson, built on
+/// [`Oklab`](crate::Oklab) and shaped after the sRGB gamut. Like
+/// [`Hsv`](crate::Hsv), every combination of saturation and value from 0.0 to
+/// 1.0 is a color within sRGB, and a saturation of 1.0 is the edge of the
+/// gamut. The edge is approximated, so colors close to it may be slightly
+/// outside. Unlike `Hsv`, the hue is perceptually uniform, and the value is
+/// closer to the perceived lightness. This makes it a good choice for color
+/// pickers, where the user picks a hue first and then the saturation and
+/// value in a square.
+///
+/// ```
+/// use palette::{FromColor, Okhsv, Srgb};
+///
+/// let red = Okhsv::from_color(Srgb::new(1.0f64, 0.0, 0.0).into_linear());
+/// assert!((red.saturation - 1.0).abs() < 1e-3);
+/// assert!((red.value - 1.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Okhsv<T = f32>
+where
+    T: FloatComponent,
+{
+    /// The hue of the color, in degrees. It's the same as the hue of
+    /// [`Oklab`](crate::Oklab).
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: OklabHue<T>,
+
+    /// The colorfulness of the color. 0.0 gives gray scale colors and 1.0
+    /// gives the most saturated color in sRGB for the hue and value.
+    pub saturation: T,
+
+    /// The brightness of the color. 0.0 gives black, and 1.0 gives the
+    /// brightest color in sRGB for the hue and saturation.
+    pub value: T,
+}
+
+impl<T> Copy for Okhsv<T> where T: FloatComponent {}
+
+impl<T> Clone for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn clone(&self) -> Okhsv<T> {
+        *self
+    }
+}
+
+impl<T> Okhsv<T>
+where
+    T: FloatComponent,
+{
+    /// Okhsv.
+    pub fn new<H: Into<OklabHue<T>>>(hue: H, saturation: T, value: T) -> Okhsv<T> {
+        Okhsv {
+            hue: hue.into(),
+            saturation,
+            value,
+        }
+    }
+
+    /// Convert to a `(hue, saturation, value)` tuple.
+    pub fn into_components(self) -> (OklabHue<T>, T, T) {
+        (self.hue, self.saturation, self.value)
+    }
+
+    /// Convert from a `(hue, saturation, value)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>((hue, saturation, value): (H, T, T)) -> Self {
+        Self::new(hue, saturation, value)
+    }
+
+    /// Return the `saturation` value minimum.
+    pub fn min_saturation() -> T {
+        T::zero()
+    }
+
+    /// Return the `saturation` value maximum.
+    pub fn max_saturation() -> T {
+        T::one()
+    }
+
+    /// Return the `value` value minimum.
+    pub fn min_value() -> T {
+        T::zero()
+    }
+
+    /// Return the `value` value maximum.
+    pub fn max_value() -> T {
+        T::one()
+    }
+}
+
+impl<T> PartialEq for Okhsv<T>
+where
+    T: FloatComponent + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hue == other.hue && self.saturation == other.saturation && self.value == other.value
+    }
+}
+
+impl<T> Eq for Okhsv<T> where T: FloatComponent + Eq {}
+
+///<span id="Okhsva"></span>[`Okhsva`](crate::Okhsva) implementations.
+impl<T, A> Alpha<Okhsv<T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// Okhsv and transparency.
+    pub fn new<H: Into<OklabHue<T>>>(hue: H, saturation: T, value: T, alpha: A) -> Self {
+        Alpha {
+            color: Okhsv::new(hue, saturation, value),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(hue, saturation, value, alpha)` tuple.
+    pub fn into_components(self) -> (OklabHue<T>, T, T, A) {
+        (self.hue, self.saturation, self.value, self.alpha)
+    }
+
+    /// Convert from a `(hue, saturation, value, alpha)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>(
+        (hue, saturation, value, alpha): (H, T, T, A),
+    ) -> Self {
+        Self::new(hue, saturation, value, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsv<T>> for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsv<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsv<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsv<T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        if color.l <= T::zero() {
+            return Okhsv::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let chroma = (color.a * color.a + color.b * color.b).sqrt();
+        let (hue, a, b) = if chroma > T::zero() {
+            (atan2(color.b, color.a), color.a / chroma, color.b / chroma)
+        } else {
+            (T::zero(), T::one(), T::zero())
+        };
+
+        let st_max = to_st(find_cusp(a, b));
+        let s_0 = from_f64::<T>(0.5);
+        let k = T::one() - s_0 / st_max.s;
+
+        // The lightness and chroma at the top of the triangle, where the
+        // value would be 1.0.
+        let t = st_max.t / (chroma + color.l * st_max.t);
+        let l_v = t * color.l;
+        let c_v = t * chroma;
+
+        // Undo the compensation for the toe and the curved top of the gamut.
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+        let [red, green, blue] = oklab_to_linear_srgb(l_vt, a * c_vt, b * c_vt);
+        let scale_l = cbrt(T::one() / red.max(green).max(blue).max(T::zero()));
+
+        let l = color.l / scale_l;
+        let l_toe = toe(l);
+
+        let value = l_toe / l_v;
+        let saturation = (s_0 + st_max.t) * c_v / (st_max.t * s_0 + st_max.t * k * c_v);
+
+        Okhsv::new(OklabHue::from_radians(hue), saturation, value)
+    }
+}
+
+impl<T> FromColorUnclamped<Okhsv<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Okhsv<T>) -> Self {
+        if color.value <= T::zero() {
+            return Oklab::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let (b, a) = sin_cos(color.hue.to_radians());
+        let st_max = to_st(find_cusp(a, b));
+        let s_0 = from_f64::<T>(0.5);
+        let k = T::one() - s_0 / st_max.s;
+        let saturation = color.saturation;
+
+        // The lightness and chroma as if the gamut was a triangle.
+        let denominator = s_0 + st_max.t - st_max.t * k * saturation;
+        let l_v = T::one() - saturation * s_0 / denominator;
+        let c_v = saturation * st_max.t * s_0 / denominator;
+
+        let l = color.value * l_v;
+        let chroma = color.value * c_v;
+
+        // Compensate for the toe and the curved top of the gamut.
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+
+        let l_new = toe_inv(l);
+        let chroma = chroma * l_new / l;
+
+        let [red, green, blue] = oklab_to_linear_srgb(l_vt, a * c_vt, b * c_vt);
+        let scale_l = cbrt(T::one() / red.max(green).max(blue).max(T::zero()));
+
+        let l = l_new * scale_l;
+        let chroma = chroma * scale_l;
+
+        Oklab::new(l, chroma * a, chroma * b)
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>> From<(H, T, T)> for Okhsv<T> {
+    fn from(components: (H, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent> Into<(OklabHue<T>, T, T)> for Okhsv<T> {
+    fn into(self) -> (OklabHue<T>, T, T) {
+        self.into_components()
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>, A: Component> From<(H, T, T, A)>
+    for Alpha<Okhsv<T>, A>
+{
+    fn from(components: (H, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent, A: Component> Into<(OklabHue<T>, T, T, A)> for Alpha<Okhsv<T>, A> {
+    fn into(self) -> (OklabHue<T>, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<T> Clamp for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    fn is_within_bounds(&self) -> bool {
+        self.saturation >= T::zero() && self.saturation <= T::one() &&
+        self.value >= T::zero() && self.value <= T::one()
+    }
+
+    fn clamp(&self) -> Okhsv<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.saturation = clamp(self.saturation, T::zero(), T::one());
+        self.value = clamp(self.value, T::zero(), T::one());
+    }
+}
+
+impl<T> Mix for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Okhsv<T>, factor: T) -> Okhsv<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
+
+        Okhsv {
+            hue: hue + factor * hue_diff,
+            saturation: self.saturation + factor * (other.saturation - self.saturation),
+            value: self.value + factor * (other.value - self.value),
+        }
+    }
+}
+
+impl<T> Shade for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Okhsv<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.value
+        } else {
+            self.value
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Okhsv {
+            hue: self.hue,
+            saturation: self.saturation,
+            value: (self.value + delta).max(T::zero()),
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Okhsv<T> {
+        Okhsv {
+            hue: self.hue,
+            saturation: self.saturation,
+            value: (self.value + amount).max(T::zero()),
+        }
+    }
+}
+
+impl<T> GetHue for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    type Hue = OklabHue<T>;
+
+    fn get_hue(&self) -> Option<OklabHue<T>> {
+        if self.saturation <= T::zero() || self.value <= T::zero() {
+            None
+        } else {
+            Some(self.hue)
+        }
+    }
+}
+
+impl<T> Hue for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn with_hue<H: Into<Self::Hue>>(&self, hue: H) -> Okhsv<T> {
+        Okhsv {
+            hue: hue.into(),
+            saturation: self.saturation,
+            value: self.value,
+        }
+    }
+
+    fn shift_hue<H: Into<Self::Hue>>(&self, amount: H) -> Okhsv<T> {
+        Okhsv {
+            hue: self.hue + amount.into(),
+            saturation: self.saturation,
+            value: self.value,
+        }
+    }
+}
+
+impl<T> Saturate for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn saturate(&self, factor: T) -> Okhsv<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.saturation
+        } else {
+            self.saturation
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Okhsv {
+            hue: self.hue,
+            saturation: (self.saturation + delta).max(T::zero()),
+            value: self.value,
+        }
+    }
+
+    fn saturate_fixed(&self, amount: T) -> Okhsv<T> {
+        Okhsv {
+            hue: self.hue,
+            saturation: (self.saturation + amount).max(T::zero()),
+            value: self.value,
+        }
+    }
+}
+
+impl<T> Chroma for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.saturation
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.saturation = chroma;
+    }
+}
+
+impl<T> Default for Okhsv<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Okhsv<T> {
+        Okhsv::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T, P> AsRef<P> for Okhsv<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<T, P> AsMut<P> for Okhsv<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+impl<T> AbsDiffEq for Okhsv<T>
+where
+    T: FloatComponent + AbsDiffEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.hue.abs_diff_eq(&other.hue, epsilon)
+            && self.saturation.abs_diff_eq(&other.saturation, epsilon)
+            && self.value.abs_diff_eq(&other.value, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Okhsv<T>
+where
+    T: FloatComponent + RelativeEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.hue.relative_eq(&other.hue, epsilon, max_relative) &&
+            self.saturation.relative_eq(&other.saturation, epsilon, max_relative) &&
+            self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Okhsv<T>
+where
+    T: FloatComponent + UlpsEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.hue.ulps_eq(&other.hue, epsilon, max_ulps) &&
+            self.saturation.ulps_eq(&other.saturation, epsilon, max_ulps) &&
+            self.value.ulps_eq(&other.value, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Okhsv<T> where T: FloatComponent + bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Okhsv<T> where T: FloatComponent + bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Okhsv;
+    use crate::convert::FromColorUnclamped;
+    use crate::{Clamp, LinSrgb, Oklab, Srgb};
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.9, 0.9, 0.1),
+            (0.5, 0.5, 0.5),
+            (0.01, 0.02, 0.0),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let okhsv = Okhsv::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(okhsv), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn gamut_edges() {
+        // The primaries and secondaries are at the edge of the gamut.
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 1.0),
+            (1.0, 0.0, 1.0),
+        ] {
+            let okhsv = Okhsv::from_color_unclamped(LinSrgb::<f64>::new(r, g, b));
+            assert_relative_eq!(okhsv.saturation, 1.0, epsilon = 1e-3);
+            assert_relative_eq!(okhsv.value, 1.0, epsilon = 1e-3);
+        }
+
+        // Grays have no saturation.
+        let white = Okhsv::from_color_unclamped(LinSrgb::<f64>::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.saturation, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(white.value, 1.0, epsilon = 1e-4);
+
+        let black = Okhsv::from_color_unclamped(LinSrgb::<f64>::new(0.0, 0.0, 0.0));
+        assert_eq!(black, Okhsv::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn within_srgb() {
+        // Every color within the bounds is within sRGB, give or take the
+        // approximations of the gamut.
+        for hue in (0..360).step_by(15) {
+            for &saturation in &[0.0, 0.3, 0.7, 1.0] {
+                for &value in &[0.1, 0.5, 1.0] {
+                    let okhsv = Okhsv::new(f64::from(hue), saturation, value);
+                    assert!(okhsv.is_within_bounds());
+
+                    let color = Srgb::from_linear(LinSrgb::from_color_unclamped(okhsv));
+                    for &component in &[color.red, color.green, color.blue] {
+                        assert!(component > -2e-3 && component < 1.0 + 2e-3, "{:?}", okhsv);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hue_from_oklab() {
+        let oklab = Oklab::<f64>::new(0.5, -0.05, 0.1);
+        let okhsv = Okhsv::from_color_unclamped(oklab);
+        assert_relative_eq!(okhsv.hue.to_radians(), 0.1f64.atan2(-0.05), epsilon = 1e-12);
+    }
+}
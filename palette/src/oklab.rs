@@ -0,0 +1,610 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::math::{atan2, cbrt};
+use crate::white_point::D65;
+use crate::{
+    clamp, from_f64, Alpha, Clamp, ColorDifference, ColorPoint, Component, ComponentWise,
+    FloatComponent, GetHue, Mix, OklabHue, Pixel, Shade, Xyz,
+};
+
+/// Oklab with an alpha component. See the [`Oklaba` implementation in
+/// `Alpha`](crate::Alpha#Oklaba).
+pub type Oklaba<T = f32> = Alpha<Oklab<T>, T>;
+
+/// The Oklab color space.
+///
+/// Oklab is a perceptually uniform color space by Björn Ottosson, designed
+/// to predict lightness, chroma and hue better than CIE L\*a\*b\*, while
+/// being as simple to use. Blue hues in particular keep their hue when their
+/// lightness or chroma changes, instead of shifting towards purple. It's
+/// defined for a D65 white point, and is best suited for colors in or near
+/// the sRGB gamut.
+///
+/// ```
+/// use palette::{FromColor, Oklab, Srgb};
+///
+/// let white = Oklab::from_color(Srgb::new(1.0f64, 1.0, 1.0));
+/// assert!((white.l - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Oklab<T = f32>
+where
+    T: FloatComponent,
+{
+    /// L is the lightness of the color. 0.0 gives absolute black and 1.0
+    /// gives the brightest white.
+    pub l: T,
+
+    /// a goes from green, when negative, to red, when positive.
+    pub a: T,
+
+    /// b goes from blue, when negative, to yellow, when positive.
+    pub b: T,
+}
+
+impl<T> Copy for Oklab<T> where T: FloatComponent {}
+
+impl<T> Clone for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn clone(&self) -> Oklab<T> {
+        *self
+    }
+}
+
+impl<T> Oklab<T>
+where
+    T: FloatComponent,
+{
+    /// Oklab.
+    pub fn new(l: T, a: T, b: T) -> Oklab<T> {
+        Oklab { l, a, b }
+    }
+
+    /// Convert to a `(L, a, b)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Convert from a `(L, a, b)` tuple.
+    pub fn from_components((l, a, b): (T, T, T)) -> Self {
+        Self::new(l, a, b)
+    }
+
+    /// Return the `l` value minimum.
+    pub fn min_l() -> T {
+        T::zero()
+    }
+
+    /// Return the `l` value maximum.
+    pub fn max_l() -> T {
+        T::one()
+    }
+}
+
+impl<T> PartialEq for Oklab<T>
+where
+    T: FloatComponent + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.l == other.l && self.a == other.a && self.b == other.b
+    }
+}
+
+impl<T> Eq for Oklab<T> where T: FloatComponent + Eq {}
+
+///<span id="Oklaba"></span>[`Oklaba`](crate::Oklaba) implementations.
+impl<T, A> Alpha<Oklab<T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// Oklab and transparency.
+    pub fn new(l: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Oklab::new(l, a, b),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(L, a, b, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.l, self.a, self.b, self.alpha)
+    }
+
+    /// Convert from a `(L, a, b, alpha)` tuple.
+    pub fn from_components((l, a, b, alpha): (T, T, T, A)) -> Self {
+        Self::new(l, a, b, alpha)
+    }
+}
+
+/// The XYZ to LMS matrix of Oklab.
+///
+/// The matrices are the ones from the original description of Oklab, with
+/// the rows normalized so that the D65 white point of this library becomes
+/// exactly `L = 1`, `a = 0` and `b = 0`.
+#[rustfmt::skip]
+pub(crate) const XYZ_TO_LMS: [f64; 9] = [
+    0.8189889647022367, 0.3618914673889600, -0.1288685181979693,
+    0.0329839120754665, 0.9292940788255502, 0.0361449466529038,
+    0.0481841136683564, 0.2642774813578804, 0.6336388271114470,
+];
+
+/// The LMS to XYZ matrix of Oklab.
+#[rustfmt::skip]
+pub(crate) const LMS_TO_XYZ: [f64; 9] = [
+    1.2269300196052513, -0.5578106605363604, 0.2813506409311092,
+    -0.0405774059222577, 1.1122781653775979, -0.0717007594553403,
+    -0.0763760660173303, -0.4214900482979348, 1.5866961143152651,
+];
+
+/// The non-linear LMS to Oklab matrix.
+#[rustfmt::skip]
+pub(crate) const LMS_TO_LAB: [f64; 9] = [
+    0.2104542566679527, 0.7936177901585156, -0.0040720468264683,
+    1.9779984951000000, -2.4285922050000002, 0.4505937099000000,
+    0.0259040371000000, 0.7827717662000000, -0.8086758033000000,
+];
+
+/// The Oklab to non-linear LMS matrix.
+#[rustfmt::skip]
+pub(crate) const LAB_TO_LMS: [f64; 9] = [
+    1.0000000000000000, 0.3963377914534668, 0.2158037476649721,
+    1.0000000000000000, -0.1055613421105265, -0.0638541716956965,
+    1.0000000000000000, -0.0894841777842972, -1.2914854756501233,
+];
+
+fn multiply<T: FloatComponent>(matrix: &[f64; 9], [x, y, z]: [T; 3]) -> [T; 3] {
+    let row = |index: usize| {
+        from_f64::<T>(matrix[index * 3]) * x
+            + from_f64::<T>(matrix[index * 3 + 1]) * y
+            + from_f64::<T>(matrix[index * 3 + 2]) * z
+    };
+
+    [row(0), row(1), row(2)]
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        let [l, m, s] = multiply(&XYZ_TO_LMS, [color.x, color.y, color.z]);
+        let [l, a, b] = multiply(&LMS_TO_LAB, [cbrt(l), cbrt(m), cbrt(s)]);
+
+        Oklab::new(l, a, b)
+    }
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        let [l, m, s] = multiply(&LAB_TO_LMS, [color.l, color.a, color.b]);
+        let [x, y, z] = multiply(&LMS_TO_XYZ, [l * l * l, m * m * m, s * s * s]);
+
+        Xyz::with_wp(x, y, z)
+    }
+}
+
+impl<T: FloatComponent> From<(T, T, T)> for Oklab<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent> Into<(T, T, T)> for Oklab<T> {
+    fn into(self) -> (T, T, T) {
+        self.into_components()
+    }
+}
+
+impl<T: FloatComponent, A: Component> From<(T, T, T, A)> for Alpha<Oklab<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent, A: Component> Into<(T, T, T, A)> for Alpha<Oklab<T>, A> {
+    fn into(self) -> (T, T, T, A) {
+        self.into_components()
+    }
+}
+
+impl<T> Clamp for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn is_within_bounds(&self) -> bool {
+        self.l >= T::zero() && self.l <= T::one()
+    }
+
+    fn clamp(&self) -> Oklab<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.l = clamp(self.l, T::zero(), T::one());
+    }
+}
+
+impl<T> Mix for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Oklab<T>, factor: T) -> Oklab<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Oklab {
+            l: self.l + factor * (other.l - self.l),
+            a: self.a + factor * (other.a - self.a),
+            b: self.b + factor * (other.b - self.b),
+        }
+    }
+}
+
+impl<T> Shade for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Oklab<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.l
+        } else {
+            self.l
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Oklab {
+            l: (self.l + delta).max(T::zero()),
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Oklab<T> {
+        Oklab {
+            l: (self.l + amount).max(T::zero()),
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+impl<T> GetHue for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Hue = OklabHue<T>;
+
+    fn get_hue(&self) -> Option<OklabHue<T>> {
+        if self.a == T::zero() && self.b == T::zero() {
+            None
+        } else {
+            Some(OklabHue::from_radians(atan2(self.b, self.a)))
+        }
+    }
+}
+
+impl<T> ColorDifference for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    /// The Euclidean distance between the two colors, ΔE.
+    fn get_color_difference(&self, other: &Oklab<T>) -> Self::Scalar {
+        let l = self.l - other.l;
+        let a = self.a - other.a;
+        let b = self.b - other.b;
+
+        (l * l + a * a + b * b).sqrt()
+    }
+}
+
+impl<T> ColorPoint for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Oklab<T>) -> T {
+        self.l * other.l + self.a * other.a + self.b * other.b
+    }
+
+    fn linear_combination(&self, a: T, other: &Oklab<T>, b: T) -> Oklab<T> {
+        Oklab {
+            l: a * self.l + b * other.l,
+            a: a * self.a + b * other.a,
+            b: a * self.b + b * other.b,
+        }
+    }
+}
+
+impl<T> ComponentWise for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn component_wise<F: FnMut(T, T) -> T>(&self, other: &Oklab<T>, mut f: F) -> Oklab<T> {
+        Oklab {
+            l: f(self.l, other.l),
+            a: f(self.a, other.a),
+            b: f(self.b, other.b),
+        }
+    }
+
+    fn component_wise_self<F: FnMut(T) -> T>(&self, mut f: F) -> Oklab<T> {
+        Oklab {
+            l: f(self.l),
+            a: f(self.a),
+            b: f(self.b),
+        }
+    }
+}
+
+impl<T> Default for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Oklab<T> {
+        Oklab::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T> Add<Oklab<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Output = Oklab<T>;
+
+    fn add(self, other: Oklab<T>) -> Self::Output {
+        Oklab {
+            l: self.l + other.l,
+            a: self.a + other.a,
+            b: self.b + other.b,
+        }
+    }
+}
+
+impl<T> Add<T> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Output = Oklab<T>;
+
+    fn add(self, c: T) -> Self::Output {
+        Oklab {
+            l: self.l + c,
+            a: self.a + c,
+            b: self.b + c,
+        }
+    }
+}
+
+impl<T> AddAssign<Oklab<T>> for Oklab<T>
+where
+    T: FloatComponent + AddAssign,
+{
+    fn add_assign(&mut self, other: Oklab<T>) {
+        self.l += other.l;
+        self.a += other.a;
+        self.b += other.b;
+    }
+}
+
+impl<T> AddAssign<T> for Oklab<T>
+where
+    T: FloatComponent + AddAssign,
+{
+    fn add_assign(&mut self, c: T) {
+        self.l += c;
+        self.a += c;
+        self.b += c;
+    }
+}
+
+impl<T> Sub<Oklab<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Output = Oklab<T>;
+
+    fn sub(self, other: Oklab<T>) -> Self::Output {
+        Oklab {
+            l: self.l - other.l,
+            a: self.a - other.a,
+            b: self.b - other.b,
+        }
+    }
+}
+
+impl<T> Sub<T> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    type Output = Oklab<T>;
+
+    fn sub(self, c: T) -> Self::Output {
+        Oklab {
+            l: self.l - c,
+            a: self.a - c,
+            b: self.b - c,
+        }
+    }
+}
+
+impl<T> SubAssign<Oklab<T>> for Oklab<T>
+where
+    T: FloatComponent + SubAssign,
+{
+    fn sub_assign(&mut self, other: Oklab<T>) {
+        self.l -= other.l;
+        self.a -= other.a;
+        self.b -= other.b;
+    }
+}
+
+impl<T> SubAssign<T> for Oklab<T>
+where
+    T: FloatComponent + SubAssign,
+{
+    fn sub_assign(&mut self, c: T) {
+        self.l -= c;
+        self.a -= c;
+        self.b -= c;
+    }
+}
+
+impl<T> AbsDiffEq for Oklab<T>
+where
+    T: FloatComponent + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.l.abs_diff_eq(&other.l, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Oklab<T>
+where
+    T: FloatComponent + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.l.relative_eq(&other.l, epsilon, max_relative)
+            && self.a.relative_eq(&other.a, epsilon, max_relative)
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Oklab<T>
+where
+    T: FloatComponent + UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.l.ulps_eq(&other.l, epsilon, max_ulps)
+            && self.a.ulps_eq(&other.a, epsilon, max_ulps)
+            && self.b.ulps_eq(&other.b, epsilon, max_ulps)
+    }
+}
+
+impl<T, P> AsRef<P> for Oklab<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<T, P> AsMut<P> for Oklab<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Oklab<T> where T: FloatComponent + bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Oklab<T> where T: FloatComponent + bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Oklab;
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::D65;
+    use crate::{ColorDifference, LinSrgb, Srgb, Xyz};
+
+    #[test]
+    fn white_and_black() {
+        let white = Oklab::from_color_unclamped(Srgb::<f64>::new(1.0, 1.0, 1.0).into_linear());
+        assert_relative_eq!(white, Oklab::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+
+        let black = Oklab::from_color_unclamped(Xyz::<D65, f64>::with_wp(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Oklab::new(0.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.9, 0.9, 0.1),
+            (0.5, 0.5, 0.5),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let oklab = Oklab::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(oklab), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = Oklab::<f64>::new(0.5, 0.1, -0.1);
+        let b = Oklab::new(0.6, 0.1, 0.1);
+        assert_relative_eq!(a.get_color_difference(&b), 0.05f64.sqrt());
+    }
+}
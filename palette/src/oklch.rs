@@ -0,0 +1,522 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pixel::RawPixel;
+use crate::float::Float;
+use crate::hues::mix_hues;
+use crate::math::sin_cos;
+use crate::white_point::D65;
+use crate::{
+    clamp, from_f64, Alpha, Chroma, Clamp, Component, FloatComponent, FromF64, GetHue, Hue, Mix,
+    Oklab, OklabHue, Pixel, Saturate, Shade, Xyz,
+};
+
+/// Oklch with an alpha component. See the [`Oklcha` implementation in
+/// `Alpha`](crate::Alpha#Oklcha).
+pub type Oklcha<T = f32> = Alpha<Oklch<T>, T>;
+
+/// The Oklch color space.
+///
+/// Oklch is the cylindrical form of [`Oklab`](crate::Oklab), in the same way
+/// as [`Lch`](crate::Lch) is the cylindrical form of `Lab`. The lightness is
+/// the same as in Oklab, and the chroma and hue describe the `a` and `b`
+/// components as a distance and an angle. Changing the lightness or chroma
+/// of an Oklch color keeps its perceived hue, which makes it a good space for
+/// adjusting colors.
+///
+/// ```
+/// use palette::{FromColor, Oklch, Srgb};
+///
+/// let red = Oklch::from_color(Srgb::new(1.0f64, 0.0, 0.0));
+/// assert!((red.hue.to_positive_degrees() - 29.2).abs() < 0.1);
+/// ```
+#[derive(Debug, Pixel, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    palette_internal_not_base_type,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz)
+)]
+#[repr(C)]
+pub struct Oklch<T = f32>
+where
+    T: FloatComponent,
+{
+    /// L is the lightness of the color. 0.0 gives absolute black and 1.0
+    /// gives the brightest white.
+    pub l: T,
+
+    /// The colorfulness of the color. 0.0 gives gray scale colors, and
+    /// colors within sRGB have a chroma of up to about 0.32. It's the
+    /// distance from the gray scale in Oklab.
+    pub chroma: T,
+
+    /// The hue of the color, in degrees. It's the same as the hue of
+    /// [`Oklab`](crate::Oklab).
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: OklabHue<T>,
+}
+
+impl<T> Copy for Oklch<T> where T: FloatComponent {}
+
+impl<T> Clone for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn clone(&self) -> Oklch<T> {
+        *self
+    }
+}
+
+impl<T> Oklch<T>
+where
+    T: FloatComponent,
+{
+    /// Oklch.
+    pub fn new<H: Into<OklabHue<T>>>(l: T, chroma: T, hue: H) -> Oklch<T> {
+        Oklch {
+            l,
+            chroma,
+            hue: hue.into(),
+        }
+    }
+
+    /// Convert to a `(L, C, h)` tuple.
+    pub fn into_components(self) -> (T, T, OklabHue<T>) {
+        (self.l, self.chroma, self.hue)
+    }
+
+    /// Convert from a `(L, C, h)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>((l, chroma, hue): (T, T, H)) -> Self {
+        Self::new(l, chroma, hue)
+    }
+
+    /// Return the `l` value minimum.
+    pub fn min_l() -> T {
+        T::zero()
+    }
+
+    /// Return the `l` value maximum.
+    pub fn max_l() -> T {
+        T::one()
+    }
+
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return the `chroma` value maximum. This value does not cover the
+    /// entire color space, but covers enough to be practical for
+    /// downsampling to smaller color spaces like sRGB and Display P3. It's
+    /// the same as the chroma range of CSS.
+    pub fn max_chroma() -> T {
+        from_f64(0.4)
+    }
+}
+
+impl<T> PartialEq for Oklch<T>
+where
+    T: FloatComponent + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.l == other.l && self.chroma == other.chroma && self.hue == other.hue
+    }
+}
+
+impl<T> Eq for Oklch<T> where T: FloatComponent + Eq {}
+
+///<span id="Oklcha"></span>[`Oklcha`](crate::Oklcha) implementations.
+impl<T, A> Alpha<Oklch<T>, A>
+where
+    T: FloatComponent,
+    A: Component,
+{
+    /// Oklch and transparency.
+    pub fn new<H: Into<OklabHue<T>>>(l: T, chroma: T, hue: H, alpha: A) -> Self {
+        Alpha {
+            color: Oklch::new(l, chroma, hue),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(L, C, h, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, OklabHue<T>, A) {
+        (self.l, self.chroma, self.hue, self.alpha)
+    }
+
+    /// Convert from a `(L, C, h, alpha)` tuple.
+    pub fn from_components<H: Into<OklabHue<T>>>((l, chroma, hue, alpha): (T, T, H, A)) -> Self {
+        Self::new(l, chroma, hue, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Oklch<T>> for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklch<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Oklch<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklch<T>) -> Self {
+        Self::from_color_unclamped(Oklab::from_color_unclamped(color))
+    }
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        Oklch {
+            l: color.l,
+            chroma: (color.a * color.a + color.b * color.b).sqrt(),
+            hue: color.get_hue().unwrap_or_else(|| OklabHue::from(T::zero())),
+        }
+    }
+}
+
+impl<T> FromColorUnclamped<Oklch<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklch<T>) -> Self {
+        let chroma = color.chroma.max(T::zero());
+        let (b, a) = sin_cos(color.hue.to_radians());
+
+        Oklab::new(color.l, a * chroma, b * chroma)
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>> From<(T, T, H)> for Oklch<T> {
+    fn from(components: (T, T, H)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent> Into<(T, T, OklabHue<T>)> for Oklch<T> {
+    fn into(self) -> (T, T, OklabHue<T>) {
+        self.into_components()
+    }
+}
+
+impl<T: FloatComponent, H: Into<OklabHue<T>>, A: Component> From<(T, T, H, A)>
+    for Alpha<Oklch<T>, A>
+{
+    fn from(components: (T, T, H, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T: FloatComponent, A: Component> Into<(T, T, OklabHue<T>, A)> for Alpha<Oklch<T>, A> {
+    fn into(self) -> (T, T, OklabHue<T>, A) {
+        self.into_components()
+    }
+}
+
+impl<T> Clamp for Oklch<T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    fn is_within_bounds(&self) -> bool {
+        self.l >= T::zero() && self.l <= T::one() &&
+        self.chroma >= T::zero()
+    }
+
+    fn clamp(&self) -> Oklch<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.l = clamp(self.l, T::zero(), T::one());
+        self.chroma = self.chroma.max(T::zero())
+    }
+}
+
+impl<T> Mix for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn mix(&self, other: &Oklch<T>, factor: T) -> Oklch<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+        let (hue, other_hue) = mix_hues(self, other, self.hue, other.hue);
+        let hue_diff: T = (other_hue - hue).to_degrees();
+
+        Oklch {
+            l: self.l + factor * (other.l - self.l),
+            chroma: self.chroma + factor * (other.chroma - self.chroma),
+            hue: hue + factor * hue_diff,
+        }
+    }
+}
+
+impl<T> Shade for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn lighten(&self, factor: T) -> Oklch<T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.l
+        } else {
+            self.l
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Oklch {
+            l: (self.l + delta).max(T::zero()),
+            chroma: self.chroma,
+            hue: self.hue,
+        }
+    }
+
+    fn lighten_fixed(&self, amount: T) -> Oklch<T> {
+        Oklch {
+            l: (self.l + amount).max(T::zero()),
+            chroma: self.chroma,
+            hue: self.hue,
+        }
+    }
+}
+
+impl<T> GetHue for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Hue = OklabHue<T>;
+
+    fn get_hue(&self) -> Option<OklabHue<T>> {
+        if self.chroma <= T::zero() {
+            None
+        } else {
+            Some(self.hue)
+        }
+    }
+}
+
+impl<T> Hue for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn with_hue<H: Into<Self::Hue>>(&self, hue: H) -> Oklch<T> {
+        Oklch {
+            l: self.l,
+            chroma: self.chroma,
+            hue: hue.into(),
+        }
+    }
+
+    fn shift_hue<H: Into<Self::Hue>>(&self, amount: H) -> Oklch<T> {
+        Oklch {
+            l: self.l,
+            chroma: self.chroma,
+            hue: self.hue + amount.into(),
+        }
+    }
+}
+
+impl<T> Saturate for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn saturate(&self, factor: T) -> Oklch<T> {
+        let difference = if factor >= T::zero() {
+            Self::max_chroma() - self.chroma
+        } else {
+            self.chroma
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Oklch {
+            l: self.l,
+            chroma: (self.chroma + delta).max(T::zero()),
+            hue: self.hue,
+        }
+    }
+
+    fn saturate_fixed(&self, amount: T) -> Oklch<T> {
+        Oklch {
+            l: self.l,
+            chroma: (self.chroma + Self::max_chroma() * amount).max(T::zero()),
+            hue: self.hue,
+        }
+    }
+}
+
+impl<T> Chroma for Oklch<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn chroma(&self) -> T {
+        self.chroma
+    }
+
+    fn set_chroma(&mut self, chroma: T) {
+        self.chroma = chroma;
+    }
+}
+
+impl<T> Default for Oklch<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Oklch<T> {
+        Oklch::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T, P> AsRef<P> for Oklch<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_ref(&self) -> &P {
+        self.as_raw()
+    }
+}
+
+impl<T, P> AsMut<P> for Oklch<T>
+where
+    T: FloatComponent,
+    P: RawPixel<T> + ?Sized,
+{
+    fn as_mut(&mut self) -> &mut P {
+        self.as_raw_mut()
+    }
+}
+
+impl<T> AbsDiffEq for Oklch<T>
+where
+    T: FloatComponent + AbsDiffEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.l.abs_diff_eq(&other.l, epsilon)
+            && self.chroma.abs_diff_eq(&other.chroma, epsilon)
+            && self.hue.abs_diff_eq(&other.hue, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Oklch<T>
+where
+    T: FloatComponent + RelativeEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.l.relative_eq(&other.l, epsilon, max_relative) &&
+            self.chroma.relative_eq(&other.chroma, epsilon, max_relative) &&
+            self.hue.relative_eq(&other.hue, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Oklch<T>
+where
+    T: FloatComponent + UlpsEq,
+    T::Epsilon: Copy + Float + FromF64,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.l.ulps_eq(&other.l, epsilon, max_ulps) &&
+            self.chroma.ulps_eq(&other.chroma, epsilon, max_ulps) &&
+            self.hue.ulps_eq(&other.hue, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Oklch<T> where T: FloatComponent + bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Oklch<T> where T: FloatComponent + bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::{Oklch, Oklcha};
+    use crate::convert::FromColorUnclamped;
+    use crate::{Chroma, GetHue, LinSrgb, Oklab};
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.5, 0.8),
+            (0.5, 0.5, 0.5),
+        ] {
+            let color = LinSrgb::<f64>::new(r, g, b);
+            let oklch = Oklch::from_color_unclamped(color);
+            assert_relative_eq!(LinSrgb::from_color_unclamped(oklch), color, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn from_oklab() {
+        let oklab = Oklab::<f64>::new(0.6, 0.1, -0.1);
+        let oklch = Oklch::from_color_unclamped(oklab);
+        assert_relative_eq!(oklch.l, 0.6);
+        assert_relative_eq!(oklch.chroma, 0.02f64.sqrt());
+        assert_relative_eq!(oklch.hue.to_degrees(), -45.0);
+        assert_relative_eq!(Oklab::from_color_unclamped(oklch), oklab, epsilon = 1e-12);
+
+        let gray = Oklch::from_color_unclamped(Oklab::<f64>::new(0.5, 0.0, 0.0));
+        assert_eq!(gray.chroma, 0.0);
+        assert_eq!(gray.get_hue(), None);
+    }
+
+    #[test]
+    fn chroma() {
+        let color = Oklcha::new(0.5f64, 0.1, 200.0, 0.5);
+        assert_eq!(color.chroma(), 0.1);
+        assert_eq!(color.scale_chroma(2.0), Oklcha::new(0.5, 0.2, 200.0, 0.5));
+        assert_eq!(color.with_chroma(0.0).chroma, 0.0);
+    }
+}
@@ -0,0 +1,198 @@
+//! Slices through color spaces, for color picker widgets.
+//!
+//! A color picker usually shows a plane of colors where one component is
+//! fixed, such as a saturation and value square for the selected hue. The
+//! functions in this module fill a caller provided buffer with such a plane,
+//! one row after the other, from the top left corner. The first and last
+//! column and row are exactly at the ends of the ranges, so the corners of
+//! the picker show the extreme colors.
+//!
+//! ```
+//! use palette::picker::okhsv_saturation_value;
+//! use palette::Srgb;
+//!
+//! // A 64 by 64 saturation and value square for a red hue.
+//! let mut buffer = vec![Srgb::new(0.0, 0.0, 0.0); 64 * 64];
+//! okhsv_saturation_value(&mut buffer, 64, 30.0);
+//!
+//! // The top right corner is fully saturated, and the bottom row is black.
+//! let corner = buffer[63];
+//! assert!(corner.red > 0.9 && corner.blue < 0.1);
+//! assert!(buffer[63 * 64].red < 1e-3);
+//! ```
+//!
+//! [`fill_plane`](fn.fill_plane.html) is the building block for the other
+//! functions, and can be used to fill a buffer of any type, such as
+//! `Srgb<u8>` for a texture:
+//!
+//! ```
+//! use palette::picker::fill_plane;
+//! use palette::{FromColor, Okhsl, Srgb};
+//!
+//! let mut buffer = vec![Srgb::new(0u8, 0, 0); 32 * 8];
+//! fill_plane(&mut buffer, 32, |x: f32, y: f32| {
+//!     let color = Okhsl::new(x * 360.0, 1.0 - y, 0.6);
+//!     Srgb::from_color(color).into_format()
+//! });
+//! ```
+
+use crate::{from_f64, FloatComponent, FromColor, Okhsl, Okhsv, Oklab, OklabHue};
+
+/// Fill `buffer` with a plane of `width` columns, where the color of each
+/// pixel is `color_at(x, y)`. `x` goes from `0.0` in the leftmost column to
+/// `1.0` in the rightmost column, and `y` goes from `0.0` in the top row to
+/// `1.0` in the bottom row. The number of rows is the length of `buffer`
+/// divided by `width`.
+///
+/// # Panics
+///
+/// Panics if `width` is `0`, or if the length of `buffer` is not a multiple
+/// of `width`.
+pub fn fill_plane<T, O, F>(buffer: &mut [O], width: usize, mut color_at: F)
+where
+    T: FloatComponent,
+    F: FnMut(T, T) -> O,
+{
+    assert!(width > 0, "the width must be at least 1");
+
+    let height = buffer.len() / width;
+    assert!(
+        height * width == buffer.len(),
+        "the buffer length must be a multiple of the width"
+    );
+
+    for (row_index, row) in buffer.chunks_mut(width).enumerate() {
+        let y = position(row_index, height);
+
+        for (column, pixel) in row.iter_mut().enumerate() {
+            *pixel = color_at(position(column, width), y);
+        }
+    }
+}
+
+/// Fill `buffer` with the saturation and value plane of
+/// [`Okhsv`](../struct.Okhsv.html) for `hue`. The saturation goes from `0.0`
+/// on the left to `1.0` on the right, and the value goes from `1.0` at the
+/// top to `0.0` at the bottom. See [`fill_plane`](fn.fill_plane.html) for
+/// the layout of the buffer.
+pub fn okhsv_saturation_value<T, H, O>(buffer: &mut [O], width: usize, hue: H)
+where
+    T: FloatComponent,
+    H: Into<OklabHue<T>>,
+    O: FromColor<Okhsv<T>>,
+{
+    let hue = hue.into();
+    fill_plane(buffer, width, |x: T, y: T| {
+        O::from_color(Okhsv::new(hue, x, T::one() - y))
+    });
+}
+
+/// Fill `buffer` with the hue and saturation plane of
+/// [`Okhsl`](../struct.Okhsl.html) for `lightness`. The hue goes from `0.0`
+/// on the left to `360.0` on the right, and the saturation goes from `1.0` at
+/// the top to `0.0` at the bottom. See [`fill_plane`](fn.fill_plane.html)
+/// for the layout of the buffer.
+pub fn okhsl_hue_saturation<T, O>(buffer: &mut [O], width: usize, lightness: T)
+where
+    T: FloatComponent,
+    O: FromColor<Okhsl<T>>,
+{
+    let full_turn: T = from_f64(360.0);
+    fill_plane(buffer, width, |x: T, y: T| {
+        O::from_color(Okhsl::new(x * full_turn, T::one() - y, lightness))
+    });
+}
+
+/// Fill `buffer` with the `a` and `b` plane of [`Oklab`](../struct.Oklab.html)
+/// for the lightness `l`. `a` goes from `-extent` on the left to `extent` on
+/// the right, and `b` goes from `extent` at the top to `-extent` at the
+/// bottom. Colors outside the gamut of `O` are clamped. See
+/// [`fill_plane`](fn.fill_plane.html) for the layout of the buffer.
+pub fn oklab_a_b<T, O>(buffer: &mut [O], width: usize, l: T, extent: T)
+where
+    T: FloatComponent,
+    O: FromColor<Oklab<T>>,
+{
+    let span = extent + extent;
+    fill_plane(buffer, width, |x: T, y: T| {
+        O::from_color(Oklab::new(l, x * span - extent, extent - y * span))
+    });
+}
+
+/// The position of `index` in `0.0..=1.0`, when there are `count` steps.
+fn position<T: FloatComponent>(index: usize, count: usize) -> T {
+    if count > 1 {
+        from_f64::<T>(index as f64) / from_f64(count as f64 - 1.0)
+    } else {
+        T::zero()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fill_plane, okhsl_hue_saturation, okhsv_saturation_value, oklab_a_b};
+    use crate::{FromColor, LinSrgb, Okhsl, Okhsv, Oklab, OklabHue};
+
+    #[test]
+    fn plane_positions() {
+        let mut buffer = vec![(0.0, 0.0); 6];
+        fill_plane(&mut buffer, 3, |x: f64, y: f64| (x, y));
+
+        assert_eq!(
+            buffer,
+            vec![
+                (0.0, 0.0),
+                (0.5, 0.0),
+                (1.0, 0.0),
+                (0.0, 1.0),
+                (0.5, 1.0),
+                (1.0, 1.0)
+            ]
+        );
+
+        // A single row or column is at the start of the range.
+        let mut buffer = vec![(1.0, 1.0); 2];
+        fill_plane(&mut buffer, 2, |x: f64, y: f64| (x, y));
+        assert_eq!(buffer, vec![(0.0, 0.0), (1.0, 0.0)]);
+    }
+
+    #[test]
+    fn okhsv_plane() {
+        let mut buffer = vec![LinSrgb::new(0.0, 0.0, 0.0); 5 * 4];
+        okhsv_saturation_value(&mut buffer, 5, 140.0f64);
+
+        let top_right = Okhsv::from_color(buffer[4]);
+        assert_relative_eq!(top_right.saturation, 1.0, epsilon = 1e-2);
+        assert_relative_eq!(top_right.value, 1.0, epsilon = 1e-2);
+        assert_relative_eq!(top_right.hue.to_positive_degrees(), 140.0, epsilon = 1e-2);
+
+        // The left column is gray and the bottom row is black.
+        let left = Okhsv::from_color(buffer[5]);
+        assert_relative_eq!(left.saturation, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(left.value, 2.0 / 3.0, epsilon = 1e-6);
+        assert_relative_eq!(buffer[17], LinSrgb::new(0.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn okhsl_plane() {
+        let mut buffer = vec![LinSrgb::new(0.0, 0.0, 0.0); 7 * 2];
+        okhsl_hue_saturation(&mut buffer, 7, 0.5f64);
+
+        for (column, &color) in buffer[..7].iter().enumerate() {
+            let okhsl = Okhsl::from_color(color);
+            let hue = OklabHue::from(column as f64 * 60.0);
+            assert_relative_eq!(okhsl.hue, hue, epsilon = 1e-2);
+            assert_relative_eq!(okhsl.lightness, 0.5, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn oklab_plane() {
+        let mut buffer = vec![Oklab::new(0.0, 0.0, 0.0); 3 * 3];
+        oklab_a_b(&mut buffer, 3, 0.7f64, 0.2);
+
+        assert_relative_eq!(buffer[0], Oklab::new(0.7, -0.2, 0.2));
+        assert_relative_eq!(buffer[4], Oklab::new(0.7, 0.0, 0.0));
+        assert_relative_eq!(buffer[8], Oklab::new(0.7, 0.2, -0.2));
+    }
+}
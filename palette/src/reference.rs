@@ -0,0 +1,171 @@
+//! Reference colors for skin tones and other memory colors.
+//!
+//! Memory colors are the colors that people have strong expectations about,
+//! such as skin, the sky and foliage. They are common targets when tuning
+//! cameras and image pipelines, and when checking that a pipeline treats
+//! all skin tones equally well. The constants in this module are 8 bit
+//! sRGB, like the [`named`](../named/index.html) colors:
+//!
+//! ```
+//! use palette::reference::{individual_typology_angle, SkinCategory, MONK_SKIN_TONES};
+//! use palette::{Lab, FromColor, Srgb};
+//!
+//! let lightest: Lab = Lab::from_color(Srgb::<f32>::from_format(MONK_SKIN_TONES[0]).into_linear());
+//! let darkest: Lab = Lab::from_color(Srgb::<f32>::from_format(MONK_SKIN_TONES[9]).into_linear());
+//! assert!(lightest.l > darkest.l);
+//!
+//! assert_eq!(SkinCategory::classify(lightest), SkinCategory::VeryLight);
+//! assert_eq!(SkinCategory::classify(darkest), SkinCategory::Dark);
+//! assert!(individual_typology_angle(lightest) > individual_typology_angle(darkest));
+//! ```
+//!
+//! # Provenance
+//!
+//! * [`MONK_SKIN_TONES`](constant.MONK_SKIN_TONES.html) is the Monk Skin Tone
+//!   Scale by Dr. Ellis Monk, as published by Google under the CC BY 4.0
+//!   license at <https://skintone.google>.
+//! * The ColorChecker colors, such as [`DARK_SKIN`](constant.DARK_SKIN.html)
+//!   and [`BLUE_SKY`](constant.BLUE_SKY.html), are the sRGB values that
+//!   X-Rite publishes for the ColorChecker Classic chart, which are rounded
+//!   from its L\*a\*b\* reference values.
+//!
+//! The Fitzpatrick scale classifies skin by how it reacts to sunlight, and
+//! it doesn't define any colors. Colors can instead be sorted into the
+//! [`SkinCategory`](enum.SkinCategory.html) groups, which are based on the
+//! individual typology angle and are commonly used as a colorimetric
+//! stand-in for the Fitzpatrick types.
+
+use crate::math::atan2;
+use crate::white_point::D65;
+use crate::{from_f64, FloatComponent, Lab, Srgb};
+
+macro_rules! srgb_constant {
+    ($red: expr, $green: expr, $blue: expr) => {
+        Srgb {
+            red: $red,
+            green: $green,
+            blue: $blue,
+            standard: ::core::marker::PhantomData,
+        }
+    };
+}
+
+/// The ten tones of the Monk Skin Tone Scale, from the lightest to the
+/// darkest, as sorted by the individual typology angle.
+pub const MONK_SKIN_TONES: [Srgb<u8>; 10] = [
+    srgb_constant!(0xf6, 0xed, 0xe4),
+    srgb_constant!(0xf3, 0xe7, 0xdb),
+    srgb_constant!(0xf7, 0xea, 0xd0),
+    srgb_constant!(0xea, 0xda, 0xba),
+    srgb_constant!(0xd7, 0xbd, 0x96),
+    srgb_constant!(0xa0, 0x7e, 0x56),
+    srgb_constant!(0x82, 0x5c, 0x43),
+    srgb_constant!(0x60, 0x41, 0x34),
+    srgb_constant!(0x3a, 0x31, 0x2a),
+    srgb_constant!(0x29, 0x24, 0x20),
+];
+
+/// The "dark skin" patch of the ColorChecker Classic chart.
+pub const DARK_SKIN: Srgb<u8> = srgb_constant!(115, 82, 68);
+
+/// The "light skin" patch of the ColorChecker Classic chart.
+pub const LIGHT_SKIN: Srgb<u8> = srgb_constant!(194, 150, 130);
+
+/// The "blue sky" patch of the ColorChecker Classic chart.
+pub const BLUE_SKY: Srgb<u8> = srgb_constant!(98, 122, 157);
+
+/// The "foliage" patch of the ColorChecker Classic chart.
+pub const FOLIAGE: Srgb<u8> = srgb_constant!(87, 108, 67);
+
+/// The individual typology angle (ITA°) of a skin color, in degrees.
+///
+/// It's `atan((L* - 50) / b*)`, which grows with the lightness and shrinks
+/// with the yellowness of the skin. The angle is between `-90.0` and `90.0`.
+pub fn individual_typology_angle<T: FloatComponent>(color: Lab<D65, T>) -> T {
+    let angle = atan2(color.l - from_f64(50.0), color.b).to_degrees();
+
+    // Keep it in the range of `atan`, for colors with a negative b*.
+    if angle > from_f64(90.0) {
+        angle - from_f64(180.0)
+    } else if angle < from_f64(-90.0) {
+        angle + from_f64(180.0)
+    } else {
+        angle
+    }
+}
+
+/// A skin color group, based on the individual typology angle.
+///
+/// The groups and their limits are from Chardon et al, "Skin colour
+/// typology and suntanning pathways" (1991).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SkinCategory {
+    /// An angle above 55°.
+    VeryLight,
+    /// An angle from 41° to 55°.
+    Light,
+    /// An angle from 28° to 41°.
+    Intermediate,
+    /// An angle from 10° to 28°.
+    Tan,
+    /// An angle from -30° to 10°.
+    Brown,
+    /// An angle below -30°.
+    Dark,
+}
+
+impl SkinCategory {
+    /// Find the group of a skin color.
+    pub fn classify<T: FloatComponent>(color: Lab<D65, T>) -> Self {
+        Self::from_angle(individual_typology_angle(color))
+    }
+
+    /// Find the group for an individual typology angle, in degrees.
+    pub fn from_angle<T: FloatComponent>(angle: T) -> Self {
+        if angle > from_f64(55.0) {
+            SkinCategory::VeryLight
+        } else if angle > from_f64(41.0) {
+            SkinCategory::Light
+        } else if angle > from_f64(28.0) {
+            SkinCategory::Intermediate
+        } else if angle > from_f64(10.0) {
+            SkinCategory::Tan
+        } else if angle > from_f64(-30.0) {
+            SkinCategory::Brown
+        } else {
+            SkinCategory::Dark
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{individual_typology_angle, SkinCategory, DARK_SKIN, LIGHT_SKIN, MONK_SKIN_TONES};
+    use crate::convert::FromColor;
+    use crate::{Lab, Srgb};
+
+    fn lab(color: Srgb<u8>) -> Lab<crate::white_point::D65, f64> {
+        Lab::from_color(color.into_format::<f64>())
+    }
+
+    #[test]
+    fn monk_scale_gets_darker() {
+        for pair in MONK_SKIN_TONES.windows(2) {
+            let first = individual_typology_angle(lab(pair[0]));
+            let second = individual_typology_angle(lab(pair[1]));
+            assert!(first > second);
+        }
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(SkinCategory::classify(lab(LIGHT_SKIN)), SkinCategory::Light);
+        assert_eq!(SkinCategory::classify(lab(DARK_SKIN)), SkinCategory::Dark);
+        assert_eq!(SkinCategory::from_angle(60.0), SkinCategory::VeryLight);
+        assert_eq!(SkinCategory::from_angle(-45.0), SkinCategory::Dark);
+        assert_eq!(
+            individual_typology_angle(Lab::<_, f64>::new(60.0, 10.0, -10.0)),
+            -45.0
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use core::ops::{Add, Div};
 
 use crate::component::Component;
-use crate::{from_f64, FromF64};
+use crate::convert::{FromColor, IntoColor};
+use crate::math::powf;
+use crate::{from_f64, FloatComponent, FromF64, Oklch, Srgb};
 
 /// A trait for calculating relative contrast between two colors.
 ///
@@ -39,8 +41,9 @@ use crate::{from_f64, FromF64};
 /// with text and images of text. SC 1.4.11 is a Success Criterion for "non-text
 /// contrast" such as user interface components and other graphics. The relative
 /// contrast is calculated by `(L1 + 0.05) / (L2 + 0.05)`, where `L1` is the
-/// luminance of the brighter color and `L2` is the luminance of the darker
-/// color both in sRGB linear space. A higher contrast ratio is generally
+/// [relative luminance](#tymethod.relative_luminance) of the brighter color
+/// and `L2` is the relative luminance of the darker color, both in linear
+/// space. A higher contrast ratio is generally
 /// desireable.
 ///
 /// For more details, visit the following links:
@@ -52,11 +55,27 @@ use crate::{from_f64, FromF64};
 /// [Success Criterion 1.4.11 Non-text Contrast (Level AA)](https://www.w3.org/WAI/WCAG21/Understanding/non-text-contrast.html)
 #[doc(alias = "wcag")]
 pub trait RelativeContrast {
-    /// The type of the contrast ratio.
-    type Scalar: FromF64 + PartialOrd;
+    /// The type of the luminance and contrast ratio.
+    type Scalar: Component + FromF64 + Add<Output = Self::Scalar> + Div<Output = Self::Scalar>;
+
+    /// Calculate the relative luminance of the color, as defined by WCAG.
+    ///
+    /// It's the luminance (`Y`) of the color in its linear space, from `0.0`
+    /// for black to `1.0` for white.
+    ///
+    /// ```
+    /// use palette::{RelativeContrast, Srgb};
+    ///
+    /// assert_eq!(Srgb::new(1.0, 1.0, 1.0).relative_luminance(), 1.0);
+    /// let red: Srgb = Srgb::new(1.0, 0.0, 0.0);
+    /// assert!((red.relative_luminance() - 0.2126).abs() < 1e-4);
+    /// ```
+    fn relative_luminance(&self) -> Self::Scalar;
 
     /// Calculate the contrast ratio between two colors.
-    fn get_contrast_ratio(&self, other: &Self) -> Self::Scalar;
+    fn get_contrast_ratio(&self, other: &Self) -> Self::Scalar {
+        contrast_ratio(self.relative_luminance(), other.relative_luminance())
+    }
     /// Verify the contrast between two colors satisfies SC 1.4.3. Contrast
     /// is at least 4.5:1 (Level AA).
     fn has_min_contrast_text(&self, other: &Self) -> bool {
@@ -96,18 +115,209 @@ where
     }
 }
 
+/// Calculate the APCA lightness contrast (`Lc`) of `text` on `background`.
+///
+/// The Accessible Perceptual Contrast Algorithm, or APCA, is proposed for
+/// WCAG 3. Unlike [`contrast_ratio`](fn.contrast_ratio.html), it depends on
+/// which color is the text, and it's meant to match how contrast is perceived
+/// for both dark and light backgrounds. The result goes from about `106.0`
+/// for black text on white to about `-108.0` for white text on black, where
+/// negative values mean light text on a dark background. Pairs with very
+/// low contrast are rounded to `0.0`.
+///
+/// This implements version 0.0.98G-4g of the algorithm, which uses the
+/// encoded sRGB components directly.
+///
+/// ```
+/// use palette::{apca_contrast, Srgb};
+///
+/// let black: Srgb = Srgb::new(0.0, 0.0, 0.0);
+/// let white: Srgb = Srgb::new(1.0, 1.0, 1.0);
+///
+/// assert!((apca_contrast(black, white) - 106.04).abs() < 0.01);
+/// assert!((apca_contrast(white, black) + 107.88).abs() < 0.01);
+/// ```
+pub fn apca_contrast<T: FloatComponent>(text: Srgb<T>, background: Srgb<T>) -> T {
+    let text_y = apca_luminance(text);
+    let background_y = apca_luminance(background);
+
+    if (background_y - text_y).abs() < from_f64(0.0005) {
+        return T::zero();
+    }
+
+    let scale = from_f64::<T>(1.14);
+    let offset = from_f64::<T>(0.027);
+    let low_clip = from_f64::<T>(0.1);
+
+    let contrast = if background_y > text_y {
+        let contrast = (powf(background_y, from_f64(0.56)) - powf(text_y, from_f64(0.57))) * scale;
+        if contrast < low_clip {
+            T::zero()
+        } else {
+            contrast - offset
+        }
+    } else {
+        let contrast = (powf(background_y, from_f64(0.65)) - powf(text_y, from_f64(0.62))) * scale;
+        if contrast > -low_clip {
+            T::zero()
+        } else {
+            contrast + offset
+        }
+    };
+
+    contrast * from_f64(100.0)
+}
+
+/// The estimated screen luminance of a color for APCA, with a soft clamp
+/// near black.
+fn apca_luminance<T: FloatComponent>(color: Srgb<T>) -> T {
+    let linear = |component: T| powf(component.max(T::zero()).min(T::one()), from_f64(2.4));
+    let luminance = linear(color.red) * from_f64(0.2126729)
+        + linear(color.green) * from_f64(0.7151522)
+        + linear(color.blue) * from_f64(0.0721750);
+
+    let black_threshold = from_f64::<T>(0.022);
+    if luminance > black_threshold {
+        luminance
+    } else {
+        luminance + powf(black_threshold - luminance, from_f64(1.414))
+    }
+}
+
+/// A measure of contrast between a color and its background, for
+/// [`adjust_for_contrast`](fn.adjust_for_contrast.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContrastMetric {
+    /// The WCAG 2.1 contrast ratio, from
+    /// [`get_contrast_ratio`](trait.RelativeContrast.html#method.get_contrast_ratio).
+    /// Targets go from `1.0` to `21.0`, such as `4.5` for normal text.
+    Wcag2,
+
+    /// The absolute APCA lightness contrast, from
+    /// [`apca_contrast`](fn.apca_contrast.html), with the color as the text.
+    /// Targets go from `0.0` to about `106.0`, such as `75.0` for body text.
+    Apca,
+}
+
+/// Change the lightness of `color` as little as possible, until its contrast
+/// with `background` is at least `target`, as measured by `metric`.
+///
+/// The lightness is changed in [`Oklch`](crate::Oklch), where the chroma and
+/// hue stay the same, so the result looks like a lighter or darker version of
+/// the original color. Both directions are tried, and the one that needs the
+/// smallest change wins. The color is returned as it is if it already has
+/// enough contrast, and `None` is returned if the target can't be reached,
+/// such as when it's above 21:1 for `ContrastMetric::Wcag2`.
+///
+/// ```
+/// use palette::{adjust_for_contrast, apca_contrast, ContrastMetric, RelativeContrast, Srgb};
+///
+/// let background = Srgb::new(1.0, 1.0, 1.0);
+/// let link = Srgb::new(0.4, 0.6, 1.0);
+/// assert!(!link.has_min_contrast_text(&background));
+///
+/// let wcag = adjust_for_contrast(link, background, 4.5, ContrastMetric::Wcag2).unwrap();
+/// assert!(wcag.has_min_contrast_text(&background));
+///
+/// let apca = adjust_for_contrast(link, background, 75.0, ContrastMetric::Apca).unwrap();
+/// assert!(apca_contrast(apca, background) >= 75.0);
+/// ```
+pub fn adjust_for_contrast<C, T>(
+    color: C,
+    background: C,
+    target: T,
+    metric: ContrastMetric,
+) -> Option<C>
+where
+    C: RelativeContrast<Scalar = T>
+        + Clone
+        + FromColor<Oklch<T>>
+        + IntoColor<Oklch<T>>
+        + IntoColor<Srgb<T>>,
+    T: FloatComponent,
+{
+    let background_srgb: Srgb<T> = background.clone().into_color();
+    let contrast = |candidate: &C| match metric {
+        ContrastMetric::Wcag2 => candidate.get_contrast_ratio(&background),
+        ContrastMetric::Apca => {
+            apca_contrast(candidate.clone().into_color(), background_srgb).abs()
+        }
+    };
+
+    if contrast(&color) >= target {
+        return Some(color);
+    }
+
+    let oklch: Oklch<T> = color.into_color();
+    let reaches_target = |l: T| {
+        let candidate = C::from_color(Oklch::new(l, oklch.chroma, oklch.hue));
+        if contrast(&candidate) >= target {
+            Some(candidate)
+        } else {
+            None
+        }
+    };
+    let lighter =
+        reaches_target(T::one()).map(|_| search_lightness((oklch.l, T::one()), &reaches_target));
+    let darker =
+        reaches_target(T::zero()).map(|_| search_lightness((oklch.l, T::zero()), &reaches_target));
+
+    let lightness = match (lighter, darker) {
+        (Some(lighter), Some(darker)) => {
+            if lighter - oklch.l <= oklch.l - darker {
+                lighter
+            } else {
+                darker
+            }
+        }
+        (Some(lightness), None) | (None, Some(lightness)) => lightness,
+        (None, None) => return None,
+    };
+
+    reaches_target(lightness)
+}
+
+const SEARCH_STEPS: usize = 32;
+
+/// Bisect the lightness between `failing` and `passing` until it's close to
+/// where the target is first reached.
+fn search_lightness<T, C, F>((mut failing, mut passing): (T, T), reaches_target: &F) -> T
+where
+    T: FloatComponent,
+    F: Fn(T) -> Option<C>,
+{
+    for _ in 0..SEARCH_STEPS {
+        let middle = (failing + passing) / from_f64(2.0);
+        if reaches_target(middle).is_some() {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+
+    passing
+}
+
 #[cfg(test)]
 mod test {
     use core::str::FromStr;
 
-    use crate::RelativeContrast;
-    use crate::Srgb;
+    use super::{adjust_for_contrast, apca_contrast, ContrastMetric};
+    use crate::{FromColor, Oklch, RelativeContrast, Srgb};
 
     #[test]
     fn relative_contrast() {
         let white = Srgb::new(1.0, 1.0, 1.0);
         let black = Srgb::new(0.0, 0.0, 0.0);
 
+        assert_relative_eq!(white.relative_luminance(), 1.0);
+        assert_relative_eq!(black.relative_luminance(), 0.0);
+        assert_relative_eq!(
+            Srgb::new(0.5, 0.5, 0.5).relative_luminance(),
+            0.2140,
+            epsilon = 1e-4
+        );
+
         assert_relative_eq!(white.get_contrast_ratio(&white), 1.0);
         assert_relative_eq!(white.get_contrast_ratio(&black), 21.0);
         assert_relative_eq!(
@@ -141,4 +351,87 @@ mod test {
         assert_relative_eq!(c1.get_contrast_ratio(&white), 1.22, epsilon = 0.01);
         assert_relative_eq!(c1.get_contrast_ratio(&black), 17.11, epsilon = 0.01);
     }
+
+    #[test]
+    fn adjust_contrast() {
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let gray = Srgb::new(0.5f64, 0.5, 0.5);
+
+        // Enough contrast already.
+        assert_eq!(
+            adjust_for_contrast(black, white, 7.0, ContrastMetric::Wcag2),
+            Some(black)
+        );
+
+        // Gray on white gets darker, and just reaches the target.
+        let darker = adjust_for_contrast(gray, white, 4.5, ContrastMetric::Wcag2).unwrap();
+        let ratio = darker.get_contrast_ratio(&white);
+        assert!((4.5..4.5 + 1e-6).contains(&ratio));
+        assert!(darker.red < gray.red);
+
+        // A dark orange on a dark background gets lighter, keeping its hue.
+        let orange = Srgb::new(0.6f64, 0.3, 0.1);
+        let background = Srgb::new(0.15f64, 0.15, 0.15);
+        let lighter = adjust_for_contrast(orange, background, 7.0, ContrastMetric::Wcag2).unwrap();
+        assert!(lighter.get_contrast_ratio(&background) >= 7.0);
+        assert_relative_eq!(
+            Oklch::from_color(lighter).hue,
+            Oklch::from_color(orange).hue,
+            epsilon = 1.0
+        );
+
+        // No color has more contrast than black on white.
+        assert_eq!(
+            adjust_for_contrast(gray, white, 22.0, ContrastMetric::Wcag2),
+            None
+        );
+        assert_eq!(
+            adjust_for_contrast(gray, gray, 21.0, ContrastMetric::Wcag2),
+            None
+        );
+    }
+
+    #[test]
+    fn apca() {
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+
+        assert_relative_eq!(apca_contrast(black, white), 106.04, epsilon = 0.01);
+        assert_relative_eq!(apca_contrast(white, black), -107.88, epsilon = 0.01);
+        assert_eq!(apca_contrast(white, white), 0.0);
+
+        // Reference values from the APCA documentation.
+        let text = Srgb::new(0x88u8, 0x88, 0x88).into_format();
+        assert_relative_eq!(apca_contrast(text, white), 63.06, epsilon = 0.01);
+        let background = Srgb::new(0xaau8, 0xaa, 0xaa).into_format();
+        assert_relative_eq!(apca_contrast(black, background), 58.15, epsilon = 0.01);
+    }
+
+    #[test]
+    fn adjust_apca_contrast() {
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let gray = Srgb::new(0.5f64, 0.5, 0.5);
+
+        assert_eq!(
+            adjust_for_contrast(black, white, 90.0, ContrastMetric::Apca),
+            Some(black)
+        );
+
+        // Gray on white gets darker, and just reaches the target.
+        let darker = adjust_for_contrast(gray, white, 75.0, ContrastMetric::Apca).unwrap();
+        let contrast = apca_contrast(darker, white);
+        assert!((75.0..75.0 + 1e-4).contains(&contrast), "{}", contrast);
+
+        // Gray on black gets lighter, with negative contrast.
+        let lighter = adjust_for_contrast(gray, black, 90.0, ContrastMetric::Apca).unwrap();
+        assert!(apca_contrast(lighter, black) <= -90.0);
+        assert!(lighter.red > gray.red);
+
+        assert_eq!(
+            adjust_for_contrast(gray, white, 110.0, ContrastMetric::Apca),
+            None
+        );
+    }
 }
@@ -6,9 +6,59 @@ use crate::{Component, FloatComponent, FromComponent, Yxy};
 
 pub use self::packed::{channels, Packed, RgbChannels};
 pub use self::rgb::{FromHexError, Rgb, Rgba};
+pub use self::soft_clip::SoftClip;
+
+/// Create an [`Srgb<u8>`](type.Srgb.html) constant from a hex code or an
+/// `rgb(...)` function, which is checked at compile time.
+///
+/// The hex code is written as in CSS, with 3 or 6 digits and a `#` prefix.
+/// The `rgb(...)` function takes numbers from `0` to `255`, or percentages,
+/// separated by commas or spaces. Adding an alpha value, with 4 or 8 hex
+/// digits, or as a fourth `rgb(...)` argument from `0.0` to `1.0`, gives an
+/// [`Srgba<u8>`](type.Srgba.html) instead. Invalid input is a compile error.
+///
+/// ```
+/// use palette::{srgb, Srgb, Srgba};
+///
+/// const DODGER_BLUE: Srgb<u8> = srgb!("#1e90ff");
+/// assert_eq!(DODGER_BLUE, Srgb::new(30, 144, 255));
+///
+/// assert_eq!(srgb!("#f80"), Srgb::new(255, 136, 0));
+/// assert_eq!(srgb!("rgb(30, 144, 255)"), DODGER_BLUE);
+/// assert_eq!(srgb!("rgb(100% 0% 50%)"), Srgb::new(255, 0, 128));
+/// assert_eq!(srgb!("#1e90ff80"), Srgba::new(30, 144, 255, 128));
+/// assert_eq!(srgb!("rgba(30, 144, 255, 0.5)"), Srgba::new(30, 144, 255, 128));
+/// ```
+///
+/// ```compile_fail
+/// let color = palette::srgb!("#1e90fg");
+/// ```
+pub use palette_derive::srgb;
+
+/// Create a [`LinSrgb`](type.LinSrgb.html) constant from a hex code or an
+/// `rgb(...)` function, which is checked and decoded into linear values at
+/// compile time.
+///
+/// The input is the same as for [`srgb!`](macro.srgb.html). The component
+/// type is inferred, and defaults to `f64` if nothing decides it.
+///
+/// ```
+/// use palette::{lin_srgb, LinSrgb, Srgb};
+///
+/// const DODGER_BLUE: LinSrgb = lin_srgb!("#1e90ff");
+/// assert_eq!(
+///     Srgb::from_linear(DODGER_BLUE).into_format(),
+///     Srgb::new(30u8, 144, 255)
+/// );
+///
+/// let white: LinSrgb<f64> = lin_srgb!("rgb(255 255 255)");
+/// assert_eq!(white, LinSrgb::new(1.0, 1.0, 1.0));
+/// ```
+pub use palette_derive::lin_srgb;
 
 mod packed;
 mod rgb;
+mod soft_clip;
 
 /// Nonlinear sRGB.
 pub type Srgb<T = f32> = Rgb<encoding::Srgb, T>;
@@ -1,5 +1,6 @@
 pub mod channels;
 
+use core::fmt;
 use core::marker::PhantomData;
 
 use crate::rgb::{Rgb, RgbStandard, Rgba};
@@ -167,6 +168,20 @@ where
     }
 }
 
+impl<C> fmt::LowerHex for Packed<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let size = f.width().unwrap_or(8);
+        write!(f, "{:0width$x}", self.color, width = size)
+    }
+}
+
+impl<C> fmt::UpperHex for Packed<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let size = f.width().unwrap_or(8);
+        write!(f, "{:0width$X}", self.color, width = size)
+    }
+}
+
 #[cfg(feature = "bytemuck")]
 unsafe impl<C> bytemuck::Zeroable for Packed<C> {}
 #[cfg(feature = "bytemuck")]
@@ -342,4 +357,14 @@ mod test {
         assert_eq!(0xFFFF_FF80, u32::from(Srgb::new(255u8, 255, 128)));
         assert_eq!(0x7FFF_FF80, u32::from(Srgba::new(127u8, 255u8, 255, 128)));
     }
+
+    #[test]
+    fn hex() {
+        let packed = Packed::<Argb>::from(Srgb::new(1u8, 35, 171));
+        assert_eq!(format!("{:x}", packed), "ff0123ab");
+        assert_eq!(
+            format!("{:X}", Packed::<Rgba>::from(0x0012_ABCD)),
+            "0012ABCD"
+        );
+    }
 }
@@ -1,7 +1,6 @@
 use core::any::TypeId;
 use core::fmt;
 use core::marker::PhantomData;
-use core::num::ParseIntError;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use core::str::FromStr;
 
@@ -15,18 +14,20 @@ use rand::Rng;
 
 use crate::alpha::Alpha;
 use crate::blend::PreAlpha;
-use crate::convert::FromColorUnclamped;
+use crate::convert::{FromColor, FromColorUnclamped};
 use crate::encoding::linear::LinearFn;
 use crate::encoding::pixel::RawPixel;
 use crate::encoding::{Linear, Srgb};
 use crate::luma::LumaStandard;
+use crate::math::atan2;
 use crate::matrix::{matrix_inverse, multiply_xyz_to_rgb, rgb_to_xyz_matrix};
 use crate::rgb::{Packed, RgbChannels, RgbSpace, RgbStandard, TransferFn};
 use crate::{
-    clamp, contrast_ratio, from_f64, Blend, Clamp, Component, ComponentWise, FloatComponent,
-    FromComponent, GetHue, Mix, Pixel, RelativeContrast, Shade,
+    clamp, from_f64, Blend, Clamp, Complement, Component, ComponentWise, FloatComponent,
+    FromComponent, GetHue, IntoComponentRounded, Invert, Mix, Pixel, RelativeContrast, Rounding,
+    Shade,
 };
-use crate::{Hsl, Hsv, Luma, RgbHue, Xyz};
+use crate::{Hsl, Hsv, Hwb, Luma, RgbHue, Xyz};
 
 /// Generic RGB with an alpha component. See the [`Rgba` implementation in
 /// `Alpha`](crate::Alpha#Rgba).
@@ -104,6 +105,32 @@ impl<S: RgbStandard, T: Component> Rgb<S, T> {
         }
     }
 
+    /// Convert into an integer component type, with a chosen rounding mode.
+    ///
+    /// The components are converted in the order red, green, blue. See
+    /// [`IntoComponentRounded`](crate::IntoComponentRounded) for the exact
+    /// formula.
+    ///
+    /// ```
+    /// use palette::{RoundFloor, Srgb};
+    ///
+    /// let color = Srgb::new(0.999f32, 0.5, 0.0).into_format_rounded::<u8, _>(&mut RoundFloor);
+    /// assert_eq!(color, Srgb::new(254, 127, 0));
+    /// ```
+    pub fn into_format_rounded<U, R>(self, rounding: &mut R) -> Rgb<S, U>
+    where
+        U: Component,
+        T: IntoComponentRounded<U>,
+        R: Rounding + ?Sized,
+    {
+        Rgb {
+            red: self.red.into_component_rounded(rounding),
+            green: self.green.into_component_rounded(rounding),
+            blue: self.blue.into_component_rounded(rounding),
+            standard: PhantomData,
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U>(color: Rgb<S, U>) -> Self
     where
@@ -113,6 +140,116 @@ impl<S: RgbStandard, T: Component> Rgb<S, T> {
         color.into_format()
     }
 
+    /// Change the RGB standard without changing the component values.
+    ///
+    /// This does *not* convert the color. It only changes how the values are
+    /// interpreted, which is useful when the actual standard is known from
+    /// somewhere else, such as the metadata of a texture. Use
+    /// [`from_color`](crate::FromColor::from_color) or
+    /// [`into_linear`](#method.into_linear) to convert between standards.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let linear = LinSrgb::new(0.5f32, 0.25, 0.0);
+    /// let srgb: Srgb = linear.reinterpret_standard();
+    /// assert_eq!(srgb, Srgb::new(0.5, 0.25, 0.0));
+    /// ```
+    #[inline]
+    pub fn reinterpret_standard<St: RgbStandard>(self) -> Rgb<St, T> {
+        Rgb {
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+            standard: PhantomData,
+        }
+    }
+
+    /// Cast a slice of colors to a slice of colors with another RGB standard,
+    /// without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors. See
+    /// [`reinterpret_standard`](#method.reinterpret_standard) for more
+    /// details.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let texture = [Srgb::new(0.5f32, 0.25, 0.0), Srgb::new(1.0, 1.0, 1.0)];
+    /// let linear: &[LinSrgb] = Srgb::reinterpret_slice(&texture);
+    /// assert_eq!(linear[0], LinSrgb::new(0.5, 0.25, 0.0));
+    /// ```
+    #[inline]
+    pub fn reinterpret_slice<St: RgbStandard>(colors: &[Self]) -> &[Rgb<St, T>] {
+        // Safe because `Rgb` is `#[repr(C)]` and the standard is a zero sized
+        // `PhantomData`, so the layout doesn't depend on it.
+        unsafe { core::slice::from_raw_parts(colors.as_ptr() as *const Rgb<St, T>, colors.len()) }
+    }
+
+    /// Cast a mutable slice of colors to a mutable slice of colors with
+    /// another RGB standard, without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors. See
+    /// [`reinterpret_standard`](#method.reinterpret_standard) for more
+    /// details.
+    #[inline]
+    pub fn reinterpret_slice_mut<St: RgbStandard>(colors: &mut [Self]) -> &mut [Rgb<St, T>] {
+        // Safe for the same reason as in `reinterpret_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(colors.as_mut_ptr() as *mut Rgb<St, T>, colors.len())
+        }
+    }
+
+    /// Convert into another color space and component type in one step.
+    ///
+    /// The components are first converted to the floating point type `U`,
+    /// which is then used for converting into `C`. This is the same as
+    /// `C::from_color(self.into_format::<U>())`, but without having to spell
+    /// out the intermediate RGB type.
+    ///
+    /// It's only a convenience wrapper for those two conversions, and it isn't
+    /// faster than calling them separately. The color space conversion needs
+    /// floating point components, so there's no shortcut for integer colors.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Lab, Srgb};
+    ///
+    /// let lab: Lab = Srgb::new(255u8, 0, 0).into_color_format();
+    /// assert_relative_eq!(lab, Lab::new(53.2408, 80.0925, 67.2032), epsilon = 0.01);
+    /// ```
+    pub fn into_color_format<C, U>(self) -> C
+    where
+        U: FloatComponent + FromComponent<T>,
+        C: FromColor<Rgb<S, U>>,
+    {
+        C::from_color(self.into_format())
+    }
+
+    /// Convert from another color space and component type in one step.
+    ///
+    /// The color is first converted into RGB with the floating point component
+    /// type `U`, which is then converted to `T`. This is the same as
+    /// `Rgb::<S, U>::from_color(color).into_format()`, but without having to
+    /// spell out the intermediate RGB type. Like
+    /// [`into_color_format`](#method.into_color_format), it only chains the
+    /// two conversions.
+    ///
+    /// ```
+    /// use palette::{Lab, Srgb};
+    ///
+    /// let rgb = Srgb::<u8>::from_color_format(Lab::new(53.2408, 80.0925, 67.2032));
+    /// assert_eq!(rgb, Srgb::new(255u8, 0, 0));
+    /// ```
+    pub fn from_color_format<C, U>(color: C) -> Self
+    where
+        U: FloatComponent,
+        T: FromComponent<U>,
+        Rgb<S, U>: FromColor<C>,
+    {
+        Rgb::<S, U>::from_color(color).into_format()
+    }
+
     /// Convert to a `(red, green, blue)` tuple.
     pub fn into_components(self) -> (T, T, T) {
         (self.red, self.green, self.blue)
@@ -236,6 +373,53 @@ impl<S: RgbStandard, T: FloatComponent> Rgb<S, T> {
             S::TransferFn::from_linear(St::TransferFn::into_linear(color.blue)),
         )
     }
+
+    /// Convert the color to HSV, using `hue_hint` as the hue if the color is
+    /// a shade of gray.
+    ///
+    /// Black, white and grays don't have a hue, so a plain conversion sets
+    /// it to `0.0`. This loses the hue when a color goes back and forth
+    /// between RGB and HSV, such as in a color picker where the user drags
+    /// the value down to black. Passing the hue from before the change keeps
+    /// it instead.
+    ///
+    /// ```
+    /// use palette::{Hsv, Srgb};
+    ///
+    /// let previous = Hsv::new(200.0, 0.8, 0.6);
+    /// let black = Srgb::new(0.0, 0.0, 0.0);
+    ///
+    /// let hsv = black.into_hsv_with_hue_hint(previous.hue);
+    /// assert_eq!(hsv.hue, 200.0);
+    /// assert_eq!(hsv.value, 0.0);
+    /// ```
+    pub fn into_hsv_with_hue_hint<H: Into<RgbHue<T>>>(self, hue_hint: H) -> Hsv<S, T> {
+        let mut hsv = Hsv::from_color(self);
+        if hsv.saturation <= T::zero() {
+            hsv.hue = hue_hint.into();
+        }
+        hsv
+    }
+
+    /// Convert the color to HSL, using `hue_hint` as the hue if the color is
+    /// a shade of gray. See
+    /// [`into_hsv_with_hue_hint`](#method.into_hsv_with_hue_hint) for more
+    /// details.
+    pub fn into_hsl_with_hue_hint<H: Into<RgbHue<T>>>(self, hue_hint: H) -> Hsl<S, T> {
+        let mut hsl = Hsl::from_color(self);
+        if hsl.saturation <= T::zero() {
+            hsl.hue = hue_hint.into();
+        }
+        hsl
+    }
+
+    /// Convert the color to HWB, using `hue_hint` as the hue if the color is
+    /// a shade of gray. See
+    /// [`into_hsv_with_hue_hint`](#method.into_hsv_with_hue_hint) for more
+    /// details.
+    pub fn into_hwb_with_hue_hint<H: Into<RgbHue<T>>>(self, hue_hint: H) -> Hwb<S, T> {
+        Hwb::from_color(self.into_hsv_with_hue_hint(hue_hint))
+    }
 }
 
 impl<S: RgbStandard, T: Component> Rgb<S, T> {
@@ -277,6 +461,25 @@ impl<S: RgbStandard, T: Component, A: Component> Alpha<Rgb<S, T>, A> {
         )
     }
 
+    /// Convert into an integer component type, with a chosen rounding mode.
+    ///
+    /// The components are converted in the order red, green, blue, alpha. See
+    /// [`IntoComponentRounded`](crate::IntoComponentRounded) for the exact
+    /// formula.
+    pub fn into_format_rounded<U, B, R>(self, rounding: &mut R) -> Alpha<Rgb<S, U>, B>
+    where
+        U: Component,
+        B: Component,
+        T: IntoComponentRounded<U>,
+        A: IntoComponentRounded<B>,
+        R: Rounding + ?Sized,
+    {
+        Alpha {
+            color: self.color.into_format_rounded(rounding),
+            alpha: self.alpha.into_component_rounded(rounding),
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U, B>(color: Alpha<Rgb<S, U>, B>) -> Self
     where
@@ -288,6 +491,94 @@ impl<S: RgbStandard, T: Component, A: Component> Alpha<Rgb<S, T>, A> {
         color.into_format()
     }
 
+    /// Change the RGB standard without changing the component values.
+    ///
+    /// This does *not* convert the color. See
+    /// [`Rgb::reinterpret_standard`](crate::rgb::Rgb::reinterpret_standard)
+    /// for more details.
+    #[inline]
+    pub fn reinterpret_standard<St: RgbStandard>(self) -> Alpha<Rgb<St, T>, A> {
+        Alpha {
+            color: self.color.reinterpret_standard(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Cast a slice of colors to a slice of colors with another RGB standard,
+    /// without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors. See
+    /// [`Rgb::reinterpret_standard`](crate::rgb::Rgb::reinterpret_standard)
+    /// for more details.
+    #[inline]
+    pub fn reinterpret_slice<St: RgbStandard>(colors: &[Self]) -> &[Alpha<Rgb<St, T>, A>] {
+        // Safe because `Alpha` and `Rgb` are `#[repr(C)]` and the standard is
+        // a zero sized `PhantomData`, so the layout doesn't depend on it.
+        unsafe {
+            core::slice::from_raw_parts(
+                colors.as_ptr() as *const Alpha<Rgb<St, T>, A>,
+                colors.len(),
+            )
+        }
+    }
+
+    /// Cast a mutable slice of colors to a mutable slice of colors with
+    /// another RGB standard, without copying or changing the component values.
+    ///
+    /// This does *not* convert the colors. See
+    /// [`Rgb::reinterpret_standard`](crate::rgb::Rgb::reinterpret_standard)
+    /// for more details.
+    #[inline]
+    pub fn reinterpret_slice_mut<St: RgbStandard>(
+        colors: &mut [Self],
+    ) -> &mut [Alpha<Rgb<St, T>, A>] {
+        // Safe for the same reason as in `reinterpret_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                colors.as_mut_ptr() as *mut Alpha<Rgb<St, T>, A>,
+                colors.len(),
+            )
+        }
+    }
+
+    /// Convert into another color space and component type in one step.
+    ///
+    /// Both the color components and the alpha component are first converted
+    /// to the floating point type `U`, which is then used for converting into
+    /// `C`. This is a convenience wrapper for
+    /// `C::from_color(self.into_format::<U, U>())`, and does the same work.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use palette::{Laba, Srgba};
+    ///
+    /// let lab: Laba = Srgba::new(255u8, 0, 0, 255).into_color_format();
+    /// assert_relative_eq!(lab, Laba::new(53.2408, 80.0925, 67.2032, 1.0), epsilon = 0.01);
+    /// ```
+    pub fn into_color_format<C, U>(self) -> C
+    where
+        U: FloatComponent + FromComponent<T> + FromComponent<A>,
+        C: FromColor<Alpha<Rgb<S, U>, U>>,
+    {
+        C::from_color(self.into_format())
+    }
+
+    /// Convert from another color space and component type in one step.
+    ///
+    /// The color is first converted into RGB with the floating point component
+    /// type `U`, for both the color and the alpha component, which are then
+    /// converted to `T` and `A`. This is a convenience wrapper for those two
+    /// conversions, and does the same work.
+    pub fn from_color_format<C, U>(color: C) -> Self
+    where
+        U: FloatComponent,
+        T: FromComponent<U>,
+        A: FromComponent<U>,
+        Alpha<Rgb<S, U>, U>: FromColor<C>,
+    {
+        Alpha::<Rgb<S, U>, U>::from_color(color).into_format()
+    }
+
     /// Convert to a `(red, green, blue, alpha)` tuple.
     pub fn into_components(self) -> (T, T, T, A) {
         (self.red, self.green, self.blue, self.alpha)
@@ -371,6 +662,29 @@ impl<S: RgbStandard, T: FloatComponent, A: Component> Alpha<Rgb<S, T>, A> {
     }
 }
 
+impl<S: RgbStandard, T: FloatComponent> Alpha<Rgb<S, T>, T> {
+    /// Decode the color and premultiply it with its alpha. Premultiplying
+    /// encoded components gives dark fringes when the result is blended, so
+    /// the components are made linear first.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgba};
+    ///
+    /// let premultiplied = Srgba::new(1.0, 0.5, 0.0, 0.5).into_linear_premultiplied();
+    /// assert_eq!(premultiplied.alpha, 0.5);
+    /// assert_eq!(premultiplied.color.red, 0.5);
+    /// ```
+    pub fn into_linear_premultiplied(self) -> PreAlpha<Rgb<Linear<S::Space>, T>, T> {
+        self.into_linear().into()
+    }
+
+    /// Unpremultiply a linear color and encode it. This is the inverse of
+    /// [`into_linear_premultiplied`](#method.into_linear_premultiplied).
+    pub fn from_linear_premultiplied(color: PreAlpha<Rgb<Linear<S::Space>, T>, T>) -> Self {
+        Self::from_linear(color.into())
+    }
+}
+
 impl<S1, S2, T> FromColorUnclamped<Rgb<S2, T>> for Rgb<S1, T>
 where
     S1: RgbStandard,
@@ -592,10 +906,10 @@ where
         if self.red == self.green && self.red == self.blue {
             None
         } else {
-            Some(RgbHue::from_radians(
-                (sqrt_3 * (self.green - self.blue))
-                    .atan2(self.red * from_f64(2.0) - self.green - self.blue),
-            ))
+            Some(RgbHue::from_radians(atan2(
+                sqrt_3 * (self.green - self.blue),
+                self.red * from_f64(2.0) - self.green - self.blue,
+            )))
         }
     }
 }
@@ -646,6 +960,49 @@ where
     }
 }
 
+impl<S, T> Invert for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component + Sub<Output = T>,
+{
+    fn invert(&self) -> Rgb<S, T> {
+        Rgb {
+            red: T::max_intensity() - self.red,
+            green: T::max_intensity() - self.green,
+            blue: T::max_intensity() - self.blue,
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Complement for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component + Sub<Output = T>,
+{
+    fn complement(&self) -> Rgb<S, T> {
+        let mut min = self.red;
+        let mut max = self.red;
+
+        for &component in &[self.green, self.blue] {
+            if component < min {
+                min = component;
+            }
+            if component > max {
+                max = component;
+            }
+        }
+
+        // Same as `max + min - component`, but without overflowing integers.
+        Rgb {
+            red: max - (self.red - min),
+            green: max - (self.green - min),
+            blue: max - (self.blue - min),
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Default for Rgb<S, T>
 where
     T: Component,
@@ -776,6 +1133,8 @@ where
     }
 }
 
+impl_integer_arithmetic!(Rgb<S: RgbStandard>, [red, green, blue], standard);
+
 impl<S, T> Mul<Rgb<S, T>> for Rgb<S, T>
 where
     S: RgbStandard<TransferFn = LinearFn>,
@@ -1061,73 +1420,112 @@ where
     }
 }
 
-/// Error type for parsing a string of hexadecimal characters to an `Rgb` color.
-#[derive(Debug)]
+/// Error type for parsing a string of hexadecimal characters to an `Rgb` or
+/// `Rgba` color.
+///
+/// More kinds of errors may be added in the future, so matches on it need a
+/// wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FromHexError {
-    /// An error occurred while parsing the string into a valid integer.
-    ParseIntError(ParseIntError),
-    /// The hex value was not in a valid 3 or 6 character format.
-    HexFormatError(&'static str),
+    /// A character that is not a hexadecimal digit was found.
+    InvalidDigit {
+        /// The byte offset of the character in the input string, including
+        /// any prefix.
+        position: usize,
+        /// The invalid character.
+        character: char,
+    },
+    /// The number of hexadecimal digits was not one of the supported lengths.
+    InvalidLength(usize),
 }
 
-impl From<ParseIntError> for FromHexError {
-    fn from(err: ParseIntError) -> FromHexError {
-        FromHexError::ParseIntError(err)
-    }
-}
-
-impl From<&'static str> for FromHexError {
-    fn from(err: &'static str) -> FromHexError {
-        FromHexError::HexFormatError(err)
-    }
-}
 impl core::fmt::Display for FromHexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &*self {
-            FromHexError::ParseIntError(e) => write!(f, "{}", e),
-            FromHexError::HexFormatError(s) => write!(
+        match *self {
+            FromHexError::InvalidDigit {
+                position,
+                character,
+            } => write!(
                 f,
-                "{}, please use format '#fff', 'fff', '#ffffff' or 'ffffff'.",
-                s
+                "invalid hex digit {:?} at position {}",
+                character, position
+            ),
+            FromHexError::InvalidLength(length) => write!(
+                f,
+                "invalid hex code length {}, expected 3 or 6 digits, or 4 or 8 digits with alpha",
+                length
             ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for FromHexError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &*self {
-            FromHexError::HexFormatError(_s) => None,
-            FromHexError::ParseIntError(e) => Some(e),
+impl std::error::Error for FromHexError {}
+
+/// Parse a hex code with 3 or 6 digits, or 4 or 8 digits if `with_alpha` is
+/// set, and an optional `#` or `0x` prefix, into its red, green, blue and
+/// alpha values.
+fn parse_hex(hex: &str, with_alpha: bool) -> Result<[u8; 4], FromHexError> {
+    let prefix_length = if hex.starts_with('#') {
+        1
+    } else if hex.starts_with("0x") || hex.starts_with("0X") {
+        2
+    } else {
+        0
+    };
+
+    let mut digits = [0xfu8; 8];
+    let mut length = 0;
+    for (position, character) in hex.char_indices().skip(prefix_length) {
+        let digit = character.to_digit(16).ok_or(FromHexError::InvalidDigit {
+            position,
+            character,
+        })?;
+
+        if let Some(slot) = digits.get_mut(length) {
+            *slot = digit as u8;
         }
+        length += 1;
+    }
+
+    match length {
+        3 | 4 if length == 3 || with_alpha => Ok([
+            digits[0] * 17,
+            digits[1] * 17,
+            digits[2] * 17,
+            digits[3] * 17,
+        ]),
+        6 | 8 if length == 6 || with_alpha => Ok([
+            digits[0] << 4 | digits[1],
+            digits[2] << 4 | digits[3],
+            digits[4] << 4 | digits[5],
+            digits[6] << 4 | digits[7],
+        ]),
+        length => Err(FromHexError::InvalidLength(length)),
     }
 }
 
 impl<S: RgbStandard> FromStr for Rgb<S, u8> {
     type Err = FromHexError;
 
-    // Parses a color hex code of format '#ff00bb' or '#abc' into a
-    // Rgb<S, u8> instance.
+    // Parses a color hex code of format '#ff00bb' or '#abc', optionally
+    // prefixed with '0x' instead of '#', into a Rgb<S, u8> instance.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 => {
-                let red = u8::from_str_radix(&hex_code[..1], 16)?;
-                let green = u8::from_str_radix(&hex_code[1..2], 16)?;
-                let blue = u8::from_str_radix(&hex_code[2..3], 16)?;
-                let col: Rgb<S, u8> = Rgb::new(red * 17, green * 17, blue * 17);
-                Ok(col)
-            }
-            6 => {
-                let red = u8::from_str_radix(&hex_code[..2], 16)?;
-                let green = u8::from_str_radix(&hex_code[2..4], 16)?;
-                let blue = u8::from_str_radix(&hex_code[4..6], 16)?;
-                let col: Rgb<S, u8> = Rgb::new(red, green, blue);
-                Ok(col)
-            }
-            _ => Err("invalid hex code format".into()),
-        }
+        let [red, green, blue, _] = parse_hex(hex, false)?;
+        Ok(Rgb::new(red, green, blue))
+    }
+}
+
+impl<S: RgbStandard> FromStr for Rgba<S, u8> {
+    type Err = FromHexError;
+
+    // Parses a color hex code of format '#ff00bb80', '#ff00bb', '#abc8' or
+    // '#abc', optionally prefixed with '0x' instead of '#', into a
+    // Rgba<S, u8> instance. The alpha is 255 if it's omitted.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let [red, green, blue, alpha] = parse_hex(hex, true)?;
+        Ok(Rgba::new(red, green, blue, alpha))
     }
 }
 
@@ -1138,13 +1536,10 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn relative_luminance(&self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
+        Xyz::from_color(*self).y
     }
 }
 
@@ -1256,7 +1651,7 @@ where
 mod test {
     use core::str::FromStr;
 
-    use super::{Rgb, Rgba};
+    use super::{FromHexError, Rgb, Rgba};
     use crate::encoding::Srgb;
     use crate::rgb::packed::channels;
 
@@ -1367,6 +1762,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn hue_hint() {
+        let hint = 120.0;
+
+        let black = Rgb::<Srgb, f64>::new(0.0, 0.0, 0.0);
+        assert_eq!(black.into_hsv_with_hue_hint(hint).hue, 120.0);
+        assert_eq!(black.into_hwb_with_hue_hint(hint).hue, 120.0);
+
+        let gray = Rgb::<Srgb, f64>::new(0.5, 0.5, 0.5);
+        let hsl = gray.into_hsl_with_hue_hint(hint);
+        assert_eq!(hsl.hue, 120.0);
+        assert_eq!(hsl.lightness, 0.5);
+
+        // Colors with a hue ignore the hint.
+        let red = Rgb::<Srgb, f64>::new(1.0, 0.0, 0.0);
+        assert_eq!(red.into_hsv_with_hue_hint(hint).hue, 0.0);
+        assert_eq!(red.into_hsl_with_hue_hint(hint).hue, 0.0);
+    }
+
     #[test]
     fn rgb_hex_into_from() {
         let c1 = Rgb::<Srgb, u8>::from_u32::<channels::Argb>(0x1100_7FFF);
@@ -1431,7 +1845,7 @@ mod test {
         assert!(c.is_err());
         assert_eq!(
             format!("{}", c.err().unwrap()),
-            "invalid digit found in string"
+            "invalid hex digit 'i' at position 1"
         );
         let c = Rgb::<Srgb, u8>::from_str("#08f");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(0, 136, 255));
@@ -1443,8 +1857,7 @@ mod test {
         assert!(c.is_err());
         assert_eq!(
             format!("{}", c.err().unwrap()),
-            "invalid hex code format, \
-             please use format \'#fff\', \'fff\', \'#ffffff\' or \'ffffff\'."
+            "invalid hex code length 2, expected 3 or 6 digits, or 4 or 8 digits with alpha"
         );
         let c = Rgb::<Srgb, u8>::from_str("da0bce");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(218, 11, 206));
@@ -1452,6 +1865,68 @@ mod test {
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(240, 52, 230));
         let c = Rgb::<Srgb, u8>::from_str("abc");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(170, 187, 204));
+        let c = Rgb::<Srgb, u8>::from_str("0xDA0BCE");
+        assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(218, 11, 206));
+        let c = Rgb::<Srgb, u8>::from_str("#abcd");
+        assert_eq!(c, Err(FromHexError::InvalidLength(4)));
+        let c = Rgb::<Srgb, u8>::from_str("#da0bce80");
+        assert_eq!(c, Err(FromHexError::InvalidLength(8)));
+    }
+
+    #[test]
+    fn reinterpret_slice() {
+        use crate::encoding::Linear;
+
+        let mut colors = [
+            Rgba::<Srgb, f32>::new(0.1, 0.2, 0.3, 0.4),
+            Rgba::new(0.5, 0.6, 0.7, 0.8),
+        ];
+
+        {
+            let linear = Rgba::<Srgb, f32>::reinterpret_slice_mut::<Linear<Srgb>>(&mut colors);
+            assert_eq!(linear[1], Rgba::new(0.5, 0.6, 0.7, 0.8));
+            linear[0].red = 1.0;
+        }
+
+        let linear = Rgba::<Srgb, f32>::reinterpret_slice::<Linear<Srgb>>(&colors);
+        assert_eq!(linear.len(), 2);
+        assert_eq!(linear[0], Rgba::new(1.0, 0.2, 0.3, 0.4));
+        assert_eq!(
+            linear[0].reinterpret_standard::<Srgb>(),
+            Rgba::<Srgb, f32>::new(1.0, 0.2, 0.3, 0.4)
+        );
+    }
+
+    #[test]
+    fn from_str_alpha() {
+        let c = Rgba::<Srgb, u8>::from_str("#abc");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(170, 187, 204, 255));
+        let c = Rgba::<Srgb, u8>::from_str("#abc8");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(170, 187, 204, 136));
+        let c = Rgba::<Srgb, u8>::from_str("da0bce");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(218, 11, 206, 255));
+        let c = Rgba::<Srgb, u8>::from_str("0xda0bce80");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(218, 11, 206, 128));
+        let c = Rgba::<Srgb, u8>::from_str("#da0bce800");
+        assert_eq!(c, Err(FromHexError::InvalidLength(9)));
+        let c = Rgba::<Srgb, u8>::from_str("#");
+        assert_eq!(c, Err(FromHexError::InvalidLength(0)));
+        let c = Rgba::<Srgb, u8>::from_str("0xda0bcé80");
+        assert_eq!(
+            c,
+            Err(FromHexError::InvalidDigit {
+                position: 7,
+                character: 'é'
+            })
+        );
+        let c = Rgba::<Srgb, u8>::from_str("#ff00ff00 ");
+        assert_eq!(
+            c,
+            Err(FromHexError::InvalidDigit {
+                position: 9,
+                character: ' '
+            })
+        );
     }
 
     #[test]
@@ -1464,6 +1939,43 @@ mod test {
         assert_relative_eq!(Rgb::<Srgb, f32>::max_blue(), 1.0);
     }
 
+    #[test]
+    fn invert_and_complement() {
+        use crate::{Complement, FromColor, Hsv, Hue, Invert};
+
+        let color = Rgb::<Srgb, u8>::new(250, 100, 0);
+        assert_eq!(color.invert(), Rgb::new(5, 155, 255));
+        assert_eq!(color.complement(), Rgb::new(0, 150, 250));
+        assert_eq!(color.complement().complement(), color);
+
+        let color = Rgba::<Srgb, f64>::new(0.8, 0.3, 0.5, 0.4);
+        let rotated = Rgb::from_color(Hsv::from_color(color.color).shift_hue(180.0));
+        assert_relative_eq!(color.complement().color, rotated, epsilon = 1e-10);
+        assert_relative_eq!(
+            color.invert(),
+            Rgba::new(0.2, 0.7, 0.5, 0.4),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn integer_arithmetic() {
+        use crate::{CheckedArithmetic, SaturatingArithmetic, WrappingArithmetic};
+
+        let a = Rgba::<Srgb, u8>::new(250, 100, 0, 200);
+        let b = Rgba::<Srgb, u8>::new(10, 10, 10, 10);
+
+        assert_eq!(a.saturating_add(b), Rgba::new(255, 110, 10, 210));
+        assert_eq!(a.saturating_sub(b), Rgba::new(240, 90, 0, 190));
+        assert_eq!(a.saturating_add(60), Rgba::new(255, 160, 60, 255));
+        assert_eq!(a.wrapping_add(b), Rgba::new(4, 110, 10, 210));
+        assert_eq!(a.wrapping_sub(1), Rgba::new(249, 99, 255, 199));
+        assert_eq!(a.checked_add(5), Some(Rgba::new(255, 105, 5, 205)));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(b.checked_sub(10), Some(Rgba::new(0, 0, 0, 0)));
+    }
+
     #[cfg(feature = "random")]
     test_uniform_distribution! {
         Rgb<Srgb, f32> {
@@ -0,0 +1,172 @@
+use crate::encoding::linear::LinearFn;
+use crate::math::exp;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{from_f64, FloatComponent};
+
+/// Smooth clipping of linear RGB values into the `0.0` to `1.0` range.
+///
+/// Hard clipping of high dynamic range intermediates, such as the result of
+/// lighting calculations or exposure adjustments, creates flat areas and
+/// visible edges where the values leave the displayable range. `SoftClip`
+/// instead compresses the values near the ends of the range, so that they
+/// approach `0.0` and `1.0` without ever crossing them:
+///
+/// * Values above the shoulder knee are rolled off exponentially toward
+///   `1.0`.
+/// * Values below the toe knee are rolled off exponentially toward `0.0`.
+/// * Values between the knees are left unchanged.
+///
+/// The curve is continuous and has a continuous slope at the knees. Each
+/// channel is processed independently.
+///
+/// ```
+/// use palette::rgb::SoftClip;
+/// use palette::LinSrgb;
+///
+/// let soft_clip = SoftClip::new(0.05, 0.8);
+/// let color = soft_clip.apply(LinSrgb::new(4.0, 0.5, -0.2));
+///
+/// assert!(color.red > 0.8 && color.red < 1.0);
+/// assert_eq!(color.green, 0.5);
+/// assert!(color.blue > 0.0 && color.blue < 0.05);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoftClip<T = f32> {
+    toe: T,
+    shoulder: T,
+}
+
+impl<T: FloatComponent> SoftClip<T> {
+    /// Create a soft clipping curve with knees at `toe` and `shoulder`.
+    ///
+    /// A `toe` of `0.0` or a `shoulder` of `1.0` gives a hard clip at that end
+    /// of the range.
+    ///
+    /// Panics unless `0.0 <= toe <= shoulder <= 1.0`.
+    pub fn new(toe: T, shoulder: T) -> Self {
+        assert!(T::zero() <= toe && toe <= shoulder && shoulder <= T::one());
+
+        SoftClip { toe, shoulder }
+    }
+
+    /// The knee point where the roll-off toward `0.0` starts.
+    pub fn toe(&self) -> T {
+        self.toe
+    }
+
+    /// The knee point where the roll-off toward `1.0` starts.
+    pub fn shoulder(&self) -> T {
+        self.shoulder
+    }
+
+    /// Apply the curve to a single value.
+    pub fn apply_component(&self, x: T) -> T {
+        if x > self.shoulder {
+            let range = T::one() - self.shoulder;
+            if range > T::zero() {
+                self.shoulder + range * (T::one() - exp(-(x - self.shoulder) / range))
+            } else {
+                T::one()
+            }
+        } else if x < self.toe {
+            if self.toe > T::zero() {
+                self.toe * exp((x - self.toe) / self.toe)
+            } else {
+                T::zero()
+            }
+        } else {
+            x
+        }
+    }
+
+    /// Apply the curve to each channel of a linear RGB color.
+    pub fn apply<S>(&self, color: Rgb<S, T>) -> Rgb<S, T>
+    where
+        S: RgbStandard<TransferFn = LinearFn>,
+    {
+        Rgb::new(
+            self.apply_component(color.red),
+            self.apply_component(color.green),
+            self.apply_component(color.blue),
+        )
+    }
+
+    /// Apply the curve to each channel of each color in a slice of linear RGB
+    /// colors.
+    pub fn apply_slice<S>(&self, colors: &mut [Rgb<S, T>])
+    where
+        S: RgbStandard<TransferFn = LinearFn>,
+    {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl<T: FloatComponent> Default for SoftClip<T> {
+    /// A curve with the toe knee at `0.02` and the shoulder knee at `0.8`.
+    fn default() -> Self {
+        SoftClip::new(from_f64(0.02), from_f64(0.8))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SoftClip;
+    use crate::LinSrgb;
+
+    #[test]
+    fn stays_in_range() {
+        let soft_clip = SoftClip::<f64>::default();
+
+        for i in -100..=400 {
+            let x = i as f64 / 20.0;
+            let y = soft_clip.apply_component(x);
+            assert!(y >= 0.0 && y <= 1.0, "{} -> {}", x, y);
+        }
+    }
+
+    #[test]
+    fn monotonic_and_continuous() {
+        let soft_clip = SoftClip::<f64>::new(0.1, 0.7);
+        let mut previous = soft_clip.apply_component(-1.0);
+
+        for i in 1..=3000 {
+            let x = -1.0 + i as f64 / 1000.0;
+            let y = soft_clip.apply_component(x);
+            assert!(y >= previous);
+            assert!(y - previous < 0.0011);
+            previous = y;
+        }
+
+        assert_relative_eq!(soft_clip.apply_component(0.1), 0.1);
+        assert_relative_eq!(soft_clip.apply_component(0.7), 0.7);
+        assert_relative_eq!(soft_clip.apply_component(0.4), 0.4);
+    }
+
+    #[test]
+    fn hard_clip() {
+        let soft_clip = SoftClip::<f64>::new(0.0, 1.0);
+
+        assert_relative_eq!(
+            soft_clip.apply(LinSrgb::new(-0.5, 0.5, 1.5)),
+            LinSrgb::new(0.0, 0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn slice() {
+        let soft_clip = SoftClip::default();
+        let mut colors = [LinSrgb::new(2.0, 0.5, -1.0), LinSrgb::new(0.3, 10.0, 0.2)];
+        soft_clip.apply_slice(&mut colors);
+
+        assert_eq!(colors[0], soft_clip.apply(LinSrgb::new(2.0, 0.5, -1.0)));
+        assert_eq!(colors[1], soft_clip.apply(LinSrgb::new(0.3, 10.0, 0.2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_knees() {
+        SoftClip::new(0.9, 0.5);
+    }
+}
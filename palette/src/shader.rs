@@ -0,0 +1,340 @@
+//! Shader code for color conversions.
+//!
+//! Applications that convert colors on both the CPU and the GPU need both
+//! paths to give the same results, or colors will shift between them. This
+//! module writes WGSL or GLSL functions for a chain of conversions, using
+//! the same formulas and constants as the conversions in this library:
+//!
+//! ```
+//! use palette::shader::{generate, Language, Space};
+//!
+//! let wgsl = generate(Language::Wgsl, &[Space::Srgb, Space::LinearSrgb, Space::Xyz, Space::Oklab])
+//!     .unwrap();
+//!
+//! assert!(wgsl.contains("fn srgb_to_linear_srgb(c: vec3<f32>) -> vec3<f32>"));
+//! assert!(wgsl.contains("fn srgb_to_oklab(c: vec3<f32>) -> vec3<f32>"));
+//! ```
+//!
+//! Each step of the chain becomes its own function, named
+//! `<from>_to_<to>`, and a chain with more than one step gets a function
+//! from the first space to the last. Each pair of neighboring spaces in the
+//! chain must have a direct conversion, as described by the
+//! [`graph`](../graph/index.html) module:
+//!
+//! * `Srgb` and `LinearSrgb`, which is the sRGB transfer function.
+//! * `LinearSrgb` and `Xyz`.
+//! * `Xyz` and `Lab`, as well as `Lab` and `Lch`.
+//! * `Xyz` and `Oklab`, as well as `Oklab` and `Oklch`.
+//!
+//! [`generate_path`](fn.generate_path.html) finds the chain between two
+//! spaces instead.
+//!
+//! The colors are `vec3` values with the components in the same order and
+//! range as in the corresponding types, including hues in degrees. All of
+//! the spaces use the D65 white point.
+//!
+//! This module is only available if the `shader` feature is enabled.
+
+use std::fmt::Write;
+
+use crate::error::ShaderError;
+use crate::graph::{path, step, Operation};
+
+pub use crate::graph::Space;
+
+/// A shading language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    /// The WebGPU Shading Language.
+    Wgsl,
+    /// The OpenGL Shading Language, version 3.30 or ES 3.00 and later.
+    Glsl,
+}
+
+/// Generate the functions for converting along `chain`, in `language`.
+///
+/// Returns an error if `chain` has fewer than two spaces, or if two
+/// neighboring spaces don't have a direct conversion.
+pub fn generate(language: Language, chain: &[Space]) -> Result<String, ShaderError> {
+    if chain.len() < 2 {
+        return Err(ShaderError::TooShort);
+    }
+
+    let code = Code { language };
+    let mut output = String::new();
+
+    for pair in chain.windows(2) {
+        let step = step(pair[0], pair[1]).ok_or(ShaderError::UnsupportedStep {
+            from: pair[0],
+            to: pair[1],
+        })?;
+        output.push_str(&code.function(step.from, step.to, &code.body(&step.operations)));
+        output.push('\n');
+    }
+
+    if chain.len() > 2 {
+        let mut call = String::from("c");
+        for pair in chain.windows(2) {
+            call = format!("{}_to_{}({})", pair[0].name(), pair[1].name(), call);
+        }
+
+        let first = chain[0];
+        let last = chain[chain.len() - 1];
+        output.push_str(&code.function(first, last, &format!("    return {};\n", call)));
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// Generate the functions for converting from `from` to `to`, in `language`,
+/// along the shortest [`path`](../graph/fn.path.html) between them.
+///
+/// Returns an error if `from` and `to` are the same space.
+///
+/// ```
+/// use palette::shader::{generate_path, Language, Space};
+///
+/// let glsl = generate_path(Language::Glsl, Space::Lch, Space::Srgb).unwrap();
+/// assert!(glsl.contains("vec3 lab_to_xyz(vec3 c) {"));
+/// assert!(glsl.contains("vec3 lch_to_srgb(vec3 c) {"));
+/// ```
+pub fn generate_path(language: Language, from: Space, to: Space) -> Result<String, ShaderError> {
+    let steps = path(from, to).ok_or(ShaderError::UnsupportedStep { from, to })?;
+    let chain: Vec<_> = steps
+        .first()
+        .map(|step| step.from)
+        .into_iter()
+        .chain(steps.iter().map(|step| step.to))
+        .collect();
+
+    generate(language, &chain)
+}
+
+/// Helpers for writing code in a language.
+struct Code {
+    language: Language,
+}
+
+impl Code {
+    fn vec3(&self) -> &'static str {
+        match self.language {
+            Language::Wgsl => "vec3<f32>",
+            Language::Glsl => "vec3",
+        }
+    }
+
+    fn function(&self, from: Space, to: Space, body: &str) -> String {
+        let name = format!("{}_to_{}", from.name(), to.name());
+        match self.language {
+            Language::Wgsl => format!("fn {}(c: vec3<f32>) -> vec3<f32> {{\n{}}}\n", name, body),
+            Language::Glsl => format!("vec3 {}(vec3 c) {{\n{}}}\n", name, body),
+        }
+    }
+
+    /// A vector with all components set to `value`.
+    fn splat(&self, value: f64) -> String {
+        format!("{}({})", self.vec3(), number(value))
+    }
+
+    /// Pick `if_true` or `if_false` for each component, based on `condition`.
+    fn select(&self, if_true: &str, if_false: &str, condition: &str) -> String {
+        match self.language {
+            Language::Wgsl => format!("select({}, {}, {})", if_false, if_true, condition),
+            Language::Glsl => format!("mix({}, {}, {})", if_false, if_true, condition),
+        }
+    }
+
+    /// A component wise comparison.
+    fn less_or_equal(&self, a: &str, b: &str) -> String {
+        match self.language {
+            Language::Wgsl => format!("{} <= {}", a, b),
+            Language::Glsl => format!("lessThanEqual({}, {})", a, b),
+        }
+    }
+
+    fn atan2(&self, y: &str, x: &str) -> String {
+        match self.language {
+            Language::Wgsl => format!("atan2({}, {})", y, x),
+            Language::Glsl => format!("atan({}, {})", y, x),
+        }
+    }
+
+    /// The cube root of each component, which keeps the sign.
+    fn cbrt(&self, value: &str) -> String {
+        format!(
+            "sign({0}) * pow(abs({0}), {1})",
+            value,
+            self.splat(1.0 / 3.0)
+        )
+    }
+
+    /// Multiply a vector with a row major 3x3 matrix.
+    fn multiply(&self, matrix: &[f64; 9], vector: &str) -> String {
+        let rows: Vec<_> = matrix
+            .chunks(3)
+            .map(|row| {
+                format!(
+                    "dot({}({}, {}, {}), {})",
+                    self.vec3(),
+                    number(row[0]),
+                    number(row[1]),
+                    number(row[2]),
+                    vector
+                )
+            })
+            .collect();
+
+        format!("{}({})", self.vec3(), rows.join(", "))
+    }
+
+    /// The body of a function that applies `operations` to `c`.
+    fn body(&self, operations: &[Operation]) -> String {
+        let mut body = match self.language {
+            Language::Wgsl => String::from("    var v = c;\n"),
+            Language::Glsl => String::from("    vec3 v = c;\n"),
+        };
+
+        for &operation in operations {
+            for value in self.operation(operation) {
+                let _ = writeln!(body, "    v = {};", value);
+            }
+        }
+
+        body.push_str("    return v;\n");
+        body
+    }
+
+    /// The values that `v` is set to, in order, to apply `operation` to it.
+    fn operation(&self, operation: Operation) -> Vec<String> {
+        let vec3 = self.vec3();
+        let vector =
+            |[x, y, z]: [f64; 3]| format!("{}({}, {}, {})", vec3, number(x), number(y), number(z));
+
+        match operation {
+            Operation::SrgbDecode => vec![self.select(
+                &format!("v / {}", number(12.92)),
+                &format!(
+                    "pow((v + {}) / {}, {})",
+                    self.splat(0.055),
+                    number(1.055),
+                    self.splat(2.4)
+                ),
+                &self.less_or_equal("v", &self.splat(0.04045)),
+            )],
+            Operation::SrgbEncode => vec![self.select(
+                &format!("v * {}", number(12.92)),
+                &format!(
+                    "pow(v, {}) * {} - {}",
+                    self.splat(1.0 / 2.4),
+                    number(1.055),
+                    self.splat(0.055)
+                ),
+                &self.less_or_equal("v", &self.splat(0.0031308)),
+            )],
+            Operation::Matrix(matrix) => vec![self.multiply(&matrix, "v")],
+            Operation::CubeRoot => vec![self.cbrt("v")],
+            Operation::Cube => vec!["v * v * v".to_owned()],
+            Operation::XyzToLab { white_point } => vec![
+                format!("v / {}", vector(white_point)),
+                self.select(
+                    &format!("{} * v + {}", number(841.0 / 108.0), self.splat(4.0 / 29.0)),
+                    &self.cbrt("v"),
+                    &self.less_or_equal("v", &self.splat((6.0f64 / 29.0).powi(3))),
+                ),
+                format!(
+                    "{}({} * v.y - {}, {} * (v.x - v.y), {} * (v.y - v.z))",
+                    vec3,
+                    number(116.0),
+                    number(16.0),
+                    number(500.0),
+                    number(200.0)
+                ),
+            ],
+            Operation::LabToXyz { white_point } => vec![
+                format!(
+                    "{0}(v.x + {1}) / {2} + {0}(v.y / {3}, 0.0, -v.z / {4})",
+                    vec3,
+                    number(16.0),
+                    number(116.0),
+                    number(500.0),
+                    number(200.0)
+                ),
+                self.select(
+                    &format!(
+                        "(v - {}) * {}",
+                        self.splat(4.0 / 29.0),
+                        number(108.0 / 841.0)
+                    ),
+                    "v * v * v",
+                    &self.less_or_equal("v", &self.splat(6.0 / 29.0)),
+                ),
+                format!("v * {}", vector(white_point)),
+            ],
+            Operation::ToPolar => vec![format!(
+                "{}(v.x, length(v.yz), degrees({}))",
+                vec3,
+                self.atan2("v.z", "v.y")
+            )],
+            Operation::FromPolar => vec![format!(
+                "{}(v.x, v.y * cos(radians(v.z)), v.y * sin(radians(v.z)))",
+                vec3
+            )],
+        }
+    }
+}
+
+/// Format a number as a floating point literal, without losing precision.
+fn number(value: f64) -> String {
+    format!("{:?}", value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, Language, Space};
+    use crate::error::ShaderError;
+
+    #[test]
+    fn chain() {
+        let glsl = generate(
+            Language::Glsl,
+            &[Space::Srgb, Space::LinearSrgb, Space::Xyz],
+        )
+        .unwrap();
+
+        assert!(glsl.contains("vec3 srgb_to_linear_srgb(vec3 c) {"));
+        assert!(glsl.contains("vec3 linear_srgb_to_xyz(vec3 c) {"));
+        assert!(glsl.contains("return linear_srgb_to_xyz(srgb_to_linear_srgb(c));"));
+        assert!(glsl.contains("lessThanEqual(v, vec3(0.04045))"));
+        assert!(!glsl.contains("<f32>"));
+
+        let wgsl = generate(Language::Wgsl, &[Space::Lab, Space::Lch]).unwrap();
+        assert!(wgsl.contains("atan2(v.z, v.y)"));
+        assert!(!wgsl.contains("lab_to_lch(lab_to_lch"));
+    }
+
+    #[test]
+    fn exact_constants() {
+        let wgsl = generate(Language::Wgsl, &[Space::Xyz, Space::Oklab, Space::Xyz]).unwrap();
+        assert!(wgsl.contains("0.8189889647022367"));
+        assert!(wgsl.contains("-1.2914854756501233"));
+
+        let wgsl = generate(Language::Wgsl, &[Space::Xyz, Space::Lab]).unwrap();
+        assert!(wgsl.contains("vec3<f32>(0.95047, 1.0, 1.08883)"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            generate(Language::Wgsl, &[Space::Srgb]),
+            Err(ShaderError::TooShort)
+        );
+        assert_eq!(
+            generate(Language::Wgsl, &[Space::Srgb, Space::Oklab]),
+            Err(ShaderError::UnsupportedStep {
+                from: Space::Srgb,
+                to: Space::Oklab
+            })
+        );
+    }
+}
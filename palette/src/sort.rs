@@ -0,0 +1,209 @@
+//! Perceptual sort keys for ordering color swatches.
+//!
+//! Sorting colors by their RGB components, or only by hue, gives lists that
+//! look random, since colors that are close in one component can be far
+//! apart in the others. [`SortKey`](struct.SortKey.html) maps a color to a
+//! single number that first groups the colors into a few hue bands, with the
+//! grays in their own band first, and then orders each band by lightness.
+//! The hue and lightness are taken from [`Oklch`](../struct.Oklch.html), so
+//! the bands follow the perceived hues.
+//!
+//! ```
+//! use palette::sort::SortKey;
+//! use palette::Srgb;
+//!
+//! let mut swatches = vec![
+//!     Srgb::new(0.1, 0.2, 0.9),
+//!     Srgb::new(0.9, 0.1, 0.1),
+//!     Srgb::new(0.5, 0.5, 0.5),
+//!     Srgb::new(1.0, 0.5, 0.5),
+//! ];
+//! SortKey::default().sort(&mut swatches);
+//!
+//! assert_eq!(swatches[0], Srgb::new(0.5, 0.5, 0.5));
+//! assert_eq!(swatches[1], Srgb::new(0.9, 0.1, 0.1));
+//! assert_eq!(swatches[3], Srgb::new(0.1, 0.2, 0.9));
+//! ```
+
+#[cfg(feature = "std")]
+use core::cmp::Ordering;
+
+use crate::{from_f64, FloatComponent, FromColor, Oklch};
+
+/// Settings for calculating sort keys.
+///
+/// The key of a color is `band * hue_weight + lightness * lightness_weight`,
+/// where `band` is `0` for the grays and from `1` to `hue_bands` for the other
+/// colors, and `lightness` is the Oklch lightness. The bands are kept apart
+/// as long as `lightness_weight` is less than `hue_weight`. A larger
+/// `lightness_weight` lets light and dark colors cross into the neighboring
+/// bands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SortKey<T = f32> {
+    /// The number of equally wide hue bands. The first band starts at
+    /// `hue_offset`.
+    pub hue_bands: u32,
+
+    /// The hue, in degrees, where the first band starts. The default is
+    /// `0.0`, which puts the boundary between the reddish purples and the
+    /// pinks.
+    pub hue_offset: T,
+
+    /// The Oklch chroma where colors stop being counted as grays.
+    pub gray_chroma: T,
+
+    /// The weight of the hue band.
+    pub hue_weight: T,
+
+    /// The weight of the lightness.
+    pub lightness_weight: T,
+}
+
+impl<T: FloatComponent> SortKey<T> {
+    /// Calculate the sort key of `color`.
+    pub fn key<C>(&self, color: C) -> T
+    where
+        Oklch<T>: FromColor<C>,
+    {
+        let color = Oklch::from_color(color);
+
+        let band = if color.chroma < self.gray_chroma || self.hue_bands == 0 {
+            T::zero()
+        } else {
+            let hue = (color.hue - self.hue_offset).to_positive_degrees();
+            let width = from_f64::<T>(360.0) / from_f64(f64::from(self.hue_bands));
+            let last_band: T = from_f64(f64::from(self.hue_bands - 1));
+
+            (hue / width).floor().min(last_band) + T::one()
+        };
+
+        band * self.hue_weight + color.l * self.lightness_weight
+    }
+
+    /// Sort `colors` by their keys, from the lowest to the highest. The sort
+    /// is stable, so colors with the same key keep their order.
+    #[cfg(feature = "std")]
+    pub fn sort<C>(&self, colors: &mut [C])
+    where
+        C: Clone,
+        Oklch<T>: FromColor<C>,
+    {
+        colors.sort_by_cached_key(|color| OrderedKey(self.key(color.clone())));
+    }
+}
+
+impl<T: FloatComponent> Default for SortKey<T> {
+    fn default() -> Self {
+        SortKey {
+            hue_bands: 12,
+            hue_offset: T::zero(),
+            gray_chroma: from_f64(0.02),
+            hue_weight: T::one(),
+            lightness_weight: from_f64(0.5),
+        }
+    }
+}
+
+/// Calculate the sort key of `color` with the default settings.
+pub fn sort_key<C, T>(color: C) -> T
+where
+    T: FloatComponent,
+    Oklch<T>: FromColor<C>,
+{
+    SortKey::default().key(color)
+}
+
+/// A key that orders NaN after every other value, to make the keys totally
+/// ordered.
+#[cfg(feature = "std")]
+struct OrderedKey<T>(T);
+
+#[cfg(feature = "std")]
+impl<T: PartialOrd> Ord for OrderedKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
+            // Only NaN is unordered, including with itself.
+            let self_is_nan = self.0.partial_cmp(&self.0).is_none();
+            let other_is_nan = other.0.partial_cmp(&other.0).is_none();
+            self_is_nan.cmp(&other_is_nan)
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialOrd> PartialOrd for OrderedKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialOrd> PartialEq for OrderedKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialOrd> Eq for OrderedKey<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::{sort_key, SortKey};
+    use crate::{Oklch, Srgb};
+
+    #[test]
+    fn bands() {
+        let settings = SortKey::<f64>::default();
+
+        let gray = settings.key(Oklch::new(0.9, 0.0, 0.0));
+        let red = settings.key(Oklch::new(0.1, 0.2, 10.0));
+        let light_red = settings.key(Oklch::new(0.9, 0.2, 20.0));
+        let green = settings.key(Oklch::new(0.1, 0.2, 140.0));
+        let purple = settings.key(Oklch::new(0.5, 0.2, 359.0));
+
+        assert!(gray < red);
+        assert!(red < light_red);
+        assert!(light_red < green);
+        assert!(green < purple);
+        assert_relative_eq!(purple, 12.25);
+    }
+
+    #[test]
+    fn weights() {
+        let settings = SortKey {
+            hue_bands: 4,
+            hue_offset: 45.0,
+            gray_chroma: 0.05,
+            hue_weight: 0.0,
+            lightness_weight: 1.0,
+        };
+
+        assert_relative_eq!(settings.key(Oklch::new(0.3, 0.2, 30.0)), 0.3);
+        assert_relative_eq!(settings.key(Oklch::new(0.7, 0.2, 60.0)), 0.7);
+
+        let settings = SortKey {
+            hue_weight: 1.0,
+            ..settings
+        };
+        assert_relative_eq!(settings.key(Oklch::new(0.3, 0.2, 30.0)), 4.3);
+        assert_relative_eq!(settings.key(Oklch::new(0.3, 0.2, 50.0)), 1.3);
+        assert_relative_eq!(settings.key(Oklch::new(0.3, 0.04, 50.0)), 0.3);
+    }
+
+    #[test]
+    fn stable_sort() {
+        let mut colors = vec![
+            Srgb::new(1.0f64, 1.0, 1.0),
+            Srgb::new(0.0, 0.0, 1.0),
+            Srgb::new(0.0, 0.0, 0.0),
+            Srgb::new(1.0, 1.0, 1.0),
+        ];
+        SortKey::default().sort(&mut colors);
+
+        assert_eq!(colors[0], Srgb::new(0.0, 0.0, 0.0));
+        assert_eq!(colors[1], Srgb::new(1.0, 1.0, 1.0));
+        assert_eq!(colors[3], Srgb::new(0.0, 0.0, 1.0));
+        assert!(sort_key(colors[1]) < sort_key(colors[3]));
+    }
+}
@@ -0,0 +1,741 @@
+//! Spectral power distributions.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+//!
+//! Light sources and surfaces can be described by how much light they emit or
+//! reflect at each wavelength. An [`Spd`](struct.Spd.html) stores such a
+//! description as evenly spaced samples, and turns it into a color with the
+//! CIE 1931 2° standard observer, or any other
+//! [`Observer`](struct.Observer.html). The distributions of the standard
+//! illuminants are in the [`illuminant`](illuminant/index.html) module, if the
+//! `illuminant_spectra` feature is enabled.
+//!
+//! ```
+//! use palette::spectrum::Spd;
+//! use palette::white_point::D65;
+//! use palette::Xyz;
+//!
+//! // A surface that reflects half of the light at every wavelength.
+//! let gray = Spd::new(380.0, 10.0, vec![0.5f64; 41]);
+//! let light = Spd::new(380.0, 10.0, vec![1.0; 41]);
+//!
+//! let color: Xyz<D65, f64> = gray.reflectance_to_xyz(&light);
+//! assert!((color.y - 0.5).abs() < 1e-12);
+//! ```
+
+use core::ops::{Add, Mul};
+
+use crate::chromatic_adaptation::{adaptation_matrix, Method, TransformMatrix};
+use crate::chromaticity::daylight_xy;
+use crate::cmf;
+use crate::math;
+use crate::matrix::multiply_xyz;
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, LinSrgb, Xyz};
+
+use self::observer::cie_1931_samples;
+
+pub use self::observer::Observer;
+
+#[cfg(feature = "illuminant_spectra")]
+pub mod illuminant;
+mod observer;
+mod smits;
+
+/// The second radiation constant in Planck's law, in m·K.
+const SECOND_RADIATION_CONSTANT: f64 = 1.4388e-2;
+
+/// The components `S0`, `S1` and `S2` of the CIE daylight illuminants, from
+/// 380 nm to 780 nm in steps of 10 nm.
+#[rustfmt::skip]
+const DAYLIGHT_COMPONENTS: [[f64; 3]; 41] = [
+    [63.4, 38.5, 3.0], // 380 nm
+    [65.8, 35.0, 1.2], // 390 nm
+    [94.8, 43.4, -1.1], // 400 nm
+    [104.8, 46.3, -0.5], // 410 nm
+    [105.9, 43.9, -0.7], // 420 nm
+    [96.8, 37.1, -1.2], // 430 nm
+    [113.9, 36.7, -2.6], // 440 nm
+    [125.6, 35.9, -2.9], // 450 nm
+    [125.5, 32.6, -2.8], // 460 nm
+    [121.3, 27.9, -2.6], // 470 nm
+    [121.3, 24.3, -2.6], // 480 nm
+    [113.5, 20.1, -1.8], // 490 nm
+    [113.1, 16.2, -1.5], // 500 nm
+    [110.8, 13.2, -1.3], // 510 nm
+    [106.5, 8.6, -1.2], // 520 nm
+    [108.8, 6.1, -1.0], // 530 nm
+    [105.3, 4.2, -0.5], // 540 nm
+    [104.4, 1.9, -0.3], // 550 nm
+    [100.0, 0.0, 0.0], // 560 nm
+    [96.0, -1.6, 0.2], // 570 nm
+    [95.1, -3.5, 0.5], // 580 nm
+    [89.1, -3.5, 2.1], // 590 nm
+    [90.5, -5.8, 3.2], // 600 nm
+    [90.3, -7.2, 4.1], // 610 nm
+    [88.4, -8.6, 4.7], // 620 nm
+    [84.0, -9.5, 5.1], // 630 nm
+    [85.1, -10.9, 6.7], // 640 nm
+    [81.9, -10.7, 7.3], // 650 nm
+    [82.6, -12.0, 8.6], // 660 nm
+    [84.9, -14.0, 9.8], // 670 nm
+    [81.3, -13.6, 10.2], // 680 nm
+    [71.9, -12.0, 8.3], // 690 nm
+    [74.3, -13.3, 9.6], // 700 nm
+    [76.4, -12.9, 8.5], // 710 nm
+    [63.3, -10.6, 7.0], // 720 nm
+    [71.7, -11.6, 7.6], // 730 nm
+    [77.0, -12.2, 8.0], // 740 nm
+    [65.2, -10.2, 6.7], // 750 nm
+    [47.7, -7.8, 5.2], // 760 nm
+    [68.6, -11.2, 7.4], // 770 nm
+    [65.0, -10.4, 6.8], // 780 nm
+];
+
+/// A spectral power distribution, sampled at evenly spaced wavelengths.
+///
+/// The samples can be the power of a light source, or the reflectance of a
+/// surface, at each wavelength. The distribution is linearly interpolated
+/// between the samples and zero outside them. The wavelengths are in
+/// nanometers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spd<T = f32> {
+    start: T,
+    step: T,
+    values: Vec<T>,
+}
+
+impl<T: FloatComponent> Spd<T> {
+    /// Create a distribution from `values`, where the first value is at the
+    /// wavelength `start` and the others follow in steps of `step`.
+    ///
+    /// Panics if `values` is empty or `step` isn't positive.
+    pub fn new(start: T, step: T, values: Vec<T>) -> Self {
+        assert!(
+            !values.is_empty(),
+            "a distribution needs at least one value"
+        );
+        assert!(step > T::zero(), "the step must be positive");
+
+        Spd {
+            start,
+            step,
+            values,
+        }
+    }
+
+    /// Create a distribution with `len` samples of `function`, from the
+    /// wavelength `start` in steps of `step`.
+    ///
+    /// Panics if `len` is zero or `step` isn't positive.
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    ///
+    /// // A light that gets stronger towards the longer wavelengths.
+    /// let ramp = Spd::from_fn(380.0f64, 10.0, 41, |wavelength| (wavelength - 380.0) / 400.0);
+    /// assert_eq!(ramp.value_at(580.0), 0.5);
+    /// ```
+    pub fn from_fn<F: Fn(T) -> T>(start: T, step: T, len: usize, function: F) -> Self {
+        let values = (0..len)
+            .map(|i| function(start + step * from_f64(i as f64)))
+            .collect();
+
+        Spd::new(start, step, values)
+    }
+
+    /// A Gaussian peak at the wavelength `center`, where `width` is the full
+    /// width at half maximum. It's sampled from 380 nm to 780 nm in steps of
+    /// 1 nm, and its highest value is `1.0`.
+    ///
+    /// Peaks like this are good approximations of LEDs and lasers, and they
+    /// can be combined into more complex light sources with `+` and `*`:
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    ///
+    /// // A made up RGB LED.
+    /// let led = Spd::gaussian(630.0f64, 20.0)
+    ///     + Spd::gaussian(530.0, 35.0) * 0.8
+    ///     + Spd::gaussian(460.0, 25.0) * 0.6;
+    ///
+    /// assert!((led.value_at(630.0) - 1.0).abs() < 1e-3);
+    /// assert!((led.value_at(640.0) - 0.5).abs() < 1e-3);
+    /// ```
+    pub fn gaussian(center: T, width: T) -> Self {
+        // The full width at half maximum is 2√(2 ln 2) standard deviations.
+        let deviation = width / from_f64(2.354_820_045_030_949);
+        let two_variance = from_f64::<T>(2.0) * deviation * deviation;
+
+        Self::from_fn(from_f64(cmf::START), T::one(), 401, |wavelength| {
+            let offset = wavelength - center;
+            math::exp(-offset * offset / two_variance)
+        })
+    }
+
+    /// A band that is `1.0` from the wavelength `from` to `to`, and `0.0`
+    /// elsewhere. It's sampled from 380 nm to 780 nm in steps of 1 nm.
+    ///
+    /// Bands can be used as ideal filters, or as light sources with a flat
+    /// distribution in a part of the spectrum.
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    ///
+    /// let light = Spd::new(380.0f64, 10.0, vec![1.0; 41]);
+    /// let long_pass = Spd::band(600.0, 780.0);
+    ///
+    /// let filtered = light * long_pass;
+    /// assert_eq!(filtered.value_at(500.0), 0.0);
+    /// assert_eq!(filtered.value_at(650.0), 1.0);
+    /// ```
+    pub fn band(from: T, to: T) -> Self {
+        Self::from_fn(from_f64(cmf::START), T::one(), 401, |wavelength| {
+            if wavelength >= from && wavelength <= to {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// The wavelength of the first sample.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The distance between the wavelengths of the samples.
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// The wavelength of the last sample.
+    pub fn end(&self) -> T {
+        self.start + self.step * from_f64((self.values.len() - 1) as f64)
+    }
+
+    /// The samples of the distribution.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The value of the distribution at `wavelength`, interpolated between
+    /// the samples.
+    pub fn value_at(&self, wavelength: T) -> T {
+        let position = (wavelength - self.start) / self.step;
+        let last = from_f64::<T>((self.values.len() - 1) as f64);
+
+        if !(position >= T::zero() && position <= last) {
+            return T::zero();
+        }
+
+        let index = position.to_usize().unwrap_or(0);
+        if index + 1 >= self.values.len() {
+            return self.values[self.values.len() - 1];
+        }
+
+        let t = position - from_f64(index as f64);
+        let (a, b) = (self.values[index], self.values[index + 1]);
+
+        a + (b - a) * t
+    }
+
+    /// Convert the distribution of a light source to XYZ, with the CIE 1931
+    /// 2° standard observer.
+    ///
+    /// The result is scaled so that a distribution of `1.0` at every
+    /// wavelength has the luminance `1.0`.
+    pub fn to_xyz<Wp: WhitePoint>(&self) -> Xyz<Wp, T> {
+        integrate(cie_1931_samples(), |wavelength| self.value_at(wavelength))
+    }
+
+    /// Convert the distribution of a light source to XYZ, with the color
+    /// matching functions of `observer`.
+    ///
+    /// The result is scaled so that a distribution of `1.0` at every
+    /// wavelength has the luminance `1.0`.
+    pub fn to_xyz_with<Wp: WhitePoint>(&self, observer: &Observer<T>) -> Xyz<Wp, T> {
+        integrate(observer.samples(), |wavelength| self.value_at(wavelength))
+    }
+
+    /// Convert the distribution of a surface's reflectance to XYZ, as seen
+    /// under the light source `illuminant` by the CIE 1931 2° standard
+    /// observer.
+    ///
+    /// The result is scaled so that a surface that reflects all of the light
+    /// has the luminance `1.0`.
+    pub fn reflectance_to_xyz<Wp: WhitePoint>(&self, illuminant: &Spd<T>) -> Xyz<Wp, T> {
+        let white: Xyz<Wp, T> = illuminant.to_xyz();
+        let color: Xyz<Wp, T> = integrate(cie_1931_samples(), |wavelength| {
+            self.value_at(wavelength) * illuminant.value_at(wavelength)
+        });
+
+        relative_to(color, white)
+    }
+
+    /// Convert the distribution of a surface's reflectance to XYZ, as seen
+    /// under the light source `illuminant` by `observer`.
+    ///
+    /// The result is scaled so that a surface that reflects all of the light
+    /// has the luminance `1.0`.
+    pub fn reflectance_to_xyz_with<Wp: WhitePoint>(
+        &self,
+        illuminant: &Spd<T>,
+        observer: &Observer<T>,
+    ) -> Xyz<Wp, T> {
+        let white: Xyz<Wp, T> = illuminant.to_xyz_with(observer);
+        let color: Xyz<Wp, T> = integrate(observer.samples(), |wavelength| {
+            self.value_at(wavelength) * illuminant.value_at(wavelength)
+        });
+
+        relative_to(color, white)
+    }
+
+    /// Convert the distribution of a surface's reflectance to XYZ, as it
+    /// looks under the light source `illuminant` to someone who is adapted to
+    /// that light.
+    ///
+    /// The color is first calculated like in
+    /// [`reflectance_to_xyz`](#method.reflectance_to_xyz), and then adapted
+    /// from the white of `illuminant` to the white point `Wp` with the
+    /// Bradford method. A surface that reflects all of the light becomes the
+    /// white of `Wp`, while other surfaces keep the shifts that the light
+    /// causes. This shows how the surface would look under the light when
+    /// displayed on a screen with the white point `Wp`.
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    /// use palette::white_point::{WhitePoint, D65};
+    /// use palette::Xyz;
+    ///
+    /// // Warm light, where the longer wavelengths are stronger.
+    /// let warm = Spd::new(380.0, 100.0, vec![0.2f64, 0.5, 0.8, 1.0, 1.0]);
+    /// let gray = Spd::new(380.0, 10.0, vec![0.5f64; 41]);
+    ///
+    /// let color: Xyz<D65, f64> = gray.relight(&warm);
+    /// let white: Xyz<D65, f64> = D65::get_xyz();
+    /// assert!((color.x - 0.5 * white.x).abs() < 1e-6);
+    /// assert!((color.z - 0.5 * white.z).abs() < 1e-6);
+    /// ```
+    pub fn relight<Wp: WhitePoint>(&self, illuminant: &Spd<T>) -> Xyz<Wp, T> {
+        let color: Xyz<Wp, T> = self.reflectance_to_xyz(illuminant);
+        let white: Xyz<Wp, T> = illuminant.to_xyz();
+        if white.y <= T::zero() {
+            return color;
+        }
+
+        let cone_response =
+            <Method as TransformMatrix<Wp, Wp, T>>::get_cone_response(&Method::Bradford);
+        let target: Xyz<Wp, T> = Wp::get_xyz();
+        let adapt = adaptation_matrix(&cone_response, &(white / white.y), &target);
+
+        multiply_xyz(&adapt, &color)
+    }
+
+    /// An approximate reflectance spectrum for a linear sRGB color.
+    ///
+    /// This uses the method from "An RGB to Spectrum Conversion for
+    /// Reflectances" by Brian Smits, which builds the spectrum from smooth
+    /// basis spectra for white, cyan, magenta, yellow, red, green and blue.
+    /// The spectrum is sampled from 380 nm to 720 nm in 10 steps, and gives
+    /// roughly the same color under a light source with an even distribution.
+    /// The components are expected to be in `0.0..=1.0`, since a surface can't
+    /// reflect more light than it receives.
+    ///
+    /// This makes it possible to use colors from images and textures in
+    /// spectral calculations, such as [`relight`](#method.relight):
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    /// use palette::white_point::D65;
+    /// use palette::{LinSrgb, Xyz};
+    ///
+    /// let paint = Spd::from_rgb(LinSrgb::new(0.8f64, 0.3, 0.1));
+    /// let led = Spd::new(380.0, 20.0, vec![
+    ///     0.0, 0.1, 0.6, 1.0, 0.5, 0.3, 0.4, 0.6, 0.8, 0.9, 0.9, 0.8, 0.7, 0.5, 0.4, 0.3,
+    /// ]);
+    ///
+    /// let under_led: Xyz<D65, f64> = paint.relight(&led);
+    /// ```
+    pub fn from_rgb(color: LinSrgb<T>) -> Self {
+        Spd::new(
+            from_f64(smits::START),
+            from_f64(smits::STEP),
+            smits::reflectance(color.red, color.green, color.blue),
+        )
+    }
+
+    /// The relative distribution of a black body radiator with the
+    /// `temperature`, in kelvin, from 380 nm to 780 nm in steps of 5 nm. It's
+    /// normalized to `1.0` at 560 nm.
+    ///
+    /// The distribution follows Planck's law, with the second radiation
+    /// constant from ITS-90, so a temperature of 2856 K gives the CIE
+    /// standard illuminant A.
+    ///
+    /// ```
+    /// use palette::spectrum::Spd;
+    /// use palette::white_point::A;
+    /// use palette::Xyz;
+    ///
+    /// let incandescent: Spd<f64> = Spd::blackbody(2856.0);
+    /// assert_eq!(incandescent.value_at(560.0), 1.0);
+    ///
+    /// let color: Xyz<A, f64> = incandescent.to_xyz();
+    /// assert!(color.x > color.z);
+    /// ```
+    pub fn blackbody(temperature: T) -> Self {
+        Self::blackbody_sampled(
+            temperature,
+            from_f64(cmf::START),
+            from_f64(cmf::STEP),
+            cmf::CIE_1931.len(),
+        )
+    }
+
+    /// The relative distribution of a black body radiator with the
+    /// `temperature`, in kelvin, with `len` samples from the wavelength
+    /// `start` in steps of `step`. It's normalized to `1.0` at 560 nm, even if
+    /// that's outside the samples.
+    ///
+    /// Panics if `len` is zero or `step` isn't positive.
+    pub fn blackbody_sampled(temperature: T, start: T, step: T, len: usize) -> Self {
+        let temperature = temperature.to_f64().unwrap_or(0.0);
+        let planck = |wavelength: f64| {
+            let meters = wavelength * 1e-9;
+            1.0 / (meters.powi(5)
+                * (math::exp(SECOND_RADIATION_CONSTANT / (meters * temperature)) - 1.0))
+        };
+        let normalization = planck(560.0);
+        let (start_f64, step_f64) = (start.to_f64().unwrap_or(0.0), step.to_f64().unwrap_or(0.0));
+        let values = (0..len)
+            .map(|i| from_f64(planck(start_f64 + step_f64 * i as f64) / normalization))
+            .collect();
+
+        Spd::new(start, step, values)
+    }
+
+    /// The relative distribution of the CIE daylight illuminant with the
+    /// correlated color `temperature`, in kelvin. It's normalized to `100.0`
+    /// at 560 nm.
+    pub(crate) fn daylight(temperature: T) -> Self {
+        let (x, y) = daylight_xy(temperature);
+        let (x, y) = (x.to_f64().unwrap_or(0.0), y.to_f64().unwrap_or(0.0));
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+        let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+
+        let values = DAYLIGHT_COMPONENTS
+            .iter()
+            .map(|&[s0, s1, s2]| from_f64(s0 + m1 * s1 + m2 * s2))
+            .collect();
+
+        Spd::new(from_f64(cmf::START), from_f64(10.0), values)
+    }
+}
+
+impl<T: FloatComponent> Spd<T> {
+    /// Combine the values of `self` and `other` at each wavelength. The
+    /// result covers both distributions, with the smaller of their steps.
+    fn combine<F: Fn(T, T) -> T>(&self, other: &Spd<T>, function: F) -> Spd<T> {
+        let start = self.start.min(other.start);
+        let end = self.end().max(other.end());
+        let step = self.step.min(other.step);
+        let len = ((end - start) / step).round().to_usize().unwrap_or(0) + 1;
+
+        Spd::from_fn(start, step, len, |wavelength| {
+            function(self.value_at(wavelength), other.value_at(wavelength))
+        })
+    }
+}
+
+/// The sum of two distributions, such as two light sources that shine on the
+/// same surface.
+impl<T: FloatComponent> Add for Spd<T> {
+    type Output = Spd<T>;
+
+    fn add(self, other: Spd<T>) -> Spd<T> {
+        self.combine(&other, |a, b| a + b)
+    }
+}
+
+/// The product of two distributions, such as a light source and a filter or
+/// a reflectance.
+impl<T: FloatComponent> Mul for Spd<T> {
+    type Output = Spd<T>;
+
+    fn mul(self, other: Spd<T>) -> Spd<T> {
+        self.combine(&other, |a, b| a * b)
+    }
+}
+
+/// Scale the distribution by `factor`.
+impl<T: FloatComponent> Mul<T> for Spd<T> {
+    type Output = Spd<T>;
+
+    fn mul(mut self, factor: T) -> Spd<T> {
+        for value in &mut self.values {
+            *value = *value * factor;
+        }
+
+        self
+    }
+}
+
+/// Integrate `spectrum` with the color matching functions in `samples`,
+/// scaled so that a spectrum of `1.0` has the luminance `1.0`.
+fn integrate<Wp, T, I, F>(samples: I, spectrum: F) -> Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+    I: Iterator<Item = (T, [T; 3])>,
+    F: Fn(T) -> T,
+{
+    let mut sum = [T::zero(); 3];
+    let mut normalization = T::zero();
+
+    for (wavelength, functions) in samples {
+        let value = spectrum(wavelength);
+        for (sum, &weight) in sum.iter_mut().zip(&functions) {
+            *sum = *sum + value * weight;
+        }
+        normalization = normalization + functions[1];
+    }
+
+    Xyz::with_wp(
+        sum[0] / normalization,
+        sum[1] / normalization,
+        sum[2] / normalization,
+    )
+}
+
+/// Scale `color` so that `white` would have the luminance `1.0`.
+fn relative_to<Wp: WhitePoint, T: FloatComponent>(
+    color: Xyz<Wp, T>,
+    white: Xyz<Wp, T>,
+) -> Xyz<Wp, T> {
+    if white.y > T::zero() {
+        color / white.y
+    } else {
+        color
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Observer, Spd};
+    use crate::white_point::{D50Degree10, D65Degree10, WhitePoint, A, D65};
+    use crate::Xyz;
+
+    #[test]
+    fn interpolation() {
+        let spd = Spd::new(400.0f64, 10.0, vec![1.0, 3.0, 2.0]);
+        assert_relative_eq!(spd.end(), 420.0);
+        assert_relative_eq!(spd.value_at(400.0), 1.0);
+        assert_relative_eq!(spd.value_at(405.0), 2.0);
+        assert_relative_eq!(spd.value_at(417.5), 2.25);
+        assert_relative_eq!(spd.value_at(420.0), 2.0);
+        assert_relative_eq!(spd.value_at(399.0), 0.0);
+        assert_relative_eq!(spd.value_at(421.0), 0.0);
+    }
+
+    #[test]
+    fn equal_energy() {
+        let spd = Spd::new(300.0f64, 1.0, vec![1.0; 600]);
+        let xyz: Xyz<D65, f64> = spd.to_xyz();
+        assert_relative_eq!(xyz.x, 1.0, epsilon = 1e-3);
+        assert_relative_eq!(xyz.y, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(xyz.z, 1.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn standard_illuminants() {
+        let d65: Xyz<D65, f64> = Spd::daylight(6504.0).to_xyz();
+        assert_relative_eq!(d65 / d65.y, D65::get_xyz(), epsilon = 1e-3);
+
+        let a: Xyz<A, f64> = Spd::blackbody(2856.0).to_xyz();
+        assert_relative_eq!(a / a.y, A::get_xyz(), epsilon = 2e-3);
+    }
+
+    #[test]
+    fn relight() {
+        let d65 = Spd::daylight(6504.0);
+        let a = Spd::blackbody(2856.0);
+        let gray = Spd::new(380.0f64, 10.0, vec![0.4; 41]);
+        let red = Spd::new(380.0f64, 100.0, vec![0.05, 0.1, 0.8, 0.9, 0.9]);
+
+        let white: Xyz<D65, f64> = D65::get_xyz();
+        let adapted: Xyz<D65, f64> = gray.relight(&a);
+        assert_relative_eq!(adapted, white * 0.4, epsilon = 1e-6);
+
+        let same: Xyz<D65, f64> = red.relight(&d65);
+        let expected: Xyz<D65, f64> = red.reflectance_to_xyz(&d65);
+        assert_relative_eq!(same, expected, epsilon = 1e-3);
+
+        // The red surface is lighter and more saturated in incandescent light,
+        // relative to its white.
+        let under_a: Xyz<D65, f64> = red.relight(&a);
+        assert!(under_a.y > same.y);
+
+        let dark = Spd::new(380.0f64, 10.0, vec![0.0; 41]);
+        let black: Xyz<D65, f64> = red.relight(&dark);
+        assert_relative_eq!(black, Xyz::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn from_rgb() {
+        use crate::white_point::E;
+        use crate::{FromColor, Hsv, LinSrgb};
+
+        let light = Spd::new(380.0f64, 10.0, vec![1.0; 41]);
+        let d65 = Spd::daylight(6504.0);
+
+        let gray: Xyz<E, f64> =
+            Spd::from_rgb(LinSrgb::new(0.5, 0.5, 0.5)).reflectance_to_xyz(&light);
+        assert_relative_eq!(gray.y, 0.5, epsilon = 2e-3);
+        assert_relative_eq!(gray.x, gray.y, epsilon = 1e-2);
+        assert_relative_eq!(gray.z, gray.y, epsilon = 1e-2);
+
+        // The hues should be about right, even if the spectra can't reproduce
+        // the most saturated colors.
+        let colors = [
+            LinSrgb::new(0.8, 0.1, 0.1),
+            LinSrgb::new(0.8, 0.8, 0.1),
+            LinSrgb::new(0.1, 0.8, 0.1),
+            LinSrgb::new(0.1, 0.8, 0.8),
+            LinSrgb::new(0.1, 0.1, 0.8),
+            LinSrgb::new(0.8, 0.1, 0.8),
+            LinSrgb::new(0.6, 0.4, 0.2),
+        ];
+        for &color in &colors {
+            let spd = Spd::from_rgb(color);
+            assert!(spd.values().iter().all(|&value| value >= 0.0));
+
+            let xyz: Xyz<D65, f64> = spd.reflectance_to_xyz(&d65);
+            let expected = Hsv::from_color(color);
+            let actual = Hsv::from_color(LinSrgb::from_color(xyz));
+            let hue_difference = (expected.hue - actual.hue).to_degrees().abs();
+            assert!(hue_difference < 5.0, "{:?} became {:?}", color, actual);
+        }
+    }
+
+    #[test]
+    fn blackbody_sampled() {
+        let default = Spd::blackbody(5000.0f64);
+        let sampled = Spd::blackbody_sampled(5000.0f64, 380.0, 5.0, 81);
+        assert_eq!(default, sampled);
+
+        let fine = Spd::blackbody_sampled(5000.0f64, 300.0, 0.5, 1601);
+        assert_relative_eq!(fine.start(), 300.0);
+        assert_relative_eq!(fine.end(), 1100.0);
+        assert_relative_eq!(fine.value_at(560.0), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            fine.value_at(452.5),
+            default.value_at(452.5),
+            epsilon = 1e-3
+        );
+
+        // Hotter radiators are bluer.
+        let hot = Spd::blackbody_sampled(10000.0f64, 400.0, 100.0, 4);
+        let cold = Spd::blackbody_sampled(2000.0f64, 400.0, 100.0, 4);
+        assert!(hot.value_at(400.0) > 1.0);
+        assert!(cold.value_at(400.0) < 1.0);
+        assert!(hot.value_at(700.0) < cold.value_at(700.0));
+    }
+
+    #[test]
+    fn gaussian() {
+        let peak = Spd::gaussian(550.0f64, 40.0);
+        assert_relative_eq!(peak.start(), 380.0);
+        assert_relative_eq!(peak.end(), 780.0);
+        assert_relative_eq!(peak.value_at(550.0), 1.0);
+        assert_relative_eq!(peak.value_at(530.0), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(peak.value_at(570.0), 0.5, epsilon = 1e-12);
+        assert!(peak.value_at(450.0) < 1e-6);
+    }
+
+    #[test]
+    fn band() {
+        let band = Spd::band(500.0f64, 600.0);
+        assert_relative_eq!(band.value_at(499.0), 0.0);
+        assert_relative_eq!(band.value_at(500.0), 1.0);
+        assert_relative_eq!(band.value_at(600.0), 1.0);
+        assert_relative_eq!(band.value_at(600.5), 0.5);
+        assert_relative_eq!(band.value_at(601.0), 0.0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Spd::new(400.0f64, 10.0, vec![1.0, 2.0, 3.0]);
+        let b = Spd::new(410.0f64, 5.0, vec![1.0, 1.0, 1.0, 1.0, 1.0]);
+
+        let sum = a.clone() + b.clone();
+        assert_relative_eq!(sum.start(), 400.0);
+        assert_relative_eq!(sum.step(), 5.0);
+        assert_relative_eq!(sum.end(), 430.0);
+        assert_relative_eq!(sum.value_at(400.0), 1.0);
+        assert_relative_eq!(sum.value_at(415.0), 3.5);
+        assert_relative_eq!(sum.value_at(430.0), 1.0);
+
+        let product = a.clone() * b;
+        assert_relative_eq!(product.value_at(400.0), 0.0);
+        assert_relative_eq!(product.value_at(410.0), 2.0);
+        assert_relative_eq!(product.value_at(425.0), 0.0);
+
+        let scaled = a * 2.0;
+        assert_eq!(scaled.values(), &[2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn cie_1964_observer() {
+        let observer = Observer::cie_1964();
+
+        let d65: Xyz<D65Degree10, f64> = Spd::daylight(6504.0).to_xyz_with(&observer);
+        assert_relative_eq!(d65 / d65.y, D65Degree10::get_xyz(), epsilon = 3e-3);
+
+        let d50: Xyz<D50Degree10, f64> = Spd::daylight(5003.0).to_xyz_with(&observer);
+        assert_relative_eq!(d50 / d50.y, D50Degree10::get_xyz(), epsilon = 3e-3);
+
+        let equal_energy = Spd::new(380.0, 10.0, vec![1.0; 41]);
+        let white: Xyz<D65Degree10, f64> = equal_energy.to_xyz_with(&observer);
+        assert_relative_eq!(white, Xyz::with_wp(1.0, 1.0, 1.0), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn observer_interpolation() {
+        let observer = Observer::new(400.0f64, 10.0, vec![[1.0, 2.0, 0.0], [3.0, 2.0, 4.0]]);
+        assert_eq!(observer.functions_at(400.0), [1.0, 2.0, 0.0]);
+        assert_eq!(observer.functions_at(405.0), [2.0, 2.0, 2.0]);
+        assert_eq!(observer.functions_at(410.0), [3.0, 2.0, 4.0]);
+        assert_eq!(observer.functions_at(399.0), [0.0; 3]);
+        assert_eq!(observer.functions_at(411.0), [0.0; 3]);
+
+        let cie_1931 = Observer::<f64>::cie_1931();
+        assert_eq!(cie_1931.functions_at(552.5), crate::cmf::cie_1931(552.5));
+    }
+
+    #[test]
+    fn custom_observer() {
+        let spd = Spd::new(
+            380.0f64,
+            10.0,
+            (0..41).map(|i| 1.0 + 0.01 * i as f64).collect(),
+        );
+        let light = Spd::daylight(5000.0);
+
+        let builtin: Xyz<D65, f64> = spd.to_xyz();
+        let custom: Xyz<D65, f64> = spd.to_xyz_with(&Observer::cie_1931());
+        assert_relative_eq!(builtin, custom, epsilon = 1e-12);
+
+        let builtin: Xyz<D65, f64> = spd.reflectance_to_xyz(&light);
+        let custom: Xyz<D65, f64> = spd.reflectance_to_xyz_with(&light, &Observer::cie_1931());
+        assert_relative_eq!(builtin, custom, epsilon = 1e-12);
+
+        // An observer that only sees long wavelengths.
+        let red = Observer::new(600.0, 10.0, vec![[1.0, 1.0, 0.0]; 11]);
+        let color: Xyz<D65, f64> = spd.to_xyz_with(&red);
+        assert_relative_eq!(color.y, 1.27, epsilon = 1e-12);
+        assert_relative_eq!(color.z, 0.0);
+    }
+}
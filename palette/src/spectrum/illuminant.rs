@@ -0,0 +1,182 @@
+//! The spectral power distributions of standard illuminants.
+//!
+//! This module is only available if the `illuminant_spectra` feature is
+//! enabled.
+//!
+//! The distributions are the ones published by the CIE, and are meant as
+//! shared reference data for calculations with
+//! [`Spd`](../struct.Spd.html) values, such as relighting, metamerism and
+//! color rendering. Their white points are in the
+//! [`white_point`](../../white_point/index.html) module.
+//!
+//! ```
+//! use palette::spectrum::illuminant;
+//! use palette::white_point::{WhitePoint, F11};
+//! use palette::Xyz;
+//!
+//! let f11 = illuminant::f11::<f64>();
+//! let white: Xyz<F11, f64> = f11.to_xyz();
+//! let expected: Xyz<F11, f64> = F11::get_xyz();
+//! assert!((white.x / white.y - expected.x).abs() < 1e-4);
+//! ```
+
+use super::{Spd, DAYLIGHT_COMPONENTS};
+use crate::math;
+use crate::{from_f64, FloatComponent};
+
+/// CIE illuminant F2, from 380 nm to 780 nm in steps of 5 nm.
+#[rustfmt::skip]
+const F2: [f64; 81] = [
+    1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19,
+    4.62, 5.06, 34.98, 11.81, 6.27, 6.63, 6.93, 7.19, 7.40,
+    7.54, 7.62, 7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05,
+    7.04, 7.16, 7.47, 8.04, 8.88, 10.01, 24.88, 16.64, 14.59,
+    16.16, 17.56, 18.62, 21.47, 22.79, 19.29, 18.66, 17.73, 16.54,
+    15.21, 13.80, 12.36, 10.95, 9.65, 8.40, 7.32, 6.31, 5.43,
+    4.68, 4.02, 3.45, 2.96, 2.55, 2.19, 1.89, 1.64, 1.53,
+    1.27, 1.10, 0.99, 0.88, 0.76, 0.68, 0.61, 0.56, 0.54,
+    0.51, 0.47, 0.47, 0.43, 0.46, 0.47, 0.40, 0.33, 0.27,
+];
+
+/// CIE illuminant F7, from 380 nm to 780 nm in steps of 5 nm.
+#[rustfmt::skip]
+const F7: [f64; 81] = [
+    2.56, 3.18, 3.84, 4.53, 6.15, 19.37, 7.37, 7.05, 7.71,
+    8.41, 9.15, 44.14, 17.52, 11.35, 12.00, 12.58, 13.08, 13.45,
+    13.71, 13.88, 13.95, 13.93, 13.82, 13.64, 13.43, 13.25, 13.08,
+    12.93, 12.78, 12.60, 12.44, 12.33, 12.26, 29.52, 17.05, 12.44,
+    12.58, 12.72, 12.83, 15.46, 16.75, 12.83, 12.67, 12.45, 12.19,
+    11.89, 11.60, 11.35, 11.12, 10.95, 10.76, 10.42, 10.11, 10.04,
+    10.02, 10.11, 9.87, 8.65, 7.27, 6.44, 5.83, 5.41, 5.04,
+    4.57, 4.12, 3.77, 3.46, 3.08, 2.73, 2.47, 2.25, 2.06,
+    1.90, 1.75, 1.62, 1.54, 1.45, 1.32, 1.17, 0.99, 0.81,
+];
+
+/// CIE illuminant F11, from 380 nm to 780 nm in steps of 5 nm.
+#[rustfmt::skip]
+const F11: [f64; 81] = [
+    0.91, 0.63, 0.46, 0.37, 1.29, 12.68, 1.59, 1.79, 2.46,
+    3.33, 4.49, 33.94, 12.13, 6.95, 7.19, 7.12, 6.72, 6.13,
+    5.46, 4.79, 5.66, 14.29, 14.96, 8.97, 4.72, 2.33, 1.47,
+    1.10, 0.89, 0.83, 1.18, 4.90, 39.59, 72.84, 32.61, 7.52,
+    2.83, 1.96, 1.67, 4.43, 11.28, 14.76, 12.73, 9.74, 7.33,
+    9.72, 55.27, 42.58, 13.18, 13.16, 12.26, 5.11, 2.07, 2.34,
+    3.58, 3.01, 2.48, 2.14, 1.54, 1.33, 1.46, 1.94, 2.00,
+    1.20, 1.35, 4.10, 5.58, 2.51, 0.57, 0.27, 0.23, 0.21,
+    0.24, 0.24, 0.20, 0.24, 0.32, 0.26, 0.16, 0.12, 0.09,
+];
+
+/// CIE standard illuminant A, which represents incandescent light, from
+/// 380 nm to 780 nm in steps of 5 nm.
+///
+/// It's calculated with the formula from CIE 15, which is a black body
+/// radiator at about 2856 K, and normalized to `100.0` at 560 nm.
+pub fn a<T: FloatComponent>() -> Spd<T> {
+    let planck = |wavelength: f64| math::exp(1.435e7 / (2848.0 * wavelength)) - 1.0;
+    let values = (0..81)
+        .map(|i| {
+            let wavelength = 380.0 + 5.0 * i as f64;
+            from_f64(100.0 * (560.0 / wavelength).powi(5) * planck(560.0) / planck(wavelength))
+        })
+        .collect();
+
+    Spd::new(from_f64(380.0), from_f64(5.0), values)
+}
+
+/// CIE standard illuminant D50, which represents horizon daylight, from
+/// 380 nm to 780 nm in steps of 10 nm.
+pub fn d50<T: FloatComponent>() -> Spd<T> {
+    daylight(-1.039, 0.363)
+}
+
+/// CIE illuminant D55, which represents mid-morning and mid-afternoon
+/// daylight, from 380 nm to 780 nm in steps of 10 nm.
+pub fn d55<T: FloatComponent>() -> Spd<T> {
+    daylight(-0.785, -0.198)
+}
+
+/// CIE standard illuminant D65, which represents average daylight, from
+/// 380 nm to 780 nm in steps of 10 nm.
+pub fn d65<T: FloatComponent>() -> Spd<T> {
+    daylight(-0.295, -0.689)
+}
+
+/// CIE illuminant D75, which represents north sky daylight, from 380 nm to
+/// 780 nm in steps of 10 nm.
+pub fn d75<T: FloatComponent>() -> Spd<T> {
+    daylight(0.145, -0.760)
+}
+
+/// CIE illuminant F2, a cool white fluorescent lamp, from 380 nm to 780 nm
+/// in steps of 5 nm.
+pub fn f2<T: FloatComponent>() -> Spd<T> {
+    from_table(&F2)
+}
+
+/// CIE illuminant F7, a broadband fluorescent lamp that simulates D65, from
+/// 380 nm to 780 nm in steps of 5 nm.
+pub fn f7<T: FloatComponent>() -> Spd<T> {
+    from_table(&F7)
+}
+
+/// CIE illuminant F11, a narrow tri-band fluorescent lamp, from 380 nm to
+/// 780 nm in steps of 5 nm.
+pub fn f11<T: FloatComponent>() -> Spd<T> {
+    from_table(&F11)
+}
+
+/// A D series illuminant from the daylight components. The CIE tables are
+/// calculated with `m1` and `m2` rounded to three decimals, so they are
+/// given here instead of being calculated from the temperature.
+fn daylight<T: FloatComponent>(m1: f64, m2: f64) -> Spd<T> {
+    let values = DAYLIGHT_COMPONENTS
+        .iter()
+        .map(|&[s0, s1, s2]| from_f64(s0 + m1 * s1 + m2 * s2))
+        .collect();
+
+    Spd::new(from_f64(380.0), from_f64(10.0), values)
+}
+
+fn from_table<T: FloatComponent>(table: &[f64; 81]) -> Spd<T> {
+    Spd::new(
+        from_f64(380.0),
+        from_f64(5.0),
+        table.iter().map(|&value| from_f64(value)).collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{a, d50, d55, d65, d75, f11, f2, f7};
+    use crate::spectrum::Spd;
+    use crate::white_point::{WhitePoint, A, D50, D55, D65, D75, F11, F2, F7};
+    use crate::Xyz;
+
+    fn check_white_point<Wp: WhitePoint + PartialEq + std::fmt::Debug>(spd: Spd<f64>) {
+        let white: Xyz<Wp, f64> = spd.to_xyz();
+        let expected: Xyz<Wp, f64> = Wp::get_xyz();
+        assert_relative_eq!(white / white.y, expected, epsilon = 5e-4);
+    }
+
+    #[test]
+    fn white_points() {
+        check_white_point::<A>(a());
+        check_white_point::<D50>(d50());
+        check_white_point::<D55>(d55());
+        check_white_point::<D65>(d65());
+        check_white_point::<D75>(d75());
+        check_white_point::<F2>(f2());
+        check_white_point::<F7>(f7());
+        check_white_point::<F11>(f11());
+    }
+
+    #[test]
+    fn published_values() {
+        assert_relative_eq!(a::<f64>().value_at(380.0), 9.7951, epsilon = 1e-4);
+        assert_relative_eq!(a::<f64>().value_at(560.0), 100.0);
+        assert_relative_eq!(d50::<f64>().value_at(380.0), 24.4875, epsilon = 1e-9);
+        assert_relative_eq!(d65::<f64>().value_at(380.0), 49.9755, epsilon = 1e-9);
+        assert_relative_eq!(d65::<f64>().value_at(560.0), 100.0);
+        assert_relative_eq!(d65::<f64>().value_at(780.0), 63.3828, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::cmf;
+use crate::{from_f64, FloatComponent};
+
+/// The color matching functions of a standard observer.
+///
+/// The functions are sampled at evenly spaced wavelengths, in nanometers, and
+/// [`functions_at`](#method.functions_at) interpolates linearly between the
+/// samples. The CIE 1931 2° and CIE 1964 10° standard observers are built in,
+/// and other observers can be loaded from their tables. That includes the CIE
+/// 2006 physiological observers, which are derived from cone fundamentals for
+/// a given age and field size, and the CIE 2015 2° and 10° observers among
+/// them. Their tables, transformed to XYZ, are published by the CIE and by the
+/// Colour & Vision Research Laboratory.
+///
+/// ```
+/// use palette::spectrum::{Observer, Spd};
+/// use palette::white_point::E;
+/// use palette::Xyz;
+///
+/// // A made up observer that sees the same at every wavelength.
+/// let observer = Observer::new(400.0, 100.0, vec![[1.0f64, 1.0, 1.0]; 4]);
+/// let light = Spd::new(400.0, 100.0, vec![2.0, 2.0, 2.0, 2.0]);
+///
+/// let color: Xyz<E, f64> = light.to_xyz_with(&observer);
+/// assert_eq!(color, Xyz::with_wp(2.0, 2.0, 2.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observer<T = f32> {
+    start: T,
+    step: T,
+    functions: Vec<[T; 3]>,
+}
+
+impl<T: FloatComponent> Observer<T> {
+    /// Create an observer from the values of its color matching functions,
+    /// as `[x̄, ȳ, z̄]`, where the first values are at the wavelength `start`
+    /// and the others follow in steps of `step`.
+    ///
+    /// Panics if `functions` is empty, `step` isn't positive, or `ȳ` is
+    /// zero everywhere.
+    pub fn new(start: T, step: T, functions: Vec<[T; 3]>) -> Self {
+        assert!(step > T::zero(), "the step must be positive");
+        assert!(
+            functions.iter().any(|values| values[1] != T::zero()),
+            "the luminance function must not be zero everywhere"
+        );
+
+        Observer {
+            start,
+            step,
+            functions,
+        }
+    }
+
+    /// The CIE 1931 2° standard observer, from 380 nm to 780 nm in steps of
+    /// 5 nm.
+    pub fn cie_1931() -> Self {
+        Observer::new(
+            from_f64(cmf::START),
+            from_f64(cmf::STEP),
+            cie_1931_samples().map(|(_, values)| values).collect(),
+        )
+    }
+
+    /// The CIE 1964 10° supplementary standard observer, from 380 nm to
+    /// 780 nm in steps of 10 nm.
+    ///
+    /// It's meant for colors that cover more than about 4° of the field of
+    /// view, and it goes with the 10° white points, such as
+    /// [`D65Degree10`](../white_point/struct.D65Degree10.html).
+    pub fn cie_1964() -> Self {
+        Observer::new(
+            from_f64(cmf::START),
+            from_f64(cmf::CIE_1964_STEP),
+            cmf::CIE_1964
+                .iter()
+                .map(|values| {
+                    [
+                        from_f64(values[0]),
+                        from_f64(values[1]),
+                        from_f64(values[2]),
+                    ]
+                })
+                .collect(),
+        )
+    }
+
+    /// The wavelength of the first sample.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The distance between the wavelengths of the samples.
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// The samples of the color matching functions, as `[x̄, ȳ, z̄]`.
+    pub fn functions(&self) -> &[[T; 3]] {
+        &self.functions
+    }
+
+    /// The values of the color matching functions at `wavelength`, as
+    /// `[x̄, ȳ, z̄]`, interpolated between the samples. The functions are zero
+    /// outside the sampled range.
+    pub fn functions_at(&self, wavelength: T) -> [T; 3] {
+        let position = (wavelength - self.start) / self.step;
+        let last = from_f64::<T>((self.functions.len() - 1) as f64);
+
+        if !(position >= T::zero() && position <= last) {
+            return [T::zero(); 3];
+        }
+
+        let index = position.to_usize().unwrap_or(0);
+        if index + 1 >= self.functions.len() {
+            return self.functions[self.functions.len() - 1];
+        }
+
+        let t = position - from_f64(index as f64);
+        let (a, b) = (self.functions[index], self.functions[index + 1]);
+
+        [
+            a[0] + (b[0] - a[0]) * t,
+            a[1] + (b[1] - a[1]) * t,
+            a[2] + (b[2] - a[2]) * t,
+        ]
+    }
+
+    /// The wavelengths and values of the samples.
+    pub(crate) fn samples<'a>(&'a self) -> impl Iterator<Item = (T, [T; 3])> + 'a {
+        self.functions
+            .iter()
+            .enumerate()
+            .map(move |(i, &values)| (self.start + self.step * from_f64(i as f64), values))
+    }
+}
+
+/// The wavelengths and values of the CIE 1931 color matching functions.
+pub(crate) fn cie_1931_samples<T: FloatComponent>() -> impl Iterator<Item = (T, [T; 3])> {
+    cmf::CIE_1931.iter().enumerate().map(|(i, values)| {
+        (
+            from_f64(cmf::START + cmf::STEP * i as f64),
+            [
+                from_f64(values[0]),
+                from_f64(values[1]),
+                from_f64(values[2]),
+            ],
+        )
+    })
+}
@@ -0,0 +1,68 @@
+use crate::{from_f64, FloatComponent};
+
+/// The wavelength of the first sample of the basis spectra.
+pub(crate) const START: f64 = 380.0;
+
+/// The distance between the samples of the basis spectra.
+pub(crate) const STEP: f64 = 340.0 / 9.0;
+
+/// The basis spectra from "An RGB to Spectrum Conversion for Reflectances" by
+/// Brian Smits, from 380 nm to 720 nm in 10 steps.
+#[rustfmt::skip]
+const WHITE: [f64; 10] = [1.0000, 1.0000, 0.9999, 0.9993, 0.9992, 0.9998, 1.0000, 1.0000, 1.0000, 1.0000];
+#[rustfmt::skip]
+const CYAN: [f64; 10] = [0.9710, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0000, 0.0000, 0.0000];
+#[rustfmt::skip]
+const MAGENTA: [f64; 10] = [1.0000, 1.0000, 0.9685, 0.2229, 0.0000, 0.0458, 0.8369, 1.0000, 1.0000, 0.9959];
+#[rustfmt::skip]
+const YELLOW: [f64; 10] = [0.0001, 0.0000, 0.1088, 0.6651, 1.0000, 1.0000, 0.9996, 0.9586, 0.9685, 0.9840];
+#[rustfmt::skip]
+const RED: [f64; 10] = [0.1012, 0.0515, 0.0000, 0.0000, 0.0000, 0.0000, 0.8325, 1.0149, 1.0149, 1.0149];
+#[rustfmt::skip]
+const GREEN: [f64; 10] = [0.0000, 0.0000, 0.0273, 0.7937, 1.0000, 0.9418, 0.1719, 0.0000, 0.0000, 0.0025];
+#[rustfmt::skip]
+const BLUE: [f64; 10] = [1.0000, 1.0000, 0.8916, 0.3323, 0.0000, 0.0000, 0.0003, 0.0369, 0.0483, 0.0496];
+
+/// A reflectance spectrum for linear RGB values, as a sum of the basis
+/// spectra. The smallest component is made of white, and the rest of the
+/// secondary color between the two largest components and the primary color
+/// of the largest component.
+pub(crate) fn reflectance<T: FloatComponent>(red: T, green: T, blue: T) -> Vec<T> {
+    let (red, green, blue) = (
+        red.to_f64().unwrap_or(0.0),
+        green.to_f64().unwrap_or(0.0),
+        blue.to_f64().unwrap_or(0.0),
+    );
+
+    let (white, secondary, primary) = if red <= green && red <= blue {
+        if green <= blue {
+            ((red, &WHITE), (green - red, &CYAN), (blue - green, &BLUE))
+        } else {
+            ((red, &WHITE), (blue - red, &CYAN), (green - blue, &GREEN))
+        }
+    } else if green <= red && green <= blue {
+        if red <= blue {
+            (
+                (green, &WHITE),
+                (red - green, &MAGENTA),
+                (blue - red, &BLUE),
+            )
+        } else {
+            (
+                (green, &WHITE),
+                (blue - green, &MAGENTA),
+                (red - blue, &RED),
+            )
+        }
+    } else if red <= green {
+        ((blue, &WHITE), (red - blue, &YELLOW), (green - red, &GREEN))
+    } else {
+        ((blue, &WHITE), (green - blue, &YELLOW), (red - green, &RED))
+    };
+
+    (0..WHITE.len())
+        .map(|i| {
+            from_f64(white.0 * white.1[i] + secondary.0 * secondary.1[i] + primary.0 * primary.1[i])
+        })
+        .collect()
+}
@@ -0,0 +1,256 @@
+//! Cache friendly traversal of large color buffers.
+//!
+//! Processing an image one row at a time is fine for operations that only
+//! look at one pixel at a time, but anything that reads neighboring rows, or
+//! more than one buffer, keeps evicting the data it's about to need from the
+//! CPU cache. Splitting the image into tiles that fit in the cache keeps the
+//! working set small. [`Tiles`](struct.Tiles.html) iterates over the tiles of
+//! an image, and [`for_each_tile_mut`](fn.for_each_tile_mut.html) runs a
+//! closure for each tile in a buffer:
+//!
+//! ```
+//! use palette::tile::{for_each_tile_mut, TileSize};
+//! use palette::LinSrgb;
+//!
+//! let width = 100;
+//! let mut image = vec![LinSrgb::new(0.5f32, 0.5, 0.5); width * 80];
+//!
+//! for_each_tile_mut(&mut image, width, TileSize::for_type::<LinSrgb>(), |tile, rows| {
+//!     for (y, row) in (tile.y..).zip(rows) {
+//!         for (x, color) in (tile.x..).zip(row) {
+//!             *color = *color * (x + y) as f32 / 180.0;
+//!         }
+//!     }
+//! });
+//!
+//! assert_eq!(image[width * 79 + 99], LinSrgb::new(0.5f32, 0.5, 0.5) * 178.0 / 180.0);
+//! ```
+
+use core::mem::size_of;
+use core::slice::ChunksMut;
+
+/// The number of bytes that a tile should fit in. This is a common size for
+/// the L1 data cache.
+pub const DEFAULT_TILE_BYTES: usize = 32 * 1024;
+
+/// The size of the tiles, in pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TileSize {
+    /// The width of a tile.
+    pub width: usize,
+    /// The height of a tile.
+    pub height: usize,
+}
+
+impl TileSize {
+    /// Create a tile size. Both sides are at least `1`.
+    pub fn new(width: usize, height: usize) -> Self {
+        TileSize {
+            width: width.max(1),
+            height: height.max(1),
+        }
+    }
+
+    /// The size of the largest square tile of `T` that fits in
+    /// [`DEFAULT_TILE_BYTES`](constant.DEFAULT_TILE_BYTES.html).
+    pub fn for_type<T>() -> Self {
+        Self::for_bytes::<T>(DEFAULT_TILE_BYTES)
+    }
+
+    /// The size of the largest square tile of `T` that fits in `bytes`.
+    pub fn for_bytes<T>(bytes: usize) -> Self {
+        let pixels = bytes / size_of::<T>().max(1);
+
+        // The integer square root, rounded down.
+        let mut side = 0;
+        while (side + 1) * (side + 1) <= pixels {
+            side += 1;
+        }
+
+        Self::new(side, side)
+    }
+}
+
+/// A rectangular part of an image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tile {
+    /// The column of the top left pixel.
+    pub x: usize,
+    /// The row of the top left pixel.
+    pub y: usize,
+    /// The width of the tile. It may be less than the tile size at the right
+    /// edge of the image.
+    pub width: usize,
+    /// The height of the tile. It may be less than the tile size at the
+    /// bottom edge of the image.
+    pub height: usize,
+}
+
+/// An iterator over the tiles of an image, from left to right and top to
+/// bottom.
+#[derive(Clone, Debug)]
+pub struct Tiles {
+    width: usize,
+    height: usize,
+    size: TileSize,
+    x: usize,
+    y: usize,
+}
+
+impl Tiles {
+    /// Iterate over the tiles of an image with the size `width` times
+    /// `height`.
+    pub fn new(width: usize, height: usize, size: TileSize) -> Self {
+        let size = TileSize::new(size.width, size.height);
+        Tiles {
+            width,
+            height,
+            size,
+            x: 0,
+            y: if width == 0 { height } else { 0 },
+        }
+    }
+}
+
+impl Iterator for Tiles {
+    type Item = Tile;
+
+    fn next(&mut self) -> Option<Tile> {
+        if self.y >= self.height {
+            return None;
+        }
+
+        let tile = Tile {
+            x: self.x,
+            y: self.y,
+            width: self.size.width.min(self.width - self.x),
+            height: self.size.height.min(self.height - self.y),
+        };
+
+        self.x += self.size.width;
+        if self.x >= self.width {
+            self.x = 0;
+            self.y += self.size.height;
+        }
+
+        Some(tile)
+    }
+}
+
+/// The rows of a tile in a buffer, from top to bottom.
+#[derive(Debug)]
+pub struct TileRowsMut<'a, T> {
+    rows: ChunksMut<'a, T>,
+    x: usize,
+    width: usize,
+}
+
+impl<'a, T> Iterator for TileRowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        let row = self.rows.next()?;
+        Some(&mut row[self.x..self.x + self.width])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+/// Call `f` for each tile in `buffer`, which is an image with rows of `width`
+/// pixels. `f` gets the position of the tile and an iterator over its rows.
+///
+/// A partial row at the end of `buffer` is not included in any tile.
+pub fn for_each_tile_mut<T, F>(buffer: &mut [T], width: usize, size: TileSize, mut f: F)
+where
+    F: FnMut(Tile, TileRowsMut<'_, T>),
+{
+    if width == 0 {
+        return;
+    }
+
+    let height = buffer.len() / width;
+    let size = TileSize::new(size.width, size.height);
+    let band_length = size.height * width;
+
+    for (band_index, band) in buffer[..height * width].chunks_mut(band_length).enumerate() {
+        let band_height = band.len() / width;
+        for tile in Tiles::new(width, band_height, TileSize::new(size.width, band_height)) {
+            let tile = Tile {
+                y: band_index * size.height,
+                ..tile
+            };
+            let rows = TileRowsMut {
+                rows: band.chunks_mut(width),
+                x: tile.x,
+                width: tile.width,
+            };
+            f(tile, rows);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{for_each_tile_mut, Tile, TileSize, Tiles};
+
+    #[test]
+    fn tiles_cover_image() {
+        let tiles: Vec<_> = Tiles::new(5, 3, TileSize::new(2, 2)).collect();
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(
+            tiles[2],
+            Tile {
+                x: 4,
+                y: 0,
+                width: 1,
+                height: 2
+            }
+        );
+        assert_eq!(
+            tiles[5],
+            Tile {
+                x: 4,
+                y: 2,
+                width: 1,
+                height: 1
+            }
+        );
+
+        let area: usize = tiles.iter().map(|tile| tile.width * tile.height).sum();
+        assert_eq!(area, 15);
+        assert_eq!(Tiles::new(0, 3, TileSize::new(2, 2)).count(), 0);
+    }
+
+    #[test]
+    fn visits_each_pixel_once() {
+        let width = 7;
+        let mut buffer = vec![0u32; width * 5 + 3];
+
+        for_each_tile_mut(&mut buffer, width, TileSize::new(3, 2), |tile, rows| {
+            for (y, row) in (tile.y..).zip(rows) {
+                assert_eq!(row.len(), tile.width);
+                for (x, value) in (tile.x..).zip(row) {
+                    *value += (y * width + x) as u32 + 1;
+                }
+            }
+        });
+
+        for (index, &value) in buffer.iter().enumerate() {
+            let expected = if index < width * 5 {
+                index as u32 + 1
+            } else {
+                0
+            };
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[test]
+    fn size_for_type() {
+        assert_eq!(TileSize::for_bytes::<[f32; 4]>(1024), TileSize::new(8, 8));
+        assert_eq!(TileSize::for_bytes::<u8>(10), TileSize::new(3, 3));
+        assert_eq!(TileSize::for_bytes::<[u8; 64]>(16), TileSize::new(1, 1));
+    }
+}
@@ -0,0 +1,401 @@
+//! Time based transitions between colors, with easing.
+//!
+//! A [`Transition`](struct.Transition.html) interpolates between two colors
+//! over time, for example in a UI animation. The progress is shaped by an
+//! [`Easing`](enum.Easing.html) curve, and the colors can be mixed in another
+//! color space than the one they are stored in. Mixing the components of
+//! encoded sRGB colors darkens the middle of the transition, so it's usually
+//! better to mix in linear RGB, or in a perceptual space, such as `Lch`:
+//!
+//! ```
+//! use palette::transition::{Easing, Transition};
+//! use palette::{Lch, Srgb};
+//!
+//! let red = Srgb::new(0.8, 0.1, 0.1);
+//! let blue = Srgb::new(0.1, 0.2, 0.8);
+//!
+//! let transition = Transition::<_, Lch>::in_space(red, blue).easing(Easing::EaseInOut);
+//!
+//! let start: Srgb = transition.get(0.0);
+//! let middle: Srgb = transition.get(0.5);
+//! ```
+//!
+//! The easing curves can also be used on their own, for example to blend
+//! two gradients:
+//!
+//! ```
+//! use palette::transition::Easing;
+//! use palette::{Gradient, LinSrgb};
+//!
+//! let light = Gradient::new(vec![LinSrgb::new(1.0, 1.0, 0.8), LinSrgb::new(0.8, 0.8, 1.0)]);
+//! let dark = Gradient::new(vec![LinSrgb::new(0.2, 0.1, 0.0), LinSrgb::new(0.0, 0.1, 0.2)]);
+//!
+//! let progress = Easing::Ease.apply(0.3);
+//! let current = light.blend_with(&dark, progress);
+//! ```
+
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use crate::{clamp, from_f64, FloatComponent, FromColor, Mix};
+
+/// A curve that maps the linear progress of a transition to the progress of
+/// the color.
+///
+/// The named curves are the same as the ones in CSS.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing<T = f32> {
+    /// Keep the progress as it is.
+    #[default]
+    Linear,
+
+    /// Start a bit slow, speed up quickly and slow down towards the end.
+    /// The same as `CubicBezier(0.25, 0.1, 0.25, 1.0)`.
+    Ease,
+
+    /// Start slow and end fast. The same as
+    /// `CubicBezier(0.42, 0.0, 1.0, 1.0)`.
+    EaseIn,
+
+    /// Start fast and end slow. The same as
+    /// `CubicBezier(0.0, 0.0, 0.58, 1.0)`.
+    EaseOut,
+
+    /// Start and end slow. The same as `CubicBezier(0.42, 0.0, 0.58, 1.0)`.
+    EaseInOut,
+
+    /// Start and end slow, with the smoothstep polynomial `3x² - 2x³`. It's
+    /// cheaper than the Bézier curves.
+    Smoothstep,
+
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)`, with the control
+    /// points `(x1, y1)` and `(x2, y2)`. The `x` coordinates are clamped to
+    /// `[0.0, 1.0]`.
+    CubicBezier(T, T, T, T),
+
+    /// Jump between a number of fixed steps, starting at `0.0` and reaching
+    /// `1.0` only at the end.
+    Steps(u32),
+}
+
+impl<T: FloatComponent> Easing<T> {
+    /// Apply the curve to `progress`, which is clamped to `[0.0, 1.0]`.
+    ///
+    /// ```
+    /// use palette::transition::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    /// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    /// assert_eq!(Easing::Steps(4).apply(0.3), 0.25);
+    /// ```
+    pub fn apply(&self, progress: T) -> T {
+        let progress = clamp(progress, T::zero(), T::one());
+
+        match *self {
+            Easing::Linear => progress,
+            Easing::Ease => cubic_bezier(
+                from_f64(0.25),
+                from_f64(0.1),
+                from_f64(0.25),
+                T::one(),
+                progress,
+            ),
+            Easing::EaseIn => cubic_bezier(from_f64(0.42), T::zero(), T::one(), T::one(), progress),
+            Easing::EaseOut => {
+                cubic_bezier(T::zero(), T::zero(), from_f64(0.58), T::one(), progress)
+            }
+            Easing::EaseInOut => cubic_bezier(
+                from_f64(0.42),
+                T::zero(),
+                from_f64(0.58),
+                T::one(),
+                progress,
+            ),
+            Easing::Smoothstep => {
+                progress * progress * (from_f64::<T>(3.0) - from_f64::<T>(2.0) * progress)
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(
+                clamp(x1, T::zero(), T::one()),
+                y1,
+                clamp(x2, T::zero(), T::one()),
+                y2,
+                progress,
+            ),
+            Easing::Steps(0) => progress,
+            Easing::Steps(steps) => {
+                let steps: T = from_f64(f64::from(steps));
+                (progress * steps).floor() / steps
+            }
+        }
+    }
+}
+
+/// An easing curve, that maps the linear progress between two colors to the
+/// progress of the color.
+///
+/// It's implemented for [`Easing`](enum.Easing.html) and for closures, such
+/// as `|x: f32| x * x`, so custom curves can be used where the named curves
+/// are not enough. The curve should map `0.0` to `0.0` and `1.0` to `1.0`.
+pub trait Ease<T> {
+    /// Apply the curve to `progress`, which goes from `0.0` to `1.0`.
+    fn ease(&self, progress: T) -> T;
+}
+
+impl<T: FloatComponent> Ease<T> for Easing<T> {
+    fn ease(&self, progress: T) -> T {
+        self.apply(progress)
+    }
+}
+
+impl<T, F: Fn(T) -> T> Ease<T> for F {
+    fn ease(&self, progress: T) -> T {
+        self(progress)
+    }
+}
+
+/// Evaluate a cubic Bézier curve from `(0, 0)` to `(1, 1)` at `x`, by
+/// finding the curve parameter for `x` and returning the `y` for it.
+fn cubic_bezier<T: FloatComponent>(x1: T, y1: T, x2: T, y2: T, x: T) -> T {
+    // The polynomial coefficients of each coordinate, from the control
+    // points.
+    let three: T = from_f64(3.0);
+    let (cx, cy) = (three * x1, three * y1);
+    let (bx, by) = (three * (x2 - x1) - cx, three * (y2 - y1) - cy);
+    let (ax, ay) = (T::one() - cx - bx, T::one() - cy - by);
+
+    let sample_x = |t: T| ((ax * t + bx) * t + cx) * t;
+    let sample_y = |t: T| ((ay * t + by) * t + cy) * t;
+    let slope_x = |t: T| (three * ax * t + from_f64::<T>(2.0) * bx) * t + cx;
+
+    let epsilon: T = from_f64(1e-7);
+
+    // Newton's method converges quickly for most curves...
+    let mut t = x;
+    for _ in 0..8 {
+        let error = sample_x(t) - x;
+        if error.abs() < epsilon {
+            return sample_y(t);
+        }
+
+        let slope = slope_x(t);
+        if slope.abs() < epsilon {
+            break;
+        }
+
+        t = t - error / slope;
+    }
+
+    // ...but falls back to bisection for flat parts, since `x` is
+    // monotonic in `t`.
+    let (mut low, mut high) = (T::zero(), T::one());
+    t = x;
+    for _ in 0..64 {
+        let current = sample_x(t);
+        if (current - x).abs() < epsilon {
+            break;
+        }
+
+        if current < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / from_f64(2.0);
+    }
+
+    sample_y(t)
+}
+
+/// A transition from one color to another, where the colors are stored as
+/// `C` and mixed in the color space `S`.
+///
+/// The progress of the transition goes from `0.0` to `1.0`, and is shaped
+/// by an [`Easing`](enum.Easing.html) curve before the colors are mixed. See
+/// the [module documentation](index.html) for an example.
+#[derive(Clone, Debug)]
+pub struct Transition<C, S: Mix = C> {
+    from: S,
+    to: S,
+    easing: Easing<S::Scalar>,
+    color_type: PhantomData<C>,
+}
+
+impl<C: Mix + Clone> Transition<C, C> {
+    /// Create a linear transition from `from` to `to`, that mixes the colors
+    /// in their own color space.
+    pub fn new(from: C, to: C) -> Self {
+        Transition {
+            from,
+            to,
+            easing: Easing::Linear,
+            color_type: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Transition<C, S>
+where
+    S: Mix + Clone,
+    S::Scalar: FloatComponent,
+{
+    /// Create a linear transition from `from` to `to`, that mixes the colors
+    /// in the color space `S`.
+    pub fn in_space(from: C, to: C) -> Self
+    where
+        S: FromColor<C>,
+    {
+        Transition {
+            from: S::from_color(from),
+            to: S::from_color(to),
+            easing: Easing::Linear,
+            color_type: PhantomData,
+        }
+    }
+
+    /// Change the easing curve of the transition.
+    pub fn easing(mut self, easing: Easing<S::Scalar>) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Get the color at `progress`, which is clamped to `[0.0, 1.0]`.
+    pub fn get(&self, progress: S::Scalar) -> C
+    where
+        C: FromColor<S>,
+    {
+        let factor = self.easing.apply(progress);
+        C::from_color(self.from.mix(&self.to, factor))
+    }
+
+    /// Get the color after `elapsed` time of a transition that lasts for
+    /// `duration`. A transition with zero duration is always at its end.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use palette::transition::Transition;
+    /// use palette::LinSrgb;
+    ///
+    /// let transition = Transition::new(LinSrgb::new(0.0, 0.0, 0.0), LinSrgb::new(1.0, 1.0, 1.0));
+    /// let duration = Duration::from_millis(200);
+    ///
+    /// let color = transition.at(Duration::from_millis(50), duration);
+    /// assert_eq!(color, LinSrgb::new(0.25, 0.25, 0.25));
+    /// ```
+    pub fn at(&self, elapsed: Duration, duration: Duration) -> C
+    where
+        C: FromColor<S>,
+    {
+        if duration == Duration::from_secs(0) {
+            return self.get(from_f64(1.0));
+        }
+
+        self.get(from_f64(elapsed.as_secs_f64() / duration.as_secs_f64()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{cubic_bezier, Easing, Transition};
+    use crate::{Hsl, LinSrgb, Srgb};
+
+    #[test]
+    fn easing_ends() {
+        let curves = [
+            Easing::Linear,
+            Easing::Ease,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Smoothstep,
+            Easing::CubicBezier(0.1, -0.5, 0.9, 1.5),
+            Easing::Steps(5),
+        ];
+
+        for curve in &curves {
+            assert_relative_eq!(curve.apply(0.0f64), 0.0, epsilon = 1e-6);
+            assert_relative_eq!(curve.apply(1.0f64), 1.0, epsilon = 1e-6);
+            assert_relative_eq!(curve.apply(-1.0f64), 0.0, epsilon = 1e-6);
+            assert_relative_eq!(curve.apply(2.0f64), 1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_values() {
+        // A straight line is the identity.
+        for &x in &[0.1, 0.35, 0.5, 0.9] {
+            assert_relative_eq!(cubic_bezier(0.0, 0.0, 1.0, 1.0, x), x, epsilon = 1e-6);
+        }
+
+        // Ease in and out is symmetric.
+        for &x in &[0.1, 0.35, 0.5] {
+            let early = Easing::EaseInOut.apply(x);
+            let late = Easing::EaseInOut.apply(1.0 - x);
+            assert_relative_eq!(early, 1.0 - late, epsilon = 1e-6);
+        }
+        assert_relative_eq!(Easing::EaseInOut.apply(0.5f64), 0.5, epsilon = 1e-6);
+        assert_relative_eq!(Easing::Smoothstep.apply(0.25f64), 0.15625);
+
+        // Flat ends need the bisection fallback.
+        let flat = Easing::CubicBezier(1.0, 0.0, 0.0, 1.0);
+        assert_relative_eq!(flat.apply(0.5f64), 0.5, epsilon = 1e-6);
+        assert!(flat.apply(0.01f64) < 0.01);
+    }
+
+    #[test]
+    fn steps() {
+        let steps = Easing::Steps(4);
+        assert_eq!(steps.apply(0.0f64), 0.0);
+        assert_eq!(steps.apply(0.24f64), 0.0);
+        assert_eq!(steps.apply(0.25f64), 0.25);
+        assert_eq!(steps.apply(0.99f64), 0.75);
+        assert_eq!(steps.apply(1.0f64), 1.0);
+    }
+
+    #[test]
+    fn transition() {
+        let transition =
+            Transition::new(LinSrgb::new(0.0f64, 0.0, 0.0), LinSrgb::new(1.0, 0.5, 0.0))
+                .easing(Easing::Steps(2));
+
+        assert_relative_eq!(transition.get(0.3), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(transition.get(0.6), LinSrgb::new(0.5, 0.25, 0.0));
+        assert_relative_eq!(transition.get(1.0), LinSrgb::new(1.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn in_space() {
+        let red = Srgb::new(1.0f64, 0.0, 0.0);
+        let blue = Srgb::new(0.0, 0.0, 1.0);
+        let transition = Transition::<_, Hsl<_, f64>>::in_space(red, blue);
+
+        // The hue goes the short way, through magenta.
+        let middle: Srgb<f64> = transition.get(0.5);
+        assert_relative_eq!(middle, Srgb::new(1.0, 0.0, 1.0), epsilon = 1e-12);
+        assert_relative_eq!(transition.get(0.0), red, epsilon = 1e-12);
+        assert_relative_eq!(transition.get(1.0), blue, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn at() {
+        let transition =
+            Transition::new(LinSrgb::new(0.0f64, 0.0, 0.0), LinSrgb::new(1.0, 1.0, 1.0));
+        let duration = Duration::from_millis(400);
+
+        assert_relative_eq!(
+            transition.at(Duration::from_millis(100), duration),
+            LinSrgb::new(0.25, 0.25, 0.25)
+        );
+        assert_relative_eq!(
+            transition.at(Duration::from_secs(1), duration),
+            LinSrgb::new(1.0, 1.0, 1.0)
+        );
+        assert_relative_eq!(
+            transition.at(Duration::from_secs(0), Duration::from_secs(0)),
+            LinSrgb::new(1.0, 1.0, 1.0)
+        );
+    }
+}
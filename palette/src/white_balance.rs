@@ -0,0 +1,302 @@
+//! Estimation of the light that illuminated a scene.
+//!
+//! The color of the light in a photo, or any other captured image, tints the
+//! whole image. [`estimate_illuminant`](fn.estimate_illuminant.html) makes an
+//! educated guess of that color from the pixels alone, which can then be
+//! corrected with [`ColorMatrix::white_balance`](../adjust/struct.ColorMatrix.html#method.white_balance).
+//!
+//! ```
+//! use palette::adjust::ColorMatrix;
+//! use palette::white_balance::{estimate_illuminant, Estimator};
+//! use palette::LinSrgb;
+//!
+//! // A scene under warm light.
+//! let mut pixels: [LinSrgb; 3] = [
+//!     LinSrgb::new(0.9, 0.6, 0.3),
+//!     LinSrgb::new(0.2, 0.3, 0.3),
+//!     LinSrgb::new(0.5, 0.2, 0.1),
+//! ];
+//!
+//! let illuminant = estimate_illuminant(&pixels, Estimator::GrayWorld);
+//! ColorMatrix::white_balance(illuminant).apply_slice(&mut pixels);
+//!
+//! let sum = pixels[0] + pixels[1] + pixels[2];
+//! assert!((sum.red - sum.blue).abs() < 1e-4);
+//! ```
+//!
+//! When the image has a known neutral surface, such as a gray card,
+//! [`white_balance_from_neutral`](fn.white_balance_from_neutral.html) gives
+//! the per channel gains that make it gray, which is how most cameras
+//! correct the white balance:
+//!
+//! ```
+//! use palette::white_balance::white_balance_from_neutral;
+//! use palette::LinSrgb;
+//!
+//! let gray_card = LinSrgb::new(0.5f32, 0.4, 0.25);
+//! let gains = white_balance_from_neutral(gray_card).unwrap();
+//!
+//! let mut pixels = [gray_card, LinSrgb::new(0.2, 0.1, 0.1)];
+//! gains.apply_slice(&mut pixels);
+//! assert_eq!(pixels[0], LinSrgb::new(0.4, 0.4, 0.4));
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::adjust::ColorMatrix;
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Linear;
+use crate::math::powf;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// A method for estimating the illuminant of a scene.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Estimator<T = f32> {
+    /// Assume that the average color of the scene is gray.
+    GrayWorld,
+
+    /// Assume that the largest value of each channel comes from a white
+    /// surface.
+    ///
+    /// This is sensitive to clipped highlights and noise, so it's a good idea
+    /// to leave out clipped pixels.
+    WhitePatch,
+
+    /// Assume that the Minkowski p-norm of each channel is gray.
+    ///
+    /// This is a generalization of the other two methods, where `p = 1` is the
+    /// gray world assumption and an infinite `p` is the white patch
+    /// assumption. A `p` around 6 tends to work better than either of them.
+    ShadesOfGray(T),
+}
+
+/// Estimate the illuminant of a scene from its linear colors.
+///
+/// The result is normalized to have a luminance of `1.0`. The white point of
+/// the space is returned if the colors are empty or all black. Negative
+/// components are treated as `0.0`.
+pub fn estimate_illuminant<S, T>(
+    colors: &[Rgb<Linear<S>, T>],
+    estimator: Estimator<T>,
+) -> Xyz<S::WhitePoint, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    let zero = Rgb::<Linear<S>, T>::new(T::zero(), T::zero(), T::zero());
+    let positive = |color: &Rgb<Linear<S>, T>| {
+        Rgb::<Linear<S>, T>::new(
+            color.red.max(T::zero()),
+            color.green.max(T::zero()),
+            color.blue.max(T::zero()),
+        )
+    };
+
+    let estimate = match estimator {
+        Estimator::GrayWorld => colors.iter().map(positive).fold(zero, |sum, c| sum + c),
+        Estimator::WhitePatch => colors.iter().map(positive).fold(zero, |max, c| {
+            Rgb::new(
+                max.red.max(c.red),
+                max.green.max(c.green),
+                max.blue.max(c.blue),
+            )
+        }),
+        Estimator::ShadesOfGray(p) => {
+            let sum = colors.iter().map(positive).fold(zero, |sum, c| {
+                sum + Rgb::new(powf(c.red, p), powf(c.green, p), powf(c.blue, p))
+            });
+            let count = from_f64::<T>(colors.len().max(1) as f64);
+            let inverse_p = T::one() / p;
+
+            Rgb::new(
+                powf(sum.red / count, inverse_p),
+                powf(sum.green / count, inverse_p),
+                powf(sum.blue / count, inverse_p),
+            )
+        }
+    };
+
+    let illuminant = Xyz::from_color_unclamped(estimate);
+    if illuminant.y > T::zero() {
+        illuminant / illuminant.y
+    } else {
+        S::WhitePoint::get_xyz()
+    }
+}
+
+/// Gains for each channel of linear RGB colors in the space `S`.
+///
+/// Multiplying each channel with its own gain is the von Kries model of
+/// chromatic adaptation, applied directly to the RGB channels. It's cheaper
+/// than a full chromatic adaptation, such as
+/// [`ColorMatrix::white_balance`](../adjust/struct.ColorMatrix.html#method.white_balance),
+/// and it's what cameras usually do for white balance.
+#[derive(Debug)]
+pub struct ChannelGains<S, T = f32> {
+    /// The gain for the red channel.
+    pub red: T,
+    /// The gain for the green channel.
+    pub green: T,
+    /// The gain for the blue channel.
+    pub blue: T,
+    space: PhantomData<S>,
+}
+
+impl<S, T> ChannelGains<S, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    /// Create gains for each channel.
+    pub fn new(red: T, green: T, blue: T) -> Self {
+        ChannelGains {
+            red,
+            green,
+            blue,
+            space: PhantomData,
+        }
+    }
+
+    /// Apply the gains to `color`.
+    #[inline]
+    pub fn apply(&self, color: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T> {
+        Rgb::new(
+            color.red * self.red,
+            color.green * self.green,
+            color.blue * self.blue,
+        )
+    }
+
+    /// Apply the gains to each color in `colors`.
+    pub fn apply_slice(&self, colors: &mut [Rgb<Linear<S>, T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+
+    /// The gains as a diagonal color matrix, that can be combined with other
+    /// adjustments.
+    pub fn into_matrix(self) -> ColorMatrix<S, T> {
+        let z = T::zero();
+        ColorMatrix::from_matrix([self.red, z, z, z, self.green, z, z, z, self.blue])
+    }
+}
+
+impl<S, T: Clone> Clone for ChannelGains<S, T> {
+    fn clone(&self) -> Self {
+        ChannelGains {
+            red: self.red.clone(),
+            green: self.green.clone(),
+            blue: self.blue.clone(),
+            space: PhantomData,
+        }
+    }
+}
+
+impl<S, T: Copy> Copy for ChannelGains<S, T> {}
+
+/// Find the gains that make `measured_neutral` gray, where it's the linear
+/// color of a surface that should be neutral, such as a gray card.
+///
+/// The green gain is always `1.0`, so the exposure stays the same for the
+/// green channel, as is common in cameras. Returns `None` if any of the
+/// channels of `measured_neutral` is not positive and finite.
+pub fn white_balance_from_neutral<S, T>(
+    measured_neutral: Rgb<Linear<S>, T>,
+) -> Option<ChannelGains<S, T>>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    let valid = |value: T| value > T::zero() && value.is_finite();
+    if !valid(measured_neutral.red)
+        || !valid(measured_neutral.green)
+        || !valid(measured_neutral.blue)
+    {
+        return None;
+    }
+
+    Some(ChannelGains::new(
+        measured_neutral.green / measured_neutral.red,
+        T::one(),
+        measured_neutral.green / measured_neutral.blue,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{estimate_illuminant, white_balance_from_neutral, Estimator};
+    use crate::convert::FromColorUnclamped;
+    use crate::encoding::Srgb;
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{LinSrgb, Xyz};
+
+    fn scene() -> [LinSrgb<f64>; 4] {
+        [
+            LinSrgb::new(0.4, 0.2, 0.1),
+            LinSrgb::new(0.1, 0.4, 0.2),
+            LinSrgb::new(0.8, 0.7, 0.2),
+            LinSrgb::new(0.3, 0.1, -0.1),
+        ]
+    }
+
+    fn normalized(color: LinSrgb<f64>) -> Xyz<D65, f64> {
+        let xyz = Xyz::from_color_unclamped(color);
+        xyz / xyz.y
+    }
+
+    #[test]
+    fn gray_world() {
+        let illuminant = estimate_illuminant(&scene(), Estimator::GrayWorld);
+        assert_relative_eq!(
+            illuminant,
+            normalized(LinSrgb::new(1.6, 1.4, 0.5)),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn white_patch() {
+        let illuminant = estimate_illuminant(&scene(), Estimator::WhitePatch);
+        assert_relative_eq!(illuminant, normalized(LinSrgb::new(0.8, 0.7, 0.2)));
+    }
+
+    #[test]
+    fn shades_of_gray() {
+        let gray_world = estimate_illuminant(&scene(), Estimator::GrayWorld);
+        let one = estimate_illuminant(&scene(), Estimator::ShadesOfGray(1.0));
+        assert_relative_eq!(one, gray_world, epsilon = 1e-12);
+
+        let white_patch = estimate_illuminant(&scene(), Estimator::WhitePatch);
+        let large = estimate_illuminant(&scene(), Estimator::ShadesOfGray(200.0));
+        assert_relative_eq!(large, white_patch, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn no_information() {
+        let black = [LinSrgb::new(0.0, 0.0, 0.0)];
+        assert_relative_eq!(
+            estimate_illuminant(&black, Estimator::GrayWorld),
+            D65::get_xyz()
+        );
+        assert_relative_eq!(
+            estimate_illuminant::<Srgb, f64>(&[], Estimator::ShadesOfGray(6.0)),
+            D65::get_xyz()
+        );
+    }
+
+    #[test]
+    fn from_neutral() {
+        let neutral = LinSrgb::new(0.6f64, 0.3, 0.15);
+        let gains = white_balance_from_neutral(neutral).unwrap();
+        assert_relative_eq!(gains.apply(neutral), LinSrgb::new(0.3, 0.3, 0.3));
+
+        let color = LinSrgb::new(0.2, 0.5, 0.9);
+        assert_relative_eq!(gains.into_matrix().apply(color), gains.apply(color));
+
+        assert!(white_balance_from_neutral(LinSrgb::new(0.5f64, 0.0, 0.5)).is_none());
+        assert!(white_balance_from_neutral(LinSrgb::new(0.5, 0.5, f64::NAN)).is_none());
+    }
+}
@@ -0,0 +1,195 @@
+//! Whiteness and yellowness indices.
+//!
+//! Materials such as paper, plastics and textiles are often graded by how
+//! white, or how yellow, they look. The indices in this module follow CIE 15
+//! and ASTM E313, and are defined for a few combinations of illuminant and
+//! standard observer. These combinations are the white points that implement
+//! [`IndexWhitePoint`](trait.IndexWhitePoint.html).
+//!
+//! The indices are calculated from the color of the material, as seen under
+//! the illuminant of the white point. Any color that can be converted to
+//! `Xyz` with that white point, such as `Lab`, can be used.
+//!
+//! ```
+//! use palette::white_point::D65;
+//! use palette::whiteness::{cie_whiteness, yellowness_index};
+//! use palette::Lab;
+//!
+//! let paper = Lab::<D65, f64>::new(95.0, 1.5, -6.0);
+//! assert!(cie_whiteness(paper) > 110.0);
+//! assert!(yellowness_index(paper) < 0.0);
+//! ```
+
+use crate::convert::IntoColorUnclamped;
+use crate::white_point::{D65Degree10, WhitePoint, C, D65};
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// A white point that the whiteness and yellowness indices are defined for.
+pub trait IndexWhitePoint: WhitePoint {
+    /// The coefficients `C_X` and `C_Z` of the ASTM E313 yellowness index.
+    fn yellowness_coefficients() -> (f64, f64);
+
+    /// The factor for the difference in `x` in the CIE tint formula, which
+    /// depends on the standard observer.
+    fn tint_factor() -> f64;
+}
+
+impl IndexWhitePoint for D65 {
+    fn yellowness_coefficients() -> (f64, f64) {
+        (1.2985, 1.1335)
+    }
+
+    fn tint_factor() -> f64 {
+        1000.0
+    }
+}
+
+impl IndexWhitePoint for D65Degree10 {
+    fn yellowness_coefficients() -> (f64, f64) {
+        (1.3013, 1.1498)
+    }
+
+    fn tint_factor() -> f64 {
+        900.0
+    }
+}
+
+impl IndexWhitePoint for C {
+    fn yellowness_coefficients() -> (f64, f64) {
+        (1.2769, 1.0592)
+    }
+
+    fn tint_factor() -> f64 {
+        1000.0
+    }
+}
+
+/// The CIE whiteness index, W, which is also the ASTM E313 whiteness index.
+///
+/// The perfect reflecting diffuser has a whiteness of `100.0`, and higher
+/// values are whiter, usually because of a blue tint or fluorescence. The
+/// formula is only meant for colors that are close to white.
+pub fn cie_whiteness<Wp, T, Color>(color: Color) -> T
+where
+    Wp: IndexWhitePoint,
+    T: FloatComponent,
+    Color: IntoColorUnclamped<Xyz<Wp, T>>,
+{
+    let (luminance, dx, dy) = chromaticity_offset(color.into_color_unclamped());
+    luminance + from_f64::<T>(800.0) * dx + from_f64::<T>(1700.0) * dy
+}
+
+/// The CIE tint index, T.
+///
+/// Positive values are greenish and negative values are reddish. Colors with
+/// a tint between `-3.0` and `3.0` are usually considered neutral.
+pub fn cie_tint<Wp, T, Color>(color: Color) -> T
+where
+    Wp: IndexWhitePoint,
+    T: FloatComponent,
+    Color: IntoColorUnclamped<Xyz<Wp, T>>,
+{
+    let (_, dx, dy) = chromaticity_offset(color.into_color_unclamped());
+    from_f64::<T>(Wp::tint_factor()) * dx - from_f64::<T>(650.0) * dy
+}
+
+/// The ASTM E313 yellowness index, YI.
+///
+/// White and gray colors have an index of `0.0`, yellowish colors have a
+/// positive index and bluish colors have a negative index. Black has an index
+/// of `0.0`.
+pub fn yellowness_index<Wp, T, Color>(color: Color) -> T
+where
+    Wp: IndexWhitePoint,
+    T: FloatComponent,
+    Color: IntoColorUnclamped<Xyz<Wp, T>>,
+{
+    let color: Xyz<Wp, T> = color.into_color_unclamped();
+    if color.y <= T::zero() {
+        return T::zero();
+    }
+
+    let (cx, cz) = Wp::yellowness_coefficients();
+    from_f64::<T>(100.0) * (from_f64::<T>(cx) * color.x - from_f64::<T>(cz) * color.z) / color.y
+}
+
+/// The luminance of `color`, from `0.0` to `100.0`, and the distance from
+/// its chromaticity to the chromaticity of the white point.
+fn chromaticity_offset<Wp, T>(color: Xyz<Wp, T>) -> (T, T, T)
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let white: Xyz<Wp, T> = Wp::get_xyz();
+    let white_sum = white.x + white.y + white.z;
+    let sum = color.x + color.y + color.z;
+
+    let (x, y) = if sum > T::zero() {
+        (color.x / sum, color.y / sum)
+    } else {
+        (T::zero(), T::zero())
+    };
+
+    (
+        color.y * from_f64(100.0),
+        white.x / white_sum - x,
+        white.y / white_sum - y,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cie_tint, cie_whiteness, yellowness_index};
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::{D65Degree10, WhitePoint, C, D65};
+    use crate::{Lab, Xyz};
+
+    #[test]
+    fn perfect_white() {
+        let white: Xyz<D65, f64> = D65::get_xyz();
+        assert_relative_eq!(cie_whiteness(white), 100.0, epsilon = 1e-9);
+        assert_relative_eq!(cie_tint(white), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(yellowness_index(white), 0.0, epsilon = 0.05);
+
+        let white: Xyz<D65Degree10, f64> = D65Degree10::get_xyz();
+        assert_relative_eq!(cie_whiteness(white), 100.0, epsilon = 1e-9);
+        assert_relative_eq!(yellowness_index(white), 0.0, epsilon = 0.05);
+
+        let white: Xyz<C, f64> = C::get_xyz();
+        assert_relative_eq!(yellowness_index(white), 0.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn tinted() {
+        let yellow = Xyz::from_color_unclamped(Lab::<D65, f64>::new(90.0, 0.0, 10.0));
+        let blue = Xyz::from_color_unclamped(Lab::<D65, f64>::new(90.0, 0.0, -5.0));
+        let green = Xyz::from_color_unclamped(Lab::<D65, f64>::new(90.0, -5.0, 0.0));
+
+        assert!(yellowness_index(yellow) > 15.0);
+        assert!(yellowness_index(blue) < 0.0);
+        assert!(cie_whiteness(blue) > cie_whiteness(yellow));
+        assert!(cie_tint(green) > 3.0);
+
+        assert_relative_eq!(
+            yellowness_index(Lab::<D65, f64>::new(90.0, 0.0, 10.0)),
+            yellowness_index(yellow)
+        );
+        assert_eq!(yellowness_index(Xyz::<D65, f64>::new(0.0, 0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn reference_value() {
+        // X = 80, Y = 85, Z = 100 under D65 and the 2° observer.
+        let color = Xyz::<D65, f64>::new(0.80, 0.85, 1.00);
+        assert_relative_eq!(
+            yellowness_index(color),
+            100.0 * (1.2985 * 80.0 - 1.1335 * 100.0) / 85.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            cie_whiteness(color),
+            85.0 + 800.0 * (0.312727 - 80.0 / 265.0) + 1700.0 * (0.329023 - 85.0 / 265.0),
+            epsilon = 1e-2
+        );
+    }
+}
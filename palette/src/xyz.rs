@@ -8,6 +8,7 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
+use crate::cmf;
 use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
 use crate::luma::LumaStandard;
@@ -15,8 +16,8 @@ use crate::matrix::{multiply_rgb_to_xyz, rgb_to_xyz_matrix};
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Clamp, Component, ComponentWise, FloatComponent, Lab,
-    Luma, Luv, Mix, Pixel, RelativeContrast, Shade, Yxy,
+    clamp, from_f64, Alpha, Clamp, ColorPoint, Component, ComponentWise, FloatComponent, Lab, Luma,
+    Luv, Mix, Pixel, RelativeContrast, Shade, Yxy,
 };
 
 /// CIE 1931 XYZ with an alpha component. See the [`Xyza` implementation in
@@ -123,6 +124,27 @@ where
         Self::with_wp(x, y, z)
     }
 
+    /// The color of monochromatic light with the `wavelength`, in
+    /// nanometers, for the CIE 1931 2° standard observer.
+    ///
+    /// The light has unit radiant power, so `y` is `1.0` at 555 nm, where the
+    /// eye is the most sensitive. The color matching functions are tabulated
+    /// from 380 nm to 780 nm in steps of 5 nm, and linearly interpolated in
+    /// between. Wavelengths outside the table are black.
+    ///
+    /// ```
+    /// use palette::white_point::D65;
+    /// use palette::Xyz;
+    ///
+    /// let green: Xyz<D65, f64> = Xyz::from_wavelength(520.0);
+    /// assert!(green.y > 0.7 && green.y < 0.72);
+    /// ```
+    pub fn from_wavelength(wavelength: T) -> Self {
+        let [x, y, z] = wavelength.to_f64().map_or([0.0; 3], cmf::cie_1931);
+
+        Self::with_wp(from_f64(x), from_f64(y), from_f64(z))
+    }
+
     /// Return the `x` value minimum.
     pub fn min_x() -> T {
         T::zero()
@@ -433,6 +455,27 @@ where
     }
 }
 
+impl<Wp, T> ColorPoint for Xyz<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Xyz<Wp, T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn linear_combination(&self, a: T, other: &Xyz<Wp, T>, b: T) -> Xyz<Wp, T> {
+        Xyz {
+            x: a * self.x + b * other.x,
+            y: a * self.y + b * other.y,
+            z: a * self.z + b * other.z,
+            white_point: PhantomData,
+        }
+    }
+}
+
 impl<Wp, T> ComponentWise for Xyz<Wp, T>
 where
     T: FloatComponent,
@@ -730,8 +773,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        contrast_ratio(self.y, other.y)
+    fn relative_luminance(&self) -> T {
+        self.y
     }
 }
 
@@ -853,6 +896,23 @@ mod test {
     const Y_N: f64 = 1.0;
     const Z_N: f64 = 1.08883;
 
+    #[test]
+    fn wavelength() {
+        let xyz = Xyz::<D65, f64>::from_wavelength(555.0);
+        assert_relative_eq!(xyz.y, 1.0, epsilon = 1e-3);
+
+        let xyz = Xyz::<D65, f64>::from_wavelength(520.0);
+        let (x, y) = crate::chromaticity::spectral_xy(520.0);
+        let sum = xyz.x + xyz.y + xyz.z;
+        assert_relative_eq!(xyz.x / sum, x, epsilon = 1e-12);
+        assert_relative_eq!(xyz.y / sum, y, epsilon = 1e-12);
+
+        assert_eq!(
+            Xyz::<D65, f64>::from_wavelength(800.0),
+            Xyz::new(0.0, 0.0, 0.0)
+        );
+    }
+
     #[test]
     fn luma() {
         let a = Xyz::from_color(LinLuma::new(0.5));
@@ -0,0 +1,183 @@
+//! Y′CbCr, the color encoding of digital video and JPEG.
+//!
+//! [`YCbCr`](struct.YCbCr.html) splits a gamma encoded RGB color into a luma
+//! component, `y`, and two color difference components, `cb` and `cr`. The
+//! weights of the red, green and blue components in the luma are given by a
+//! [`YCbCrMatrix`](trait.YCbCrMatrix.html), such as
+//! [`Bt709`](struct.Bt709.html) for HD video. The components are in full
+//! range, with `y` from `0.0` to `1.0`, and `cb` and `cr` from `-0.5` to
+//! `0.5`.
+//!
+//! ```
+//! use palette::ycbcr::{Bt709, YCbCr};
+//! use palette::Srgb;
+//!
+//! let color = Srgb::new(1.0, 0.0, 0.0);
+//! let ycbcr = YCbCr::<Bt709>::from_rgb(color);
+//! assert!((ycbcr.y - 0.2126).abs() < 1e-6);
+//! assert!((ycbcr.cr - 0.5).abs() < 1e-6);
+//!
+//! let rgb: Srgb = ycbcr.into_rgb();
+//! assert!((rgb.red - 1.0).abs() < 1e-6);
+//! ```
+//!
+//! Video frames usually store the chroma components with a lower resolution
+//! than the luma. [`YCbCrPlanes`](struct.YCbCrPlanes.html) holds the three
+//! planes of such a frame, and converts to and from full resolution pixels
+//! with the [`Subsampling`](enum.Subsampling.html),
+//! [`ChromaSiting`](enum.ChromaSiting.html) and
+//! [`Filter`](enum.Filter.html) of choice.
+//!
+//! The components are stored as integer codes in files and video streams,
+//! usually in limited range. [`SignalRange`](enum.SignalRange.html) converts
+//! between the components and the codes, and between limited and full range
+//! codes.
+
+use core::marker::PhantomData;
+
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{from_f64, FloatComponent};
+
+pub use self::range::SignalRange;
+#[cfg(feature = "std")]
+pub use self::subsampling::{
+    downsample_chroma, upsample_chroma, ChromaSiting, Filter, Subsampling, YCbCrPlanes,
+};
+
+mod range;
+#[cfg(feature = "std")]
+mod subsampling;
+
+/// The weights of the red and blue components in the luma of a Y′CbCr
+/// color. The weight of green is `1 - KR - KB`.
+pub trait YCbCrMatrix {
+    /// The weight of the red component.
+    const KR: f64;
+
+    /// The weight of the blue component.
+    const KB: f64;
+}
+
+/// The Y′CbCr matrix from ITU-R BT.601, for standard definition video and
+/// JPEG.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bt601;
+
+impl YCbCrMatrix for Bt601 {
+    const KR: f64 = 0.299;
+    const KB: f64 = 0.114;
+}
+
+/// The Y′CbCr matrix from ITU-R BT.709, for high definition video.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bt709;
+
+impl YCbCrMatrix for Bt709 {
+    const KR: f64 = 0.2126;
+    const KB: f64 = 0.0722;
+}
+
+/// The non-constant luminance Y′CbCr matrix from ITU-R BT.2020, for ultra
+/// high definition video.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bt2020;
+
+impl YCbCrMatrix for Bt2020 {
+    const KR: f64 = 0.2627;
+    const KB: f64 = 0.0593;
+}
+
+/// A full range Y′CbCr color, with the matrix `M`.
+///
+/// See the [module documentation](index.html) for more details.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct YCbCr<M = Bt709, T = f32> {
+    /// The luma, from `0.0` to `1.0`.
+    pub y: T,
+
+    /// The blue difference, from `-0.5` to `0.5`.
+    pub cb: T,
+
+    /// The red difference, from `-0.5` to `0.5`.
+    pub cr: T,
+
+    /// The Y′CbCr matrix of the color.
+    pub matrix: PhantomData<M>,
+}
+
+impl<M, T> YCbCr<M, T>
+where
+    M: YCbCrMatrix,
+    T: FloatComponent,
+{
+    /// Create a Y′CbCr color.
+    pub fn new(y: T, cb: T, cr: T) -> Self {
+        YCbCr {
+            y,
+            cb,
+            cr,
+            matrix: PhantomData,
+        }
+    }
+
+    /// Convert a gamma encoded RGB color to Y′CbCr.
+    pub fn from_rgb<S: RgbStandard>(color: Rgb<S, T>) -> Self {
+        let (kr, kb) = weights::<M, T>();
+        let y = kr * color.red + (T::one() - kr - kb) * color.green + kb * color.blue;
+        let two: T = from_f64(2.0);
+
+        YCbCr::new(
+            y,
+            (color.blue - y) / (two * (T::one() - kb)),
+            (color.red - y) / (two * (T::one() - kr)),
+        )
+    }
+
+    /// Convert to a gamma encoded RGB color.
+    pub fn into_rgb<S: RgbStandard>(self) -> Rgb<S, T> {
+        let (kr, kb) = weights::<M, T>();
+        let kg = T::one() - kr - kb;
+        let two: T = from_f64(2.0);
+
+        let red = self.y + two * (T::one() - kr) * self.cr;
+        let blue = self.y + two * (T::one() - kb) * self.cb;
+        let green = (self.y - kr * red - kb * blue) / kg;
+
+        Rgb::new(red, green, blue)
+    }
+}
+
+fn weights<M: YCbCrMatrix, T: FloatComponent>() -> (T, T) {
+    (from_f64(M::KR), from_f64(M::KB))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bt2020, Bt601, Bt709, YCbCr};
+    use crate::Srgb;
+
+    #[test]
+    fn primaries() {
+        let white = YCbCr::<Bt601, f64>::from_rgb(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.y, 1.0);
+        assert_relative_eq!(white.cb, 0.0);
+        assert_relative_eq!(white.cr, 0.0);
+
+        let blue = YCbCr::<Bt709, f64>::from_rgb(Srgb::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue.y, 0.0722);
+        assert_relative_eq!(blue.cb, 0.5);
+
+        let green = YCbCr::<Bt2020, f64>::from_rgb(Srgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(green.y, 0.678);
+        assert!(green.cb < 0.0 && green.cr < 0.0);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(r, g, b) in &[(0.2, 0.5, 0.8), (1.0, 0.0, 0.5), (0.0, 0.0, 0.0)] {
+            let color = Srgb::<f64>::new(r, g, b);
+            let rgb: Srgb<f64> = YCbCr::<Bt601, f64>::from_rgb(color).into_rgb();
+            assert_relative_eq!(rgb, color, epsilon = 1e-12);
+        }
+    }
+}
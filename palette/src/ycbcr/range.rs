@@ -0,0 +1,259 @@
+use super::{YCbCr, YCbCrMatrix};
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{clamp, from_f64, FloatComponent};
+
+/// The range of integer codes that a video signal uses.
+///
+/// Most video is in limited range, also called studio swing or TV range,
+/// where black is at code 16 and white at code 235, with 8 bits. The codes
+/// below and above are left as footroom and headroom. Computer graphics,
+/// JPEG and some video use full range, also called full swing or PC range,
+/// where black is at code 0 and white at code 255. Mixing them up makes
+/// video look washed out, or makes shadows and highlights crushed.
+///
+/// The codes scale with the bit depth, so 8 bit code 16 is 10 bit code 64
+/// and 12 bit code 256.
+///
+/// ```
+/// use palette::ycbcr::SignalRange;
+///
+/// assert_eq!(SignalRange::Limited.encode_luma(1.0, 8), 235);
+/// assert_eq!(SignalRange::Limited.encode_luma(1.0, 10), 940);
+/// assert_eq!(SignalRange::Full.encode_chroma(0.0, 10), 512);
+///
+/// // Expand a limited range code to full range.
+/// assert_eq!(SignalRange::Limited.convert_luma(235, 8, SignalRange::Full), 255);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignalRange {
+    /// Full range, where the codes from `0` to `2^bits - 1` are used.
+    Full,
+
+    /// Limited range, where the luma and RGB codes go from `16` to `235` and
+    /// the chroma codes go from `16` to `240`, scaled to the bit depth.
+    Limited,
+}
+
+impl SignalRange {
+    /// Encode a luma or RGB `value`, from `0.0` to `1.0`, as a code with
+    /// `bits` bits. Values outside the range are clamped to the codes that
+    /// fit in `bits`, so limited range keeps some of the overshoot.
+    ///
+    /// Panics if `bits` is not in `8..=16`.
+    pub fn encode_luma<T: FloatComponent>(self, value: T, bits: u32) -> u16 {
+        let (scale, offset) = self.luma_scale::<T>(bits);
+        to_code(value * scale + offset, bits)
+    }
+
+    /// Decode a luma or RGB `code` with `bits` bits to a value where black
+    /// is `0.0` and white is `1.0`. Limited range codes in the footroom and
+    /// headroom give values below `0.0` and above `1.0`.
+    ///
+    /// Panics if `bits` is not in `8..=16`.
+    pub fn decode_luma<T: FloatComponent>(self, code: u16, bits: u32) -> T {
+        let (scale, offset) = self.luma_scale::<T>(bits);
+        (from_f64::<T>(f64::from(code)) - offset) / scale
+    }
+
+    /// Encode a chroma `value`, from `-0.5` to `0.5`, as a code with `bits`
+    /// bits. `0.0` is encoded as the middle code, such as `128` for 8 bits.
+    ///
+    /// Panics if `bits` is not in `8..=16`.
+    pub fn encode_chroma<T: FloatComponent>(self, value: T, bits: u32) -> u16 {
+        let (scale, offset) = self.chroma_scale::<T>(bits);
+        to_code(value * scale + offset, bits)
+    }
+
+    /// Decode a chroma `code` with `bits` bits to a value from `-0.5` to
+    /// `0.5`.
+    ///
+    /// Panics if `bits` is not in `8..=16`.
+    pub fn decode_chroma<T: FloatComponent>(self, code: u16, bits: u32) -> T {
+        let (scale, offset) = self.chroma_scale::<T>(bits);
+        (from_f64::<T>(f64::from(code)) - offset) / scale
+    }
+
+    /// Convert a luma or RGB `code` with `bits` bits from this range to
+    /// `target`.
+    pub fn convert_luma(self, code: u16, bits: u32, target: SignalRange) -> u16 {
+        target.encode_luma(self.decode_luma::<f64>(code, bits), bits)
+    }
+
+    /// Convert a chroma `code` with `bits` bits from this range to `target`.
+    pub fn convert_chroma(self, code: u16, bits: u32, target: SignalRange) -> u16 {
+        target.encode_chroma(self.decode_chroma::<f64>(code, bits), bits)
+    }
+
+    /// Encode a Y′CbCr color as `[y, cb, cr]` codes with `bits` bits.
+    pub fn encode_ycbcr<M, T>(self, color: YCbCr<M, T>, bits: u32) -> [u16; 3]
+    where
+        M: YCbCrMatrix,
+        T: FloatComponent,
+    {
+        [
+            self.encode_luma(color.y, bits),
+            self.encode_chroma(color.cb, bits),
+            self.encode_chroma(color.cr, bits),
+        ]
+    }
+
+    /// Decode `[y, cb, cr]` codes with `bits` bits to a Y′CbCr color.
+    pub fn decode_ycbcr<M, T>(self, [y, cb, cr]: [u16; 3], bits: u32) -> YCbCr<M, T>
+    where
+        M: YCbCrMatrix,
+        T: FloatComponent,
+    {
+        YCbCr::new(
+            self.decode_luma(y, bits),
+            self.decode_chroma(cb, bits),
+            self.decode_chroma(cr, bits),
+        )
+    }
+
+    /// Encode each component of an RGB color as a code with `bits` bits.
+    pub fn encode_rgb<S, T>(self, color: Rgb<S, T>, bits: u32) -> Rgb<S, u16>
+    where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        Rgb::new(
+            self.encode_luma(color.red, bits),
+            self.encode_luma(color.green, bits),
+            self.encode_luma(color.blue, bits),
+        )
+    }
+
+    /// Decode each component of an RGB color from a code with `bits` bits.
+    pub fn decode_rgb<S, T>(self, color: Rgb<S, u16>, bits: u32) -> Rgb<S, T>
+    where
+        S: RgbStandard,
+        T: FloatComponent,
+    {
+        Rgb::new(
+            self.decode_luma(color.red, bits),
+            self.decode_luma(color.green, bits),
+            self.decode_luma(color.blue, bits),
+        )
+    }
+
+    /// The scale and offset from luma values to codes.
+    fn luma_scale<T: FloatComponent>(self, bits: u32) -> (T, T) {
+        match self {
+            SignalRange::Full => (max_code(bits), T::zero()),
+            SignalRange::Limited => {
+                let step = step::<T>(bits);
+                (from_f64::<T>(219.0) * step, from_f64::<T>(16.0) * step)
+            }
+        }
+    }
+
+    /// The scale and offset from chroma values to codes.
+    fn chroma_scale<T: FloatComponent>(self, bits: u32) -> (T, T) {
+        let step = step::<T>(bits);
+        let middle = from_f64::<T>(128.0) * step;
+
+        match self {
+            SignalRange::Full => (max_code(bits), middle),
+            SignalRange::Limited => (from_f64::<T>(224.0) * step, middle),
+        }
+    }
+}
+
+/// The size of an 8 bit code step, in codes with `bits` bits.
+fn step<T: FloatComponent>(bits: u32) -> T {
+    assert!(
+        (8..=16).contains(&bits),
+        "the number of bits must be between 8 and 16"
+    );
+
+    from_f64(f64::from(1u32 << (bits - 8)))
+}
+
+fn max_code<T: FloatComponent>(bits: u32) -> T {
+    from_f64::<T>(256.0) * step::<T>(bits) - T::one()
+}
+
+fn to_code<T: FloatComponent>(code: T, bits: u32) -> u16 {
+    clamp(code.round(), T::zero(), max_code(bits))
+        .to_u16()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::SignalRange;
+    use crate::rgb::Rgb;
+    use crate::ycbcr::{Bt709, YCbCr};
+    use crate::Srgb;
+
+    #[test]
+    fn limited_codes() {
+        for &(bits, black, white, chroma_min, chroma_max) in &[
+            (8, 16, 235, 16, 240),
+            (10, 64, 940, 64, 960),
+            (12, 256, 3760, 256, 3840),
+        ] {
+            let range = SignalRange::Limited;
+            assert_eq!(range.encode_luma(0.0f64, bits), black);
+            assert_eq!(range.encode_luma(1.0f64, bits), white);
+            assert_eq!(range.encode_chroma(-0.5f64, bits), chroma_min);
+            assert_eq!(range.encode_chroma(0.5f64, bits), chroma_max);
+            assert_eq!(range.encode_chroma(0.0f64, bits), 1 << (bits - 1));
+
+            assert_eq!(range.decode_luma::<f64>(black, bits), 0.0);
+            assert_eq!(range.decode_luma::<f64>(white, bits), 1.0);
+            assert_eq!(range.decode_chroma::<f64>(chroma_max, bits), 0.5);
+        }
+    }
+
+    #[test]
+    fn full_codes() {
+        let range = SignalRange::Full;
+        assert_eq!(range.encode_luma(1.0f64, 8), 255);
+        assert_eq!(range.encode_luma(1.0f64, 12), 4095);
+        assert_eq!(range.encode_chroma(0.0f64, 8), 128);
+        assert_eq!(range.encode_chroma(0.5f64, 8), 255);
+        assert_eq!(range.encode_chroma(-0.5f64, 8), 1);
+    }
+
+    #[test]
+    fn clamping_and_overshoot() {
+        // Limited range keeps some of the overshoot, full range can't.
+        assert_eq!(SignalRange::Limited.encode_luma(1.05f64, 8), 246);
+        assert_eq!(SignalRange::Limited.encode_luma(2.0f64, 8), 255);
+        assert_eq!(SignalRange::Full.encode_luma(1.05f64, 8), 255);
+        assert_eq!(SignalRange::Full.encode_luma(-0.1f64, 8), 0);
+
+        assert!(SignalRange::Limited.decode_luma::<f64>(250, 8) > 1.0);
+        assert!(SignalRange::Limited.decode_luma::<f64>(4, 8) < 0.0);
+    }
+
+    #[test]
+    fn conversion() {
+        let limited = SignalRange::Limited;
+        let full = SignalRange::Full;
+
+        assert_eq!(limited.convert_luma(16, 8, full), 0);
+        assert_eq!(limited.convert_luma(235, 8, full), 255);
+        assert_eq!(full.convert_luma(1023, 10, limited), 940);
+        assert_eq!(limited.convert_chroma(128, 8, full), 128);
+        assert_eq!(limited.convert_chroma(240, 8, full), 255);
+        assert_eq!(full.convert_chroma(512, 10, limited), 512);
+    }
+
+    #[test]
+    fn colors() {
+        let color = YCbCr::<Bt709, f64>::new(0.5, -0.25, 0.25);
+        let codes = SignalRange::Limited.encode_ycbcr(color, 10);
+        assert_eq!(codes, [502, 288, 736]);
+
+        let decoded: YCbCr<Bt709, f64> = SignalRange::Limited.decode_ycbcr(codes, 10);
+        assert_relative_eq!(decoded.y, 0.5, epsilon = 1.0 / 876.0);
+        assert_relative_eq!(decoded.cb, -0.25, epsilon = 1e-12);
+
+        let rgb = SignalRange::Limited.encode_rgb(Srgb::new(1.0f64, 0.0, 0.5), 8);
+        assert_eq!(rgb, Rgb::new(235, 16, 126));
+        let rgb: Srgb<f64> = SignalRange::Limited.decode_rgb(rgb, 8);
+        assert_relative_eq!(rgb.red, 1.0);
+    }
+}
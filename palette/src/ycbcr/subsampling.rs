@@ -0,0 +1,521 @@
+use core::marker::PhantomData;
+
+use super::{YCbCr, YCbCrMatrix};
+use crate::{from_f64, FloatComponent};
+
+/// The resolution of the chroma planes, relative to the luma plane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subsampling {
+    /// 4:4:4, where the chroma planes have full resolution.
+    Chroma444,
+
+    /// 4:2:2, where the chroma planes have half of the horizontal
+    /// resolution.
+    Chroma422,
+
+    /// 4:2:0, where the chroma planes have half of the horizontal and half
+    /// of the vertical resolution.
+    Chroma420,
+}
+
+impl Subsampling {
+    /// The number of luma pixels per chroma pixel, horizontally and
+    /// vertically.
+    pub fn factors(self) -> (usize, usize) {
+        match self {
+            Subsampling::Chroma444 => (1, 1),
+            Subsampling::Chroma422 => (2, 1),
+            Subsampling::Chroma420 => (2, 2),
+        }
+    }
+
+    /// The width and height of the chroma planes for an image of `width` ×
+    /// `height` pixels. Odd sizes are rounded up.
+    pub fn chroma_size(self, width: usize, height: usize) -> (usize, usize) {
+        let (x, y) = self.factors();
+        (width.div_ceil(x), height.div_ceil(y))
+    }
+}
+
+/// Where the chroma samples are placed, relative to the luma samples, when
+/// the chroma planes are subsampled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChromaSiting {
+    /// Between the luma samples, horizontally and vertically. This is used
+    /// by JPEG and MPEG-1.
+    Center,
+
+    /// At the same column as the left luma sample, and between the luma
+    /// samples vertically. This is used by MPEG-2, H.264 and most video.
+    Left,
+
+    /// At the same position as the top left luma sample. This is used by
+    /// BT.2020 and HDR video.
+    TopLeft,
+}
+
+impl ChromaSiting {
+    /// The position of the first chroma sample, in luma pixels.
+    fn offsets<T: FloatComponent>(self, subsampling: Subsampling) -> (T, T) {
+        let (x, y) = subsampling.factors();
+        let half = from_f64::<T>(0.5);
+
+        let x_offset = match self {
+            ChromaSiting::Center if x > 1 => half,
+            _ => T::zero(),
+        };
+        let y_offset = match self {
+            ChromaSiting::Center | ChromaSiting::Left if y > 1 => half,
+            _ => T::zero(),
+        };
+
+        (x_offset, y_offset)
+    }
+}
+
+/// The filter that is used when resampling the chroma planes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Filter {
+    /// Pick the closest sample. It's fast, but blocky.
+    Nearest,
+
+    /// Linear interpolation between the closest samples.
+    Bilinear,
+
+    /// Catmull-Rom cubic interpolation. It's sharper than bilinear, but may
+    /// overshoot slightly around sharp edges.
+    CatmullRom,
+}
+
+impl Filter {
+    /// How many samples away from the center the filter reaches.
+    fn radius(self) -> f64 {
+        match self {
+            Filter::Nearest => 0.5,
+            Filter::Bilinear => 1.0,
+            Filter::CatmullRom => 2.0,
+        }
+    }
+
+    /// The weight of a sample at `distance` from the center.
+    fn weight<T: FloatComponent>(self, distance: T) -> T {
+        let x = distance.abs();
+
+        match self {
+            Filter::Nearest => T::one(),
+            Filter::Bilinear => (T::one() - x).max(T::zero()),
+            Filter::CatmullRom => {
+                let c = |value: f64| from_f64::<T>(value);
+
+                if x < T::one() {
+                    (c(1.5) * x - c(2.5)) * x * x + T::one()
+                } else if x < c(2.0) {
+                    ((c(-0.5) * x + c(2.5)) * x - c(4.0)) * x + c(2.0)
+                } else {
+                    T::zero()
+                }
+            }
+        }
+    }
+}
+
+/// Downsample a full resolution chroma plane with `width` columns to the
+/// resolution of `subsampling`. The rows are one after the other, from the
+/// top. The chroma samples are taken at the positions given by `siting`, and
+/// the filter is widened to cover all of the luma pixels that they
+/// represent.
+///
+/// # Panics
+///
+/// Panics if `width` is 0, or if the length of `plane` is not a multiple of
+/// `width`.
+pub fn downsample_chroma<T>(
+    plane: &[T],
+    width: usize,
+    subsampling: Subsampling,
+    siting: ChromaSiting,
+    filter: Filter,
+) -> Vec<T>
+where
+    T: FloatComponent,
+{
+    let height = plane_height(plane, width);
+    let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+    let (x_factor, y_factor) = subsampling.factors();
+    let (x_offset, y_offset) = siting.offsets(subsampling);
+
+    resample(
+        plane,
+        (width, height),
+        (chroma_width, chroma_height),
+        (from_f64(x_factor as f64), x_offset),
+        (from_f64(y_factor as f64), y_offset),
+        filter,
+    )
+}
+
+/// Upsample a chroma plane with the resolution of `subsampling` to the full
+/// resolution of `width` × `height` pixels. The rows are one after the
+/// other, from the top, and the size of the chroma plane is
+/// `subsampling.chroma_size(width, height)`.
+///
+/// # Panics
+///
+/// Panics if the length of `plane` doesn't match the chroma size.
+pub fn upsample_chroma<T>(
+    plane: &[T],
+    width: usize,
+    height: usize,
+    subsampling: Subsampling,
+    siting: ChromaSiting,
+    filter: Filter,
+) -> Vec<T>
+where
+    T: FloatComponent,
+{
+    let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+    assert_eq!(
+        plane.len(),
+        chroma_width * chroma_height,
+        "the chroma plane has the wrong size"
+    );
+
+    let (x_factor, y_factor) = subsampling.factors();
+    let (x_offset, y_offset) = siting.offsets::<T>(subsampling);
+    let x_scale = T::one() / from_f64(x_factor as f64);
+    let y_scale = T::one() / from_f64(y_factor as f64);
+
+    resample(
+        plane,
+        (chroma_width, chroma_height),
+        (width, height),
+        (x_scale, -x_offset * x_scale),
+        (y_scale, -y_offset * y_scale),
+        filter,
+    )
+}
+
+/// The three planes of a Y′CbCr image, with subsampled chroma.
+///
+/// ```
+/// use palette::ycbcr::{Bt709, ChromaSiting, Filter, Subsampling, YCbCr, YCbCrPlanes};
+///
+/// let pixels = vec![YCbCr::<Bt709>::new(0.5, 0.1, -0.2); 4 * 4];
+/// let planes = YCbCrPlanes::from_pixels(
+///     &pixels,
+///     4,
+///     Subsampling::Chroma420,
+///     ChromaSiting::Left,
+///     Filter::Bilinear,
+/// );
+/// assert_eq!(planes.cb.len(), 2 * 2);
+///
+/// let restored = planes.to_pixels(Filter::CatmullRom);
+/// assert!((restored[5].cr + 0.2).abs() < 1e-6);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct YCbCrPlanes<M, T = f32> {
+    /// The width of the image, in luma pixels.
+    pub width: usize,
+
+    /// The height of the image, in luma pixels.
+    pub height: usize,
+
+    /// The resolution of the chroma planes.
+    pub subsampling: Subsampling,
+
+    /// The position of the chroma samples.
+    pub siting: ChromaSiting,
+
+    /// The luma plane, with `width` × `height` samples.
+    pub y: Vec<T>,
+
+    /// The blue difference plane, with the size from
+    /// [`Subsampling::chroma_size`](enum.Subsampling.html#method.chroma_size).
+    pub cb: Vec<T>,
+
+    /// The red difference plane, with the size from
+    /// [`Subsampling::chroma_size`](enum.Subsampling.html#method.chroma_size).
+    pub cr: Vec<T>,
+
+    /// The Y′CbCr matrix of the image.
+    pub matrix: PhantomData<M>,
+}
+
+impl<M, T> YCbCrPlanes<M, T>
+where
+    M: YCbCrMatrix,
+    T: FloatComponent,
+{
+    /// Split full resolution pixels, with `width` columns, into planes and
+    /// downsample the chroma with `filter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0, or if the length of `pixels` is not a
+    /// multiple of `width`.
+    pub fn from_pixels(
+        pixels: &[YCbCr<M, T>],
+        width: usize,
+        subsampling: Subsampling,
+        siting: ChromaSiting,
+        filter: Filter,
+    ) -> Self {
+        let height = plane_height(pixels, width);
+        let cb: Vec<T> = pixels.iter().map(|pixel| pixel.cb).collect();
+        let cr: Vec<T> = pixels.iter().map(|pixel| pixel.cr).collect();
+
+        YCbCrPlanes {
+            width,
+            height,
+            subsampling,
+            siting,
+            y: pixels.iter().map(|pixel| pixel.y).collect(),
+            cb: downsample_chroma(&cb, width, subsampling, siting, filter),
+            cr: downsample_chroma(&cr, width, subsampling, siting, filter),
+            matrix: PhantomData,
+        }
+    }
+
+    /// Upsample the chroma with `filter` and merge the planes into full
+    /// resolution pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the planes don't have the expected sizes.
+    pub fn to_pixels(&self, filter: Filter) -> Vec<YCbCr<M, T>> {
+        assert_eq!(
+            self.y.len(),
+            self.width * self.height,
+            "the luma plane has the wrong size"
+        );
+
+        let upsample = |plane: &[T]| {
+            upsample_chroma(
+                plane,
+                self.width,
+                self.height,
+                self.subsampling,
+                self.siting,
+                filter,
+            )
+        };
+        let cb = upsample(&self.cb);
+        let cr = upsample(&self.cr);
+
+        self.y
+            .iter()
+            .zip(cb)
+            .zip(cr)
+            .map(|((&y, cb), cr)| YCbCr::new(y, cb, cr))
+            .collect()
+    }
+}
+
+fn plane_height<P>(plane: &[P], width: usize) -> usize {
+    assert!(width > 0, "the width must be at least 1");
+
+    let height = plane.len() / width;
+    assert!(
+        height * width == plane.len(),
+        "the plane length must be a multiple of the width"
+    );
+
+    height
+}
+
+/// Resample a plane, where the output sample `i` is at the input position
+/// `i * scale + offset` along each axis.
+fn resample<T: FloatComponent>(
+    input: &[T],
+    (input_width, input_height): (usize, usize),
+    (output_width, output_height): (usize, usize),
+    (x_scale, x_offset): (T, T),
+    (y_scale, y_offset): (T, T),
+    filter: Filter,
+) -> Vec<T> {
+    let columns = weights(input_width, output_width, x_scale, x_offset, filter);
+    let rows = weights(input_height, output_height, y_scale, y_offset, filter);
+
+    // Horizontally first, and then vertically.
+    let mut horizontal = Vec::with_capacity(output_width * input_height);
+    for row in input.chunks(input_width) {
+        horizontal.extend(
+            columns
+                .iter()
+                .map(|weights| apply(weights, |index| row[index])),
+        );
+    }
+
+    let mut output = Vec::with_capacity(output_width * output_height);
+    for weights in &rows {
+        for column in 0..output_width {
+            output.push(apply(weights, |index| {
+                horizontal[index * output_width + column]
+            }));
+        }
+    }
+
+    output
+}
+
+fn apply<T: FloatComponent>(weights: &[(usize, T)], sample: impl Fn(usize) -> T) -> T {
+    weights.iter().fold(T::zero(), |sum, &(index, weight)| {
+        sum + sample(index) * weight
+    })
+}
+
+/// The normalized filter weights of the input samples for each output
+/// sample. Samples outside the edges are replaced by the closest edge
+/// sample.
+fn weights<T: FloatComponent>(
+    input_length: usize,
+    output_length: usize,
+    scale: T,
+    offset: T,
+    filter: Filter,
+) -> Vec<Vec<(usize, T)>> {
+    let last = input_length as isize - 1;
+    let clamp_index = |index: isize| index.max(0).min(last) as usize;
+
+    (0..output_length)
+        .map(|output_index| {
+            let position = from_f64::<T>(output_index as f64) * scale + offset;
+
+            if filter == Filter::Nearest {
+                // Ties go to the lower sample.
+                let index = (position - from_f64(0.5)).ceil().to_isize().unwrap_or(0);
+                return vec![(clamp_index(index), T::one())];
+            }
+
+            // The filter covers all of the input samples when downsampling.
+            let stretch = scale.max(T::one());
+            let radius = from_f64::<T>(filter.radius()) * stretch;
+            let first = (position - radius).floor().to_isize().unwrap_or(0);
+            let end = (position + radius).ceil().to_isize().unwrap_or(0);
+
+            let mut weights: Vec<(usize, T)> = Vec::new();
+            let mut sum = T::zero();
+            for index in first..=end {
+                let weight = filter.weight((from_f64::<T>(index as f64) - position) / stretch);
+                if weight == T::zero() {
+                    continue;
+                }
+
+                sum = sum + weight;
+                let index = clamp_index(index);
+                match weights.iter_mut().find(|(existing, _)| *existing == index) {
+                    Some((_, existing)) => *existing = *existing + weight,
+                    None => weights.push((index, weight)),
+                }
+            }
+
+            for (_, weight) in &mut weights {
+                *weight = *weight / sum;
+            }
+
+            weights
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{downsample_chroma, upsample_chroma, ChromaSiting, Filter, Subsampling};
+
+    const FILTERS: [Filter; 3] = [Filter::Nearest, Filter::Bilinear, Filter::CatmullRom];
+    const SITINGS: [ChromaSiting; 3] = [
+        ChromaSiting::Center,
+        ChromaSiting::Left,
+        ChromaSiting::TopLeft,
+    ];
+
+    #[test]
+    fn sizes() {
+        assert_eq!(Subsampling::Chroma444.chroma_size(5, 3), (5, 3));
+        assert_eq!(Subsampling::Chroma422.chroma_size(5, 3), (3, 3));
+        assert_eq!(Subsampling::Chroma420.chroma_size(5, 3), (3, 2));
+    }
+
+    #[test]
+    fn constant_planes() {
+        let plane = vec![0.25f64; 7 * 5];
+
+        for &filter in &FILTERS {
+            for &siting in &SITINGS {
+                let small = downsample_chroma(&plane, 7, Subsampling::Chroma420, siting, filter);
+                assert_eq!(small.len(), 4 * 3);
+                for &value in &small {
+                    assert_relative_eq!(value, 0.25, epsilon = 1e-12);
+                }
+
+                let large = upsample_chroma(&small, 7, 5, Subsampling::Chroma420, siting, filter);
+                assert_eq!(large.len(), 7 * 5);
+                for &value in &large {
+                    assert_relative_eq!(value, 0.25, epsilon = 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn siting() {
+        // A horizontal ramp, where each value is the column.
+        let plane: Vec<f64> = (0..8).map(f64::from).collect();
+
+        let center = downsample_chroma(
+            &plane,
+            8,
+            Subsampling::Chroma422,
+            ChromaSiting::Center,
+            Filter::Bilinear,
+        );
+        assert_relative_eq!(center[1], 2.5);
+        assert_relative_eq!(center[2], 4.5);
+
+        let left = downsample_chroma(
+            &plane,
+            8,
+            Subsampling::Chroma422,
+            ChromaSiting::Left,
+            Filter::Bilinear,
+        );
+        assert_relative_eq!(left[1], 2.0);
+        assert_relative_eq!(left[2], 4.0);
+
+        let nearest = downsample_chroma(
+            &plane,
+            8,
+            Subsampling::Chroma422,
+            ChromaSiting::Left,
+            Filter::Nearest,
+        );
+        assert_eq!(nearest, vec![0.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn upsample_ramp() {
+        // A ramp sampled at the chroma positions is restored in the interior.
+        for &(siting, offset) in &[(ChromaSiting::Center, 0.5), (ChromaSiting::Left, 0.0)] {
+            let chroma: Vec<f64> = (0..6).map(|i| f64::from(i) * 2.0 + offset).collect();
+
+            for &filter in &[Filter::Bilinear, Filter::CatmullRom] {
+                let full = upsample_chroma(&chroma, 12, 1, Subsampling::Chroma422, siting, filter);
+
+                for (column, &value) in full.iter().enumerate().take(9).skip(4) {
+                    assert_relative_eq!(value, column as f64, epsilon = 1e-12);
+                }
+            }
+        }
+
+        let nearest = upsample_chroma(
+            &[1.0, 2.0],
+            4,
+            1,
+            Subsampling::Chroma422,
+            ChromaSiting::Left,
+            Filter::Nearest,
+        );
+        assert_eq!(nearest, vec![1.0, 1.0, 2.0, 2.0]);
+    }
+}
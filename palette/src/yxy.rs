@@ -13,8 +13,8 @@ use crate::encoding::pixel::RawPixel;
 use crate::luma::LumaStandard;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, Alpha, Clamp, Component, ComponentWise, FloatComponent, Luma, Mix,
-    Pixel, RelativeContrast, Shade, Xyz,
+    clamp, Alpha, Clamp, Component, ComponentWise, FloatComponent, Luma, Mix, Pixel,
+    RelativeContrast, Shade, Xyz,
 };
 
 /// CIE 1931 Yxy (xyY) with an alpha component. See the [`Yxya` implementation
@@ -659,8 +659,8 @@ where
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        contrast_ratio(self.luma, other.luma)
+    fn relative_luminance(&self) -> T {
+        self.luma
     }
 }
 
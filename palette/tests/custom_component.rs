@@ -0,0 +1,518 @@
+//! Tests that custom number types can be used as color components.
+//!
+//! The number type in this test is a dual number, which is used for forward
+//! mode automatic differentiation. It carries a value and its derivative
+//! through every conversion.
+
+use std::cmp::Ordering;
+use std::num::FpCategory;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use approx::assert_relative_eq;
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use palette::convert::FromColorUnclamped;
+use palette::{Component, FromF64, Hsv, Lab, LinSrgb, Srgb, Xyz};
+
+#[derive(Copy, Clone, Debug)]
+struct Dual {
+    value: f64,
+    derivative: f64,
+}
+
+impl Dual {
+    fn constant(value: f64) -> Dual {
+        Dual {
+            value,
+            derivative: 0.0,
+        }
+    }
+
+    fn variable(value: f64) -> Dual {
+        Dual {
+            value,
+            derivative: 1.0,
+        }
+    }
+
+    fn chain(self, value: f64, derivative: f64) -> Dual {
+        Dual {
+            value,
+            derivative: self.derivative * derivative,
+        }
+    }
+}
+
+impl PartialEq for Dual {
+    fn eq(&self, other: &Dual) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for Dual {
+    fn partial_cmp(&self, other: &Dual) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value + other.value,
+            derivative: self.derivative + other.derivative,
+        }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value - other.value,
+            derivative: self.derivative - other.derivative,
+        }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value * other.value,
+            derivative: self.derivative * other.value + self.value * other.derivative,
+        }
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value / other.value,
+            derivative: (self.derivative * other.value - self.value * other.derivative)
+                / (other.value * other.value),
+        }
+    }
+}
+
+impl Rem for Dual {
+    type Output = Dual;
+
+    fn rem(self, other: Dual) -> Dual {
+        self - other * (self / other).trunc()
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual {
+            value: -self.value,
+            derivative: -self.derivative,
+        }
+    }
+}
+
+impl Zero for Dual {
+    fn zero() -> Dual {
+        Dual::constant(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0.0
+    }
+}
+
+impl One for Dual {
+    fn one() -> Dual {
+        Dual::constant(1.0)
+    }
+}
+
+impl Num for Dual {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Dual, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl ToPrimitive for Dual {
+    fn to_i64(&self) -> Option<i64> {
+        self.value.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.value)
+    }
+}
+
+impl NumCast for Dual {
+    fn from<T: ToPrimitive>(n: T) -> Option<Dual> {
+        n.to_f64().map(Dual::constant)
+    }
+}
+
+impl Float for Dual {
+    fn nan() -> Dual {
+        Dual::constant(f64::NAN)
+    }
+
+    fn infinity() -> Dual {
+        Dual::constant(f64::INFINITY)
+    }
+
+    fn neg_infinity() -> Dual {
+        Dual::constant(f64::NEG_INFINITY)
+    }
+
+    fn neg_zero() -> Dual {
+        Dual::constant(-0.0)
+    }
+
+    fn min_value() -> Dual {
+        Dual::constant(f64::MIN)
+    }
+
+    fn min_positive_value() -> Dual {
+        Dual::constant(f64::MIN_POSITIVE)
+    }
+
+    fn max_value() -> Dual {
+        Dual::constant(f64::MAX)
+    }
+
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.value.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.value.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.value.classify()
+    }
+
+    fn floor(self) -> Dual {
+        self.chain(self.value.floor(), 0.0)
+    }
+
+    fn ceil(self) -> Dual {
+        self.chain(self.value.ceil(), 0.0)
+    }
+
+    fn round(self) -> Dual {
+        self.chain(self.value.round(), 0.0)
+    }
+
+    fn trunc(self) -> Dual {
+        self.chain(self.value.trunc(), 0.0)
+    }
+
+    fn fract(self) -> Dual {
+        self.chain(self.value.fract(), 1.0)
+    }
+
+    fn abs(self) -> Dual {
+        self.chain(self.value.abs(), self.value.signum())
+    }
+
+    fn signum(self) -> Dual {
+        self.chain(self.value.signum(), 0.0)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Dual, b: Dual) -> Dual {
+        self * a + b
+    }
+
+    fn recip(self) -> Dual {
+        Dual::one() / self
+    }
+
+    fn powi(self, n: i32) -> Dual {
+        self.chain(self.value.powi(n), n as f64 * self.value.powi(n - 1))
+    }
+
+    fn powf(self, n: Dual) -> Dual {
+        let value = self.value.powf(n.value);
+        let derivative = if self.value == 0.0 {
+            0.0
+        } else {
+            n.value * self.value.powf(n.value - 1.0) * self.derivative
+                + value * self.value.ln() * n.derivative
+        };
+
+        Dual { value, derivative }
+    }
+
+    fn sqrt(self) -> Dual {
+        let value = self.value.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    fn exp(self) -> Dual {
+        let value = self.value.exp();
+        self.chain(value, value)
+    }
+
+    fn exp2(self) -> Dual {
+        let value = self.value.exp2();
+        self.chain(value, value * std::f64::consts::LN_2)
+    }
+
+    fn ln(self) -> Dual {
+        self.chain(self.value.ln(), self.value.recip())
+    }
+
+    fn log(self, base: Dual) -> Dual {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Dual {
+        self.chain(
+            self.value.log2(),
+            (self.value * std::f64::consts::LN_2).recip(),
+        )
+    }
+
+    fn log10(self) -> Dual {
+        self.chain(
+            self.value.log10(),
+            (self.value * std::f64::consts::LN_10).recip(),
+        )
+    }
+
+    fn max(self, other: Dual) -> Dual {
+        if self.value >= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn min(self, other: Dual) -> Dual {
+        if self.value <= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn abs_sub(self, other: Dual) -> Dual {
+        (self - other).max(Dual::zero())
+    }
+
+    fn cbrt(self) -> Dual {
+        let value = self.value.cbrt();
+        self.chain(value, (3.0 * value * value).recip())
+    }
+
+    fn hypot(self, other: Dual) -> Dual {
+        (self * self + other * other).sqrt()
+    }
+
+    fn sin(self) -> Dual {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Dual {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Dual {
+        let value = self.value.tan();
+        self.chain(value, 1.0 + value * value)
+    }
+
+    fn asin(self) -> Dual {
+        self.chain(
+            self.value.asin(),
+            (1.0 - self.value * self.value).sqrt().recip(),
+        )
+    }
+
+    fn acos(self) -> Dual {
+        self.chain(
+            self.value.acos(),
+            -(1.0 - self.value * self.value).sqrt().recip(),
+        )
+    }
+
+    fn atan(self) -> Dual {
+        self.chain(self.value.atan(), (1.0 + self.value * self.value).recip())
+    }
+
+    fn atan2(self, other: Dual) -> Dual {
+        let denominator = self.value * self.value + other.value * other.value;
+        Dual {
+            value: self.value.atan2(other.value),
+            derivative: (other.value * self.derivative - self.value * other.derivative)
+                / denominator,
+        }
+    }
+
+    fn sin_cos(self) -> (Dual, Dual) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Dual {
+        self.chain(self.value.exp_m1(), self.value.exp())
+    }
+
+    fn ln_1p(self) -> Dual {
+        self.chain(self.value.ln_1p(), (1.0 + self.value).recip())
+    }
+
+    fn sinh(self) -> Dual {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    fn cosh(self) -> Dual {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    fn tanh(self) -> Dual {
+        let value = self.value.tanh();
+        self.chain(value, 1.0 - value * value)
+    }
+
+    fn asinh(self) -> Dual {
+        self.chain(
+            self.value.asinh(),
+            (self.value * self.value + 1.0).sqrt().recip(),
+        )
+    }
+
+    fn acosh(self) -> Dual {
+        self.chain(
+            self.value.acosh(),
+            (self.value * self.value - 1.0).sqrt().recip(),
+        )
+    }
+
+    fn atanh(self) -> Dual {
+        self.chain(self.value.atanh(), (1.0 - self.value * self.value).recip())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.value.integer_decode()
+    }
+}
+
+impl Component for Dual {
+    fn max_intensity() -> Dual {
+        Dual::one()
+    }
+}
+
+impl FromF64 for Dual {
+    fn from_f64(c: f64) -> Dual {
+        Dual::constant(c)
+    }
+}
+
+// Approximates the derivative of `f` at `x` with a central difference.
+fn numeric_derivative(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+    let h = 1e-6;
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+#[test]
+fn values_match_f64() {
+    let dual = Lab::<_, Dual>::from_color_unclamped(LinSrgb::new(
+        Dual::variable(0.3),
+        Dual::constant(0.6),
+        Dual::constant(0.1),
+    ));
+    let float = Lab::<_, f64>::from_color_unclamped(LinSrgb::new(0.3, 0.6, 0.1));
+
+    assert_relative_eq!(dual.l.value, float.l, epsilon = 1e-12);
+    assert_relative_eq!(dual.a.value, float.a, epsilon = 1e-12);
+    assert_relative_eq!(dual.b.value, float.b, epsilon = 1e-12);
+}
+
+#[test]
+fn srgb_to_lab_derivative() {
+    let lightness = |red: f64| Lab::<_, f64>::from_color_unclamped(Srgb::new(red, 0.4, 0.2)).l;
+
+    for &red in &[0.02, 0.2, 0.5, 0.9] {
+        let lab = Lab::<_, Dual>::from_color_unclamped(Srgb::new(
+            Dual::variable(red),
+            Dual::constant(0.4),
+            Dual::constant(0.2),
+        ));
+
+        assert_relative_eq!(
+            lab.l.derivative,
+            numeric_derivative(lightness, red),
+            epsilon = 1e-4
+        );
+    }
+}
+
+#[test]
+fn hsv_to_xyz_derivative() {
+    let luminance = |saturation: f64| {
+        Xyz::<_, f64>::from_color_unclamped(Hsv::<palette::encoding::Srgb, f64>::new(
+            40.0, saturation, 0.8,
+        ))
+        .y
+    };
+
+    let xyz = Xyz::<_, Dual>::from_color_unclamped(Hsv::<palette::encoding::Srgb, Dual>::new(
+        Dual::constant(40.0),
+        Dual::variable(0.5),
+        Dual::constant(0.8),
+    ));
+
+    assert_relative_eq!(
+        xyz.y.derivative,
+        numeric_derivative(luminance, 0.5),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn optimize_lightness() {
+    // Find the gray level that has 50% lightness in L*a*b*.
+    let mut gray = 0.2;
+
+    for _ in 0..20 {
+        let variable = Dual::variable(gray);
+        let lab = Lab::<_, Dual>::from_color_unclamped(Srgb::new(variable, variable, variable));
+        let error = lab.l - Dual::constant(50.0);
+        let loss = error * error;
+
+        // Newton's method, using the derivative from the dual number.
+        gray -= loss.derivative / (2.0 * error.derivative * error.derivative);
+    }
+
+    let lab = Lab::<_, f64>::from_color_unclamped(Srgb::new(gray, gray, gray));
+    assert_relative_eq!(lab.l, 50.0, epsilon = 1e-6);
+}
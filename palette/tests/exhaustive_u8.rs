@@ -0,0 +1,102 @@
+//! Round trips of every 8 bit sRGB color through other color spaces.
+//!
+//! The full tests convert all 16.7 million colors and are ignored by default,
+//! since they are slow without optimizations. Run them with
+//! `cargo test --release --test exhaustive_u8 -- --ignored`. The sampled tests
+//! check every 15th value of each channel and run as usual.
+//!
+//! The error bounds are listed in the "Accuracy" section of the README.
+
+use palette::convert::FromColorUnclamped;
+use palette::white_point::D65;
+use palette::{Hsl, Hsv, Hwb, Lab, Lch, LinSrgb, Luv, Oklab, Oklch, Srgb, Xyz};
+
+/// The result of converting colors from `Srgb<u8>` to another color space
+/// and back.
+struct RoundTrip {
+    /// The largest difference in any channel, in 8 bit steps.
+    max_error: f64,
+    /// The number of colors that don't come back as the same `Srgb<u8>`.
+    mismatches: usize,
+}
+
+fn round_trip<C>(step: usize) -> RoundTrip
+where
+    C: FromColorUnclamped<Srgb<f32>>,
+    Srgb<f32>: FromColorUnclamped<C>,
+{
+    let mut result = RoundTrip {
+        max_error: 0.0,
+        mismatches: 0,
+    };
+
+    for red in (0..=255u8).step_by(step) {
+        for green in (0..=255u8).step_by(step) {
+            for blue in (0..=255u8).step_by(step) {
+                let original = Srgb::new(red, green, blue);
+                let converted = C::from_color_unclamped(original.into_format::<f32>());
+                let back = Srgb::<f32>::from_color_unclamped(converted);
+
+                let errors = [
+                    (f64::from(back.red) * 255.0 - f64::from(red)).abs(),
+                    (f64::from(back.green) * 255.0 - f64::from(green)).abs(),
+                    (f64::from(back.blue) * 255.0 - f64::from(blue)).abs(),
+                ];
+                for &error in &errors {
+                    assert!(!error.is_nan(), "NaN from {:?}", original);
+                    if error > result.max_error {
+                        result.max_error = error;
+                    }
+                }
+
+                if back.into_format::<u8>() != original {
+                    result.mismatches += 1;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+macro_rules! round_trip_tests {
+    ($($name: ident, $full_name: ident: $color: ty => $max_error: expr;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                let result = round_trip::<$color>(15);
+                assert_eq!(result.mismatches, 0);
+                assert!(
+                    result.max_error <= $max_error,
+                    "max error {}",
+                    result.max_error
+                );
+            }
+
+            #[test]
+            #[ignore]
+            fn $full_name() {
+                let result = round_trip::<$color>(1);
+                assert_eq!(result.mismatches, 0);
+                assert!(
+                    result.max_error <= $max_error,
+                    "max error {}",
+                    result.max_error
+                );
+            }
+        )+
+    };
+}
+
+round_trip_tests! {
+    lin_srgb, lin_srgb_full: LinSrgb<f32> => 1e-4;
+    xyz, xyz_full: Xyz<D65, f32> => 2e-3;
+    lab, lab_full: Lab<D65, f32> => 3e-3;
+    lch, lch_full: Lch<D65, f32> => 4e-3;
+    luv, luv_full: Luv<D65, f32> => 5e-3;
+    oklab, oklab_full: Oklab<f32> => 7e-3;
+    oklch, oklch_full: Oklch<f32> => 8e-3;
+    hsl, hsl_full: Hsl<palette::encoding::Srgb, f32> => 5e-4;
+    hsv, hsv_full: Hsv<palette::encoding::Srgb, f32> => 5e-4;
+    hwb, hwb_full: Hwb<palette::encoding::Srgb, f32> => 5e-4;
+}
@@ -0,0 +1,61 @@
+//! Renders colors from a few conversions and compares them with reference
+//! images in `tests/golden`, to catch regressions that are easy to spot by
+//! eye. Set `PALETTE_UPDATE_GOLDEN` to write new reference images.
+
+use palette::{FromColor, Hsv, Lab, Lch, Luv, Oklch, Srgb};
+
+#[path = "../examples/util/mod.rs"]
+mod util;
+
+const TOLERANCE: u8 = 1;
+
+fn compare(image: image::RgbImage, name: &str) {
+    let filename = format!("{}/tests/golden/{}.png", env!("CARGO_MANIFEST_DIR"), name);
+    if let Err(error) = util::compare_golden(&image, &filename, TOLERANCE) {
+        panic!("{}", error);
+    }
+}
+
+#[test]
+fn hue_sweeps() {
+    let image = util::stack(&[
+        util::continuous_strip(360, 16, |x| {
+            Srgb::from_color(Hsv::new(x * 360.0, 0.8, 0.9)).into_format()
+        }),
+        util::continuous_strip(360, 16, |x| {
+            Srgb::from_color(Lch::new(60.0, 50.0, x * 360.0)).into_format()
+        }),
+        util::continuous_strip(360, 16, |x| {
+            Srgb::from_color(Oklch::new(0.7, 0.12, x * 360.0)).into_format()
+        }),
+    ]);
+
+    compare(image, "hue_sweeps");
+}
+
+#[test]
+fn lightness_ramps() {
+    let image = util::stack(&[
+        util::continuous_strip(256, 16, |x| {
+            Srgb::from_color(Lab::new(x * 100.0, 20.0, -40.0)).into_format()
+        }),
+        util::continuous_strip(256, 16, |x| {
+            Srgb::from_color(Luv::new(x * 100.0, 40.0, 10.0)).into_format()
+        }),
+    ]);
+
+    compare(image, "lightness_ramps");
+}
+
+#[test]
+fn round_trips() {
+    let colors: Vec<Srgb<u8>> = (0..16)
+        .map(|i| {
+            let color = Srgb::new(i as f32 / 15.0, 1.0 - i as f32 / 15.0, 0.5);
+            let lab = Lab::from_color(color);
+            Srgb::from_color(Lch::from_color(lab)).into_format()
+        })
+        .collect();
+
+    compare(util::swatches(&colors, 16), "round_trips");
+}
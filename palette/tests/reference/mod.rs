@@ -0,0 +1,260 @@
+/*
+Tests against published reference values, as opposed to round trips that
+only check that the library agrees with itself.
+
+Every fixture is a CSV file in this directory with a `source` column that
+names where the values come from:
+
+ * `lindbloom`: Bruce Lindbloom's tables and calculator,
+   http://www.brucelindbloom.com/
+ * `hsluv-rev4`: rev4 of the HSLuv verification snapshot,
+   https://github.com/hsluv/hsluv/blob/master/snapshots/snapshot-rev4.json
+ * `css-color-4`: the worked examples in CSS Color Module Level 4,
+   https://www.w3.org/TR/css-color-4/
+ * `icc-v4` and `icc-v2`: the L*a*b* encodings in ICC.1:2010 (version 4) and
+   ICC.1:2001-04 (version 2), https://www.color.org/specification/
+ * `tiff-6`: the CIELab encoding in TIFF 6.0, section 23.
+ * `oklab-post`: the table in Björn Ottosson's post that introduced Oklab,
+   https://bottosson.github.io/posts/oklab/
+
+The files are:
+
+ * `conversions.csv`: a color in the `from` space and the same color in the
+   `to` space. Each row is checked in both directions. The space names are
+   listed in `space.rs`.
+ * `matrices.csv`: RGB to XYZ and chromatic adaptation matrices.
+ * `white_points.csv`: XYZ values of the standard illuminants.
+ * `icc_lab.csv`: integer encodings of L*a*b* colors.
+
+Adding a new color space only takes a variant in `space.rs` and rows in
+`conversions.csv`.
+*/
+
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+
+use palette::chromatic_adaptation::{Method, TransformMatrix};
+use palette::encoding::lab::{
+    IccLab16, IccLab16Legacy, IccLab8, LabEncoding, TiffCieLab16, TiffCieLab8,
+};
+use palette::encoding::Srgb;
+use palette::matrix::{matrix_inverse, rgb_to_xyz_matrix};
+use palette::white_point::{WhitePoint, A, B, C, D50, D55, D65, D75, E, F11, F2, F7};
+use palette::{Lab, Xyz};
+
+mod space;
+
+use self::space::Space;
+
+fn load_data<R: DeserializeOwned>(file_name: &str) -> Vec<R> {
+    let path = format!("tests/reference/{}", file_name);
+    let mut rdr =
+        csv::Reader::from_path(&path).unwrap_or_else(|_| panic!("{} could not be loaded", path));
+    rdr.deserialize()
+        .map(|record| record.unwrap_or_else(|error| panic!("{}: {}", path, error)))
+        .collect()
+}
+
+fn check_components(
+    source: &str,
+    expected: [f64; 3],
+    actual: [f64; 3],
+    hue_index: Option<usize>,
+    epsilon: f64,
+) {
+    for (index, (&expected, &actual)) in expected.iter().zip(&actual).enumerate() {
+        let mut difference = (expected - actual).abs();
+        if hue_index == Some(index) {
+            difference = difference.min(360.0 - difference);
+        }
+
+        assert!(
+            difference <= epsilon,
+            "{}: expected {:?}, got {:?}",
+            source,
+            expected,
+            actual
+        );
+    }
+}
+
+#[derive(Deserialize)]
+struct ConversionRaw {
+    source: String,
+    from: String,
+    from_1: f64,
+    from_2: f64,
+    from_3: f64,
+    to: String,
+    to_1: f64,
+    to_2: f64,
+    to_3: f64,
+    epsilon: f64,
+}
+
+#[test]
+fn conversions() {
+    for row in load_data::<ConversionRaw>("conversions.csv") {
+        let from = Space::from_name(&row.from);
+        let to = Space::from_name(&row.to);
+        let from_components = [row.from_1, row.from_2, row.from_3];
+        let to_components = [row.to_1, row.to_2, row.to_3];
+        let source = format!("{} {} -> {}", row.source, row.from, row.to);
+
+        check_components(
+            &source,
+            to_components,
+            from.convert(from_components, to),
+            to.hue_index(),
+            row.epsilon,
+        );
+        check_components(
+            &source,
+            from_components,
+            to.convert(to_components, from),
+            from.hue_index(),
+            row.epsilon,
+        );
+    }
+}
+
+#[derive(Deserialize)]
+struct MatrixRaw {
+    source: String,
+    matrix: String,
+    m11: f64,
+    m12: f64,
+    m13: f64,
+    m21: f64,
+    m22: f64,
+    m23: f64,
+    m31: f64,
+    m32: f64,
+    m33: f64,
+    epsilon: f64,
+}
+
+#[test]
+fn matrices() {
+    for row in load_data::<MatrixRaw>("matrices.csv") {
+        let actual: [f64; 9] = match &*row.matrix {
+            "srgb_to_xyz" => rgb_to_xyz_matrix::<Srgb, f64>(),
+            "xyz_to_srgb" => matrix_inverse(&rgb_to_xyz_matrix::<Srgb, f64>()),
+            "bradford_d65_to_d50" => {
+                TransformMatrix::<D65, D50, f64>::generate_transform_matrix(&Method::Bradford)
+            }
+            "bradford_d50_to_d65" => {
+                TransformMatrix::<D50, D65, f64>::generate_transform_matrix(&Method::Bradford)
+            }
+            "von_kries_d65_to_d50" => {
+                TransformMatrix::<D65, D50, f64>::generate_transform_matrix(&Method::VonKries)
+            }
+            "xyz_scaling_d65_to_d50" => {
+                TransformMatrix::<D65, D50, f64>::generate_transform_matrix(&Method::XyzScaling)
+            }
+            name => panic!("unknown matrix in reference data: {}", name),
+        };
+        let expected = [
+            row.m11, row.m12, row.m13, row.m21, row.m22, row.m23, row.m31, row.m32, row.m33,
+        ];
+
+        for (&expected, &actual) in expected.iter().zip(&actual) {
+            assert!(
+                (expected - actual).abs() <= row.epsilon,
+                "{} {}: expected {:?}, got {:?}",
+                row.source,
+                row.matrix,
+                expected,
+                actual
+            );
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct WhitePointRaw {
+    source: String,
+    white_point: String,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+fn white_point_components<Wp: WhitePoint>() -> [f64; 3] {
+    let white: Xyz<Wp, f64> = Wp::get_xyz();
+    [white.x, white.y, white.z]
+}
+
+#[test]
+fn white_points() {
+    for row in load_data::<WhitePointRaw>("white_points.csv") {
+        let actual = match &*row.white_point {
+            "A" => white_point_components::<A>(),
+            "B" => white_point_components::<B>(),
+            "C" => white_point_components::<C>(),
+            "D50" => white_point_components::<D50>(),
+            "D55" => white_point_components::<D55>(),
+            "D65" => white_point_components::<D65>(),
+            "D75" => white_point_components::<D75>(),
+            "E" => white_point_components::<E>(),
+            "F2" => white_point_components::<F2>(),
+            "F7" => white_point_components::<F7>(),
+            "F11" => white_point_components::<F11>(),
+            name => panic!("unknown white point in reference data: {}", name),
+        };
+        let source = format!("{} {}", row.source, row.white_point);
+
+        check_components(&source, [row.x, row.y, row.z], actual, None, 1e-12);
+    }
+}
+
+#[derive(Deserialize)]
+struct IccLabRaw {
+    source: String,
+    encoding: String,
+    l: f64,
+    a: f64,
+    b: f64,
+    encoded_1: u16,
+    encoded_2: u16,
+    encoded_3: u16,
+}
+
+fn check_lab_encoding<E>(row: &IccLabRaw, to_component: fn(u16) -> E::Component)
+where
+    E: LabEncoding,
+    E::Component: PartialEq + std::fmt::Debug,
+{
+    let lab = Lab::<D50, f64>::with_wp(row.l, row.a, row.b);
+    let encoded = [
+        to_component(row.encoded_1),
+        to_component(row.encoded_2),
+        to_component(row.encoded_3),
+    ];
+    let source = format!("{} {}", row.source, row.encoding);
+
+    assert_eq!(lab.into_format::<E>(), encoded, "{}", source);
+
+    let decoded = Lab::<D50, f64>::from_format::<E>(encoded);
+    check_components(
+        &source,
+        [row.l, row.a, row.b],
+        [decoded.l, decoded.a, decoded.b],
+        None,
+        1e-12,
+    );
+}
+
+#[test]
+fn icc_lab_encodings() {
+    for row in load_data::<IccLabRaw>("icc_lab.csv") {
+        match &*row.encoding {
+            "icc_lab_8" => check_lab_encoding::<IccLab8>(&row, |c| c as u8),
+            "icc_lab_16" => check_lab_encoding::<IccLab16>(&row, |c| c),
+            "icc_lab_16_legacy" => check_lab_encoding::<IccLab16Legacy>(&row, |c| c),
+            "tiff_cie_lab_8" => check_lab_encoding::<TiffCieLab8>(&row, |c| c as u8),
+            "tiff_cie_lab_16" => check_lab_encoding::<TiffCieLab16>(&row, |c| c),
+            name => panic!("unknown L*a*b* encoding in reference data: {}", name),
+        }
+    }
+}
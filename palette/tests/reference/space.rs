@@ -0,0 +1,127 @@
+//! Conversions between the color spaces named in the fixtures.
+
+use palette::convert::FromColorUnclamped;
+use palette::encoding::Srgb as SrgbStandard;
+use palette::white_point::D65;
+use palette::{Hsl, Hsluv, Hsv, Hwb, Lab, Lch, Lchuv, LinSrgb, Luv, Oklab, Srgb, Xyz, Yxy};
+
+/// A color space in the fixtures, with its name as it's written in the
+/// `from` and `to` columns. All CIE spaces use the D65 white point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Space {
+    Srgb,
+    LinSrgb,
+    Xyz,
+    Yxy,
+    Lab,
+    Lch,
+    Luv,
+    Lchuv,
+    Hsluv,
+    Hsl,
+    Hsv,
+    Hwb,
+    Oklab,
+}
+
+impl Space {
+    pub fn from_name(name: &str) -> Space {
+        match name {
+            "srgb" => Space::Srgb,
+            "lin_srgb" => Space::LinSrgb,
+            "xyz" => Space::Xyz,
+            "yxy" => Space::Yxy,
+            "lab" => Space::Lab,
+            "lch" => Space::Lch,
+            "luv" => Space::Luv,
+            "lchuv" => Space::Lchuv,
+            "hsluv" => Space::Hsluv,
+            "hsl" => Space::Hsl,
+            "hsv" => Space::Hsv,
+            "hwb" => Space::Hwb,
+            "oklab" => Space::Oklab,
+            _ => panic!("unknown color space in reference data: {}", name),
+        }
+    }
+
+    /// The index of the hue component, if there is one. Hues are compared
+    /// modulo 360 degrees.
+    pub fn hue_index(self) -> Option<usize> {
+        match self {
+            Space::Lch | Space::Lchuv => Some(2),
+            Space::Hsluv | Space::Hsl | Space::Hsv | Space::Hwb => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Convert the components of a color in this space to `target`.
+    pub fn convert(self, [a, b, c]: [f64; 3], target: Space) -> [f64; 3] {
+        let xyz: Xyz<D65, f64> = match self {
+            Space::Srgb => Xyz::from_color_unclamped(Srgb::new(a, b, c)),
+            Space::LinSrgb => Xyz::from_color_unclamped(LinSrgb::new(a, b, c)),
+            Space::Xyz => Xyz::new(a, b, c),
+            Space::Yxy => Xyz::from_color_unclamped(Yxy::<D65, f64>::new(a, b, c)),
+            Space::Lab => Xyz::from_color_unclamped(Lab::<D65, f64>::new(a, b, c)),
+            Space::Lch => Xyz::from_color_unclamped(Lch::<D65, f64>::new(a, b, c)),
+            Space::Luv => Xyz::from_color_unclamped(Luv::<D65, f64>::new(a, b, c)),
+            Space::Lchuv => Xyz::from_color_unclamped(Lchuv::<D65, f64>::new(a, b, c)),
+            Space::Hsluv => Xyz::from_color_unclamped(Hsluv::<D65, f64>::new(a, b, c)),
+            Space::Hsl => Xyz::from_color_unclamped(Hsl::<SrgbStandard, f64>::new(a, b, c)),
+            Space::Hsv => Xyz::from_color_unclamped(Hsv::<SrgbStandard, f64>::new(a, b, c)),
+            Space::Hwb => Xyz::from_color_unclamped(Hwb::<SrgbStandard, f64>::new(a, b, c)),
+            Space::Oklab => Xyz::from_color_unclamped(Oklab::new(a, b, c)),
+        };
+
+        match target {
+            Space::Srgb => {
+                let c = Srgb::<f64>::from_color_unclamped(xyz);
+                [c.red, c.green, c.blue]
+            }
+            Space::LinSrgb => {
+                let c = LinSrgb::<f64>::from_color_unclamped(xyz);
+                [c.red, c.green, c.blue]
+            }
+            Space::Xyz => [xyz.x, xyz.y, xyz.z],
+            Space::Yxy => {
+                let c = Yxy::from_color_unclamped(xyz);
+                [c.x, c.y, c.luma]
+            }
+            Space::Lab => {
+                let c = Lab::from_color_unclamped(xyz);
+                [c.l, c.a, c.b]
+            }
+            Space::Lch => {
+                let c = Lch::from_color_unclamped(xyz);
+                [c.l, c.chroma, c.hue.to_positive_degrees()]
+            }
+            Space::Luv => {
+                let c = Luv::from_color_unclamped(xyz);
+                [c.l, c.u, c.v]
+            }
+            Space::Lchuv => {
+                let c = Lchuv::from_color_unclamped(xyz);
+                [c.l, c.chroma, c.hue.to_positive_degrees()]
+            }
+            Space::Hsluv => {
+                let c = Hsluv::from_color_unclamped(xyz);
+                [c.hue.to_positive_degrees(), c.saturation, c.l]
+            }
+            Space::Hsl => {
+                let c = Hsl::<SrgbStandard, f64>::from_color_unclamped(xyz);
+                [c.hue.to_positive_degrees(), c.saturation, c.lightness]
+            }
+            Space::Hsv => {
+                let c = Hsv::<SrgbStandard, f64>::from_color_unclamped(xyz);
+                [c.hue.to_positive_degrees(), c.saturation, c.value]
+            }
+            Space::Hwb => {
+                let c = Hwb::<SrgbStandard, f64>::from_color_unclamped(xyz);
+                [c.hue.to_positive_degrees(), c.whiteness, c.blackness]
+            }
+            Space::Oklab => {
+                let c = Oklab::from_color_unclamped(xyz);
+                [c.l, c.a, c.b]
+            }
+        }
+    }
+}
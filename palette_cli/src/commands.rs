@@ -0,0 +1,330 @@
+use std::str::FromStr;
+
+use palette::{
+    named, ColorDifference, FromColor, Gradient, Hsl, Hsv, Hwb, Lab, Lch, LinSrgb, Oklab, Oklch,
+    RelativeContrast, Srgb, Xyz,
+};
+
+/// The color spaces that colors can be shown and mixed in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Space {
+    Srgb,
+    LinSrgb,
+    Hsl,
+    Hsv,
+    Hwb,
+    Xyz,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+const SPACES: &[Space] = &[
+    Space::Srgb,
+    Space::LinSrgb,
+    Space::Hsl,
+    Space::Hsv,
+    Space::Hwb,
+    Space::Xyz,
+    Space::Lab,
+    Space::Lch,
+    Space::Oklab,
+    Space::Oklch,
+];
+
+impl Space {
+    fn name(self) -> &'static str {
+        match self {
+            Space::Srgb => "srgb",
+            Space::LinSrgb => "linsrgb",
+            Space::Hsl => "hsl",
+            Space::Hsv => "hsv",
+            Space::Hwb => "hwb",
+            Space::Xyz => "xyz",
+            Space::Lab => "lab",
+            Space::Lch => "lch",
+            Space::Oklab => "oklab",
+            Space::Oklch => "oklch",
+        }
+    }
+
+    /// Format the components of `color` in this space.
+    fn format(self, color: Srgb) -> String {
+        match self {
+            Space::Srgb => format!("{:.4} {:.4} {:.4}", color.red, color.green, color.blue),
+            Space::LinSrgb => {
+                let color = color.into_linear();
+                format!("{:.4} {:.4} {:.4}", color.red, color.green, color.blue)
+            }
+            Space::Hsl => {
+                let color = Hsl::from_color(color);
+                format!(
+                    "{:.2} {:.4} {:.4}",
+                    color.hue.to_positive_degrees(),
+                    color.saturation,
+                    color.lightness
+                )
+            }
+            Space::Hsv => {
+                let color = Hsv::from_color(color);
+                format!(
+                    "{:.2} {:.4} {:.4}",
+                    color.hue.to_positive_degrees(),
+                    color.saturation,
+                    color.value
+                )
+            }
+            Space::Hwb => {
+                let color = Hwb::from_color(color);
+                format!(
+                    "{:.2} {:.4} {:.4}",
+                    color.hue.to_positive_degrees(),
+                    color.whiteness,
+                    color.blackness
+                )
+            }
+            Space::Xyz => {
+                let color = Xyz::from_color(color);
+                format!("{:.4} {:.4} {:.4}", color.x, color.y, color.z)
+            }
+            Space::Lab => {
+                let color = Lab::from_color(color);
+                format!("{:.2} {:.2} {:.2}", color.l, color.a, color.b)
+            }
+            Space::Lch => {
+                let color = Lch::from_color(color);
+                format!(
+                    "{:.2} {:.2} {:.2}",
+                    color.l,
+                    color.chroma,
+                    color.hue.to_positive_degrees()
+                )
+            }
+            Space::Oklab => {
+                let color = Oklab::from_color(color);
+                format!("{:.4} {:.4} {:.4}", color.l, color.a, color.b)
+            }
+            Space::Oklch => {
+                let color = Oklch::from_color(color);
+                format!(
+                    "{:.4} {:.4} {:.2}",
+                    color.l,
+                    color.chroma,
+                    color.hue.to_positive_degrees()
+                )
+            }
+        }
+    }
+
+    /// Create `steps` evenly spaced colors from a gradient through `colors`,
+    /// mixed in this space.
+    fn gradient(self, colors: &[Srgb], steps: usize) -> Result<Vec<Srgb>, String> {
+        fn mix<C>(colors: &[Srgb], steps: usize) -> Vec<Srgb>
+        where
+            C: palette::Mix<Scalar = f32> + Clone + FromColor<Srgb>,
+            Srgb: FromColor<C>,
+        {
+            Gradient::new(colors.iter().map(|&color| C::from_color(color)))
+                .take(steps)
+                .map(Srgb::from_color)
+                .collect()
+        }
+
+        let colors = match self {
+            Space::Srgb => {
+                return Err("nonlinear sRGB can't be mixed, try linsrgb instead".into());
+            }
+            Space::LinSrgb => mix::<LinSrgb>(colors, steps),
+            Space::Hsl => mix::<Hsl>(colors, steps),
+            Space::Hsv => mix::<Hsv>(colors, steps),
+            Space::Hwb => mix::<Hwb>(colors, steps),
+            Space::Xyz => mix::<Xyz>(colors, steps),
+            Space::Lab => mix::<Lab>(colors, steps),
+            Space::Lch => mix::<Lch>(colors, steps),
+            Space::Oklab => mix::<Oklab>(colors, steps),
+            Space::Oklch => mix::<Oklch>(colors, steps),
+        };
+
+        Ok(colors)
+    }
+}
+
+impl FromStr for Space {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        SPACES
+            .iter()
+            .cloned()
+            .find(|space| space.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("unknown color space {:?}", name))
+    }
+}
+
+/// Parse a hex code or a CSS color name.
+fn parse_color(input: &str) -> Result<Srgb, String> {
+    Srgb::<u8>::from_str(input)
+        .ok()
+        .or_else(|| named::from_str(&input.to_ascii_lowercase()))
+        .map(Srgb::into_format)
+        .ok_or_else(|| format!("invalid color {:?}", input))
+}
+
+fn parse_colors(inputs: &[&str], expected: usize) -> Result<Vec<Srgb>, String> {
+    if inputs.len() != expected {
+        return Err(format!(
+            "expected {} colors, but found {}",
+            expected,
+            inputs.len()
+        ));
+    }
+
+    inputs.iter().map(|input| parse_color(input)).collect()
+}
+
+fn hex(color: Srgb) -> String {
+    format!("#{:x}", color.into_format::<u8>())
+}
+
+pub fn convert(arguments: &[&str]) -> Result<String, String> {
+    let (color, spaces) = match arguments {
+        [color] => (parse_color(color)?, SPACES.to_vec()),
+        [color, space] => (parse_color(color)?, vec![space.parse()?]),
+        _ => return Err("expected a color and an optional color space".into()),
+    };
+
+    let mut output = format!("{:<8}{}", "hex", hex(color));
+    for space in spaces {
+        output.push_str(&format!("\n{:<8}{}", space.name(), space.format(color)));
+    }
+
+    Ok(output)
+}
+
+pub fn delta_e(arguments: &[&str]) -> Result<String, String> {
+    let colors = parse_colors(arguments, 2)?;
+    let difference = Lab::from_color(colors[0]).get_color_difference(&Lab::from_color(colors[1]));
+
+    Ok(format!("{:.4}", difference))
+}
+
+pub fn contrast(arguments: &[&str]) -> Result<String, String> {
+    let colors = parse_colors(arguments, 2)?;
+    let (foreground, background) = (colors[0], colors[1]);
+    let verdict = |passes: bool| if passes { "pass" } else { "fail" };
+
+    Ok(format!(
+        "ratio            {:.2}:1\n\
+         AA text          {}\n\
+         AA large text    {}\n\
+         AAA text         {}\n\
+         AAA large text   {}\n\
+         graphics         {}",
+        foreground.get_contrast_ratio(&background),
+        verdict(foreground.has_min_contrast_text(&background)),
+        verdict(foreground.has_min_contrast_large_text(&background)),
+        verdict(foreground.has_enhanced_contrast_text(&background)),
+        verdict(foreground.has_enhanced_contrast_large_text(&background)),
+        verdict(foreground.has_min_contrast_graphics(&background)),
+    ))
+}
+
+pub fn gradient(arguments: &[&str]) -> Result<String, String> {
+    let mut steps = 10;
+    let mut space = Space::Oklab;
+    let mut colors = Vec::new();
+
+    let mut arguments = arguments.iter();
+    while let Some(&argument) = arguments.next() {
+        match argument {
+            "--steps" => {
+                steps = arguments
+                    .next()
+                    .and_then(|steps| steps.parse().ok())
+                    .ok_or("expected a number of steps after --steps")?;
+            }
+            "--space" => {
+                space = arguments
+                    .next()
+                    .ok_or("expected a color space after --space")?
+                    .parse()?;
+            }
+            color => colors.push(parse_color(color)?),
+        }
+    }
+
+    if colors.len() < 2 {
+        return Err("expected at least two colors".into());
+    }
+
+    let colors: Vec<_> = space
+        .gradient(&colors, steps)?
+        .into_iter()
+        .map(hex)
+        .collect();
+    Ok(colors.join("\n"))
+}
+
+pub fn swatch(arguments: &[&str]) -> Result<String, String> {
+    if arguments.is_empty() {
+        return Err("expected at least one color".into());
+    }
+
+    let lines = arguments
+        .iter()
+        .map(|input| {
+            let color = parse_color(input)?.into_format::<u8>();
+            Ok(format!(
+                "\x1b[48;2;{};{};{}m        \x1b[0m {:x}",
+                color.red, color.green, color.blue, color
+            ))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{contrast, convert, delta_e, gradient, parse_color, swatch};
+    use palette::Srgb;
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            parse_color("#ff8000"),
+            Ok(Srgb::new(1.0, 128.0 / 255.0, 0.0))
+        );
+        assert_eq!(parse_color("f80"), Ok(Srgb::new(1.0, 136.0 / 255.0, 0.0)));
+        assert_eq!(parse_color("White"), Ok(Srgb::new(1.0, 1.0, 1.0)));
+        assert!(parse_color("#ff80").is_err());
+        assert!(parse_color("notacolor").is_err());
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            convert(&["#ffffff", "lab"]),
+            Ok("hex     #ffffff\nlab     100.00 0.00 0.00".into())
+        );
+        assert_eq!(
+            convert(&["red"]).map(|output| output.lines().count()),
+            Ok(11)
+        );
+        assert!(convert(&["red", "cmyk"]).is_err());
+
+        assert_eq!(delta_e(&["#123456", "#123456"]), Ok("0.0000".into()));
+        assert!(contrast(&["black", "white"])
+            .unwrap()
+            .starts_with("ratio            21.00:1"));
+
+        assert_eq!(
+            gradient(&["black", "white", "--steps", "3", "--space", "hsv"]),
+            Ok("#000000\n#808080\n#ffffff".into())
+        );
+        assert!(gradient(&["black", "white", "--space", "srgb"]).is_err());
+        assert!(gradient(&["black"]).is_err());
+        assert!(swatch(&["black", "#fff"]).unwrap().ends_with(" ffffff"));
+    }
+}
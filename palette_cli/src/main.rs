@@ -0,0 +1,49 @@
+//! A command line tool for converting, comparing and previewing colors.
+//!
+//! Run `palette-cli help` for a list of commands.
+
+use std::env;
+use std::process;
+
+mod commands;
+
+const USAGE: &str = "\
+Usage: palette-cli <command> [arguments]
+
+Colors are hex codes, such as #1e90ff or f80, or CSS color names.
+
+Commands:
+    convert <color> [space]         Show the color in all spaces, or only in `space`
+    delta-e <color> <color>         The CIEDE2000 difference between two colors
+    contrast <color> <color>        The WCAG contrast ratio between two colors
+    gradient <color>... [options]   Print evenly spaced colors from a gradient
+        --steps <n>                 The number of colors (default: 10)
+        --space <space>             The space to mix in (default: oklab)
+    swatch <color>...               Preview colors in the terminal
+    help                            Show this message
+
+Spaces: srgb, linsrgb, hsl, hsv, hwb, xyz, lab, lch, oklab, oklch";
+
+fn main() {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+
+    let result = match arguments.split_first() {
+        Some((&"convert", arguments)) => commands::convert(arguments),
+        Some((&"delta-e", arguments)) => commands::delta_e(arguments),
+        Some((&"contrast", arguments)) => commands::contrast(arguments),
+        Some((&"gradient", arguments)) => commands::gradient(arguments),
+        Some((&"swatch", arguments)) => commands::swatch(arguments),
+        Some((&"help", _)) | Some((&"--help", _)) | Some((&"-h", _)) => Ok(USAGE.into()),
+        Some((command, _)) => Err(format!("unknown command {:?}", command)),
+        None => Err("missing command".into()),
+    };
+
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            process::exit(1);
+        }
+    }
+}
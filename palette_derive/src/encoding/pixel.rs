@@ -98,6 +98,7 @@ pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::
     }
 
     let pixel_trait_path = util::path(&["Pixel"], item_meta.internal);
+    let component_array_trait_path = util::path(["ComponentArray"], item_meta.internal);
 
     let mut implementation = if let Some(field_type) = field_type {
         let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
@@ -107,6 +108,9 @@ pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::
             unsafe impl #impl_generics #pixel_trait_path<#field_type> for #ident #type_generics #where_clause {
                 const CHANNELS: usize = #number_of_channels;
             }
+
+            #[automatically_derived]
+            unsafe impl #impl_generics #component_array_trait_path<#field_type, #number_of_channels> for #ident #type_generics #where_clause {}
         }
     } else {
         errors.push(syn::Error::new(
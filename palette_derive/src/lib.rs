@@ -34,6 +34,7 @@ macro_rules! syn_try {
 mod alpha;
 mod convert;
 mod encoding;
+mod literal;
 mod meta;
 mod util;
 
@@ -69,3 +70,13 @@ pub fn derive_from_color_unclamped(tokens: TokenStream) -> TokenStream {
 pub fn derive_pixel(tokens: TokenStream) -> TokenStream {
     syn_try!(encoding::derive_pixel(tokens))
 }
+
+#[proc_macro]
+pub fn srgb(tokens: TokenStream) -> TokenStream {
+    syn_try!(literal::srgb(tokens))
+}
+
+#[proc_macro]
+pub fn lin_srgb(tokens: TokenStream) -> TokenStream {
+    syn_try!(literal::lin_srgb(tokens))
+}
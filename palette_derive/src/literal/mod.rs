@@ -0,0 +1,3 @@
+pub use self::rgb::{lin_srgb, srgb};
+
+mod rgb;
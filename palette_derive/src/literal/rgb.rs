@@ -0,0 +1,214 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+
+use quote::quote;
+use syn::LitStr;
+
+use crate::util;
+
+pub fn srgb(input: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    let color = parse_input(input)?;
+
+    let [red, green, blue] = color.rgb;
+    let red = Literal::u8_suffixed(to_u8(red));
+    let green = Literal::u8_suffixed(to_u8(green));
+    let blue = Literal::u8_suffixed(to_u8(blue));
+
+    let rgb_path = util::path(["rgb", "Rgb"], false);
+    let srgb_path = util::path(["encoding", "Srgb"], false);
+
+    let rgb = quote! {
+        #rgb_path::<#srgb_path, u8> {
+            red: #red,
+            green: #green,
+            blue: #blue,
+            standard: ::core::marker::PhantomData,
+        }
+    };
+
+    let alpha = color.alpha.map(|alpha| Literal::u8_suffixed(to_u8(alpha)));
+    Ok(with_alpha(rgb, alpha).into())
+}
+
+pub fn lin_srgb(
+    input: TokenStream,
+) -> ::std::result::Result<TokenStream, Vec<::syn::parse::Error>> {
+    let color = parse_input(input)?;
+
+    let [red, green, blue] = color.rgb;
+    let red = Literal::f64_unsuffixed(into_linear(red));
+    let green = Literal::f64_unsuffixed(into_linear(green));
+    let blue = Literal::f64_unsuffixed(into_linear(blue));
+
+    let rgb_path = util::path(["rgb", "Rgb"], false);
+    let linear_path = util::path(["encoding", "Linear"], false);
+    let srgb_path = util::path(["encoding", "Srgb"], false);
+
+    let rgb = quote! {
+        #rgb_path::<#linear_path<#srgb_path>, _> {
+            red: #red,
+            green: #green,
+            blue: #blue,
+            standard: ::core::marker::PhantomData,
+        }
+    };
+
+    let alpha = color.alpha.map(Literal::f64_unsuffixed);
+    Ok(with_alpha(rgb, alpha).into())
+}
+
+fn with_alpha(rgb: TokenStream2, alpha: Option<Literal>) -> TokenStream2 {
+    if let Some(alpha) = alpha {
+        let alpha_path = util::path(["Alpha"], false);
+        quote! {
+            #alpha_path {
+                color: #rgb,
+                alpha: #alpha,
+            }
+        }
+    } else {
+        rgb
+    }
+}
+
+fn parse_input(input: TokenStream) -> ::std::result::Result<Color, Vec<::syn::parse::Error>> {
+    let literal: LitStr = syn::parse(input).map_err(|error| vec![error])?;
+    parse_color(&literal.value()).map_err(|message| vec![syn::Error::new(literal.span(), message)])
+}
+
+/// An sRGB color with components from `0.0` to `1.0`.
+struct Color {
+    rgb: [f64; 3],
+    alpha: Option<f64>,
+}
+
+/// Parse a hex code, such as `#1e90ff`, or an `rgb(...)` or `rgba(...)`
+/// function.
+fn parse_color(input: &str) -> Result<Color, String> {
+    let input = input.trim();
+
+    if let Some(hex) = input.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    let lowercase = input.to_ascii_lowercase();
+    let arguments = lowercase
+        .strip_prefix("rgba(")
+        .or_else(|| lowercase.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'));
+
+    match arguments {
+        Some(arguments) => parse_rgb_function(arguments),
+        None => Err(format!(
+            "expected a hex code, such as \"#1e90ff\", or an rgb(...) function, but found {:?}",
+            input
+        )),
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Color, String> {
+    let digits = hex
+        .chars()
+        .map(|character| {
+            character
+                .to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| format!("invalid hex digit {:?}", character))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let values: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect(),
+        length => {
+            return Err(format!(
+                "invalid hex code length {}, expected 3 or 6 digits, or 4 or 8 digits with alpha",
+                length
+            ))
+        }
+    };
+
+    let to_float = |value: u8| f64::from(value) / 255.0;
+    Ok(Color {
+        rgb: [
+            to_float(values[0]),
+            to_float(values[1]),
+            to_float(values[2]),
+        ],
+        alpha: values.get(3).cloned().map(to_float),
+    })
+}
+
+/// Parse the arguments of `rgb(...)`, separated either by commas, or by
+/// spaces with a `/` before the alpha.
+fn parse_rgb_function(arguments: &str) -> Result<Color, String> {
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if arguments.contains(',') {
+        let mut arguments: Vec<_> = arguments.split(',').map(str::trim).collect();
+        let alpha = if arguments.len() == 4 {
+            arguments.pop()
+        } else {
+            None
+        };
+        (arguments, alpha)
+    } else {
+        let mut parts = arguments.splitn(2, '/');
+        let channels = parts.next().unwrap_or("").split_whitespace().collect();
+        (channels, parts.next().map(str::trim))
+    };
+
+    if channels.len() != 3 {
+        return Err(format!(
+            "expected 3 color channels, but found {}",
+            channels.len()
+        ));
+    }
+
+    let mut rgb = [0.0; 3];
+    for (value, channel) in rgb.iter_mut().zip(channels) {
+        *value = parse_number(channel, 255.0)?;
+    }
+
+    Ok(Color {
+        rgb,
+        alpha: alpha.map(|alpha| parse_number(alpha, 1.0)).transpose()?,
+    })
+}
+
+/// Parse a number or a percentage, and scale it to the range from `0.0` to
+/// `1.0`, where a number equal to `max` is `1.0`.
+fn parse_number(input: &str, max: f64) -> Result<f64, String> {
+    let (number, max) = match input.strip_suffix('%') {
+        Some(number) => (number, 100.0),
+        None => (input, max),
+    };
+
+    let value: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid number {:?}", input))?;
+
+    if (0.0..=max).contains(&value) {
+        Ok(value / max)
+    } else {
+        Err(format!(
+            "{:?} is outside the range from 0 to {}",
+            input, max
+        ))
+    }
+}
+
+fn to_u8(value: f64) -> u8 {
+    (value * 255.0).round() as u8
+}
+
+/// The sRGB transfer function, from encoded to linear values.
+fn into_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}